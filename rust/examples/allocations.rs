@@ -0,0 +1,59 @@
+//! Count the heap allocations made while generating superstore rows.
+//!
+//! Low-cardinality columns (ship mode, segment, country, region, category)
+//! share one allocation per distinct value, so they add nothing per row.
+//!
+//! Run with `cargo run --release --example allocations -p superstore`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use superstore::{superstore_with_config, SuperstoreConfig};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() {
+    let count = 1_000_000;
+    let config = SuperstoreConfig {
+        count,
+        seed: Some(42),
+        ..Default::default()
+    };
+
+    let (allocations, bytes) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        BYTES.load(Ordering::Relaxed),
+    );
+    let start = Instant::now();
+    let rows = superstore_with_config(&config);
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let bytes = BYTES.load(Ordering::Relaxed) - bytes;
+
+    println!("{} rows in {:.2?}", rows.len(), elapsed);
+    println!(
+        "{} allocations ({:.1} per row), {:.1} MiB allocated",
+        allocations,
+        allocations as f64 / count as f64,
+        bytes as f64 / (1024.0 * 1024.0)
+    );
+}
@@ -0,0 +1,44 @@
+//! Generate an e-commerce dataset from Rust and join orders back to their
+//! sessions.
+//!
+//! Run with `cargo run --example ecommerce -p superstore`.
+
+use std::collections::HashMap;
+
+use superstore::{ecommerce, EcommerceConfig};
+
+fn main() {
+    let data = ecommerce(&EcommerceConfig {
+        sessions: 5000,
+        seed: Some(42),
+        ..Default::default()
+    });
+
+    println!(
+        "{} products, {} sessions, {} cart events, {} orders, {} customers",
+        data.products.len(),
+        data.sessions.len(),
+        data.cart_events.len(),
+        data.orders.len(),
+        data.customers.len()
+    );
+
+    let sessions: HashMap<&str, _> = data
+        .sessions
+        .iter()
+        .map(|s| (s.session_id.as_str(), s))
+        .collect();
+    let mut revenue_by_source: HashMap<&str, f64> = HashMap::new();
+    for order in &data.orders {
+        let session = sessions[order.session_id.as_str()];
+        *revenue_by_source
+            .entry(session.traffic_source.as_str())
+            .or_default() += order.total;
+    }
+
+    let mut sources: Vec<_> = revenue_by_source.into_iter().collect();
+    sources.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for (source, revenue) in sources {
+        println!("{:<12} {:>12.2}", source, revenue);
+    }
+}
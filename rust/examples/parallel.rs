@@ -0,0 +1,47 @@
+//! Time 1M configured superstore rows on one thread and on every core.
+//!
+//! Both runs produce the same rows: with `per_row_seeding`,
+//! `superstore_parallel_with_config` equals `superstore_with_config` whatever
+//! the thread count.
+//!
+//! Run with `cargo run --release --example parallel -p superstore`.
+
+use std::time::Instant;
+
+use superstore::parallel::{num_threads, superstore_parallel_with_config};
+use superstore::{superstore_with_config, SuperstoreConfig};
+
+fn main() {
+    let config = SuperstoreConfig {
+        count: 1_000_000,
+        seed: Some(42),
+        per_row_seeding: true,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let sequential = superstore_with_config(&config);
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel = superstore_parallel_with_config(&config).expect("per_row_seeding is set");
+    let parallel_time = start.elapsed();
+
+    assert_eq!(sequential.len(), parallel.len());
+    assert_eq!(
+        sequential.last().unwrap().order_id,
+        parallel.last().unwrap().order_id
+    );
+    println!(
+        "sequential: {} rows in {:.2?}",
+        sequential.len(),
+        sequential_time
+    );
+    println!(
+        "parallel:   {} rows in {:.2?} on {} threads ({:.1}x)",
+        parallel.len(),
+        parallel_time,
+        num_threads(),
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,45 @@
+//! Time rounding a 10M-value column to cents with `round_cents` against the
+//! scalar `(x * 100.0).round() / 100.0`.
+//!
+//! Both give bit-for-bit the same values.
+//!
+//! Run with `cargo run --release --example round_cents -p superstore`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rand::RngExt;
+use superstore::{round_cents, seeded_rng};
+
+const VALUES: usize = 10_000_000;
+
+fn main() {
+    let mut rng = seeded_rng(Some(42));
+    let values: Vec<f64> = (0..VALUES)
+        .map(|_| rng.random_range(-10_000.0..10_000.0))
+        .collect();
+
+    let mut scalar = values.clone();
+    let start = Instant::now();
+    for value in black_box(&mut scalar).iter_mut() {
+        *value = (*value * 100.0).round() / 100.0;
+    }
+    let scalar_time = start.elapsed();
+
+    let mut vectorized = values;
+    let start = Instant::now();
+    round_cents(black_box(&mut vectorized));
+    let vectorized_time = start.elapsed();
+
+    assert!(scalar
+        .iter()
+        .zip(&vectorized)
+        .all(|(a, b)| a.to_bits() == b.to_bits()));
+    println!("scalar:      {} values in {:.2?}", VALUES, scalar_time);
+    println!(
+        "round_cents: {} values in {:.2?} ({:.1}x)",
+        VALUES,
+        vectorized_time,
+        scalar_time.as_secs_f64() / vectorized_time.as_secs_f64()
+    );
+}
@@ -4,11 +4,161 @@
 
 #![allow(non_snake_case)]
 
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray, TimestampNanosecondArray,
+};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use chrono::NaiveDateTime;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 
+use crate::output::import_output_library;
 use superstore::arrow::{employees_arrow, superstore_arrow};
 
+/// Parse a generator timestamp ("YYYY-MM-DD HH:MM:SS", optionally with a
+/// 'T' separator or fractional seconds)
+pub fn parse_timestamp(name: &str, value: &str) -> PyResult<NaiveDateTime> {
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Column '{}' has an unparseable timestamp '{}'",
+                name, value
+            ))
+        })
+}
+
+/// A pyarrow Table built column by column from generator rows.
+///
+/// Each column's type comes from the method that adds it, not from the
+/// values, so the schema is the same for every seed and count (zero rows
+/// included). Integers are int64, floats float64, strings string, and
+/// timestamps timestamp[ns]; `optional_*` columns are nullable.
+#[derive(Default)]
+pub struct ArrowTable {
+    fields: Vec<Field>,
+    arrays: Vec<ArrayRef>,
+}
+
+impl ArrowTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn column(mut self, name: &str, nullable: bool, array: ArrayRef) -> Self {
+        self.fields
+            .push(Field::new(name, array.data_type().clone(), nullable));
+        self.arrays.push(array);
+        self
+    }
+
+    pub fn int64(self, name: &str, values: impl IntoIterator<Item = i64>) -> Self {
+        self.column(name, false, Arc::new(Int64Array::from_iter_values(values)))
+    }
+
+    pub fn optional_int64(self, name: &str, values: impl IntoIterator<Item = Option<i64>>) -> Self {
+        self.column(name, true, Arc::new(Int64Array::from_iter(values)))
+    }
+
+    pub fn float64(self, name: &str, values: impl IntoIterator<Item = f64>) -> Self {
+        self.column(
+            name,
+            false,
+            Arc::new(Float64Array::from_iter_values(values)),
+        )
+    }
+
+    pub fn optional_float64(
+        self,
+        name: &str,
+        values: impl IntoIterator<Item = Option<f64>>,
+    ) -> Self {
+        self.column(name, true, Arc::new(Float64Array::from_iter(values)))
+    }
+
+    pub fn boolean(self, name: &str, values: impl IntoIterator<Item = bool>) -> Self {
+        let values: BooleanArray = values.into_iter().map(Some).collect();
+        self.column(name, false, Arc::new(values))
+    }
+
+    pub fn utf8<S: AsRef<str>>(self, name: &str, values: impl IntoIterator<Item = S>) -> Self {
+        self.column(name, false, Arc::new(StringArray::from_iter_values(values)))
+    }
+
+    pub fn optional_utf8<S: AsRef<str>>(
+        self,
+        name: &str,
+        values: impl IntoIterator<Item = Option<S>>,
+    ) -> Self {
+        self.column(name, true, Arc::new(StringArray::from_iter(values)))
+    }
+
+    pub fn timestamp(
+        self,
+        name: &str,
+        values: impl IntoIterator<Item = NaiveDateTime>,
+    ) -> PyResult<Self> {
+        let nanos = values
+            .into_iter()
+            .map(|dt| {
+                dt.and_utc().timestamp_nanos_opt().ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "Column '{}' has timestamp {} outside the timestamp[ns] range",
+                        name, dt
+                    ))
+                })
+            })
+            .collect::<PyResult<Vec<i64>>>()?;
+        Ok(self.column(name, false, Arc::new(TimestampNanosecondArray::from(nanos))))
+    }
+
+    /// Append columns `range` of a RecordBatch from the core crate, renamed to
+    /// `names[range]` (the pandas column names); int32 columns widen to int64
+    pub fn batch_columns(
+        mut self,
+        batch: &RecordBatch,
+        range: Range<usize>,
+        names: &[&str],
+    ) -> PyResult<Self> {
+        let schema = batch.schema();
+        for i in range {
+            let array = batch.column(i);
+            let array = if array.data_type() == &DataType::Int32 {
+                cast(array, &DataType::Int64).map_err(|e| PyRuntimeError::new_err(e.to_string()))?
+            } else {
+                array.clone()
+            };
+            self = self.column(names[i], schema.field(i).is_nullable(), array);
+        }
+        Ok(self)
+    }
+
+    /// Hand the columns to pyarrow as a Table
+    pub fn into_pyarrow(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(self.fields)), self.arrays)
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        batch_to_pyarrow(py, &batch)
+    }
+}
+
+/// Hand a RecordBatch to pyarrow as a Table, through an IPC stream
+fn batch_to_pyarrow(py: Python<'_>, batch: &RecordBatch) -> PyResult<Py<PyAny>> {
+    import_output_library(py, "arrow")?;
+    let ipc_bytes = batch_to_ipc_bytes(batch).map_err(PyRuntimeError::new_err)?;
+    let table = py
+        .import("pyarrow.ipc")?
+        .call_method1("open_stream", (PyBytes::new(py, &ipc_bytes),))?
+        .call_method0("read_all")?;
+    Ok(table.into())
+}
+
 /// Convert an Arrow RecordBatch to IPC bytes.
 fn batch_to_ipc_bytes(batch: &arrow::record_batch::RecordBatch) -> Result<Vec<u8>, String> {
     use arrow::ipc::writer::StreamWriter;
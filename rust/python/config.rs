@@ -0,0 +1,26 @@
+//! Config argument handling shared by the generator bindings.
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Convert a `config` argument (pydantic model or dict) into a dict.
+///
+/// Models are dumped with `mode="json"` so enums arrive as strings, which
+/// makes a model and the equivalent dict interchangeable. `expected` names
+/// the accepted types for the TypeError, e.g. "a LogsConfig, dict, or None".
+pub fn config_dict<'py>(
+    config: &Bound<'py, PyAny>,
+    expected: &str,
+) -> PyResult<Bound<'py, PyDict>> {
+    if config.hasattr("model_dump")? {
+        let kwargs = PyDict::new(config.py());
+        kwargs.set_item("mode", "json")?;
+        let dumped = config.call_method("model_dump", (), Some(&kwargs))?;
+        return Ok(dumped.cast_into::<PyDict>()?);
+    }
+    match config.cast::<PyDict>() {
+        Ok(dict) => Ok(dict.clone()),
+        Err(_) => Err(PyTypeError::new_err(format!("config must be {}", expected))),
+    }
+}
@@ -2,9 +2,8 @@
 
 use pyo3::prelude::*;
 use pyo3::types::PyList;
-use rand::rngs::StdRng;
-use rand::SeedableRng;
-use superstore::{ClaytonCopula, FrankCopula, GaussianCopula, GumbelCopula};
+use superstore::rng::seeded_rng;
+use superstore::{ClaytonCopula, FrankCopula, GaussianCopula, GumbelCopula, StudentTCopula};
 
 /// Gaussian (Normal) Copula.
 ///
@@ -49,10 +48,7 @@ impl PyGaussianCopula {
     ///     List of n samples, where each sample is a list of d uniform [0,1] values
     #[pyo3(signature = (n, seed=None))]
     fn sample(&self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         // Convert Vec<Vec<f64>> to PyList of PyLists
         let result = PyList::empty(py);
@@ -64,6 +60,67 @@ impl PyGaussianCopula {
     }
 }
 
+/// Student-t Copula.
+///
+/// Uses a multivariate Student-t distribution, so unlike the Gaussian copula
+/// extreme values tend to occur together in both tails. Lower df means
+/// stronger tail dependence.
+///
+/// Example:
+///     >>> copula = StudentTCopula([[1.0, 0.5], [0.5, 1.0]], df=3.0)
+///     >>> samples = copula.sample(100)
+#[pyclass(name = "StudentTCopula")]
+pub struct PyStudentTCopula {
+    inner: StudentTCopula,
+}
+
+#[pymethods]
+impl PyStudentTCopula {
+    /// Create a new Student-t copula.
+    ///
+    /// Args:
+    ///     correlation_matrix: Symmetric positive semi-definite matrix with 1s on diagonal
+    ///     df: Degrees of freedom (must be > 0)
+    #[new]
+    fn new(correlation_matrix: Vec<Vec<f64>>, df: f64) -> PyResult<Self> {
+        let inner = StudentTCopula::new(correlation_matrix, df)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Get the degrees of freedom.
+    #[getter]
+    fn df(&self) -> f64 {
+        self.inner.df()
+    }
+
+    /// Get the dimension of the copula.
+    #[getter]
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    /// Generate n samples from the copula.
+    ///
+    /// Args:
+    ///     n: Number of samples to generate
+    ///     seed: Optional random seed
+    ///
+    /// Returns:
+    ///     List of n samples, where each sample is a list of d uniform [0,1] values
+    #[pyo3(signature = (n, seed=None))]
+    fn sample(&self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
+        let mut rng = seeded_rng(seed);
+        let samples = self.inner.sample_n(&mut rng, n);
+        let result = PyList::empty(py);
+        for sample in samples {
+            let inner_list = PyList::new(py, &sample)?;
+            result.append(inner_list)?;
+        }
+        Ok(result.into())
+    }
+}
+
 /// Clayton Copula.
 ///
 /// An Archimedean copula with lower tail dependence.
@@ -108,13 +165,15 @@ impl PyClaytonCopula {
         self.inner.kendalls_tau()
     }
 
+    /// Get lower tail dependence coefficient.
+    fn lower_tail_dependence(&self) -> f64 {
+        self.inner.lower_tail_dependence()
+    }
+
     /// Generate n samples from the copula.
     #[pyo3(signature = (n, seed=None))]
     fn sample(&self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         let result = PyList::empty(py);
         for sample in samples {
@@ -170,10 +229,7 @@ impl PyFrankCopula {
     ///     List of n tuples (u, v), each containing two uniform [0,1] values
     #[pyo3(signature = (n, seed=None))]
     fn sample(&self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         let result = PyList::empty(py);
         for (u, v) in samples {
@@ -190,7 +246,7 @@ impl PyFrankCopula {
 /// Good for modeling dependencies where extreme high values tend to occur together.
 ///
 /// Example:
-///     >>> copula = GumbelCopula(2.0)  # theta=2 means moderate upper tail dependence
+///     >>> copula = GumbelCopula(2.0, 2)  # theta=2 means moderate upper tail dependence
 ///     >>> samples = copula.sample(100)
 #[pyclass(name = "GumbelCopula")]
 pub struct PyGumbelCopula {
@@ -205,9 +261,11 @@ impl PyGumbelCopula {
     ///     theta: Dependence parameter (must be >= 1)
     ///         - theta = 1: independence
     ///         - theta > 1: positive dependence
+    ///     dim: Number of dimensions (must be >= 2, default: 2)
     #[new]
-    fn new(theta: f64) -> PyResult<Self> {
-        let inner = GumbelCopula::new(theta)
+    #[pyo3(signature = (theta, dim=2))]
+    fn new(theta: f64, dim: usize) -> PyResult<Self> {
+        let inner = GumbelCopula::new(theta, dim)
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
         Ok(Self { inner })
     }
@@ -218,6 +276,12 @@ impl PyGumbelCopula {
         self.inner.theta()
     }
 
+    /// Get the dimension of the copula.
+    #[getter]
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
     /// Get Kendall's tau (measure of correlation).
     fn kendalls_tau(&self) -> f64 {
         self.inner.kendalls_tau()
@@ -228,21 +292,18 @@ impl PyGumbelCopula {
         self.inner.upper_tail_dependence()
     }
 
-    /// Generate n bivariate samples from the copula.
+    /// Generate n samples from the copula.
     ///
     /// Returns:
-    ///     List of n tuples (u, v), each containing two uniform [0,1] values
+    ///     List of n samples, where each sample is a list of d uniform [0,1] values
     #[pyo3(signature = (n, seed=None))]
     fn sample(&self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         let result = PyList::empty(py);
-        for (u, v) in samples {
-            let pair = PyList::new(py, &[u, v])?;
-            result.append(pair)?;
+        for sample in samples {
+            let inner_list = PyList::new(py, &sample)?;
+            result.append(inner_list)?;
         }
         Ok(result.into())
     }
@@ -251,6 +312,7 @@ impl PyGumbelCopula {
 /// Register copula classes with Python module.
 pub fn register_copulas(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyGaussianCopula>()?;
+    m.add_class::<PyStudentTCopula>()?;
     m.add_class::<PyClaytonCopula>()?;
     m.add_class::<PyFrankCopula>()?;
     m.add_class::<PyGumbelCopula>()?;
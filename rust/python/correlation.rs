@@ -5,7 +5,9 @@
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 
-use superstore::correlation::{pearson_correlation, sample_bivariate, CorrelationMatrix};
+use superstore::correlation::{
+    nearest_correlation_matrix, pearson_correlation, sample_bivariate, CorrelationMatrix,
+};
 
 /// A correlation matrix for generating correlated multivariate normal data.
 ///
@@ -193,10 +195,27 @@ fn pearsonCorrelation(x: Vec<f64>, y: Vec<f64>) -> f64 {
     pearson_correlation(&x, &y)
 }
 
+/// Find the nearest valid correlation matrix to a symmetric matrix.
+///
+/// Uses Higham's alternating projections, so correlations that can't all hold
+/// at once are adjusted as little as possible.
+///
+/// # Arguments
+/// * `matrix` - Symmetric matrix as a list of rows
+///
+/// # Returns
+/// Tuple of (repaired matrix, Frobenius distance from the input)
+#[pyfunction]
+fn nearestCorrelationMatrix(matrix: Vec<Vec<f64>>) -> PyResult<(Vec<Vec<f64>>, f64)> {
+    nearest_correlation_matrix(&matrix)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
 /// Register correlation functions with the Python module.
 pub fn register_correlation(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyCorrelationMatrix>()?;
     m.add_function(wrap_pyfunction!(sampleBivariate, m)?)?;
     m.add_function(wrap_pyfunction!(pearsonCorrelation, m)?)?;
+    m.add_function(wrap_pyfunction!(nearestCorrelationMatrix, m)?)?;
     Ok(())
 }
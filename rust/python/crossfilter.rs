@@ -1,3 +1,6 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
@@ -151,19 +154,11 @@ pub fn py_machines(
         if let Ok(int_val) = cfg.extract::<usize>() {
             (int_val, None)
         // Check if it's a pydantic model (has model_dump method)
-        } else if cfg.hasattr("model_dump")? {
-            // Use mode="json" to ensure enums are serialized as strings
-            let kwargs = PyDict::new(py);
-            kwargs.set_item("mode", "json")?;
-            let dict = cfg.call_method("model_dump", (), Some(&kwargs))?;
-            let dict = dict.downcast::<PyDict>()?;
-            parse_crossfilter_config(dict)?
-        } else if let Ok(dict) = cfg.downcast::<PyDict>() {
-            parse_crossfilter_config(dict)?
         } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "config must be a CrossfilterConfig, dict, int, or None",
-            ));
+            parse_crossfilter_config(&config_dict(
+                cfg,
+                "a CrossfilterConfig, dict, int, or None",
+            )?)?
         }
     } else {
         (100, None)
@@ -482,9 +477,11 @@ fn telemetry_to_pydict(py: Python<'_>, r: &TelemetryReading) -> PyResult<Py<PyDi
     Ok(dict.into())
 }
 
-/// Create pandas DataFrame from TelemetryReading rows
-fn create_telemetry_pandas(py: Python<'_>, rows: &[TelemetryReading]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for TelemetryReading rows, shared by the pandas and Arrow outputs
+fn telemetry_columns<'py>(
+    py: Python<'py>,
+    rows: &[TelemetryReading],
+) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
@@ -517,13 +514,39 @@ fn create_telemetry_pandas(py: Python<'_>, rows: &[TelemetryReading]) -> PyResul
     data.set_item("anomaly_type", PyList::new(py, &anomaly_types)?)?;
     data.set_item("health_score", PyList::new(py, &health_scores)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from TelemetryReading rows
+fn create_telemetry_pandas(py: Python<'_>, rows: &[TelemetryReading]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (telemetry_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from TelemetryReading rows
+fn create_telemetry_arrow(py: Python<'_>, rows: &[TelemetryReading]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("timestamp", rows.iter().map(|r| r.timestamp.as_str()))
+        .utf8("machine_id", rows.iter().map(|r| r.machine_id.as_str()))
+        .utf8("kind", rows.iter().map(|r| r.kind.as_str()))
+        .int64("cores", rows.iter().map(|r| i64::from(r.cores)))
+        .utf8("region", rows.iter().map(|r| r.region.as_str()))
+        .utf8("zone", rows.iter().map(|r| r.zone.as_str()))
+        .float64("cpu", rows.iter().map(|r| r.cpu))
+        .float64("mem", rows.iter().map(|r| r.mem))
+        .float64("free", rows.iter().map(|r| r.free))
+        .float64("network", rows.iter().map(|r| r.network))
+        .float64("disk", rows.iter().map(|r| r.disk))
+        .utf8("state", rows.iter().map(|r| r.state.as_str()))
+        .utf8("anomaly_type", rows.iter().map(|r| r.anomaly_type.as_str()))
+        .float64("health_score", rows.iter().map(|r| r.health_score))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from TelemetryReading rows
 fn create_telemetry_polars(py: Python<'_>, rows: &[TelemetryReading]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
@@ -848,7 +871,7 @@ fn parse_telemetry_config(dict: &Bound<'_, PyDict>) -> PyResult<(TelemetryConfig
 #[pyo3(signature = (config=None, scenario=None))]
 pub fn telemetry(
     py: Python<'_>,
-    config: Option<&Bound<'_, PyDict>>,
+    config: Option<&Bound<'_, PyAny>>,
     scenario: Option<&str>,
 ) -> PyResult<Py<PyAny>> {
     // Start with preset scenario if specified
@@ -870,7 +893,8 @@ pub fn telemetry(
     let mut output_format = "pandas".to_string();
 
     // Override with config if provided
-    if let Some(dict) = config {
+    if let Some(cfg) = config {
+        let dict = &config_dict(cfg, "a CrossfilterConfig, dict, or None")?;
         let (parsed_config, out) = parse_telemetry_config(dict)?;
         // Merge: config overrides scenario defaults only for explicitly set values
         if dict.get_item("machine_count")?.is_some() {
@@ -909,7 +933,7 @@ pub fn telemetry(
         output_format = out;
     }
 
-    let readings = generate_telemetry(&telemetry_config);
+    let readings = py.detach(|| generate_telemetry(&telemetry_config));
 
     match output_format.to_lowercase().as_str() {
         "polars" => create_telemetry_polars(py, &readings),
@@ -920,7 +944,9 @@ pub fn telemetry(
             }
             Ok(list.into())
         }
-        _ => create_telemetry_pandas(py, &readings),
+        "arrow" => create_telemetry_arrow(py, &readings),
+        "pandas" => create_telemetry_pandas(py, &readings),
+        _ => Err(invalid_output(&output_format, TABLE_OUTPUTS)),
     }
 }
 
@@ -3,8 +3,7 @@
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 
-use rand::rngs::StdRng;
-use rand::SeedableRng;
+use superstore::rng::seeded_rng;
 
 use superstore::distributions::{DistributionType, NoiseModel};
 
@@ -27,10 +26,7 @@ pub fn py_sample_uniform(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::uniform(min, max);
 
     if n == 1 {
@@ -60,10 +56,7 @@ pub fn py_sample_normal(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::normal(mean, std_dev);
 
     if n == 1 {
@@ -93,10 +86,7 @@ pub fn py_sample_log_normal(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::log_normal(mu, sigma);
 
     if n == 1 {
@@ -124,10 +114,7 @@ pub fn py_sample_exponential(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::exponential(lambda_);
 
     if n == 1 {
@@ -155,10 +142,7 @@ pub fn py_sample_poisson(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::poisson(lambda_);
 
     if n == 1 {
@@ -195,10 +179,7 @@ pub fn py_sample_pareto(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::pareto(scale, shape);
 
     if n == 1 {
@@ -228,10 +209,7 @@ pub fn py_sample_beta(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::beta(alpha, beta);
 
     if n == 1 {
@@ -261,10 +239,7 @@ pub fn py_sample_gamma(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::gamma(shape, scale);
 
     if n == 1 {
@@ -294,10 +269,7 @@ pub fn py_sample_weibull(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::weibull(shape, scale);
 
     if n == 1 {
@@ -325,10 +297,7 @@ pub fn py_sample_categorical(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let dist = DistributionType::categorical(weights);
 
     if n == 1 {
@@ -371,10 +340,7 @@ pub fn py_sample_mixture(
     n: usize,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
 
     let distributions: Vec<DistributionType> = means
         .iter()
@@ -409,10 +375,7 @@ pub fn py_add_gaussian_noise(
     std_dev: f64,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let noise = NoiseModel::Gaussian { std_dev };
 
     let noisy: Vec<f64> = values
@@ -440,10 +403,7 @@ pub fn py_apply_missing(
     probability: f64,
     seed: Option<u64>,
 ) -> PyResult<Py<PyAny>> {
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    };
+    let mut rng = seeded_rng(seed);
     let noise = NoiseModel::MissingAtRandom { probability };
 
     let list = PyList::empty(py);
@@ -1,20 +1,27 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
+use chrono::{Duration, NaiveDate};
+use pyo3::exceptions::{PyUserWarning, PyValueError};
+use std::collections::HashMap;
+use std::ffi::CString;
 use superstore::ecommerce::{
-    ecommerce, generate_cart_events, generate_catalog, generate_customers, generate_funnel_events,
-    generate_orders, generate_sessions, CartConfig, CartEvent, CatalogConfig, Customer,
-    EcommerceConfig, EcommerceData, FunnelConfig, FunnelEvent, Order, Product, RfmConfig, Session,
-    SessionConfig,
+    cohort_retention as build_cohort_retention, ecommerce, generate_cart_events, generate_catalog,
+    generate_customers, generate_funnel_events, generate_interactions, generate_orders,
+    generate_sessions, interaction_edgelist, ActivitySpan, CartConfig, CartEvent, CatalogConfig,
+    CohortPeriod, Customer, EcommerceConfig, EcommerceData, FunnelConfig, FunnelEvent,
+    InteractionConfig, MinOrderPolicy, Order, Product, RfmConfig, Session, SessionConfig,
 };
 
 // =============================================================================
 // Helper Functions for creating DataFrames
 // =============================================================================
 
-/// Create pandas DataFrame from Session rows
-fn create_sessions_pandas(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for Session rows, shared by the pandas and Arrow outputs
+fn sessions_columns<'py>(py: Python<'py>, rows: &[Session]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let session_ids: Vec<&str> = rows.iter().map(|r| r.session_id.as_str()).collect();
@@ -30,6 +37,7 @@ fn create_sessions_pandas(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny
     let bounced: Vec<bool> = rows.iter().map(|r| r.bounced).collect();
     let converted: Vec<bool> = rows.iter().map(|r| r.converted).collect();
     let values: Vec<f64> = rows.iter().map(|r| r.total_value).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
 
     data.set_item("session_id", PyList::new(py, &session_ids)?)?;
     data.set_item("user_id", PyList::new(py, &user_ids)?)?;
@@ -44,14 +52,50 @@ fn create_sessions_pandas(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny
     data.set_item("bounced", PyList::new(py, &bounced)?)?;
     data.set_item("converted", PyList::new(py, &converted)?)?;
     data.set_item("total_value", PyList::new(py, &values)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
+
+    Ok(data)
+}
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+/// Create pandas DataFrame from Session rows
+fn create_sessions_pandas(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (sessions_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from Session rows
+fn create_sessions_arrow(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("session_id", rows.iter().map(|r| r.session_id.as_str()))
+        .utf8("user_id", rows.iter().map(|r| r.user_id.as_str()))
+        .utf8("start_time", rows.iter().map(|r| r.start_time.as_str()))
+        .utf8("end_time", rows.iter().map(|r| r.end_time.as_str()))
+        .int64(
+            "duration_seconds",
+            rows.iter().map(|r| i64::from(r.duration_seconds)),
+        )
+        .utf8("device_type", rows.iter().map(|r| r.device_type.as_str()))
+        .utf8("browser", rows.iter().map(|r| r.browser.as_str()))
+        .utf8(
+            "traffic_source",
+            rows.iter().map(|r| r.traffic_source.as_str()),
+        )
+        .utf8("landing_page", rows.iter().map(|r| r.landing_page.as_str()))
+        .int64(
+            "pages_viewed",
+            rows.iter().map(|r| i64::from(r.pages_viewed)),
+        )
+        .boolean("bounced", rows.iter().map(|r| r.bounced))
+        .boolean("converted", rows.iter().map(|r| r.converted))
+        .float64("total_value", rows.iter().map(|r| r.total_value))
+        .utf8("currency", rows.iter().map(|r| r.currency.as_str()))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from Session rows
 fn create_sessions_polars(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let session_ids: Vec<&str> = rows.iter().map(|r| r.session_id.as_str()).collect();
@@ -67,6 +111,7 @@ fn create_sessions_polars(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny
     let bounced: Vec<bool> = rows.iter().map(|r| r.bounced).collect();
     let converted: Vec<bool> = rows.iter().map(|r| r.converted).collect();
     let values: Vec<f64> = rows.iter().map(|r| r.total_value).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
 
     data.set_item("session_id", PyList::new(py, &session_ids)?)?;
     data.set_item("user_id", PyList::new(py, &user_ids)?)?;
@@ -81,6 +126,7 @@ fn create_sessions_polars(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny
     data.set_item("bounced", PyList::new(py, &bounced)?)?;
     data.set_item("converted", PyList::new(py, &converted)?)?;
     data.set_item("total_value", PyList::new(py, &values)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
 
     let df = polars.call_method1("DataFrame", (data,))?;
     Ok(df.into())
@@ -103,6 +149,7 @@ fn create_sessions_dict(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny>>
     let bounced: Vec<bool> = rows.iter().map(|r| r.bounced).collect();
     let converted: Vec<bool> = rows.iter().map(|r| r.converted).collect();
     let values: Vec<f64> = rows.iter().map(|r| r.total_value).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
 
     data.set_item("session_id", PyList::new(py, &session_ids)?)?;
     data.set_item("user_id", PyList::new(py, &user_ids)?)?;
@@ -117,56 +164,112 @@ fn create_sessions_dict(py: Python<'_>, rows: &[Session]) -> PyResult<Py<PyAny>>
     data.set_item("bounced", PyList::new(py, &bounced)?)?;
     data.set_item("converted", PyList::new(py, &converted)?)?;
     data.set_item("total_value", PyList::new(py, &values)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
 
     Ok(data.into())
 }
 
-/// Create pandas DataFrame from Product rows
-fn create_products_pandas(py: Python<'_>, rows: &[Product]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for Product rows, shared by the pandas and Arrow outputs
+fn products_columns<'py>(py: Python<'py>, rows: &[Product]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
+    let parents: Vec<&str> = rows.iter().map(|r| r.parent_product_id.as_str()).collect();
+    let skus: Vec<&str> = rows.iter().map(|r| r.sku.as_str()).collect();
     let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
     let categories: Vec<&str> = rows.iter().map(|r| r.category.as_str()).collect();
     let subcategories: Vec<&str> = rows.iter().map(|r| r.subcategory.as_str()).collect();
+    let brands: Vec<&str> = rows.iter().map(|r| r.brand.as_str()).collect();
+    let sizes: Vec<Option<&str>> = rows.iter().map(|r| r.size.as_deref()).collect();
+    let colors: Vec<Option<&str>> = rows.iter().map(|r| r.color.as_deref()).collect();
     let prices: Vec<f64> = rows.iter().map(|r| r.price).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
     let ratings: Vec<f64> = rows.iter().map(|r| r.rating).collect();
     let reviews: Vec<u32> = rows.iter().map(|r| r.review_count).collect();
     let in_stock: Vec<bool> = rows.iter().map(|r| r.in_stock).collect();
 
     data.set_item("product_id", PyList::new(py, &ids)?)?;
+    data.set_item("parent_product_id", PyList::new(py, &parents)?)?;
+    data.set_item("sku", PyList::new(py, &skus)?)?;
     data.set_item("name", PyList::new(py, &names)?)?;
     data.set_item("category", PyList::new(py, &categories)?)?;
     data.set_item("subcategory", PyList::new(py, &subcategories)?)?;
+    data.set_item("brand", PyList::new(py, &brands)?)?;
+    data.set_item("size", PyList::new(py, &sizes)?)?;
+    data.set_item("color", PyList::new(py, &colors)?)?;
     data.set_item("price", PyList::new(py, &prices)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
     data.set_item("rating", PyList::new(py, &ratings)?)?;
     data.set_item("review_count", PyList::new(py, &reviews)?)?;
     data.set_item("in_stock", PyList::new(py, &in_stock)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from Product rows
+fn create_products_pandas(py: Python<'_>, rows: &[Product]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (products_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from Product rows
+fn create_products_arrow(py: Python<'_>, rows: &[Product]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("product_id", rows.iter().map(|r| r.product_id.as_str()))
+        .utf8(
+            "parent_product_id",
+            rows.iter().map(|r| r.parent_product_id.as_str()),
+        )
+        .utf8("sku", rows.iter().map(|r| r.sku.as_str()))
+        .utf8("name", rows.iter().map(|r| r.name.as_str()))
+        .utf8("category", rows.iter().map(|r| r.category.as_str()))
+        .utf8("subcategory", rows.iter().map(|r| r.subcategory.as_str()))
+        .utf8("brand", rows.iter().map(|r| r.brand.as_str()))
+        .optional_utf8("size", rows.iter().map(|r| r.size.as_deref()))
+        .optional_utf8("color", rows.iter().map(|r| r.color.as_deref()))
+        .float64("price", rows.iter().map(|r| r.price))
+        .utf8("currency", rows.iter().map(|r| r.currency.as_str()))
+        .float64("rating", rows.iter().map(|r| r.rating))
+        .int64(
+            "review_count",
+            rows.iter().map(|r| i64::from(r.review_count)),
+        )
+        .boolean("in_stock", rows.iter().map(|r| r.in_stock))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from Product rows
 fn create_products_polars(py: Python<'_>, rows: &[Product]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
+    let parents: Vec<&str> = rows.iter().map(|r| r.parent_product_id.as_str()).collect();
+    let skus: Vec<&str> = rows.iter().map(|r| r.sku.as_str()).collect();
     let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
     let categories: Vec<&str> = rows.iter().map(|r| r.category.as_str()).collect();
     let subcategories: Vec<&str> = rows.iter().map(|r| r.subcategory.as_str()).collect();
+    let brands: Vec<&str> = rows.iter().map(|r| r.brand.as_str()).collect();
+    let sizes: Vec<Option<&str>> = rows.iter().map(|r| r.size.as_deref()).collect();
+    let colors: Vec<Option<&str>> = rows.iter().map(|r| r.color.as_deref()).collect();
     let prices: Vec<f64> = rows.iter().map(|r| r.price).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
     let ratings: Vec<f64> = rows.iter().map(|r| r.rating).collect();
     let reviews: Vec<u32> = rows.iter().map(|r| r.review_count).collect();
     let in_stock: Vec<bool> = rows.iter().map(|r| r.in_stock).collect();
 
     data.set_item("product_id", PyList::new(py, &ids)?)?;
+    data.set_item("parent_product_id", PyList::new(py, &parents)?)?;
+    data.set_item("sku", PyList::new(py, &skus)?)?;
     data.set_item("name", PyList::new(py, &names)?)?;
     data.set_item("category", PyList::new(py, &categories)?)?;
     data.set_item("subcategory", PyList::new(py, &subcategories)?)?;
+    data.set_item("brand", PyList::new(py, &brands)?)?;
+    data.set_item("size", PyList::new(py, &sizes)?)?;
+    data.set_item("color", PyList::new(py, &colors)?)?;
     data.set_item("price", PyList::new(py, &prices)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
     data.set_item("rating", PyList::new(py, &ratings)?)?;
     data.set_item("review_count", PyList::new(py, &reviews)?)?;
     data.set_item("in_stock", PyList::new(py, &in_stock)?)?;
@@ -180,19 +283,31 @@ fn create_products_dict(py: Python<'_>, rows: &[Product]) -> PyResult<Py<PyAny>>
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
+    let parents: Vec<&str> = rows.iter().map(|r| r.parent_product_id.as_str()).collect();
+    let skus: Vec<&str> = rows.iter().map(|r| r.sku.as_str()).collect();
     let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
     let categories: Vec<&str> = rows.iter().map(|r| r.category.as_str()).collect();
     let subcategories: Vec<&str> = rows.iter().map(|r| r.subcategory.as_str()).collect();
+    let brands: Vec<&str> = rows.iter().map(|r| r.brand.as_str()).collect();
+    let sizes: Vec<Option<&str>> = rows.iter().map(|r| r.size.as_deref()).collect();
+    let colors: Vec<Option<&str>> = rows.iter().map(|r| r.color.as_deref()).collect();
     let prices: Vec<f64> = rows.iter().map(|r| r.price).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
     let ratings: Vec<f64> = rows.iter().map(|r| r.rating).collect();
     let reviews: Vec<u32> = rows.iter().map(|r| r.review_count).collect();
     let in_stock: Vec<bool> = rows.iter().map(|r| r.in_stock).collect();
 
     data.set_item("product_id", PyList::new(py, &ids)?)?;
+    data.set_item("parent_product_id", PyList::new(py, &parents)?)?;
+    data.set_item("sku", PyList::new(py, &skus)?)?;
     data.set_item("name", PyList::new(py, &names)?)?;
     data.set_item("category", PyList::new(py, &categories)?)?;
     data.set_item("subcategory", PyList::new(py, &subcategories)?)?;
+    data.set_item("brand", PyList::new(py, &brands)?)?;
+    data.set_item("size", PyList::new(py, &sizes)?)?;
+    data.set_item("color", PyList::new(py, &colors)?)?;
     data.set_item("price", PyList::new(py, &prices)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
     data.set_item("rating", PyList::new(py, &ratings)?)?;
     data.set_item("review_count", PyList::new(py, &reviews)?)?;
     data.set_item("in_stock", PyList::new(py, &in_stock)?)?;
@@ -200,9 +315,8 @@ fn create_products_dict(py: Python<'_>, rows: &[Product]) -> PyResult<Py<PyAny>>
     Ok(data.into())
 }
 
-/// Create pandas DataFrame from Order rows
-fn create_orders_pandas(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for Order rows, shared by the pandas and Arrow outputs
+fn orders_columns<'py>(py: Python<'py>, rows: &[Order]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.order_id.as_str()).collect();
@@ -215,6 +329,7 @@ fn create_orders_pandas(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
     let taxes: Vec<f64> = rows.iter().map(|r| r.tax).collect();
     let shippings: Vec<f64> = rows.iter().map(|r| r.shipping).collect();
     let totals: Vec<f64> = rows.iter().map(|r| r.total).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
     let payments: Vec<&str> = rows.iter().map(|r| r.payment_method.as_str()).collect();
     let statuses: Vec<&str> = rows.iter().map(|r| r.status.as_str()).collect();
 
@@ -228,16 +343,45 @@ fn create_orders_pandas(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
     data.set_item("tax", PyList::new(py, &taxes)?)?;
     data.set_item("shipping", PyList::new(py, &shippings)?)?;
     data.set_item("total", PyList::new(py, &totals)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
     data.set_item("payment_method", PyList::new(py, &payments)?)?;
     data.set_item("status", PyList::new(py, &statuses)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from Order rows
+fn create_orders_pandas(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (orders_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from Order rows
+fn create_orders_arrow(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("order_id", rows.iter().map(|r| r.order_id.as_str()))
+        .utf8("user_id", rows.iter().map(|r| r.user_id.as_str()))
+        .utf8("session_id", rows.iter().map(|r| r.session_id.as_str()))
+        .utf8("order_time", rows.iter().map(|r| r.order_time.as_str()))
+        .int64("total_items", rows.iter().map(|r| i64::from(r.total_items)))
+        .float64("subtotal", rows.iter().map(|r| r.subtotal))
+        .float64("discount", rows.iter().map(|r| r.discount))
+        .float64("tax", rows.iter().map(|r| r.tax))
+        .float64("shipping", rows.iter().map(|r| r.shipping))
+        .float64("total", rows.iter().map(|r| r.total))
+        .utf8("currency", rows.iter().map(|r| r.currency.as_str()))
+        .utf8(
+            "payment_method",
+            rows.iter().map(|r| r.payment_method.as_str()),
+        )
+        .utf8("status", rows.iter().map(|r| r.status.as_str()))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from Order rows
 fn create_orders_polars(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.order_id.as_str()).collect();
@@ -250,6 +394,7 @@ fn create_orders_polars(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
     let taxes: Vec<f64> = rows.iter().map(|r| r.tax).collect();
     let shippings: Vec<f64> = rows.iter().map(|r| r.shipping).collect();
     let totals: Vec<f64> = rows.iter().map(|r| r.total).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
     let payments: Vec<&str> = rows.iter().map(|r| r.payment_method.as_str()).collect();
     let statuses: Vec<&str> = rows.iter().map(|r| r.status.as_str()).collect();
 
@@ -263,6 +408,7 @@ fn create_orders_polars(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
     data.set_item("tax", PyList::new(py, &taxes)?)?;
     data.set_item("shipping", PyList::new(py, &shippings)?)?;
     data.set_item("total", PyList::new(py, &totals)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
     data.set_item("payment_method", PyList::new(py, &payments)?)?;
     data.set_item("status", PyList::new(py, &statuses)?)?;
 
@@ -284,6 +430,7 @@ fn create_orders_dict(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
     let taxes: Vec<f64> = rows.iter().map(|r| r.tax).collect();
     let shippings: Vec<f64> = rows.iter().map(|r| r.shipping).collect();
     let totals: Vec<f64> = rows.iter().map(|r| r.total).collect();
+    let currencies: Vec<&str> = rows.iter().map(|r| r.currency.as_str()).collect();
     let payments: Vec<&str> = rows.iter().map(|r| r.payment_method.as_str()).collect();
     let statuses: Vec<&str> = rows.iter().map(|r| r.status.as_str()).collect();
 
@@ -297,15 +444,15 @@ fn create_orders_dict(py: Python<'_>, rows: &[Order]) -> PyResult<Py<PyAny>> {
     data.set_item("tax", PyList::new(py, &taxes)?)?;
     data.set_item("shipping", PyList::new(py, &shippings)?)?;
     data.set_item("total", PyList::new(py, &totals)?)?;
+    data.set_item("currency", PyList::new(py, &currencies)?)?;
     data.set_item("payment_method", PyList::new(py, &payments)?)?;
     data.set_item("status", PyList::new(py, &statuses)?)?;
 
     Ok(data.into())
 }
 
-/// Create pandas DataFrame from Customer rows
-fn create_customers_pandas(py: Python<'_>, rows: &[Customer]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for Customer rows, shared by the pandas and Arrow outputs
+fn customers_columns<'py>(py: Python<'py>, rows: &[Customer]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.customer_id.as_str()).collect();
@@ -336,13 +483,49 @@ fn create_customers_pandas(py: Python<'_>, rows: &[Customer]) -> PyResult<Py<PyA
     data.set_item("rfm_score", PyList::new(py, &scores)?)?;
     data.set_item("rfm_segment", PyList::new(py, &segments)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from Customer rows
+fn create_customers_pandas(py: Python<'_>, rows: &[Customer]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (customers_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from Customer rows
+fn create_customers_arrow(py: Python<'_>, rows: &[Customer]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("customer_id", rows.iter().map(|r| r.customer_id.as_str()))
+        .utf8("email", rows.iter().map(|r| r.email.as_str()))
+        .optional_utf8(
+            "first_order_date",
+            rows.iter().map(|r| r.first_order_date.as_deref()),
+        )
+        .optional_utf8(
+            "last_order_date",
+            rows.iter().map(|r| r.last_order_date.as_deref()),
+        )
+        .int64(
+            "total_orders",
+            rows.iter().map(|r| i64::from(r.total_orders)),
+        )
+        .float64("total_spent", rows.iter().map(|r| r.total_spent))
+        .float64("avg_order_value", rows.iter().map(|r| r.avg_order_value))
+        .int64("rfm_recency", rows.iter().map(|r| i64::from(r.rfm_recency)))
+        .int64(
+            "rfm_frequency",
+            rows.iter().map(|r| i64::from(r.rfm_frequency)),
+        )
+        .float64("rfm_monetary", rows.iter().map(|r| r.rfm_monetary))
+        .utf8("rfm_score", rows.iter().map(|r| r.rfm_score.as_str()))
+        .utf8("rfm_segment", rows.iter().map(|r| r.rfm_segment.as_str()))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from Customer rows
 fn create_customers_polars(py: Python<'_>, rows: &[Customer]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.customer_id.as_str()).collect();
@@ -412,9 +595,8 @@ fn create_customers_dict(py: Python<'_>, rows: &[Customer]) -> PyResult<Py<PyAny
     Ok(data.into())
 }
 
-/// Create pandas DataFrame from CartEvent rows
-fn create_cart_events_pandas(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for CartEvent rows, shared by the pandas and Arrow outputs
+fn cart_events_columns<'py>(py: Python<'py>, rows: &[CartEvent]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.event_id.as_str()).collect();
@@ -422,7 +604,7 @@ fn create_cart_events_pandas(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<
     let user_ids: Vec<&str> = rows.iter().map(|r| r.user_id.as_str()).collect();
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
     let types: Vec<&str> = rows.iter().map(|r| r.event_type.as_str()).collect();
-    let product_ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
+    let product_ids: Vec<Option<&str>> = rows.iter().map(|r| r.product_id.as_deref()).collect();
     let quantities: Vec<u32> = rows.iter().map(|r| r.quantity).collect();
     let unit_prices: Vec<f64> = rows.iter().map(|r| r.unit_price).collect();
     let total_prices: Vec<f64> = rows.iter().map(|r| r.total_price).collect();
@@ -437,13 +619,34 @@ fn create_cart_events_pandas(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<
     data.set_item("unit_price", PyList::new(py, &unit_prices)?)?;
     data.set_item("total_price", PyList::new(py, &total_prices)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from CartEvent rows
+fn create_cart_events_pandas(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (cart_events_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from CartEvent rows
+fn create_cart_events_arrow(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("event_id", rows.iter().map(|r| r.event_id.as_str()))
+        .utf8("session_id", rows.iter().map(|r| r.session_id.as_str()))
+        .utf8("user_id", rows.iter().map(|r| r.user_id.as_str()))
+        .utf8("timestamp", rows.iter().map(|r| r.timestamp.as_str()))
+        .utf8("event_type", rows.iter().map(|r| r.event_type.as_str()))
+        .optional_utf8("product_id", rows.iter().map(|r| r.product_id.as_deref()))
+        .int64("quantity", rows.iter().map(|r| i64::from(r.quantity)))
+        .float64("unit_price", rows.iter().map(|r| r.unit_price))
+        .float64("total_price", rows.iter().map(|r| r.total_price))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from CartEvent rows
 fn create_cart_events_polars(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let ids: Vec<&str> = rows.iter().map(|r| r.event_id.as_str()).collect();
@@ -451,7 +654,7 @@ fn create_cart_events_polars(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<
     let user_ids: Vec<&str> = rows.iter().map(|r| r.user_id.as_str()).collect();
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
     let types: Vec<&str> = rows.iter().map(|r| r.event_type.as_str()).collect();
-    let product_ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
+    let product_ids: Vec<Option<&str>> = rows.iter().map(|r| r.product_id.as_deref()).collect();
     let quantities: Vec<u32> = rows.iter().map(|r| r.quantity).collect();
     let unit_prices: Vec<f64> = rows.iter().map(|r| r.unit_price).collect();
     let total_prices: Vec<f64> = rows.iter().map(|r| r.total_price).collect();
@@ -479,7 +682,7 @@ fn create_cart_events_dict(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<Py
     let user_ids: Vec<&str> = rows.iter().map(|r| r.user_id.as_str()).collect();
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
     let types: Vec<&str> = rows.iter().map(|r| r.event_type.as_str()).collect();
-    let product_ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
+    let product_ids: Vec<Option<&str>> = rows.iter().map(|r| r.product_id.as_deref()).collect();
     let quantities: Vec<u32> = rows.iter().map(|r| r.quantity).collect();
     let unit_prices: Vec<f64> = rows.iter().map(|r| r.unit_price).collect();
     let total_prices: Vec<f64> = rows.iter().map(|r| r.total_price).collect();
@@ -497,11 +700,102 @@ fn create_cart_events_dict(py: Python<'_>, rows: &[CartEvent]) -> PyResult<Py<Py
     Ok(data.into())
 }
 
+// =============================================================================
+// Cohort Retention Helpers
+// =============================================================================
+
+/// Read a date-like column from a dict, pandas or polars frame.
+///
+/// Values are parsed from their leading `YYYY-MM-DD`, so strings, dates and
+/// timestamps all work; missing or unparseable values become None.
+fn extract_dates(frame: &Bound<'_, PyAny>, name: &str) -> PyResult<Vec<Option<NaiveDate>>> {
+    let values: Vec<Bound<'_, PyAny>> = frame
+        .py()
+        .import("builtins")?
+        .getattr("list")?
+        .call1((frame.get_item(name)?,))?
+        .extract()?;
+    Ok(values
+        .iter()
+        .map(|v| {
+            if v.is_none() {
+                return None;
+            }
+            let text = v.str().ok()?.to_string();
+            NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()
+        })
+        .collect())
+}
+
+/// Read an ID column from a dict, pandas or polars frame as strings
+fn extract_ids(frame: &Bound<'_, PyAny>, name: &str) -> PyResult<Vec<String>> {
+    let values: Vec<Bound<'_, PyAny>> = frame
+        .py()
+        .import("builtins")?
+        .getattr("list")?
+        .call1((frame.get_item(name)?,))?
+        .extract()?;
+    values.iter().map(|v| Ok(v.str()?.to_string())).collect()
+}
+
+/// Build activity spans from a customers, subscriptions, orders or billing
+/// events table, detected from its columns.
+fn activity_spans(frame: &Bound<'_, PyAny>) -> PyResult<Vec<ActivitySpan>> {
+    let has = |name: &str| frame.contains(name).unwrap_or(false);
+
+    // (customer column, start column, end column, end is exclusive)
+    let (id_col, start_col, end_col, exclusive) = if has("first_order_date") {
+        (
+            "customer_id",
+            "first_order_date",
+            Some("last_order_date"),
+            false,
+        )
+    } else if has("cancel_date") {
+        ("customer_id", "start_date", Some("cancel_date"), true)
+    } else if has("order_time") {
+        ("user_id", "order_time", None, false)
+    } else if has("billing_date") {
+        ("subscription_id", "billing_date", None, false)
+    } else {
+        return Err(PyValueError::new_err(
+            "cohort_retention expects customers, orders, subscriptions or billing_events data",
+        ));
+    };
+
+    let ids = extract_ids(frame, id_col)?;
+    let starts = extract_dates(frame, start_col)?;
+    let ends = match end_col {
+        Some(col) => extract_dates(frame, col)?,
+        None => starts.clone(),
+    };
+
+    Ok(ids
+        .into_iter()
+        .zip(starts)
+        .zip(ends)
+        .filter_map(|((customer_id, start), end)| {
+            let start = start?;
+            // A subscription is no longer active on its cancel date
+            let end = if exclusive {
+                end.map(|e| (e - Duration::days(1)).max(start))
+            } else {
+                end
+            };
+            Some(ActivitySpan {
+                customer_id,
+                start,
+                end,
+            })
+        })
+        .collect())
+}
+
 // =============================================================================
 // Config Parsing
 // =============================================================================
 
-fn parse_session_config(dict: &Bound<'_, PyDict>) -> SessionConfig {
+fn parse_session_config(dict: &Bound<'_, PyDict>) -> PyResult<SessionConfig> {
     let mut config = SessionConfig::default();
     if let Some(v) = dict.get_item("avg_pages_per_session").ok().flatten() {
         config.avg_pages_per_session = v.extract().unwrap_or(config.avg_pages_per_session);
@@ -532,7 +826,32 @@ fn parse_session_config(dict: &Bound<'_, PyDict>) -> SessionConfig {
     if let Some(v) = dict.get_item("bounce_rate").ok().flatten() {
         config.bounce_rate = v.extract().unwrap_or(config.bounce_rate);
     }
+    if let Some(v) = dict.get_item("custom_states")? {
+        config.custom_states = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("custom_transition_matrix")? {
+        config.custom_transition_matrix = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("purchase_state").ok().flatten() {
+        config.purchase_state = v.extract().unwrap_or(config.purchase_state);
+    }
+    if let Some(v) = dict.get_item("exit_state").ok().flatten() {
+        config.exit_state = v.extract().unwrap_or(config.exit_state);
+    }
+    if let Some(v) = dict.get_item("target_conversion_rate")? {
+        config.target_conversion_rate = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("landing_page_weights")? {
+        config.landing_page_weights = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("landing_page_conversion")? {
+        config.landing_page_conversion = v.extract()?;
+    }
+    // Reject malformed custom chains here rather than panicking mid-generation
     config
+        .landing_chains()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(config)
 }
 
 fn parse_cart_config(dict: &Bound<'_, PyDict>) -> CartConfig {
@@ -576,6 +895,18 @@ fn parse_catalog_config(dict: &Bound<'_, PyDict>) -> CatalogConfig {
     if let Some(v) = dict.get_item("categories").ok().flatten() {
         config.categories = v.extract().unwrap_or(config.categories);
     }
+    if let Some(v) = dict.get_item("brands").ok().flatten() {
+        // Overrides the built-in pools per category
+        if let Ok(brands) = v.extract::<HashMap<String, Vec<String>>>() {
+            config.brands.extend(brands);
+        }
+    }
+    if let Some(v) = dict.get_item("brand_zipf_exponent").ok().flatten() {
+        config.brand_zipf_exponent = v.extract().unwrap_or(config.brand_zipf_exponent);
+    }
+    if let Some(v) = dict.get_item("variants_per_product").ok().flatten() {
+        config.variants_per_product = v.extract().unwrap_or(config.variants_per_product);
+    }
     config
 }
 
@@ -613,7 +944,15 @@ fn parse_funnel_config(dict: &Bound<'_, PyDict>) -> FunnelConfig {
     config
 }
 
-fn parse_ecommerce_config(dict: &Bound<'_, PyDict>) -> EcommerceConfig {
+fn parse_interaction_config(dict: &Bound<'_, PyDict>) -> InteractionConfig {
+    let mut config = InteractionConfig::default();
+    if let Some(v) = dict.get_item("popularity_exponent").ok().flatten() {
+        config.popularity_exponent = v.extract().unwrap_or(config.popularity_exponent);
+    }
+    config
+}
+
+fn parse_ecommerce_config(dict: &Bound<'_, PyDict>) -> PyResult<EcommerceConfig> {
     let mut config = EcommerceConfig::default();
 
     if let Some(v) = dict.get_item("sessions").ok().flatten() {
@@ -633,7 +972,7 @@ fn parse_ecommerce_config(dict: &Bound<'_, PyDict>) -> EcommerceConfig {
     }
     if let Some(v) = dict.get_item("session").ok().flatten() {
         if let Ok(d) = v.downcast::<PyDict>() {
-            config.session = parse_session_config(d);
+            config.session = parse_session_config(d)?;
         }
     }
     if let Some(v) = dict.get_item("cart").ok().flatten() {
@@ -656,8 +995,40 @@ fn parse_ecommerce_config(dict: &Bound<'_, PyDict>) -> EcommerceConfig {
             config.funnel = parse_funnel_config(d);
         }
     }
+    if let Some(v) = dict.get_item("interactions").ok().flatten() {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.interactions = parse_interaction_config(d);
+        }
+    }
+    if let Some(v) = dict.get_item("locale")? {
+        config.locale = v.extract()?;
+        if let Some(message) = config.locale_warning() {
+            let message = CString::new(message).expect("warning message has no NUL bytes");
+            PyErr::warn(
+                dict.py(),
+                &dict.py().get_type::<PyUserWarning>(),
+                &message,
+                1,
+            )?;
+        }
+    }
+    if let Some(v) = dict.get_item("min_order_value")? {
+        config.min_order_value = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("min_order_policy")? {
+        config.min_order_policy = match v.extract::<String>()?.as_str() {
+            "bump" => MinOrderPolicy::Bump,
+            "drop" => MinOrderPolicy::Drop,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid min_order_policy '{}'. Must be 'bump' or 'drop'",
+                    other
+                )))
+            }
+        };
+    }
 
-    config
+    Ok(config)
 }
 
 // =============================================================================
@@ -669,7 +1040,7 @@ fn parse_ecommerce_config(dict: &Bound<'_, PyDict>) -> EcommerceConfig {
 /// Args:
 ///     count: Number of sessions to generate
 ///     seed: Optional random seed for reproducibility
-///     output: Output format ("pandas", "polars", or "dict")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
 ///
 /// Returns:
 ///     DataFrame or dict with session data
@@ -686,12 +1057,14 @@ pub fn ecommerce_sessions(
         seed,
         ..Default::default()
     };
-    let sessions = generate_sessions(&config);
+    let sessions = py.detach(|| generate_sessions(&config));
 
     match output {
         "polars" => create_sessions_polars(py, &sessions),
         "dict" => create_sessions_dict(py, &sessions),
-        _ => create_sessions_pandas(py, &sessions),
+        "arrow" => create_sessions_arrow(py, &sessions),
+        "pandas" => create_sessions_pandas(py, &sessions),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 }
 
@@ -700,7 +1073,7 @@ pub fn ecommerce_sessions(
 /// Args:
 ///     count: Number of products to generate
 ///     seed: Optional random seed for reproducibility
-///     output: Output format ("pandas", "polars", or "dict")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
 ///
 /// Returns:
 ///     DataFrame or dict with product data
@@ -720,12 +1093,14 @@ pub fn ecommerce_products(
         },
         ..Default::default()
     };
-    let products = generate_catalog(&config);
+    let products = py.detach(|| generate_catalog(&config));
 
     match output {
         "polars" => create_products_polars(py, &products),
         "dict" => create_products_dict(py, &products),
-        _ => create_products_pandas(py, &products),
+        "arrow" => create_products_arrow(py, &products),
+        "pandas" => create_products_pandas(py, &products),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 }
 
@@ -733,7 +1108,7 @@ pub fn ecommerce_products(
 ///
 /// Args:
 ///     config: EcommerceConfig dict with generation parameters
-///     output: Output format ("pandas", "polars", or "dict")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
 ///
 /// Returns:
 ///     Dict with DataFrames for products, sessions, cart_events, orders, customers
@@ -741,15 +1116,15 @@ pub fn ecommerce_products(
 #[pyo3(signature = (config = None, output = "pandas"))]
 pub fn ecommerce_data(
     py: Python<'_>,
-    config: Option<&Bound<'_, PyDict>>,
+    config: Option<&Bound<'_, PyAny>>,
     output: &str,
 ) -> PyResult<Py<PyAny>> {
     let cfg = match config {
-        Some(d) => parse_ecommerce_config(d),
+        Some(d) => parse_ecommerce_config(&config_dict(d, "an EcommerceConfig, dict, or None")?)?,
         None => EcommerceConfig::default(),
     };
 
-    let data = ecommerce(&cfg);
+    let data = py.detach(|| ecommerce(&cfg));
     let result = PyDict::new(py);
 
     match output {
@@ -773,7 +1148,17 @@ pub fn ecommerce_data(
             result.set_item("orders", create_orders_dict(py, &data.orders)?)?;
             result.set_item("customers", create_customers_dict(py, &data.customers)?)?;
         }
-        _ => {
+        "arrow" => {
+            result.set_item("products", create_products_arrow(py, &data.products)?)?;
+            result.set_item("sessions", create_sessions_arrow(py, &data.sessions)?)?;
+            result.set_item(
+                "cart_events",
+                create_cart_events_arrow(py, &data.cart_events)?,
+            )?;
+            result.set_item("orders", create_orders_arrow(py, &data.orders)?)?;
+            result.set_item("customers", create_customers_arrow(py, &data.customers)?)?;
+        }
+        "pandas" => {
             result.set_item("products", create_products_pandas(py, &data.products)?)?;
             result.set_item("sessions", create_sessions_pandas(py, &data.sessions)?)?;
             result.set_item(
@@ -783,15 +1168,151 @@ pub fn ecommerce_data(
             result.set_item("orders", create_orders_pandas(py, &data.orders)?)?;
             result.set_item("customers", create_customers_pandas(py, &data.customers)?)?;
         }
+        _ => return Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 
     Ok(result.into())
 }
 
+/// Compute a cohort retention matrix
+///
+/// Customers are grouped by the period of their first activity, and each
+/// row gives the share of that cohort still active 0, 1, 2, ... periods
+/// later. Period 0 is always 1.0; later cohorts have fewer periods, so the
+/// matrix is triangular (missing cells are None).
+///
+/// Args:
+///     data: Customers, orders, subscriptions or billing_events table from
+///         ecommerce_data() or subscriptions() (DataFrame or dict of columns)
+///     period: Cohort period ("day", "week", or "month")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// Returns:
+///     DataFrame or dict with cohort, cohort_size and period_0..period_N columns
+#[pyfunction]
+#[pyo3(signature = (data, period = "month", output = "pandas"))]
+pub fn cohort_retention(
+    py: Python<'_>,
+    data: &Bound<'_, PyAny>,
+    period: &str,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let period = CohortPeriod::parse(period).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unknown period '{}': expected 'day', 'week', or 'month'",
+            period
+        ))
+    })?;
+    let matrix = build_cohort_retention(&activity_spans(data)?, period);
+
+    let periods = matrix.first().map_or(0, |row| row.retention.len());
+    let columns = PyDict::new(py);
+    let cohorts: Vec<&str> = matrix.iter().map(|r| r.cohort.as_str()).collect();
+    let sizes: Vec<usize> = matrix.iter().map(|r| r.cohort_size).collect();
+    columns.set_item("cohort", PyList::new(py, &cohorts)?)?;
+    columns.set_item("cohort_size", PyList::new(py, &sizes)?)?;
+    for k in 0..periods {
+        let values: Vec<Option<f64>> = matrix.iter().map(|r| r.retention.get(k).copied()).collect();
+        columns.set_item(format!("period_{}", k), PyList::new(py, &values)?)?;
+    }
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (columns,))?
+            .into()),
+        "dict" => Ok(columns.into()),
+        "arrow" => {
+            let mut table = ArrowTable::new()
+                .utf8("cohort", cohorts)
+                .int64("cohort_size", sizes.iter().map(|&s| s as i64));
+            for k in 0..periods {
+                table = table.optional_float64(
+                    &format!("period_{}", k),
+                    matrix.iter().map(|r| r.retention.get(k).copied()),
+                );
+            }
+            table.into_pyarrow(py)
+        }
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (columns,))?
+            .into()),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Generate a bipartite user-product interaction graph
+///
+/// Edges are derived from the cart events and orders of the same config:
+/// every cart item event is a view, every add an add_to_cart, and the cart
+/// at checkout of each order is purchased. Cart events draw products by Zipf
+/// popularity, so product degree is heavy-tailed.
+///
+/// Args:
+///     config: EcommerceConfig dict (popularity via interactions.popularity_exponent)
+///     output: Output format ("pandas", "polars", "dict", "arrow", or "edgelist")
+///
+/// Returns:
+///     DataFrame or dict with user_id, product_id, interaction_type and weight.
+///     With output="edgelist", a list of (user_id, product_id, weight) tuples
+///     with one edge per user-product pair (weights summed over types), ready
+///     for networkx.Graph.add_weighted_edges_from or igraph.Graph.TupleList.
+#[pyfunction]
+#[pyo3(signature = (config = None, output = "pandas"))]
+pub fn ecommerce_interactions(
+    py: Python<'_>,
+    config: Option<&Bound<'_, PyAny>>,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let cfg = match config {
+        Some(d) => parse_ecommerce_config(&config_dict(d, "an EcommerceConfig, dict, or None")?)?,
+        None => EcommerceConfig::default(),
+    };
+    let edges = py.detach(|| {
+        let sessions = generate_sessions(&cfg);
+        let cart_events = generate_cart_events(&sessions, &generate_catalog(&cfg), &cfg);
+        generate_interactions(&cart_events, &generate_orders(&sessions, &cfg))
+    });
+    if output == "edgelist" {
+        return Ok(PyList::new(py, interaction_edgelist(&edges))?.into());
+    }
+
+    let columns = PyDict::new(py);
+    let users: Vec<&str> = edges.iter().map(|e| e.user_id.as_str()).collect();
+    let products: Vec<&str> = edges.iter().map(|e| e.product_id.as_str()).collect();
+    let kinds: Vec<&str> = edges.iter().map(|e| e.interaction_type.as_str()).collect();
+    let weights: Vec<u32> = edges.iter().map(|e| e.weight).collect();
+    columns.set_item("user_id", PyList::new(py, &users)?)?;
+    columns.set_item("product_id", PyList::new(py, &products)?)?;
+    columns.set_item("interaction_type", PyList::new(py, &kinds)?)?;
+    columns.set_item("weight", PyList::new(py, &weights)?)?;
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (columns,))?
+            .into()),
+        "dict" => Ok(columns.into()),
+        "arrow" => ArrowTable::new()
+            .utf8("user_id", users)
+            .utf8("product_id", products)
+            .utf8("interaction_type", kinds)
+            .int64("weight", weights.iter().map(|&w| i64::from(w)))
+            .into_pyarrow(py),
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (columns,))?
+            .into()),
+        _ => Err(invalid_output(
+            output,
+            &["pandas", "polars", "dict", "arrow", "edgelist"],
+        )),
+    }
+}
+
 /// Register ecommerce module functions
 pub fn register_ecommerce(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ecommerce_sessions, m)?)?;
     m.add_function(wrap_pyfunction!(ecommerce_products, m)?)?;
     m.add_function(wrap_pyfunction!(ecommerce_data, m)?)?;
+    m.add_function(wrap_pyfunction!(cohort_retention, m)?)?;
+    m.add_function(wrap_pyfunction!(ecommerce_interactions, m)?)?;
     Ok(())
 }
@@ -1,19 +1,26 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
+use crate::timeseries::parse_event_window_config;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use std::collections::HashMap;
 
 use superstore::finance::{
-    generate_finance_data, generate_multi_asset_prices, generate_options_chain,
-    generate_stock_prices, FinanceConfig, OhlcvBar, OhlcvConfig, OptionQuote, OptionsConfig,
-    StockConfig,
+    generate_credit_portfolio, generate_finance_data, generate_fix_messages,
+    generate_intraday_prices, generate_multi_asset_prices, generate_options_chain,
+    generate_stock_prices, generate_yield_curve, pivot_closes, portfolio_values, CreditConfig,
+    FinanceConfig, FixMessage, IntradayBarConfig, OhlcvBar, OhlcvConfig, OptionQuote,
+    OptionsConfig, Rebalance, StockConfig, VarMethod, YieldCurveConfig, CREDIT_RATINGS,
 };
+use superstore::timeseries::EventWindowConfig;
 
 // =============================================================================
 // Helper Functions for creating DataFrames
 // =============================================================================
 
-/// Create pandas DataFrame from OhlcvBar rows
-fn create_ohlcv_pandas(py: Python<'_>, rows: &[OhlcvBar]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for OhlcvBar rows, shared by the pandas and Arrow outputs
+fn ohlcv_columns<'py>(py: Python<'py>, rows: &[OhlcvBar]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let dates: Vec<&str> = rows.iter().map(|r| r.date.as_str()).collect();
@@ -36,13 +43,34 @@ fn create_ohlcv_pandas(py: Python<'_>, rows: &[OhlcvBar]) -> PyResult<Py<PyAny>>
     data.set_item("vwap", PyList::new(py, &vwaps)?)?;
     data.set_item("returns", PyList::new(py, &returns)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from OhlcvBar rows
+fn create_ohlcv_pandas(py: Python<'_>, rows: &[OhlcvBar]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (ohlcv_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from OhlcvBar rows
+fn create_ohlcv_arrow(py: Python<'_>, rows: &[OhlcvBar]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("date", rows.iter().map(|r| r.date.as_str()))
+        .utf8("ticker", rows.iter().map(|r| r.ticker.as_str()))
+        .float64("open", rows.iter().map(|r| r.open))
+        .float64("high", rows.iter().map(|r| r.high))
+        .float64("low", rows.iter().map(|r| r.low))
+        .float64("close", rows.iter().map(|r| r.close))
+        .int64("volume", rows.iter().map(|r| r.volume as i64))
+        .float64("vwap", rows.iter().map(|r| r.vwap))
+        .float64("returns", rows.iter().map(|r| r.returns))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from OhlcvBar rows
 fn create_ohlcv_polars(py: Python<'_>, rows: &[OhlcvBar]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let dates: Vec<&str> = rows.iter().map(|r| r.date.as_str()).collect();
@@ -96,9 +124,8 @@ fn create_ohlcv_dict(py: Python<'_>, rows: &[OhlcvBar]) -> PyResult<Py<PyAny>> {
     Ok(data.into())
 }
 
-/// Create pandas DataFrame from OptionQuote rows
-fn create_options_pandas(py: Python<'_>, rows: &[OptionQuote]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for OptionQuote rows, shared by the pandas and Arrow outputs
+fn options_columns<'py>(py: Python<'py>, rows: &[OptionQuote]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let dates: Vec<&str> = rows.iter().map(|r| r.date.as_str()).collect();
@@ -129,13 +156,46 @@ fn create_options_pandas(py: Python<'_>, rows: &[OptionQuote]) -> PyResult<Py<Py
     data.set_item("vega", PyList::new(py, &vegas)?)?;
     data.set_item("implied_vol", PyList::new(py, &implied_vols)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from OptionQuote rows
+fn create_options_pandas(py: Python<'_>, rows: &[OptionQuote]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (options_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from OptionQuote rows
+fn create_options_arrow(py: Python<'_>, rows: &[OptionQuote]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("date", rows.iter().map(|r| r.date.as_str()))
+        .utf8("ticker", rows.iter().map(|r| r.ticker.as_str()))
+        .utf8("option_type", rows.iter().map(|r| r.option_type.as_str()))
+        .float64("underlying_price", rows.iter().map(|r| r.underlying_price))
+        .float64("strike", rows.iter().map(|r| r.strike))
+        .utf8("expiration", rows.iter().map(|r| r.expiration.as_str()))
+        .int64(
+            "days_to_expiry",
+            rows.iter().map(|r| i64::from(r.days_to_expiry)),
+        )
+        .float64("price", rows.iter().map(|r| r.price))
+        .float64("delta", rows.iter().map(|r| r.delta))
+        .float64("gamma", rows.iter().map(|r| r.gamma))
+        .float64("theta", rows.iter().map(|r| r.theta))
+        .float64("vega", rows.iter().map(|r| r.vega))
+        .float64("implied_vol", rows.iter().map(|r| r.implied_vol))
+        .into_pyarrow(py)
+}
+
+/// Create dict from OptionQuote rows
+fn create_options_dict(py: Python<'_>, rows: &[OptionQuote]) -> PyResult<Py<PyAny>> {
+    Ok(options_columns(py, rows)?.into())
+}
+
 /// Create polars DataFrame from OptionQuote rows
 fn create_options_polars(py: Python<'_>, rows: &[OptionQuote]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let dates: Vec<&str> = rows.iter().map(|r| r.date.as_str()).collect();
@@ -336,6 +396,62 @@ fn parse_finance_config(dict: &Bound<'_, PyDict>) -> PyResult<(FinanceConfig, St
         OptionsConfig::default()
     };
 
+    // Parse nested IntradayBarConfig
+    let intraday = if let Some(intraday_val) = dict.get_item("intraday")? {
+        if let Ok(intraday_dict) = intraday_val.downcast::<PyDict>() {
+            let enable: bool = intraday_dict
+                .get_item("enable")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(false);
+            let bar_minutes: u32 = intraday_dict
+                .get_item("bar_minutes")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(1);
+            let opening_volatility_mult: f64 = intraday_dict
+                .get_item("opening_volatility_mult")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(1.5);
+            let midday_volatility_mult: f64 = intraday_dict
+                .get_item("midday_volatility_mult")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(0.7);
+            let closing_volatility_mult: f64 = intraday_dict
+                .get_item("closing_volatility_mult")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(1.3);
+            IntradayBarConfig {
+                enable,
+                bar_minutes,
+                opening_volatility_mult,
+                midday_volatility_mult,
+                closing_volatility_mult,
+            }
+        } else {
+            IntradayBarConfig::default()
+        }
+    } else {
+        IntradayBarConfig::default()
+    };
+
+    let events = match dict.get_item("events")? {
+        Some(v) => match v.cast::<PyDict>() {
+            Ok(d) => parse_event_window_config(d)?,
+            Err(_) => EventWindowConfig::default(),
+        },
+        None => EventWindowConfig::default(),
+    };
+
+    let earnings_dates: Vec<String> = dict
+        .get_item("earnings_dates")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or_default();
+
     let config = FinanceConfig {
         ndays,
         n_assets,
@@ -345,12 +461,104 @@ fn parse_finance_config(dict: &Bound<'_, PyDict>) -> PyResult<(FinanceConfig, St
         stock,
         ohlcv,
         options,
+        intraday,
+        events,
+        earnings_dates,
         tickers,
     };
 
     Ok((config, output))
 }
 
+/// Build columnar dict from FixMessage rows
+fn fix_messages_columns<'py>(py: Python<'py>, rows: &[FixMessage]) -> PyResult<Bound<'py, PyDict>> {
+    let data = PyDict::new(py);
+
+    let seq_nums: Vec<u64> = rows.iter().map(|r| r.seq_num).collect();
+    let sending_times: Vec<&str> = rows.iter().map(|r| r.sending_time.as_str()).collect();
+    let msg_types: Vec<&str> = rows.iter().map(|r| r.msg_type.as_str()).collect();
+    let cl_ord_ids: Vec<&str> = rows.iter().map(|r| r.cl_ord_id.as_str()).collect();
+    let orig_cl_ord_ids: Vec<Option<&str>> =
+        rows.iter().map(|r| r.orig_cl_ord_id.as_deref()).collect();
+    let order_ids: Vec<Option<&str>> = rows.iter().map(|r| r.order_id.as_deref()).collect();
+    let exec_ids: Vec<Option<&str>> = rows.iter().map(|r| r.exec_id.as_deref()).collect();
+    let exec_types: Vec<Option<&str>> = rows.iter().map(|r| r.exec_type.as_deref()).collect();
+    let ord_statuses: Vec<Option<&str>> = rows.iter().map(|r| r.ord_status.as_deref()).collect();
+    let symbols: Vec<&str> = rows.iter().map(|r| r.symbol.as_str()).collect();
+    let sides: Vec<&str> = rows.iter().map(|r| r.side.as_str()).collect();
+    let order_qtys: Vec<u64> = rows.iter().map(|r| r.order_qty).collect();
+    let prices: Vec<f64> = rows.iter().map(|r| r.price).collect();
+    let last_qtys: Vec<u64> = rows.iter().map(|r| r.last_qty).collect();
+    let last_pxs: Vec<f64> = rows.iter().map(|r| r.last_px).collect();
+    let cum_qtys: Vec<u64> = rows.iter().map(|r| r.cum_qty).collect();
+    let leaves_qtys: Vec<u64> = rows.iter().map(|r| r.leaves_qty).collect();
+    let raws: Vec<&str> = rows.iter().map(|r| r.raw.as_str()).collect();
+
+    data.set_item("seq_num", PyList::new(py, &seq_nums)?)?;
+    data.set_item("sending_time", PyList::new(py, &sending_times)?)?;
+    data.set_item("msg_type", PyList::new(py, &msg_types)?)?;
+    data.set_item("cl_ord_id", PyList::new(py, &cl_ord_ids)?)?;
+    data.set_item("orig_cl_ord_id", PyList::new(py, &orig_cl_ord_ids)?)?;
+    data.set_item("order_id", PyList::new(py, &order_ids)?)?;
+    data.set_item("exec_id", PyList::new(py, &exec_ids)?)?;
+    data.set_item("exec_type", PyList::new(py, &exec_types)?)?;
+    data.set_item("ord_status", PyList::new(py, &ord_statuses)?)?;
+    data.set_item("symbol", PyList::new(py, &symbols)?)?;
+    data.set_item("side", PyList::new(py, &sides)?)?;
+    data.set_item("order_qty", PyList::new(py, &order_qtys)?)?;
+    data.set_item("price", PyList::new(py, &prices)?)?;
+    data.set_item("last_qty", PyList::new(py, &last_qtys)?)?;
+    data.set_item("last_px", PyList::new(py, &last_pxs)?)?;
+    data.set_item("cum_qty", PyList::new(py, &cum_qtys)?)?;
+    data.set_item("leaves_qty", PyList::new(py, &leaves_qtys)?)?;
+    data.set_item("raw", PyList::new(py, &raws)?)?;
+
+    Ok(data)
+}
+
+/// Create pyarrow Table from FixMessage rows
+fn create_fix_messages_arrow(py: Python<'_>, rows: &[FixMessage]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .int64("seq_num", rows.iter().map(|r| r.seq_num as i64))
+        .utf8("sending_time", rows.iter().map(|r| r.sending_time.as_str()))
+        .utf8("msg_type", rows.iter().map(|r| r.msg_type.as_str()))
+        .utf8("cl_ord_id", rows.iter().map(|r| r.cl_ord_id.as_str()))
+        .optional_utf8(
+            "orig_cl_ord_id",
+            rows.iter().map(|r| r.orig_cl_ord_id.as_deref()),
+        )
+        .optional_utf8("order_id", rows.iter().map(|r| r.order_id.as_deref()))
+        .optional_utf8("exec_id", rows.iter().map(|r| r.exec_id.as_deref()))
+        .optional_utf8("exec_type", rows.iter().map(|r| r.exec_type.as_deref()))
+        .optional_utf8("ord_status", rows.iter().map(|r| r.ord_status.as_deref()))
+        .utf8("symbol", rows.iter().map(|r| r.symbol.as_str()))
+        .utf8("side", rows.iter().map(|r| r.side.as_str()))
+        .int64("order_qty", rows.iter().map(|r| r.order_qty as i64))
+        .float64("price", rows.iter().map(|r| r.price))
+        .int64("last_qty", rows.iter().map(|r| r.last_qty as i64))
+        .float64("last_px", rows.iter().map(|r| r.last_px))
+        .int64("cum_qty", rows.iter().map(|r| r.cum_qty as i64))
+        .int64("leaves_qty", rows.iter().map(|r| r.leaves_qty as i64))
+        .utf8("raw", rows.iter().map(|r| r.raw.as_str()))
+        .into_pyarrow(py)
+}
+
+/// Extract a column from a dict, pandas or polars frame as a Vec
+fn extract_column<'py, T: for<'a> FromPyObject<'a, 'py>>(
+    frame: &Bound<'py, PyAny>,
+    name: &str,
+) -> PyResult<Vec<T>> {
+    let column = frame.get_item(name).map_err(|_| {
+        pyo3::exceptions::PyValueError::new_err(format!("prices is missing column '{}'", name))
+    })?;
+    let list = frame
+        .py()
+        .import("builtins")?
+        .getattr("list")?
+        .call1((column,))?;
+    list.extract::<Vec<T>>()
+}
+
 // =============================================================================
 // Python Functions
 // =============================================================================
@@ -359,32 +567,39 @@ fn parse_finance_config(dict: &Bound<'_, PyDict>) -> PyResult<(FinanceConfig, St
 ///
 /// Returns realistic stock price data using Geometric Brownian Motion
 /// with optional jump diffusion. Includes OHLCV bars with realistic
-/// intraday relationships and volume patterns.
+/// intraday relationships and volume patterns. With `intraday.enable`,
+/// emits minute bars with a U-shaped volume profile instead of daily bars.
 ///
 /// # Arguments
 /// * `config` - Optional FinanceConfig or dict with generation parameters
 ///
 /// # Returns
-/// * DataFrame (pandas/polars) or dict of OHLCV bars
+/// * DataFrame (pandas/polars), pyarrow Table, or dict of OHLCV bars
 #[pyfunction]
 #[pyo3(signature = (config=None))]
-pub fn stock_prices(py: Python<'_>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Py<PyAny>> {
-    let (finance_config, output_format) = if let Some(dict) = config {
-        parse_finance_config(dict)?
+pub fn stock_prices(py: Python<'_>, config: Option<&Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+    let (finance_config, output_format) = if let Some(cfg) = config {
+        parse_finance_config(&config_dict(cfg, "a FinanceConfig, dict, or None")?)?
     } else {
         (FinanceConfig::default(), "pandas".to_string())
     };
 
-    let rows = if finance_config.n_assets > 1 {
-        generate_multi_asset_prices(&finance_config)
-    } else {
-        generate_stock_prices(&finance_config)
-    };
+    let rows = py.detach(|| {
+        if finance_config.intraday.enable {
+            generate_intraday_prices(&finance_config)
+        } else if finance_config.n_assets > 1 {
+            generate_multi_asset_prices(&finance_config)
+        } else {
+            generate_stock_prices(&finance_config)
+        }
+    });
 
     match output_format.to_lowercase().as_str() {
         "polars" => create_ohlcv_polars(py, &rows),
         "dict" => create_ohlcv_dict(py, &rows),
-        _ => create_ohlcv_pandas(py, &rows),
+        "arrow" => create_ohlcv_arrow(py, &rows),
+        "pandas" => create_ohlcv_pandas(py, &rows),
+        _ => Err(invalid_output(&output_format, TABLE_OUTPUTS)),
     }
 }
 
@@ -404,12 +619,12 @@ pub fn stock_prices(py: Python<'_>, config: Option<&Bound<'_, PyDict>>) -> PyRes
 #[pyo3(signature = (config=None, spot_price=None, date=None))]
 pub fn options_chain(
     py: Python<'_>,
-    config: Option<&Bound<'_, PyDict>>,
+    config: Option<&Bound<'_, PyAny>>,
     spot_price: Option<f64>,
     date: Option<&str>,
 ) -> PyResult<Py<PyAny>> {
-    let (finance_config, output_format) = if let Some(dict) = config {
-        parse_finance_config(dict)?
+    let (finance_config, output_format) = if let Some(cfg) = config {
+        parse_finance_config(&config_dict(cfg, "a FinanceConfig, dict, or None")?)?
     } else {
         (FinanceConfig::default(), "pandas".to_string())
     };
@@ -417,11 +632,14 @@ pub fn options_chain(
     let spot = spot_price.unwrap_or(100.0);
     let pricing_date = date.unwrap_or("2024-01-15");
 
-    let rows = generate_options_chain(&finance_config, spot, pricing_date);
+    let rows = py.detach(|| generate_options_chain(&finance_config, spot, pricing_date));
 
     match output_format.to_lowercase().as_str() {
         "polars" => create_options_polars(py, &rows),
-        _ => create_options_pandas(py, &rows),
+        "dict" => create_options_dict(py, &rows),
+        "arrow" => create_options_arrow(py, &rows),
+        "pandas" => create_options_pandas(py, &rows),
+        _ => Err(invalid_output(&output_format, TABLE_OUTPUTS)),
     }
 }
 
@@ -439,25 +657,381 @@ pub fn options_chain(
 #[pyo3(signature = (config=None))]
 pub fn finance(
     py: Python<'_>,
-    config: Option<&Bound<'_, PyDict>>,
+    config: Option<&Bound<'_, PyAny>>,
 ) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
-    let (finance_config, output_format) = if let Some(dict) = config {
-        parse_finance_config(dict)?
+    let (finance_config, output_format) = if let Some(cfg) = config {
+        parse_finance_config(&config_dict(cfg, "a FinanceConfig, dict, or None")?)?
     } else {
         (FinanceConfig::default(), "pandas".to_string())
     };
 
-    let (prices, options) = generate_finance_data(&finance_config);
+    let (prices, options) = py.detach(|| generate_finance_data(&finance_config));
+
+    let (prices_df, options_df) = match output_format.to_lowercase().as_str() {
+        "polars" => (
+            create_ohlcv_polars(py, &prices)?,
+            create_options_polars(py, &options)?,
+        ),
+        "dict" => (
+            create_ohlcv_dict(py, &prices)?,
+            create_options_dict(py, &options)?,
+        ),
+        "arrow" => (
+            create_ohlcv_arrow(py, &prices)?,
+            create_options_arrow(py, &options)?,
+        ),
+        "pandas" => (
+            create_ohlcv_pandas(py, &prices)?,
+            create_options_pandas(py, &options)?,
+        ),
+        _ => return Err(invalid_output(&output_format, TABLE_OUTPUTS)),
+    };
+
+    Ok((prices_df, options_df))
+}
+
+/// Generate a FIX 4.4 message log.
+///
+/// Produces `n` order lifecycles (NewOrderSingle, ExecutionReports for the
+/// acknowledgement and any partial fills, then a final fill or a cancel)
+/// with consistent ClOrdID/OrderID linkage. Each row carries the parsed
+/// fields plus the raw tag=value message (`|`-delimited).
+///
+/// # Arguments
+/// * `n` - Number of orders (default: 100)
+/// * `seed` - Optional random seed for reproducibility
+/// * `output` - Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// # Returns
+/// * DataFrame (pandas/polars), pyarrow Table, or dict of FIX messages
+#[pyfunction]
+#[pyo3(signature = (n=100, seed=None, output="pandas"))]
+pub fn fix_messages(
+    py: Python<'_>,
+    n: usize,
+    seed: Option<u64>,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let rows = py.detach(|| generate_fix_messages(n, seed));
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (fix_messages_columns(py, &rows)?,))?
+            .into()),
+        "dict" => Ok(fix_messages_columns(py, &rows)?.into()),
+        "arrow" => create_fix_messages_arrow(py, &rows),
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (fix_messages_columns(py, &rows)?,))?
+            .into()),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Aggregate multi-asset prices into a portfolio value series.
+///
+/// Takes `stock_prices` output (long format with `date`, `ticker` and
+/// `close` columns) and a weight per ticker, in first-seen ticker order.
+/// Weights are normalized to sum to one.
+///
+/// # Arguments
+/// * `prices` - DataFrame (pandas/polars) or dict of OHLCV bars
+/// * `weights` - Portfolio weight for each ticker
+/// * `rebalance` - "none" (buy and hold) or "daily" (default: "none")
+/// * `initial_value` - Starting portfolio value (default: weighted first close)
+/// * `output` - Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// # Returns
+/// * DataFrame (pandas/polars), pyarrow Table, or dict with date, value, and return columns
+#[pyfunction]
+#[pyo3(signature = (prices, weights, rebalance="none", initial_value=None, output="pandas"))]
+pub fn portfolio(
+    py: Python<'_>,
+    prices: &Bound<'_, PyAny>,
+    weights: Vec<f64>,
+    rebalance: &str,
+    initial_value: Option<f64>,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let to_py_err = |e: superstore::finance::FinanceError| {
+        pyo3::exceptions::PyValueError::new_err(e.to_string())
+    };
+
+    let rebalance = Rebalance::parse(rebalance).map_err(to_py_err)?;
+    let dates: Vec<String> = extract_column(prices, "date")?;
+    let tickers: Vec<String> = extract_column(prices, "ticker")?;
+    let closes: Vec<f64> = extract_column(prices, "close")?;
+
+    let matrix = pivot_closes(&dates, &tickers, &closes).map_err(to_py_err)?;
+    let values =
+        portfolio_values(&matrix.closes, &weights, rebalance, initial_value).map_err(to_py_err)?;
+    let returns: Vec<f64> = std::iter::once(0.0)
+        .chain(values.windows(2).map(|w| w[1] / w[0] - 1.0))
+        .take(values.len())
+        .collect();
+
+    let data = PyDict::new(py);
+    data.set_item("date", PyList::new(py, &matrix.dates)?)?;
+    data.set_item("value", PyList::new(py, &values)?)?;
+    data.set_item("return", PyList::new(py, &returns)?)?;
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        "dict" => Ok(data.into()),
+        "arrow" => ArrowTable::new()
+            .utf8("date", &matrix.dates)
+            .float64("value", values)
+            .float64("return", returns)
+            .into_pyarrow(py),
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Compute Value-at-Risk and Conditional VaR of a return series.
+///
+/// Both measures are returned as positive loss magnitudes at the given
+/// confidence level; CVaR is always at least as large as VaR.
+///
+/// # Arguments
+/// * `returns` - Sequence of periodic returns (list, Series, or array)
+/// * `alpha` - Confidence level (default: 0.95)
+/// * `method` - "historical" (empirical) or "gaussian" (closed form)
+///
+/// # Returns
+/// * dict with `var` and `cvar`
+#[pyfunction]
+#[pyo3(signature = (returns, alpha=0.95, method="historical"))]
+pub fn var_cvar(
+    py: Python<'_>,
+    returns: &Bound<'_, PyAny>,
+    alpha: f64,
+    method: &str,
+) -> PyResult<Py<PyAny>> {
+    let to_py_err = |e: superstore::finance::FinanceError| {
+        pyo3::exceptions::PyValueError::new_err(e.to_string())
+    };
+
+    let method = VarMethod::parse(method).map_err(to_py_err)?;
+    let returns: Vec<f64> = py
+        .import("builtins")?
+        .getattr("list")?
+        .call1((returns,))?
+        .extract()?;
+    let risk = superstore::finance::var_cvar(&returns, alpha, method).map_err(to_py_err)?;
+
+    let data = PyDict::new(py);
+    data.set_item("var", risk.var)?;
+    data.set_item("cvar", risk.cvar)?;
+    Ok(data.into())
+}
+
+/// Generate Nelson-Siegel yield curves.
+///
+/// Produces a smooth curve across tenors from level, slope and curvature
+/// factors. With `ndays > 1`, the factors evolve as mean-reverting
+/// processes and one curve is emitted per trading day.
+///
+/// # Arguments
+/// * `tenors` - Tenors in years (default: 3M to 30Y)
+/// * `seed` - Optional random seed for reproducibility
+/// * `level` - Long-run level beta0 (default: 0.045)
+/// * `slope` - Slope beta1; negative is upward-sloping (default: -0.02)
+/// * `curvature` - Curvature beta2 (default: 0.01)
+/// * `tau` - Decay parameter in years (default: 2.0)
+/// * `ndays` - Number of trading days (default: 1)
+/// * `start_date` - Start date (default: "2024-01-02")
+/// * `factor_volatility` - Daily factor shock volatility (default: 0.0005)
+/// * `mean_reversion` - Daily factor mean reversion (default: 0.02)
+/// * `output` - Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// # Returns
+/// * DataFrame (pandas/polars), pyarrow Table, or dict with date, tenor, and yield columns
+#[pyfunction]
+#[pyo3(signature = (
+    tenors=None,
+    seed=None,
+    level=0.045,
+    slope=-0.02,
+    curvature=0.01,
+    tau=2.0,
+    ndays=1,
+    start_date=None,
+    factor_volatility=0.0005,
+    mean_reversion=0.02,
+    output="pandas"
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn yield_curve(
+    py: Python<'_>,
+    tenors: Option<Vec<f64>>,
+    seed: Option<u64>,
+    level: f64,
+    slope: f64,
+    curvature: f64,
+    tau: f64,
+    ndays: usize,
+    start_date: Option<String>,
+    factor_volatility: f64,
+    mean_reversion: f64,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    if tau <= 0.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "tau must be positive",
+        ));
+    }
 
-    let prices_df = match output_format.to_lowercase().as_str() {
-        "polars" => create_ohlcv_polars(py, &prices)?,
-        _ => create_ohlcv_pandas(py, &prices)?,
+    let defaults = YieldCurveConfig::default();
+    let config = YieldCurveConfig {
+        tenors: tenors.unwrap_or(defaults.tenors),
+        level,
+        slope,
+        curvature,
+        tau,
+        ndays,
+        start_date,
+        factor_volatility,
+        mean_reversion,
+        seed,
     };
+    let rows = py.detach(|| generate_yield_curve(&config));
+
+    let data = PyDict::new(py);
+    let dates: Vec<&str> = rows.iter().map(|r| r.date.as_str()).collect();
+    let tenors: Vec<f64> = rows.iter().map(|r| r.tenor).collect();
+    let yields: Vec<f64> = rows.iter().map(|r| r.yield_rate).collect();
+    data.set_item("date", PyList::new(py, &dates)?)?;
+    data.set_item("tenor", PyList::new(py, &tenors)?)?;
+    data.set_item("yield", PyList::new(py, &yields)?)?;
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        "dict" => Ok(data.into()),
+        "arrow" => ArrowTable::new()
+            .utf8("date", dates)
+            .float64("tenor", tenors)
+            .float64("yield", yields)
+            .into_pyarrow(py),
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Generate a credit portfolio with ratings and simulated defaults.
+///
+/// Each obligor gets a rating (AAA..CCC) and the annual PD for that rating.
+/// Defaults are simulated year by year with a one-factor Gaussian copula:
+/// a shared systematic factor makes defaults cluster in bad years as
+/// `asset_correlation` rises, while each rating's expected default rate
+/// stays at its PD.
+///
+/// # Arguments
+/// * `n_obligors` - Number of obligors (default: 1000)
+/// * `horizon_years` - Simulation horizon in years (default: 1)
+/// * `asset_correlation` - Loading on the systematic factor (default: 0.2)
+/// * `default_probabilities` - Optional dict overriding annual PD per rating
+/// * `seed` - Optional random seed for reproducibility
+/// * `output` - Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// # Returns
+/// * DataFrame (pandas/polars), pyarrow Table, or dict of obligors
+#[pyfunction]
+#[pyo3(signature = (
+    n_obligors=1000,
+    horizon_years=1,
+    asset_correlation=0.2,
+    default_probabilities=None,
+    seed=None,
+    output="pandas"
+))]
+pub fn credit_defaults(
+    py: Python<'_>,
+    n_obligors: usize,
+    horizon_years: usize,
+    asset_correlation: f64,
+    default_probabilities: Option<HashMap<String, f64>>,
+    seed: Option<u64>,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    if !(0.0..=1.0).contains(&asset_correlation) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "asset_correlation must be between 0 and 1, got {}",
+            asset_correlation
+        )));
+    }
 
-    let options_df = match output_format.to_lowercase().as_str() {
-        "polars" => create_options_polars(py, &options)?,
-        _ => create_options_pandas(py, &options)?,
+    let mut config = CreditConfig {
+        n_obligors,
+        horizon_years,
+        asset_correlation,
+        seed,
+        ..Default::default()
     };
+    for (rating, pd) in default_probabilities.unwrap_or_default() {
+        let idx = CREDIT_RATINGS
+            .iter()
+            .position(|r| *r == rating)
+            .ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unknown rating '{}'. Must be one of {:?}",
+                    rating, CREDIT_RATINGS
+                ))
+            })?;
+        if !(0.0..=1.0).contains(&pd) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Default probability for {} must be between 0 and 1, got {}",
+                rating, pd
+            )));
+        }
+        config.default_probabilities[idx] = pd;
+    }
 
-    Ok((prices_df, options_df))
+    let rows = py.detach(|| generate_credit_portfolio(&config));
+
+    let data = PyDict::new(py);
+    let obligor_ids: Vec<&str> = rows.iter().map(|r| r.obligor_id.as_str()).collect();
+    let ratings: Vec<&str> = rows.iter().map(|r| r.rating.as_str()).collect();
+    let pds: Vec<f64> = rows.iter().map(|r| r.pd).collect();
+    let exposures: Vec<f64> = rows.iter().map(|r| r.exposure).collect();
+    let defaulted: Vec<bool> = rows.iter().map(|r| r.defaulted).collect();
+    let default_years: Vec<Option<u32>> = rows.iter().map(|r| r.default_year).collect();
+    let final_ratings: Vec<&str> = rows.iter().map(|r| r.final_rating.as_str()).collect();
+    data.set_item("obligor_id", PyList::new(py, &obligor_ids)?)?;
+    data.set_item("rating", PyList::new(py, &ratings)?)?;
+    data.set_item("pd", PyList::new(py, &pds)?)?;
+    data.set_item("exposure", PyList::new(py, &exposures)?)?;
+    data.set_item("defaulted", PyList::new(py, &defaulted)?)?;
+    data.set_item("default_year", PyList::new(py, &default_years)?)?;
+    data.set_item("final_rating", PyList::new(py, &final_ratings)?)?;
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        "dict" => Ok(data.into()),
+        "arrow" => ArrowTable::new()
+            .utf8("obligor_id", obligor_ids)
+            .utf8("rating", ratings)
+            .float64("pd", pds)
+            .float64("exposure", exposures)
+            .boolean("defaulted", defaulted)
+            .optional_int64(
+                "default_year",
+                default_years.iter().map(|y| y.map(i64::from)),
+            )
+            .utf8("final_rating", final_ratings)
+            .into_pyarrow(py),
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
 }
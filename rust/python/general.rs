@@ -1,34 +1,78 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
+use pyo3::exceptions::PyUserWarning;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use std::ffi::CString;
+
+use superstore::arrow::{employees_to_arrow, superstore_to_arrow_with_columns};
 
 use superstore::general::{
-    employees, superstore_with_config, CustomerConfig, EmployeeRow, PromotionalConfig,
+    employees_with_config, mask_employee_pii, superstore_batch, superstore_row_at,
+    superstore_with_chargebacks, superstore_with_config, superstore_with_diagnostics,
+    superstore_with_employees, BundlingConfig, ChargebackEvent, CustomerConfig, Department,
+    EmployeeConfig, EmployeeRow, InventoryConfig, OptionalColumns, PaymentConfig, PiiMaskConfig,
+    ProductBundle, PromotionalConfig, RegionalConfig, RegionalPreference, ReturnsConfig, SalesMode,
     SeasonalityConfig, SuperstoreConfig, SuperstoreRow,
 };
 
-fn superstore_row_to_pydict(py: Python<'_>, row: &SuperstoreRow) -> PyResult<Py<PyDict>> {
+fn superstore_row_to_pydict(
+    py: Python<'_>,
+    row: &SuperstoreRow,
+    columns: OptionalColumns,
+) -> PyResult<Py<PyDict>> {
     let dict = PyDict::new(py);
     dict.set_item("Row ID", row.row_id)?;
     dict.set_item("Order ID", &row.order_id)?;
     dict.set_item("Order Date", &row.order_date)?;
     dict.set_item("Ship Date", &row.ship_date)?;
-    dict.set_item("Ship Mode", &row.ship_mode)?;
+    dict.set_item("Ship Mode", &*row.ship_mode)?;
     dict.set_item("Customer ID", &row.customer_id)?;
-    dict.set_item("Segment", &row.segment)?;
-    dict.set_item("Country", &row.country)?;
+    dict.set_item("Segment", &*row.segment)?;
+    dict.set_item("Country", &*row.country)?;
     dict.set_item("City", &row.city)?;
     dict.set_item("State", &row.state)?;
     dict.set_item("Postal Code", &row.postal_code)?;
-    dict.set_item("Region", &row.region)?;
+    dict.set_item("Region", &*row.region)?;
     dict.set_item("Product ID", &row.product_id)?;
-    dict.set_item("Category", &row.category)?;
+    dict.set_item("Category", &*row.category)?;
     dict.set_item("Sub-Category", &row.sub_category)?;
+    if columns.product_type {
+        dict.set_item("Product Type", row.product_type.as_deref())?;
+    }
     dict.set_item("Item Status", &row.item_status)?;
+    if columns.currency {
+        dict.set_item("Currency", row.currency.as_deref())?;
+    }
     dict.set_item("Item Price", row.item_price)?;
+    dict.set_item("MSRP", row.msrp)?;
     dict.set_item("Sales", row.sales)?;
     dict.set_item("Quantity", row.quantity)?;
     dict.set_item("Discount", row.discount)?;
     dict.set_item("Profit", row.profit)?;
+    if columns.bundling {
+        dict.set_item("Bundle ID", row.bundle_id.as_deref())?;
+    }
+    if columns.inventory {
+        dict.set_item("Stock Status", row.stock_status.as_deref())?;
+        dict.set_item("Backorder Days", row.backorder_days)?;
+    }
+    if columns.payment {
+        dict.set_item("Payment Method", row.payment_method.as_deref())?;
+        dict.set_item("Is Fraud", row.is_fraud)?;
+        dict.set_item("Processing Fee", row.processing_fee)?;
+        dict.set_item("Settlement Date", row.settlement_date.as_deref())?;
+    }
+    if columns.cards {
+        dict.set_item("Card Network", row.card_network.as_deref())?;
+        dict.set_item("Card Number", row.card_number.as_deref())?;
+    }
+    if columns.returns {
+        dict.set_item("Return Reason", row.return_reason.as_deref())?;
+        dict.set_item("Return Date", row.return_date.as_deref())?;
+        dict.set_item("Original Order ID", row.original_order_id.as_deref())?;
+    }
     Ok(dict.into())
 }
 
@@ -52,12 +96,23 @@ fn employee_row_to_pydict(py: Python<'_>, row: &EmployeeRow) -> PyResult<Py<PyDi
     // Convert dates to Python date objects
     dict.set_item("Start Date", row.start_date.to_string())?;
     dict.set_item("Date of Birth", row.date_of_birth.to_string())?;
+    dict.set_item("Department", &row.department)?;
+    dict.set_item("Job Title", &row.job_title)?;
+    dict.set_item("Salary", row.salary)?;
+    dict.set_item("Manager ID", row.manager_id.as_deref())?;
+    dict.set_item(
+        "Termination Date",
+        row.termination_date.map(|d| d.to_string()),
+    )?;
     Ok(dict.into())
 }
 
-/// Create a pandas DataFrame from superstore rows (columnar approach for performance)
-fn create_superstore_pandas(py: Python<'_>, rows: &[SuperstoreRow]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for superstore rows, shared by the pandas and Arrow outputs
+fn superstore_columns<'py>(
+    py: Python<'py>,
+    rows: &[SuperstoreRow],
+    columns: OptionalColumns,
+) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     // Build column arrays (same as polars approach - much faster than list of dicts)
@@ -65,19 +120,20 @@ fn create_superstore_pandas(py: Python<'_>, rows: &[SuperstoreRow]) -> PyResult<
     let order_ids: Vec<&str> = rows.iter().map(|r| r.order_id.as_str()).collect();
     let order_dates: Vec<&str> = rows.iter().map(|r| r.order_date.as_str()).collect();
     let ship_dates: Vec<&str> = rows.iter().map(|r| r.ship_date.as_str()).collect();
-    let ship_modes: Vec<&str> = rows.iter().map(|r| r.ship_mode.as_str()).collect();
+    let ship_modes: Vec<&str> = rows.iter().map(|r| &*r.ship_mode).collect();
     let customer_ids: Vec<&str> = rows.iter().map(|r| r.customer_id.as_str()).collect();
-    let segments: Vec<&str> = rows.iter().map(|r| r.segment.as_str()).collect();
-    let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
+    let segments: Vec<&str> = rows.iter().map(|r| &*r.segment).collect();
+    let countries: Vec<&str> = rows.iter().map(|r| &*r.country).collect();
     let cities: Vec<&str> = rows.iter().map(|r| r.city.as_str()).collect();
     let states: Vec<&str> = rows.iter().map(|r| r.state.as_str()).collect();
     let postal_codes: Vec<&str> = rows.iter().map(|r| r.postal_code.as_str()).collect();
-    let regions: Vec<&str> = rows.iter().map(|r| r.region.as_str()).collect();
+    let regions: Vec<&str> = rows.iter().map(|r| &*r.region).collect();
     let product_ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
-    let categories: Vec<&str> = rows.iter().map(|r| r.category.as_str()).collect();
+    let categories: Vec<&str> = rows.iter().map(|r| &*r.category).collect();
     let sub_categories: Vec<&str> = rows.iter().map(|r| r.sub_category.as_str()).collect();
     let item_statuses: Vec<&str> = rows.iter().map(|r| r.item_status.as_str()).collect();
     let item_prices: Vec<f64> = rows.iter().map(|r| r.item_price).collect();
+    let msrps: Vec<Option<f64>> = rows.iter().map(|r| r.msrp).collect();
     let sales: Vec<i32> = rows.iter().map(|r| r.sales).collect();
     let quantities: Vec<i32> = rows.iter().map(|r| r.quantity).collect();
     let discounts: Vec<f64> = rows.iter().map(|r| r.discount).collect();
@@ -98,83 +154,192 @@ fn create_superstore_pandas(py: Python<'_>, rows: &[SuperstoreRow]) -> PyResult<
     data.set_item("Product ID", PyList::new(py, &product_ids)?)?;
     data.set_item("Category", PyList::new(py, &categories)?)?;
     data.set_item("Sub-Category", PyList::new(py, &sub_categories)?)?;
+    if columns.product_type {
+        let product_types: Vec<Option<&str>> =
+            rows.iter().map(|r| r.product_type.as_deref()).collect();
+        data.set_item("Product Type", PyList::new(py, &product_types)?)?;
+    }
     data.set_item("Item Status", PyList::new(py, &item_statuses)?)?;
+    if columns.currency {
+        let currencies: Vec<Option<&str>> = rows.iter().map(|r| r.currency.as_deref()).collect();
+        data.set_item("Currency", PyList::new(py, &currencies)?)?;
+    }
     data.set_item("Item Price", PyList::new(py, &item_prices)?)?;
+    data.set_item("MSRP", PyList::new(py, &msrps)?)?;
     data.set_item("Sales", PyList::new(py, &sales)?)?;
     data.set_item("Quantity", PyList::new(py, &quantities)?)?;
     data.set_item("Discount", PyList::new(py, &discounts)?)?;
     data.set_item("Profit", PyList::new(py, &profits)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
-    Ok(df.into())
+    if columns.bundling {
+        let bundle_ids: Vec<Option<&str>> = rows.iter().map(|r| r.bundle_id.as_deref()).collect();
+        data.set_item("Bundle ID", PyList::new(py, &bundle_ids)?)?;
+    }
+    if columns.inventory {
+        let stock: Vec<Option<&str>> = rows.iter().map(|r| r.stock_status.as_deref()).collect();
+        let backorder: Vec<Option<i32>> = rows.iter().map(|r| r.backorder_days).collect();
+        data.set_item("Stock Status", PyList::new(py, &stock)?)?;
+        data.set_item("Backorder Days", PyList::new(py, &backorder)?)?;
+    }
+    if columns.payment {
+        let methods: Vec<Option<&str>> = rows.iter().map(|r| r.payment_method.as_deref()).collect();
+        let fraud: Vec<Option<bool>> = rows.iter().map(|r| r.is_fraud).collect();
+        let fees: Vec<Option<f64>> = rows.iter().map(|r| r.processing_fee).collect();
+        let settled: Vec<Option<&str>> =
+            rows.iter().map(|r| r.settlement_date.as_deref()).collect();
+        data.set_item("Payment Method", PyList::new(py, &methods)?)?;
+        data.set_item("Is Fraud", PyList::new(py, &fraud)?)?;
+        data.set_item("Processing Fee", PyList::new(py, &fees)?)?;
+        data.set_item("Settlement Date", PyList::new(py, &settled)?)?;
+    }
+    if columns.cards {
+        let networks: Vec<Option<&str>> = rows.iter().map(|r| r.card_network.as_deref()).collect();
+        let numbers: Vec<Option<&str>> = rows.iter().map(|r| r.card_number.as_deref()).collect();
+        data.set_item("Card Network", PyList::new(py, &networks)?)?;
+        data.set_item("Card Number", PyList::new(py, &numbers)?)?;
+    }
+    if columns.returns {
+        let reasons: Vec<Option<&str>> = rows.iter().map(|r| r.return_reason.as_deref()).collect();
+        let dates: Vec<Option<&str>> = rows.iter().map(|r| r.return_date.as_deref()).collect();
+        let originals: Vec<Option<&str>> = rows
+            .iter()
+            .map(|r| r.original_order_id.as_deref())
+            .collect();
+        data.set_item("Return Reason", PyList::new(py, &reasons)?)?;
+        data.set_item("Return Date", PyList::new(py, &dates)?)?;
+        data.set_item("Original Order ID", PyList::new(py, &originals)?)?;
+    }
+
+    Ok(data)
 }
 
-/// Create a polars DataFrame from superstore rows
-fn create_superstore_polars(py: Python<'_>, rows: &[SuperstoreRow]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
-    let data = PyDict::new(py);
+/// Create a pandas DataFrame from superstore rows (columnar approach for performance)
+fn create_superstore_pandas(
+    py: Python<'_>,
+    rows: &[SuperstoreRow],
+    columns: OptionalColumns,
+) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (superstore_columns(py, rows, columns)?,))?;
+    Ok(df.into())
+}
 
-    // Build column arrays
-    let row_ids: Vec<i32> = rows.iter().map(|r| r.row_id).collect();
-    let order_ids: Vec<&str> = rows.iter().map(|r| r.order_id.as_str()).collect();
-    let order_dates: Vec<&str> = rows.iter().map(|r| r.order_date.as_str()).collect();
-    let ship_dates: Vec<&str> = rows.iter().map(|r| r.ship_date.as_str()).collect();
-    let ship_modes: Vec<&str> = rows.iter().map(|r| r.ship_mode.as_str()).collect();
-    let customer_ids: Vec<&str> = rows.iter().map(|r| r.customer_id.as_str()).collect();
-    let segments: Vec<&str> = rows.iter().map(|r| r.segment.as_str()).collect();
-    let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
-    let cities: Vec<&str> = rows.iter().map(|r| r.city.as_str()).collect();
-    let states: Vec<&str> = rows.iter().map(|r| r.state.as_str()).collect();
-    let postal_codes: Vec<&str> = rows.iter().map(|r| r.postal_code.as_str()).collect();
-    let regions: Vec<&str> = rows.iter().map(|r| r.region.as_str()).collect();
-    let product_ids: Vec<&str> = rows.iter().map(|r| r.product_id.as_str()).collect();
-    let categories: Vec<&str> = rows.iter().map(|r| r.category.as_str()).collect();
-    let sub_categories: Vec<&str> = rows.iter().map(|r| r.sub_category.as_str()).collect();
-    let item_statuses: Vec<&str> = rows.iter().map(|r| r.item_status.as_str()).collect();
-    let item_prices: Vec<f64> = rows.iter().map(|r| r.item_price).collect();
-    let sales: Vec<i32> = rows.iter().map(|r| r.sales).collect();
-    let quantities: Vec<i32> = rows.iter().map(|r| r.quantity).collect();
-    let discounts: Vec<f64> = rows.iter().map(|r| r.discount).collect();
-    let profits: Vec<f64> = rows.iter().map(|r| r.profit).collect();
+/// pandas column names for the columns of `superstore_to_arrow_with_columns`, in order
+pub(crate) fn superstore_arrow_names(columns: OptionalColumns) -> Vec<&'static str> {
+    let mut names = vec![
+        "Row ID",
+        "Order ID",
+        "Order Date",
+        "Ship Date",
+        "Ship Mode",
+        "Customer ID",
+        "Segment",
+        "Country",
+        "City",
+        "State",
+        "Postal Code",
+        "Region",
+        "Product ID",
+        "Category",
+        "Sub-Category",
+    ];
+    if columns.product_type {
+        names.push("Product Type");
+    }
+    if columns.currency {
+        names.push("Currency");
+    }
+    names.extend(["Sales", "Quantity", "Discount", "Profit"]);
+    if columns.bundling {
+        names.push("Bundle ID");
+    }
+    if columns.inventory {
+        names.extend(["Stock Status", "Backorder Days"]);
+    }
+    if columns.payment {
+        names.extend([
+            "Payment Method",
+            "Is Fraud",
+            "Processing Fee",
+            "Settlement Date",
+        ]);
+    }
+    if columns.cards {
+        names.extend(["Card Network", "Card Number"]);
+    }
+    if columns.returns {
+        names.extend(["Return Reason", "Return Date", "Original Order ID"]);
+    }
+    names
+}
 
-    data.set_item("Row ID", PyList::new(py, &row_ids)?)?;
-    data.set_item("Order ID", PyList::new(py, &order_ids)?)?;
-    data.set_item("Order Date", PyList::new(py, &order_dates)?)?;
-    data.set_item("Ship Date", PyList::new(py, &ship_dates)?)?;
-    data.set_item("Ship Mode", PyList::new(py, &ship_modes)?)?;
-    data.set_item("Customer ID", PyList::new(py, &customer_ids)?)?;
-    data.set_item("Segment", PyList::new(py, &segments)?)?;
-    data.set_item("Country", PyList::new(py, &countries)?)?;
-    data.set_item("City", PyList::new(py, &cities)?)?;
-    data.set_item("State", PyList::new(py, &states)?)?;
-    data.set_item("Postal Code", PyList::new(py, &postal_codes)?)?;
-    data.set_item("Region", PyList::new(py, &regions)?)?;
-    data.set_item("Product ID", PyList::new(py, &product_ids)?)?;
-    data.set_item("Category", PyList::new(py, &categories)?)?;
-    data.set_item("Sub-Category", PyList::new(py, &sub_categories)?)?;
-    data.set_item("Item Status", PyList::new(py, &item_statuses)?)?;
-    data.set_item("Item Price", PyList::new(py, &item_prices)?)?;
-    data.set_item("Sales", PyList::new(py, &sales)?)?;
-    data.set_item("Quantity", PyList::new(py, &quantities)?)?;
-    data.set_item("Discount", PyList::new(py, &discounts)?)?;
-    data.set_item("Profit", PyList::new(py, &profits)?)?;
+/// Create pyarrow Table from superstore rows.
+///
+/// Reuses the typed batch of `superstoreArrowIpc`, with the pandas column
+/// names and the item status and pricing columns it doesn't export.
+fn create_superstore_arrow(
+    py: Python<'_>,
+    rows: &[SuperstoreRow],
+    columns: OptionalColumns,
+) -> PyResult<Py<PyAny>> {
+    let batch = superstore_to_arrow_with_columns(rows, columns)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    let names = superstore_arrow_names(columns);
+    let status_at = 15 + usize::from(columns.product_type);
+    let sales_at = status_at + usize::from(columns.currency);
+    ArrowTable::new()
+        .batch_columns(&batch, 0..status_at, &names)?
+        .utf8("Item Status", rows.iter().map(|r| r.item_status.as_str()))
+        .batch_columns(&batch, status_at..sales_at, &names)?
+        .float64("Item Price", rows.iter().map(|r| r.item_price))
+        .optional_float64("MSRP", rows.iter().map(|r| r.msrp))
+        .batch_columns(&batch, sales_at..names.len(), &names)?
+        .into_pyarrow(py)
+}
 
-    let df = polars.call_method1("DataFrame", (data,))?;
+/// Create a polars DataFrame from superstore rows
+fn create_superstore_polars(
+    py: Python<'_>,
+    rows: &[SuperstoreRow],
+    columns: OptionalColumns,
+) -> PyResult<Py<PyAny>> {
+    let polars = import_output_library(py, "polars")?;
+    let df = polars.call_method1("DataFrame", (superstore_columns(py, rows, columns)?,))?;
     Ok(df.into())
 }
 
+/// Build superstore rows generated from `config` in the `output` format
+pub(crate) fn superstore_output(
+    py: Python<'_>,
+    rows: &[SuperstoreRow],
+    config: &SuperstoreConfig,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let columns = OptionalColumns::for_config(config);
+    match output {
+        "pandas" => create_superstore_pandas(py, rows, columns),
+        "polars" => create_superstore_polars(py, rows, columns),
+        "dict" => create_superstore_dict(py, rows, columns),
+        "arrow" => create_superstore_arrow(py, rows, columns),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
 /// Create a list of dicts from superstore rows
-fn create_superstore_dict(py: Python<'_>, rows: &[SuperstoreRow]) -> PyResult<Py<PyAny>> {
+fn create_superstore_dict(
+    py: Python<'_>,
+    rows: &[SuperstoreRow],
+    columns: OptionalColumns,
+) -> PyResult<Py<PyAny>> {
     let list = PyList::empty(py);
     for row in rows {
-        list.append(superstore_row_to_pydict(py, row)?)?;
+        list.append(superstore_row_to_pydict(py, row, columns)?)?;
     }
     Ok(list.into())
 }
 
-/// Create a pandas DataFrame from employee rows (columnar approach for performance)
-fn create_employees_pandas(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for employee rows, shared by the pandas and Arrow outputs
+fn employees_columns<'py>(py: Python<'py>, rows: &[EmployeeRow]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     // Build column arrays (much faster than list of dicts)
@@ -195,6 +360,14 @@ fn create_employees_pandas(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<
     let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
     let start_dates: Vec<String> = rows.iter().map(|r| r.start_date.to_string()).collect();
     let dobs: Vec<String> = rows.iter().map(|r| r.date_of_birth.to_string()).collect();
+    let departments: Vec<&str> = rows.iter().map(|r| r.department.as_str()).collect();
+    let job_titles: Vec<&str> = rows.iter().map(|r| r.job_title.as_str()).collect();
+    let salaries: Vec<f64> = rows.iter().map(|r| r.salary).collect();
+    let manager_ids: Vec<Option<&str>> = rows.iter().map(|r| r.manager_id.as_deref()).collect();
+    let termination_dates: Vec<Option<String>> = rows
+        .iter()
+        .map(|r| r.termination_date.map(|d| d.to_string()))
+        .collect();
 
     data.set_item("Row ID", PyList::new(py, &row_ids)?)?;
     data.set_item("Employee ID", PyList::new(py, &employee_ids)?)?;
@@ -213,14 +386,60 @@ fn create_employees_pandas(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<
     data.set_item("Country", PyList::new(py, &countries)?)?;
     data.set_item("Start Date", PyList::new(py, &start_dates)?)?;
     data.set_item("Date of Birth", PyList::new(py, &dobs)?)?;
+    data.set_item("Department", PyList::new(py, &departments)?)?;
+    data.set_item("Job Title", PyList::new(py, &job_titles)?)?;
+    data.set_item("Salary", PyList::new(py, &salaries)?)?;
+    data.set_item("Manager ID", PyList::new(py, &manager_ids)?)?;
+    data.set_item("Termination Date", PyList::new(py, &termination_dates)?)?;
+
+    Ok(data)
+}
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+/// Create a pandas DataFrame from employee rows (columnar approach for performance)
+fn create_employees_pandas(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (employees_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// pandas column names for the columns of `employees_to_arrow`, in order
+const EMPLOYEE_ARROW_NAMES: [&str; 22] = [
+    "Row ID",
+    "Employee ID",
+    "First Name",
+    "Surname",
+    "Prefix",
+    "Suffix",
+    "Phone Number",
+    "Email",
+    "SSN",
+    "Street",
+    "City",
+    "Postal Code",
+    "Region",
+    "State",
+    "Country",
+    "Start Date",
+    "Date of Birth",
+    "Department",
+    "Job Title",
+    "Salary",
+    "Manager ID",
+    "Termination Date",
+];
+
+/// Create pyarrow Table from employee rows, reusing the typed batch of `employeesArrowIpc`
+pub(crate) fn create_employees_arrow(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<PyAny>> {
+    let batch = employees_to_arrow(rows)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    ArrowTable::new()
+        .batch_columns(&batch, 0..EMPLOYEE_ARROW_NAMES.len(), &EMPLOYEE_ARROW_NAMES)?
+        .into_pyarrow(py)
+}
+
 /// Create a polars DataFrame from employee rows
 fn create_employees_polars(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let row_ids: Vec<i32> = rows.iter().map(|r| r.row_id).collect();
@@ -240,6 +459,14 @@ fn create_employees_polars(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<
     let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
     let start_dates: Vec<String> = rows.iter().map(|r| r.start_date.to_string()).collect();
     let dobs: Vec<String> = rows.iter().map(|r| r.date_of_birth.to_string()).collect();
+    let departments: Vec<&str> = rows.iter().map(|r| r.department.as_str()).collect();
+    let job_titles: Vec<&str> = rows.iter().map(|r| r.job_title.as_str()).collect();
+    let salaries: Vec<f64> = rows.iter().map(|r| r.salary).collect();
+    let manager_ids: Vec<Option<&str>> = rows.iter().map(|r| r.manager_id.as_deref()).collect();
+    let termination_dates: Vec<Option<String>> = rows
+        .iter()
+        .map(|r| r.termination_date.map(|d| d.to_string()))
+        .collect();
 
     data.set_item("Row ID", PyList::new(py, &row_ids)?)?;
     data.set_item("Employee ID", PyList::new(py, &employee_ids)?)?;
@@ -258,6 +485,11 @@ fn create_employees_polars(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<
     data.set_item("Country", PyList::new(py, &countries)?)?;
     data.set_item("Start Date", PyList::new(py, &start_dates)?)?;
     data.set_item("Date of Birth", PyList::new(py, &dobs)?)?;
+    data.set_item("Department", PyList::new(py, &departments)?)?;
+    data.set_item("Job Title", PyList::new(py, &job_titles)?)?;
+    data.set_item("Salary", PyList::new(py, &salaries)?)?;
+    data.set_item("Manager ID", PyList::new(py, &manager_ids)?)?;
+    data.set_item("Termination Date", PyList::new(py, &termination_dates)?)?;
 
     let df = polars.call_method1("DataFrame", (data,))?;
     Ok(df.into())
@@ -278,22 +510,103 @@ fn create_employees_dict(py: Python<'_>, rows: &[EmployeeRow]) -> PyResult<Py<Py
 ///     config: Optional SuperstoreConfig pydantic model, dict, or int (for backward compatibility).
 ///             If int, treated as count. If None, uses default configuration.
 ///     count: Number of rows (overrides config if provided)
-///     output: Output format ("pandas", "polars", or "dict")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
 ///     seed: Random seed (overrides config if provided)
+///     stable_v1: Use the version-stable generation mode, whose seeded output
+///                is frozen across releases (overrides config if provided)
+///     verbose: Also return a dict of generation diagnostics: "copula"
+///              ("gaussian", "student_t", or None if the correlated columns
+///              were sampled independently), "correlation_repair_distance", "clamped_quantities",
+///              "clamped_margins", "segment_counts", and "empty_segments"
 ///
 /// Returns:
-///     Superstore sales data in the specified format.
+///     Superstore sales data in the specified format, or a
+///     (data, diagnostics) tuple when verbose is true.
 #[pyfunction]
-#[pyo3(name = "superstore", signature = (config=None, count=None, output=None, seed=None))]
+#[pyo3(name = "superstore", signature = (config=None, count=None, output=None, seed=None, stable_v1=None, verbose=None))]
 pub fn py_superstore(
     py: Python<'_>,
     config: Option<&Bound<'_, PyAny>>,
     count: Option<usize>,
     output: Option<&str>,
     seed: Option<u64>,
+    stable_v1: Option<bool>,
+    verbose: Option<bool>,
 ) -> PyResult<Py<PyAny>> {
     // Parse config from pydantic model, dict, or int (backward compat)
-    let (mut store_config, cfg_output) = if let Some(cfg) = config {
+    let (mut store_config, cfg_output) = resolve_superstore_config(config)?;
+
+    // Override with explicit parameters if provided
+    if let Some(c) = count {
+        store_config.count = c;
+    }
+    if let Some(s) = seed {
+        store_config.seed = Some(s);
+    }
+    if let Some(stable) = stable_v1 {
+        store_config.stable_v1 = stable;
+    }
+
+    let final_output = output.unwrap_or(&cfg_output);
+
+    // Generate without the GIL; only building the output needs it
+    let verbose = verbose.unwrap_or(false);
+    let (rows, diagnostics) = py.detach(|| {
+        if verbose {
+            let (rows, diagnostics) = superstore_with_diagnostics(&store_config);
+            (rows, Some(diagnostics))
+        } else {
+            (superstore_with_config(&store_config), None)
+        }
+    });
+    let data = superstore_output(py, &rows, &store_config, final_output)?;
+    match diagnostics {
+        Some(diagnostics) => {
+            let dict = PyDict::new(py);
+            dict.set_item("copula", diagnostics.copula.map(|c| c.as_str()))?;
+            dict.set_item(
+                "correlation_repair_distance",
+                diagnostics.correlation_repair_distance,
+            )?;
+            dict.set_item("clamped_quantities", diagnostics.clamped_quantities)?;
+            dict.set_item("clamped_margins", diagnostics.clamped_margins)?;
+            dict.set_item("segment_counts", &diagnostics.segment_counts)?;
+            dict.set_item("empty_segments", &diagnostics.empty_segments)?;
+            Ok((data, dict).into_pyobject(py)?.into_any().unbind())
+        }
+        None => Ok(data),
+    }
+}
+
+/// Resolve a superstore config argument (pydantic model, dict, int, or None)
+/// into (config, output format), warning about invalid correlations and
+/// unsupported locales
+pub(crate) fn resolve_superstore_config(
+    config: Option<&Bound<'_, PyAny>>,
+) -> PyResult<(SuperstoreConfig, String)> {
+    let resolved = parse_superstore_config_arg(config)?;
+    if let Some(cfg) = config {
+        let py = cfg.py();
+        for message in [
+            resolved.0.correlation_warning(),
+            resolved.0.locale_warning(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let message = CString::new(message).expect("warning message has no NUL bytes");
+            PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1)?;
+        }
+    }
+    Ok(resolved)
+}
+
+/// Parse a superstore config argument (pydantic model, dict, int, or None)
+/// into (config, output format) without warning
+fn parse_superstore_config_arg(
+    config: Option<&Bound<'_, PyAny>>,
+) -> PyResult<(SuperstoreConfig, String)> {
+    Ok(if let Some(cfg) = config {
         // Check if it's an integer (backward compatibility: superstore(1000))
         if let Ok(int_val) = cfg.extract::<usize>() {
             (
@@ -304,46 +617,15 @@ pub fn py_superstore(
                 "pandas".to_string(),
             )
         // Check if it's a pydantic model (has model_dump method)
-        } else if cfg.hasattr("model_dump")? {
-            // Use mode="json" to ensure enums are serialized as strings
-            let kwargs = PyDict::new(py);
-            kwargs.set_item("mode", "json")?;
-            let dict = cfg.call_method("model_dump", (), Some(&kwargs))?;
-            let dict = dict.downcast::<PyDict>()?;
-            parse_full_superstore_config(dict)?
-        } else if let Ok(dict) = cfg.downcast::<PyDict>() {
-            parse_full_superstore_config(dict)?
         } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "config must be a SuperstoreConfig, dict, int, or None",
-            ));
+            parse_full_superstore_config(&config_dict(
+                cfg,
+                "a SuperstoreConfig, dict, int, or None",
+            )?)?
         }
     } else {
         (SuperstoreConfig::default(), "pandas".to_string())
-    };
-
-    // Override with explicit parameters if provided
-    if let Some(c) = count {
-        store_config.count = c;
-    }
-    if let Some(s) = seed {
-        store_config.seed = Some(s);
-    }
-
-    let final_output = output.unwrap_or(&cfg_output);
-
-    // Use enhanced config-based generation
-    let rows = superstore_with_config(&store_config);
-
-    match final_output {
-        "pandas" => create_superstore_pandas(py, &rows),
-        "polars" => create_superstore_polars(py, &rows),
-        "dict" => create_superstore_dict(py, &rows),
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-            "Invalid output format '{}'. Must be 'pandas', 'polars', or 'dict'",
-            output.unwrap_or("unknown")
-        ))),
-    }
+    })
 }
 
 /// Parse SuperstoreConfig dict into (count, output, seed, pool_size)
@@ -391,6 +673,34 @@ fn parse_full_superstore_config(dict: &Bound<'_, PyDict>) -> PyResult<(Superstor
         .transpose()?
         .unwrap_or(1000);
 
+    // Value ranges default to the Rust defaults, like every sub-config
+    let defaults = SuperstoreConfig::default();
+    let min_sales: i32 = dict
+        .get_item("min_sales")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.min_sales);
+    let max_sales: i32 = dict
+        .get_item("max_sales")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.max_sales);
+    let min_quantity: i32 = dict
+        .get_item("min_quantity")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.min_quantity);
+    let max_quantity: i32 = dict
+        .get_item("max_quantity")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.max_quantity);
+    let max_discount_percent: f64 = dict
+        .get_item("max_discount_percent")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.max_discount_percent);
+
     // Parse nested SeasonalityConfig
     let seasonality = if let Some(seasonality_val) = dict.get_item("seasonality")? {
         if let Ok(seasonality_dict) = seasonality_val.downcast::<PyDict>() {
@@ -448,10 +758,16 @@ fn parse_full_superstore_config(dict: &Bound<'_, PyDict>) -> PyResult<(Superstor
                 .map(|v| v.extract())
                 .transpose()?
                 .unwrap_or(-0.8);
+            let reference_price: Option<f64> = promo_dict
+                .get_item("reference_price")?
+                .filter(|v| !v.is_none())
+                .map(|v| v.extract())
+                .transpose()?;
             PromotionalConfig {
                 enable,
                 discount_quantity_correlation,
                 price_elasticity,
+                reference_price,
             }
         } else {
             PromotionalConfig::default()
@@ -503,53 +819,883 @@ fn parse_full_superstore_config(dict: &Bound<'_, PyDict>) -> PyResult<(Superstor
         .get_item("sales_quantity_correlation")?
         .map(|v| v.extract())
         .transpose()?
-        .unwrap_or(0.8);
+        .unwrap_or(0.7);
 
     let sales_profit_correlation: f64 = dict
         .get_item("sales_profit_correlation")?
         .map(|v| v.extract())
         .transpose()?
-        .unwrap_or(0.9);
+        .unwrap_or(0.6);
 
     let discount_profit_correlation: f64 = dict
         .get_item("discount_profit_correlation")?
         .map(|v| v.extract())
         .transpose()?
-        .unwrap_or(-0.6);
+        .unwrap_or(-0.4);
+
+    let repair_correlations: bool = dict
+        .get_item("repair_correlations")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(true);
+
+    let sales_mode = match dict.get_item("sales_mode")? {
+        Some(v) if !v.is_none() => match v.extract::<String>()?.as_str() {
+            "synthetic" => SalesMode::Synthetic,
+            "price_times_quantity" => SalesMode::PriceTimesQuantity,
+            other => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Invalid sales_mode '{}'. Must be 'synthetic' or 'price_times_quantity'",
+                    other
+                )))
+            }
+        },
+        _ => SalesMode::Synthetic,
+    };
+
+    // Given as [min, max]; validate() checks the bounds themselves
+    let margin_bounds = match dict.get_item("margin_bounds")? {
+        Some(v) if !v.is_none() => match v.extract::<Vec<f64>>()?[..] {
+            [min, max] => Some((min, max)),
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "margin_bounds must be [min, max]",
+                ))
+            }
+        },
+        _ => None,
+    };
+
+    let copula_df: Option<f64> = dict
+        .get_item("copula_df")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
+    let valid_identifiers: bool = dict
+        .get_item("valid_identifiers")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    let stable_v1: bool = dict
+        .get_item("stable_v1")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    let per_row_seeding: bool = dict
+        .get_item("per_row_seeding")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    let order_date_start: Option<String> = dict
+        .get_item("order_date_start")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
+    let order_date_end: Option<String> = dict
+        .get_item("order_date_end")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
+    let currency: Option<String> = dict
+        .get_item("currency")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
 
-    let config = SuperstoreConfig {
+    let mut config = SuperstoreConfig {
         count,
         seed,
         pool_size,
+        min_sales,
+        max_sales,
+        min_quantity,
+        max_quantity,
+        max_discount_percent,
         seasonality,
         promotions,
         customers,
         sales_quantity_correlation,
         sales_profit_correlation,
         discount_profit_correlation,
+        copula_df,
+        repair_correlations,
+        sales_mode,
+        margin_bounds,
+        valid_identifiers,
+        stable_v1,
+        per_row_seeding,
+        order_date_start,
+        order_date_end,
+        currency,
         ..Default::default()
     };
 
+    if let Some(v) = dict.get_item("locale")? {
+        config.locale = v.extract()?;
+    }
+
+    if let Some(regions) = parse_regions(dict)? {
+        config.regions = regions;
+    }
+    // Categories are given as {category: [sub-category, ...]}
+    if let Some(v) = dict.get_item("categories")? {
+        // None keeps the US sectors and industries
+        if !v.is_none() {
+            config.categories = Some(
+                v.cast::<PyDict>()?
+                    .iter()
+                    .map(|(category, subs)| Ok((category.extract()?, subs.extract()?)))
+                    .collect::<PyResult<_>>()?,
+            );
+        }
+    }
+    // The taxonomy is given as {category: {sub-category: [product type, ...]}}
+    if let Some(v) = dict.get_item("taxonomy")? {
+        if !v.is_none() {
+            config.taxonomy = Some(
+                v.cast::<PyDict>()?
+                    .iter()
+                    .map(|(category, subs)| {
+                        let subs = subs
+                            .cast::<PyDict>()?
+                            .iter()
+                            .map(|(sub, types)| Ok((sub.extract()?, types.extract()?)))
+                            .collect::<PyResult<_>>()?;
+                        Ok((category.extract()?, subs))
+                    })
+                    .collect::<PyResult<_>>()?,
+            );
+        }
+    }
+    if let Some(v) = dict.get_item("bundling")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.bundling = parse_bundling_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("regional")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.regional = parse_regional_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("inventory")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.inventory = parse_inventory_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("payment")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.payment = parse_payment_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("returns")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.returns = parse_returns_config(d)?;
+        }
+    }
+    config
+        .validate()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+
     Ok((config, output))
 }
 
+fn parse_bundling_config(dict: &Bound<'_, PyDict>) -> PyResult<BundlingConfig> {
+    let mut config = BundlingConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("bundle_probability")? {
+        config.bundle_probability = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("bundles")? {
+        // None keeps the built-in bundles
+        if !v.is_none() {
+            let bundles = v.cast::<PyList>()?;
+            config.bundles = bundles
+                .iter()
+                .map(|b| {
+                    let b = b.cast_into::<PyDict>()?;
+                    Ok(ProductBundle {
+                        name: b
+                            .get_item("name")?
+                            .map(|v| v.extract())
+                            .transpose()?
+                            .unwrap_or_else(|| "Bundle".to_string()),
+                        categories: b
+                            .get_item("categories")?
+                            .map(|v| v.extract())
+                            .transpose()?
+                            .unwrap_or_default(),
+                        discount_multiplier: b
+                            .get_item("discount_multiplier")?
+                            .map(|v| v.extract())
+                            .transpose()?
+                            .unwrap_or(0.9),
+                    })
+                })
+                .collect::<PyResult<_>>()?;
+        }
+    }
+    Ok(config)
+}
+
+/// Regional preferences are given as `{region: {category: weight}}`
+fn parse_regional_config(dict: &Bound<'_, PyDict>) -> PyResult<RegionalConfig> {
+    let mut config = RegionalConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("preferences")? {
+        // None keeps the built-in preferences
+        if !v.is_none() {
+            let preferences = v.cast::<PyDict>()?;
+            config.preferences = preferences
+                .iter()
+                .map(|(region, weights)| {
+                    Ok(RegionalPreference {
+                        region: region.extract()?,
+                        category_weights: weights
+                            .cast::<PyDict>()?
+                            .iter()
+                            .map(|(c, w)| Ok((c.extract()?, w.extract()?)))
+                            .collect::<PyResult<_>>()?,
+                    })
+                })
+                .collect::<PyResult<_>>()?;
+        }
+    }
+    Ok(config)
+}
+
+fn parse_inventory_config(dict: &Bound<'_, PyDict>) -> PyResult<InventoryConfig> {
+    let mut config = InventoryConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("stock_out_probability")? {
+        config.stock_out_probability = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("backorder_delay_days")? {
+        config.backorder_delay_days = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("low_stock_threshold")? {
+        config.low_stock_threshold = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("low_stock_price_premium")? {
+        config.low_stock_price_premium = v.extract()?;
+    }
+    Ok(config)
+}
+
+fn parse_payment_config(dict: &Bound<'_, PyDict>) -> PyResult<PaymentConfig> {
+    let mut config = PaymentConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("fraud_simulation")? {
+        config.fraud_simulation = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("card_numbers")? {
+        config.card_numbers = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("chargebacks")? {
+        config.chargebacks = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("fraud_chargeback_rate")? {
+        config.fraud_chargeback_rate = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("dispute_chargeback_rate")? {
+        config.dispute_chargeback_rate = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("fixed_fee")? {
+        config.fixed_fee = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("min_fee")? {
+        config.min_fee = v.extract()?;
+    }
+    Ok(config)
+}
+
+fn parse_returns_config(dict: &Bound<'_, PyDict>) -> PyResult<ReturnsConfig> {
+    let mut config = ReturnsConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("return_rate")? {
+        config.return_rate = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("restocking_fee_rate")? {
+        config.restocking_fee_rate = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("avg_days_to_return")? {
+        config.avg_days_to_return = v.extract()?;
+    }
+    Ok(config)
+}
+
+/// Parse `mask_pii` (bool or list of field names) into a PiiMaskConfig
+fn parse_pii_mask(mask_pii: Option<&Bound<'_, PyAny>>) -> PyResult<PiiMaskConfig> {
+    let Some(value) = mask_pii else {
+        return Ok(PiiMaskConfig::default());
+    };
+    if let Ok(flag) = value.extract::<bool>() {
+        return Ok(if flag {
+            PiiMaskConfig::all()
+        } else {
+            PiiMaskConfig::default()
+        });
+    }
+
+    let fields: Vec<String> = value.extract().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("mask_pii must be a bool or a list of field names")
+    })?;
+    let mut mask = PiiMaskConfig::default();
+    for field in fields {
+        match field.to_lowercase().as_str() {
+            "ssn" => mask.ssn = true,
+            "email" => mask.email = true,
+            "phone" | "phone_number" => mask.phone_number = true,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Invalid mask_pii field '{}'. Must be 'ssn', 'email', or 'phone_number'",
+                    field
+                )))
+            }
+        }
+    }
+    Ok(mask)
+}
+
+/// Parse the optional `regions` list shared by SuperstoreConfig and EmployeeConfig
+fn parse_regions(dict: &Bound<'_, PyDict>) -> PyResult<Option<Vec<String>>> {
+    let Some(value) = dict.get_item("regions")? else {
+        return Ok(None);
+    };
+    if value.is_none() {
+        return Ok(None);
+    }
+    let regions: Vec<String> = value.extract()?;
+    if regions.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "regions must not be empty",
+        ));
+    }
+    Ok(Some(regions))
+}
+
+/// Parse EmployeeConfig dict into Rust struct
+fn parse_employee_config(dict: &Bound<'_, PyDict>) -> PyResult<(EmployeeConfig, String)> {
+    let defaults = EmployeeConfig::default();
+
+    let count: usize = dict
+        .get_item("count")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.count);
+
+    let output: String = dict
+        .get_item("output")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or_else(|| "pandas".to_string());
+
+    let seed: Option<u64> = dict.get_item("seed")?.and_then(|v| v.extract().ok());
+
+    let pool_size: usize = dict
+        .get_item("pool_size")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.pool_size);
+    if pool_size < 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "pool_size must be at least 1",
+        ));
+    }
+
+    let valid_identifiers: bool = dict
+        .get_item("valid_identifiers")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.valid_identifiers);
+
+    let regions = parse_regions(dict)?.unwrap_or(defaults.regions);
+
+    // Departments are given as [{"name", "job_titles", "salary_range"}, ...]
+    let departments = match dict.get_item("departments")? {
+        Some(v) if !v.is_none() => {
+            let departments = v
+                .try_iter()?
+                .map(|d| parse_department(&d?))
+                .collect::<PyResult<Vec<_>>>()?;
+            if departments.is_empty() {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "departments must not be empty",
+                ));
+            }
+            departments
+        }
+        _ => defaults.departments,
+    };
+
+    let manager_ratio: f64 = dict
+        .get_item("manager_ratio")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.manager_ratio);
+    if !(0.0..=1.0).contains(&manager_ratio) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "manager_ratio must be between 0 and 1, got {}",
+            manager_ratio
+        )));
+    }
+
+    let attrition_rate: f64 = dict
+        .get_item("attrition_rate")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(defaults.attrition_rate);
+    if !(0.0..=1.0).contains(&attrition_rate) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "attrition_rate must be between 0 and 1, got {}",
+            attrition_rate
+        )));
+    }
+
+    Ok((
+        EmployeeConfig {
+            count,
+            pool_size,
+            seed,
+            valid_identifiers,
+            regions,
+            departments,
+            manager_ratio,
+            attrition_rate,
+        },
+        output,
+    ))
+}
+
+fn parse_department(value: &Bound<'_, PyAny>) -> PyResult<Department> {
+    let dict = config_dict(value, "a Department or dict")?;
+    let name: String = dict
+        .get_item("name")?
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("department needs a name"))?
+        .extract()?;
+    let job_titles: Vec<String> = dict
+        .get_item("job_titles")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or_default();
+    let salary_range: Vec<f64> = dict
+        .get_item("salary_range")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or_default();
+    match salary_range[..] {
+        [low, high] if low > 0.0 && low <= high => Ok(Department {
+            name,
+            job_titles,
+            salary_range: (low, high),
+        }),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "department '{}' needs a salary_range [low, high] with 0 < low <= high",
+            name
+        ))),
+    }
+}
+
+/// Generate employee records.
+///
+/// Args:
+///     count: Number of employees (overrides config if provided)
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
+///     seed: Random seed for reproducibility (overrides config if provided)
+///     mask_pii: True to mask SSN, email and phone number, or a list of
+///               fields to mask (e.g. ["ssn", "email"])
+///     valid_identifiers: Only emit structurally valid SSNs
+///     config: Optional EmployeeConfig pydantic model or dict
+///
+/// Returns:
+///     Employee data in the specified format.
 #[pyfunction]
-#[pyo3(name = "employees", signature = (count=1000, output="pandas", seed=None))]
+#[pyo3(
+    name = "employees",
+    signature = (count=None, output=None, seed=None, mask_pii=None, valid_identifiers=None, config=None)
+)]
 pub fn py_employees(
     py: Python<'_>,
-    count: usize,
+    count: Option<usize>,
+    output: Option<&str>,
+    seed: Option<u64>,
+    mask_pii: Option<&Bound<'_, PyAny>>,
+    valid_identifiers: Option<bool>,
+    config: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    let mask = parse_pii_mask(mask_pii)?;
+    let (mut emp_config, cfg_output) = match config {
+        Some(cfg) => parse_employee_config(&config_dict(cfg, "an EmployeeConfig, dict, or None")?)?,
+        None => (EmployeeConfig::default(), "pandas".to_string()),
+    };
+    if let Some(c) = count {
+        emp_config.count = c;
+    }
+    if let Some(s) = seed {
+        emp_config.seed = Some(s);
+    }
+    if let Some(v) = valid_identifiers {
+        emp_config.valid_identifiers = v;
+    }
+    let output = output.unwrap_or(&cfg_output);
+
+    let mut rows = py.detach(|| employees_with_config(&emp_config));
+    if mask.any() {
+        mask_employee_pii(&mut rows, &mask);
+    }
+    employees_output(py, &rows, output)
+}
+
+/// Build employee rows in the `output` format
+pub(crate) fn employees_output(
+    py: Python<'_>,
+    rows: &[EmployeeRow],
     output: &str,
+) -> PyResult<Py<PyAny>> {
+    match output {
+        "pandas" => create_employees_pandas(py, rows),
+        "polars" => create_employees_polars(py, rows),
+        "dict" => create_employees_dict(py, rows),
+        "arrow" => create_employees_arrow(py, rows),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Generate superstore sales data together with a joinable employee table.
+///
+/// Both tables share one location pool and the superstore region set, so
+/// employees can be joined to sales rows on `Region`. Cities and states are
+/// drawn per row from the shared pool, so they are not a guaranteed join key.
+///
+/// Args:
+///     count: Number of superstore rows (overrides config if provided)
+///     config: Optional SuperstoreConfig pydantic model, dict, or int.
+///     employee_count: Number of employees (default: count / 10, at least 1)
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
+///     seed: Random seed (overrides config if provided)
+///     mask_pii: True or a list of employee fields to mask (see `employees`)
+///
+/// Returns:
+///     Tuple of (superstore, employees) in the specified format.
+#[pyfunction]
+#[pyo3(signature = (count=None, config=None, employee_count=None, output=None, seed=None, mask_pii=None))]
+pub fn with_employees(
+    py: Python<'_>,
+    count: Option<usize>,
+    config: Option<&Bound<'_, PyAny>>,
+    employee_count: Option<usize>,
+    output: Option<&str>,
     seed: Option<u64>,
+    mask_pii: Option<&Bound<'_, PyAny>>,
 ) -> PyResult<Py<PyAny>> {
-    let rows = employees(count, seed, None);
+    let mask = parse_pii_mask(mask_pii)?;
+    let (mut store_config, cfg_output) = resolve_superstore_config(config)?;
+    if let Some(c) = count {
+        store_config.count = c;
+    }
+    if let Some(s) = seed {
+        store_config.seed = Some(s);
+    }
+    let employee_count = employee_count.unwrap_or((store_config.count / 10).max(1));
+
+    let (rows, mut staff) = py.detach(|| superstore_with_employees(&store_config, employee_count));
+    if mask.any() {
+        mask_employee_pii(&mut staff, &mask);
+    }
+
+    let columns = OptionalColumns::for_config(&store_config);
+    let final_output = output.unwrap_or(&cfg_output);
+    let (sales, people) = match final_output {
+        "pandas" => (
+            create_superstore_pandas(py, &rows, columns)?,
+            create_employees_pandas(py, &staff)?,
+        ),
+        "polars" => (
+            create_superstore_polars(py, &rows, columns)?,
+            create_employees_polars(py, &staff)?,
+        ),
+        "dict" => (
+            create_superstore_dict(py, &rows, columns)?,
+            create_employees_dict(py, &staff)?,
+        ),
+        "arrow" => (
+            create_superstore_arrow(py, &rows, columns)?,
+            create_employees_arrow(py, &staff)?,
+        ),
+        _ => return Err(invalid_output(final_output, TABLE_OUTPUTS)),
+    };
+
+    Ok((sales, people).into_pyobject(py)?.into_any().unbind())
+}
+
+/// Build the column dict for chargeback events
+fn chargebacks_columns<'py>(
+    py: Python<'py>,
+    events: &[ChargebackEvent],
+) -> PyResult<Bound<'py, PyDict>> {
+    let data = PyDict::new(py);
+    let order_ids: Vec<&str> = events.iter().map(|e| e.order_id.as_str()).collect();
+    let dates: Vec<&str> = events.iter().map(|e| e.chargeback_date.as_str()).collect();
+    let amounts: Vec<f64> = events.iter().map(|e| e.amount).collect();
+    let reason_codes: Vec<&str> = events.iter().map(|e| e.reason_code.as_str()).collect();
+
+    data.set_item("Order ID", PyList::new(py, &order_ids)?)?;
+    data.set_item("Chargeback Date", PyList::new(py, &dates)?)?;
+    data.set_item("Amount", PyList::new(py, &amounts)?)?;
+    data.set_item("Reason Code", PyList::new(py, &reason_codes)?)?;
+    Ok(data)
+}
 
+/// Build chargeback events in the `output` format
+fn chargebacks_output(
+    py: Python<'_>,
+    events: &[ChargebackEvent],
+    output: &str,
+) -> PyResult<Py<PyAny>> {
     match output {
-        "pandas" => create_employees_pandas(py, &rows),
-        "polars" => create_employees_polars(py, &rows),
-        "dict" => create_employees_dict(py, &rows),
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-            "Invalid output format '{}'. Must be 'pandas', 'polars', or 'dict'",
-            output
-        ))),
+        "pandas" | "polars" => {
+            let library = import_output_library(py, output)?;
+            let df = library.call_method1("DataFrame", (chargebacks_columns(py, events)?,))?;
+            Ok(df.into())
+        }
+        "dict" => {
+            let list = PyList::empty(py);
+            for event in events {
+                let row = PyDict::new(py);
+                row.set_item("Order ID", &event.order_id)?;
+                row.set_item("Chargeback Date", &event.chargeback_date)?;
+                row.set_item("Amount", event.amount)?;
+                row.set_item("Reason Code", &event.reason_code)?;
+                list.append(row)?;
+            }
+            Ok(list.into())
+        }
+        "arrow" => ArrowTable::new()
+            .utf8("Order ID", events.iter().map(|e| e.order_id.as_str()))
+            .utf8(
+                "Chargeback Date",
+                events.iter().map(|e| e.chargeback_date.as_str()),
+            )
+            .float64("Amount", events.iter().map(|e| e.amount))
+            .utf8("Reason Code", events.iter().map(|e| e.reason_code.as_str()))
+            .into_pyarrow(py),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Generate superstore sales data together with its chargeback events.
+///
+/// Fraudulent orders are charged back at `payment.fraud_chargeback_rate`
+/// with reason code "10.4"; legitimate orders are disputed at the much
+/// lower `payment.dispute_chargeback_rate`. Every event's `Order ID` refers
+/// to a sale row. The chargeback table is empty unless `payment.enable`
+/// and `payment.chargebacks` are set in the config.
+///
+/// Args:
+///     count: Number of superstore rows (overrides config if provided)
+///     config: Optional SuperstoreConfig pydantic model, dict, or int.
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
+///     seed: Random seed (overrides config if provided)
+///
+/// Returns:
+///     Tuple of (superstore, chargebacks) in the specified format. The
+///     chargebacks have columns Order ID, Chargeback Date, Amount, and
+///     Reason Code.
+#[pyfunction]
+#[pyo3(signature = (count=None, config=None, output=None, seed=None))]
+pub fn with_chargebacks(
+    py: Python<'_>,
+    count: Option<usize>,
+    config: Option<&Bound<'_, PyAny>>,
+    output: Option<&str>,
+    seed: Option<u64>,
+) -> PyResult<Py<PyAny>> {
+    let (mut store_config, cfg_output) = resolve_superstore_config(config)?;
+    if let Some(c) = count {
+        store_config.count = c;
+    }
+    if let Some(s) = seed {
+        store_config.seed = Some(s);
+    }
+
+    let final_output = output.unwrap_or(&cfg_output);
+    let (rows, events) = py.detach(|| superstore_with_chargebacks(&store_config));
+    let sales = superstore_output(py, &rows, &store_config, final_output)?;
+    let chargebacks = chargebacks_output(py, &events, final_output)?;
+    Ok((sales, chargebacks).into_pyobject(py)?.into_any().unbind())
+}
+
+/// Generate several independent superstore datasets from one base seed.
+///
+/// Dataset `i` is seeded with a hash of `(base_seed, i)`, so the datasets
+/// differ from each other but the whole batch is reproducible.
+///
+/// Args:
+///     count: Number of rows per dataset (overrides config if provided)
+///     n_datasets: Number of datasets to generate
+///     base_seed: Seed the per-dataset seeds are derived from (overrides config seed)
+///     config: Optional SuperstoreConfig pydantic model, dict, or int.
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// Returns:
+///     List of `n_datasets` datasets in the specified format.
+#[pyfunction]
+#[pyo3(signature = (count=None, n_datasets=10, base_seed=None, config=None, output=None))]
+pub fn batch(
+    py: Python<'_>,
+    count: Option<usize>,
+    n_datasets: usize,
+    base_seed: Option<u64>,
+    config: Option<&Bound<'_, PyAny>>,
+    output: Option<&str>,
+) -> PyResult<Py<PyAny>> {
+    let (mut store_config, cfg_output) = resolve_superstore_config(config)?;
+    if let Some(c) = count {
+        store_config.count = c;
+    }
+    if let Some(s) = base_seed {
+        store_config.seed = Some(s);
     }
+
+    let final_output = output.unwrap_or(&cfg_output);
+    let create = match final_output {
+        "pandas" => create_superstore_pandas,
+        "polars" => create_superstore_polars,
+        "dict" => create_superstore_dict,
+        "arrow" => create_superstore_arrow,
+        _ => return Err(invalid_output(final_output, TABLE_OUTPUTS)),
+    };
+
+    let columns = OptionalColumns::for_config(&store_config);
+    let datasets = py
+        .detach(|| superstore_batch(&store_config, n_datasets))
+        .iter()
+        .map(|rows| create(py, rows, columns))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyList::new(py, datasets)?.into_any().unbind())
+}
+
+/// Generate a single superstore row by index without generating the rows before it.
+///
+/// Rows are seeded individually from `(seed, index)`, so the result equals row
+/// `index` of `superstore(config, seed=seed)` with `per_row_seeding=True`.
+///
+/// Args:
+///     index: Row index (0-based)
+///     seed: Random seed (overrides config if provided)
+///     config: Optional SuperstoreConfig pydantic model, dict, or int.
+///
+/// Returns:
+///     The row as a dict.
+#[pyfunction]
+#[pyo3(signature = (index, seed=None, config=None))]
+pub fn row_at(
+    py: Python<'_>,
+    index: usize,
+    seed: Option<u64>,
+    config: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<PyDict>> {
+    let (mut store_config, _) = resolve_superstore_config(config)?;
+    if let Some(s) = seed {
+        store_config.seed = Some(s);
+    }
+    superstore_row_to_pydict(
+        py,
+        &superstore_row_at(&store_config, index),
+        OptionalColumns::for_config(&store_config),
+    )
+}
+
+/// Resolve a superstore config to the values generation actually uses.
+///
+/// User input is merged over the defaults, so this shows every field after
+/// the merge; `effective_config({})` returns the full default config. The
+/// result can be passed back as `config` to reproduce the same data.
+///
+/// Args:
+///     config: Optional SuperstoreConfig pydantic model, dict, or int.
+///
+/// Returns:
+///     The resolved config as a nested dict, including `output`.
+#[pyfunction]
+#[pyo3(signature = (config=None))]
+pub fn effective_config(py: Python<'_>, config: Option<&Bound<'_, PyAny>>) -> PyResult<Py<PyDict>> {
+    let (store_config, output) = resolve_superstore_config(config)?;
+    let json = serde_json::to_string(&store_config)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let resolved = py.import("json")?.call_method1("loads", (json,))?;
+    let resolved = resolved.cast_into::<PyDict>()?;
+    resolved.set_item("output", output)?;
+
+    // Regional preferences are passed in as {region: {category: weight}}
+    let preferences = PyDict::new(py);
+    for pref in &store_config.regional.preferences {
+        let weights = PyDict::new(py);
+        for (category, weight) in &pref.category_weights {
+            weights.set_item(category, weight)?;
+        }
+        preferences.set_item(&pref.region, weights)?;
+    }
+    resolved
+        .get_item("regional")?
+        .expect("regional is always serialized")
+        .set_item("preferences", preferences)?;
+
+    // Categories are passed in as {category: [sub-category, ...]}
+    if let Some(categories) = &store_config.categories {
+        let by_name = PyDict::new(py);
+        for (category, subs) in categories {
+            by_name.set_item(category, subs)?;
+        }
+        resolved.set_item("categories", by_name)?;
+    }
+    // and the taxonomy as {category: {sub-category: [product type, ...]}}
+    if let Some(taxonomy) = &store_config.taxonomy {
+        let by_name = PyDict::new(py);
+        for (category, subs) in taxonomy {
+            let sub_dict = PyDict::new(py);
+            for (sub, types) in subs {
+                sub_dict.set_item(sub, types)?;
+            }
+            by_name.set_item(category, sub_dict)?;
+        }
+        resolved.set_item("taxonomy", by_name)?;
+    }
+    Ok(resolved.unbind())
+}
+
+/// Check a superstore config for settings that generate fine but probably
+/// not as intended, such as a `pool_size` so small relative to `count` that
+/// location columns have low cardinality.
+///
+/// Unlike invalid values, which raise ValueError, these never stop
+/// generation. Sales correlations that aren't a valid correlation matrix
+/// and unsupported locales are included too; `superstore()` reports those
+/// as a UserWarning, but this function only returns them.
+///
+/// Args:
+///     config: Optional SuperstoreConfig pydantic model, dict, or int.
+///
+/// Returns:
+///     A list of warning messages, empty if nothing looks off.
+#[pyfunction]
+#[pyo3(signature = (config=None))]
+pub fn validate_config(config: Option<&Bound<'_, PyAny>>) -> PyResult<Vec<String>> {
+    let (store_config, _) = parse_superstore_config_arg(config)?;
+    Ok(store_config.warnings())
 }
@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
 
 mod arrow;
+mod config;
 mod copulas;
 mod correlation;
 mod crossfilter;
@@ -10,8 +11,10 @@ mod export;
 mod finance;
 mod general;
 mod logs;
+mod output;
 mod parallel;
 mod streaming;
+mod subscriptions;
 mod temporal;
 mod timeseries;
 mod weather;
@@ -21,10 +24,17 @@ fn superstore(m: &Bound<PyModule>) -> PyResult<()> {
     // General module functions
     m.add_function(wrap_pyfunction!(general::py_superstore, m)?)?;
     m.add_function(wrap_pyfunction!(general::py_employees, m)?)?;
+    m.add_function(wrap_pyfunction!(general::with_employees, m)?)?;
+    m.add_function(wrap_pyfunction!(general::with_chargebacks, m)?)?;
+    m.add_function(wrap_pyfunction!(general::batch, m)?)?;
+    m.add_function(wrap_pyfunction!(general::row_at, m)?)?;
+    m.add_function(wrap_pyfunction!(general::effective_config, m)?)?;
+    m.add_function(wrap_pyfunction!(general::validate_config, m)?)?;
 
     // Timeseries module functions
     m.add_function(wrap_pyfunction!(timeseries::py_get_time_series, m)?)?;
     m.add_function(wrap_pyfunction!(timeseries::py_get_time_series_data, m)?)?;
+    m.add_function(wrap_pyfunction!(timeseries::py_indicators, m)?)?;
 
     // Crossfilter module functions
     m.add_function(wrap_pyfunction!(crossfilter::py_machines, m)?)?;
@@ -70,15 +80,24 @@ fn superstore(m: &Bound<PyModule>) -> PyResult<()> {
 
     // Logs module
     m.add_function(wrap_pyfunction!(logs::logs, m)?)?;
+    m.add_function(wrap_pyfunction!(logs::logs_with_deploys, m)?)?;
     m.add_function(wrap_pyfunction!(logs::app_logs, m)?)?;
 
     // Finance module
     m.add_function(wrap_pyfunction!(finance::stock_prices, m)?)?;
     m.add_function(wrap_pyfunction!(finance::options_chain, m)?)?;
     m.add_function(wrap_pyfunction!(finance::finance, m)?)?;
+    m.add_function(wrap_pyfunction!(finance::fix_messages, m)?)?;
+    m.add_function(wrap_pyfunction!(finance::portfolio, m)?)?;
+    m.add_function(wrap_pyfunction!(finance::var_cvar, m)?)?;
+    m.add_function(wrap_pyfunction!(finance::yield_curve, m)?)?;
+    m.add_function(wrap_pyfunction!(finance::credit_defaults, m)?)?;
 
     // Ecommerce module
     ecommerce::register_ecommerce(m)?;
 
+    // Subscriptions module
+    subscriptions::register_subscriptions(m)?;
+
     Ok(())
 }
@@ -1,18 +1,23 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
 use superstore::logs::{
-    generate_app_logs, generate_logs, AppLogEntry, ErrorBurstConfig, LatencyConfig, LogEntry,
-    LogFormat, LogsConfig,
+    generate_app_logs, generate_logs, generate_logs_with_deploys, AppLogEntry, ClientConfig,
+    DeployConfig, DeployEvent, ErrorBurstConfig, LatencyConfig, LogEntry, LogFormat, LogsConfig,
+    TraceConfig, TrafficConfig,
 };
 
-/// Create pandas DataFrame from LogEntry rows
-fn create_logs_pandas(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for LogEntry rows, shared by the pandas and Arrow outputs
+fn logs_columns<'py>(py: Python<'py>, rows: &[LogEntry]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
     let ip_addresses: Vec<&str> = rows.iter().map(|r| r.ip_address.as_str()).collect();
+    let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
+    let cities: Vec<&str> = rows.iter().map(|r| r.city.as_str()).collect();
     let user_ids: Vec<Option<&str>> = rows.iter().map(|r| r.user_id.as_deref()).collect();
     let methods: Vec<&str> = rows.iter().map(|r| r.method.as_str()).collect();
     let paths: Vec<&str> = rows.iter().map(|r| r.path.as_str()).collect();
@@ -20,10 +25,15 @@ fn create_logs_pandas(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>>
     let response_bytes: Vec<u32> = rows.iter().map(|r| r.response_bytes).collect();
     let latency_ms: Vec<f64> = rows.iter().map(|r| r.latency_ms).collect();
     let user_agents: Vec<Option<&str>> = rows.iter().map(|r| r.user_agent.as_deref()).collect();
+    let is_bot: Vec<bool> = rows.iter().map(|r| r.is_bot).collect();
     let referers: Vec<Option<&str>> = rows.iter().map(|r| r.referer.as_deref()).collect();
+    let deploy_ids: Vec<Option<&str>> = rows.iter().map(|r| r.deploy_id.as_deref()).collect();
+    let post_deploy: Vec<bool> = rows.iter().map(|r| r.post_deploy).collect();
 
     data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
     data.set_item("ip_address", PyList::new(py, &ip_addresses)?)?;
+    data.set_item("country", PyList::new(py, &countries)?)?;
+    data.set_item("city", PyList::new(py, &cities)?)?;
     data.set_item("user_id", PyList::new(py, &user_ids)?)?;
     data.set_item("method", PyList::new(py, &methods)?)?;
     data.set_item("path", PyList::new(py, &paths)?)?;
@@ -31,19 +41,63 @@ fn create_logs_pandas(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>>
     data.set_item("response_bytes", PyList::new(py, &response_bytes)?)?;
     data.set_item("latency_ms", PyList::new(py, &latency_ms)?)?;
     data.set_item("user_agent", PyList::new(py, &user_agents)?)?;
+    data.set_item("is_bot", PyList::new(py, &is_bot)?)?;
     data.set_item("referer", PyList::new(py, &referers)?)?;
+    data.set_item("deploy_id", PyList::new(py, &deploy_ids)?)?;
+    data.set_item("post_deploy", PyList::new(py, &post_deploy)?)?;
+
+    Ok(data)
+}
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+/// Create pandas DataFrame from LogEntry rows
+fn create_logs_pandas(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (logs_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from LogEntry rows
+fn create_logs_arrow(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("timestamp", rows.iter().map(|r| r.timestamp.as_str()))
+        .utf8("ip_address", rows.iter().map(|r| r.ip_address.as_str()))
+        .utf8("country", rows.iter().map(|r| r.country.as_str()))
+        .utf8("city", rows.iter().map(|r| r.city.as_str()))
+        .optional_utf8("user_id", rows.iter().map(|r| r.user_id.as_deref()))
+        .utf8("method", rows.iter().map(|r| r.method.as_str()))
+        .utf8("path", rows.iter().map(|r| r.path.as_str()))
+        .int64("status_code", rows.iter().map(|r| i64::from(r.status_code)))
+        .int64(
+            "response_bytes",
+            rows.iter().map(|r| i64::from(r.response_bytes)),
+        )
+        .float64("latency_ms", rows.iter().map(|r| r.latency_ms))
+        .optional_utf8("user_agent", rows.iter().map(|r| r.user_agent.as_deref()))
+        .boolean("is_bot", rows.iter().map(|r| r.is_bot))
+        .optional_utf8("referer", rows.iter().map(|r| r.referer.as_deref()))
+        .optional_utf8("deploy_id", rows.iter().map(|r| r.deploy_id.as_deref()))
+        .boolean("post_deploy", rows.iter().map(|r| r.post_deploy))
+        .into_pyarrow(py)
+}
+
+/// Create pyarrow Table from DeployEvent rows
+fn create_deploys_arrow(py: Python<'_>, rows: &[DeployEvent]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("deploy_id", rows.iter().map(|r| r.deploy_id.as_str()))
+        .utf8("timestamp", rows.iter().map(|r| r.timestamp.as_str()))
+        .utf8("version", rows.iter().map(|r| r.version.as_str()))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from LogEntry rows
 fn create_logs_polars(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
     let ip_addresses: Vec<&str> = rows.iter().map(|r| r.ip_address.as_str()).collect();
+    let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
+    let cities: Vec<&str> = rows.iter().map(|r| r.city.as_str()).collect();
     let user_ids: Vec<Option<&str>> = rows.iter().map(|r| r.user_id.as_deref()).collect();
     let methods: Vec<&str> = rows.iter().map(|r| r.method.as_str()).collect();
     let paths: Vec<&str> = rows.iter().map(|r| r.path.as_str()).collect();
@@ -51,10 +105,15 @@ fn create_logs_polars(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>>
     let response_bytes: Vec<u32> = rows.iter().map(|r| r.response_bytes).collect();
     let latency_ms: Vec<f64> = rows.iter().map(|r| r.latency_ms).collect();
     let user_agents: Vec<Option<&str>> = rows.iter().map(|r| r.user_agent.as_deref()).collect();
+    let is_bot: Vec<bool> = rows.iter().map(|r| r.is_bot).collect();
     let referers: Vec<Option<&str>> = rows.iter().map(|r| r.referer.as_deref()).collect();
+    let deploy_ids: Vec<Option<&str>> = rows.iter().map(|r| r.deploy_id.as_deref()).collect();
+    let post_deploy: Vec<bool> = rows.iter().map(|r| r.post_deploy).collect();
 
     data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
     data.set_item("ip_address", PyList::new(py, &ip_addresses)?)?;
+    data.set_item("country", PyList::new(py, &countries)?)?;
+    data.set_item("city", PyList::new(py, &cities)?)?;
     data.set_item("user_id", PyList::new(py, &user_ids)?)?;
     data.set_item("method", PyList::new(py, &methods)?)?;
     data.set_item("path", PyList::new(py, &paths)?)?;
@@ -62,7 +121,10 @@ fn create_logs_polars(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>>
     data.set_item("response_bytes", PyList::new(py, &response_bytes)?)?;
     data.set_item("latency_ms", PyList::new(py, &latency_ms)?)?;
     data.set_item("user_agent", PyList::new(py, &user_agents)?)?;
+    data.set_item("is_bot", PyList::new(py, &is_bot)?)?;
     data.set_item("referer", PyList::new(py, &referers)?)?;
+    data.set_item("deploy_id", PyList::new(py, &deploy_ids)?)?;
+    data.set_item("post_deploy", PyList::new(py, &post_deploy)?)?;
 
     let df = polars.call_method1("DataFrame", (data,))?;
     Ok(df.into())
@@ -74,6 +136,8 @@ fn create_logs_dict(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
     let ip_addresses: Vec<&str> = rows.iter().map(|r| r.ip_address.as_str()).collect();
+    let countries: Vec<&str> = rows.iter().map(|r| r.country.as_str()).collect();
+    let cities: Vec<&str> = rows.iter().map(|r| r.city.as_str()).collect();
     let user_ids: Vec<Option<&str>> = rows.iter().map(|r| r.user_id.as_deref()).collect();
     let methods: Vec<&str> = rows.iter().map(|r| r.method.as_str()).collect();
     let paths: Vec<&str> = rows.iter().map(|r| r.path.as_str()).collect();
@@ -81,10 +145,15 @@ fn create_logs_dict(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
     let response_bytes: Vec<u32> = rows.iter().map(|r| r.response_bytes).collect();
     let latency_ms: Vec<f64> = rows.iter().map(|r| r.latency_ms).collect();
     let user_agents: Vec<Option<&str>> = rows.iter().map(|r| r.user_agent.as_deref()).collect();
+    let is_bot: Vec<bool> = rows.iter().map(|r| r.is_bot).collect();
     let referers: Vec<Option<&str>> = rows.iter().map(|r| r.referer.as_deref()).collect();
+    let deploy_ids: Vec<Option<&str>> = rows.iter().map(|r| r.deploy_id.as_deref()).collect();
+    let post_deploy: Vec<bool> = rows.iter().map(|r| r.post_deploy).collect();
 
     data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
     data.set_item("ip_address", PyList::new(py, &ip_addresses)?)?;
+    data.set_item("country", PyList::new(py, &countries)?)?;
+    data.set_item("city", PyList::new(py, &cities)?)?;
     data.set_item("user_id", PyList::new(py, &user_ids)?)?;
     data.set_item("method", PyList::new(py, &methods)?)?;
     data.set_item("path", PyList::new(py, &paths)?)?;
@@ -92,14 +161,31 @@ fn create_logs_dict(py: Python<'_>, rows: &[LogEntry]) -> PyResult<Py<PyAny>> {
     data.set_item("response_bytes", PyList::new(py, &response_bytes)?)?;
     data.set_item("latency_ms", PyList::new(py, &latency_ms)?)?;
     data.set_item("user_agent", PyList::new(py, &user_agents)?)?;
+    data.set_item("is_bot", PyList::new(py, &is_bot)?)?;
     data.set_item("referer", PyList::new(py, &referers)?)?;
+    data.set_item("deploy_id", PyList::new(py, &deploy_ids)?)?;
+    data.set_item("post_deploy", PyList::new(py, &post_deploy)?)?;
 
     Ok(data.into())
 }
 
-/// Create pandas DataFrame from AppLogEntry rows
-fn create_app_logs_pandas(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Create dict of columns from DeployEvent rows
+fn create_deploys_dict<'py>(py: Python<'py>, rows: &[DeployEvent]) -> PyResult<Bound<'py, PyDict>> {
+    let data = PyDict::new(py);
+
+    let deploy_ids: Vec<&str> = rows.iter().map(|r| r.deploy_id.as_str()).collect();
+    let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
+    let versions: Vec<&str> = rows.iter().map(|r| r.version.as_str()).collect();
+
+    data.set_item("deploy_id", PyList::new(py, &deploy_ids)?)?;
+    data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
+    data.set_item("version", PyList::new(py, &versions)?)?;
+
+    Ok(data)
+}
+
+/// Build the column dict for AppLogEntry rows, shared by the pandas and Arrow outputs
+fn app_logs_columns<'py>(py: Python<'py>, rows: &[AppLogEntry]) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
@@ -107,8 +193,12 @@ fn create_app_logs_pandas(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<P
     let loggers: Vec<&str> = rows.iter().map(|r| r.logger.as_str()).collect();
     let messages: Vec<&str> = rows.iter().map(|r| r.message.as_str()).collect();
     let thread_ids: Vec<u32> = rows.iter().map(|r| r.thread_id).collect();
+    let services: Vec<&str> = rows.iter().map(|r| r.service.as_str()).collect();
     let trace_ids: Vec<Option<&str>> = rows.iter().map(|r| r.trace_id.as_deref()).collect();
     let span_ids: Vec<Option<&str>> = rows.iter().map(|r| r.span_id.as_deref()).collect();
+    let parent_span_ids: Vec<Option<&str>> =
+        rows.iter().map(|r| r.parent_span_id.as_deref()).collect();
+    let durations: Vec<Option<f64>> = rows.iter().map(|r| r.duration_ms).collect();
     let exceptions: Vec<Option<&str>> = rows.iter().map(|r| r.exception.as_deref()).collect();
 
     data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
@@ -116,17 +206,46 @@ fn create_app_logs_pandas(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<P
     data.set_item("logger", PyList::new(py, &loggers)?)?;
     data.set_item("message", PyList::new(py, &messages)?)?;
     data.set_item("thread_id", PyList::new(py, &thread_ids)?)?;
+    data.set_item("service", PyList::new(py, &services)?)?;
     data.set_item("trace_id", PyList::new(py, &trace_ids)?)?;
     data.set_item("span_id", PyList::new(py, &span_ids)?)?;
+    data.set_item("parent_span_id", PyList::new(py, &parent_span_ids)?)?;
+    data.set_item("duration_ms", PyList::new(py, &durations)?)?;
     data.set_item("exception", PyList::new(py, &exceptions)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from AppLogEntry rows
+fn create_app_logs_pandas(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (app_logs_columns(py, rows)?,))?;
     Ok(df.into())
 }
 
+/// Create pyarrow Table from AppLogEntry rows
+fn create_app_logs_arrow(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("timestamp", rows.iter().map(|r| r.timestamp.as_str()))
+        .utf8("level", rows.iter().map(|r| r.level.as_str()))
+        .utf8("logger", rows.iter().map(|r| r.logger.as_str()))
+        .utf8("message", rows.iter().map(|r| r.message.as_str()))
+        .int64("thread_id", rows.iter().map(|r| i64::from(r.thread_id)))
+        .utf8("service", rows.iter().map(|r| r.service.as_str()))
+        .optional_utf8("trace_id", rows.iter().map(|r| r.trace_id.as_deref()))
+        .optional_utf8("span_id", rows.iter().map(|r| r.span_id.as_deref()))
+        .optional_utf8(
+            "parent_span_id",
+            rows.iter().map(|r| r.parent_span_id.as_deref()),
+        )
+        .optional_float64("duration_ms", rows.iter().map(|r| r.duration_ms))
+        .optional_utf8("exception", rows.iter().map(|r| r.exception.as_deref()))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from AppLogEntry rows
 fn create_app_logs_polars(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = rows.iter().map(|r| r.timestamp.as_str()).collect();
@@ -134,8 +253,12 @@ fn create_app_logs_polars(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<P
     let loggers: Vec<&str> = rows.iter().map(|r| r.logger.as_str()).collect();
     let messages: Vec<&str> = rows.iter().map(|r| r.message.as_str()).collect();
     let thread_ids: Vec<u32> = rows.iter().map(|r| r.thread_id).collect();
+    let services: Vec<&str> = rows.iter().map(|r| r.service.as_str()).collect();
     let trace_ids: Vec<Option<&str>> = rows.iter().map(|r| r.trace_id.as_deref()).collect();
     let span_ids: Vec<Option<&str>> = rows.iter().map(|r| r.span_id.as_deref()).collect();
+    let parent_span_ids: Vec<Option<&str>> =
+        rows.iter().map(|r| r.parent_span_id.as_deref()).collect();
+    let durations: Vec<Option<f64>> = rows.iter().map(|r| r.duration_ms).collect();
     let exceptions: Vec<Option<&str>> = rows.iter().map(|r| r.exception.as_deref()).collect();
 
     data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
@@ -143,8 +266,11 @@ fn create_app_logs_polars(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<P
     data.set_item("logger", PyList::new(py, &loggers)?)?;
     data.set_item("message", PyList::new(py, &messages)?)?;
     data.set_item("thread_id", PyList::new(py, &thread_ids)?)?;
+    data.set_item("service", PyList::new(py, &services)?)?;
     data.set_item("trace_id", PyList::new(py, &trace_ids)?)?;
     data.set_item("span_id", PyList::new(py, &span_ids)?)?;
+    data.set_item("parent_span_id", PyList::new(py, &parent_span_ids)?)?;
+    data.set_item("duration_ms", PyList::new(py, &durations)?)?;
     data.set_item("exception", PyList::new(py, &exceptions)?)?;
 
     let df = polars.call_method1("DataFrame", (data,))?;
@@ -160,8 +286,12 @@ fn create_app_logs_dict(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<PyA
     let loggers: Vec<&str> = rows.iter().map(|r| r.logger.as_str()).collect();
     let messages: Vec<&str> = rows.iter().map(|r| r.message.as_str()).collect();
     let thread_ids: Vec<u32> = rows.iter().map(|r| r.thread_id).collect();
+    let services: Vec<&str> = rows.iter().map(|r| r.service.as_str()).collect();
     let trace_ids: Vec<Option<&str>> = rows.iter().map(|r| r.trace_id.as_deref()).collect();
     let span_ids: Vec<Option<&str>> = rows.iter().map(|r| r.span_id.as_deref()).collect();
+    let parent_span_ids: Vec<Option<&str>> =
+        rows.iter().map(|r| r.parent_span_id.as_deref()).collect();
+    let durations: Vec<Option<f64>> = rows.iter().map(|r| r.duration_ms).collect();
     let exceptions: Vec<Option<&str>> = rows.iter().map(|r| r.exception.as_deref()).collect();
 
     data.set_item("timestamp", PyList::new(py, &timestamps)?)?;
@@ -169,8 +299,11 @@ fn create_app_logs_dict(py: Python<'_>, rows: &[AppLogEntry]) -> PyResult<Py<PyA
     data.set_item("logger", PyList::new(py, &loggers)?)?;
     data.set_item("message", PyList::new(py, &messages)?)?;
     data.set_item("thread_id", PyList::new(py, &thread_ids)?)?;
+    data.set_item("service", PyList::new(py, &services)?)?;
     data.set_item("trace_id", PyList::new(py, &trace_ids)?)?;
     data.set_item("span_id", PyList::new(py, &span_ids)?)?;
+    data.set_item("parent_span_id", PyList::new(py, &parent_span_ids)?)?;
+    data.set_item("duration_ms", PyList::new(py, &durations)?)?;
     data.set_item("exception", PyList::new(py, &exceptions)?)?;
 
     Ok(data.into())
@@ -249,6 +382,18 @@ fn parse_logs_config(dict: &Bound<'_, PyDict>) -> PyResult<(LogsConfig, String)>
         .transpose()?
         .unwrap_or(0.7);
 
+    let endpoint_zipf_exponent: f64 = dict
+        .get_item("endpoint_zipf_exponent")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(1.0);
+
+    let not_modified_rate: f64 = dict
+        .get_item("not_modified_rate")?
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or(0.3);
+
     // Parse nested ErrorBurstConfig
     let error_burst = if let Some(eb_val) = dict.get_item("error_burst")? {
         if let Ok(eb_dict) = eb_val.downcast::<PyDict>() {
@@ -285,6 +430,154 @@ fn parse_logs_config(dict: &Bound<'_, PyDict>) -> PyResult<(LogsConfig, String)>
         ErrorBurstConfig::default()
     };
 
+    // Parse nested ClientConfig
+    let clients = if let Some(cl_val) = dict.get_item("clients")? {
+        if let Ok(cl_dict) = cl_val.cast::<PyDict>() {
+            let defaults = ClientConfig::default();
+            let ipv6_ratio: f64 = cl_dict
+                .get_item("ipv6_ratio")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.ipv6_ratio);
+            let bot_traffic_share: f64 = cl_dict
+                .get_item("bot_traffic_share")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.bot_traffic_share);
+            let bot_ips_per_range: usize = cl_dict
+                .get_item("bot_ips_per_range")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.bot_ips_per_range);
+            let bot_client_error_rate: f64 = cl_dict
+                .get_item("bot_client_error_rate")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.bot_client_error_rate);
+            ClientConfig {
+                ipv6_ratio,
+                bot_traffic_share,
+                bot_ips_per_range,
+                bot_client_error_rate,
+            }
+        } else {
+            ClientConfig::default()
+        }
+    } else {
+        ClientConfig::default()
+    };
+
+    // Parse nested TrafficConfig
+    let traffic = if let Some(tf_val) = dict.get_item("traffic")? {
+        if let Ok(tf_dict) = tf_val.cast::<PyDict>() {
+            let defaults = TrafficConfig::default();
+            let enable: bool = tf_dict
+                .get_item("enable")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.enable);
+            let peak_hour: f64 = tf_dict
+                .get_item("peak_hour")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.peak_hour);
+            let peak_to_trough: f64 = tf_dict
+                .get_item("peak_to_trough")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.peak_to_trough);
+            let spikes_per_day: f64 = tf_dict
+                .get_item("spikes_per_day")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.spikes_per_day);
+            let spike_duration_seconds: u32 = tf_dict
+                .get_item("spike_duration_seconds")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.spike_duration_seconds);
+            let spike_multiplier: f64 = tf_dict
+                .get_item("spike_multiplier")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.spike_multiplier);
+            TrafficConfig {
+                enable,
+                peak_hour,
+                peak_to_trough,
+                spikes_per_day,
+                spike_duration_seconds,
+                spike_multiplier,
+            }
+        } else {
+            TrafficConfig::default()
+        }
+    } else {
+        TrafficConfig::default()
+    };
+
+    // Parse nested DeployConfig
+    let deploys = if let Some(dep_val) = dict.get_item("deploys")? {
+        if let Ok(dep_dict) = dep_val.cast::<PyDict>() {
+            let defaults = DeployConfig::default();
+            let enable: bool = dep_dict
+                .get_item("enable")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.enable);
+            let deploys_per_hour: f64 = dep_dict
+                .get_item("deploys_per_hour")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.deploys_per_hour);
+            let impact_duration_seconds: u32 = dep_dict
+                .get_item("impact_duration_seconds")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.impact_duration_seconds);
+            let post_deploy_error_rate: f64 = dep_dict
+                .get_item("post_deploy_error_rate")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.post_deploy_error_rate);
+            DeployConfig {
+                enable,
+                deploys_per_hour,
+                impact_duration_seconds,
+                post_deploy_error_rate,
+            }
+        } else {
+            DeployConfig::default()
+        }
+    } else {
+        DeployConfig::default()
+    };
+
+    // Parse nested TraceConfig
+    let traces = if let Some(tr_val) = dict.get_item("traces")? {
+        if let Ok(tr_dict) = tr_val.cast::<PyDict>() {
+            let defaults = TraceConfig::default();
+            let max_depth: usize = tr_dict
+                .get_item("max_depth")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.max_depth);
+            let max_children: usize = tr_dict
+                .get_item("max_children")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(defaults.max_children);
+            TraceConfig {
+                max_depth,
+                max_children,
+            }
+        } else {
+            TraceConfig::default()
+        }
+    } else {
+        TraceConfig::default()
+    };
+
     // Parse nested LatencyConfig
     let latency = if let Some(lat_val) = dict.get_item("latency")? {
         if let Ok(lat_dict) = lat_val.downcast::<PyDict>() {
@@ -308,11 +601,23 @@ fn parse_logs_config(dict: &Bound<'_, PyDict>) -> PyResult<(LogsConfig, String)>
                 .map(|v| v.extract())
                 .transpose()?
                 .unwrap_or(10.0);
+            let server_error_multiplier: f64 = lat_dict
+                .get_item("server_error_multiplier")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(5.0);
+            let not_modified_multiplier: f64 = lat_dict
+                .get_item("not_modified_multiplier")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or(0.2);
             LatencyConfig {
                 base_latency_ms,
                 latency_stddev,
                 slow_request_probability,
                 slow_request_multiplier,
+                server_error_multiplier,
+                not_modified_multiplier,
             }
         } else {
             LatencyConfig::default()
@@ -327,14 +632,20 @@ fn parse_logs_config(dict: &Bound<'_, PyDict>) -> PyResult<(LogsConfig, String)>
         format,
         start_time,
         requests_per_second,
+        traffic,
         success_rate,
         error_burst,
+        deploys,
+        traces,
         latency,
         include_user_agent,
         include_referer,
         unique_ips,
         unique_users,
         api_path_ratio,
+        endpoint_zipf_exponent,
+        not_modified_rate,
+        clients,
     };
 
     Ok((config, output))
@@ -352,26 +663,78 @@ fn parse_logs_config(dict: &Bound<'_, PyDict>) -> PyResult<(LogsConfig, String)>
 /// * DataFrame (pandas/polars) or dict of log entries
 #[pyfunction]
 #[pyo3(signature = (config=None))]
-pub fn logs(py: Python<'_>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Py<PyAny>> {
-    let (logs_config, output_format) = if let Some(dict) = config {
-        parse_logs_config(dict)?
+pub fn logs(py: Python<'_>, config: Option<&Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+    let (logs_config, output_format) = if let Some(cfg) = config {
+        parse_logs_config(&config_dict(cfg, "a LogsConfig, dict, or None")?)?
     } else {
         (LogsConfig::default(), "pandas".to_string())
     };
 
-    let rows = generate_logs(&logs_config);
+    let rows = py.detach(|| generate_logs(&logs_config));
 
     match output_format.to_lowercase().as_str() {
         "polars" => create_logs_polars(py, &rows),
         "dict" => create_logs_dict(py, &rows),
-        _ => create_logs_pandas(py, &rows),
+        "arrow" => create_logs_arrow(py, &rows),
+        "pandas" => create_logs_pandas(py, &rows),
+        _ => Err(invalid_output(&output_format, TABLE_OUTPUTS)),
+    }
+}
+
+/// Generate web server access logs together with their deploy events.
+///
+/// Same as `logs()`, with the deploy sub-stream returned alongside. Enable it
+/// with `deploys={"enable": True}` in the config; requests in the minutes after
+/// each deploy see a decaying spike in 5xx errors and are marked `post_deploy`.
+///
+/// # Arguments
+/// * `config` - Optional LogsConfig or dict with generation parameters
+///
+/// # Returns
+/// * Dict with "logs" and "deploys" DataFrames (pandas/polars) or dicts
+#[pyfunction]
+#[pyo3(signature = (config=None))]
+pub fn logs_with_deploys(py: Python<'_>, config: Option<&Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+    let (logs_config, output_format) = if let Some(cfg) = config {
+        parse_logs_config(&config_dict(cfg, "a LogsConfig, dict, or None")?)?
+    } else {
+        (LogsConfig::default(), "pandas".to_string())
+    };
+
+    let (rows, deploy_rows) = py.detach(|| generate_logs_with_deploys(&logs_config));
+    let deploys = create_deploys_dict(py, &deploy_rows)?;
+
+    let result = PyDict::new(py);
+    match output_format.to_lowercase().as_str() {
+        "polars" => {
+            result.set_item("logs", create_logs_polars(py, &rows)?)?;
+            let polars = import_output_library(py, "polars")?;
+            result.set_item("deploys", polars.call_method1("DataFrame", (deploys,))?)?;
+        }
+        "dict" => {
+            result.set_item("logs", create_logs_dict(py, &rows)?)?;
+            result.set_item("deploys", deploys)?;
+        }
+        "arrow" => {
+            result.set_item("logs", create_logs_arrow(py, &rows)?)?;
+            result.set_item("deploys", create_deploys_arrow(py, &deploy_rows)?)?;
+        }
+        "pandas" => {
+            result.set_item("logs", create_logs_pandas(py, &rows)?)?;
+            let pandas = import_output_library(py, "pandas")?;
+            result.set_item("deploys", pandas.call_method1("DataFrame", (deploys,))?)?;
+        }
+        _ => return Err(invalid_output(&output_format, TABLE_OUTPUTS)),
     }
+    Ok(result.into())
 }
 
 /// Generate application event logs.
 ///
 /// Returns application-level log entries with log levels, loggers, messages,
-/// thread IDs, trace/span IDs, and optional exceptions.
+/// thread IDs, and optional exceptions. Each request is a distributed trace:
+/// one line per span, across services, sharing a trace ID and linked to its
+/// parent span, with the span's duration.
 ///
 /// # Arguments
 /// * `config` - Optional LogsConfig or dict with generation parameters
@@ -380,18 +743,20 @@ pub fn logs(py: Python<'_>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Py<P
 /// * DataFrame (pandas/polars) or dict of application log entries
 #[pyfunction]
 #[pyo3(signature = (config=None))]
-pub fn app_logs(py: Python<'_>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Py<PyAny>> {
-    let (logs_config, output_format) = if let Some(dict) = config {
-        parse_logs_config(dict)?
+pub fn app_logs(py: Python<'_>, config: Option<&Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+    let (logs_config, output_format) = if let Some(cfg) = config {
+        parse_logs_config(&config_dict(cfg, "a LogsConfig, dict, or None")?)?
     } else {
         (LogsConfig::default(), "pandas".to_string())
     };
 
-    let rows = generate_app_logs(&logs_config);
+    let rows = py.detach(|| generate_app_logs(&logs_config));
 
     match output_format.to_lowercase().as_str() {
         "polars" => create_app_logs_polars(py, &rows),
         "dict" => create_app_logs_dict(py, &rows),
-        _ => create_app_logs_pandas(py, &rows),
+        "arrow" => create_app_logs_arrow(py, &rows),
+        "pandas" => create_app_logs_pandas(py, &rows),
+        _ => Err(invalid_output(&output_format, TABLE_OUTPUTS)),
     }
 }
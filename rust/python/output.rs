@@ -0,0 +1,49 @@
+//! Output format helpers shared by the generator bindings.
+
+use pyo3::exceptions::{PyImportError, PyValueError};
+use pyo3::prelude::*;
+
+/// Output formats of the tabular generators
+pub const TABLE_OUTPUTS: &[&str] = &["pandas", "polars", "dict", "arrow"];
+
+/// Python package backing an output format
+fn output_package(output: &str) -> &str {
+    match output {
+        "arrow" => "pyarrow",
+        other => other,
+    }
+}
+
+/// Import the package backing `output` ("pandas", "polars", ...).
+///
+/// A missing package becomes an ImportError naming the output format and the
+/// `pip install` command, instead of a bare "No module named ..." raised from
+/// deep inside a generator.
+pub fn import_output_library<'py>(py: Python<'py>, output: &str) -> PyResult<Bound<'py, PyModule>> {
+    let package = output_package(output);
+    py.import(package).map_err(|err| {
+        if err.is_instance_of::<PyImportError>(py) {
+            PyImportError::new_err(format!(
+                "output=\"{}\" requires the '{}' package, which is not installed. \
+                 Install it with `pip install {}`, or use output=\"dict\".",
+                output, package, package
+            ))
+        } else {
+            err
+        }
+    })
+}
+
+/// ValueError for an unrecognized `output`, listing the formats accepted
+pub fn invalid_output(output: &str, valid: &[&str]) -> PyErr {
+    let quoted: Vec<String> = valid.iter().map(|v| format!("'{}'", v)).collect();
+    let options = match quoted.split_last() {
+        Some((last, rest)) if rest.len() > 1 => format!("{}, or {}", rest.join(", "), last),
+        Some((last, [first])) => format!("{} or {}", first, last),
+        _ => quoted.join(""),
+    };
+    PyValueError::new_err(format!(
+        "Invalid output format '{}'. Must be {}",
+        output, options
+    ))
+}
@@ -1,9 +1,18 @@
 //! Python bindings for parallel data generation.
 
+use crate::arrow::ArrowTable;
+use crate::general::{create_employees_arrow, superstore_arrow_names};
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
-use superstore::parallel::{employees_parallel, superstore_parallel};
+use superstore::arrow::superstore_to_arrow_with_columns;
+use superstore::general::OptionalColumns;
+use superstore::parallel::{
+    employees_parallel, employees_parallel_with_threads, superstore_parallel,
+    superstore_parallel_with_threads,
+};
+use superstore::rng::{rng_algorithm, set_rng_algorithm, RngAlgorithm};
 
 fn superstore_row_to_pydict<'py>(
     py: Python<'py>,
@@ -14,16 +23,16 @@ fn superstore_row_to_pydict<'py>(
     dict.set_item("Order ID", &row.order_id).unwrap();
     dict.set_item("Order Date", &row.order_date).unwrap();
     dict.set_item("Ship Date", &row.ship_date).unwrap();
-    dict.set_item("Ship Mode", &row.ship_mode).unwrap();
+    dict.set_item("Ship Mode", &*row.ship_mode).unwrap();
     dict.set_item("Customer ID", &row.customer_id).unwrap();
-    dict.set_item("Segment", &row.segment).unwrap();
-    dict.set_item("Country", &row.country).unwrap();
+    dict.set_item("Segment", &*row.segment).unwrap();
+    dict.set_item("Country", &*row.country).unwrap();
     dict.set_item("City", &row.city).unwrap();
     dict.set_item("State", &row.state).unwrap();
     dict.set_item("Postal Code", &row.postal_code).unwrap();
-    dict.set_item("Region", &row.region).unwrap();
+    dict.set_item("Region", &*row.region).unwrap();
     dict.set_item("Product ID", &row.product_id).unwrap();
-    dict.set_item("Category", &row.category).unwrap();
+    dict.set_item("Category", &*row.category).unwrap();
     dict.set_item("Sub-Category", &row.sub_category).unwrap();
     dict.set_item("Sales", row.sales).unwrap();
     dict.set_item("Quantity", row.quantity).unwrap();
@@ -32,6 +41,21 @@ fn superstore_row_to_pydict<'py>(
     dict
 }
 
+/// Create pyarrow Table from superstore rows, with the same columns as the
+/// dict output
+fn create_superstore_arrow(
+    py: Python<'_>,
+    rows: &[superstore::SuperstoreRow],
+) -> PyResult<Py<PyAny>> {
+    let columns = OptionalColumns::default();
+    let batch = superstore_to_arrow_with_columns(rows, columns)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    let names = superstore_arrow_names(columns);
+    ArrowTable::new()
+        .batch_columns(&batch, 0..names.len(), &names)?
+        .into_pyarrow(py)
+}
+
 fn employee_row_to_pydict<'py>(
     py: Python<'py>,
     row: &superstore::EmployeeRow,
@@ -59,6 +83,17 @@ fn employee_row_to_pydict<'py>(
         row.date_of_birth.format("%Y-%m-%d").to_string(),
     )
     .unwrap();
+    dict.set_item("Department", &row.department).unwrap();
+    dict.set_item("Job Title", &row.job_title).unwrap();
+    dict.set_item("Salary", row.salary).unwrap();
+    dict.set_item("Manager ID", row.manager_id.as_deref())
+        .unwrap();
+    dict.set_item(
+        "Termination Date",
+        row.termination_date
+            .map(|d| d.format("%Y-%m-%d").to_string()),
+    )
+    .unwrap();
     dict
 }
 
@@ -69,23 +104,34 @@ fn employee_row_to_pydict<'py>(
 ///
 /// Args:
 ///     count: Number of rows to generate
-///     output: Output format - "pandas", "polars", or "dict" (default: "pandas")
-///     seed: Optional seed for reproducibility
+///     output: Output format - "pandas", "polars", "dict", or "arrow" (default: "pandas")
+///     seed: Optional seed for reproducibility, independent of the thread count
+///     num_threads: Threads for this call only, on a dedicated pool (default: the global pool)
 ///
 /// Returns:
 ///     DataFrame or list of dicts depending on output format
 ///
+/// Raises:
+///     RuntimeError: If the thread pool cannot be built
+///
 /// Example:
 ///     >>> df = superstoreParallel(1_000_000)  # Uses all CPU cores
+///     >>> df = superstoreParallel(1_000_000, num_threads=4)
 #[pyfunction]
-#[pyo3(name = "superstoreParallel", signature = (count=1000, output="pandas", seed=None))]
+#[pyo3(name = "superstoreParallel", signature = (count=1000, output="pandas", seed=None, num_threads=None))]
 pub fn py_superstore_parallel(
     py: Python<'_>,
     count: usize,
     output: &str,
     seed: Option<u64>,
+    num_threads: Option<usize>,
 ) -> PyResult<Py<PyAny>> {
-    let rows = superstore_parallel(count, seed);
+    let rows = py
+        .detach(|| match num_threads {
+            Some(n) => superstore_parallel_with_threads(count, seed, n),
+            None => Ok(superstore_parallel(count, seed)),
+        })
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
 
     match output {
         "dict" => {
@@ -96,7 +142,7 @@ pub fn py_superstore_parallel(
             Ok(list.into_any().into())
         }
         "polars" => {
-            let polars = py.import("polars")?;
+            let polars = import_output_library(py, "polars")?;
             let list = PyList::empty(py);
             for row in &rows {
                 list.append(superstore_row_to_pydict(py, row))?;
@@ -104,9 +150,8 @@ pub fn py_superstore_parallel(
             let df = polars.call_method1("DataFrame", (list,))?;
             Ok(df.into())
         }
-        _ => {
-            // Default to pandas
-            let pandas = py.import("pandas")?;
+        "pandas" => {
+            let pandas = import_output_library(py, "pandas")?;
             let list = PyList::empty(py);
             for row in &rows {
                 list.append(superstore_row_to_pydict(py, row))?;
@@ -114,6 +159,8 @@ pub fn py_superstore_parallel(
             let df = pandas.call_method1("DataFrame", (list,))?;
             Ok(df.into())
         }
+        "arrow" => create_superstore_arrow(py, &rows),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 }
 
@@ -124,23 +171,34 @@ pub fn py_superstore_parallel(
 ///
 /// Args:
 ///     count: Number of employees to generate
-///     output: Output format - "pandas", "polars", or "dict" (default: "pandas")
-///     seed: Optional seed for reproducibility
+///     output: Output format - "pandas", "polars", "dict", or "arrow" (default: "pandas")
+///     seed: Optional seed for reproducibility, independent of the thread count
+///     num_threads: Threads for this call only, on a dedicated pool (default: the global pool)
 ///
 /// Returns:
 ///     DataFrame or list of dicts depending on output format
 ///
+/// Raises:
+///     RuntimeError: If the thread pool cannot be built
+///
 /// Example:
 ///     >>> df = employeesParallel(1_000_000)  # Uses all CPU cores
+///     >>> df = employeesParallel(1_000_000, num_threads=4)
 #[pyfunction]
-#[pyo3(name = "employeesParallel", signature = (count=1000, output="pandas", seed=None))]
+#[pyo3(name = "employeesParallel", signature = (count=1000, output="pandas", seed=None, num_threads=None))]
 pub fn py_employees_parallel(
     py: Python<'_>,
     count: usize,
     output: &str,
     seed: Option<u64>,
+    num_threads: Option<usize>,
 ) -> PyResult<Py<PyAny>> {
-    let rows = employees_parallel(count, seed);
+    let rows = py
+        .detach(|| match num_threads {
+            Some(n) => employees_parallel_with_threads(count, seed, n),
+            None => Ok(employees_parallel(count, seed)),
+        })
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
 
     match output {
         "dict" => {
@@ -151,7 +209,7 @@ pub fn py_employees_parallel(
             Ok(list.into_any().into())
         }
         "polars" => {
-            let polars = py.import("polars")?;
+            let polars = import_output_library(py, "polars")?;
             let list = PyList::empty(py);
             for row in &rows {
                 list.append(employee_row_to_pydict(py, row))?;
@@ -159,9 +217,8 @@ pub fn py_employees_parallel(
             let df = polars.call_method1("DataFrame", (list,))?;
             Ok(df.into())
         }
-        _ => {
-            // Default to pandas
-            let pandas = py.import("pandas")?;
+        "pandas" => {
+            let pandas = import_output_library(py, "pandas")?;
             let list = PyList::empty(py);
             for row in &rows {
                 list.append(employee_row_to_pydict(py, row))?;
@@ -169,6 +226,8 @@ pub fn py_employees_parallel(
             let df = pandas.call_method1("DataFrame", (list,))?;
             Ok(df.into())
         }
+        "arrow" => create_employees_arrow(py, &rows),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 }
 
@@ -185,7 +244,8 @@ pub fn py_num_threads() -> usize {
 /// Set the number of threads for parallel operations.
 ///
 /// This should be called early in the program before any parallel operations.
-/// Once set, it cannot be changed.
+/// Once set, it cannot be changed; pass `num_threads` to `superstoreParallel`
+/// or `employeesParallel` to use a different count for a single call.
 ///
 /// Args:
 ///     num_threads: Number of threads to use for parallel generation
@@ -201,10 +261,10 @@ pub fn py_set_num_threads(num_threads: usize) -> PyResult<()> {
 
 /// Enable deterministic mode for reproducible results across platforms.
 ///
-/// This sets a fixed number of threads (default: 1) to ensure that
-/// parallel operations produce identical results regardless of the
-/// number of CPU cores available. Combined with a seed, this guarantees
-/// reproducible outputs.
+/// This sets a fixed number of threads (default: 1) for the global pool.
+/// Seeded parallel generators already give identical results for any
+/// thread count, since every row has its own seed. Pinning the pool keeps
+/// any other Rayon work in the process deterministic too.
 ///
 /// Args:
 ///     num_threads: Number of threads to use (default: 1 for maximum determinism)
@@ -225,11 +285,55 @@ pub fn py_set_deterministic_mode(num_threads: usize) -> PyResult<()> {
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
 }
 
+/// Select the PRNG algorithm used by all generators.
+///
+/// The default ("std") is rand's StdRng, whose output for a given seed may
+/// change when the underlying rand crate is upgraded. "pcg64" pins a PCG64
+/// generator implemented in superstore itself, so the random bit stream for
+/// a seed stays the same across dependency upgrades. Values sampled from that
+/// stream (ranges, shuffles, distributions) can still change if rand changes
+/// its sampling algorithms. Applies to generators created after the call.
+///
+/// Args:
+///     algorithm: "std" or "pcg64"
+///
+/// Raises:
+///     ValueError: If the algorithm name is unknown
+///
+/// Example:
+///     >>> import superstore
+///     >>> superstore.setRngAlgorithm("pcg64")
+///     >>> df = superstore.superstore(count=1000, seed=42)
+#[pyfunction]
+#[pyo3(name = "setRngAlgorithm")]
+pub fn py_set_rng_algorithm(algorithm: &str) -> PyResult<()> {
+    let algorithm = RngAlgorithm::parse(algorithm).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown RNG algorithm '{}'. Must be 'std' or 'pcg64'",
+            algorithm
+        ))
+    })?;
+    set_rng_algorithm(algorithm);
+    Ok(())
+}
+
+/// Get the name of the PRNG algorithm used by all generators.
+///
+/// Returns:
+///     "std" or "pcg64"
+#[pyfunction]
+#[pyo3(name = "rngAlgorithm")]
+pub fn py_rng_algorithm() -> &'static str {
+    rng_algorithm().as_str()
+}
+
 pub fn register_parallel(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_superstore_parallel, m)?)?;
     m.add_function(wrap_pyfunction!(py_employees_parallel, m)?)?;
     m.add_function(wrap_pyfunction!(py_num_threads, m)?)?;
     m.add_function(wrap_pyfunction!(py_set_num_threads, m)?)?;
     m.add_function(wrap_pyfunction!(py_set_deterministic_mode, m)?)?;
+    m.add_function(wrap_pyfunction!(py_set_rng_algorithm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_rng_algorithm, m)?)?;
     Ok(())
 }
@@ -1,9 +1,16 @@
 //! Python bindings for streaming data generation.
 
+use crate::general::{employees_output, resolve_superstore_config, superstore_output};
+use crate::output::{invalid_output, TABLE_OUTPUTS};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
-use superstore::streaming::{employees_stream, superstore_stream};
+use superstore::quantiles::stream_quantiles;
+use superstore::streaming::{
+    employees_stream, kafka_partition, superstore_stream, superstore_stream_with_config,
+    EmployeeIterator, SuperstoreConfigIterator,
+};
+use superstore::SuperstoreConfig;
 
 fn superstore_row_to_pydict<'py>(
     py: Python<'py>,
@@ -14,17 +21,20 @@ fn superstore_row_to_pydict<'py>(
     dict.set_item("Order ID", &row.order_id).unwrap();
     dict.set_item("Order Date", &row.order_date).unwrap();
     dict.set_item("Ship Date", &row.ship_date).unwrap();
-    dict.set_item("Ship Mode", &row.ship_mode).unwrap();
+    dict.set_item("Ship Mode", &*row.ship_mode).unwrap();
     dict.set_item("Customer ID", &row.customer_id).unwrap();
-    dict.set_item("Segment", &row.segment).unwrap();
-    dict.set_item("Country", &row.country).unwrap();
+    dict.set_item("Segment", &*row.segment).unwrap();
+    dict.set_item("Country", &*row.country).unwrap();
     dict.set_item("City", &row.city).unwrap();
     dict.set_item("State", &row.state).unwrap();
     dict.set_item("Postal Code", &row.postal_code).unwrap();
-    dict.set_item("Region", &row.region).unwrap();
+    dict.set_item("Region", &*row.region).unwrap();
     dict.set_item("Product ID", &row.product_id).unwrap();
-    dict.set_item("Category", &row.category).unwrap();
+    dict.set_item("Category", &*row.category).unwrap();
     dict.set_item("Sub-Category", &row.sub_category).unwrap();
+    dict.set_item("Item Status", &row.item_status).unwrap();
+    dict.set_item("Item Price", row.item_price).unwrap();
+    dict.set_item("MSRP", row.msrp).unwrap();
     dict.set_item("Sales", row.sales).unwrap();
     dict.set_item("Quantity", row.quantity).unwrap();
     dict.set_item("Discount", row.discount).unwrap();
@@ -59,13 +69,75 @@ fn employee_row_to_pydict<'py>(
         row.date_of_birth.format("%Y-%m-%d").to_string(),
     )
     .unwrap();
+    dict.set_item("Department", &row.department).unwrap();
+    dict.set_item("Job Title", &row.job_title).unwrap();
+    dict.set_item("Salary", row.salary).unwrap();
+    dict.set_item("Manager ID", row.manager_id.as_deref())
+        .unwrap();
+    dict.set_item(
+        "Termination Date",
+        row.termination_date
+            .map(|d| d.format("%Y-%m-%d").to_string()),
+    )
+    .unwrap();
     dict
 }
 
+/// Partition assignment for streamed rows: a column name or a callable
+/// taking the row dict, hashed onto `partitions` like Kafka's default
+/// partitioner.
+struct PartitionSpec {
+    partitions: usize,
+    key: Py<PyAny>,
+}
+
+impl PartitionSpec {
+    fn new(
+        py: Python<'_>,
+        partitions: Option<usize>,
+        partition_key: Option<Py<PyAny>>,
+        default_key: &str,
+    ) -> PyResult<Option<Self>> {
+        let Some(partitions) = partitions else {
+            return Ok(None);
+        };
+        if partitions == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "partitions must be at least 1",
+            ));
+        }
+        let key = match partition_key {
+            Some(key) => key,
+            None => default_key.into_pyobject(py)?.into_any().unbind(),
+        };
+        Ok(Some(Self { partitions, key }))
+    }
+
+    /// Add a "Partition" entry to the row dict
+    fn tag(&self, py: Python<'_>, row: &Bound<'_, PyDict>) -> PyResult<()> {
+        let key = self.key.bind(py);
+        let value = if key.is_callable() {
+            key.call1((row,))?
+        } else {
+            row.get_item(key)?.ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unknown partition_key column {}",
+                    key
+                ))
+            })?
+        };
+        let key_str = value.str()?;
+        let partition = kafka_partition(key_str.to_str()?.as_bytes(), self.partitions)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        row.set_item("Partition", partition)
+    }
+}
+
 /// Python iterator for streaming superstore data generation.
 #[pyclass]
 pub struct SuperstoreStream {
     iter: superstore::streaming::SuperstoreIterator,
+    partitioning: Option<PartitionSpec>,
 }
 
 #[pymethods]
@@ -74,14 +146,19 @@ impl SuperstoreStream {
         slf
     }
 
-    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> Option<Py<PyList>> {
-        slf.iter.next().map(|chunk| {
-            let list = PyList::empty(py);
-            for row in &chunk {
-                list.append(superstore_row_to_pydict(py, row)).unwrap();
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<Py<PyList>>> {
+        let Some(chunk) = slf.iter.next() else {
+            return Ok(None);
+        };
+        let list = PyList::empty(py);
+        for row in &chunk {
+            let dict = superstore_row_to_pydict(py, row);
+            if let Some(spec) = &slf.partitioning {
+                spec.tag(py, &dict)?;
             }
-            list.into()
-        })
+            list.append(dict)?;
+        }
+        Ok(Some(list.into()))
     }
 }
 
@@ -89,6 +166,7 @@ impl SuperstoreStream {
 #[pyclass]
 pub struct EmployeeStream {
     iter: superstore::streaming::EmployeeIterator,
+    partitioning: Option<PartitionSpec>,
 }
 
 #[pymethods]
@@ -97,14 +175,19 @@ impl EmployeeStream {
         slf
     }
 
-    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> Option<Py<PyList>> {
-        slf.iter.next().map(|chunk| {
-            let list = PyList::empty(py);
-            for row in &chunk {
-                list.append(employee_row_to_pydict(py, row)).unwrap();
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<Py<PyList>>> {
+        let Some(chunk) = slf.iter.next() else {
+            return Ok(None);
+        };
+        let list = PyList::empty(py);
+        for row in &chunk {
+            let dict = employee_row_to_pydict(py, row);
+            if let Some(spec) = &slf.partitioning {
+                spec.tag(py, &dict)?;
             }
-            list.into()
-        })
+            list.append(dict)?;
+        }
+        Ok(Some(list.into()))
     }
 }
 
@@ -117,6 +200,13 @@ impl EmployeeStream {
 ///     total_count: Total number of rows to generate
 ///     chunk_size: Number of rows per chunk (default: 1000)
 ///     seed: Optional seed for reproducibility
+///     partitions: If set, tag each row with a "Partition" index in 0..partitions
+///     partition_key: Column name or callable(row) giving the partition key
+///         (default: "Customer ID"); hashed like Kafka's default partitioner
+///     start_offset: Resume from this row; yields the same rows as positions
+///         start_offset..total_count of a full stream with the same seed
+///     pool_size: Size of the pre-generated value pools rows draw from, as
+///         for the eager generator (default: 1000)
 ///
 /// Returns:
 ///     An iterator yielding lists of dicts
@@ -125,15 +215,30 @@ impl EmployeeStream {
 ///     >>> for chunk in superstoreStream(1_000_000, chunk_size=10000):
 ///     ...     process(chunk)  # Each chunk is a list of 10000 dicts
 #[pyfunction]
-#[pyo3(name = "superstoreStream", signature = (total_count, chunk_size=1000, seed=None))]
+#[pyo3(name = "superstoreStream", signature = (total_count, chunk_size=1000, seed=None, partitions=None, partition_key=None, start_offset=0, pool_size=1000))]
+#[allow(clippy::too_many_arguments)]
 pub fn py_superstore_stream(
+    py: Python<'_>,
     total_count: usize,
     chunk_size: usize,
     seed: Option<u64>,
-) -> SuperstoreStream {
-    SuperstoreStream {
-        iter: superstore_stream(total_count, chunk_size, seed),
+    partitions: Option<usize>,
+    partition_key: Option<Py<PyAny>>,
+    start_offset: usize,
+    pool_size: usize,
+) -> PyResult<SuperstoreStream> {
+    if pool_size < 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "pool_size must be at least 1",
+        ));
     }
+    Ok(SuperstoreStream {
+        iter: superstore_stream(total_count, chunk_size, seed)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+            .with_pool_size(pool_size)
+            .skip_to(start_offset),
+        partitioning: PartitionSpec::new(py, partitions, partition_key, "Customer ID")?,
+    })
 }
 
 /// Create a streaming employee data generator.
@@ -145,6 +250,13 @@ pub fn py_superstore_stream(
 ///     total_count: Total number of employees to generate
 ///     chunk_size: Number of employees per chunk (default: 1000)
 ///     seed: Optional seed for reproducibility
+///     partitions: If set, tag each row with a "Partition" index in 0..partitions
+///     partition_key: Column name or callable(row) giving the partition key
+///         (default: "Employee ID"); hashed like Kafka's default partitioner
+///     start_offset: Resume from this row; yields the same rows as positions
+///         start_offset..total_count of a full stream with the same seed
+///     pool_size: Size of the pre-generated value pools rows draw from, as
+///         for the eager generator (default: 1000)
 ///
 /// Returns:
 ///     An iterator yielding lists of dicts
@@ -153,21 +265,215 @@ pub fn py_superstore_stream(
 ///     >>> for chunk in employeesStream(1_000_000, chunk_size=10000):
 ///     ...     process(chunk)  # Each chunk is a list of 10000 dicts
 #[pyfunction]
-#[pyo3(name = "employeesStream", signature = (total_count, chunk_size=1000, seed=None))]
+#[pyo3(name = "employeesStream", signature = (total_count, chunk_size=1000, seed=None, partitions=None, partition_key=None, start_offset=0, pool_size=1000))]
+#[allow(clippy::too_many_arguments)]
 pub fn py_employees_stream(
+    py: Python<'_>,
+    total_count: usize,
+    chunk_size: usize,
+    seed: Option<u64>,
+    partitions: Option<usize>,
+    partition_key: Option<Py<PyAny>>,
+    start_offset: usize,
+    pool_size: usize,
+) -> PyResult<EmployeeStream> {
+    if pool_size < 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "pool_size must be at least 1",
+        ));
+    }
+    Ok(EmployeeStream {
+        iter: employees_stream(total_count, chunk_size, seed)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+            .with_pool_size(pool_size)
+            .skip_to(start_offset),
+        partitioning: PartitionSpec::new(py, partitions, partition_key, "Employee ID")?,
+    })
+}
+
+/// Python iterator yielding superstore chunks as tables in the requested
+/// output format, each generated without the GIL.
+#[pyclass]
+pub struct SuperstoreChunks {
+    iter: SuperstoreConfigIterator,
+    config: SuperstoreConfig,
+    output: String,
+}
+
+#[pymethods]
+impl SuperstoreChunks {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let this = &mut *slf;
+        let Some(chunk) = py.detach(|| this.iter.next()) else {
+            return Ok(None);
+        };
+        superstore_output(py, &chunk, &this.config, &this.output).map(Some)
+    }
+}
+
+/// Python iterator yielding employee chunks as tables in the requested
+/// output format, each generated without the GIL.
+#[pyclass]
+pub struct EmployeeChunks {
+    iter: EmployeeIterator,
+    output: String,
+}
+
+#[pymethods]
+impl EmployeeChunks {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let this = &mut *slf;
+        let Some(chunk) = py.detach(|| this.iter.next()) else {
+            return Ok(None);
+        };
+        employees_output(py, &chunk, &this.output).map(Some)
+    }
+}
+
+fn check_table_output(output: &str) -> PyResult<()> {
+    if TABLE_OUTPUTS.contains(&output) {
+        Ok(())
+    } else {
+        Err(invalid_output(output, TABLE_OUTPUTS))
+    }
+}
+
+/// Stream superstore data as one table per chunk.
+///
+/// Only one chunk is held at a time, so memory stays flat however many
+/// chunks are consumed: generating a billion rows uses about as much as one
+/// chunk of `chunk_size` rows plus the pools. Each chunk is generated with
+/// the GIL released, so other Python threads keep running meanwhile.
+///
+/// The chunks concatenated are the sale rows of `superstore()` with the same
+/// config, count, and seed; return rows are not streamed, and a config with
+/// `stable_v1` raises ValueError.
+///
+/// Args:
+///     total_count: Total number of rows to generate
+///     chunk_size: Number of rows per chunk (default: 1000)
+///     seed: Optional seed for reproducibility (overrides config if provided)
+///     output: Output format of each chunk ("pandas", "polars", "dict", or "arrow")
+///     config: Optional SuperstoreConfig pydantic model or dict
+///
+/// Returns:
+///     An iterator yielding one table per chunk
+///
+/// Example:
+///     >>> for df in superstore_stream(1_000_000_000, chunk_size=1_000_000, seed=42):
+///     ...     df.to_parquet(...)  # Each chunk is a DataFrame of 1,000,000 rows
+#[pyfunction]
+#[pyo3(name = "superstore_stream", signature = (total_count, chunk_size=1000, seed=None, output="pandas", config=None))]
+pub fn py_superstore_chunks(
+    py: Python<'_>,
     total_count: usize,
     chunk_size: usize,
     seed: Option<u64>,
-) -> EmployeeStream {
-    EmployeeStream {
-        iter: employees_stream(total_count, chunk_size, seed),
+    output: &str,
+    config: Option<&Bound<'_, PyAny>>,
+) -> PyResult<SuperstoreChunks> {
+    check_table_output(output)?;
+    let (mut config, _) = resolve_superstore_config(config)?;
+    config.count = total_count;
+    if let Some(s) = seed {
+        config.seed = Some(s);
     }
+    let iter = py
+        .detach(|| superstore_stream_with_config(&config, chunk_size))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(SuperstoreChunks {
+        iter,
+        config,
+        output: output.to_string(),
+    })
+}
+
+/// Stream employee data as one table per chunk.
+///
+/// Only one chunk is held at a time, so memory stays flat however many
+/// chunks are consumed. Each chunk is generated with the GIL released.
+/// The rows are those of `employeesStream()` with the same seed.
+///
+/// Args:
+///     total_count: Total number of employees to generate
+///     chunk_size: Number of employees per chunk (default: 1000)
+///     seed: Optional seed for reproducibility
+///     output: Output format of each chunk ("pandas", "polars", "dict", or "arrow")
+///
+/// Returns:
+///     An iterator yielding one table per chunk
+///
+/// Example:
+///     >>> for df in employees_stream(10_000_000, chunk_size=100_000):
+///     ...     process(df)  # Each chunk is a DataFrame of 100,000 employees
+#[pyfunction]
+#[pyo3(name = "employees_stream", signature = (total_count, chunk_size=1000, seed=None, output="pandas"))]
+pub fn py_employees_chunks(
+    total_count: usize,
+    chunk_size: usize,
+    seed: Option<u64>,
+    output: &str,
+) -> PyResult<EmployeeChunks> {
+    check_table_output(output)?;
+    Ok(EmployeeChunks {
+        iter: employees_stream(total_count, chunk_size, seed)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
+        output: output.to_string(),
+    })
+}
+
+/// Estimate quantiles of a superstore column over a stream, without holding
+/// the rows.
+///
+/// Rows are generated `chunk_size` at a time and fed to one P² estimator per
+/// quantile, so memory stays flat whatever `count` is. The rows are those of
+/// `superstoreStream()` with the same seed, and generation runs with the GIL
+/// released.
+///
+/// Args:
+///     count: Number of rows to stream
+///     chunk_size: Number of rows generated at a time (default: 1000)
+///     column: Numeric column: "Sales", "Quantity", "Discount", "Profit", or
+///         "Item Price" (case-insensitive; "item_price" also works)
+///     quantiles: Quantiles to estimate, each between 0 and 1
+///     seed: Optional seed for reproducibility
+///
+/// Returns:
+///     The estimates, in the order of `quantiles` (NaN when count is 0)
+///
+/// Example:
+///     >>> p50, p95, p99 = stream_quantiles(100_000_000, 100_000, "Sales", [0.5, 0.95, 0.99], seed=42)
+#[pyfunction]
+#[pyo3(name = "stream_quantiles", signature = (count, chunk_size=1000, column="Sales", quantiles=vec![0.5, 0.95, 0.99], seed=None))]
+pub fn py_stream_quantiles(
+    py: Python<'_>,
+    count: usize,
+    chunk_size: usize,
+    column: &str,
+    quantiles: Vec<f64>,
+    seed: Option<u64>,
+) -> PyResult<Vec<f64>> {
+    py.detach(|| stream_quantiles(count, chunk_size, column, &quantiles, seed))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
 }
 
 pub fn register_streaming(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<SuperstoreStream>()?;
     m.add_class::<EmployeeStream>()?;
+    m.add_class::<SuperstoreChunks>()?;
+    m.add_class::<EmployeeChunks>()?;
     m.add_function(wrap_pyfunction!(py_superstore_stream, m)?)?;
     m.add_function(wrap_pyfunction!(py_employees_stream, m)?)?;
+    m.add_function(wrap_pyfunction!(py_superstore_chunks, m)?)?;
+    m.add_function(wrap_pyfunction!(py_employees_chunks, m)?)?;
+    m.add_function(wrap_pyfunction!(py_stream_quantiles, m)?)?;
     Ok(())
 }
@@ -0,0 +1,239 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use superstore::subscriptions::{
+    generate_subscriptions, BillingEvent, PlanTier, Subscription, SubscriptionConfig,
+};
+
+// =============================================================================
+// Helper Functions for creating DataFrames
+// =============================================================================
+
+/// Wrap a dict of columns in the requested output format; "arrow" is
+/// built from the rows by the caller instead
+fn to_output(py: Python<'_>, data: Bound<'_, PyDict>, output: &str) -> PyResult<Py<PyAny>> {
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        "dict" => Ok(data.into_any().unbind()),
+        "pandas" => Ok(import_output_library(py, "pandas")?
+            .call_method1("DataFrame", (data,))?
+            .into()),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
+    }
+}
+
+/// Create pyarrow Table from Subscription rows
+fn create_subscriptions_arrow(py: Python<'_>, rows: &[Subscription]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8(
+            "subscription_id",
+            rows.iter().map(|r| r.subscription_id.as_str()),
+        )
+        .utf8("customer_id", rows.iter().map(|r| r.customer_id.as_str()))
+        .utf8("initial_plan", rows.iter().map(|r| r.initial_plan.as_str()))
+        .utf8("current_plan", rows.iter().map(|r| r.current_plan.as_str()))
+        .utf8("start_date", rows.iter().map(|r| r.start_date.as_str()))
+        .optional_utf8("cancel_date", rows.iter().map(|r| r.cancel_date.as_deref()))
+        .utf8("status", rows.iter().map(|r| r.status.as_str()))
+        .float64("initial_mrr", rows.iter().map(|r| r.initial_mrr))
+        .float64("current_mrr", rows.iter().map(|r| r.current_mrr))
+        .into_pyarrow(py)
+}
+
+/// Create pyarrow Table from BillingEvent rows
+fn create_billing_events_arrow(py: Python<'_>, rows: &[BillingEvent]) -> PyResult<Py<PyAny>> {
+    ArrowTable::new()
+        .utf8("event_id", rows.iter().map(|r| r.event_id.as_str()))
+        .utf8(
+            "subscription_id",
+            rows.iter().map(|r| r.subscription_id.as_str()),
+        )
+        .utf8("billing_date", rows.iter().map(|r| r.billing_date.as_str()))
+        .utf8("event_type", rows.iter().map(|r| r.event_type.as_str()))
+        .utf8("plan", rows.iter().map(|r| r.plan.as_str()))
+        .float64("mrr", rows.iter().map(|r| r.mrr))
+        .float64("mrr_change", rows.iter().map(|r| r.mrr_change))
+        .into_pyarrow(py)
+}
+
+/// Create column dict from Subscription rows
+fn subscriptions_columns<'py>(
+    py: Python<'py>,
+    rows: &[Subscription],
+) -> PyResult<Bound<'py, PyDict>> {
+    let data = PyDict::new(py);
+
+    let ids: Vec<&str> = rows.iter().map(|r| r.subscription_id.as_str()).collect();
+    let customers: Vec<&str> = rows.iter().map(|r| r.customer_id.as_str()).collect();
+    let initial_plans: Vec<&str> = rows.iter().map(|r| r.initial_plan.as_str()).collect();
+    let current_plans: Vec<&str> = rows.iter().map(|r| r.current_plan.as_str()).collect();
+    let starts: Vec<&str> = rows.iter().map(|r| r.start_date.as_str()).collect();
+    let cancels: Vec<Option<&str>> = rows.iter().map(|r| r.cancel_date.as_deref()).collect();
+    let statuses: Vec<&str> = rows.iter().map(|r| r.status.as_str()).collect();
+    let initial_mrr: Vec<f64> = rows.iter().map(|r| r.initial_mrr).collect();
+    let current_mrr: Vec<f64> = rows.iter().map(|r| r.current_mrr).collect();
+
+    data.set_item("subscription_id", PyList::new(py, &ids)?)?;
+    data.set_item("customer_id", PyList::new(py, &customers)?)?;
+    data.set_item("initial_plan", PyList::new(py, &initial_plans)?)?;
+    data.set_item("current_plan", PyList::new(py, &current_plans)?)?;
+    data.set_item("start_date", PyList::new(py, &starts)?)?;
+    data.set_item("cancel_date", PyList::new(py, &cancels)?)?;
+    data.set_item("status", PyList::new(py, &statuses)?)?;
+    data.set_item("initial_mrr", PyList::new(py, &initial_mrr)?)?;
+    data.set_item("current_mrr", PyList::new(py, &current_mrr)?)?;
+
+    Ok(data)
+}
+
+/// Create column dict from BillingEvent rows
+fn billing_events_columns<'py>(
+    py: Python<'py>,
+    rows: &[BillingEvent],
+) -> PyResult<Bound<'py, PyDict>> {
+    let data = PyDict::new(py);
+
+    let event_ids: Vec<&str> = rows.iter().map(|r| r.event_id.as_str()).collect();
+    let sub_ids: Vec<&str> = rows.iter().map(|r| r.subscription_id.as_str()).collect();
+    let dates: Vec<&str> = rows.iter().map(|r| r.billing_date.as_str()).collect();
+    let types: Vec<&str> = rows.iter().map(|r| r.event_type.as_str()).collect();
+    let plans: Vec<&str> = rows.iter().map(|r| r.plan.as_str()).collect();
+    let mrr: Vec<f64> = rows.iter().map(|r| r.mrr).collect();
+    let changes: Vec<f64> = rows.iter().map(|r| r.mrr_change).collect();
+
+    data.set_item("event_id", PyList::new(py, &event_ids)?)?;
+    data.set_item("subscription_id", PyList::new(py, &sub_ids)?)?;
+    data.set_item("billing_date", PyList::new(py, &dates)?)?;
+    data.set_item("event_type", PyList::new(py, &types)?)?;
+    data.set_item("plan", PyList::new(py, &plans)?)?;
+    data.set_item("mrr", PyList::new(py, &mrr)?)?;
+    data.set_item("mrr_change", PyList::new(py, &changes)?)?;
+
+    Ok(data)
+}
+
+// =============================================================================
+// Config Parsing
+// =============================================================================
+
+fn parse_plans(value: &Bound<'_, PyAny>) -> Option<Vec<PlanTier>> {
+    let list = value.cast::<PyList>().ok()?;
+    let mut plans = Vec::with_capacity(list.len());
+    for item in list.iter() {
+        let d = item.cast::<PyDict>().ok()?;
+        plans.push(PlanTier {
+            name: d.get_item("name").ok()??.extract().ok()?,
+            monthly_price: d.get_item("monthly_price").ok()??.extract().ok()?,
+            weight: match d.get_item("weight").ok().flatten() {
+                Some(w) => w.extract().ok()?,
+                None => 1.0,
+            },
+        });
+    }
+    Some(plans)
+}
+
+fn parse_subscription_config(dict: &Bound<'_, PyDict>) -> SubscriptionConfig {
+    let mut config = SubscriptionConfig::default();
+    if let Some(v) = dict.get_item("count").ok().flatten() {
+        config.count = v.extract().unwrap_or(config.count);
+    }
+    if let Some(v) = dict.get_item("seed").ok().flatten() {
+        config.seed = v.extract().ok();
+    }
+    if let Some(v) = dict.get_item("start_date").ok().flatten() {
+        config.start_date = v.extract().ok();
+    }
+    if let Some(v) = dict.get_item("months").ok().flatten() {
+        config.months = v.extract().unwrap_or(config.months);
+    }
+    if let Some(v) = dict.get_item("plans").ok().flatten() {
+        if let Some(plans) = parse_plans(&v) {
+            config.plans = plans;
+        }
+    }
+    if let Some(v) = dict.get_item("monthly_churn_rate").ok().flatten() {
+        config.monthly_churn_rate = v.extract().unwrap_or(config.monthly_churn_rate);
+    }
+    if let Some(v) = dict.get_item("upgrade_rate").ok().flatten() {
+        config.upgrade_rate = v.extract().unwrap_or(config.upgrade_rate);
+    }
+    if let Some(v) = dict.get_item("downgrade_rate").ok().flatten() {
+        config.downgrade_rate = v.extract().unwrap_or(config.downgrade_rate);
+    }
+    config
+}
+
+// =============================================================================
+// PyO3 Functions
+// =============================================================================
+
+/// Generate subscription and recurring-billing data
+///
+/// Args:
+///     count: Number of subscriptions to generate
+///     seed: Optional random seed for reproducibility
+///     config: SubscriptionConfig dict (plans, months, churn/upgrade/downgrade rates)
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
+///
+/// Returns:
+///     Dict with DataFrames for subscriptions and billing_events
+#[pyfunction]
+#[pyo3(signature = (count = None, seed = None, config = None, output = "pandas"))]
+pub fn subscriptions(
+    py: Python<'_>,
+    count: Option<usize>,
+    seed: Option<u64>,
+    config: Option<&Bound<'_, PyAny>>,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let mut cfg = match config {
+        Some(d) => parse_subscription_config(&config_dict(d, "a dict or None")?),
+        None => SubscriptionConfig::default(),
+    };
+    if let Some(count) = count {
+        cfg.count = count;
+    }
+    if seed.is_some() {
+        cfg.seed = seed;
+    }
+
+    let data = py.detach(|| generate_subscriptions(&cfg));
+    let result = PyDict::new(py);
+    if output == "arrow" {
+        result.set_item(
+            "subscriptions",
+            create_subscriptions_arrow(py, &data.subscriptions)?,
+        )?;
+        result.set_item(
+            "billing_events",
+            create_billing_events_arrow(py, &data.billing_events)?,
+        )?;
+        return Ok(result.into());
+    }
+    result.set_item(
+        "subscriptions",
+        to_output(py, subscriptions_columns(py, &data.subscriptions)?, output)?,
+    )?;
+    result.set_item(
+        "billing_events",
+        to_output(
+            py,
+            billing_events_columns(py, &data.billing_events)?,
+            output,
+        )?,
+    )?;
+
+    Ok(result.into())
+}
+
+/// Register subscriptions module functions
+pub fn register_subscriptions(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(subscriptions, m)?)?;
+    Ok(())
+}
@@ -3,8 +3,7 @@
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 
-use rand::rngs::StdRng;
-use rand::SeedableRng;
+use superstore::rng::seeded_rng;
 
 use superstore::temporal::{ARp, ExponentialSmoothing, MarkovChain, RandomWalk, AR1};
 
@@ -87,10 +86,7 @@ impl PyAR1 {
     ///     List of n values
     #[pyo3(signature = (n, seed=None))]
     fn sample(&mut self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         Ok(PyList::new(py, &samples)?.into())
     }
@@ -142,10 +138,7 @@ impl PyARp {
     /// Generate n samples.
     #[pyo3(signature = (n, seed=None))]
     fn sample(&mut self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         Ok(PyList::new(py, &samples)?.into())
     }
@@ -221,13 +214,19 @@ impl PyMarkovChain {
         Ok(PyList::new(py, &dist)?.into())
     }
 
+    /// Expected consecutive steps spent in a state once entered.
+    ///
+    /// Returns `1 / (1 - p_ii)`, or `inf` for absorbing states.
+    fn expected_dwell_time(&self, state: &str) -> PyResult<f64> {
+        self.inner
+            .expected_dwell_time(state)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
     /// Generate n state transitions.
     #[pyo3(signature = (n, seed=None))]
     fn sample(&mut self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         Ok(PyList::new(py, &samples)?.into())
     }
@@ -240,10 +239,7 @@ impl PyMarkovChain {
         n: usize,
         seed: Option<u64>,
     ) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n_indices(&mut rng, n);
         Ok(PyList::new(py, &samples)?.into())
     }
@@ -286,10 +282,7 @@ impl PyRandomWalk {
     /// Generate n samples.
     #[pyo3(signature = (n, seed=None))]
     fn sample(&mut self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         Ok(PyList::new(py, &samples)?.into())
     }
@@ -326,10 +319,7 @@ impl PyExponentialSmoothing {
     /// Generate n samples.
     #[pyo3(signature = (n, seed=None))]
     fn sample(&mut self, py: Python<'_>, n: usize, seed: Option<u64>) -> PyResult<Py<PyList>> {
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
         let samples = self.inner.sample_n(&mut rng, n);
         Ok(PyList::new(py, &samples)?.into())
     }
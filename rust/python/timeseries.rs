@@ -1,21 +1,26 @@
+use crate::arrow::ArrowTable;
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
 use chrono::NaiveDateTime;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{IntoPyDict, PyDict, PyList};
+use pyo3::types::{IntoPyDict, PyDict, PyList, PyTuple};
 use std::collections::HashMap;
 
 use superstore::timeseries::{
-    get_time_series_data, get_time_series_with_config, GarchConfig, IntradayConfig, JumpConfig,
-    MeanReversionConfig, RegimeConfig, TimeSeriesData, TimeseriesConfig,
+    add_indicators, get_time_series_data, get_time_series_with_config, realized_correlation,
+    EventWindowConfig, FinancialMetrics, GarchConfig, IntradayConfig, JumpConfig,
+    MeanReversionConfig, RegimeConfig, TimeSeriesColumn, TimeSeriesData, TimeseriesConfig,
 };
 
 /// Create pandas DataFrame from TimeSeriesData struct
 fn create_timeseries_pandas(py: Python<'_>, data: &TimeSeriesData) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+    let pandas = import_output_library(py, "pandas")?;
 
     let columns_dict = PyDict::new(py);
     for col in &data.columns {
         let values = PyList::new(py, &col.values)?;
-        columns_dict.set_item(col.name.to_string(), values)?;
+        columns_dict.set_item(col.name.as_str(), values)?;
     }
 
     let index_list: Vec<String> = data
@@ -32,9 +37,21 @@ fn create_timeseries_pandas(py: Python<'_>, data: &TimeSeriesData) -> PyResult<P
     Ok(df.into())
 }
 
+/// Create pyarrow Table from TimeSeriesData struct.
+///
+/// Arrow tables have no index, so the pandas DatetimeIndex becomes a leading
+/// timestamp[ns] "index" column (the name `reset_index()` gives it).
+fn create_timeseries_arrow(py: Python<'_>, data: &TimeSeriesData) -> PyResult<Py<PyAny>> {
+    let mut table = ArrowTable::new().timestamp("index", data.index.iter().copied())?;
+    for col in &data.columns {
+        table = table.float64(&col.name, col.values.iter().copied());
+    }
+    table.into_pyarrow(py)
+}
+
 /// Create polars DataFrame from TimeSeriesData struct
 fn create_timeseries_polars(py: Python<'_>, data: &TimeSeriesData) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let columns_dict = PyDict::new(py);
 
     // Add index as a column
@@ -47,7 +64,7 @@ fn create_timeseries_polars(py: Python<'_>, data: &TimeSeriesData) -> PyResult<P
 
     // Add data columns
     for col in &data.columns {
-        columns_dict.set_item(col.name.to_string(), PyList::new(py, &col.values)?)?;
+        columns_dict.set_item(col.name.as_str(), PyList::new(py, &col.values)?)?;
     }
 
     let df = polars.call_method1("DataFrame", (columns_dict,))?;
@@ -66,18 +83,112 @@ fn create_timeseries_dict(py: Python<'_>, data: &TimeSeriesData) -> PyResult<Py<
     result.set_item("index", PyList::new(py, &index_list)?)?;
 
     for col in &data.columns {
-        result.set_item(col.name.to_string(), PyList::new(py, &col.values)?)?;
+        result.set_item(col.name.as_str(), PyList::new(py, &col.values)?)?;
     }
 
     Ok(result.into())
 }
 
+/// Create a correlation matrix keyed by column name in the given output format
+fn create_correlation_output(
+    py: Python<'_>,
+    data: &TimeSeriesData,
+    corr: &[Vec<f64>],
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let names: Vec<&str> = data.columns.iter().map(|c| c.name.as_str()).collect();
+    let columns_dict = PyDict::new(py);
+    for (name, row) in names.iter().zip(corr.iter()) {
+        columns_dict.set_item(*name, PyList::new(py, row)?)?;
+    }
+
+    match output {
+        "polars" => Ok(import_output_library(py, "polars")?
+            .call_method1("DataFrame", (columns_dict,))?
+            .into()),
+        "dict" => Ok(columns_dict.into()),
+        "pandas" => {
+            let kwargs = [("index", PyList::new(py, &names)?)].into_py_dict(py)?;
+            Ok(import_output_library(py, "pandas")?
+                .call_method("DataFrame", (columns_dict,), Some(&kwargs))?
+                .into())
+        }
+        _ => Err(invalid_output(output, &["pandas", "polars", "dict"])),
+    }
+}
+
+const METRIC_FIELDS: [&str; 5] = [
+    "alpha",
+    "beta",
+    "sharpe_ratio",
+    "volatility",
+    "max_drawdown",
+];
+
+fn metric_values(m: &FinancialMetrics) -> [f64; 5] {
+    [
+        m.alpha,
+        m.beta,
+        m.sharpe_ratio,
+        m.volatility,
+        m.max_drawdown,
+    ]
+}
+
+/// Per-column financial metrics, keyed by column name in column order.
+///
+/// pandas gets a DataFrame indexed by column name and polars a DataFrame with
+/// a leading "column" column; every other format gets a dict of dicts.
+fn create_metrics_output(
+    py: Python<'_>,
+    data: &TimeSeriesData,
+    metrics: &HashMap<String, FinancialMetrics>,
+    output: &str,
+) -> PyResult<Py<PyAny>> {
+    let names: Vec<&str> = data.columns.iter().map(|c| c.name.as_str()).collect();
+    let rows: Vec<FinancialMetrics> = names
+        .iter()
+        .map(|name| metrics.get(*name).cloned().unwrap_or_default())
+        .collect();
+
+    if output == "pandas" || output == "polars" {
+        let columns_dict = PyDict::new(py);
+        if output == "polars" {
+            columns_dict.set_item("column", PyList::new(py, &names)?)?;
+        }
+        for (i, field) in METRIC_FIELDS.iter().enumerate() {
+            let values: Vec<f64> = rows.iter().map(|m| metric_values(m)[i]).collect();
+            columns_dict.set_item(*field, PyList::new(py, values)?)?;
+        }
+        return if output == "polars" {
+            Ok(import_output_library(py, "polars")?
+                .call_method1("DataFrame", (columns_dict,))?
+                .into())
+        } else {
+            let kwargs = [("index", PyList::new(py, &names)?)].into_py_dict(py)?;
+            Ok(import_output_library(py, "pandas")?
+                .call_method("DataFrame", (columns_dict,), Some(&kwargs))?
+                .into())
+        };
+    }
+
+    let result = PyDict::new(py);
+    for (name, row) in names.iter().zip(rows.iter()) {
+        let entry = PyDict::new(py);
+        for (field, value) in METRIC_FIELDS.iter().zip(metric_values(row)) {
+            entry.set_item(*field, value)?;
+        }
+        result.set_item(*name, entry)?;
+    }
+    Ok(result.into())
+}
+
 /// Create pandas dict of Series from HashMap data
 fn create_hashmap_pandas(
     py: Python<'_>,
     data: &HashMap<char, (Vec<NaiveDateTime>, Vec<f64>)>,
 ) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+    let pandas = import_output_library(py, "pandas")?;
     let result_dict = PyDict::new(py);
 
     for (col_name, (dates, values)) in data.iter() {
@@ -103,7 +214,7 @@ fn create_hashmap_polars(
     py: Python<'_>,
     data: &HashMap<char, (Vec<NaiveDateTime>, Vec<f64>)>,
 ) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let result_dict = PyDict::new(py);
 
     for (col_name, (dates, values)) in data.iter() {
@@ -123,6 +234,25 @@ fn create_hashmap_polars(
     Ok(result_dict.into())
 }
 
+/// Create dict of pyarrow Tables from HashMap data, one `index`/`value`
+/// table per column like the polars output
+fn create_hashmap_arrow(
+    py: Python<'_>,
+    data: &HashMap<char, (Vec<NaiveDateTime>, Vec<f64>)>,
+) -> PyResult<Py<PyAny>> {
+    let result_dict = PyDict::new(py);
+
+    for (col_name, (dates, values)) in data.iter() {
+        let table = ArrowTable::new()
+            .timestamp("index", dates.iter().copied())?
+            .float64("value", values.iter().copied())
+            .into_pyarrow(py)?;
+        result_dict.set_item(col_name.to_string(), table)?;
+    }
+
+    Ok(result_dict.into())
+}
+
 /// Create dict from HashMap data
 fn create_hashmap_dict(
     py: Python<'_>,
@@ -212,6 +342,12 @@ fn parse_full_timeseries_config(dict: &Bound<'_, PyDict>) -> PyResult<(Timeserie
         .transpose()?
         .unwrap_or(0.95);
 
+    let ar_coefficients: Option<Vec<f64>> = dict
+        .get_item("ar_coefficients")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
     let sigma: f64 = dict
         .get_item("sigma")?
         .map(|v| v.extract())
@@ -248,6 +384,37 @@ fn parse_full_timeseries_config(dict: &Bound<'_, PyDict>) -> PyResult<(Timeserie
         .transpose()?
         .unwrap_or(0.0);
 
+    let column_names: Option<Vec<String>> = dict
+        .get_item("column_names")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
+    let start_date: Option<String> = dict
+        .get_item("start_date")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
+    let holidays: Vec<String> = dict
+        .get_item("holidays")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?
+        .unwrap_or_default();
+
+    let exchange: Option<String> = dict
+        .get_item("exchange")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
+    let correlation_matrix: Option<Vec<Vec<f64>>> = dict
+        .get_item("correlation_matrix")?
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()?;
+
     // Parse nested RegimeConfig
     let regimes = if let Some(regimes_val) = dict.get_item("regimes")? {
         if let Ok(regimes_dict) = regimes_val.downcast::<PyDict>() {
@@ -271,11 +438,17 @@ fn parse_full_timeseries_config(dict: &Bound<'_, PyDict>) -> PyResult<(Timeserie
                 .map(|v| v.extract())
                 .transpose()?
                 .unwrap_or_else(|| vec![1.0, 2.5]);
+            let correlation_multipliers: Vec<f64> = regimes_dict
+                .get_item("correlation_multipliers")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or_default();
             RegimeConfig {
                 enable,
                 n_regimes,
                 regime_persistence,
                 volatility_multipliers,
+                correlation_multipliers,
             }
         } else {
             RegimeConfig::default()
@@ -320,31 +493,143 @@ fn parse_full_timeseries_config(dict: &Bound<'_, PyDict>) -> PyResult<(Timeserie
         JumpConfig::default()
     };
 
-    let config = TimeseriesConfig {
+    let mut config = TimeseriesConfig {
         nper,
         ncol,
         freq,
         seed,
         ar_phi,
+        ar_coefficients,
         sigma,
         drift,
         cumulative,
         use_fat_tails,
         degrees_freedom,
         cross_correlation,
+        correlation_matrix,
         regimes,
         jumps,
-        // Priority 5 fields - use defaults
-        garch: GarchConfig::default(),
-        mean_reversion: MeanReversionConfig::default(),
-        intraday: IntradayConfig::default(),
-        event_windows: superstore::timeseries::EventWindowConfig::default(),
         compute_metrics: false,
+        column_names,
+        start_date,
+        holidays,
+        exchange,
+        ..Default::default()
     };
 
+    if let Some(v) = dict.get_item("garch")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.garch = parse_garch_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("mean_reversion")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.mean_reversion = parse_mean_reversion_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("intraday")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.intraday = parse_intraday_config(d)?;
+        }
+    }
+    if let Some(v) = dict.get_item("event_windows")? {
+        if let Ok(d) = v.cast::<PyDict>() {
+            config.event_windows = parse_event_window_config(d)?;
+        }
+    }
+
     Ok((config, output))
 }
 
+fn parse_garch_config(dict: &Bound<'_, PyDict>) -> PyResult<GarchConfig> {
+    let mut config = GarchConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("alpha")? {
+        config.alpha = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("beta")? {
+        config.beta = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("omega")? {
+        config.omega = v.extract()?;
+    }
+    // The long-run variance omega / (1 - alpha - beta) only exists when the
+    // process is stationary
+    if config.enable && config.alpha + config.beta >= 1.0 {
+        return Err(PyValueError::new_err(format!(
+            "garch alpha + beta must be less than 1 (got {})",
+            config.alpha + config.beta
+        )));
+    }
+    Ok(config)
+}
+
+fn parse_mean_reversion_config(dict: &Bound<'_, PyDict>) -> PyResult<MeanReversionConfig> {
+    let mut config = MeanReversionConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("theta")? {
+        config.theta = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("mu")? {
+        config.mu = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("sigma")? {
+        config.sigma = v.extract()?;
+    }
+    Ok(config)
+}
+
+fn parse_intraday_config(dict: &Bound<'_, PyDict>) -> PyResult<IntradayConfig> {
+    let mut config = IntradayConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("opening_volatility_mult")? {
+        config.opening_volatility_mult = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("midday_volatility_mult")? {
+        config.midday_volatility_mult = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("closing_volatility_mult")? {
+        config.closing_volatility_mult = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("market_open")? {
+        config.market_open = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("market_close")? {
+        config.market_close = v.extract()?;
+    }
+    Ok(config)
+}
+
+/// Parse an event window dict (shared with the finance generator's `events`)
+pub(crate) fn parse_event_window_config(dict: &Bound<'_, PyDict>) -> PyResult<EventWindowConfig> {
+    let mut config = EventWindowConfig::default();
+    if let Some(v) = dict.get_item("enable")? {
+        config.enable = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("event_indices")? {
+        config.event_indices = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("pre_event_window")? {
+        config.pre_event_window = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("post_event_window")? {
+        config.post_event_window = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("abnormal_return_mean")? {
+        config.abnormal_return_mean = v.extract()?;
+    }
+    if let Some(v) = dict.get_item("abnormal_return_stddev")? {
+        config.abnormal_return_stddev = v.extract()?;
+    }
+    Ok(config)
+}
+
 /// Generate time series data with structured configuration.
 ///
 /// Args:
@@ -353,13 +638,18 @@ fn parse_full_timeseries_config(dict: &Bound<'_, PyDict>) -> PyResult<(Timeserie
 ///     nper: Number of periods (overrides config if provided)
 ///     freq: Frequency string (overrides config if provided)
 ///     ncol: Number of columns (overrides config if provided)
-///     output: Output format ("pandas", "polars", or "dict")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
 ///     seed: Random seed (overrides config if provided)
+///     include_metrics: Also return per-column financial metrics (alpha, beta,
+///             sharpe_ratio, volatility, max_drawdown). The first column is the
+///             market proxy for alpha and beta.
 ///
 /// Returns:
-///     Time series data in the specified format.
+///     Time series data in the specified format, or a `(data, metrics)` tuple
+///     when include_metrics is set.
 #[pyfunction]
-#[pyo3(name = "timeseries", signature = (config=None, nper=None, freq=None, ncol=None, output=None, seed=None))]
+#[pyo3(name = "timeseries", signature = (config=None, nper=None, freq=None, ncol=None, output=None, seed=None, include_metrics=None))]
+#[allow(clippy::too_many_arguments)]
 pub fn py_get_time_series(
     py: Python<'_>,
     config: Option<&Bound<'_, PyAny>>,
@@ -368,6 +658,7 @@ pub fn py_get_time_series(
     ncol: Option<usize>,
     output: Option<&str>,
     seed: Option<u64>,
+    include_metrics: Option<bool>,
 ) -> PyResult<Py<PyAny>> {
     // Parse config from pydantic model, dict, or int (backward compat)
     let (mut ts_config, cfg_output) = if let Some(cfg) = config {
@@ -381,19 +672,11 @@ pub fn py_get_time_series(
                 "pandas".to_string(),
             )
         // Check if it's a pydantic model (has model_dump method)
-        } else if cfg.hasattr("model_dump")? {
-            // Use mode="json" to ensure enums are serialized as strings
-            let kwargs = PyDict::new(py);
-            kwargs.set_item("mode", "json")?;
-            let dict = cfg.call_method("model_dump", (), Some(&kwargs))?;
-            let dict = dict.downcast::<PyDict>()?;
-            parse_full_timeseries_config(dict)?
-        } else if let Ok(dict) = cfg.downcast::<PyDict>() {
-            parse_full_timeseries_config(dict)?
         } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "config must be a TimeseriesConfig, dict, int, or None",
-            ));
+            parse_full_timeseries_config(&config_dict(
+                cfg,
+                "a TimeseriesConfig, dict, int, or None",
+            )?)?
         }
     } else {
         (TimeseriesConfig::default(), "pandas".to_string())
@@ -412,22 +695,141 @@ pub fn py_get_time_series(
     if let Some(s) = seed {
         ts_config.seed = Some(s);
     }
+    if let Some(m) = include_metrics {
+        ts_config.compute_metrics = m;
+    }
 
     let final_output = output.unwrap_or(&cfg_output);
 
     // Use enhanced config-based generation
-    let data_with_metrics = get_time_series_with_config(&ts_config);
+    let mut data_with_metrics = py
+        .detach(|| get_time_series_with_config(&ts_config))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let corr = (final_output == "with_corr")
+        .then(|| realized_correlation(&data_with_metrics.columns, ts_config.cumulative));
+    let metrics = data_with_metrics.metrics.take();
     // Convert to basic TimeSeriesData for output functions
     let data: TimeSeriesData = data_with_metrics.into();
 
-    match final_output {
-        "pandas" => create_timeseries_pandas(py, &data),
-        "polars" => create_timeseries_polars(py, &data),
-        "dict" => create_timeseries_dict(py, &data),
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-            "Invalid output format '{}'. Must be 'pandas', 'polars', or 'dict'",
-            output.unwrap_or("unknown")
-        ))),
+    // With "with_corr" the frame keeps the config's output format (pandas
+    // unless overridden); the matrix has no arrow form
+    let frame_output = if corr.is_some() {
+        match cfg_output.as_str() {
+            "pandas" | "polars" | "dict" => cfg_output.as_str(),
+            other => return Err(invalid_output(other, &["pandas", "polars", "dict"])),
+        }
+    } else {
+        final_output
+    };
+    let frame = match frame_output {
+        "pandas" => create_timeseries_pandas(py, &data)?,
+        "polars" => create_timeseries_polars(py, &data)?,
+        "dict" => create_timeseries_dict(py, &data)?,
+        "arrow" => create_timeseries_arrow(py, &data)?,
+        _ => {
+            return Err(invalid_output(
+                final_output,
+                &["pandas", "polars", "dict", "arrow", "with_corr"],
+            ))
+        }
+    };
+
+    let mut extras = Vec::new();
+    if let Some(corr) = corr {
+        extras.push(create_correlation_output(py, &data, &corr, frame_output)?);
+    }
+    if let Some(metrics) = metrics {
+        extras.push(create_metrics_output(py, &data, &metrics, frame_output)?);
+    }
+    if extras.is_empty() {
+        return Ok(frame);
+    }
+    extras.insert(0, frame);
+    Ok(PyTuple::new(py, extras)?.into_any().unbind())
+}
+
+/// Append technical indicator columns to timeseries output
+///
+/// Args:
+///     data: Output of timeseries() as a pandas DataFrame, polars DataFrame, or dict
+///     indicators: Indicator specs such as "sma:20", "ema:12", or "rsi:14"
+///
+/// Returns:
+///     A copy of data (same type) with a "{column}_{kind}_{N}" column per
+///     numeric column and indicator, e.g. "A_sma_20". Warm-up rows are NaN.
+#[pyfunction]
+#[pyo3(name = "indicators", signature = (data, indicators = None))]
+pub fn py_indicators(
+    py: Python<'_>,
+    data: &Bound<'_, PyAny>,
+    indicators: Option<Vec<String>>,
+) -> PyResult<Py<PyAny>> {
+    let specs = indicators.unwrap_or_else(|| {
+        vec![
+            "sma:20".to_string(),
+            "ema:12".to_string(),
+            "rsi:14".to_string(),
+        ]
+    });
+    let specs: Vec<&str> = specs.iter().map(String::as_str).collect();
+
+    // Collect numeric columns; the index column and non-numeric columns are skipped
+    let dict = data.cast::<PyDict>().ok();
+    let is_polars = dict.is_none() && data.hasattr("with_columns")?;
+    let names: Vec<String> = match &dict {
+        Some(d) => d.keys().extract()?,
+        None => data
+            .getattr("columns")?
+            .try_iter()?
+            .map(|c| c?.str()?.extract())
+            .collect::<PyResult<_>>()?,
+    };
+    let mut columns = Vec::with_capacity(names.len());
+    for name in names.into_iter().filter(|n| n != "index") {
+        let values = match &dict {
+            Some(d) => d.get_item(&name)?.map(|v| v.extract::<Vec<f64>>()),
+            None => {
+                let series = data.get_item(&name)?;
+                Some(if is_polars {
+                    series.call_method0("to_list")?.extract::<Vec<f64>>()
+                } else {
+                    series.call_method0("tolist")?.extract::<Vec<f64>>()
+                })
+            }
+        };
+        if let Some(Ok(values)) = values {
+            columns.push(TimeSeriesColumn { name, values });
+        }
+    }
+
+    let n_source = columns.len();
+    let mut ts = TimeSeriesData {
+        index: Vec::new(),
+        columns,
+    };
+    add_indicators(&mut ts, &specs)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let added = &ts.columns[n_source..];
+
+    if let Some(d) = dict {
+        let result = d.copy()?;
+        for col in added {
+            result.set_item(col.name.as_str(), PyList::new(py, &col.values)?)?;
+        }
+        Ok(result.into())
+    } else if is_polars {
+        let polars = import_output_library(py, "polars")?;
+        let series = added
+            .iter()
+            .map(|col| polars.call_method1("Series", (col.name.as_str(), col.values.clone())))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(data.call_method1("with_columns", (series,))?.into())
+    } else {
+        let result = data.call_method0("copy")?;
+        for col in added {
+            result.set_item(col.name.as_str(), PyList::new(py, &col.values)?)?;
+        }
+        Ok(result.into())
     }
 }
 
@@ -447,9 +849,7 @@ pub fn py_get_time_series_data(
         "pandas" => create_hashmap_pandas(py, &data),
         "polars" => create_hashmap_polars(py, &data),
         "dict" => create_hashmap_dict(py, &data),
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-            "Invalid output format '{}'. Must be 'pandas', 'polars', or 'dict'",
-            output
-        ))),
+        "arrow" => create_hashmap_arrow(py, &data),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 }
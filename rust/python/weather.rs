@@ -1,3 +1,6 @@
+use crate::arrow::{parse_timestamp, ArrowTable};
+use crate::config::config_dict;
+use crate::output::{import_output_library, invalid_output, TABLE_OUTPUTS};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
@@ -93,9 +96,11 @@ fn weather_reading_to_pydict(py: Python<'_>, reading: &WeatherReading) -> PyResu
     Ok(dict.into())
 }
 
-/// Create pandas DataFrame from weather readings
-fn create_weather_pandas(py: Python<'_>, readings: &[WeatherReading]) -> PyResult<Py<PyAny>> {
-    let pandas = py.import("pandas")?;
+/// Build the column dict for weather readings, shared by the pandas and Arrow outputs
+fn weather_columns<'py>(
+    py: Python<'py>,
+    readings: &[WeatherReading],
+) -> PyResult<Bound<'py, PyDict>> {
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = readings.iter().map(|r| r.timestamp.as_str()).collect();
@@ -112,7 +117,13 @@ fn create_weather_pandas(py: Python<'_>, readings: &[WeatherReading]) -> PyResul
     data.set_item("weather_event", PyList::new(py, &events)?)?;
     data.set_item("is_outlier", PyList::new(py, &outliers)?)?;
 
-    let df = pandas.call_method1("DataFrame", (data,))?;
+    Ok(data)
+}
+
+/// Create pandas DataFrame from weather readings
+fn create_weather_pandas(py: Python<'_>, readings: &[WeatherReading]) -> PyResult<Py<PyAny>> {
+    let pandas = import_output_library(py, "pandas")?;
+    let df = pandas.call_method1("DataFrame", (weather_columns(py, readings)?,))?;
 
     // Convert timestamp column to datetime
     let df = df.call_method1("assign", ())?;
@@ -125,9 +136,37 @@ fn create_weather_pandas(py: Python<'_>, readings: &[WeatherReading]) -> PyResul
     Ok(df.into())
 }
 
+/// Create pyarrow Table from weather readings, with a timestamp[ns] timestamp column
+fn create_weather_arrow(py: Python<'_>, readings: &[WeatherReading]) -> PyResult<Py<PyAny>> {
+    let timestamps = readings
+        .iter()
+        .map(|r| parse_timestamp("timestamp", &r.timestamp))
+        .collect::<PyResult<Vec<_>>>()?;
+    ArrowTable::new()
+        .timestamp("timestamp", timestamps)?
+        .float64(
+            "temperature_celsius",
+            readings.iter().map(|r| r.temperature_celsius),
+        )
+        .float64(
+            "humidity_percent",
+            readings.iter().map(|r| r.humidity_percent),
+        )
+        .float64(
+            "precipitation_mm",
+            readings.iter().map(|r| r.precipitation_mm),
+        )
+        .utf8(
+            "weather_event",
+            readings.iter().map(|r| r.weather_event.as_str()),
+        )
+        .boolean("is_outlier", readings.iter().map(|r| r.is_outlier))
+        .into_pyarrow(py)
+}
+
 /// Create polars DataFrame from weather readings
 fn create_weather_polars(py: Python<'_>, readings: &[WeatherReading]) -> PyResult<Py<PyAny>> {
-    let polars = py.import("polars")?;
+    let polars = import_output_library(py, "polars")?;
     let data = PyDict::new(py);
 
     let timestamps: Vec<&str> = readings.iter().map(|r| r.timestamp.as_str()).collect();
@@ -163,7 +202,7 @@ fn create_weather_dict(py: Python<'_>, readings: &[WeatherReading]) -> PyResult<
 ///     config: Optional WeatherConfig pydantic model or dict with configuration.
 ///             If None, uses default configuration.
 ///     count: Number of readings (overrides config if provided)
-///     output: Output format ("pandas", "polars", or "dict")
+///     output: Output format ("pandas", "polars", "dict", or "arrow")
 ///     seed: Random seed (overrides config if provided)
 ///
 /// Returns:
@@ -179,21 +218,8 @@ pub fn py_weather(
 ) -> PyResult<Py<PyAny>> {
     // Parse config from pydantic model or dict
     let mut weather_config = if let Some(cfg) = config {
-        // Check if it's a pydantic model (has model_dump method)
-        if cfg.hasattr("model_dump")? {
-            // Use mode="json" to ensure enums are serialized as strings
-            let kwargs = pyo3::types::PyDict::new(py);
-            kwargs.set_item("mode", "json")?;
-            let dict = cfg.call_method("model_dump", (), Some(&kwargs))?;
-            let dict = dict.downcast::<PyDict>()?;
-            dict_to_weather_config(py, Some(dict))?
-        } else if let Ok(dict) = cfg.downcast::<PyDict>() {
-            dict_to_weather_config(py, Some(dict))?
-        } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "config must be a WeatherConfig, dict, or None",
-            ));
-        }
+        let dict = config_dict(cfg, "a WeatherConfig, dict, or None")?;
+        dict_to_weather_config(py, Some(&dict))?
     } else {
         WeatherConfig::default()
     };
@@ -206,16 +232,14 @@ pub fn py_weather(
         weather_config.seed = Some(s);
     }
 
-    let readings = generate_weather(&weather_config);
+    let readings = py.detach(|| generate_weather(&weather_config));
 
     match output {
         "pandas" => create_weather_pandas(py, &readings),
         "polars" => create_weather_polars(py, &readings),
         "dict" => create_weather_dict(py, &readings),
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-            "Invalid output format '{}'. Must be 'pandas', 'polars', or 'dict'",
-            output
-        ))),
+        "arrow" => create_weather_arrow(py, &readings),
+        _ => Err(invalid_output(output, TABLE_OUTPUTS)),
     }
 }
 
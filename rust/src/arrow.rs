@@ -3,16 +3,24 @@
 //! This module provides functions to convert generated data directly to Apache Arrow
 //! RecordBatches, enabling efficient memory sharing with Python DataFrames.
 
-use arrow::array::{ArrayRef, Float64Array, Int32Array, StringArray};
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int32Array, StringArray};
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::record_batch::RecordBatch;
 use std::sync::Arc;
 
-use crate::general::{employees, superstore, EmployeeRow, SuperstoreRow};
+use crate::general::{employees, superstore, EmployeeRow, OptionalColumns, SuperstoreRow};
 
 /// Create the Arrow schema for superstore data
 pub fn superstore_schema() -> Schema {
-    Schema::new(vec![
+    superstore_schema_with_columns(OptionalColumns::default())
+}
+
+/// Create the Arrow schema for superstore data with the optional `columns`.
+///
+/// `product_type` and `currency` follow `sub_category`; the remaining groups
+/// follow `profit`, all nullable.
+pub fn superstore_schema_with_columns(columns: OptionalColumns) -> Schema {
+    let mut fields = vec![
         Field::new("row_id", DataType::Int32, false),
         Field::new("order_id", DataType::Utf8, false),
         Field::new("order_date", DataType::Utf8, false),
@@ -28,11 +36,42 @@ pub fn superstore_schema() -> Schema {
         Field::new("product_id", DataType::Utf8, false),
         Field::new("category", DataType::Utf8, false),
         Field::new("sub_category", DataType::Utf8, false),
+    ];
+    if columns.product_type {
+        fields.push(Field::new("product_type", DataType::Utf8, true));
+    }
+    if columns.currency {
+        fields.push(Field::new("currency", DataType::Utf8, true));
+    }
+    fields.extend([
         Field::new("sales", DataType::Int32, false),
         Field::new("quantity", DataType::Int32, false),
         Field::new("discount", DataType::Float64, false),
         Field::new("profit", DataType::Float64, false),
-    ])
+    ]);
+    if columns.bundling {
+        fields.push(Field::new("bundle_id", DataType::Utf8, true));
+    }
+    if columns.inventory {
+        fields.push(Field::new("stock_status", DataType::Utf8, true));
+        fields.push(Field::new("backorder_days", DataType::Int32, true));
+    }
+    if columns.payment {
+        fields.push(Field::new("payment_method", DataType::Utf8, true));
+        fields.push(Field::new("is_fraud", DataType::Boolean, true));
+        fields.push(Field::new("processing_fee", DataType::Float64, true));
+        fields.push(Field::new("settlement_date", DataType::Utf8, true));
+    }
+    if columns.cards {
+        fields.push(Field::new("card_network", DataType::Utf8, true));
+        fields.push(Field::new("card_number", DataType::Utf8, true));
+    }
+    if columns.returns {
+        fields.push(Field::new("return_reason", DataType::Utf8, true));
+        fields.push(Field::new("return_date", DataType::Utf8, true));
+        fields.push(Field::new("original_order_id", DataType::Utf8, true));
+    }
+    Schema::new(fields)
 }
 
 /// Create the Arrow schema for employee data
@@ -55,6 +94,11 @@ pub fn employee_schema() -> Schema {
         Field::new("country", DataType::Utf8, false),
         Field::new("start_date", DataType::Utf8, false),
         Field::new("date_of_birth", DataType::Utf8, false),
+        Field::new("department", DataType::Utf8, false),
+        Field::new("job_title", DataType::Utf8, false),
+        Field::new("salary", DataType::Float64, false),
+        Field::new("manager_id", DataType::Utf8, true),
+        Field::new("termination_date", DataType::Utf8, true),
     ])
 }
 
@@ -62,86 +106,88 @@ pub fn employee_schema() -> Schema {
 pub fn superstore_to_arrow(
     rows: &[SuperstoreRow],
 ) -> Result<RecordBatch, arrow::error::ArrowError> {
-    let schema = Arc::new(superstore_schema());
+    superstore_to_arrow_with_columns(rows, OptionalColumns::default())
+}
 
-    let row_id: ArrayRef = Arc::new(Int32Array::from_iter_values(rows.iter().map(|r| r.row_id)));
-    let order_id: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.order_id.as_str()),
-    ));
-    let order_date: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.order_date.as_str()),
-    ));
-    let ship_date: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.ship_date.as_str()),
-    ));
-    let ship_mode: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.ship_mode.as_str()),
-    ));
-    let customer_id: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.customer_id.as_str()),
-    ));
-    let segment: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.segment.as_str()),
-    ));
-    let country: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.country.as_str()),
-    ));
-    let city: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.city.as_str()),
-    ));
-    let state: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.state.as_str()),
-    ));
-    let postal_code: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.postal_code.as_str()),
-    ));
-    let region: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.region.as_str()),
-    ));
-    let product_id: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.product_id.as_str()),
-    ));
-    let category: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.category.as_str()),
-    ));
-    let sub_category: ArrayRef = Arc::new(StringArray::from_iter_values(
-        rows.iter().map(|r| r.sub_category.as_str()),
-    ));
-    let sales: ArrayRef = Arc::new(Int32Array::from_iter_values(rows.iter().map(|r| r.sales)));
-    let quantity: ArrayRef = Arc::new(Int32Array::from_iter_values(
-        rows.iter().map(|r| r.quantity),
-    ));
-    let discount: ArrayRef = Arc::new(Float64Array::from_iter_values(
-        rows.iter().map(|r| r.discount),
-    ));
-    let profit: ArrayRef = Arc::new(Float64Array::from_iter_values(
-        rows.iter().map(|r| r.profit),
-    ));
+/// Convert superstore rows to an Arrow RecordBatch with the optional
+/// `columns`, laid out as in [`superstore_schema_with_columns`]
+pub fn superstore_to_arrow_with_columns(
+    rows: &[SuperstoreRow],
+    columns: OptionalColumns,
+) -> Result<RecordBatch, arrow::error::ArrowError> {
+    let schema = Arc::new(superstore_schema_with_columns(columns));
+    let strings = |value: fn(&SuperstoreRow) -> &str| -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(rows.iter().map(value)))
+    };
+    let optional_strings = |value: fn(&SuperstoreRow) -> Option<&str>| -> ArrayRef {
+        Arc::new(StringArray::from_iter(rows.iter().map(value)))
+    };
 
-    RecordBatch::try_new(
-        schema,
-        vec![
-            row_id,
-            order_id,
-            order_date,
-            ship_date,
-            ship_mode,
-            customer_id,
-            segment,
-            country,
-            city,
-            state,
-            postal_code,
-            region,
-            product_id,
-            category,
-            sub_category,
-            sales,
-            quantity,
-            discount,
-            profit,
-        ],
-    )
+    let mut arrays: Vec<ArrayRef> = vec![
+        Arc::new(Int32Array::from_iter_values(rows.iter().map(|r| r.row_id))),
+        strings(|r| &r.order_id),
+        strings(|r| &r.order_date),
+        strings(|r| &r.ship_date),
+        strings(|r| &r.ship_mode),
+        strings(|r| &r.customer_id),
+        strings(|r| &r.segment),
+        strings(|r| &r.country),
+        strings(|r| &r.city),
+        strings(|r| &r.state),
+        strings(|r| &r.postal_code),
+        strings(|r| &r.region),
+        strings(|r| &r.product_id),
+        strings(|r| &r.category),
+        strings(|r| &r.sub_category),
+    ];
+    if columns.product_type {
+        arrays.push(optional_strings(|r| r.product_type.as_deref()));
+    }
+    if columns.currency {
+        arrays.push(optional_strings(|r| r.currency.as_deref()));
+    }
+    arrays.extend([
+        Arc::new(Int32Array::from_iter_values(rows.iter().map(|r| r.sales))) as ArrayRef,
+        Arc::new(Int32Array::from_iter_values(
+            rows.iter().map(|r| r.quantity),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            rows.iter().map(|r| r.discount),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            rows.iter().map(|r| r.profit),
+        )),
+    ]);
+    if columns.bundling {
+        arrays.push(optional_strings(|r| r.bundle_id.as_deref()));
+    }
+    if columns.inventory {
+        arrays.push(optional_strings(|r| r.stock_status.as_deref()));
+        arrays.push(Arc::new(Int32Array::from_iter(
+            rows.iter().map(|r| r.backorder_days),
+        )));
+    }
+    if columns.payment {
+        arrays.push(optional_strings(|r| r.payment_method.as_deref()));
+        arrays.push(Arc::new(BooleanArray::from_iter(
+            rows.iter().map(|r| r.is_fraud),
+        )));
+        arrays.push(Arc::new(Float64Array::from_iter(
+            rows.iter().map(|r| r.processing_fee),
+        )));
+        arrays.push(optional_strings(|r| r.settlement_date.as_deref()));
+    }
+    if columns.cards {
+        arrays.push(optional_strings(|r| r.card_network.as_deref()));
+        arrays.push(optional_strings(|r| r.card_number.as_deref()));
+    }
+    if columns.returns {
+        arrays.push(optional_strings(|r| r.return_reason.as_deref()));
+        arrays.push(optional_strings(|r| r.return_date.as_deref()));
+        arrays.push(optional_strings(|r| r.original_order_id.as_deref()));
+    }
+
+    RecordBatch::try_new(schema, arrays)
 }
 
 /// Convert employee rows to an Arrow RecordBatch
@@ -197,6 +243,22 @@ pub fn employees_to_arrow(rows: &[EmployeeRow]) -> Result<RecordBatch, arrow::er
     let date_of_birth: ArrayRef = Arc::new(StringArray::from_iter_values(
         rows.iter().map(|r| r.date_of_birth.to_string()),
     ));
+    let department: ArrayRef = Arc::new(StringArray::from_iter_values(
+        rows.iter().map(|r| r.department.as_str()),
+    ));
+    let job_title: ArrayRef = Arc::new(StringArray::from_iter_values(
+        rows.iter().map(|r| r.job_title.as_str()),
+    ));
+    let salary: ArrayRef = Arc::new(Float64Array::from_iter_values(
+        rows.iter().map(|r| r.salary),
+    ));
+    let manager_id: ArrayRef = Arc::new(StringArray::from_iter(
+        rows.iter().map(|r| r.manager_id.as_deref()),
+    ));
+    let termination_date: ArrayRef = Arc::new(StringArray::from_iter(
+        rows.iter()
+            .map(|r| r.termination_date.map(|d| d.to_string())),
+    ));
 
     RecordBatch::try_new(
         schema,
@@ -218,6 +280,11 @@ pub fn employees_to_arrow(rows: &[EmployeeRow]) -> Result<RecordBatch, arrow::er
             country,
             start_date,
             date_of_birth,
+            department,
+            job_title,
+            salary,
+            manager_id,
+            termination_date,
         ],
     )
 }
@@ -255,7 +322,7 @@ mod tests {
     fn test_employees_arrow() {
         let batch = employees_arrow(100, Some(42)).unwrap();
         assert_eq!(batch.num_rows(), 100);
-        assert_eq!(batch.num_columns(), 17);
+        assert_eq!(batch.num_columns(), 22);
     }
 
     #[test]
@@ -269,8 +336,13 @@ mod tests {
     #[test]
     fn test_employee_schema() {
         let schema = employee_schema();
-        assert_eq!(schema.fields().len(), 17);
+        assert_eq!(schema.fields().len(), 22);
         assert_eq!(schema.field(0).name(), "row_id");
+        assert!(schema.field_with_name("manager_id").unwrap().is_nullable());
+        assert!(schema
+            .field_with_name("termination_date")
+            .unwrap()
+            .is_nullable());
     }
 
     #[test]
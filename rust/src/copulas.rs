@@ -5,7 +5,7 @@
 //! with any combination of marginal distributions and dependency structures.
 
 use rand::prelude::*;
-use rand_distr::{Distribution, Normal, StandardNormal, Uniform};
+use rand_distr::{ChiSquared, Distribution, Normal, StandardNormal, Uniform};
 use std::f64::consts::PI;
 
 /// Error type for copula operations.
@@ -115,20 +115,10 @@ impl GaussianCopula {
     ///
     /// Returns a vector of n samples, where each sample is a vector of d uniform [0,1] values.
     pub fn sample_n<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<Vec<f64>> {
-        let normal = StandardNormal;
         let mut samples = Vec::with_capacity(n);
 
         for _ in 0..n {
-            // Generate independent standard normal variates
-            let z: Vec<f64> = (0..self.dim).map(|_| normal.sample(rng)).collect();
-
-            // Apply Cholesky decomposition to get correlated normals
-            let mut correlated = vec![0.0; self.dim];
-            for i in 0..self.dim {
-                for j in 0..=i {
-                    correlated[i] += self.cholesky[i][j] * z[j];
-                }
-            }
+            let correlated = self.correlated_normals(rng);
 
             // Transform to uniform using the normal CDF
             let uniforms: Vec<f64> = correlated.iter().map(|&x| norm_cdf(x)).collect();
@@ -139,16 +129,99 @@ impl GaussianCopula {
         samples
     }
 
+    /// Draw one vector of standard normals with the copula's correlation.
+    fn correlated_normals<R: Rng>(&self, rng: &mut R) -> Vec<f64> {
+        let normal = StandardNormal;
+
+        // Generate independent standard normal variates
+        let z: Vec<f64> = (0..self.dim).map(|_| normal.sample(rng)).collect();
+
+        // Apply Cholesky decomposition to get correlated normals
+        let mut correlated = vec![0.0; self.dim];
+        for i in 0..self.dim {
+            for j in 0..=i {
+                correlated[i] += self.cholesky[i][j] * z[j];
+            }
+        }
+        correlated
+    }
+
     /// Dimension of the copula.
     pub fn dim(&self) -> usize {
         self.dim
     }
 }
 
+/// Student-t Copula.
+///
+/// Like the Gaussian copula it is parameterized by a correlation matrix, but
+/// all variables share one chi-square mixing draw, so extreme values in one
+/// variable tend to coincide with extremes in the others (symmetric upper and
+/// lower tail dependence). Lower `df` means stronger tail dependence; as `df`
+/// grows it approaches the Gaussian copula.
+pub struct StudentTCopula {
+    gaussian: GaussianCopula,
+    df: f64,
+}
+
+impl StudentTCopula {
+    /// Create a new Student-t copula with the given correlation matrix and
+    /// degrees of freedom (`df > 0`).
+    ///
+    /// The correlation matrix must be positive semi-definite with ones on the diagonal.
+    pub fn new(correlation_matrix: Vec<Vec<f64>>, df: f64) -> Result<Self, CopulaError> {
+        if !(df > 0.0 && df.is_finite()) {
+            return Err(CopulaError(format!(
+                "Degrees of freedom must be positive and finite, got {}",
+                df
+            )));
+        }
+        let gaussian = GaussianCopula::new(correlation_matrix)?;
+        Ok(Self { gaussian, df })
+    }
+
+    /// Generate n samples from the copula.
+    ///
+    /// Returns a vector of n samples, where each sample is a vector of d uniform [0,1] values.
+    pub fn sample_n<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<Vec<f64>> {
+        let chi_squared = ChiSquared::new(self.df).expect("df validated in new");
+        let mut samples = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            let correlated = self.gaussian.correlated_normals(rng);
+
+            // Dividing every component by the same sqrt(chi2 / df) gives a
+            // multivariate t, then the t CDF maps each margin to uniform
+            let scale = (chi_squared.sample(rng) / self.df).sqrt();
+            let uniforms: Vec<f64> = correlated
+                .iter()
+                .map(|&x| student_t_cdf(x / scale, self.df))
+                .collect();
+
+            samples.push(uniforms);
+        }
+
+        samples
+    }
+
+    /// Degrees of freedom.
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+
+    /// Dimension of the copula.
+    pub fn dim(&self) -> usize {
+        self.gaussian.dim()
+    }
+}
+
 /// Clayton Copula.
 ///
 /// An Archimedean copula with lower tail dependence.
 /// Good for modeling dependencies where extreme low values tend to occur together.
+///
+/// Sampled with the Marshall-Olkin algorithm: a shared Gamma(1/theta) frailty
+/// pushed through the generator (Laplace transform) `(1 + t)^(-1/theta)`.
 pub struct ClaytonCopula {
     /// Dependence parameter (theta > 0 for positive dependence)
     theta: f64,
@@ -162,8 +235,10 @@ impl ClaytonCopula {
     /// theta > 0: positive dependence
     /// theta -> 0: independence
     /// theta -> infinity: perfect positive dependence
+    ///
+    /// Returns an error unless `theta > 0` and `dim >= 2`.
     pub fn new(theta: f64, dim: usize) -> Result<Self, CopulaError> {
-        if theta <= 0.0 {
+        if theta.is_nan() || theta <= 0.0 {
             return Err(CopulaError(format!(
                 "Clayton copula theta must be positive, got {}",
                 theta
@@ -216,6 +291,11 @@ impl ClaytonCopula {
     pub fn kendalls_tau(&self) -> f64 {
         self.theta / (self.theta + 2.0)
     }
+
+    /// Lower tail dependence coefficient.
+    pub fn lower_tail_dependence(&self) -> f64 {
+        2.0f64.powf(-1.0 / self.theta)
+    }
 }
 
 /// Frank Copula.
@@ -285,9 +365,15 @@ impl FrankCopula {
 ///
 /// An Archimedean copula with upper tail dependence.
 /// Good for modeling dependencies where extreme high values tend to occur together.
+///
+/// Sampled with the Marshall-Olkin algorithm: a shared positive stable
+/// frailty with index 1/theta pushed through the generator (Laplace
+/// transform) `exp(-t^(1/theta))`.
 pub struct GumbelCopula {
     /// Dependence parameter (theta >= 1)
     theta: f64,
+    /// Dimension
+    dim: usize,
 }
 
 impl GumbelCopula {
@@ -296,34 +382,44 @@ impl GumbelCopula {
     /// theta = 1: independence
     /// theta > 1: positive dependence
     /// theta -> infinity: perfect positive dependence
-    pub fn new(theta: f64) -> Result<Self, CopulaError> {
-        if theta < 1.0 {
+    ///
+    /// Returns an error unless `theta >= 1` and `dim >= 2`.
+    pub fn new(theta: f64, dim: usize) -> Result<Self, CopulaError> {
+        if theta.is_nan() || theta < 1.0 || theta.is_infinite() {
             return Err(CopulaError(format!(
                 "Gumbel copula theta must be >= 1, got {}",
                 theta
             )));
         }
-        Ok(Self { theta })
+        if dim < 2 {
+            return Err(CopulaError(format!(
+                "Dimension must be at least 2, got {}",
+                dim
+            )));
+        }
+        Ok(Self { theta, dim })
     }
 
-    /// Generate n samples from the bivariate Gumbel copula.
-    pub fn sample_n<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<(f64, f64)> {
+    /// Generate n samples from the copula.
+    ///
+    /// Returns a vector of n samples, where each sample is a vector of d uniform [0,1] values.
+    pub fn sample_n<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<Vec<f64>> {
         let uniform = Uniform::new(0.0f64, 1.0f64).expect("valid uniform distribution [0, 1]");
+        let alpha = 1.0 / self.theta;
         let mut samples = Vec::with_capacity(n);
 
         for _ in 0..n {
-            // Generate stable distribution sample for Gumbel
-            let s = sample_stable(rng, 1.0 / self.theta);
-
-            // Generate independent exponential variates
-            let e1 = -uniform.sample(rng).ln();
-            let e2 = -uniform.sample(rng).ln();
-
-            // Apply transformation
-            let u = (-((e1 / s).powf(self.theta))).exp();
-            let v = (-((e2 / s).powf(self.theta))).exp();
+            // Shared frailty with Laplace transform exp(-t^alpha)
+            let frailty = sample_positive_stable(rng, alpha);
+
+            let uniforms: Vec<f64> = (0..self.dim)
+                .map(|_| {
+                    let e = -uniform.sample(rng).ln();
+                    (-(e / frailty).powf(alpha)).exp()
+                })
+                .collect();
 
-            samples.push((u.clamp(0.0, 1.0), v.clamp(0.0, 1.0)));
+            samples.push(uniforms);
         }
 
         samples
@@ -334,6 +430,11 @@ impl GumbelCopula {
         self.theta
     }
 
+    /// Dimension of the copula.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
     /// Kendall's tau for the copula.
     pub fn kendalls_tau(&self) -> f64 {
         1.0 - 1.0 / self.theta
@@ -352,6 +453,103 @@ fn norm_cdf(x: f64) -> f64 {
     0.5 * (1.0 + erf(x / 2.0f64.sqrt()))
 }
 
+/// Student-t CDF with `df` degrees of freedom, via the regularized incomplete
+/// beta function.
+fn student_t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * regularized_incomplete_beta(df / (df + t * t), df / 2.0, 0.5);
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// Regularized incomplete beta function I_x(a, b).
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    // The continued fraction converges quickly only below the mean; use the
+    // symmetry I_x(a, b) = 1 - I_{1-x}(b, a) above it
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Continued fraction for the incomplete beta function (modified Lentz).
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..300 {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // Even step
+        let aa = m * (b - m) * x / ((a + m2 - 1.0) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        // Odd step
+        let aa = -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    h
+}
+
+/// Natural log of the gamma function (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut series = 1.000000000190015;
+    for (i, c) in COEFFICIENTS.iter().enumerate() {
+        series += c / (x + 1.0 + i as f64);
+    }
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
 /// Error function approximation.
 fn erf(x: f64) -> f64 {
     // Horner's method approximation
@@ -402,26 +600,21 @@ fn sample_gamma<R: Rng>(rng: &mut R, shape: f64, scale: f64) -> f64 {
     }
 }
 
-/// Sample from a stable distribution (for Gumbel copula).
-fn sample_stable<R: Rng>(rng: &mut R, alpha: f64) -> f64 {
-    let uniform =
-        Uniform::new(-PI / 2.0, PI / 2.0).expect("valid uniform distribution [-pi/2, pi/2]");
+/// Sample a positive stable variate with Laplace transform `exp(-t^alpha)`,
+/// `0 < alpha <= 1`, using Kanter's representation (for the Gumbel copula).
+fn sample_positive_stable<R: Rng>(rng: &mut R, alpha: f64) -> f64 {
+    if (alpha - 1.0).abs() < 1e-12 {
+        // Degenerate at 1: the independence copula
+        return 1.0;
+    }
+    let uniform = Uniform::new(0.0, PI).expect("valid uniform distribution [0, pi]");
     let exp_dist = rand_distr::Exp::new(1.0).unwrap();
 
     let u = uniform.sample(rng);
     let w = exp_dist.sample(rng);
 
-    if (alpha - 1.0).abs() < 1e-10 {
-        // Cauchy distribution (alpha = 1)
-        u.tan()
-    } else {
-        // General stable distribution
-        let t = alpha * u;
-        let s = (PI / 2.0 * alpha).cos().powf(1.0 / alpha);
-        let x = (t.sin() / u.cos().powf(1.0 / alpha))
-            * (((1.0 - alpha) * u).cos() / w).powf((1.0 - alpha) / alpha);
-        s * x
-    }
+    ((alpha * u).sin() / u.sin().powf(1.0 / alpha))
+        * (((1.0 - alpha) * u).sin() / w).powf((1.0 - alpha) / alpha)
 }
 
 /// Debye function D_1(x) for Frank copula Kendall's tau calculation.
@@ -468,6 +661,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_student_t_cdf() {
+        assert!((student_t_cdf(0.0, 4.0) - 0.5).abs() < 1e-12);
+        // df = 1 is the Cauchy distribution
+        for t in [-3.0, -0.5, 0.7, 10.0] {
+            let cauchy = 0.5 + f64::atan(t) / PI;
+            assert!((student_t_cdf(t, 1.0) - cauchy).abs() < 1e-9);
+        }
+        // 97.5% quantile of t with 5 degrees of freedom
+        assert!((student_t_cdf(2.570582, 5.0) - 0.975).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_student_t_copula_tail_dependence() {
+        let corr = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+        let gaussian = GaussianCopula::new(corr.clone()).unwrap();
+        let student = StudentTCopula::new(corr, 3.0).unwrap();
+        assert_eq!(student.dim(), 2);
+        assert!(StudentTCopula::new(vec![vec![1.0]], 0.0).is_err());
+
+        let n = 50_000;
+        let joint_upper_tail = |samples: &[Vec<f64>]| {
+            samples
+                .iter()
+                .filter(|s| s[0] > 0.99 && s[1] > 0.99)
+                .count() as f64
+                / n as f64
+        };
+        let t_samples = student.sample_n(&mut StdRng::seed_from_u64(42), n);
+        let gaussian_samples = gaussian.sample_n(&mut StdRng::seed_from_u64(42), n);
+
+        // Margins are still uniform
+        let mean = t_samples.iter().map(|s| s[0]).sum::<f64>() / n as f64;
+        assert!((mean - 0.5).abs() < 0.01);
+
+        // Same correlation, but extremes co-occur far more often
+        let t_rate = joint_upper_tail(&t_samples);
+        let gaussian_rate = joint_upper_tail(&gaussian_samples);
+        assert!(
+            t_rate > 1.5 * gaussian_rate,
+            "t {} vs gaussian {}",
+            t_rate,
+            gaussian_rate
+        );
+    }
+
     #[test]
     fn test_clayton_copula() {
         let copula = ClaytonCopula::new(2.0, 2).unwrap();
@@ -488,11 +727,81 @@ mod tests {
 
     #[test]
     fn test_gumbel_copula() {
-        let copula = GumbelCopula::new(2.0).unwrap();
+        let copula = GumbelCopula::new(2.0, 3).unwrap();
         assert!((copula.kendalls_tau() - 0.5).abs() < 0.01);
 
         let mut rng = StdRng::seed_from_u64(42);
         let samples = copula.sample_n(&mut rng, 100);
         assert_eq!(samples.len(), 100);
+        assert!(samples
+            .iter()
+            .all(|s| s.len() == 3 && s.iter().all(|u| (0.0..=1.0).contains(u))));
+
+        assert!(GumbelCopula::new(0.9, 2).is_err());
+        assert!(GumbelCopula::new(2.0, 1).is_err());
+        assert!(ClaytonCopula::new(0.0, 2).is_err());
+    }
+
+    /// Sample Kendall's tau between the first two columns
+    fn sample_kendalls_tau(samples: &[Vec<f64>]) -> f64 {
+        let mut concordance = 0.0;
+        for (i, a) in samples.iter().enumerate() {
+            for b in &samples[i + 1..] {
+                concordance += ((a[0] - b[0]) * (a[1] - b[1])).signum();
+            }
+        }
+        let n = samples.len() as f64;
+        concordance / (n * (n - 1.0) / 2.0)
+    }
+
+    #[test]
+    fn test_archimedean_kendalls_tau_matches_theta() {
+        for theta in [0.5, 2.0, 6.0] {
+            let copula = ClaytonCopula::new(theta, 2).unwrap();
+            let samples = copula.sample_n(&mut StdRng::seed_from_u64(42), 2000);
+            let tau = sample_kendalls_tau(&samples);
+            assert!(
+                (tau - copula.kendalls_tau()).abs() < 0.04,
+                "Clayton theta {}: tau {}",
+                theta,
+                tau
+            );
+        }
+        for theta in [1.0, 1.5, 3.0] {
+            let copula = GumbelCopula::new(theta, 2).unwrap();
+            let samples = copula.sample_n(&mut StdRng::seed_from_u64(42), 2000);
+            let tau = sample_kendalls_tau(&samples);
+            assert!(
+                (tau - copula.kendalls_tau()).abs() < 0.04,
+                "Gumbel theta {}: tau {}",
+                theta,
+                tau
+            );
+        }
+    }
+
+    #[test]
+    fn test_archimedean_tail_asymmetry() {
+        let n = 20_000;
+        let tails = |samples: &[Vec<f64>]| {
+            let lower = samples
+                .iter()
+                .filter(|s| s[0] < 0.02 && s[1] < 0.02)
+                .count();
+            let upper = samples
+                .iter()
+                .filter(|s| s[0] > 0.98 && s[1] > 0.98)
+                .count();
+            (lower, upper)
+        };
+
+        let clayton = ClaytonCopula::new(2.0, 2).unwrap();
+        let (lower, upper) = tails(&clayton.sample_n(&mut StdRng::seed_from_u64(42), n));
+        assert!(lower > 3 * upper, "Clayton lower {} upper {}", lower, upper);
+        assert!((clayton.lower_tail_dependence() - 0.5f64.sqrt()).abs() < 1e-12);
+
+        let gumbel = GumbelCopula::new(2.0, 2).unwrap();
+        let (lower, upper) = tails(&gumbel.sample_n(&mut StdRng::seed_from_u64(42), n));
+        assert!(upper > 3 * lower, "Gumbel lower {} upper {}", lower, upper);
     }
 }
@@ -3,22 +3,29 @@
 //! This module provides functions to generate correlated random variables using
 //! Cholesky decomposition of covariance matrices.
 
+use crate::rng::seeded_rng;
 use nalgebra::{DMatrix, DVector};
-use rand::rngs::StdRng;
-use rand::SeedableRng;
 use rand_distr::{Distribution, StandardNormal};
 
 /// Error type for correlation operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CorrelationError {
     /// Matrix is not positive definite (cannot be Cholesky decomposed)
     NotPositiveDefinite,
+    /// Matrix has a negative eigenvalue, so it is not a valid correlation matrix
+    NotPositiveSemiDefinite,
+    /// Entry (row, col) differs from its transpose (col, row)
+    NotSymmetric { row: usize, col: usize },
+    /// Diagonal entry is not 1
+    InvalidDiagonal { index: usize, value: f64 },
     /// Matrix dimensions don't match
     DimensionMismatch { expected: usize, got: usize },
     /// Invalid correlation value (must be between -1 and 1)
     InvalidCorrelation(f64),
     /// Matrix is not square
     NotSquare { rows: usize, cols: usize },
+    /// Matrix has no rows
+    Empty,
 }
 
 impl std::fmt::Display for CorrelationError {
@@ -27,6 +34,19 @@ impl std::fmt::Display for CorrelationError {
             CorrelationError::NotPositiveDefinite => {
                 write!(f, "Correlation matrix is not positive definite")
             }
+            CorrelationError::NotPositiveSemiDefinite => {
+                write!(f, "Correlation matrix is not positive semi-definite")
+            }
+            CorrelationError::NotSymmetric { row, col } => write!(
+                f,
+                "Correlation matrix is not symmetric: entry [{}][{}] differs from [{}][{}]",
+                row, col, col, row
+            ),
+            CorrelationError::InvalidDiagonal { index, value } => write!(
+                f,
+                "Correlation matrix diagonal must be 1, got {} at [{}][{}]",
+                value, index, index
+            ),
             CorrelationError::DimensionMismatch { expected, got } => {
                 write!(f, "Dimension mismatch: expected {}, got {}", expected, got)
             }
@@ -40,6 +60,7 @@ impl std::fmt::Display for CorrelationError {
             CorrelationError::NotSquare { rows, cols } => {
                 write!(f, "Matrix is not square: {} x {}", rows, cols)
             }
+            CorrelationError::Empty => write!(f, "Correlation matrix is empty"),
         }
     }
 }
@@ -52,7 +73,9 @@ impl std::error::Error for CorrelationError {}
 /// to transform independent standard normal samples into correlated samples.
 #[derive(Debug, Clone)]
 pub struct CorrelationMatrix {
-    /// Cholesky decomposition (lower triangular matrix)
+    /// Cholesky decomposition (lower triangular matrix), or for singular
+    /// matrices built with [`CorrelationMatrix::from_rows`] a symmetric
+    /// square root. Either way `L * L^T` is the correlation matrix.
     cholesky: DMatrix<f64>,
     /// Number of variables
     dim: usize,
@@ -116,6 +139,76 @@ impl CorrelationMatrix {
         })
     }
 
+    /// Create a correlation matrix from its rows.
+    ///
+    /// Unlike [`CorrelationMatrix::new`], positive semi-definite matrices are
+    /// accepted, so perfectly correlated variables (a singular matrix with no
+    /// Cholesky decomposition) are allowed.
+    ///
+    /// # Example
+    /// ```
+    /// use superstore::correlation::CorrelationMatrix;
+    ///
+    /// let corr = CorrelationMatrix::from_rows(&[vec![1.0, 1.0], vec![1.0, 1.0]], None).unwrap();
+    /// assert_eq!(corr.dim(), 2);
+    /// ```
+    pub fn from_rows(
+        rows: &[Vec<f64>],
+        names: Option<Vec<String>>,
+    ) -> Result<Self, CorrelationError> {
+        let n = rows.len();
+        if let Some(row) = rows.iter().find(|r| r.len() != n) {
+            return Err(CorrelationError::NotSquare {
+                rows: n,
+                cols: row.len(),
+            });
+        }
+        if let Some(ref n_vec) = names {
+            if n_vec.len() != n {
+                return Err(CorrelationError::DimensionMismatch {
+                    expected: n,
+                    got: n_vec.len(),
+                });
+            }
+        }
+        for (i, row) in rows.iter().enumerate() {
+            if (row[i] - 1.0).abs() > 1e-9 {
+                return Err(CorrelationError::InvalidDiagonal {
+                    index: i,
+                    value: row[i],
+                });
+            }
+            for (j, &v) in row.iter().enumerate() {
+                if !(-1.0..=1.0).contains(&v) {
+                    return Err(CorrelationError::InvalidCorrelation(v));
+                }
+                if (v - rows[j][i]).abs() > 1e-9 {
+                    return Err(CorrelationError::NotSymmetric { row: i, col: j });
+                }
+            }
+        }
+
+        let matrix = DMatrix::from_fn(n, n, |i, j| rows[i][j]);
+        let cholesky = match matrix.clone().cholesky() {
+            Some(c) => c.l(),
+            None => {
+                // Singular but possibly semi-definite: V * sqrt(D) * V^T
+                let eigen = matrix.symmetric_eigen();
+                if eigen.eigenvalues.iter().any(|&l| l < -1e-9) {
+                    return Err(CorrelationError::NotPositiveSemiDefinite);
+                }
+                let sqrt_d = DMatrix::from_diagonal(&eigen.eigenvalues.map(|l| l.max(0.0).sqrt()));
+                &eigen.eigenvectors * sqrt_d * eigen.eigenvectors.transpose()
+            }
+        };
+
+        Ok(Self {
+            cholesky,
+            dim: n,
+            names,
+        })
+    }
+
     /// Create an identity correlation matrix (uncorrelated variables).
     pub fn identity(dim: usize, names: Option<Vec<String>>) -> Result<Self, CorrelationError> {
         if let Some(ref n_vec) = names {
@@ -224,10 +317,7 @@ impl CorrelationMatrix {
             });
         }
 
-        let mut rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
+        let mut rng = seeded_rng(seed);
 
         let mut result = Vec::with_capacity(n);
 
@@ -254,6 +344,35 @@ impl CorrelationMatrix {
         Ok(result)
     }
 
+    /// Correlate independent, identically distributed columns.
+    ///
+    /// Output column i at each row is `sum_k L[i][k] * columns[k]`, so when
+    /// the inputs are independent with a common variance the outputs keep that
+    /// variance and have this correlation structure.
+    pub fn correlate_columns(
+        &self,
+        columns: &[Vec<f64>],
+    ) -> Result<Vec<Vec<f64>>, CorrelationError> {
+        if columns.len() != self.dim {
+            return Err(CorrelationError::DimensionMismatch {
+                expected: self.dim,
+                got: columns.len(),
+            });
+        }
+        let len = columns.iter().map(Vec::len).min().unwrap_or(0);
+        Ok((0..self.dim)
+            .map(|i| {
+                (0..len)
+                    .map(|t| {
+                        (0..self.dim)
+                            .map(|k| self.cholesky[(i, k)] * columns[k][t])
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
     /// Sample and return as a column-oriented structure (for DataFrame-like access).
     ///
     /// # Returns
@@ -333,6 +452,112 @@ pub fn sample_bivariate(
     Ok((columns[0].clone(), columns[1].clone()))
 }
 
+// Alternating projections stop once an iteration moves the matrix by less
+// than this (relative Frobenius norm)
+const NEAREST_CORRELATION_TOLERANCE: f64 = 1e-10;
+const NEAREST_CORRELATION_MAX_ITERATIONS: usize = 10_000;
+// Smallest eigenvalue kept in the result, so it has a Cholesky decomposition
+const NEAREST_CORRELATION_MIN_EIGENVALUE: f64 = 1e-8;
+
+/// Find the nearest valid correlation matrix to a symmetric matrix.
+///
+/// Uses Higham's alternating projections with Dykstra's correction (Higham,
+/// "Computing the nearest correlation matrix", 2002), alternating between the
+/// positive semi-definite cone and the unit-diagonal matrices. The result is
+/// then nudged to be strictly positive definite so it can be used with
+/// [`CorrelationMatrix::from_rows`] or a copula. A positive definite
+/// correlation matrix comes back unchanged up to rounding.
+///
+/// Returns the repaired matrix and its Frobenius distance from the input.
+///
+/// # Example
+/// ```
+/// use superstore::correlation::nearest_correlation_matrix;
+///
+/// let (repaired, distance) = nearest_correlation_matrix(&[
+///     vec![1.0, 0.9, -0.9],
+///     vec![0.9, 1.0, 0.9],
+///     vec![-0.9, 0.9, 1.0],
+/// ])
+/// .unwrap();
+/// assert!(distance > 0.0);
+/// assert_eq!(repaired[0][0], 1.0);
+/// ```
+pub fn nearest_correlation_matrix(
+    matrix: &[Vec<f64>],
+) -> Result<(Vec<Vec<f64>>, f64), CorrelationError> {
+    let n = matrix.len();
+    if n == 0 {
+        return Err(CorrelationError::Empty);
+    }
+    if let Some(row) = matrix.iter().find(|r| r.len() != n) {
+        return Err(CorrelationError::NotSquare {
+            rows: n,
+            cols: row.len(),
+        });
+    }
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &v) in row.iter().enumerate() {
+            if !v.is_finite() {
+                return Err(CorrelationError::InvalidCorrelation(v));
+            }
+            if (v - matrix[j][i]).abs() > 1e-9 {
+                return Err(CorrelationError::NotSymmetric { row: i, col: j });
+            }
+        }
+    }
+
+    let target = DMatrix::from_fn(n, n, |i, j| matrix[i][j]);
+    let mut y = target.clone();
+    let mut correction = DMatrix::zeros(n, n);
+    for _ in 0..NEAREST_CORRELATION_MAX_ITERATIONS {
+        let r = &y - &correction;
+        let x = clip_eigenvalues(&r, 0.0);
+        correction = &x - &r;
+        let mut next = x;
+        next.fill_diagonal(1.0);
+        let change = (&next - &y).norm() / next.norm();
+        y = next;
+        if change < NEAREST_CORRELATION_TOLERANCE {
+            break;
+        }
+    }
+
+    // The unit-diagonal projection can leave eigenvalues a hair below zero;
+    // clip them to a small positive floor and rescale back to unit diagonal
+    let y = clip_eigenvalues(&y, NEAREST_CORRELATION_MIN_EIGENVALUE);
+    let scale = y.diagonal().map(|d| 1.0 / d.sqrt());
+    let repaired: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if i == j {
+                        1.0
+                    } else {
+                        // Average with the transpose so rounding can't break symmetry
+                        (0.5 * (y[(i, j)] + y[(j, i)]) * scale[i] * scale[j]).clamp(-1.0, 1.0)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let distance = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| (repaired[i][j] - matrix[i][j]).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    Ok((repaired, distance))
+}
+
+/// Project a symmetric matrix onto the matrices whose eigenvalues are all at
+/// least `floor`
+fn clip_eigenvalues(matrix: &DMatrix<f64>, floor: f64) -> DMatrix<f64> {
+    let eigen = matrix.clone().symmetric_eigen();
+    let clipped = DMatrix::from_diagonal(&eigen.eigenvalues.map(|l| l.max(floor)));
+    &eigen.eigenvectors * clipped * eigen.eigenvectors.transpose()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,6 +599,53 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn test_from_rows() {
+        let rows = vec![
+            vec![1.0, 0.6, -0.3],
+            vec![0.6, 1.0, 0.2],
+            vec![-0.3, 0.2, 1.0],
+        ];
+        let corr = CorrelationMatrix::from_rows(&rows, None).unwrap();
+        let independent = CorrelationMatrix::identity(3, None)
+            .unwrap()
+            .sample_columns(20000, &[0.0; 3], &[1.0; 3], Some(42))
+            .unwrap();
+        let cols = corr.correlate_columns(&independent).unwrap();
+        for i in 0..3 {
+            for j in (i + 1)..3 {
+                let r = pearson_correlation(&cols[i], &cols[j]);
+                assert!((r - rows[i][j]).abs() < 0.03, "[{}][{}] = {}", i, j, r);
+            }
+        }
+
+        // Singular but semi-definite: the two columns are identical
+        let corr = CorrelationMatrix::from_rows(&[vec![1.0, 1.0], vec![1.0, 1.0]], None).unwrap();
+        let cols = corr.correlate_columns(&independent[..2]).unwrap();
+        assert!((pearson_correlation(&cols[0], &cols[1]) - 1.0).abs() < 1e-9);
+
+        assert_eq!(
+            CorrelationMatrix::from_rows(&[vec![1.0, 0.5]], None).unwrap_err(),
+            CorrelationError::NotSquare { rows: 1, cols: 2 }
+        );
+        assert_eq!(
+            CorrelationMatrix::from_rows(&[vec![1.0, 0.5], vec![0.4, 1.0]], None).unwrap_err(),
+            CorrelationError::NotSymmetric { row: 0, col: 1 }
+        );
+        assert_eq!(
+            CorrelationMatrix::from_rows(
+                &[
+                    vec![1.0, 0.99, 0.99],
+                    vec![0.99, 1.0, -0.99],
+                    vec![0.99, -0.99, 1.0]
+                ],
+                None
+            )
+            .unwrap_err(),
+            CorrelationError::NotPositiveSemiDefinite
+        );
+    }
+
     #[test]
     fn test_bivariate_correlation() {
         let (x, y) = sample_bivariate(10000, 0.8, 0.0, 1.0, 0.0, 1.0, Some(42)).unwrap();
@@ -452,4 +724,53 @@ mod tests {
         assert!((mean0 - 0.0).abs() < 0.5);
         assert!((mean1 - 10.0).abs() < 1.0);
     }
+
+    #[test]
+    fn test_nearest_correlation_matrix() {
+        // Higham (2002), section 4: not PSD (eigenvalues 1 - sqrt(2), 1, 1 + sqrt(2))
+        let matrix = vec![
+            vec![1.0, 1.0, 0.0],
+            vec![1.0, 1.0, 1.0],
+            vec![0.0, 1.0, 1.0],
+        ];
+        assert_eq!(
+            CorrelationMatrix::from_rows(&matrix, None).unwrap_err(),
+            CorrelationError::NotPositiveSemiDefinite
+        );
+
+        let (repaired, distance) = nearest_correlation_matrix(&matrix).unwrap();
+        let expected = [
+            [1.0, 0.7607, 0.1573],
+            [0.7607, 1.0, 0.7607],
+            [0.1573, 0.7607, 1.0],
+        ];
+        for (row, expected_row) in repaired.iter().zip(&expected) {
+            for (v, e) in row.iter().zip(expected_row) {
+                assert!((v - e).abs() < 1e-3, "{:?}", repaired);
+            }
+        }
+        assert!((distance - 0.5278).abs() < 1e-3, "{}", distance);
+        assert!(DMatrix::from_fn(3, 3, |i, j| repaired[i][j])
+            .cholesky()
+            .is_some());
+
+        // Valid matrices are left alone
+        let valid = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+        let (same, distance) = nearest_correlation_matrix(&valid).unwrap();
+        assert!(distance < 1e-12);
+        assert!((same[0][1] - 0.5).abs() < 1e-12);
+
+        assert!(matches!(
+            nearest_correlation_matrix(&[vec![1.0, 0.2], vec![0.3, 1.0]]),
+            Err(CorrelationError::NotSymmetric { .. })
+        ));
+        assert!(matches!(
+            nearest_correlation_matrix(&[vec![1.0, 0.2]]),
+            Err(CorrelationError::NotSquare { .. })
+        ));
+        assert_eq!(
+            nearest_correlation_matrix(&[]),
+            Err(CorrelationError::Empty)
+        );
+    }
 }
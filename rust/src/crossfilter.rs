@@ -1,7 +1,7 @@
+use crate::rng::{seeded_rng, SeededRng};
 use chrono::{Datelike, Duration as ChronoDuration, NaiveDateTime, Timelike, Utc};
-use rand::rngs::StdRng;
 use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 use uuid::Uuid;
@@ -890,11 +890,8 @@ const NOUNS: [&str; 20] = [
 ];
 
 /// Create an RNG from an optional seed
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    }
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
 }
 
 fn generate_name<R: Rng>(rng: &mut R) -> String {
@@ -7,14 +7,19 @@
 //! - Product catalog with categories and pricing
 //! - Conversion funnels with realistic drop-off rates
 
-use chrono::{Duration, NaiveDateTime, Utc};
-use rand::rngs::StdRng;
-use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use crate::rng::{seeded_rng, SeededRng};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Utc};
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, RngExt};
 use rand_distr::{Distribution, Exp, LogNormal};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use crate::temporal::MarkovChain;
+use crate::distributions::sample_categorical;
+use crate::general::{
+    currency_minor_units, locale_currency, unsupported_locale_warning, PaymentMethod,
+};
+use crate::temporal::{MarkovChain, TemporalError};
 
 // =============================================================================
 // Constants
@@ -46,6 +51,9 @@ const LANDING_PAGES: &[&str] = &[
     "/sale",
     "/new-arrivals",
 ];
+const LANDING_PAGE_WEIGHTS: &[f64] = &[0.30, 0.15, 0.10, 0.10, 0.10, 0.15, 0.10];
+/// Relative conversion of sessions entering on each landing page
+const LANDING_PAGE_CONVERSION: &[f64] = &[0.8, 1.0, 1.1, 1.1, 1.1, 1.5, 0.9];
 
 const PRODUCT_CATEGORIES: &[&str] = &[
     "Electronics",
@@ -58,6 +66,71 @@ const PRODUCT_CATEGORIES: &[&str] = &[
     "Food",
 ];
 
+const VARIANT_COLORS: &[&str] = &["Black", "White", "Gray", "Navy", "Red", "Green"];
+const VARIANT_SIZES: &[&str] = &["XS", "S", "M", "L", "XL"];
+
+/// Order payment method weights, in `PaymentMethod::ALL` order
+const ORDER_PAYMENT_WEIGHTS: [f64; 8] = [0.38, 0.19, 0.15, 0.10, 0.09, 0.03, 0.04, 0.02];
+
+/// Default brand pools per product category, most popular first
+const CATEGORY_BRANDS: &[(&str, &[&str])] = &[
+    (
+        "Electronics",
+        &["Voltix", "Nexa", "Pixelon", "Auralis", "Cobalt", "Zentri"],
+    ),
+    (
+        "Clothing",
+        &["Threadline", "Northwind", "Urbane", "Kinfolk", "Solstice"],
+    ),
+    (
+        "Home & Garden",
+        &[
+            "Hearthstone",
+            "Greenleaf",
+            "Oakhaven",
+            "Casa Nova",
+            "Bloomfield",
+        ],
+    ),
+    (
+        "Sports",
+        &["Stridemax", "Peakform", "Trailhead", "Velocity", "Ironclad"],
+    ),
+    (
+        "Beauty",
+        &["Lumiere", "Velvet Bloom", "Purecrest", "Aurora", "Silkroot"],
+    ),
+    (
+        "Books",
+        &[
+            "Penwright Press",
+            "Lanternhouse",
+            "Inkwell",
+            "Meridian",
+            "Foxglove",
+        ],
+    ),
+    (
+        "Toys",
+        &["Brickle", "Wonderbox", "Tumbletown", "Kiddo", "Starfield"],
+    ),
+    (
+        "Food",
+        &[
+            "Harvest Table",
+            "Golden Acre",
+            "Brookside",
+            "Saltwind",
+            "Orchard Lane",
+        ],
+    ),
+];
+
+/// Page views a session may take after landing before it is cut off
+const MAX_SESSION_STEPS: usize = 50;
+/// Exit transitions are scaled by at most `e^20` either way when calibrating
+const EXIT_LOG_SCALE_BOUND: f64 = 20.0;
+
 const SESSION_STATES: &[&str] = &[
     "landing",
     "browse",
@@ -70,6 +143,149 @@ const SESSION_STATES: &[&str] = &[
     "exit",
 ];
 
+/// Market settings for a supported locale. The currency and its rate come
+/// from the shared superstore locale table (see [`MarketLocale::currency`])
+struct MarketLocale {
+    code: &'static str,
+    /// Sales tax / VAT rate applied to orders
+    tax_rate: f64,
+    /// Order subtotal (local currency) above which shipping may be free
+    free_shipping_threshold: f64,
+    /// Word used for generic product names
+    item_word: &'static str,
+    /// Localized names for the default product categories
+    categories: &'static [&'static str],
+    email_names: &'static [&'static str],
+    email_domains: &'static [&'static str],
+}
+
+const MARKET_LOCALES: &[MarketLocale] = &[
+    MarketLocale {
+        code: "en_US",
+        tax_rate: 0.08,
+        free_shipping_threshold: 50.0,
+        item_word: "Item",
+        categories: PRODUCT_CATEGORIES,
+        email_names: &[
+            "john", "jane", "mike", "emma", "alex", "sarah", "chris", "lisa",
+        ],
+        email_domains: &["gmail.com", "yahoo.com", "outlook.com", "email.com"],
+    },
+    MarketLocale {
+        code: "en_GB",
+        tax_rate: 0.20,
+        free_shipping_threshold: 40.0,
+        item_word: "Item",
+        categories: PRODUCT_CATEGORIES,
+        email_names: &[
+            "oliver", "amelia", "harry", "isla", "george", "ava", "jack", "poppy",
+        ],
+        email_domains: &["gmail.com", "btinternet.com", "outlook.co.uk", "sky.com"],
+    },
+    MarketLocale {
+        code: "de_DE",
+        tax_rate: 0.19,
+        free_shipping_threshold: 50.0,
+        item_word: "Artikel",
+        categories: &[
+            "Elektronik",
+            "Bekleidung",
+            "Haus & Garten",
+            "Sport",
+            "Beauty",
+            "Bücher",
+            "Spielzeug",
+            "Lebensmittel",
+        ],
+        email_names: &[
+            "lukas",
+            "anna",
+            "maximilian",
+            "lena",
+            "felix",
+            "marie",
+            "jonas",
+            "sophie",
+        ],
+        email_domains: &["web.de", "gmx.de", "t-online.de", "gmail.com"],
+    },
+    MarketLocale {
+        code: "fr_FR",
+        tax_rate: 0.20,
+        free_shipping_threshold: 50.0,
+        item_word: "Article",
+        categories: &[
+            "Électronique",
+            "Vêtements",
+            "Maison & Jardin",
+            "Sport",
+            "Beauté",
+            "Livres",
+            "Jouets",
+            "Alimentation",
+        ],
+        email_names: &[
+            "louis", "camille", "hugo", "chloe", "lucas", "manon", "jules", "ines",
+        ],
+        email_domains: &["orange.fr", "free.fr", "laposte.net", "gmail.com"],
+    },
+    MarketLocale {
+        code: "ja_JP",
+        tax_rate: 0.10,
+        free_shipping_threshold: 5000.0,
+        item_word: "商品",
+        categories: &[
+            "家電",
+            "ファッション",
+            "ホーム＆ガーデン",
+            "スポーツ",
+            "ビューティー",
+            "本",
+            "おもちゃ",
+            "食品",
+        ],
+        email_names: &[
+            "haruto", "yui", "sota", "hina", "yuto", "sakura", "riku", "aoi",
+        ],
+        email_domains: &["docomo.ne.jp", "yahoo.co.jp", "gmail.com", "icloud.com"],
+    },
+];
+
+impl MarketLocale {
+    /// Currency code of the market
+    fn currency(&self) -> &'static str {
+        self.currency_rate().0
+    }
+
+    /// Units of local currency per USD
+    fn usd_rate(&self) -> f64 {
+        self.currency_rate().1
+    }
+
+    /// Round an amount to the currency's minor units (whole yen, cents)
+    fn round_amount(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(currency_minor_units(self.currency()));
+        (value * scale).round() / scale
+    }
+
+    /// Round a price to the currency's minor units, ending prices above
+    /// 1000 minor units in 99 (14.99 USD, 1499 JPY)
+    fn price_point(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(currency_minor_units(self.currency()));
+        let price = (value * scale).round() / scale;
+        let step = 100.0 / scale;
+        if price > 10.0 * step {
+            (price / step).floor() * step + 99.0 / scale
+        } else {
+            price
+        }
+    }
+
+    fn currency_rate(&self) -> (&'static str, f64) {
+        locale_currency(self.code).expect("every market locale is a superstore locale")
+    }
+}
+
 // RFM segment names based on scores
 const RFM_SEGMENTS: &[&str] = &[
     "Champions",
@@ -105,6 +321,25 @@ pub struct SessionConfig {
     pub enable_bounces: bool,
     /// Bounce rate (probability of immediate exit)
     pub bounce_rate: f64,
+    /// Custom navigation states, entry state first (replaces the built-in
+    /// retail states; requires `custom_transition_matrix`)
+    pub custom_states: Option<Vec<String>>,
+    /// Row-stochastic transition matrix over `custom_states`
+    pub custom_transition_matrix: Option<Vec<Vec<f64>>>,
+    /// State that ends a session as converted
+    pub purchase_state: String,
+    /// State that ends a session without converting
+    pub exit_state: String,
+    /// Overall share of sessions that should convert; when set, exits are
+    /// rescaled so the compounded funnel hits this rate
+    pub target_conversion_rate: Option<f64>,
+    /// Landing page selection weights, overriding the defaults for the
+    /// pages listed (e.g. `{"/sale": 0.4}`)
+    pub landing_page_weights: HashMap<String, f64>,
+    /// Landing page conversion multipliers, overriding the defaults for the
+    /// pages listed. Multipliers are relative: they move conversions between
+    /// pages but leave the overall rate unchanged.
+    pub landing_page_conversion: HashMap<String, f64>,
 }
 
 impl Default for SessionConfig {
@@ -117,6 +352,188 @@ impl Default for SessionConfig {
             avg_session_duration_seconds: 300,
             enable_bounces: true,
             bounce_rate: 0.35,
+            custom_states: None,
+            custom_transition_matrix: None,
+            purchase_state: "purchase".to_string(),
+            exit_state: "exit".to_string(),
+            target_conversion_rate: None,
+            landing_page_weights: HashMap::new(),
+            landing_page_conversion: HashMap::new(),
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Build the navigation chain: the built-in retail states, or
+    /// `custom_states` with `custom_transition_matrix` when both are set.
+    ///
+    /// Sessions start in the first state and end on reaching `purchase_state`
+    /// or `exit_state`, or after 50 steps. With `target_conversion_rate` set,
+    /// the chain is calibrated to it (see [`SessionConfig::calibrated_matrix`]).
+    ///
+    /// Errors if the chain is invalid or either terminal state is not one of
+    /// its states.
+    pub fn markov_chain(&self) -> Result<MarkovChain, TemporalError> {
+        let (states, matrix) = self.chain_matrix()?;
+        MarkovChain::new(matrix, states)
+    }
+
+    /// Share of sessions expected to convert: those that do not bounce and
+    /// reach `purchase_state` before `exit_state` within the step limit
+    pub fn expected_conversion_rate(&self) -> Result<f64, TemporalError> {
+        let (states, matrix) = self.chain_matrix()?;
+        let (purchase, exit) = self.terminal_states(&states)?;
+        Ok((1.0 - self.bounce_probability()) * chain_conversion_rate(&matrix, purchase, exit))
+    }
+
+    /// Scale every transition into `exit_state` by a common factor, solved
+    /// by bisection, so that [`SessionConfig::expected_conversion_rate`]
+    /// equals `target`. The relative weights of all other transitions are
+    /// kept, so the shape of the funnel is unchanged.
+    ///
+    /// Errors if the chain is invalid, or if `target` cannot be reached (for
+    /// example above `1 - bounce_rate`).
+    pub fn calibrated_matrix(
+        &self,
+        target: f64,
+    ) -> Result<(Vec<String>, Vec<Vec<f64>>), TemporalError> {
+        let (states, matrix) = self.base_matrix()?;
+        MarkovChain::new(matrix.clone(), states.clone())?;
+        let (purchase, exit) = self.terminal_states(&states)?;
+
+        let stay = 1.0 - self.bounce_probability();
+        let matrix = calibrate_exit_transitions(&matrix, purchase, exit, stay, target)?;
+        Ok((states, matrix))
+    }
+
+    /// One navigation chain per landing page, with the page and its
+    /// selection weight. Each chain's exits are rescaled so the page
+    /// converts at its multiplier (relative to the weighted mean multiplier)
+    /// times the rate of [`SessionConfig::markov_chain`].
+    ///
+    /// Errors if the chain is invalid, a weight or multiplier is negative or
+    /// names an unknown page, or a page's rate cannot be reached.
+    pub fn landing_chains(&self) -> Result<Vec<(String, f64, MarkovChain)>, TemporalError> {
+        let (states, matrix) = self.chain_matrix()?;
+        let invalid = TemporalError::InvalidProbabilities;
+        for (name, values) in [
+            ("landing_page_weights", &self.landing_page_weights),
+            ("landing_page_conversion", &self.landing_page_conversion),
+        ] {
+            for (page, &value) in values {
+                if !LANDING_PAGES.contains(&page.as_str()) {
+                    return Err(invalid(format!(
+                        "{} has unknown landing page '{}' (expected one of {:?})",
+                        name, page, LANDING_PAGES
+                    )));
+                }
+                if !(value.is_finite() && value >= 0.0) {
+                    return Err(invalid(format!("{} for '{}' must be >= 0", name, page)));
+                }
+            }
+        }
+
+        let lookup = |values: &HashMap<String, f64>, defaults: &[f64]| -> Vec<f64> {
+            LANDING_PAGES
+                .iter()
+                .zip(defaults)
+                .map(|(page, &d)| values.get(*page).copied().unwrap_or(d))
+                .collect()
+        };
+        let weights = lookup(&self.landing_page_weights, LANDING_PAGE_WEIGHTS);
+        let multipliers = lookup(&self.landing_page_conversion, LANDING_PAGE_CONVERSION);
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err(invalid(
+                "landing_page_weights must not all be zero".to_string(),
+            ));
+        }
+        let mean = weights
+            .iter()
+            .zip(&multipliers)
+            .map(|(w, m)| w * m)
+            .sum::<f64>()
+            / total;
+
+        let (purchase, exit) = self.terminal_states(&states)?;
+        let base_rate = chain_conversion_rate(&matrix, purchase, exit);
+        LANDING_PAGES
+            .iter()
+            .zip(weights.iter().zip(&multipliers))
+            .map(|(page, (&weight, &multiplier))| {
+                let relative = if mean > 0.0 { multiplier / mean } else { 1.0 };
+                let page_matrix = if (relative - 1.0).abs() < 1e-12 || base_rate == 0.0 {
+                    matrix.clone()
+                } else {
+                    // Rates the chain cannot reach are capped at the extremes
+                    let (lo, hi) = exit_scaling_range(&matrix, purchase, exit);
+                    let target = (base_rate * relative).clamp(lo, hi);
+                    calibrate_exit_transitions(&matrix, purchase, exit, 1.0, target)?
+                };
+                Ok((
+                    page.to_string(),
+                    weight,
+                    MarkovChain::new(page_matrix, states.clone())?,
+                ))
+            })
+            .collect()
+    }
+
+    /// States and transition matrix before calibration
+    fn base_matrix(&self) -> Result<(Vec<String>, Vec<Vec<f64>>), TemporalError> {
+        match (&self.custom_states, &self.custom_transition_matrix) {
+            (None, None) => Ok((
+                SESSION_STATES.iter().map(|s| s.to_string()).collect(),
+                build_session_transition_matrix(self),
+            )),
+            (Some(states), Some(matrix)) => {
+                if states.is_empty() {
+                    return Err(TemporalError::InvalidProbabilities(
+                        "custom_states must not be empty".to_string(),
+                    ));
+                }
+                Ok((states.clone(), matrix.clone()))
+            }
+            _ => Err(TemporalError::InvalidProbabilities(
+                "custom_states and custom_transition_matrix must be given together".to_string(),
+            )),
+        }
+    }
+
+    /// States and transition matrix used for simulation
+    fn chain_matrix(&self) -> Result<(Vec<String>, Vec<Vec<f64>>), TemporalError> {
+        match self.target_conversion_rate {
+            Some(target) => self.calibrated_matrix(target),
+            None => {
+                let (states, matrix) = self.base_matrix()?;
+                MarkovChain::new(matrix.clone(), states.clone())?;
+                self.terminal_states(&states)?;
+                Ok((states, matrix))
+            }
+        }
+    }
+
+    /// Indices of `purchase_state` and `exit_state`, which must both be states
+    fn terminal_states(&self, states: &[String]) -> Result<(usize, usize), TemporalError> {
+        let index = |field: &str, name: &str| {
+            states.iter().position(|s| s == name).ok_or_else(|| {
+                TemporalError::InvalidProbabilities(format!(
+                    "{} '{}' is not one of the states {:?}",
+                    field, name, states
+                ))
+            })
+        };
+        Ok((
+            index("purchase_state", &self.purchase_state)?,
+            index("exit_state", &self.exit_state)?,
+        ))
+    }
+
+    fn bounce_probability(&self) -> f64 {
+        if self.enable_bounces {
+            self.bounce_rate
+        } else {
+            0.0
         }
     }
 }
@@ -128,7 +545,8 @@ pub struct CartConfig {
     pub avg_items_per_cart: f64,
     /// Probability of removing an item from cart
     pub remove_probability: f64,
-    /// Probability of updating quantity
+    /// Probability of changing an added item's quantity (emits an
+    /// `update_quantity` event)
     pub quantity_update_probability: f64,
     /// Maximum items per cart
     pub max_items: u32,
@@ -164,6 +582,13 @@ pub struct CatalogConfig {
     pub lognormal_prices: bool,
     /// Categories to use (defaults to standard categories)
     pub categories: Vec<String>,
+    /// Brand pool per category, most popular first. Categories without a
+    /// pool get generic "<Category> Brand A".."E" brands.
+    pub brands: HashMap<String, Vec<String>>,
+    /// Zipf exponent for brand popularity within a category (0 = uniform)
+    pub brand_zipf_exponent: f64,
+    /// SKUs per base product, differing by size/color (1 = no variants)
+    pub variants_per_product: usize,
 }
 
 impl Default for CatalogConfig {
@@ -174,6 +599,17 @@ impl Default for CatalogConfig {
             max_price: 1000.0,
             lognormal_prices: true,
             categories: PRODUCT_CATEGORIES.iter().map(|s| s.to_string()).collect(),
+            brands: CATEGORY_BRANDS
+                .iter()
+                .map(|(category, brands)| {
+                    (
+                        category.to_string(),
+                        brands.iter().map(|b| b.to_string()).collect(),
+                    )
+                })
+                .collect(),
+            brand_zipf_exponent: 1.1,
+            variants_per_product: 1,
         }
     }
 }
@@ -232,6 +668,22 @@ impl Default for FunnelConfig {
     }
 }
 
+/// Configuration for the user-product interaction graph
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InteractionConfig {
+    /// Zipf exponent of product popularity (0 = uniform, higher = more
+    /// concentrated on a few popular products)
+    pub popularity_exponent: f64,
+}
+
+impl Default for InteractionConfig {
+    fn default() -> Self {
+        Self {
+            popularity_exponent: 1.0,
+        }
+    }
+}
+
 /// Full e-commerce configuration
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EcommerceConfig {
@@ -255,6 +707,34 @@ pub struct EcommerceConfig {
     pub rfm: RfmConfig,
     /// Funnel configuration
     pub funnel: FunnelConfig,
+    /// Interaction graph configuration
+    pub interactions: InteractionConfig,
+    /// Market locale ("en_US", "en_GB", "de_DE", "fr_FR", or "ja_JP"); sets
+    /// the currency, tax rate, email domains and product naming. Catalog
+    /// prices are given in USD and converted to the local currency at the
+    /// same fixed rates as the superstore generator. Unknown locales fall
+    /// back to en_US (see [`EcommerceConfig::locale_warning`]).
+    pub locale: String,
+    /// Minimum order subtotal, in the market currency; sessions reaching
+    /// checkout below it are handled according to `min_order_policy`
+    pub min_order_value: Option<f64>,
+    /// What happens to sessions whose basket is below `min_order_value`
+    pub min_order_policy: MinOrderPolicy,
+}
+
+/// How baskets below [`EcommerceConfig::min_order_value`] are handled.
+///
+/// The policy is applied when sessions are generated, so sessions, cart
+/// events, funnel events and orders all agree on which sessions converted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinOrderPolicy {
+    /// Top the basket up to the floor; the session converts with
+    /// `total_value` equal to `min_order_value`
+    #[default]
+    Bump,
+    /// Abandon at checkout; the session does not convert and has no order
+    Drop,
 }
 
 impl Default for EcommerceConfig {
@@ -270,10 +750,22 @@ impl Default for EcommerceConfig {
             catalog: CatalogConfig::default(),
             rfm: RfmConfig::default(),
             funnel: FunnelConfig::default(),
+            interactions: InteractionConfig::default(),
+            locale: "en_US".to_string(),
+            min_order_value: None,
+            min_order_policy: MinOrderPolicy::default(),
         }
     }
 }
 
+impl EcommerceConfig {
+    /// Message for a `locale` without market data of its own, which falls
+    /// back to en_US
+    pub fn locale_warning(&self) -> Option<String> {
+        unsupported_locale_warning(&self.locale)
+    }
+}
+
 // =============================================================================
 // Data Structures
 // =============================================================================
@@ -282,10 +774,19 @@ impl Default for EcommerceConfig {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Product {
     pub product_id: String,
+    /// Base product shared by all variants (equal to `product_id` without variants)
+    pub parent_product_id: String,
+    pub sku: String,
     pub name: String,
     pub category: String,
     pub subcategory: String,
+    pub brand: String,
+    /// Variant size (None without variants)
+    pub size: Option<String>,
+    /// Variant color (None without variants)
+    pub color: Option<String>,
     pub price: f64,
+    pub currency: String,
     pub rating: f64,
     pub review_count: u32,
     pub in_stock: bool,
@@ -307,6 +808,7 @@ pub struct Session {
     pub bounced: bool,
     pub converted: bool,
     pub total_value: f64,
+    pub currency: String,
 }
 
 /// A cart event (add, remove, update)
@@ -317,9 +819,14 @@ pub struct CartEvent {
     pub user_id: String,
     pub timestamp: String,
     pub event_type: String,
-    pub product_id: String,
+    /// Product added or removed; `None` for checkout and abandonment events
+    pub product_id: Option<String>,
     pub quantity: u32,
+    /// Product price at the time of the event (0 for checkout and
+    /// abandonment events)
     pub unit_price: f64,
+    /// `unit_price * quantity` for item events; the running cart total
+    /// (adds minus removes so far) for checkout and abandonment events
     pub total_price: f64,
 }
 
@@ -336,6 +843,7 @@ pub struct Order {
     pub tax: f64,
     pub shipping: f64,
     pub total: f64,
+    pub currency: String,
     pub payment_method: String,
     pub status: String,
 }
@@ -381,31 +889,111 @@ pub struct FunnelEvent {
     pub time_in_stage_seconds: u32,
 }
 
+/// An edge of the bipartite user-product interaction graph
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interaction {
+    pub user_id: String,
+    pub product_id: String,
+    /// "view", "add_to_cart", or "purchase"
+    pub interaction_type: String,
+    /// Number of interactions of this type between the user and product
+    pub weight: u32,
+}
+
+/// Period granularity for cohort retention
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CohortPeriod {
+    Day,
+    Week,
+    Month,
+}
+
+impl CohortPeriod {
+    /// Parse a period name ("day", "week", or "month")
+    pub fn parse(period: &str) -> Option<Self> {
+        match period.to_lowercase().as_str() {
+            "day" | "d" => Some(CohortPeriod::Day),
+            "week" | "w" => Some(CohortPeriod::Week),
+            "month" | "m" => Some(CohortPeriod::Month),
+            _ => None,
+        }
+    }
+
+    /// Sequential index of the period containing `date`
+    fn index(&self, date: NaiveDate) -> i64 {
+        let days = date.num_days_from_ce() as i64;
+        match self {
+            CohortPeriod::Day => days,
+            CohortPeriod::Week => (days - date.weekday().num_days_from_monday() as i64) / 7,
+            CohortPeriod::Month => date.year() as i64 * 12 + date.month0() as i64,
+        }
+    }
+
+    /// Label of the period containing `date` (weeks are labelled by their Monday)
+    fn label(&self, date: NaiveDate) -> String {
+        match self {
+            CohortPeriod::Day => date.format("%Y-%m-%d").to_string(),
+            CohortPeriod::Week => {
+                let monday = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+                monday.format("%Y-%m-%d").to_string()
+            }
+            CohortPeriod::Month => date.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+/// A span of days over which a customer was active.
+///
+/// Orders and billing events are single-day spans; a subscription or a
+/// customer's first-to-last order window covers every period in between.
+/// An open `end` means the customer is still active at the end of the data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActivitySpan {
+    pub customer_id: String,
+    pub start: NaiveDate,
+    pub end: Option<NaiveDate>,
+}
+
+/// One row of a cohort retention matrix
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CohortRetention {
+    /// Label of the acquisition period
+    pub cohort: String,
+    /// Customers acquired in this period
+    pub cohort_size: usize,
+    /// Share of the cohort active `k` periods after acquisition; one entry
+    /// per period observed, so later cohorts have shorter rows
+    pub retention: Vec<f64>,
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
 
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    }
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
 }
 
 fn generate_id<R: Rng>(rng: &mut R, prefix: &str) -> String {
     format!("{}-{:08x}", prefix, rng.random::<u32>())
 }
 
-fn generate_email<R: Rng>(rng: &mut R) -> String {
-    let names = &[
-        "john", "jane", "mike", "emma", "alex", "sarah", "chris", "lisa",
-    ];
-    let domains = &["gmail.com", "yahoo.com", "outlook.com", "email.com"];
+/// Look up market settings by locale code ("de_DE" or "de-DE"), falling
+/// back to en_US for unknown locales (see [`EcommerceConfig::locale_warning`])
+fn market_locale(code: &str) -> &'static MarketLocale {
+    let code = code.replace('-', "_");
+    MARKET_LOCALES
+        .iter()
+        .find(|l| l.code.eq_ignore_ascii_case(&code))
+        .unwrap_or(&MARKET_LOCALES[0])
+}
+
+fn generate_email<R: Rng>(rng: &mut R, locale: &MarketLocale) -> String {
     format!(
         "{}{}@{}",
-        names.choose(rng).unwrap(),
+        locale.email_names.choose(rng).unwrap(),
         rng.random_range(1..9999),
-        domains.choose(rng).unwrap()
+        locale.email_domains.choose(rng).unwrap()
     )
 }
 
@@ -432,6 +1020,109 @@ fn weighted_choice<'a, R: Rng>(rng: &mut R, items: &[&'a str], weights: &[f64])
     items[0]
 }
 
+/// Probability that a session walking `matrix` from state 0 reaches
+/// `purchase` before `exit` within `MAX_SESSION_STEPS` steps
+fn chain_conversion_rate(matrix: &[Vec<f64>], purchase: usize, exit: usize) -> f64 {
+    let mut mass = vec![0.0; matrix.len()];
+    mass[0] = 1.0;
+    let mut converted = 0.0;
+    for _ in 0..MAX_SESSION_STEPS {
+        let mut next = vec![0.0; matrix.len()];
+        for (row, &m) in matrix.iter().zip(&mass) {
+            if m > 0.0 {
+                for (n, &p) in next.iter_mut().zip(row) {
+                    *n += m * p;
+                }
+            }
+        }
+        converted += next[purchase];
+        next[purchase] = 0.0;
+        next[exit] = 0.0;
+        mass = next;
+    }
+    converted
+}
+
+/// Price of `quantity` units; the one place cart line totals are computed
+fn line_total(unit_price: f64, quantity: u32) -> f64 {
+    unit_price * quantity as f64
+}
+
+/// Scale every transition into `exit` by a common factor, solved by
+/// bisection, so that `stay` times the chain's conversion rate equals `target`
+fn calibrate_exit_transitions(
+    matrix: &[Vec<f64>],
+    purchase: usize,
+    exit: usize,
+    stay: f64,
+    target: f64,
+) -> Result<Vec<Vec<f64>>, TemporalError> {
+    let rate = |log_scale: f64| stay * exit_scaled_rate(matrix, purchase, exit, log_scale);
+
+    // Conversion falls as exits get more likely
+    let (mut lo, mut hi) = (-EXIT_LOG_SCALE_BOUND, EXIT_LOG_SCALE_BOUND);
+    let (max_rate, min_rate) = (rate(lo), rate(hi));
+    if !(target > 0.0 && target >= min_rate && target <= max_rate) {
+        return Err(TemporalError::InvalidProbabilities(format!(
+            "target conversion rate {} is outside the reachable range {:.6}..{:.6}",
+            target, min_rate, max_rate
+        )));
+    }
+    for _ in 0..100 {
+        let mid = (lo + hi) / 2.0;
+        if rate(mid) > target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(scale_exit_transitions(
+        matrix,
+        purchase,
+        exit,
+        ((lo + hi) / 2.0).exp(),
+    ))
+}
+
+/// Lowest and highest conversion rate [`calibrate_exit_transitions`] can
+/// reach (before bounces)
+fn exit_scaling_range(matrix: &[Vec<f64>], purchase: usize, exit: usize) -> (f64, f64) {
+    (
+        exit_scaled_rate(matrix, purchase, exit, EXIT_LOG_SCALE_BOUND),
+        exit_scaled_rate(matrix, purchase, exit, -EXIT_LOG_SCALE_BOUND),
+    )
+}
+
+fn exit_scaled_rate(matrix: &[Vec<f64>], purchase: usize, exit: usize, log_scale: f64) -> f64 {
+    let scaled = scale_exit_transitions(matrix, purchase, exit, log_scale.exp());
+    chain_conversion_rate(&scaled, purchase, exit)
+}
+
+/// Multiply transitions into `exit` by `scale` and renormalize each row
+/// (the terminal purchase and exit rows are left as they are)
+fn scale_exit_transitions(
+    matrix: &[Vec<f64>],
+    purchase: usize,
+    exit: usize,
+    scale: f64,
+) -> Vec<Vec<f64>> {
+    matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            if i != purchase && i != exit {
+                row[exit] *= scale;
+                let sum: f64 = row.iter().sum();
+                if sum > 0.0 {
+                    row.iter_mut().for_each(|p| *p /= sum);
+                }
+            }
+            row
+        })
+        .collect()
+}
+
 /// Build a session state transition matrix for MarkovChain
 /// Ensures all rows sum to 1.0
 fn build_session_transition_matrix(config: &SessionConfig) -> Vec<Vec<f64>> {
@@ -582,10 +1273,20 @@ fn get_rfm_segment(r: u32, f: u32, m: u32) -> &'static str {
 // Product Catalog Generator
 // =============================================================================
 
-/// Generate a product catalog
+/// Generate a product catalog.
+///
+/// The catalog is a pure function of `config`, so generating it again with
+/// the same config gives the same products (and product ids) that
+/// [`ecommerce`] joins its cart events against.
+///
+/// With `variants_per_product > 1`, each base product expands into that many
+/// SKUs with distinct size/color combinations. Variants share the parent id,
+/// category, brand, price and rating, but have their own stock status.
 pub fn generate_catalog(config: &EcommerceConfig) -> Vec<Product> {
     let mut rng = create_rng(config.seed);
-    let mut products = Vec::with_capacity(config.catalog.num_products);
+    let locale = market_locale(&config.locale);
+    let variants = config.catalog.variants_per_product.max(1);
+    let mut products = Vec::with_capacity(config.catalog.num_products * variants);
 
     let categories = if config.catalog.categories.is_empty() {
         PRODUCT_CATEGORIES.iter().map(|s| s.to_string()).collect()
@@ -610,6 +1311,25 @@ pub fn generate_catalog(config: &EcommerceConfig) -> Vec<Product> {
         vec!["Snacks", "Beverages", "Organic", "International", "Pantry"],
     ];
 
+    // Brand pools and Zipf popularity weights, per category
+    let brand_pools: Vec<Vec<String>> = categories
+        .iter()
+        .map(|category| match config.catalog.brands.get(category) {
+            Some(pool) if !pool.is_empty() => pool.clone(),
+            _ => ('A'..='E')
+                .map(|c| format!("{} Brand {}", category, c))
+                .collect(),
+        })
+        .collect();
+    let brand_weights: Vec<Vec<f64>> = brand_pools
+        .iter()
+        .map(|pool| {
+            (1..=pool.len())
+                .map(|rank| 1.0 / (rank as f64).powf(config.catalog.brand_zipf_exponent))
+                .collect()
+        })
+        .collect();
+
     // Price distribution
     let ln_mean = ((config.catalog.max_price + config.catalog.min_price) / 2.0).ln();
     let ln_std = 1.0;
@@ -620,6 +1340,8 @@ pub fn generate_catalog(config: &EcommerceConfig) -> Vec<Product> {
         let category = categories[cat_idx].clone();
         let subcats = &subcategories[cat_idx % subcategories.len()];
         let subcategory = subcats.choose(&mut rng).unwrap().to_string();
+        let brand =
+            brand_pools[cat_idx][sample_categorical(&mut rng, &brand_weights[cat_idx])].clone();
 
         let price = if config.catalog.lognormal_prices {
             price_dist
@@ -629,24 +1351,71 @@ pub fn generate_catalog(config: &EcommerceConfig) -> Vec<Product> {
             rng.random_range(config.catalog.min_price..config.catalog.max_price)
         };
 
-        // Round to common price points
-        let price = (price * 100.0).round() / 100.0;
-        let price = if price > 10.0 {
-            price.floor() + 0.99
-        } else {
-            price
-        };
+        // Round to common price points in the local currency
+        let price = locale.price_point(price * locale.usd_rate());
+
+        // Default categories are named in the local language
+        let category_name = PRODUCT_CATEGORIES
+            .iter()
+            .position(|c| *c == category)
+            .map_or(category.as_str(), |idx| locale.categories[idx]);
 
-        products.push(Product {
-            product_id: format!("PROD-{:06}", i + 1),
-            name: format!("{} {} Item {}", category, subcategory, i + 1),
-            category,
+        let parent_id = format!("PROD-{:06}", i + 1);
+        let name = format!(
+            "{} {} {} {}",
+            category_name,
             subcategory,
-            price,
-            rating: 3.0 + rng.random::<f64>() * 2.0,
-            review_count: rng.random_range(0..5000),
-            in_stock: rng.random::<f64>() > 0.05,
-        });
+            locale.item_word,
+            i + 1
+        );
+        let rating = 3.0 + rng.random::<f64>() * 2.0;
+        let review_count = rng.random_range(0..5000);
+
+        if variants == 1 {
+            products.push(Product {
+                product_id: parent_id.clone(),
+                parent_product_id: parent_id,
+                sku: format!("SKU-{:06}", i + 1),
+                name,
+                category,
+                subcategory,
+                brand,
+                size: None,
+                color: None,
+                price,
+                currency: locale.currency().to_string(),
+                rating,
+                review_count,
+                in_stock: rng.random::<f64>() > 0.05,
+            });
+            continue;
+        }
+
+        // Consecutive size/color combinations from a random starting point,
+        // so variants of one product are distinct (up to 30 per product)
+        let combos = VARIANT_COLORS.len() * VARIANT_SIZES.len();
+        let offset = rng.random_range(0..combos);
+        for v in 0..variants {
+            let combo = (offset + v) % combos;
+            let color = VARIANT_COLORS[combo / VARIANT_SIZES.len()];
+            let size = VARIANT_SIZES[combo % VARIANT_SIZES.len()];
+            products.push(Product {
+                product_id: format!("{}-{:02}", parent_id, v + 1),
+                parent_product_id: parent_id.clone(),
+                sku: format!("SKU-{:06}-{:02}", i + 1, v + 1),
+                name: format!("{} - {} / {}", name, color, size),
+                category: category.clone(),
+                subcategory: subcategory.clone(),
+                brand: brand.clone(),
+                size: Some(size.to_string()),
+                color: Some(color.to_string()),
+                price,
+                currency: locale.currency().to_string(),
+                rating,
+                review_count,
+                in_stock: rng.random::<f64>() > 0.05,
+            });
+        }
     }
 
     products
@@ -657,14 +1426,30 @@ pub fn generate_catalog(config: &EcommerceConfig) -> Vec<Product> {
 // =============================================================================
 
 /// Generate user sessions with MarkovChain-based navigation
+///
+/// Panics if `config.session` describes an invalid chain; see
+/// [`SessionConfig::markov_chain`].
 pub fn generate_sessions(config: &EcommerceConfig) -> Vec<Session> {
     let mut rng = create_rng(config.seed);
+    let locale = market_locale(&config.locale);
     let mut sessions = Vec::with_capacity(config.sessions);
 
     let start_time = parse_start_date(&config.start_date);
-    let transition_matrix = build_session_transition_matrix(&config.session);
-    let states: Vec<String> = SESSION_STATES.iter().map(|s| s.to_string()).collect();
-    let mut mc = MarkovChain::new(transition_matrix, states).unwrap();
+    let (pages, weights, mut chains): (Vec<String>, Vec<f64>, Vec<MarkovChain>) = config
+        .session
+        .landing_chains()
+        .expect("invalid session states")
+        .into_iter()
+        .fold(
+            Default::default(),
+            |(mut p, mut w, mut c), (page, weight, chain)| {
+                p.push(page);
+                w.push(weight);
+                c.push(chain);
+                (p, w, c)
+            },
+        );
+    let pages: Vec<&str> = pages.iter().map(String::as_str).collect();
 
     // Pre-generate customer IDs
     let customer_ids: Vec<String> = (0..config.customers)
@@ -674,20 +1459,23 @@ pub fn generate_sessions(config: &EcommerceConfig) -> Vec<Session> {
     let time_dist =
         Exp::new(1.0 / (config.days as f64 * 86400.0 / config.sessions as f64)).unwrap();
 
-    let mut current_time = start_time;
+    // Arrival offsets in seconds from `start_time`, placed once all sessions are drawn
+    let mut offsets = Vec::with_capacity(config.sessions);
+    let mut elapsed = 0.0;
 
     for _i in 0..config.sessions {
         let session_id = generate_id(&mut rng, "SES");
         let user_id = customer_ids.choose(&mut rng).unwrap().clone();
 
         // Time of session
-        let time_delta = time_dist.sample(&mut rng) as i64;
-        current_time = current_time + Duration::seconds(time_delta);
+        elapsed += time_dist.sample(&mut rng);
+        offsets.push(elapsed);
 
         let device = weighted_choice(&mut rng, DEVICE_TYPES, DEVICE_WEIGHTS);
         let browser = weighted_choice(&mut rng, BROWSERS, BROWSER_WEIGHTS);
         let traffic_source = weighted_choice(&mut rng, TRAFFIC_SOURCES, TRAFFIC_SOURCE_WEIGHTS);
-        let landing = LANDING_PAGES.choose(&mut rng).unwrap();
+        let landing = weighted_choice(&mut rng, &pages, &weights);
+        let mc = &mut chains[pages.iter().position(|&p| p == landing).unwrap()];
 
         // Simulate session via MarkovChain
         let mut pages_viewed = 1u32;
@@ -703,18 +1491,29 @@ pub fn generate_sessions(config: &EcommerceConfig) -> Vec<Session> {
 
         if !bounced {
             // Simulate navigation
-            let max_steps = 50;
-            for _ in 0..max_steps {
+            for _ in 0..MAX_SESSION_STEPS {
                 let state_name = mc.next(&mut rng).to_string();
                 pages_viewed += 1;
 
-                if state_name == "purchase" {
-                    converted = true;
-                    // Generate order value
-                    total_value = 20.0 + rng.random::<f64>() * 200.0;
+                if state_name == config.session.purchase_state {
+                    // Generate order value; baskets below the minimum order
+                    // value are topped up or abandoned at checkout
+                    let value = (20.0 + rng.random::<f64>() * 200.0) * locale.usd_rate();
+                    match config.min_order_value {
+                        Some(floor) if value < floor => {
+                            if config.min_order_policy == MinOrderPolicy::Bump {
+                                converted = true;
+                                total_value = floor;
+                            }
+                        }
+                        _ => {
+                            converted = true;
+                            total_value = value;
+                        }
+                    }
                     break;
                 }
-                if state_name == "exit" {
+                if state_name == config.session.exit_state {
                     break;
                 }
             }
@@ -727,13 +1526,11 @@ pub fn generate_sessions(config: &EcommerceConfig) -> Vec<Session> {
             (base * (0.5 + rng.random::<f64>())).round() as u32
         };
 
-        let end_time = current_time + Duration::seconds(duration as i64);
-
         sessions.push(Session {
             session_id,
             user_id,
-            start_time: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
-            end_time: end_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            start_time: String::new(),
+            end_time: String::new(),
             duration_seconds: duration,
             device_type: device.to_string(),
             browser: browser.to_string(),
@@ -743,9 +1540,23 @@ pub fn generate_sessions(config: &EcommerceConfig) -> Vec<Session> {
             bounced,
             converted,
             total_value,
+            currency: locale.currency().to_string(),
         });
     }
 
+    // The gaps have the right mean, but their sum can overshoot the window.
+    // Rescale so the last arrival plus one closing gap still fits inside
+    // `[start, start + days]`.
+    let window = config.days as f64 * 86400.0;
+    let closing_gap = time_dist.sample(&mut rng);
+    let scale = (window / (elapsed + closing_gap)).min(1.0);
+    for (session, offset) in sessions.iter_mut().zip(&offsets) {
+        let start = start_time + Duration::seconds((offset * scale) as i64);
+        let end = start + Duration::seconds(session.duration_seconds as i64);
+        session.start_time = start.format("%Y-%m-%d %H:%M:%S").to_string();
+        session.end_time = end.format("%Y-%m-%d %H:%M:%S").to_string();
+    }
+
     sessions
 }
 
@@ -754,6 +1565,12 @@ pub fn generate_sessions(config: &EcommerceConfig) -> Vec<Session> {
 // =============================================================================
 
 /// Generate cart events based on sessions
+///
+/// Every item event references a product in `products`, so pass the same
+/// catalog instance the events will be joined against (normally
+/// `generate_catalog(config)` for the same config, as [`ecommerce`] does).
+/// Products are drawn by Zipf popularity (`interactions.popularity_exponent`).
+/// An empty catalog yields no events.
 pub fn generate_cart_events(
     sessions: &[Session],
     products: &[Product],
@@ -761,6 +1578,10 @@ pub fn generate_cart_events(
 ) -> Vec<CartEvent> {
     let mut rng = create_rng(config.seed.map(|s| s + 1));
     let mut events = Vec::new();
+    if products.is_empty() {
+        return events;
+    }
+    let popularity = ProductPopularity::new(products, config);
 
     for session in sessions {
         // Skip bounced sessions
@@ -780,10 +1601,15 @@ pub fn generate_cart_events(
         let session_start =
             NaiveDateTime::parse_from_str(&session.start_time, "%Y-%m-%d %H:%M:%S").unwrap();
         let mut current_time = session_start;
+        let mut cart_total = 0.0;
 
         for _ in 0..num_items {
-            let product = products.choose(&mut rng).unwrap();
-            let quantity = rng.random_range(1..=3);
+            let product = &products[popularity.sample(&mut rng)];
+            let mut quantity = rng.random_range(1..=3);
+            // Prices are static, so the add and any later events on the
+            // line share one price
+            let unit_price = product.price;
+            let mut total_price = line_total(unit_price, quantity);
 
             current_time = current_time + Duration::seconds(rng.random_range(10..120));
 
@@ -793,11 +1619,36 @@ pub fn generate_cart_events(
                 user_id: session.user_id.clone(),
                 timestamp: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
                 event_type: "add_to_cart".to_string(),
-                product_id: product.product_id.clone(),
+                product_id: Some(product.product_id.clone()),
                 quantity,
-                unit_price: product.price,
-                total_price: product.price * quantity as f64,
+                unit_price,
+                total_price,
             });
+            cart_total += total_price;
+
+            // Possible quantity change to 1..=5 units
+            if rng.random::<f64>() < config.cart.quantity_update_probability {
+                let mut updated = rng.random_range(1..5);
+                if updated >= quantity {
+                    updated += 1;
+                }
+                current_time += Duration::seconds(rng.random_range(10..90));
+                quantity = updated;
+                cart_total -= total_price;
+                total_price = line_total(unit_price, quantity);
+                cart_total += total_price;
+                events.push(CartEvent {
+                    event_id: generate_id(&mut rng, "EVT"),
+                    session_id: session.session_id.clone(),
+                    user_id: session.user_id.clone(),
+                    timestamp: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    event_type: "update_quantity".to_string(),
+                    product_id: Some(product.product_id.clone()),
+                    quantity,
+                    unit_price,
+                    total_price,
+                });
+            }
 
             // Possible remove
             if rng.random::<f64>() < config.cart.remove_probability {
@@ -808,11 +1659,12 @@ pub fn generate_cart_events(
                     user_id: session.user_id.clone(),
                     timestamp: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
                     event_type: "remove_from_cart".to_string(),
-                    product_id: product.product_id.clone(),
+                    product_id: Some(product.product_id.clone()),
                     quantity,
-                    unit_price: product.price,
-                    total_price: product.price * quantity as f64,
+                    unit_price,
+                    total_price,
                 });
+                cart_total -= total_price;
             }
         }
 
@@ -825,10 +1677,10 @@ pub fn generate_cart_events(
                 user_id: session.user_id.clone(),
                 timestamp: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
                 event_type: "checkout_start".to_string(),
-                product_id: "".to_string(),
+                product_id: None,
                 quantity: 0,
                 unit_price: 0.0,
-                total_price: session.total_value,
+                total_price: cart_total,
             });
 
             current_time = current_time + Duration::seconds(rng.random_range(60..300));
@@ -838,10 +1690,10 @@ pub fn generate_cart_events(
                 user_id: session.user_id.clone(),
                 timestamp: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
                 event_type: "checkout_complete".to_string(),
-                product_id: "".to_string(),
+                product_id: None,
                 quantity: 0,
                 unit_price: 0.0,
-                total_price: session.total_value,
+                total_price: cart_total,
             });
         } else if !events.is_empty() && rng.random::<f64>() < config.cart.abandonment_rate {
             // Abandoned cart
@@ -852,10 +1704,10 @@ pub fn generate_cart_events(
                 user_id: session.user_id.clone(),
                 timestamp: current_time.format("%Y-%m-%d %H:%M:%S").to_string(),
                 event_type: "cart_abandoned".to_string(),
-                product_id: "".to_string(),
+                product_id: None,
                 quantity: 0,
                 unit_price: 0.0,
-                total_price: 0.0,
+                total_price: cart_total,
             });
         }
     }
@@ -870,17 +1722,10 @@ pub fn generate_cart_events(
 /// Generate orders from converted sessions
 pub fn generate_orders(sessions: &[Session], config: &EcommerceConfig) -> Vec<Order> {
     let mut rng = create_rng(config.seed.map(|s| s + 2));
+    let locale = market_locale(&config.locale);
     let mut orders = Vec::new();
 
-    let payment_methods = &[
-        "credit_card",
-        "debit_card",
-        "paypal",
-        "apple_pay",
-        "google_pay",
-        "bank_transfer",
-    ];
-    let payment_weights = &[0.40, 0.20, 0.15, 0.10, 0.10, 0.05];
+    let payment_methods: Vec<&str> = PaymentMethod::ALL.iter().map(|m| m.as_str()).collect();
 
     for session in sessions.iter().filter(|s| s.converted) {
         let items = rng.random_range(1..=5);
@@ -890,14 +1735,15 @@ pub fn generate_orders(sessions: &[Session], config: &EcommerceConfig) -> Vec<Or
         } else {
             0.0
         };
-        let tax = (subtotal - discount) * 0.08;
-        let shipping: f64 = if subtotal > 50.0 && rng.random::<f64>() > 0.3 {
-            0.0
-        } else {
-            rng.random_range(5.0..15.0)
-        };
+        let tax = (subtotal - discount) * locale.tax_rate;
+        let shipping: f64 =
+            if subtotal > locale.free_shipping_threshold && rng.random::<f64>() > 0.3 {
+                0.0
+            } else {
+                rng.random_range(5.0..15.0) * locale.usd_rate()
+            };
 
-        let payment = weighted_choice(&mut rng, payment_methods, payment_weights);
+        let payment = weighted_choice(&mut rng, &payment_methods, &ORDER_PAYMENT_WEIGHTS);
 
         orders.push(Order {
             order_id: generate_id(&mut rng, "ORD"),
@@ -906,10 +1752,11 @@ pub fn generate_orders(sessions: &[Session], config: &EcommerceConfig) -> Vec<Or
             order_time: session.end_time.clone(),
             total_items: items,
             subtotal,
-            discount: (discount * 100.0).round() / 100.0,
-            tax: (tax * 100.0).round() / 100.0,
-            shipping: (shipping * 100.0).round() / 100.0,
-            total: ((subtotal - discount + tax + shipping) * 100.0).round() / 100.0,
+            discount: locale.round_amount(discount),
+            tax: locale.round_amount(tax),
+            shipping: locale.round_amount(shipping),
+            total: locale.round_amount(subtotal - discount + tax + shipping),
+            currency: session.currency.clone(),
             payment_method: payment.to_string(),
             status: "completed".to_string(),
         });
@@ -925,6 +1772,7 @@ pub fn generate_orders(sessions: &[Session], config: &EcommerceConfig) -> Vec<Or
 /// Generate customers with RFM metrics
 pub fn generate_customers(orders: &[Order], config: &EcommerceConfig) -> Vec<Customer> {
     let mut rng = create_rng(config.seed.map(|s| s + 3));
+    let locale = market_locale(&config.locale);
 
     // Aggregate order data by customer
     let mut customer_data: std::collections::HashMap<String, (Vec<&Order>, f64)> =
@@ -1008,7 +1856,7 @@ pub fn generate_customers(orders: &[Order], config: &EcommerceConfig) -> Vec<Cus
 
         customers.push(Customer {
             customer_id: user_id.clone(),
-            email: generate_email(&mut rng),
+            email: generate_email(&mut rng, locale),
             first_order_date: first_order,
             last_order_date: last_order.map(|d| d.format("%Y-%m-%d").to_string()),
             total_orders: frequency,
@@ -1090,11 +1938,212 @@ pub fn generate_funnel_events(sessions: &[Session], config: &EcommerceConfig) ->
     events
 }
 
+// =============================================================================
+// Interaction Graph Generator
+// =============================================================================
+
+/// Zipf product popularity over a seeded random ranking.
+///
+/// The ranking depends only on the seed and catalog size, so popularity is
+/// unrelated to product ids and the same products stay popular across calls.
+struct ProductPopularity {
+    ranked: Vec<usize>,
+    cumulative: Vec<f64>,
+}
+
+impl ProductPopularity {
+    fn new(products: &[Product], config: &EcommerceConfig) -> Self {
+        let mut rng = create_rng(config.seed.map(|s| s + 5));
+        let mut ranked: Vec<usize> = (0..products.len()).collect();
+        ranked.shuffle(&mut rng);
+        let mut cumulative = Vec::with_capacity(ranked.len());
+        let mut total = 0.0;
+        for rank in 1..=ranked.len() {
+            total += 1.0 / (rank as f64).powf(config.interactions.popularity_exponent);
+            cumulative.push(total);
+        }
+        Self { ranked, cumulative }
+    }
+
+    /// Index into the catalog of a popularity-weighted product
+    fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        let total = self.cumulative.last().copied().unwrap_or(0.0);
+        let roll = rng.random::<f64>() * total;
+        let rank = self
+            .cumulative
+            .partition_point(|&c| c < roll)
+            .min(self.ranked.len() - 1);
+        self.ranked[rank]
+    }
+}
+
+/// Generate a bipartite user-product interaction graph from cart events and
+/// orders.
+///
+/// Every item event (add, quantity update, remove) is a `view` of its
+/// product, every `add_to_cart` event an `add_to_cart` interaction, and the
+/// products still in a session's cart at checkout are `purchase`d if the
+/// session has an order. Every edge is therefore backed by rows in
+/// `cart_events` and `orders`. Cart events draw products by Zipf popularity,
+/// so product degree is heavy-tailed. Repeated interactions are aggregated
+/// into one weighted edge per (user, product, interaction type), in order of
+/// first occurrence.
+pub fn generate_interactions(cart_events: &[CartEvent], orders: &[Order]) -> Vec<Interaction> {
+    let ordered: HashSet<&str> = orders.iter().map(|o| o.session_id.as_str()).collect();
+
+    let mut edges: Vec<Interaction> = Vec::new();
+    let mut index: HashMap<(String, String, &str), usize> = HashMap::new();
+    let mut record = |user: &str, product: &str, kind: &'static str| {
+        let key = (user.to_string(), product.to_string(), kind);
+        match index.get(&key) {
+            Some(&i) => edges[i].weight += 1,
+            None => {
+                index.insert(key, edges.len());
+                edges.push(Interaction {
+                    user_id: user.to_string(),
+                    product_id: product.to_string(),
+                    interaction_type: kind.to_string(),
+                    weight: 1,
+                });
+            }
+        }
+    };
+
+    // Lines currently in each session's cart, in order of adding
+    let mut carts: HashMap<&str, Vec<&str>> = HashMap::new();
+    for event in cart_events {
+        let session = event.session_id.as_str();
+        let user = event.user_id.as_str();
+        match (event.event_type.as_str(), event.product_id.as_deref()) {
+            ("add_to_cart", Some(product)) => {
+                record(user, product, "view");
+                record(user, product, "add_to_cart");
+                carts.entry(session).or_default().push(product);
+            }
+            ("remove_from_cart", Some(product)) => {
+                record(user, product, "view");
+                let cart = carts.entry(session).or_default();
+                if let Some(line) = cart.iter().rposition(|&p| p == product) {
+                    cart.remove(line);
+                }
+            }
+            (_, Some(product)) => record(user, product, "view"),
+            ("checkout_complete", None) if ordered.contains(session) => {
+                for product in carts.remove(session).unwrap_or_default() {
+                    record(user, product, "purchase");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    edges
+}
+
+/// Collapse interactions into a weighted `(user, product, weight)` edge list.
+///
+/// Weights of all interaction types between a user and a product are summed,
+/// so each pair appears once, in order of first occurrence.
+pub fn interaction_edgelist(interactions: &[Interaction]) -> Vec<(String, String, u32)> {
+    let mut edges: Vec<(String, String, u32)> = Vec::new();
+    let mut index: HashMap<(&str, &str), usize> = HashMap::new();
+    for edge in interactions {
+        let key = (edge.user_id.as_str(), edge.product_id.as_str());
+        match index.get(&key) {
+            Some(&i) => edges[i].2 += edge.weight,
+            None => {
+                index.insert(key, edges.len());
+                edges.push((edge.user_id.clone(), edge.product_id.clone(), edge.weight));
+            }
+        }
+    }
+    edges
+}
+
+// =============================================================================
+// Cohort Retention
+// =============================================================================
+
+/// Build a triangular cohort retention matrix from customer activity.
+///
+/// Each customer belongs to the cohort of the period of their earliest
+/// activity. Retention in period `k` is the share of the cohort active `k`
+/// periods later, so period 0 is always 1.0. Rows are sorted by cohort and
+/// stop at the last period observed in the data.
+pub fn cohort_retention(activity: &[ActivitySpan], period: CohortPeriod) -> Vec<CohortRetention> {
+    let Some(last_date) = activity
+        .iter()
+        .map(|a| a.end.unwrap_or(a.start).max(a.start))
+        .max()
+    else {
+        return Vec::new();
+    };
+    let last = period.index(last_date);
+
+    // Per customer: acquisition date and the set of active period indices
+    let mut customers: HashMap<&str, (NaiveDate, HashSet<i64>)> = HashMap::new();
+    for span in activity {
+        let from = period.index(span.start);
+        let to = span.end.map_or(last, |end| period.index(end)).max(from);
+        let entry = customers
+            .entry(span.customer_id.as_str())
+            .or_insert_with(|| (span.start, HashSet::new()));
+        entry.0 = entry.0.min(span.start);
+        entry.1.extend(from..=to);
+    }
+
+    let mut cohorts: BTreeMap<i64, (NaiveDate, Vec<&HashSet<i64>>)> = BTreeMap::new();
+    for (first, active) in customers.values() {
+        let entry = cohorts
+            .entry(period.index(*first))
+            .or_insert_with(|| (*first, Vec::new()));
+        entry.1.push(active);
+    }
+
+    cohorts
+        .into_iter()
+        .map(|(cohort, (first, members))| {
+            let size = members.len();
+            let retention = (cohort..=last)
+                .map(|idx| {
+                    let active = members.iter().filter(|m| m.contains(&idx)).count();
+                    active as f64 / size as f64
+                })
+                .collect();
+            CohortRetention {
+                cohort: period.label(first),
+                cohort_size: size,
+                retention,
+            }
+        })
+        .collect()
+}
+
+/// Activity spans from orders (one single-day span per order)
+pub fn order_activity(orders: &[Order]) -> Vec<ActivitySpan> {
+    orders
+        .iter()
+        .filter_map(|o| {
+            let date = NaiveDateTime::parse_from_str(&o.order_time, "%Y-%m-%d %H:%M:%S")
+                .ok()?
+                .date();
+            Some(ActivitySpan {
+                customer_id: o.user_id.clone(),
+                start: date,
+                end: Some(date),
+            })
+        })
+        .collect()
+}
+
 // =============================================================================
 // Main Generator Functions
 // =============================================================================
 
 /// Generate complete e-commerce dataset
+///
+/// The catalog is generated once and shared by every table that references
+/// products, so all product ids in the cart events exist in `products`.
 pub fn ecommerce(config: &EcommerceConfig) -> EcommerceData {
     let products = generate_catalog(config);
     let sessions = generate_sessions(config);
@@ -1142,6 +2191,15 @@ pub fn sessions(count: usize, seed: Option<u64>) -> Vec<Session> {
     generate_sessions(&config)
 }
 
+/// Generate the user-product interaction graph for a configuration
+pub fn interactions(config: &EcommerceConfig) -> Vec<Interaction> {
+    let products = generate_catalog(config);
+    let sessions = generate_sessions(config);
+    let cart_events = generate_cart_events(&sessions, &products, config);
+    let orders = generate_orders(&sessions, config);
+    generate_interactions(&cart_events, &orders)
+}
+
 /// Generate product catalog only
 pub fn products(count: usize, seed: Option<u64>) -> Vec<Product> {
     let config = EcommerceConfig {
@@ -1154,3 +2212,601 @@ pub fn products(count: usize, seed: Option<u64>) -> Vec<Product> {
     };
     generate_catalog(&config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cohort_retention_starts_at_one() {
+        let data = ecommerce(&EcommerceConfig {
+            sessions: 5000,
+            customers: 500,
+            days: 120,
+            seed: Some(42),
+            start_date: Some("2024-01-01".to_string()),
+            ..Default::default()
+        });
+        let matrix = cohort_retention(&order_activity(&data.orders), CohortPeriod::Month);
+        assert!(!matrix.is_empty());
+
+        for (i, row) in matrix.iter().enumerate() {
+            assert!(row.cohort_size > 0);
+            assert_eq!(row.retention[0], 1.0);
+            assert!(row.retention[1..].iter().all(|&r| (0.0..=1.0).contains(&r)));
+            // Triangular: each later cohort has one fewer observed period
+            if i > 0 {
+                assert!(row.retention.len() < matrix[i - 1].retention.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_session_states() {
+        let session = SessionConfig {
+            custom_states: Some(vec![
+                "home".to_string(),
+                "article".to_string(),
+                "subscribe".to_string(),
+                "leave".to_string(),
+            ]),
+            custom_transition_matrix: Some(vec![
+                vec![0.0, 0.6, 0.1, 0.3],
+                vec![0.0, 0.5, 0.1, 0.4],
+                vec![0.0, 0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ]),
+            purchase_state: "subscribe".to_string(),
+            exit_state: "leave".to_string(),
+            enable_bounces: false,
+            ..Default::default()
+        };
+        let config = EcommerceConfig {
+            sessions: 2000,
+            seed: Some(42),
+            session: session.clone(),
+            ..Default::default()
+        };
+        let sessions = generate_sessions(&config);
+        let converted = sessions.iter().filter(|s| s.converted).count();
+        // P(subscribe before leave) is 0.2 from article, 0.1 + 0.6 * 0.2 from home
+        let rate = converted as f64 / sessions.len() as f64;
+        assert!((rate - 0.22).abs() < 0.03, "conversion rate {}", rate);
+        assert!(sessions.iter().all(|s| s.pages_viewed < 50));
+
+        // Both terminal states must name states of the chain
+        for (purchase_state, exit_state, field) in [
+            ("buy", "leave", "purchase_state 'buy'"),
+            ("subscribe", "exit", "exit_state 'exit'"),
+        ] {
+            let err = SessionConfig {
+                purchase_state: purchase_state.to_string(),
+                exit_state: exit_state.to_string(),
+                ..session.clone()
+            }
+            .markov_chain()
+            .unwrap_err();
+            assert!(err.to_string().contains(field), "{}", err);
+        }
+        assert!(SessionConfig {
+            purchase_state: "subscribe".to_string(),
+            ..Default::default()
+        }
+        .markov_chain()
+        .is_err());
+
+        let invalid = |states: Vec<&str>, matrix: Vec<Vec<f64>>| {
+            SessionConfig {
+                custom_states: Some(states.into_iter().map(String::from).collect()),
+                custom_transition_matrix: Some(matrix),
+                ..Default::default()
+            }
+            .markov_chain()
+            .is_err()
+        };
+        assert!(invalid(vec!["a", "b"], vec![vec![0.5, 0.5]]));
+        assert!(invalid(vec!["a", "b"], vec![vec![0.5, 0.5], vec![0.5]]));
+        assert!(invalid(
+            vec!["a", "b"],
+            vec![vec![0.5, 0.5], vec![0.2, 0.2]]
+        ));
+        assert!(invalid(vec![], vec![]));
+        assert!(SessionConfig {
+            custom_states: Some(vec!["a".to_string()]),
+            ..Default::default()
+        }
+        .markov_chain()
+        .is_err());
+    }
+
+    #[test]
+    fn test_target_conversion_rate() {
+        let realized = |session: SessionConfig| {
+            let sessions = generate_sessions(&EcommerceConfig {
+                sessions: 20000,
+                seed: Some(42),
+                session,
+                ..Default::default()
+            });
+            sessions.iter().filter(|s| s.converted).count() as f64 / sessions.len() as f64
+        };
+
+        // The uncalibrated estimate already matches the simulation
+        let default = SessionConfig::default();
+        let expected = default.expected_conversion_rate().unwrap();
+        assert!((realized(default) - expected).abs() < 0.003);
+
+        for target in [0.03, 0.1] {
+            let session = SessionConfig {
+                target_conversion_rate: Some(target),
+                ..Default::default()
+            };
+            assert!((session.expected_conversion_rate().unwrap() - target).abs() < 1e-6);
+            let rate = realized(session);
+            assert!(
+                (rate - target).abs() < 0.01,
+                "target {} got {}",
+                target,
+                rate
+            );
+        }
+
+        // Above 1 - bounce_rate is unreachable
+        let session = SessionConfig {
+            target_conversion_rate: Some(0.7),
+            ..Default::default()
+        };
+        assert!(session.markov_chain().is_err());
+    }
+
+    #[test]
+    fn test_landing_page_conversion() {
+        let config = EcommerceConfig {
+            sessions: 30000,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let sessions = generate_sessions(&config);
+        let by_page = |page: &str| {
+            let landed: Vec<_> = sessions.iter().filter(|s| s.landing_page == page).collect();
+            let rate = landed.iter().filter(|s| s.converted).count() as f64 / landed.len() as f64;
+            (landed.len() as f64 / sessions.len() as f64, rate)
+        };
+
+        let (home_share, home_rate) = by_page("/");
+        let (sale_share, sale_rate) = by_page("/sale");
+        assert!((home_share - 0.30).abs() < 0.02);
+        assert!((sale_share - 0.15).abs() < 0.02);
+        // /sale is set to convert at 1.5 / 0.8 times the rate of /
+        assert!(
+            sale_rate > home_rate * 1.4,
+            "sale {} home {}",
+            sale_rate,
+            home_rate
+        );
+
+        // Multipliers are relative, so the overall rate is unchanged
+        let overall =
+            sessions.iter().filter(|s| s.converted).count() as f64 / sessions.len() as f64;
+        let expected = config.session.expected_conversion_rate().unwrap();
+        assert!((overall - expected).abs() < 0.004);
+
+        // Weight overrides apply to the listed pages only
+        let session = SessionConfig {
+            landing_page_weights: HashMap::from([("/sale".to_string(), 0.0)]),
+            landing_page_conversion: HashMap::from([("/".to_string(), 3.0)]),
+            ..Default::default()
+        };
+        let sessions = generate_sessions(&EcommerceConfig {
+            sessions: 2000,
+            seed: Some(42),
+            session,
+            ..Default::default()
+        });
+        assert!(sessions.iter().all(|s| s.landing_page != "/sale"));
+        assert!(sessions.iter().any(|s| s.landing_page == "/products"));
+
+        let unknown = SessionConfig {
+            landing_page_weights: HashMap::from([("/blog".to_string(), 1.0)]),
+            ..Default::default()
+        };
+        assert!(unknown.landing_chains().is_err());
+    }
+
+    #[test]
+    fn test_sessions_within_window() {
+        let config = EcommerceConfig {
+            sessions: 5000,
+            days: 3,
+            seed: Some(42),
+            start_date: Some("2024-01-01".to_string()),
+            ..Default::default()
+        };
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let end = start + Duration::days(3);
+
+        for seed in 0..20 {
+            let sessions = generate_sessions(&EcommerceConfig {
+                seed: Some(seed),
+                ..config.clone()
+            });
+            assert_eq!(sessions.len(), 5000);
+            for session in &sessions {
+                let t = NaiveDateTime::parse_from_str(&session.start_time, "%Y-%m-%d %H:%M:%S")
+                    .unwrap();
+                assert!(
+                    t >= start && t <= end,
+                    "{} outside window",
+                    session.start_time
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_locale_currency_consistency() {
+        let config = EcommerceConfig {
+            sessions: 3000,
+            customers: 300,
+            seed: Some(42),
+            start_date: Some("2024-01-01".to_string()),
+            locale: "de_DE".to_string(),
+            ..Default::default()
+        };
+        let data = ecommerce(&config);
+        assert!(!data.orders.is_empty());
+
+        assert!(data.products.iter().all(|p| p.currency == "EUR"));
+        assert!(data.sessions.iter().all(|s| s.currency == "EUR"));
+        assert!(data.orders.iter().all(|o| o.currency == "EUR"));
+        assert!(data.products.iter().all(|p| p.name.contains("Artikel")));
+        assert!(data.customers.iter().all(|c| {
+            let domain = c.email.split('@').nth(1).unwrap();
+            ["web.de", "gmx.de", "t-online.de", "gmail.com"].contains(&domain)
+        }));
+        for order in &data.orders {
+            let expected = (order.subtotal - order.discount) * 0.19;
+            assert!((order.tax - expected).abs() < 0.01);
+        }
+
+        // Same seed, different market: totals are converted, not redrawn
+        let us = ecommerce(&EcommerceConfig {
+            locale: "en_US".to_string(),
+            ..config.clone()
+        });
+        assert!(us.orders.iter().all(|o| o.currency == "USD"));
+        for (de, us) in data.sessions.iter().zip(&us.sessions) {
+            assert!((de.total_value - us.total_value * 0.92).abs() < 1e-9);
+        }
+
+        // Unknown locales fall back to en_US, with a warning
+        let unknown = EcommerceConfig {
+            locale: "xx_XX".to_string(),
+            ..config.clone()
+        };
+        assert!(generate_sessions(&unknown)
+            .iter()
+            .all(|s| s.currency == "USD"));
+        assert!(unknown.locale_warning().unwrap().contains("xx_XX"));
+        assert_eq!(config.locale_warning(), None);
+
+        // Every market prices in the currency of the shared locale table
+        let jp = ecommerce(&EcommerceConfig {
+            locale: "ja_JP".to_string(),
+            ..config
+        });
+        assert!(jp.orders.iter().all(|o| o.currency == "JPY"));
+        assert!(jp.products.iter().all(|p| p.name.contains("商品")));
+        // Yen have no minor unit, so prices and order amounts are whole
+        for p in &jp.products {
+            assert_eq!(p.price, p.price.round(), "price {}", p.price);
+            if p.price > 1000.0 {
+                assert_eq!(p.price as u64 % 100, 99, "price {}", p.price);
+            }
+        }
+        for o in &jp.orders {
+            for amount in [o.discount, o.tax, o.shipping, o.total] {
+                assert_eq!(amount, amount.round(), "order {:?}", o);
+            }
+        }
+        assert!(us
+            .products
+            .iter()
+            .any(|p| p.price > 10.0 && (p.price - p.price.floor() - 0.99).abs() < 1e-9));
+        for (jp, us) in jp.sessions.iter().zip(&us.sessions) {
+            assert!((jp.total_value - us.total_value * 150.0).abs() < 1e-6);
+        }
+        for market in MARKET_LOCALES {
+            assert_eq!(market_locale(market.code).code, market.code);
+            assert_eq!(market.currency(), locale_currency(market.code).unwrap().0);
+        }
+    }
+
+    #[test]
+    fn test_min_order_value() {
+        let config = EcommerceConfig {
+            sessions: 3000,
+            customers: 300,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let sessions = generate_sessions(&config);
+        let converted = sessions.iter().filter(|s| s.converted).count();
+        let below = sessions
+            .iter()
+            .filter(|s| s.converted && s.total_value < 100.0)
+            .count();
+        assert!(below > 0);
+
+        let bump = EcommerceConfig {
+            min_order_value: Some(100.0),
+            ..config.clone()
+        };
+        let bumped = generate_sessions(&bump);
+        assert_eq!(bumped.iter().filter(|s| s.converted).count(), converted);
+        for (s, b) in sessions.iter().zip(&bumped) {
+            assert_eq!(s.session_id, b.session_id);
+            assert_eq!(
+                b.total_value,
+                s.total_value.max(if s.converted { 100.0 } else { 0.0 })
+            );
+        }
+        let orders = generate_orders(&bumped, &bump);
+        assert_eq!(orders.len(), converted);
+        assert!(orders.iter().all(|o| o.subtotal >= 100.0));
+
+        let drop = EcommerceConfig {
+            min_order_value: Some(100.0),
+            min_order_policy: MinOrderPolicy::Drop,
+            ..config
+        };
+        let dropped = generate_sessions(&drop);
+        assert_eq!(
+            dropped.iter().filter(|s| s.converted).count(),
+            converted - below
+        );
+        assert!(dropped
+            .iter()
+            .all(|s| s.converted == (s.total_value >= 100.0)));
+        let orders = generate_orders(&dropped, &drop);
+        assert_eq!(orders.len(), converted - below);
+        assert!(orders.iter().all(|o| o.subtotal >= 100.0));
+    }
+
+    #[test]
+    fn test_brand_distribution_per_category() {
+        let config = EcommerceConfig {
+            seed: Some(42),
+            catalog: CatalogConfig {
+                num_products: 5000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let products = generate_catalog(&config);
+
+        for (category, pool) in CATEGORY_BRANDS {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for p in products.iter().filter(|p| p.category == *category) {
+                assert!(
+                    pool.contains(&p.brand.as_str()),
+                    "{} not a {} brand",
+                    p.brand,
+                    category
+                );
+                *counts.entry(p.brand.as_str()).or_default() += 1;
+            }
+            let total: usize = counts.values().sum();
+            assert!(total > 0);
+
+            // Heavy-tailed: the top-ranked brand clearly dominates
+            let top = counts.get(pool[0]).copied().unwrap_or(0) as f64 / total as f64;
+            let last = counts.get(pool[pool.len() - 1]).copied().unwrap_or(0) as f64 / total as f64;
+            assert!(
+                top > 2.0 / pool.len() as f64,
+                "{} top share {}",
+                category,
+                top
+            );
+            assert!(top > 2.5 * last);
+        }
+
+        let custom = generate_catalog(&EcommerceConfig {
+            seed: Some(1),
+            catalog: CatalogConfig {
+                num_products: 50,
+                categories: vec!["Garden Tools".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert!(custom
+            .iter()
+            .all(|p| p.brand.starts_with("Garden Tools Brand ")));
+    }
+
+    #[test]
+    fn test_product_variants() {
+        let catalog = |variants_per_product| {
+            generate_catalog(&EcommerceConfig {
+                seed: Some(42),
+                catalog: CatalogConfig {
+                    num_products: 100,
+                    variants_per_product,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        };
+
+        let products = catalog(4);
+        assert_eq!(products.len(), 400);
+
+        let mut families: HashMap<&str, Vec<&Product>> = HashMap::new();
+        for p in &products {
+            families
+                .entry(p.parent_product_id.as_str())
+                .or_default()
+                .push(p);
+        }
+        assert_eq!(families.len(), 100);
+        for variants in families.values() {
+            assert_eq!(variants.len(), 4);
+            let first = variants[0];
+            assert!(variants.iter().all(|v| v.category == first.category
+                && v.brand == first.brand
+                && v.price == first.price));
+            let options: HashSet<_> = variants.iter().map(|v| (&v.size, &v.color)).collect();
+            assert_eq!(options.len(), 4);
+        }
+        let skus: HashSet<&str> = products.iter().map(|p| p.sku.as_str()).collect();
+        assert_eq!(skus.len(), products.len());
+
+        // Without variants each product is its own parent
+        let plain = catalog(1);
+        assert_eq!(plain.len(), 100);
+        assert!(plain
+            .iter()
+            .all(|p| p.parent_product_id == p.product_id && p.size.is_none()));
+    }
+
+    #[test]
+    fn test_interaction_degree_power_law() {
+        let edges = interactions(&EcommerceConfig {
+            sessions: 20000,
+            customers: 2000,
+            seed: Some(42),
+            start_date: Some("2024-01-01".to_string()),
+            ..Default::default()
+        });
+
+        let mut users_per_product: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for edge in &edges {
+            assert!(edge.weight >= 1);
+            users_per_product
+                .entry(edge.product_id.as_str())
+                .or_default()
+                .insert(edge.user_id.as_str());
+        }
+        let mut degrees: Vec<f64> = users_per_product.values().map(|u| u.len() as f64).collect();
+        degrees.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+        // Heavy tail: the top 5% of products account for a large share of
+        // links, and the most popular product dwarfs the median one
+        let top = degrees.len() / 20;
+        let share = degrees[..top].iter().sum::<f64>() / degrees.iter().sum::<f64>();
+        assert!(share > 0.25, "top 5% share {}", share);
+        assert!(degrees[0] > 20.0 * degrees[degrees.len() / 2]);
+
+        // Power law: log(degree) falls linearly with log(rank) over the head
+        let points: Vec<(f64, f64)> = degrees[..50]
+            .iter()
+            .enumerate()
+            .map(|(i, d)| (((i + 1) as f64).ln(), d.ln()))
+            .collect();
+        let n = points.len() as f64;
+        let (mx, my) = (
+            points.iter().map(|p| p.0).sum::<f64>() / n,
+            points.iter().map(|p| p.1).sum::<f64>() / n,
+        );
+        let slope = points.iter().map(|p| (p.0 - mx) * (p.1 - my)).sum::<f64>()
+            / points.iter().map(|p| (p.0 - mx).powi(2)).sum::<f64>();
+        assert!((-1.5..-0.4).contains(&slope), "log-log slope {}", slope);
+    }
+
+    #[test]
+    fn test_interaction_edgelist_counts() {
+        let edges = interactions(&EcommerceConfig {
+            sessions: 2000,
+            seed: Some(7),
+            ..Default::default()
+        });
+        let edgelist = interaction_edgelist(&edges);
+
+        let pairs: HashSet<(&str, &str)> = edges
+            .iter()
+            .map(|e| (e.user_id.as_str(), e.product_id.as_str()))
+            .collect();
+        assert_eq!(edgelist.len(), pairs.len());
+        assert_eq!(
+            edgelist.iter().map(|e| e.2).sum::<u32>(),
+            edges.iter().map(|e| e.weight).sum::<u32>()
+        );
+    }
+
+    #[test]
+    fn test_cohort_retention_spans() {
+        let d = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let activity = vec![
+            ActivitySpan {
+                customer_id: "a".to_string(),
+                start: d("2024-01-05"),
+                end: Some(d("2024-02-20")),
+            },
+            ActivitySpan {
+                customer_id: "b".to_string(),
+                start: d("2024-01-10"),
+                end: None,
+            },
+            ActivitySpan {
+                customer_id: "c".to_string(),
+                start: d("2024-03-01"),
+                end: Some(d("2024-03-01")),
+            },
+        ];
+        let matrix = cohort_retention(&activity, CohortPeriod::Month);
+        assert_eq!(matrix.len(), 2);
+        assert_eq!(matrix[0].cohort, "2024-01");
+        assert_eq!(matrix[0].cohort_size, 2);
+        assert_eq!(matrix[0].retention, vec![1.0, 1.0, 0.5]);
+        assert_eq!(matrix[1].cohort, "2024-03");
+        assert_eq!(matrix[1].retention, vec![1.0]);
+    }
+
+    #[test]
+    fn test_payment_vocabulary_matches_superstore() {
+        use crate::general::{superstore_with_config, PaymentConfig, SuperstoreConfig};
+
+        let data = ecommerce(&EcommerceConfig {
+            sessions: 30000,
+            customers: 2000,
+            seed: Some(42),
+            ..Default::default()
+        });
+        let order_methods: HashSet<String> = data
+            .orders
+            .iter()
+            .map(|o| o.payment_method.clone())
+            .collect();
+
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 5000,
+            seed: Some(42),
+            payment: PaymentConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let row_methods: HashSet<String> = rows
+            .iter()
+            .filter_map(|r| r.payment_method.clone())
+            .collect();
+
+        let canonical: HashSet<String> = PaymentMethod::ALL
+            .iter()
+            .map(|m| m.as_str().to_string())
+            .collect();
+        assert_eq!(order_methods, canonical);
+        assert_eq!(row_methods, canonical);
+
+        for method in PaymentMethod::ALL {
+            assert_eq!(PaymentMethod::parse(method.as_str()), Some(method));
+            assert_eq!(PaymentMethod::parse(method.label()), Some(method));
+        }
+        assert_eq!(PaymentMethod::parse("bitcoin"), None);
+    }
+}
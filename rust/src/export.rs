@@ -11,8 +11,14 @@ use parquet::arrow::ArrowWriter;
 use parquet::basic::Compression;
 use parquet::file::properties::WriterProperties;
 
-use crate::arrow::{employees_to_arrow, superstore_to_arrow};
-use crate::general::{employees, superstore, EmployeeRow, SuperstoreRow};
+use crate::arrow::{
+    employees_to_arrow, superstore_schema_with_columns, superstore_to_arrow,
+    superstore_to_arrow_with_columns,
+};
+use crate::general::{
+    employees, superstore, EmployeeRow, OptionalColumns, SuperstoreConfig, SuperstoreRow,
+};
+use crate::streaming::{superstore_stream_with_config, StreamingError};
 
 /// Error type for export operations
 #[derive(Debug)]
@@ -20,6 +26,9 @@ pub enum ExportError {
     Io(std::io::Error),
     Arrow(arrow::error::ArrowError),
     Parquet(parquet::errors::ParquetError),
+    Streaming(StreamingError),
+    /// Return rows are drawn from every sale row, so they can't be streamed
+    ReturnsNotStreamed,
 }
 
 impl From<std::io::Error> for ExportError {
@@ -40,12 +49,23 @@ impl From<parquet::errors::ParquetError> for ExportError {
     }
 }
 
+impl From<StreamingError> for ExportError {
+    fn from(e: StreamingError) -> Self {
+        ExportError::Streaming(e)
+    }
+}
+
 impl std::fmt::Display for ExportError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ExportError::Io(e) => write!(f, "IO error: {}", e),
             ExportError::Arrow(e) => write!(f, "Arrow error: {}", e),
             ExportError::Parquet(e) => write!(f, "Parquet error: {}", e),
+            ExportError::Streaming(e) => write!(f, "Streaming error: {}", e),
+            ExportError::ReturnsNotStreamed => write!(
+                f,
+                "Return rows can't be streamed; disable returns to export in chunks"
+            ),
         }
     }
 }
@@ -115,6 +135,132 @@ pub fn employees_to_parquet<P: AsRef<Path>>(
     Ok(rows.len())
 }
 
+/// Parquet writer for superstore rows generated in chunks, e.g. by
+/// [`SuperstoreIterator`](crate::streaming::SuperstoreIterator).
+///
+/// Written rows are buffered until [`flush`](Self::flush) writes them out as
+/// a row group. Dropping the writer (including while unwinding from a panic)
+/// flushes what's buffered and writes the footer, so a run interrupted after
+/// K chunks still leaves a readable file with those K chunks;
+/// [`finish`](Self::finish) does the same but reports errors.
+pub struct SuperstoreParquetWriter {
+    writer: Option<ArrowWriter<File>>,
+    columns: OptionalColumns,
+    rows: usize,
+}
+
+impl SuperstoreParquetWriter {
+    /// Create (or truncate) the file at `path`, writing the base columns
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        compression: ParquetCompression,
+    ) -> Result<Self, ExportError> {
+        Self::create_with_columns(path, compression, OptionalColumns::default())
+    }
+
+    /// Create (or truncate) the file at `path`, writing the base columns and
+    /// the optional `columns` (see
+    /// [`superstore_schema_with_columns`](crate::arrow::superstore_schema_with_columns))
+    pub fn create_with_columns<P: AsRef<Path>>(
+        path: P,
+        compression: ParquetCompression,
+        columns: OptionalColumns,
+    ) -> Result<Self, ExportError> {
+        let file = File::create(path)?;
+        let props = WriterProperties::builder()
+            .set_compression(compression.into())
+            .build();
+        let schema = superstore_schema_with_columns(columns).into();
+        let writer = ArrowWriter::try_new(file, schema, Some(props))?;
+        Ok(Self {
+            writer: Some(writer),
+            columns,
+            rows: 0,
+        })
+    }
+
+    /// Buffer a chunk of rows
+    pub fn write(&mut self, rows: &[SuperstoreRow]) -> Result<(), ExportError> {
+        let batch = superstore_to_arrow_with_columns(rows, self.columns)?;
+        self.writer_mut().write(&batch)?;
+        self.rows += rows.len();
+        Ok(())
+    }
+
+    /// Write the buffered rows to the file as a row group
+    pub fn flush(&mut self) -> Result<(), ExportError> {
+        self.writer_mut().flush()?;
+        Ok(())
+    }
+
+    /// Number of rows written so far, flushed or not
+    pub fn rows_written(&self) -> usize {
+        self.rows
+    }
+
+    /// Flush the buffered rows and write the footer, returning the row count
+    pub fn finish(mut self) -> Result<usize, ExportError> {
+        if let Some(writer) = self.writer.take() {
+            writer.close()?;
+        }
+        Ok(self.rows)
+    }
+
+    fn writer_mut(&mut self) -> &mut ArrowWriter<File> {
+        // Only `finish` and `drop` take the writer, and both consume self
+        self.writer.as_mut().expect("writer is open until finished")
+    }
+}
+
+impl Drop for SuperstoreParquetWriter {
+    fn drop(&mut self) {
+        if let Some(writer) = self.writer.take() {
+            // Best effort: errors can't be reported from drop; use `finish`
+            let _ = writer.close();
+        }
+    }
+}
+
+/// Write `total` superstore rows to a single Parquet file, generating and
+/// writing `chunk_size` rows at a time so peak memory is one chunk.
+///
+/// Each chunk becomes one row group. Rows come from
+/// [`superstore_stream_with_config`] with `config` (default if `None`), its
+/// count set to `total` and its seed overridden by `seed` if given. The file
+/// has the columns of [`superstore_to_parquet`] plus the optional columns
+/// the config enables ([`OptionalColumns::for_config`]).
+///
+/// Returns [`ExportError::ReturnsNotStreamed`] if the config enables returns,
+/// since return rows are drawn only after every sale row is generated, and
+/// [`ExportError::Streaming`] if the chunk size is zero, the config sets
+/// `stable_v1`, or [`SuperstoreConfig::validate`] fails.
+pub fn export_superstore_parquet_streaming<P: AsRef<Path>>(
+    path: P,
+    total: usize,
+    chunk_size: usize,
+    seed: Option<u64>,
+    config: Option<&SuperstoreConfig>,
+    compression: ParquetCompression,
+) -> Result<usize, ExportError> {
+    let mut config = config.cloned().unwrap_or_default();
+    config.count = total;
+    if seed.is_some() {
+        config.seed = seed;
+    }
+    let columns = OptionalColumns::for_config(&config);
+    if columns.returns {
+        return Err(ExportError::ReturnsNotStreamed);
+    }
+    let chunks = superstore_stream_with_config(&config, chunk_size)?;
+
+    let mut writer = SuperstoreParquetWriter::create_with_columns(path, compression, columns)?;
+    for chunk in chunks {
+        writer.write(&chunk)?;
+        writer.flush()?;
+    }
+    writer.finish()
+}
+
 fn escape_csv_field(field: &str) -> String {
     if field.contains(',') || field.contains('"') || field.contains('\n') {
         format!("\"{}\"", field.replace('"', "\"\""))
@@ -152,7 +298,7 @@ fn write_superstore_row<W: Write>(writer: &mut W, row: &SuperstoreRow) -> std::i
 fn write_employee_row<W: Write>(writer: &mut W, row: &EmployeeRow) -> std::io::Result<()> {
     writeln!(
         writer,
-        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
         row.row_id,
         escape_csv_field(&row.employee_id),
         escape_csv_field(&row.first_name),
@@ -169,7 +315,14 @@ fn write_employee_row<W: Write>(writer: &mut W, row: &EmployeeRow) -> std::io::R
         escape_csv_field(&row.state),
         escape_csv_field(&row.country),
         row.start_date,
-        row.date_of_birth
+        row.date_of_birth,
+        escape_csv_field(&row.department),
+        escape_csv_field(&row.job_title),
+        row.salary,
+        escape_csv_field(row.manager_id.as_deref().unwrap_or("")),
+        row.termination_date
+            .map(|d| d.to_string())
+            .unwrap_or_default()
     )
 }
 
@@ -213,7 +366,7 @@ pub fn employees_to_csv<P: AsRef<Path>>(
     // Write header
     writeln!(
         writer,
-        "row_id,employee_id,first_name,surname,prefix,suffix,phone_number,email,ssn,street,city,postal_code,region,state,country,start_date,date_of_birth"
+        "row_id,employee_id,first_name,surname,prefix,suffix,phone_number,email,ssn,street,city,postal_code,region,state,country,start_date,date_of_birth,department,job_title,salary,manager_id,termination_date"
     )?;
 
     // Write rows
@@ -228,6 +381,7 @@ pub fn employees_to_csv<P: AsRef<Path>>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::general::SuperstoreConfigError;
     use std::fs;
 
     #[test]
@@ -289,6 +443,212 @@ mod tests {
         fs::remove_file(&path).unwrap();
     }
 
+    #[test]
+    fn test_parquet_writer_dropped_early_is_readable() {
+        use crate::streaming::superstore_stream;
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let path = std::env::temp_dir().join("test_superstore_stream_interrupted.parquet");
+        let chunk_size = 250;
+        {
+            let mut writer =
+                SuperstoreParquetWriter::create(&path, ParquetCompression::Snappy).unwrap();
+            // Interrupted after 3 of 8 chunks, the last one never flushed
+            for (i, chunk) in superstore_stream(2000, chunk_size, Some(42))
+                .unwrap()
+                .take(3)
+                .enumerate()
+            {
+                writer.write(&chunk).unwrap();
+                if i < 2 {
+                    writer.flush().unwrap();
+                }
+            }
+            assert_eq!(writer.rows_written(), 3 * chunk_size);
+        }
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 3);
+        assert_eq!(
+            reader.metadata().file_metadata().num_rows(),
+            3 * chunk_size as i64
+        );
+        let rows: usize = reader
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap().num_rows())
+            .sum();
+        assert_eq!(rows, 3 * chunk_size);
+
+        let writer = SuperstoreParquetWriter::create(&path, ParquetCompression::Zstd).unwrap();
+        assert_eq!(writer.finish().unwrap(), 0);
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 0);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_export_superstore_parquet_streaming() {
+        use crate::arrow::superstore_schema;
+        use crate::general::{
+            superstore_with_config, BundlingConfig, InventoryConfig, PaymentConfig, ReturnsConfig,
+        };
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let path = std::env::temp_dir().join("test_superstore_streaming_export.parquet");
+        let count = export_superstore_parquet_streaming(
+            &path,
+            50_000,
+            5_000,
+            Some(42),
+            None,
+            ParquetCompression::Zstd,
+        )
+        .unwrap();
+        assert_eq!(count, 50_000);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 10);
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 50_000);
+        assert_eq!(reader.schema().as_ref(), &superstore_schema());
+        let batches: Vec<_> = reader
+            .with_batch_size(5_000)
+            .build()
+            .unwrap()
+            .map(|b| b.unwrap())
+            .collect();
+        assert_eq!(batches.len(), 10);
+        assert!(batches.iter().all(|b| b.num_rows() == 5_000));
+
+        // Same rows as the eager generator
+        let eager = superstore(50_000, Some(42), None);
+        for i in [0, 9] {
+            let expected = superstore_to_arrow(&eager[i * 5_000..(i + 1) * 5_000]).unwrap();
+            assert_eq!(batches[i], expected);
+        }
+
+        // Optional columns enabled by the config are written too
+        let config = SuperstoreConfig {
+            seed: Some(3),
+            currency: Some("EUR".into()),
+            bundling: BundlingConfig {
+                enable: true,
+                ..Default::default()
+            },
+            inventory: InventoryConfig {
+                enable: true,
+                ..Default::default()
+            },
+            payment: PaymentConfig {
+                enable: true,
+                card_numbers: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        export_superstore_parquet_streaming(
+            &path,
+            2_000,
+            500,
+            None,
+            Some(&config),
+            ParquetCompression::Snappy,
+        )
+        .unwrap();
+        let columns = OptionalColumns::for_config(&config);
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(
+            reader.schema().as_ref(),
+            &superstore_schema_with_columns(columns)
+        );
+        assert_eq!(reader.schema().fields().len(), 19 + 10);
+        let batches: Vec<_> = reader
+            .with_batch_size(500)
+            .build()
+            .unwrap()
+            .map(|b| b.unwrap())
+            .collect();
+        let eager = superstore_with_config(&SuperstoreConfig {
+            count: 2_000,
+            ..config.clone()
+        });
+        for (i, batch) in batches.iter().enumerate() {
+            let expected =
+                superstore_to_arrow_with_columns(&eager[i * 500..(i + 1) * 500], columns).unwrap();
+            assert_eq!(batch, &expected);
+        }
+
+        let returns = SuperstoreConfig {
+            returns: ReturnsConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..config.clone()
+        };
+        assert!(matches!(
+            export_superstore_parquet_streaming(
+                &path,
+                10,
+                5,
+                None,
+                Some(&returns),
+                ParquetCompression::None
+            ),
+            Err(ExportError::ReturnsNotStreamed)
+        ));
+
+        let config = SuperstoreConfig {
+            seed: Some(1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            export_superstore_parquet_streaming(
+                &path,
+                10,
+                0,
+                None,
+                Some(&config),
+                ParquetCompression::None
+            ),
+            Err(ExportError::Streaming(StreamingError::InvalidChunkSize(0)))
+        ));
+
+        // Invalid and stable_v1 configs are errors, not panics
+        for (config, expected) in [
+            (
+                SuperstoreConfig {
+                    order_date_start: Some("2024-13-01".to_string()),
+                    ..config.clone()
+                },
+                StreamingError::InvalidConfig(SuperstoreConfigError::InvalidOrderDate(
+                    "2024-13-01".to_string(),
+                )),
+            ),
+            (
+                SuperstoreConfig {
+                    stable_v1: true,
+                    ..config
+                },
+                StreamingError::StableV1,
+            ),
+        ] {
+            match export_superstore_parquet_streaming(
+                &path,
+                10,
+                5,
+                None,
+                Some(&config),
+                ParquetCompression::None,
+            ) {
+                Err(ExportError::Streaming(e)) => assert_eq!(e, expected),
+                other => panic!("expected a streaming error, got {:?}", other.map(|_| ())),
+            }
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn test_csv_escape() {
         assert_eq!(escape_csv_field("hello"), "hello");
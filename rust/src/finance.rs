@@ -3,18 +3,30 @@
 //! Generates realistic financial data including:
 //! - Stock prices via Geometric Brownian Motion with jumps
 //! - OHLCV bars with realistic intraday relationships
+//! - Intraday minute bars with a U-shaped volume profile
+//! - Earnings/event windows with abnormal returns
+//! - Portfolio aggregation with optional rebalancing
+//! - Value-at-Risk and Conditional VaR
+//! - Nelson-Siegel yield curves with daily factor evolution
+//! - Credit ratings and defaults via a one-factor Gaussian copula
 //! - Options data with Black-Scholes Greeks
 //! - Correlated multi-asset returns via GaussianCopula
+//! - FIX message logs with consistent order lifecycles
 
+use crate::rng::{seeded_rng, SeededRng};
 use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, Weekday};
-use rand::rngs::StdRng;
 use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
 use rand_distr::{Distribution, LogNormal, Normal};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::f64::consts::PI;
 
 use crate::copulas::GaussianCopula;
+use crate::distributions::sample_categorical;
+use crate::timeseries::{
+    apply_event_windows, get_intraday_volatility_mult, EventWindowConfig, IntradayConfig,
+};
 
 // =============================================================================
 // Constants
@@ -25,6 +37,81 @@ const TICKER_PREFIXES: &[&str] = &[
     "U", "V", "W", "X", "Y", "Z",
 ];
 
+const FIX_SYMBOLS: &[&str] = &[
+    "AAPL", "MSFT", "GOOGL", "AMZN", "META", "NVDA", "JPM", "XOM", "JNJ", "WMT",
+];
+
+/// FIX field delimiter (SOH)
+const FIX_SOH: char = '\x01';
+
+/// Credit ratings for performing obligors, best to worst ("D" marks default)
+pub const CREDIT_RATINGS: &[&str] = &["AAA", "AA", "A", "BBB", "BB", "B", "CCC"];
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+/// Error type for finance analytics
+#[derive(Debug, Clone)]
+pub enum FinanceError {
+    /// Number of weights doesn't match the number of assets
+    WeightCountMismatch { expected: usize, got: usize },
+    /// Weights sum to zero and cannot be normalized
+    ZeroWeights,
+    /// Input columns have different lengths
+    LengthMismatch,
+    /// A (date, ticker) price is missing from the input
+    MissingPrice { date: String, ticker: String },
+    /// Unknown rebalancing mode
+    InvalidRebalance(String),
+    /// Confidence level outside (0, 1)
+    InvalidAlpha(f64),
+    /// Unknown VaR method
+    InvalidVarMethod(String),
+    /// Return series is empty
+    EmptyReturns,
+}
+
+impl std::fmt::Display for FinanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FinanceError::WeightCountMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Expected {} weights (one per asset), got {}",
+                    expected, got
+                )
+            }
+            FinanceError::ZeroWeights => write!(f, "Weights must not sum to zero"),
+            FinanceError::LengthMismatch => {
+                write!(
+                    f,
+                    "date, ticker and close columns must have the same length"
+                )
+            }
+            FinanceError::MissingPrice { date, ticker } => {
+                write!(f, "Missing price for {} on {}", ticker, date)
+            }
+            FinanceError::InvalidRebalance(mode) => write!(
+                f,
+                "Invalid rebalance mode '{}'. Must be 'none' or 'daily'",
+                mode
+            ),
+            FinanceError::InvalidAlpha(alpha) => {
+                write!(f, "Invalid alpha: {} (must be between 0 and 1)", alpha)
+            }
+            FinanceError::InvalidVarMethod(method) => write!(
+                f,
+                "Invalid VaR method '{}'. Must be 'historical' or 'gaussian'",
+                method
+            ),
+            FinanceError::EmptyReturns => write!(f, "Return series is empty"),
+        }
+    }
+}
+
+impl std::error::Error for FinanceError {}
+
 // =============================================================================
 // Configuration
 // =============================================================================
@@ -86,6 +173,33 @@ impl Default for OhlcvConfig {
     }
 }
 
+/// Configuration for intraday bar generation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IntradayBarConfig {
+    /// Emit intraday bars over a 09:30-16:00 session instead of daily bars
+    pub enable: bool,
+    /// Bar length in minutes
+    pub bar_minutes: u32,
+    /// Volatility/volume multiplier at the open
+    pub opening_volatility_mult: f64,
+    /// Volatility/volume multiplier at midday (lowest)
+    pub midday_volatility_mult: f64,
+    /// Volatility/volume multiplier at the close
+    pub closing_volatility_mult: f64,
+}
+
+impl Default for IntradayBarConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            bar_minutes: 1,
+            opening_volatility_mult: 1.5,
+            midday_volatility_mult: 0.7,
+            closing_volatility_mult: 1.3,
+        }
+    }
+}
+
 /// Configuration for options generation
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OptionsConfig {
@@ -110,6 +224,81 @@ impl Default for OptionsConfig {
     }
 }
 
+/// Configuration for Nelson-Siegel yield curve generation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct YieldCurveConfig {
+    /// Tenors in years
+    pub tenors: Vec<f64>,
+    /// Long-run level (beta0)
+    pub level: f64,
+    /// Slope (beta1); negative values give an upward-sloping curve
+    pub slope: f64,
+    /// Curvature (beta2); positive values add a medium-term hump
+    pub curvature: f64,
+    /// Decay parameter (tau) in years; sets where curvature peaks
+    pub tau: f64,
+    /// Number of trading days; curves after the first evolve the factors
+    pub ndays: usize,
+    /// Start date
+    pub start_date: Option<String>,
+    /// Daily volatility of each factor shock (in yield units)
+    pub factor_volatility: f64,
+    /// Daily mean reversion of the factors toward their initial values
+    pub mean_reversion: f64,
+    /// Random seed
+    pub seed: Option<u64>,
+}
+
+impl Default for YieldCurveConfig {
+    fn default() -> Self {
+        Self {
+            tenors: vec![0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0],
+            level: 0.045,
+            slope: -0.02,
+            curvature: 0.01,
+            tau: 2.0,
+            ndays: 1,
+            start_date: None,
+            factor_volatility: 0.0005,
+            mean_reversion: 0.02,
+            seed: None,
+        }
+    }
+}
+
+/// Configuration for credit rating / default simulation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreditConfig {
+    /// Number of obligors
+    pub n_obligors: usize,
+    /// Simulation horizon in years
+    pub horizon_years: usize,
+    /// Asset correlation with the systematic factor (0 = independent defaults)
+    pub asset_correlation: f64,
+    /// Share of obligors in each rating, in `CREDIT_RATINGS` order
+    pub rating_weights: Vec<f64>,
+    /// Annual probability of default for each rating, in `CREDIT_RATINGS` order
+    pub default_probabilities: Vec<f64>,
+    /// Median exposure at default
+    pub median_exposure: f64,
+    /// Random seed
+    pub seed: Option<u64>,
+}
+
+impl Default for CreditConfig {
+    fn default() -> Self {
+        Self {
+            n_obligors: 1000,
+            horizon_years: 1,
+            asset_correlation: 0.2,
+            rating_weights: vec![0.02, 0.08, 0.20, 0.30, 0.20, 0.15, 0.05],
+            default_probabilities: vec![0.0001, 0.0002, 0.0006, 0.0018, 0.007, 0.035, 0.25],
+            median_exposure: 1_000_000.0,
+            seed: None,
+        }
+    }
+}
+
 /// Full finance configuration
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FinanceConfig {
@@ -129,6 +318,13 @@ pub struct FinanceConfig {
     pub ohlcv: OhlcvConfig,
     /// Options configuration
     pub options: OptionsConfig,
+    /// Intraday bar configuration
+    pub intraday: IntradayBarConfig,
+    /// Event windows (abnormal returns and elevated volatility around events)
+    pub events: EventWindowConfig,
+    /// Earnings dates (YYYY-MM-DD); each adds an event at the first trading
+    /// day on or after the date and enables event windows
+    pub earnings_dates: Vec<String>,
     /// Custom ticker symbols
     pub tickers: Vec<String>,
 }
@@ -144,6 +340,9 @@ impl Default for FinanceConfig {
             stock: StockConfig::default(),
             ohlcv: OhlcvConfig::default(),
             options: OptionsConfig::default(),
+            intraday: IntradayBarConfig::default(),
+            events: EventWindowConfig::default(),
+            earnings_dates: vec![],
             tickers: vec!["AAPL".to_string()],
         }
     }
@@ -167,6 +366,28 @@ pub struct OhlcvBar {
     pub returns: f64,
 }
 
+/// Obligor in a simulated credit portfolio
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Obligor {
+    pub obligor_id: String,
+    pub rating: String,
+    pub pd: f64,
+    pub exposure: f64,
+    pub defaulted: bool,
+    /// Year of default (1-based), if the obligor defaulted within the horizon
+    pub default_year: Option<u32>,
+    /// Rating at the end of the horizon ("D" if defaulted)
+    pub final_rating: String,
+}
+
+/// Point on a yield curve
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct YieldPoint {
+    pub date: String,
+    pub tenor: f64,
+    pub yield_rate: f64,
+}
+
 /// Options quote with Greeks
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OptionQuote {
@@ -185,15 +406,107 @@ pub struct OptionQuote {
     pub implied_vol: f64,
 }
 
+/// Single FIX 4.4 message (NewOrderSingle, OrderCancelRequest or ExecutionReport)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FixMessage {
+    /// MsgSeqNum (34)
+    pub seq_num: u64,
+    /// SendingTime (52)
+    pub sending_time: String,
+    /// MsgType (35): "D" NewOrderSingle, "F" OrderCancelRequest, "8" ExecutionReport
+    pub msg_type: String,
+    /// ClOrdID (11)
+    pub cl_ord_id: String,
+    /// OrigClOrdID (41), set on cancel requests and their reports
+    pub orig_cl_ord_id: Option<String>,
+    /// OrderID (37), assigned by the broker on the first ExecutionReport
+    pub order_id: Option<String>,
+    /// ExecID (17), ExecutionReport only
+    pub exec_id: Option<String>,
+    /// ExecType (150): "0" New, "F" Trade, "4" Canceled
+    pub exec_type: Option<String>,
+    /// OrdStatus (39): "0" New, "1" Partially filled, "2" Filled, "4" Canceled
+    pub ord_status: Option<String>,
+    /// Symbol (55)
+    pub symbol: String,
+    /// Side (54): "1" Buy, "2" Sell
+    pub side: String,
+    /// OrderQty (38)
+    pub order_qty: u64,
+    /// Price (44), limit price
+    pub price: f64,
+    /// LastQty (32)
+    pub last_qty: u64,
+    /// LastPx (31)
+    pub last_px: f64,
+    /// CumQty (14)
+    pub cum_qty: u64,
+    /// LeavesQty (151)
+    pub leaves_qty: u64,
+    /// Full tag=value message with `|` as the field delimiter
+    pub raw: String,
+}
+
+/// Portfolio rebalancing mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rebalance {
+    /// Buy and hold: units are fixed at the start, weights drift with prices
+    None,
+    /// Weights are reset to their targets every period
+    Daily,
+}
+
+impl Rebalance {
+    pub fn parse(mode: &str) -> Result<Self, FinanceError> {
+        match mode.to_lowercase().as_str() {
+            "none" => Ok(Rebalance::None),
+            "daily" => Ok(Rebalance::Daily),
+            _ => Err(FinanceError::InvalidRebalance(mode.to_string())),
+        }
+    }
+}
+
+/// Value-at-Risk estimation method
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VarMethod {
+    /// Empirical quantile of the observed returns
+    Historical,
+    /// Closed form assuming normally distributed returns
+    Gaussian,
+}
+
+impl VarMethod {
+    pub fn parse(method: &str) -> Result<Self, FinanceError> {
+        match method.to_lowercase().as_str() {
+            "historical" => Ok(VarMethod::Historical),
+            "gaussian" => Ok(VarMethod::Gaussian),
+            _ => Err(FinanceError::InvalidVarMethod(method.to_string())),
+        }
+    }
+}
+
+/// Value-at-Risk and Conditional VaR, expressed as positive loss magnitudes
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct RiskMeasures {
+    pub var: f64,
+    pub cvar: f64,
+}
+
+/// Close prices pivoted to a (date x asset) matrix
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PriceMatrix {
+    pub dates: Vec<String>,
+    pub tickers: Vec<String>,
+    /// `closes[t][i]` is the close of `tickers[i]` on `dates[t]`
+    pub closes: Vec<Vec<f64>>,
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
 
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    }
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
 }
 
 fn generate_ticker<R: Rng>(rng: &mut R) -> String {
@@ -308,6 +621,37 @@ fn black_scholes(
     (price, delta, gamma, theta, vega)
 }
 
+/// Resolve configured event indices and earnings dates against the trading
+/// calendar into an `EventWindowConfig` ready to apply
+fn resolve_event_windows(config: &FinanceConfig, dates: &[NaiveDateTime]) -> EventWindowConfig {
+    let mut events = config.events.clone();
+    for earnings in &config.earnings_dates {
+        if let Ok(date) = NaiveDate::parse_from_str(earnings, "%Y-%m-%d") {
+            if let Some(idx) = dates.iter().position(|d| d.date() >= date) {
+                events.event_indices.push(idx);
+            }
+        }
+    }
+    if !config.earnings_dates.is_empty() {
+        events.enable = true;
+    }
+    events.event_indices.sort_unstable();
+    events.event_indices.dedup();
+    events
+}
+
+/// Abnormal log returns per trading day from the event windows (zeros if disabled)
+fn event_abnormal_returns<R: Rng>(
+    rng: &mut R,
+    config: &FinanceConfig,
+    dates: &[NaiveDateTime],
+) -> Vec<f64> {
+    let mut abnormal = vec![0.0; dates.len()];
+    let events = resolve_event_windows(config, dates);
+    apply_event_windows(rng, &mut abnormal, &events);
+    abnormal
+}
+
 // =============================================================================
 // Generators
 // =============================================================================
@@ -342,13 +686,16 @@ pub fn generate_stock_prices(config: &FinanceConfig) -> Vec<OhlcvBar> {
     )
     .expect("Invalid lognormal params");
 
+    let abnormal = event_abnormal_returns(&mut rng, config, &dates);
+
     let mut prices = Vec::with_capacity(config.ndays);
     let mut prev_close = config.stock.initial_price;
 
-    for date in dates {
+    for (day_idx, date) in dates.into_iter().enumerate() {
         // GBM: S(t+1) = S(t) * exp((mu - 0.5*sigma^2)*dt + sigma*sqrt(dt)*Z)
         let z: f64 = normal.sample(&mut rng);
-        let mut log_return = (daily_drift - 0.5 * daily_vol.powi(2)) + daily_vol * z;
+        let mut log_return =
+            (daily_drift - 0.5 * daily_vol.powi(2)) + daily_vol * z + abnormal[day_idx];
 
         // Add jump if enabled
         if config.stock.enable_jumps && rng.random::<f64>() < config.stock.jump_probability {
@@ -406,6 +753,92 @@ pub fn generate_stock_prices(config: &FinanceConfig) -> Vec<OhlcvBar> {
     prices
 }
 
+/// Generate intraday OHLCV bars for a single asset.
+///
+/// Produces `ndays` trading sessions (09:30-16:00) of `bar_minutes` bars.
+/// Per-bar volatility and expected volume follow the same U-shaped profile
+/// as timeseries intraday volatility, so activity is highest at the open and
+/// close and lowest around midday.
+pub fn generate_intraday_prices(config: &FinanceConfig) -> Vec<OhlcvBar> {
+    let mut rng = create_rng(config.seed);
+
+    let start = config
+        .start_date
+        .as_ref()
+        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(2024, 1, 2).unwrap())
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    let dates = make_date_index(config.ndays, start);
+    let ticker = config
+        .tickers
+        .first()
+        .cloned()
+        .unwrap_or_else(|| generate_ticker(&mut rng));
+
+    let bar_minutes = config.intraday.bar_minutes.max(1) as usize;
+    let bars_per_day = (390 / bar_minutes).max(1);
+    let profile = IntradayConfig {
+        enable: true,
+        opening_volatility_mult: config.intraday.opening_volatility_mult,
+        midday_volatility_mult: config.intraday.midday_volatility_mult,
+        closing_volatility_mult: config.intraday.closing_volatility_mult,
+        ..Default::default()
+    };
+
+    // Scale daily parameters down to a single bar
+    let bar_drift = config.stock.annual_drift / 252.0 / bars_per_day as f64;
+    let bar_vol = config.stock.annual_volatility / 252.0_f64.sqrt() / (bars_per_day as f64).sqrt();
+    let bar_volume = config.ohlcv.avg_volume as f64 / bars_per_day as f64;
+
+    let normal = Normal::new(0.0, 1.0).expect("Invalid normal params");
+    let volume_noise = LogNormal::new(0.0, config.ohlcv.volume_volatility.min(1.0))
+        .expect("Invalid lognormal params");
+
+    let mut bars = Vec::with_capacity(config.ndays * bars_per_day);
+    let mut prev_close = config.stock.initial_price;
+
+    for date in dates {
+        let session_open = date.date().and_hms_opt(9, 30, 0).unwrap();
+
+        for i in 0..bars_per_day {
+            let mult = get_intraday_volatility_mult(i, bars_per_day, &profile);
+            let sigma = bar_vol * mult;
+
+            let z: f64 = normal.sample(&mut rng);
+            let log_return = (bar_drift - 0.5 * sigma.powi(2)) + sigma * z;
+            let returns = log_return.exp() - 1.0;
+
+            let open = prev_close;
+            let close = open * log_return.exp();
+            let high = open.max(close) * (1.0 + rng.random::<f64>() * sigma * 0.5);
+            let low = open.min(close) * (1.0 - rng.random::<f64>() * sigma * 0.5);
+
+            // Expected volume follows the same U-shape as volatility
+            let volume = (bar_volume * mult * volume_noise.sample(&mut rng)).max(1.0) as u64;
+            let vwap = (open + high + low + close) / 4.0;
+
+            let timestamp = session_open + ChronoDuration::minutes((i * bar_minutes) as i64);
+            bars.push(OhlcvBar {
+                date: timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                ticker: ticker.clone(),
+                open: (open * 100.0).round() / 100.0,
+                high: (high * 100.0).round() / 100.0,
+                low: (low * 100.0).round() / 100.0,
+                close: (close * 100.0).round() / 100.0,
+                volume,
+                vwap: (vwap * 100.0).round() / 100.0,
+                returns: (returns * 10000.0).round() / 10000.0,
+            });
+
+            prev_close = close;
+        }
+    }
+
+    bars
+}
+
 /// Generate correlated multi-asset OHLCV data
 pub fn generate_multi_asset_prices(config: &FinanceConfig) -> Vec<OhlcvBar> {
     let mut rng = create_rng(config.seed);
@@ -454,6 +887,11 @@ pub fn generate_multi_asset_prices(config: &FinanceConfig) -> Vec<OhlcvBar> {
     )
     .expect("Invalid lognormal params");
 
+    // Each asset gets its own abnormal returns around the event dates
+    let abnormal: Vec<Vec<f64>> = (0..n_assets)
+        .map(|_| event_abnormal_returns(&mut rng, config, &dates))
+        .collect();
+
     let mut all_bars = Vec::with_capacity(config.ndays * n_assets);
     let mut prev_closes: Vec<f64> = vec![config.stock.initial_price; n_assets];
 
@@ -463,7 +901,9 @@ pub fn generate_multi_asset_prices(config: &FinanceConfig) -> Vec<OhlcvBar> {
         for (asset_idx, ticker) in tickers.iter().enumerate() {
             // Transform uniform to standard normal
             let z = inv_norm_cdf(uniforms[asset_idx]);
-            let mut log_return = (daily_drift - 0.5 * daily_vol.powi(2)) + daily_vol * z;
+            let mut log_return = (daily_drift - 0.5 * daily_vol.powi(2))
+                + daily_vol * z
+                + abnormal[asset_idx][day_idx];
 
             // Add jump if enabled
             if config.stock.enable_jumps && rng.random::<f64>() < config.stock.jump_probability {
@@ -631,17 +1071,289 @@ pub fn generate_options_chain(
     options
 }
 
+/// Pivot long-format (date, ticker, close) columns, as produced by the price
+/// generators, into a price matrix. Dates and tickers keep first-seen order.
+pub fn pivot_closes(
+    dates: &[String],
+    tickers: &[String],
+    closes: &[f64],
+) -> Result<PriceMatrix, FinanceError> {
+    if dates.len() != tickers.len() || dates.len() != closes.len() {
+        return Err(FinanceError::LengthMismatch);
+    }
+
+    let mut date_index: HashMap<&str, usize> = HashMap::new();
+    let mut ticker_index: HashMap<&str, usize> = HashMap::new();
+    let mut unique_dates = Vec::new();
+    let mut unique_tickers = Vec::new();
+    for (date, ticker) in dates.iter().zip(tickers.iter()) {
+        date_index.entry(date).or_insert_with(|| {
+            unique_dates.push(date.clone());
+            unique_dates.len() - 1
+        });
+        ticker_index.entry(ticker).or_insert_with(|| {
+            unique_tickers.push(ticker.clone());
+            unique_tickers.len() - 1
+        });
+    }
+
+    let mut matrix = vec![vec![f64::NAN; unique_tickers.len()]; unique_dates.len()];
+    for ((date, ticker), &close) in dates.iter().zip(tickers.iter()).zip(closes.iter()) {
+        matrix[date_index[date.as_str()]][ticker_index[ticker.as_str()]] = close;
+    }
+
+    for (t, row) in matrix.iter().enumerate() {
+        if let Some(i) = row.iter().position(|v| v.is_nan()) {
+            return Err(FinanceError::MissingPrice {
+                date: unique_dates[t].clone(),
+                ticker: unique_tickers[i].clone(),
+            });
+        }
+    }
+
+    Ok(PriceMatrix {
+        dates: unique_dates,
+        tickers: unique_tickers,
+        closes: matrix,
+    })
+}
+
+/// Compute the value series of a weighted portfolio.
+///
+/// Weights are normalized to sum to one. The portfolio starts at
+/// `initial_value`, or at the weighted average of the first prices when
+/// `None` (so identical assets reproduce the single-asset price path).
+pub fn portfolio_values(
+    closes: &[Vec<f64>],
+    weights: &[f64],
+    rebalance: Rebalance,
+    initial_value: Option<f64>,
+) -> Result<Vec<f64>, FinanceError> {
+    let first = match closes.first() {
+        Some(row) => row,
+        None => return Ok(Vec::new()),
+    };
+    if weights.len() != first.len() {
+        return Err(FinanceError::WeightCountMismatch {
+            expected: first.len(),
+            got: weights.len(),
+        });
+    }
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 {
+        return Err(FinanceError::ZeroWeights);
+    }
+    let weights: Vec<f64> = weights.iter().map(|w| w / total).collect();
+
+    let start =
+        initial_value.unwrap_or_else(|| weights.iter().zip(first).map(|(w, p)| w * p).sum());
+
+    let values = match rebalance {
+        Rebalance::None => {
+            let units: Vec<f64> = weights
+                .iter()
+                .zip(first)
+                .map(|(w, p)| w * start / p)
+                .collect();
+            closes
+                .iter()
+                .map(|row| units.iter().zip(row).map(|(u, p)| u * p).sum())
+                .collect()
+        }
+        Rebalance::Daily => {
+            let mut values = Vec::with_capacity(closes.len());
+            let mut value = start;
+            values.push(value);
+            for pair in closes.windows(2) {
+                let period_return: f64 = weights
+                    .iter()
+                    .zip(pair[0].iter().zip(pair[1].iter()))
+                    .map(|(w, (prev, curr))| w * (curr / prev - 1.0))
+                    .sum();
+                value *= 1.0 + period_return;
+                values.push(value);
+            }
+            values
+        }
+    };
+
+    Ok(values)
+}
+
+/// Compute Value-at-Risk and Conditional VaR (expected shortfall) of a
+/// return series at confidence level `alpha`.
+///
+/// Both measures are reported as positive losses, and CVaR is never smaller
+/// than VaR. The Gaussian method uses the sample mean and standard deviation.
+pub fn var_cvar(
+    returns: &[f64],
+    alpha: f64,
+    method: VarMethod,
+) -> Result<RiskMeasures, FinanceError> {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(FinanceError::InvalidAlpha(alpha));
+    }
+    if returns.is_empty() {
+        return Err(FinanceError::EmptyReturns);
+    }
+
+    match method {
+        VarMethod::Historical => {
+            let mut losses: Vec<f64> = returns.iter().map(|r| -r).collect();
+            losses.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+            let idx = ((alpha * losses.len() as f64).ceil() as usize)
+                .saturating_sub(1)
+                .min(losses.len() - 1);
+            let var = losses[idx];
+            let tail = &losses[idx..];
+            let cvar = tail.iter().sum::<f64>() / tail.len() as f64;
+
+            Ok(RiskMeasures { var, cvar })
+        }
+        VarMethod::Gaussian => {
+            let n = returns.len() as f64;
+            let mean = returns.iter().sum::<f64>() / n;
+            let variance = if returns.len() > 1 {
+                returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)
+            } else {
+                0.0
+            };
+            let std_dev = variance.sqrt();
+
+            let z = inv_norm_cdf(alpha);
+            let var = -mean + std_dev * z;
+            let cvar = -mean + std_dev * norm_pdf(z) / (1.0 - alpha);
+
+            Ok(RiskMeasures { var, cvar })
+        }
+    }
+}
+
+/// Nelson-Siegel yield at `tenor` years
+pub fn nelson_siegel(tenor: f64, level: f64, slope: f64, curvature: f64, tau: f64) -> f64 {
+    if tenor <= 0.0 {
+        // Limit as tenor -> 0: the short rate
+        return level + slope;
+    }
+    let x = tenor / tau;
+    let loading = (1.0 - (-x).exp()) / x;
+    level + slope * loading + curvature * (loading - (-x).exp())
+}
+
+/// Generate Nelson-Siegel yield curves.
+///
+/// The first curve uses the configured factors exactly. Later days evolve
+/// level, slope and curvature as mean-reverting AR(1) processes, so each
+/// curve stays smooth across tenors.
+pub fn generate_yield_curve(config: &YieldCurveConfig) -> Vec<YieldPoint> {
+    let mut rng = create_rng(config.seed);
+
+    let start = config
+        .start_date
+        .as_ref()
+        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(2024, 1, 2).unwrap())
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let dates = make_date_index(config.ndays, start);
+
+    let normal = Normal::new(0.0, 1.0).expect("Invalid normal params");
+    let targets = [config.level, config.slope, config.curvature];
+    let mut factors = targets;
+
+    let mut points = Vec::with_capacity(config.ndays * config.tenors.len());
+    for (day_idx, date) in dates.into_iter().enumerate() {
+        if day_idx > 0 {
+            for (factor, target) in factors.iter_mut().zip(targets.iter()) {
+                let z: f64 = normal.sample(&mut rng);
+                *factor +=
+                    config.mean_reversion * (target - *factor) + config.factor_volatility * z;
+            }
+        }
+
+        let date = date.format("%Y-%m-%d").to_string();
+        for &tenor in &config.tenors {
+            points.push(YieldPoint {
+                date: date.clone(),
+                tenor,
+                yield_rate: nelson_siegel(tenor, factors[0], factors[1], factors[2], config.tau),
+            });
+        }
+    }
+
+    points
+}
+
+/// Simulate a credit portfolio with correlated defaults.
+///
+/// Each year, obligor `i` defaults when its latent asset value
+/// `sqrt(rho) * Z + sqrt(1 - rho) * e_i` falls below `inv_norm_cdf(pd)`.
+/// `Z` is a systematic factor shared by all obligors, so a higher asset
+/// correlation clusters defaults into bad years without changing the
+/// expected default rate of each rating.
+pub fn generate_credit_portfolio(config: &CreditConfig) -> Vec<Obligor> {
+    let mut rng = create_rng(config.seed);
+    let normal = Normal::new(0.0, 1.0).expect("Invalid normal params");
+    let exposure_dist = LogNormal::new(config.median_exposure.max(1.0).ln(), 0.8)
+        .expect("Invalid lognormal params");
+
+    let rho = config.asset_correlation.clamp(0.0, 1.0);
+    let (systematic_loading, idiosyncratic_loading) = (rho.sqrt(), (1.0 - rho).sqrt());
+
+    let mut obligors: Vec<Obligor> = (0..config.n_obligors)
+        .map(|i| {
+            let rating_idx =
+                sample_categorical(&mut rng, &config.rating_weights).min(CREDIT_RATINGS.len() - 1);
+            let pd = config
+                .default_probabilities
+                .get(rating_idx)
+                .copied()
+                .unwrap_or(0.0)
+                .clamp(0.0, 1.0);
+            let rating = CREDIT_RATINGS[rating_idx].to_string();
+            Obligor {
+                obligor_id: format!("OBL-{:06}", i + 1),
+                rating: rating.clone(),
+                pd,
+                exposure: (exposure_dist.sample(&mut rng) * 100.0).round() / 100.0,
+                defaulted: false,
+                default_year: None,
+                final_rating: rating,
+            }
+        })
+        .collect();
+
+    for year in 1..=config.horizon_years as u32 {
+        let z: f64 = normal.sample(&mut rng);
+        for obligor in obligors.iter_mut().filter(|o| !o.defaulted) {
+            let eps: f64 = normal.sample(&mut rng);
+            let asset = systematic_loading * z + idiosyncratic_loading * eps;
+            if obligor.pd > 0.0 && asset < inv_norm_cdf(obligor.pd) {
+                obligor.defaulted = true;
+                obligor.default_year = Some(year);
+                obligor.final_rating = "D".to_string();
+            }
+        }
+    }
+
+    obligors
+}
+
 /// Generate full finance dataset: prices + options
 pub fn generate_finance_data(config: &FinanceConfig) -> (Vec<OhlcvBar>, Vec<OptionQuote>) {
-    let prices = if config.n_assets > 1 {
+    let prices = if config.intraday.enable {
+        generate_intraday_prices(config)
+    } else if config.n_assets > 1 {
         generate_multi_asset_prices(config)
     } else {
         generate_stock_prices(config)
     };
 
-    // Generate options for the last date
+    // Generate options for the last date (intraday bars carry a time suffix)
     let options = if let Some(last_bar) = prices.last() {
-        generate_options_chain(config, last_bar.close, &last_bar.date)
+        let date = last_bar.date.get(..10).unwrap_or(&last_bar.date);
+        generate_options_chain(config, last_bar.close, date)
     } else {
         Vec::new()
     };
@@ -649,6 +1361,257 @@ pub fn generate_finance_data(config: &FinanceConfig) -> (Vec<OhlcvBar>, Vec<Opti
     (prices, options)
 }
 
+/// Build the tag=value wire form of a message, with BodyLength (9) and
+/// CheckSum (10) computed over the SOH-delimited bytes.
+fn encode_fix(msg: &FixMessage) -> String {
+    let (sender, target) = if msg.msg_type == "8" {
+        ("BROKER", "CLIENT")
+    } else {
+        ("CLIENT", "BROKER")
+    };
+
+    let mut fields: Vec<(u32, String)> = vec![
+        (35, msg.msg_type.clone()),
+        (49, sender.to_string()),
+        (56, target.to_string()),
+        (34, msg.seq_num.to_string()),
+        (52, msg.sending_time.clone()),
+        (11, msg.cl_ord_id.clone()),
+    ];
+    if let Some(orig) = &msg.orig_cl_ord_id {
+        fields.push((41, orig.clone()));
+    }
+    if let Some(order_id) = &msg.order_id {
+        fields.push((37, order_id.clone()));
+    }
+    if let Some(exec_id) = &msg.exec_id {
+        fields.push((17, exec_id.clone()));
+    }
+    if let Some(exec_type) = &msg.exec_type {
+        fields.push((150, exec_type.clone()));
+    }
+    if let Some(ord_status) = &msg.ord_status {
+        fields.push((39, ord_status.clone()));
+    }
+    fields.push((55, msg.symbol.clone()));
+    fields.push((54, msg.side.clone()));
+    fields.push((38, msg.order_qty.to_string()));
+    if msg.msg_type == "D" {
+        fields.push((40, "2".to_string()));
+        fields.push((44, format!("{:.2}", msg.price)));
+    }
+    if msg.msg_type == "8" {
+        fields.push((32, msg.last_qty.to_string()));
+        fields.push((31, format!("{:.2}", msg.last_px)));
+        fields.push((14, msg.cum_qty.to_string()));
+        fields.push((151, msg.leaves_qty.to_string()));
+    }
+
+    let body: String = fields
+        .iter()
+        .map(|(tag, value)| format!("{}={}{}", tag, value, FIX_SOH))
+        .collect();
+    let header = format!("8=FIX.4.4{}9={}{}", FIX_SOH, body.len(), FIX_SOH);
+    let checksum = (header
+        .bytes()
+        .chain(body.bytes())
+        .map(|b| b as u32)
+        .sum::<u32>())
+        % 256;
+    format!("{}{}10={:03}{}", header, body, checksum, FIX_SOH).replace(FIX_SOH, "|")
+}
+
+fn next_exec_id(counter: &mut u64) -> String {
+    *counter += 1;
+    format!("EX{:09}", counter)
+}
+
+/// Generate a FIX message log for `n` orders.
+///
+/// Each order follows a realistic lifecycle: NewOrderSingle, an
+/// ExecutionReport acknowledging it, zero or more partial fills, then either
+/// a final fill or a cancel request with its confirming report. Messages
+/// from different orders are interleaved by sending time and numbered with
+/// a single MsgSeqNum sequence.
+pub fn generate_fix_messages(n: usize, seed: Option<u64>) -> Vec<FixMessage> {
+    let mut rng = create_rng(seed);
+
+    let session_open = NaiveDate::from_ymd_opt(2024, 1, 2)
+        .unwrap()
+        .and_hms_opt(9, 30, 0)
+        .unwrap();
+    let session_millis: i64 = 390 * 60 * 1000;
+
+    let base_prices: Vec<f64> = FIX_SYMBOLS
+        .iter()
+        .map(|_| rng.random_range(20.0..500.0))
+        .collect();
+    let tick_noise = Normal::new(0.0, 0.002).expect("Invalid normal params");
+
+    let mut events: Vec<(NaiveDateTime, FixMessage)> = Vec::new();
+    let mut exec_counter = 0u64;
+
+    for order_idx in 0..n {
+        let symbol_idx = rng.random_range(0..FIX_SYMBOLS.len());
+        let symbol = FIX_SYMBOLS[symbol_idx].to_string();
+        let is_buy = rng.random::<f64>() < 0.5;
+        let side = if is_buy { "1" } else { "2" }.to_string();
+        let order_qty: u64 = rng.random_range(1..=50) * 100;
+        let limit =
+            (base_prices[symbol_idx] * (1.0 + tick_noise.sample(&mut rng)) * 100.0).round() / 100.0;
+
+        let cl_ord_id = format!("CL{:08}", order_idx + 1);
+        let order_id = format!("ORD{:08}", order_idx + 1);
+        let mut time =
+            session_open + ChronoDuration::milliseconds(rng.random_range(0..session_millis));
+
+        let template = FixMessage {
+            seq_num: 0,
+            sending_time: String::new(),
+            msg_type: "D".to_string(),
+            cl_ord_id: cl_ord_id.clone(),
+            orig_cl_ord_id: None,
+            order_id: None,
+            exec_id: None,
+            exec_type: None,
+            ord_status: None,
+            symbol,
+            side,
+            order_qty,
+            price: limit,
+            last_qty: 0,
+            last_px: 0.0,
+            cum_qty: 0,
+            leaves_qty: order_qty,
+            raw: String::new(),
+        };
+
+        // NewOrderSingle
+        events.push((time, template.clone()));
+
+        // Broker acknowledgement
+        time += ChronoDuration::milliseconds(rng.random_range(1..50));
+        events.push((
+            time,
+            FixMessage {
+                msg_type: "8".to_string(),
+                order_id: Some(order_id.clone()),
+                exec_id: Some(next_exec_id(&mut exec_counter)),
+                exec_type: Some("0".to_string()),
+                ord_status: Some("0".to_string()),
+                ..template.clone()
+            },
+        ));
+
+        // Partial fills
+        let mut cum_qty = 0u64;
+        let n_partials = rng.random_range(0..=3u64).min(order_qty / 100 - 1);
+        for _ in 0..n_partials {
+            let remaining_lots = (order_qty - cum_qty) / 100;
+            if remaining_lots <= 1 {
+                break;
+            }
+            let last_qty = rng.random_range(1..remaining_lots) * 100;
+            cum_qty += last_qty;
+            let improvement = rng.random::<f64>() * 0.001 * limit;
+            let last_px = if is_buy {
+                limit - improvement
+            } else {
+                limit + improvement
+            };
+            time += ChronoDuration::milliseconds(rng.random_range(10..60_000));
+            events.push((
+                time,
+                FixMessage {
+                    msg_type: "8".to_string(),
+                    order_id: Some(order_id.clone()),
+                    exec_id: Some(next_exec_id(&mut exec_counter)),
+                    exec_type: Some("F".to_string()),
+                    ord_status: Some("1".to_string()),
+                    last_qty,
+                    last_px: (last_px * 100.0).round() / 100.0,
+                    cum_qty,
+                    leaves_qty: order_qty - cum_qty,
+                    ..template.clone()
+                },
+            ));
+        }
+
+        time += ChronoDuration::milliseconds(rng.random_range(10..60_000));
+        if rng.random::<f64>() < 0.75 {
+            // Final fill
+            let last_qty = order_qty - cum_qty;
+            let improvement = rng.random::<f64>() * 0.001 * limit;
+            let last_px = if is_buy {
+                limit - improvement
+            } else {
+                limit + improvement
+            };
+            events.push((
+                time,
+                FixMessage {
+                    msg_type: "8".to_string(),
+                    order_id: Some(order_id.clone()),
+                    exec_id: Some(next_exec_id(&mut exec_counter)),
+                    exec_type: Some("F".to_string()),
+                    ord_status: Some("2".to_string()),
+                    last_qty,
+                    last_px: (last_px * 100.0).round() / 100.0,
+                    cum_qty: order_qty,
+                    leaves_qty: 0,
+                    ..template.clone()
+                },
+            ));
+        } else {
+            // Cancel request and confirmation
+            let cancel_id = format!("{}-C", cl_ord_id);
+            events.push((
+                time,
+                FixMessage {
+                    msg_type: "F".to_string(),
+                    cl_ord_id: cancel_id.clone(),
+                    orig_cl_ord_id: Some(cl_ord_id.clone()),
+                    order_id: Some(order_id.clone()),
+                    cum_qty,
+                    leaves_qty: order_qty - cum_qty,
+                    ..template.clone()
+                },
+            ));
+            time += ChronoDuration::milliseconds(rng.random_range(1..50));
+            events.push((
+                time,
+                FixMessage {
+                    msg_type: "8".to_string(),
+                    cl_ord_id: cancel_id,
+                    orig_cl_ord_id: Some(cl_ord_id.clone()),
+                    order_id: Some(order_id.clone()),
+                    exec_id: Some(next_exec_id(&mut exec_counter)),
+                    exec_type: Some("4".to_string()),
+                    ord_status: Some("4".to_string()),
+                    cum_qty,
+                    leaves_qty: 0,
+                    ..template.clone()
+                },
+            ));
+        }
+    }
+
+    // Interleave orders by sending time; the sort is stable so each order's
+    // own messages keep their lifecycle order on timestamp ties
+    events.sort_by_key(|(time, _)| *time);
+
+    events
+        .into_iter()
+        .enumerate()
+        .map(|(i, (time, mut msg))| {
+            msg.seq_num = i as u64 + 1;
+            msg.sending_time = time.format("%Y%m%d-%H:%M:%S%.3f").to_string();
+            msg.raw = encode_fix(&msg);
+            msg
+        })
+        .collect()
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -774,4 +1737,360 @@ mod tests {
         let prices = generate_stock_prices(&config);
         assert_eq!(prices.len(), 100);
     }
+
+    #[test]
+    fn test_fix_messages_lifecycle_linkage() {
+        let messages = generate_fix_messages(200, Some(42));
+        assert!(!messages.is_empty());
+
+        let mut new_orders: std::collections::HashMap<&str, &FixMessage> =
+            std::collections::HashMap::new();
+        let mut order_ids: std::collections::HashMap<&str, &str> = std::collections::HashMap::new();
+
+        for (i, msg) in messages.iter().enumerate() {
+            assert_eq!(msg.seq_num, i as u64 + 1);
+            assert!(msg.raw.starts_with("8=FIX.4.4|9="));
+            assert!(msg.raw.contains(&format!("|35={}|", msg.msg_type)));
+
+            match msg.msg_type.as_str() {
+                "D" => {
+                    new_orders.insert(msg.cl_ord_id.as_str(), msg);
+                }
+                "F" | "8" => {
+                    // Every report and cancel refers back to a NewOrderSingle already sent
+                    let root = msg.orig_cl_ord_id.as_deref().unwrap_or(&msg.cl_ord_id);
+                    let original = new_orders
+                        .get(root)
+                        .unwrap_or_else(|| panic!("{} has no prior NewOrderSingle", root));
+                    assert_eq!(original.symbol, msg.symbol);
+                    assert_eq!(original.order_qty, msg.order_qty);
+
+                    // OrderID is stable across the whole lifecycle
+                    let order_id = msg.order_id.as_deref().expect("missing OrderID");
+                    let known = order_ids.entry(root).or_insert(order_id);
+                    assert_eq!(*known, order_id);
+
+                    if msg.msg_type == "8" {
+                        assert!(msg.exec_id.is_some());
+                        assert!(msg.cum_qty + msg.leaves_qty <= msg.order_qty);
+                    }
+                }
+                other => panic!("unexpected MsgType {}", other),
+            }
+        }
+
+        // Every order reaches a terminal state (filled or canceled)
+        let terminal: std::collections::HashSet<&str> = messages
+            .iter()
+            .filter(|m| matches!(m.ord_status.as_deref(), Some("2") | Some("4")))
+            .map(|m| m.orig_cl_ord_id.as_deref().unwrap_or(&m.cl_ord_id))
+            .collect();
+        assert_eq!(terminal.len(), 200);
+    }
+
+    #[test]
+    fn test_fix_messages_checksum() {
+        let messages = generate_fix_messages(5, Some(7));
+        for msg in &messages {
+            let wire = msg.raw.replace('|', "\x01");
+            let checksum_pos = wire.rfind("10=").unwrap();
+            let expected: u32 = wire[..checksum_pos].bytes().map(|b| b as u32).sum::<u32>() % 256;
+            assert_eq!(
+                &wire[checksum_pos + 3..checksum_pos + 6],
+                format!("{:03}", expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_intraday_volume_profile() {
+        let config = FinanceConfig {
+            ndays: 40,
+            seed: Some(42),
+            intraday: IntradayBarConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bars = generate_intraday_prices(&config);
+        assert_eq!(bars.len(), 40 * 390);
+        assert!(bars[0].date.ends_with("09:30:00"));
+        assert!(bars[389].date.ends_with("15:59:00"));
+
+        // Average volume in the first/last half hour vs. the middle of the session
+        let mut open_vol = 0.0;
+        let mut mid_vol = 0.0;
+        let mut close_vol = 0.0;
+        for day in bars.chunks(390) {
+            open_vol += day[..30].iter().map(|b| b.volume as f64).sum::<f64>();
+            mid_vol += day[180..210].iter().map(|b| b.volume as f64).sum::<f64>();
+            close_vol += day[360..].iter().map(|b| b.volume as f64).sum::<f64>();
+        }
+        assert!(
+            open_vol > mid_vol * 1.3,
+            "open {} vs midday {}",
+            open_vol,
+            mid_vol
+        );
+        assert!(
+            close_vol > mid_vol * 1.3,
+            "close {} vs midday {}",
+            close_vol,
+            mid_vol
+        );
+
+        for bar in &bars {
+            assert!(bar.high >= bar.open.max(bar.close));
+            assert!(bar.low <= bar.open.min(bar.close));
+        }
+    }
+
+    #[test]
+    fn test_earnings_event_windows() {
+        let config = FinanceConfig {
+            ndays: 252,
+            seed: Some(42),
+            earnings_dates: vec![
+                "2024-02-01".to_string(),
+                "2024-05-01".to_string(),
+                "2024-08-01".to_string(),
+                "2024-11-01".to_string(),
+            ],
+            events: EventWindowConfig {
+                pre_event_window: 2,
+                post_event_window: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let prices = generate_stock_prices(&config);
+
+        let event_idx: Vec<usize> = config
+            .earnings_dates
+            .iter()
+            .map(|d| {
+                prices
+                    .iter()
+                    .position(|b| b.date.as_str() >= d.as_str())
+                    .unwrap()
+            })
+            .collect();
+        let in_window = |i: usize| event_idx.iter().any(|&e| i + 2 >= e && i <= e + 5);
+
+        let (mut event_abs, mut event_n, mut base_abs, mut base_n) = (0.0, 0, 0.0, 0);
+        for (i, bar) in prices.iter().enumerate() {
+            if in_window(i) {
+                event_abs += bar.returns.abs();
+                event_n += 1;
+            } else {
+                base_abs += bar.returns.abs();
+                base_n += 1;
+            }
+        }
+        let event_mean = event_abs / event_n as f64;
+        let base_mean = base_abs / base_n as f64;
+        assert!(
+            event_mean > base_mean * 1.5,
+            "event window |r| {} vs baseline {}",
+            event_mean,
+            base_mean
+        );
+    }
+
+    #[test]
+    fn test_portfolio_identical_assets() {
+        let single = generate_stock_prices(&FinanceConfig {
+            ndays: 100,
+            seed: Some(42),
+            ..Default::default()
+        });
+
+        // Two identical series in long format
+        let mut dates = Vec::new();
+        let mut tickers = Vec::new();
+        let mut closes = Vec::new();
+        for bar in &single {
+            for ticker in ["A", "B"] {
+                dates.push(bar.date.clone());
+                tickers.push(ticker.to_string());
+                closes.push(bar.close);
+            }
+        }
+        let matrix = pivot_closes(&dates, &tickers, &closes).unwrap();
+        assert_eq!(matrix.tickers, vec!["A", "B"]);
+        assert_eq!(matrix.dates.len(), 100);
+
+        for rebalance in [Rebalance::None, Rebalance::Daily] {
+            let values = portfolio_values(&matrix.closes, &[0.5, 0.5], rebalance, None).unwrap();
+            for (value, bar) in values.iter().zip(single.iter()) {
+                assert!((value - bar.close).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_portfolio_rebalancing() {
+        // Asset A doubles then halves; B is flat
+        let closes = vec![vec![100.0, 100.0], vec![200.0, 100.0], vec![100.0, 100.0]];
+
+        let hold = portfolio_values(&closes, &[1.0, 1.0], Rebalance::None, Some(1.0)).unwrap();
+        assert!((hold[1] - 1.5).abs() < 1e-12);
+        assert!((hold[2] - 1.0).abs() < 1e-12);
+
+        // Daily rebalancing: +50% then -25%
+        let daily = portfolio_values(&closes, &[1.0, 1.0], Rebalance::Daily, Some(1.0)).unwrap();
+        assert!((daily[1] - 1.5).abs() < 1e-12);
+        assert!((daily[2] - 1.125).abs() < 1e-12);
+
+        assert!(matches!(
+            portfolio_values(&closes, &[1.0], Rebalance::None, None),
+            Err(FinanceError::WeightCountMismatch { .. })
+        ));
+        assert!(Rebalance::parse("weekly").is_err());
+    }
+
+    #[test]
+    fn test_gaussian_var_matches_analytic_quantile() {
+        let returns = generate_stock_prices(&FinanceConfig {
+            ndays: 500,
+            seed: Some(42),
+            ..Default::default()
+        })
+        .iter()
+        .map(|bar| bar.returns)
+        .collect::<Vec<f64>>();
+
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let std_dev = (returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+
+        // z_0.95 and z_0.99 of the standard normal
+        for (alpha, z) in [(0.95, 1.6448536269514722), (0.99, 2.3263478740408408)] {
+            let risk = var_cvar(&returns, alpha, VarMethod::Gaussian).unwrap();
+            let expected = -mean + std_dev * z;
+            assert!(
+                (risk.var - expected).abs() < 1e-8,
+                "alpha={}: {} vs {}",
+                alpha,
+                risk.var,
+                expected
+            );
+            assert!(risk.cvar >= risk.var);
+
+            let hist = var_cvar(&returns, alpha, VarMethod::Historical).unwrap();
+            assert!(hist.cvar >= hist.var);
+        }
+    }
+
+    #[test]
+    fn test_historical_var() {
+        let returns: Vec<f64> = (1..=100).map(|i| -(i as f64) / 100.0).collect();
+        let risk = var_cvar(&returns, 0.95, VarMethod::Historical).unwrap();
+        assert!((risk.var - 0.95).abs() < 1e-12);
+        // Mean of the six worst losses (0.95..=1.00)
+        assert!((risk.cvar - 0.975).abs() < 1e-12);
+
+        assert!(var_cvar(&returns, 1.5, VarMethod::Historical).is_err());
+        assert!(var_cvar(&[], 0.95, VarMethod::Gaussian).is_err());
+    }
+
+    #[test]
+    fn test_yield_curve_matches_nelson_siegel() {
+        let config = YieldCurveConfig {
+            level: 0.05,
+            slope: -0.03,
+            curvature: 0.02,
+            tau: 1.5,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let curve = generate_yield_curve(&config);
+        assert_eq!(curve.len(), config.tenors.len());
+
+        for point in &curve {
+            let x = point.tenor / config.tau;
+            let loading = (1.0 - (-x).exp()) / x;
+            let expected = 0.05 - 0.03 * loading + 0.02 * (loading - (-x).exp());
+            assert!((point.yield_rate - expected).abs() < 1e-12);
+        }
+
+        // Upward sloping with these parameters
+        assert!(curve.last().unwrap().yield_rate > curve[0].yield_rate);
+        // Short and long ends approach level + slope and level
+        assert!((nelson_siegel(0.0, 0.05, -0.03, 0.02, 1.5) - 0.02).abs() < 1e-12);
+        assert!((nelson_siegel(1000.0, 0.05, -0.03, 0.02, 1.5) - 0.05).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_yield_curve_evolution() {
+        let config = YieldCurveConfig {
+            ndays: 20,
+            seed: Some(7),
+            ..Default::default()
+        };
+        let curves = generate_yield_curve(&config);
+        assert_eq!(curves.len(), 20 * config.tenors.len());
+
+        let first_day = &curves[..config.tenors.len()];
+        let last_day = &curves[curves.len() - config.tenors.len()..];
+        assert_ne!(first_day[0].date, last_day[0].date);
+        assert!(first_day
+            .iter()
+            .zip(last_day.iter())
+            .any(|(a, b)| a.yield_rate != b.yield_rate));
+    }
+
+    #[test]
+    fn test_credit_default_rates_by_rating() {
+        let config = CreditConfig {
+            n_obligors: 200_000,
+            asset_correlation: 0.0,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let obligors = generate_credit_portfolio(&config);
+
+        for (idx, rating) in CREDIT_RATINGS.iter().enumerate() {
+            let group: Vec<&Obligor> = obligors.iter().filter(|o| o.rating == *rating).collect();
+            let pd = config.default_probabilities[idx];
+            let rate = group.iter().filter(|o| o.defaulted).count() as f64 / group.len() as f64;
+            // Allow four binomial standard errors
+            let tolerance = 4.0 * (pd * (1.0 - pd) / group.len() as f64).sqrt() + 1e-4;
+            assert!(
+                (rate - pd).abs() < tolerance,
+                "{}: default rate {} vs pd {}",
+                rating,
+                rate,
+                pd
+            );
+        }
+
+        assert!(obligors
+            .iter()
+            .all(|o| o.defaulted == (o.final_rating == "D")
+                && o.defaulted == o.default_year.is_some()));
+    }
+
+    #[test]
+    fn test_credit_correlation_clusters_defaults() {
+        let yearly_rate_variance = |rho: f64| {
+            let obligors = generate_credit_portfolio(&CreditConfig {
+                n_obligors: 5000,
+                horizon_years: 40,
+                asset_correlation: rho,
+                rating_weights: vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+                seed: Some(7),
+                ..Default::default()
+            });
+            let mut counts = vec![0.0; 40];
+            for year in obligors.iter().filter_map(|o| o.default_year) {
+                counts[year as usize - 1] += 1.0;
+            }
+            let mean = counts.iter().sum::<f64>() / counts.len() as f64;
+            counts.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / counts.len() as f64
+        };
+
+        assert!(yearly_rate_variance(0.4) > 4.0 * yearly_rate_variance(0.0));
+    }
 }
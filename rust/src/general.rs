@@ -1,11 +1,18 @@
-use chrono::{Datelike, NaiveDate, Utc};
-use rand::rngs::StdRng;
+use crate::rng::{derive_seed, seeded_rng, SeededRng};
+use chrono::{Datelike, Months, NaiveDate, Utc};
 use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
+use rand_distr::{Distribution, LogNormal};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
-use crate::copulas::GaussianCopula;
-use crate::utils::{US_SECTORS, US_SECTORS_MAP};
+use crate::copulas::{GaussianCopula, StudentTCopula};
+use crate::correlation::nearest_correlation_matrix;
+use crate::distributions::sample_categorical;
+use crate::utils::{round_cent, round_cents, US_SECTORS, US_SECTORS_MAP};
 
 const SHIP_MODES: [&str; 3] = ["First Class", "Standard Class", "Second Class"];
 const SEGMENTS: [&str; 4] = ["A", "B", "C", "D"];
@@ -13,7 +20,52 @@ const PREFIXES: [&str; 6] = ["Mr.", "Mrs.", "Ms.", "Dr.", "Prof.", "Rev."];
 const SUFFIXES: [&str; 4] = ["Jr.", "Sr.", "III", "IV"];
 
 // Default pool size for pre-generated data
-const DEFAULT_POOL_SIZE: usize = 1000;
+pub(crate) const DEFAULT_POOL_SIZE: usize = 1000;
+
+// Average repeats of each pooled value above which
+// `SuperstoreConfig::warnings` flags the pool as too small
+const MAX_ROWS_PER_POOLED_VALUE: usize = 1000;
+
+// VIP order multiplier above which `SuperstoreConfig::warnings` flags it
+const HIGH_VIP_ORDER_MULTIPLIER: f64 = 3.0;
+
+/// Region labels shared by superstore rows and employees, so the two tables
+/// join on `region` out of the box
+pub const DEFAULT_REGIONS: [&str; 4] = ["West", "East", "Central", "South"];
+
+fn default_regions() -> Vec<String> {
+    DEFAULT_REGIONS.iter().map(|r| r.to_string()).collect()
+}
+
+lazy_static::lazy_static! {
+    // Low-cardinality superstore values, allocated once and shared by every
+    // row so generating a row clones a pointer instead of a string
+    pub(crate) static ref SHIP_MODE_VALUES: Vec<Arc<str>> = intern(&SHIP_MODES);
+    pub(crate) static ref SEGMENT_VALUES: Vec<Arc<str>> = intern(&SEGMENTS);
+    pub(crate) static ref COUNTRY_VALUE: Arc<str> = Arc::from("US");
+    pub(crate) static ref DEFAULT_REGION_VALUES: Vec<Arc<str>> = intern(&DEFAULT_REGIONS);
+    /// `US_SECTORS`, in the same order
+    pub(crate) static ref SECTOR_VALUES: Vec<Arc<str>> = intern(&US_SECTORS);
+}
+
+/// Allocate each value once, for sharing between rows
+fn intern<S: AsRef<str>>(values: &[S]) -> Vec<Arc<str>> {
+    values.iter().map(|v| Arc::from(v.as_ref())).collect()
+}
+
+/// Date that `stable_v1` output is generated relative to: orders fall in this
+/// year and ship no later than this date, so output doesn't drift with the
+/// wall clock.
+pub const STABLE_V1_REFERENCE_DATE: &str = "2024-12-31";
+
+// EIN prefixes assigned by the IRS (00, 07-09, 17-19, 28-29, 49, 69-70,
+// 78-79, 89 and 96-97 are never issued)
+const VALID_EIN_PREFIXES: [u32; 83] = [
+    1, 2, 3, 4, 5, 6, 10, 11, 12, 13, 14, 15, 16, 20, 21, 22, 23, 24, 25, 26, 27, 30, 31, 32, 33,
+    34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 50, 51, 52, 53, 54, 55, 56, 57, 58,
+    59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 71, 72, 73, 74, 75, 76, 77, 80, 81, 82, 83, 84, 85, 86,
+    87, 88, 90, 91, 92, 93, 94, 95, 98, 99,
+];
 
 /// Costco-style item status based on price ending
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -38,8 +90,10 @@ impl ItemStatus {
         }
     }
 
-    /// Profit margin multiplier for this item status
-    /// Regular items have full margins, sale/clearance have reduced margins
+    /// Profit margin multiplier for this item status, applied only by the
+    /// `stable_v1` generator: regular items have full margins, sale and
+    /// clearance items reduced ones. Other rows take their margin from the
+    /// copula alone
     pub fn profit_multiplier(&self) -> f64 {
         match self {
             ItemStatus::Regular => 1.0,
@@ -98,7 +152,13 @@ impl Default for SeasonalityConfig {
 pub struct PromotionalConfig {
     pub enable: bool,
     pub discount_quantity_correlation: f64,
+    /// Constant price elasticity of demand: quantity scales with
+    /// `(item_price / reference_price) ^ price_elasticity`
     pub price_elasticity: f64,
+    /// Price (USD) at which elasticity leaves quantity unchanged; `None`
+    /// uses the midpoint of `min_sales` and `max_sales`
+    #[serde(default)]
+    pub reference_price: Option<f64>,
 }
 
 impl Default for PromotionalConfig {
@@ -107,6 +167,7 @@ impl Default for PromotionalConfig {
             enable: false,
             discount_quantity_correlation: 0.5,
             price_elasticity: -0.8,
+            reference_price: None,
         }
     }
 }
@@ -255,34 +316,77 @@ impl Default for InventoryConfig {
     }
 }
 
-/// Payment method types with associated fraud rates
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Payment method types with associated fraud rates.
+///
+/// This is the single payment vocabulary for every generator: superstore rows
+/// and e-commerce orders both emit [`PaymentMethod::as_str`], the canonical
+/// snake_case form (e.g. "credit_card"). [`PaymentMethod::label`] gives the
+/// display name and [`PaymentMethod::parse`] accepts either form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PaymentMethod {
     CreditCard,
     DebitCard,
     PayPal,
+    ApplePay,
+    GooglePay,
     GiftCard,
     BankTransfer,
     CashOnDelivery,
 }
 
 impl PaymentMethod {
+    /// Every payment method, in canonical order
+    pub const ALL: [PaymentMethod; 8] = [
+        PaymentMethod::CreditCard,
+        PaymentMethod::DebitCard,
+        PaymentMethod::PayPal,
+        PaymentMethod::ApplePay,
+        PaymentMethod::GooglePay,
+        PaymentMethod::GiftCard,
+        PaymentMethod::BankTransfer,
+        PaymentMethod::CashOnDelivery,
+    ];
+
+    /// Canonical string emitted in generated data
     pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentMethod::CreditCard => "credit_card",
+            PaymentMethod::DebitCard => "debit_card",
+            PaymentMethod::PayPal => "paypal",
+            PaymentMethod::ApplePay => "apple_pay",
+            PaymentMethod::GooglePay => "google_pay",
+            PaymentMethod::GiftCard => "gift_card",
+            PaymentMethod::BankTransfer => "bank_transfer",
+            PaymentMethod::CashOnDelivery => "cash_on_delivery",
+        }
+    }
+
+    /// Human-readable name (e.g. "Credit Card")
+    pub fn label(&self) -> &'static str {
         match self {
             PaymentMethod::CreditCard => "Credit Card",
             PaymentMethod::DebitCard => "Debit Card",
             PaymentMethod::PayPal => "PayPal",
+            PaymentMethod::ApplePay => "Apple Pay",
+            PaymentMethod::GooglePay => "Google Pay",
             PaymentMethod::GiftCard => "Gift Card",
             PaymentMethod::BankTransfer => "Bank Transfer",
             PaymentMethod::CashOnDelivery => "Cash on Delivery",
         }
     }
 
+    /// Parse a canonical string or display name, ignoring case
+    pub fn parse(name: &str) -> Option<Self> {
+        let key = name.trim().to_lowercase().replace([' ', '-'], "_");
+        Self::ALL.into_iter().find(|m| m.as_str() == key)
+    }
+
     pub fn fraud_rate(&self) -> f64 {
         match self {
             PaymentMethod::CreditCard => 0.02,
             PaymentMethod::DebitCard => 0.015,
             PaymentMethod::PayPal => 0.01,
+            PaymentMethod::ApplePay | PaymentMethod::GooglePay => 0.005,
             PaymentMethod::GiftCard => 0.03,
             PaymentMethod::BankTransfer => 0.005,
             PaymentMethod::CashOnDelivery => 0.001,
@@ -294,21 +398,85 @@ impl PaymentMethod {
             PaymentMethod::CreditCard => 0.029,
             PaymentMethod::DebitCard => 0.021,
             PaymentMethod::PayPal => 0.034,
+            PaymentMethod::ApplePay | PaymentMethod::GooglePay => 0.029,
             PaymentMethod::GiftCard => 0.0,
             PaymentMethod::BankTransfer => 0.005,
             PaymentMethod::CashOnDelivery => 0.0,
         }
     }
+
+    /// Settlement delay range in days after the order date (inclusive)
+    pub fn settlement_delay_days(&self) -> (i64, i64) {
+        match self {
+            PaymentMethod::CreditCard => (1, 2),
+            PaymentMethod::DebitCard => (1, 1),
+            PaymentMethod::PayPal => (1, 3),
+            PaymentMethod::ApplePay | PaymentMethod::GooglePay => (1, 2),
+            PaymentMethod::GiftCard => (0, 0),
+            PaymentMethod::BankTransfer => (3, 5),
+            PaymentMethod::CashOnDelivery => (0, 0),
+        }
+    }
+}
+
+// Payment method distribution weights, in `PaymentMethod::ALL` order
+const PAYMENT_METHOD_WEIGHTS: [f64; 8] = [0.38, 0.24, 0.14, 0.04, 0.03, 0.07, 0.06, 0.04];
+
+/// Card network for card payments
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CardNetwork {
+    Visa,
+    Mastercard,
+    Amex,
+}
+
+impl CardNetwork {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardNetwork::Visa => "Visa",
+            CardNetwork::Mastercard => "Mastercard",
+            CardNetwork::Amex => "Amex",
+        }
+    }
+
+    /// Issuer prefixes (BINs) from the networks' published test ranges
+    pub fn test_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            CardNetwork::Visa => &["411111", "424242", "400005"],
+            CardNetwork::Mastercard => &["555555", "510510", "222300"],
+            CardNetwork::Amex => &["378282", "371449", "340000"],
+        }
+    }
+
+    /// PAN length in digits
+    pub fn pan_length(&self) -> usize {
+        match self {
+            CardNetwork::Amex => 15,
+            _ => 16,
+        }
+    }
 }
 
-// Payment method distribution weights
-const PAYMENT_METHOD_WEIGHTS: [f64; 6] = [0.40, 0.25, 0.15, 0.08, 0.07, 0.05];
+// Card network distribution weights (Visa, Mastercard, Amex)
+const CARD_NETWORK_WEIGHTS: [f64; 3] = [0.55, 0.30, 0.15];
 
 /// Configuration for payment methods
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PaymentConfig {
     pub enable: bool,
     pub fraud_simulation: bool,
+    /// Emit Luhn-valid test-range card numbers for card payments
+    pub card_numbers: bool,
+    /// Generate chargeback events (see `generate_chargebacks`)
+    pub chargebacks: bool,
+    /// Probability that a fraudulent order is charged back
+    pub fraud_chargeback_rate: f64,
+    /// Probability that a legitimate order is disputed and charged back
+    pub dispute_chargeback_rate: f64,
+    /// Fixed per-transaction fee added to the percentage fee (e.g. 0.30)
+    pub fixed_fee: f64,
+    /// Minimum fee charged per transaction
+    pub min_fee: f64,
 }
 
 impl Default for PaymentConfig {
@@ -316,10 +484,119 @@ impl Default for PaymentConfig {
         Self {
             enable: false,
             fraud_simulation: true,
+            card_numbers: false,
+            chargebacks: false,
+            fraud_chargeback_rate: 0.75,
+            dispute_chargeback_rate: 0.005,
+            fixed_fee: 0.0,
+            min_fee: 0.0,
+        }
+    }
+}
+
+/// Configuration for return and refund rows
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReturnsConfig {
+    pub enable: bool,
+    /// Expected fraction of orders that are returned. Clearance and
+    /// returned/floor-model items return more often than regular ones, so
+    /// per-status rates are scaled to average out at this rate
+    pub return_rate: f64,
+    /// Share of the sale kept as a restocking fee on "No Longer Needed"
+    /// returns; defective and wrong items are refunded in full
+    pub restocking_fee_rate: f64,
+    /// Mean days between ship date and return date
+    pub avg_days_to_return: f64,
+}
+
+impl Default for ReturnsConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            return_rate: 0.08,
+            restocking_fee_rate: 0.15,
+            avg_days_to_return: 14.0,
+        }
+    }
+}
+
+const RETURN_REASONS: [&str; 3] = ["Defective", "Wrong Item", "No Longer Needed"];
+// Return reason weights for full-price items and for discounted
+// (sale, clearance, floor model) items, which skew toward defects
+const RETURN_REASON_WEIGHTS: [f64; 3] = [0.25, 0.20, 0.55];
+const DISCOUNTED_RETURN_REASON_WEIGHTS: [f64; 3] = [0.50, 0.15, 0.35];
+
+/// Relative return likelihood by item status
+fn return_weight(item_status: &str) -> f64 {
+    match item_status {
+        "Manufacturer Sale" => 1.5,
+        "Clearance" => 2.5,
+        "Returned/Floor Model" => 3.0,
+        _ => 1.0,
+    }
+}
+
+// Chargeback reason codes (Visa dispute categories)
+const FRAUD_REASON_CODE: &str = "10.4";
+const DISPUTE_REASON_CODES: [&str; 4] = ["13.1", "13.3", "13.7", "12.6.1"];
+
+/// Chargeback raised against a superstore order
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChargebackEvent {
+    pub order_id: String,
+    pub chargeback_date: String,
+    pub amount: f64,
+    pub reason_code: String,
+}
+
+/// Which employee PII fields to mask for public sharing
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PiiMaskConfig {
+    /// Keep only the last four digits (`***-**-1234`)
+    pub ssn: bool,
+    /// Replace the local part with a stable hash, keeping the domain
+    pub email: bool,
+    /// Keep only the last four digits (`(***) ***-1234`)
+    pub phone_number: bool,
+}
+
+impl PiiMaskConfig {
+    /// Mask every supported field
+    pub fn all() -> Self {
+        Self {
+            ssn: true,
+            email: true,
+            phone_number: true,
         }
     }
+
+    /// Whether any field is masked
+    pub fn any(&self) -> bool {
+        self.ssn || self.email || self.phone_number
+    }
+}
+
+/// How the `sales` and `profit` columns relate to price and quantity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SalesMode {
+    /// `sales` is the item price (scaled up for VIP customers) regardless of
+    /// quantity, and `profit` is an additive draw independent of `sales`
+    #[default]
+    Synthetic,
+    /// `sales` is order revenue, `item_price * quantity` rounded to whole
+    /// dollars, and `profit` is a margin fraction of `sales` (see
+    /// [`PRICE_TIMES_QUANTITY_MARGIN_RANGE`])
+    PriceTimesQuantity,
 }
 
+/// Range of the base profit margin in [`SalesMode::PriceTimesQuantity`],
+/// before discount, item status, and processing fee adjustments
+pub const PRICE_TIMES_QUANTITY_MARGIN_RANGE: (f64, f64) = (-0.1, 0.4);
+
+/// Categories, each with its sub-categories and their product types
+pub type Taxonomy = Vec<(String, Vec<(String, Vec<String>)>)>;
+
 /// Full superstore configuration
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SuperstoreConfig {
@@ -334,6 +611,22 @@ pub struct SuperstoreConfig {
     pub sales_quantity_correlation: f64,
     pub sales_profit_correlation: f64,
     pub discount_profit_correlation: f64,
+    /// Degrees of freedom (> 0) of a Student-t copula over sales, quantity,
+    /// discount, and profit, so their extremes occur together; `None` keeps
+    /// the Gaussian copula
+    pub copula_df: Option<f64>,
+    /// Replace correlations that don't form a valid correlation matrix with
+    /// the nearest valid one (see [`superstore_correlation_repair`]); when
+    /// off, such correlations are dropped and the columns drawn independently.
+    /// Ignored by `stable_v1`
+    pub repair_correlations: bool,
+    /// Whether `sales` is revenue (`item_price * quantity`) or the legacy
+    /// synthetic value. Ignored by `stable_v1`
+    pub sales_mode: SalesMode,
+    /// Clamp each row's profit margin, `profit / sales`, to `(min, max)`
+    /// (e.g. `(-0.2, 0.6)`); `None` leaves profit unbounded. Ignored by
+    /// `stable_v1`
+    pub margin_bounds: Option<(f64, f64)>,
     pub seasonality: SeasonalityConfig,
     pub promotions: PromotionalConfig,
     pub customers: CustomerConfig,
@@ -343,6 +636,338 @@ pub struct SuperstoreConfig {
     pub regional: RegionalConfig,
     pub inventory: InventoryConfig,
     pub payment: PaymentConfig,
+    /// Append return rows for a fraction of orders. Ignored by `stable_v1`
+    pub returns: ReturnsConfig,
+    /// Only emit structurally valid SSNs and EINs
+    pub valid_identifiers: bool,
+    /// Use the frozen `stable_v1` generation path (see [`STABLE_V1_REFERENCE_DATE`])
+    pub stable_v1: bool,
+    /// Seed each row from `derive_seed(seed, row)` so any row can be
+    /// generated on its own with [`superstore_row_at`]
+    pub per_row_seeding: bool,
+    /// Categories and their sub-categories, used instead of the US sectors
+    /// and industries when set. Regional preferences weight these names.
+    /// Each category needs at least one sub-category (see
+    /// [`SuperstoreConfig::validate`]). Ignored by `stable_v1`
+    pub categories: Option<Vec<(String, Vec<String>)>>,
+    /// Three-level taxonomy of categories, their sub-categories, and each
+    /// sub-category's product types. When set, it replaces `categories` (the
+    /// two can't both be set) and rows also get a `product_type`, so every
+    /// row's (category, sub-category, product type) is a path in the tree.
+    /// Ignored by `stable_v1`
+    pub taxonomy: Option<Taxonomy>,
+    /// First order date (`YYYY-MM-DD`, inclusive). With neither bound set,
+    /// orders fall in the current year and ship by today; otherwise a missing
+    /// bound defaults to the start or end of the other bound's year, and
+    /// orders ship by `order_date_end`. Ignored by `stable_v1`
+    pub order_date_start: Option<String>,
+    /// Last order date (`YYYY-MM-DD`, inclusive)
+    pub order_date_end: Option<String>,
+    /// Locale for the `country`, `city`, `state`, and `postal_code` columns
+    /// ("en_US", "en_GB", "de_DE", "fr_FR", or "ja_JP"; see
+    /// [`SuperstoreConfig::locale_supported`]). Unsupported locales fall back
+    /// to en_US. Ignored by `stable_v1`
+    pub locale: String,
+    /// Currency of the money columns ("USD", "EUR", "GBP", or "JPY"),
+    /// converted from USD at fixed rates; `None` uses the locale's currency.
+    /// Ignored by `stable_v1`
+    pub currency: Option<String>,
+}
+
+/// Error for a superstore configuration that can't be generated from
+#[derive(Debug, Clone, PartialEq)]
+pub enum SuperstoreConfigError {
+    /// `categories` is set but empty
+    NoCategories,
+    /// A category in `categories` or `taxonomy` has no sub-categories
+    EmptySubCategories(String),
+    /// A sub-category in `taxonomy` has no product types
+    EmptyProductTypes {
+        category: String,
+        sub_category: String,
+    },
+    /// Both `categories` and `taxonomy` are set
+    CategoriesAndTaxonomy,
+    /// An order date bound isn't a `YYYY-MM-DD` date
+    InvalidOrderDate(String),
+    /// `order_date_start` is after `order_date_end`
+    InvalidOrderDateRange { start: NaiveDate, end: NaiveDate },
+    /// A rate that must lie in [0, 1] doesn't
+    InvalidRate { field: &'static str, value: f64 },
+    /// `currency` isn't a supported currency code
+    UnknownCurrency(String),
+    /// A `min_*` bound is above its `max_*` bound
+    InvalidBounds {
+        field: &'static str,
+        min: i32,
+        max: i32,
+    },
+    /// `max_discount_percent` is outside (0, 100]
+    InvalidMaxDiscount(f64),
+    /// `promotions.reference_price` isn't positive
+    InvalidReferencePrice(f64),
+    /// `margin_bounds` isn't a finite `(min, max)` with `min <= max`
+    InvalidMarginBounds { min: f64, max: f64 },
+    /// `pool_size` is zero, leaving rows nothing to draw from
+    EmptyPool,
+    /// `copula_df` isn't a finite positive number
+    InvalidCopulaDf(f64),
+}
+
+impl std::fmt::Display for SuperstoreConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuperstoreConfigError::NoCategories => write!(f, "categories must not be empty"),
+            SuperstoreConfigError::EmptySubCategories(category) => {
+                write!(f, "category '{}' has no sub-categories", category)
+            }
+            SuperstoreConfigError::EmptyProductTypes {
+                category,
+                sub_category,
+            } => write!(
+                f,
+                "sub-category '{}' of '{}' has no product types",
+                sub_category, category
+            ),
+            SuperstoreConfigError::CategoriesAndTaxonomy => {
+                write!(f, "categories and taxonomy can't both be set")
+            }
+            SuperstoreConfigError::InvalidOrderDate(date) => {
+                write!(f, "Invalid order date '{}' (expected YYYY-MM-DD)", date)
+            }
+            SuperstoreConfigError::InvalidOrderDateRange { start, end } => write!(
+                f,
+                "order_date_start {} is after order_date_end {}",
+                start, end
+            ),
+            SuperstoreConfigError::InvalidRate { field, value } => {
+                write!(f, "{} must be between 0 and 1, got {}", field, value)
+            }
+            SuperstoreConfigError::UnknownCurrency(currency) => {
+                let supported: Vec<&str> = CURRENCY_USD_RATES.iter().map(|(c, _)| *c).collect();
+                write!(
+                    f,
+                    "Unknown currency '{}'. Expected one of: {}",
+                    currency,
+                    supported.join(", ")
+                )
+            }
+            SuperstoreConfigError::InvalidBounds { field, min, max } => write!(
+                f,
+                "min_{0} must not exceed max_{0}, got {1} > {2}",
+                field, min, max
+            ),
+            SuperstoreConfigError::InvalidMaxDiscount(value) => {
+                write!(f, "max_discount_percent must be in (0, 100], got {}", value)
+            }
+            SuperstoreConfigError::InvalidReferencePrice(value) => {
+                write!(
+                    f,
+                    "promotions.reference_price must be positive, got {}",
+                    value
+                )
+            }
+            SuperstoreConfigError::InvalidMarginBounds { min, max } => write!(
+                f,
+                "margin_bounds must be finite with min <= max, got ({}, {})",
+                min, max
+            ),
+            SuperstoreConfigError::EmptyPool => write!(f, "pool_size must be at least 1"),
+            SuperstoreConfigError::InvalidCopulaDf(df) => {
+                write!(f, "copula_df must be finite and positive, got {}", df)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SuperstoreConfigError {}
+
+impl SuperstoreConfig {
+    /// Check the parts of the config that generation can't recover from
+    pub fn validate(&self) -> Result<(), SuperstoreConfigError> {
+        if let Some(categories) = &self.categories {
+            if categories.is_empty() {
+                return Err(SuperstoreConfigError::NoCategories);
+            }
+            if let Some((category, _)) = categories.iter().find(|(_, subs)| subs.is_empty()) {
+                return Err(SuperstoreConfigError::EmptySubCategories(category.clone()));
+            }
+        }
+        if let Some(taxonomy) = &self.taxonomy {
+            if self.categories.is_some() {
+                return Err(SuperstoreConfigError::CategoriesAndTaxonomy);
+            }
+            if taxonomy.is_empty() {
+                return Err(SuperstoreConfigError::NoCategories);
+            }
+            for (category, subs) in taxonomy {
+                if subs.is_empty() {
+                    return Err(SuperstoreConfigError::EmptySubCategories(category.clone()));
+                }
+                if let Some((sub_category, _)) = subs.iter().find(|(_, types)| types.is_empty()) {
+                    return Err(SuperstoreConfigError::EmptyProductTypes {
+                        category: category.clone(),
+                        sub_category: sub_category.clone(),
+                    });
+                }
+            }
+        }
+        for (field, min, max) in [
+            ("sales", self.min_sales, self.max_sales),
+            ("quantity", self.min_quantity, self.max_quantity),
+        ] {
+            if min > max {
+                return Err(SuperstoreConfigError::InvalidBounds { field, min, max });
+            }
+        }
+        if !(self.max_discount_percent > 0.0 && self.max_discount_percent <= 100.0) {
+            return Err(SuperstoreConfigError::InvalidMaxDiscount(
+                self.max_discount_percent,
+            ));
+        }
+        if let Some(price) = self.promotions.reference_price {
+            if !(price.is_finite() && price > 0.0) {
+                return Err(SuperstoreConfigError::InvalidReferencePrice(price));
+            }
+        }
+        if let Some((min, max)) = self.margin_bounds {
+            if !(min.is_finite() && max.is_finite() && min <= max) {
+                return Err(SuperstoreConfigError::InvalidMarginBounds { min, max });
+            }
+        }
+        if self.pool_size == 0 {
+            return Err(SuperstoreConfigError::EmptyPool);
+        }
+        if let Some(df) = self.copula_df {
+            if !(df.is_finite() && df > 0.0) {
+                return Err(SuperstoreConfigError::InvalidCopulaDf(df));
+            }
+        }
+        self.order_date_range()?;
+        for (field, value) in [
+            ("returns.return_rate", self.returns.return_rate),
+            (
+                "returns.restocking_fee_rate",
+                self.returns.restocking_fee_rate,
+            ),
+            (
+                "payment.fraud_chargeback_rate",
+                self.payment.fraud_chargeback_rate,
+            ),
+            (
+                "payment.dispute_chargeback_rate",
+                self.payment.dispute_chargeback_rate,
+            ),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(SuperstoreConfigError::InvalidRate { field, value });
+            }
+        }
+        if let Some(currency) = &self.currency {
+            if currency_usd_rate(currency).is_none() {
+                return Err(SuperstoreConfigError::UnknownCurrency(currency.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Settings that generate fine but probably not as intended, one message
+    /// each. Unlike [`SuperstoreConfig::validate`] these never stop
+    /// generation.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.pool_size.saturating_mul(MAX_ROWS_PER_POOLED_VALUE) < self.count {
+            warnings.push(format!(
+                "pool_size {} is small relative to count {}: rows draw from only {} \
+                 distinct locations, so location columns have low cardinality",
+                self.pool_size, self.count, self.pool_size
+            ));
+        }
+        if self.customers.enable_cohorts
+            && self.customers.vip_order_multiplier > HIGH_VIP_ORDER_MULTIPLIER
+        {
+            warnings.push(format!(
+                "customers.vip_order_multiplier {} is high: VIP orders will dwarf \
+                 everyone else's quantities",
+                self.customers.vip_order_multiplier
+            ));
+        }
+        let seasonality = &self.seasonality;
+        if seasonality.enable
+            && [
+                seasonality.q4_multiplier,
+                seasonality.summer_multiplier,
+                seasonality.back_to_school_multiplier,
+            ]
+            .iter()
+            .all(|&m| m == 1.0)
+        {
+            warnings.push(
+                "seasonality is enabled but every multiplier is 1.0, so it has no effect"
+                    .to_string(),
+            );
+        }
+        warnings.extend(self.correlation_warning());
+        warnings.extend(self.locale_warning());
+        warnings
+    }
+
+    /// Message for sales correlations that don't form a valid correlation
+    /// matrix, saying whether they are repaired or dropped
+    pub fn correlation_warning(&self) -> Option<String> {
+        let distance = superstore_correlation_repair(self)?;
+        Some(if self.repair_correlations {
+            format!(
+                "sales correlations do not form a valid correlation matrix; using the nearest \
+                 valid one (Frobenius distance {:.4})",
+                distance
+            )
+        } else {
+            "sales correlations do not form a valid correlation matrix; sales, quantity, \
+             discount, and profit are sampled independently (set repair_correlations=True to \
+             use the nearest valid matrix)"
+                .to_string()
+        })
+    }
+
+    /// Message for a `locale` without location data of its own, which falls
+    /// back to en_US
+    pub fn locale_warning(&self) -> Option<String> {
+        unsupported_locale_warning(&self.locale)
+    }
+
+    /// Whether `locale` has its own location data rather than falling back
+    /// to en_US
+    pub fn locale_supported(&self) -> bool {
+        superstore_locale(&self.locale).is_some()
+    }
+
+    /// Inclusive order date range, or `None` to use the current year
+    pub fn order_date_range(
+        &self,
+    ) -> Result<Option<(NaiveDate, NaiveDate)>, SuperstoreConfigError> {
+        let parse = |date: &Option<String>| {
+            date.as_deref()
+                .map(|d| {
+                    NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                        .map_err(|_| SuperstoreConfigError::InvalidOrderDate(d.to_string()))
+                })
+                .transpose()
+        };
+        let (start, end) = match (parse(&self.order_date_start)?, parse(&self.order_date_end)?) {
+            (None, None) => return Ok(None),
+            (Some(start), Some(end)) => (start, end),
+            (Some(start), None) => (
+                start,
+                NaiveDate::from_ymd_opt(start.year(), 12, 31).unwrap(),
+            ),
+            (None, Some(end)) => (NaiveDate::from_ymd_opt(end.year(), 1, 1).unwrap(), end),
+        };
+        if start > end {
+            return Err(SuperstoreConfigError::InvalidOrderDateRange { start, end });
+        }
+        Ok(Some((start, end)))
+    }
 }
 
 impl Default for SuperstoreConfig {
@@ -359,20 +984,244 @@ impl Default for SuperstoreConfig {
             sales_quantity_correlation: 0.7,
             sales_profit_correlation: 0.6,
             discount_profit_correlation: -0.4,
+            copula_df: None,
+            repair_correlations: true,
+            sales_mode: SalesMode::Synthetic,
+            margin_bounds: None,
             seasonality: SeasonalityConfig::default(),
             promotions: PromotionalConfig::default(),
             customers: CustomerConfig::default(),
-            regions: vec![
-                "West".to_string(),
-                "East".to_string(),
-                "Central".to_string(),
-                "South".to_string(),
-            ],
+            regions: default_regions(),
             bundling: BundlingConfig::default(),
             regional: RegionalConfig::default(),
             inventory: InventoryConfig::default(),
             payment: PaymentConfig::default(),
+            returns: ReturnsConfig::default(),
+            valid_identifiers: false,
+            stable_v1: false,
+            per_row_seeding: false,
+            categories: None,
+            taxonomy: None,
+            order_date_start: None,
+            order_date_end: None,
+            locale: "en_US".to_string(),
+            currency: None,
+        }
+    }
+}
+
+/// Employee generation configuration
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EmployeeConfig {
+    pub count: usize,
+    pub pool_size: usize,
+    pub seed: Option<u64>,
+    /// Only emit structurally valid SSNs
+    pub valid_identifiers: bool,
+    /// Region labels employees are assigned to (defaults to the superstore regions)
+    pub regions: Vec<String>,
+    /// Departments employees are spread evenly across (must not be empty)
+    pub departments: Vec<Department>,
+    /// Fraction of employees, beyond the first in each department, who are
+    /// managers; everyone else reports to a manager in their department
+    pub manager_ratio: f64,
+    /// Fraction of employees who have left and carry a `termination_date`
+    pub attrition_rate: f64,
+}
+
+impl Default for EmployeeConfig {
+    fn default() -> Self {
+        Self {
+            count: 1000,
+            pool_size: DEFAULT_POOL_SIZE,
+            seed: None,
+            valid_identifiers: false,
+            regions: default_regions(),
+            departments: default_departments(),
+            manager_ratio: 0.1,
+            attrition_rate: 0.1,
+        }
+    }
+}
+
+/// A department employees can belong to
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Department {
+    pub name: String,
+    /// Titles of individual contributors; managers are titled "<name> Manager"
+    pub job_titles: Vec<String>,
+    /// Salary range (USD) holding about 90% of the department's log-normal
+    /// salary draw, before the manager premium
+    pub salary_range: (f64, f64),
+}
+
+/// Departments, job titles and salary ranges used by default
+const DEFAULT_DEPARTMENTS: [(&str, &[&str], (f64, f64)); 7] = [
+    (
+        "Engineering",
+        &[
+            "Software Engineer",
+            "Senior Software Engineer",
+            "Data Engineer",
+            "QA Engineer",
+        ],
+        (90_000.0, 180_000.0),
+    ),
+    (
+        "Sales",
+        &[
+            "Account Executive",
+            "Sales Representative",
+            "Sales Engineer",
+        ],
+        (55_000.0, 130_000.0),
+    ),
+    (
+        "Marketing",
+        &[
+            "Marketing Specialist",
+            "Content Strategist",
+            "Brand Analyst",
+        ],
+        (55_000.0, 120_000.0),
+    ),
+    (
+        "Finance",
+        &["Financial Analyst", "Accountant", "Auditor"],
+        (60_000.0, 140_000.0),
+    ),
+    (
+        "Human Resources",
+        &["HR Generalist", "Recruiter", "Benefits Specialist"],
+        (50_000.0, 110_000.0),
+    ),
+    (
+        "Operations",
+        &["Operations Analyst", "Logistics Coordinator", "Buyer"],
+        (45_000.0, 100_000.0),
+    ),
+    (
+        "Support",
+        &[
+            "Support Specialist",
+            "Customer Service Representative",
+            "Technical Support Engineer",
+        ],
+        (35_000.0, 70_000.0),
+    ),
+];
+
+/// Salary multiplier for managers over their department's distribution
+const MANAGER_SALARY_PREMIUM: f64 = 1.25;
+
+pub fn default_departments() -> Vec<Department> {
+    DEFAULT_DEPARTMENTS
+        .iter()
+        .map(|(name, titles, salary_range)| Department {
+            name: name.to_string(),
+            job_titles: titles.iter().map(|t| t.to_string()).collect(),
+            salary_range: *salary_range,
+        })
+        .collect()
+}
+
+/// Assigns departments, titles, salaries and managers as employee rows are
+/// generated in order. The first employee of each department is a manager;
+/// later ones become managers with probability `manager_ratio` and otherwise
+/// report to an earlier manager of the same department, so every
+/// `manager_id` refers to a row generated before it.
+pub(crate) struct EmployeeOrg {
+    departments: Vec<Department>,
+    salaries: Vec<LogNormal<f64>>,
+    managers: Vec<Vec<String>>,
+    manager_ratio: f64,
+    attrition_rate: f64,
+}
+
+impl EmployeeOrg {
+    pub(crate) fn new(config: &EmployeeConfig) -> Self {
+        assert!(
+            !config.departments.is_empty(),
+            "EmployeeConfig::departments must not be empty"
+        );
+        // 1.645 standard deviations either side of the median covers 90%
+        let salaries = config
+            .departments
+            .iter()
+            .map(|d| {
+                let (low, high) = d.salary_range;
+                let sigma = (high / low).ln() / (2.0 * 1.645);
+                LogNormal::new((low * high).sqrt().ln(), sigma.max(0.0))
+                    .expect("salary range must be positive")
+            })
+            .collect();
+        Self {
+            departments: config.departments.clone(),
+            salaries,
+            managers: vec![Vec::new(); config.departments.len()],
+            manager_ratio: config.manager_ratio,
+            attrition_rate: config.attrition_rate,
+        }
+    }
+
+    /// Draw `(date_of_birth, start_date, termination_date)` for a new row.
+    ///
+    /// Employees are 18 to 70 years old and started within the last 30
+    /// years, no earlier than their 22nd birthday (their 18th for those still
+    /// under 22). A fraction `attrition_rate` of them have left on a date
+    /// after they started.
+    pub(crate) fn employment_dates<R: Rng>(
+        &self,
+        rng: &mut R,
+    ) -> (NaiveDate, NaiveDate, Option<NaiveDate>) {
+        let today = Utc::now().naive_utc().date();
+        let date_of_birth =
+            random_date_in_range(rng, years_before(today, 70), years_before(today, 18));
+
+        let thirty_years_ago = years_before(today, 30);
+        let graduated = years_after(date_of_birth, 22);
+        let earliest_start = if graduated <= today {
+            graduated.max(thirty_years_ago)
+        } else {
+            years_after(date_of_birth, 18)
+        };
+        let start_date = random_date_in_range(rng, earliest_start, today);
+
+        let termination_date = if start_date < today && rng.random::<f64>() < self.attrition_rate {
+            Some(random_date_in_range(
+                rng,
+                start_date + chrono::Duration::days(1),
+                today,
+            ))
+        } else {
+            None
+        };
+        (date_of_birth, start_date, termination_date)
+    }
+
+    /// Fill in the department, job title, salary and manager of `row`
+    pub(crate) fn place<R: Rng>(&mut self, rng: &mut R, row: &mut EmployeeRow) {
+        let idx = rng.random_range(0..self.departments.len());
+        let department = &self.departments[idx];
+        let managers = &mut self.managers[idx];
+        let is_manager = managers.is_empty() || rng.random::<f64>() < self.manager_ratio;
+        let mut salary = self.salaries[idx].sample(rng);
+
+        row.department = department.name.clone();
+        if is_manager {
+            row.job_title = format!("{} Manager", department.name);
+            row.manager_id = None;
+            salary *= MANAGER_SALARY_PREMIUM;
+            managers.push(row.employee_id.clone());
+        } else {
+            row.job_title = department
+                .job_titles
+                .choose(rng)
+                .cloned()
+                .unwrap_or_else(|| department.name.clone());
+            row.manager_id = managers.choose(rng).cloned();
         }
+        row.salary = salary.round();
     }
 }
 
@@ -394,8 +1243,31 @@ fn get_seasonality_multiplier(month: u32, config: &SeasonalityConfig) -> f64 {
     }
 }
 
-/// Apply promotional effects: higher discount -> higher quantity
-fn apply_promotional_effects<R: Rng>(
+/// Apply promotional effects: higher discount -> higher quantity, and
+/// constant-elasticity demand so quantity falls as `item_price` (USD) rises
+/// above the reference price
+fn apply_promotional_effects(
+    base_quantity: i32,
+    discount: f64,
+    item_price: f64,
+    config: &SuperstoreConfig,
+) -> i32 {
+    let promotions = &config.promotions;
+    if !promotions.enable {
+        return base_quantity;
+    }
+
+    let discount_boost = 1.0 + (discount / 100.0) * promotions.discount_quantity_correlation;
+    let reference_price = promotions
+        .reference_price
+        .unwrap_or((config.min_sales + config.max_sales) as f64 / 2.0);
+    let demand = (item_price / reference_price).powf(promotions.price_elasticity);
+
+    ((base_quantity as f64) * discount_boost * demand).round() as i32
+}
+
+/// Promotional effects as of `stable_v1`, where elasticity only adds noise
+fn apply_promotional_effects_v1<R: Rng>(
     rng: &mut R,
     base_quantity: i32,
     discount: f64,
@@ -428,24 +1300,85 @@ fn generate_payment_method<R: Rng>(rng: &mut R) -> PaymentMethod {
     for (i, &weight) in PAYMENT_METHOD_WEIGHTS.iter().enumerate() {
         cumulative += weight;
         if roll < cumulative {
-            return match i {
-                0 => PaymentMethod::CreditCard,
-                1 => PaymentMethod::DebitCard,
-                2 => PaymentMethod::PayPal,
-                3 => PaymentMethod::GiftCard,
-                4 => PaymentMethod::BankTransfer,
-                _ => PaymentMethod::CashOnDelivery,
-            };
+            return PaymentMethod::ALL[i];
         }
     }
     PaymentMethod::CreditCard
 }
 
+/// Processing fee for a transaction: percentage plus fixed fee, floored at the
+/// minimum fee and rounded to cents. Fee-free methods (gift card, cash on
+/// delivery) are never charged.
+pub fn processing_fee(sales: f64, payment_method: &PaymentMethod, config: &PaymentConfig) -> f64 {
+    let rate = payment_method.processing_fee_rate();
+    if rate == 0.0 {
+        return 0.0;
+    }
+    let fee = (sales * rate + config.fixed_fee).max(config.min_fee);
+    (fee * 100.0).round() / 100.0
+}
+
 /// Check if this transaction is fraudulent based on payment method fraud rate
 fn check_fraud<R: Rng>(rng: &mut R, payment_method: &PaymentMethod) -> bool {
     rng.random::<f64>() < payment_method.fraud_rate()
 }
 
+/// Compute the Luhn check digit for a string of digits
+fn luhn_check_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 0 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Check whether a card number passes the Luhn checksum
+pub fn luhn_valid(number: &str) -> bool {
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let (body, check) = number.split_at(number.len() - 1);
+    check.parse::<u32>().ok() == Some(luhn_check_digit(body))
+}
+
+/// Generate a card network and a Luhn-valid PAN in that network's test range.
+/// Debit cards are never Amex.
+fn generate_card<R: Rng>(rng: &mut R, payment_method: &PaymentMethod) -> (CardNetwork, String) {
+    let weights: &[f64] = if *payment_method == PaymentMethod::DebitCard {
+        &CARD_NETWORK_WEIGHTS[..2]
+    } else {
+        &CARD_NETWORK_WEIGHTS
+    };
+    let network = match sample_categorical(rng, weights) {
+        0 => CardNetwork::Visa,
+        1 => CardNetwork::Mastercard,
+        _ => CardNetwork::Amex,
+    };
+
+    let mut pan = network.test_prefixes().choose(rng).unwrap().to_string();
+    while pan.len() < network.pan_length() - 1 {
+        pan.push((b'0' + rng.random_range(0..10)) as char);
+    }
+    let check = luhn_check_digit(&pan);
+    pan.push(char::from_digit(check, 10).unwrap());
+
+    (network, pan)
+}
+
 /// Determine stock status and backorder days
 fn determine_stock_status<R: Rng>(rng: &mut R, config: &InventoryConfig) -> (String, Option<i32>) {
     if !config.enable {
@@ -468,10 +1401,10 @@ fn determine_stock_status<R: Rng>(rng: &mut R, config: &InventoryConfig) -> (Str
 }
 
 /// Apply regional preference to category selection - returns index of selected category
-fn apply_regional_preference<R: Rng>(
+fn apply_regional_preference<R: Rng, S: AsRef<str>>(
     rng: &mut R,
     region: &str,
-    categories: &[&str],
+    categories: &[S],
     config: &RegionalConfig,
 ) -> usize {
     if !config.enable || categories.is_empty() {
@@ -485,11 +1418,11 @@ fn apply_regional_preference<R: Rng>(
         // Build weighted list
         let mut weights: Vec<f64> = Vec::with_capacity(categories.len());
 
-        for &cat in categories {
+        for cat in categories {
             let weight = pref
                 .category_weights
                 .iter()
-                .find(|(c, _)| c == cat)
+                .find(|(c, _)| c == cat.as_ref())
                 .map(|(_, w)| *w)
                 .unwrap_or(1.0);
             weights.push(weight);
@@ -657,15 +1590,63 @@ fn apply_item_status_volume_effect<R: Rng>(
     }
 }
 
-struct LocationPool {
-    cities: Vec<String>,
-    states: Vec<String>,
-    zip_codes: Vec<String>,
+/// Postal code layout for a locale
+enum PostalFormat {
+    /// Five digits in `[min, max)`, e.g. US ZIP codes and German PLZ
+    Digits(u32, u32),
+    /// UK outward and inward code, e.g. "M4 7QD"
+    Uk,
+    /// Japanese seven-digit code, e.g. "150-0041"
+    Japan,
 }
 
-impl LocationPool {
-    fn generate<R: Rng>(rng: &mut R, pool_size: usize) -> Self {
-        const CITIES: [&str; 12] = [
+impl PostalFormat {
+    fn generate<R: Rng>(&self, rng: &mut R) -> String {
+        const UK_AREAS: [&str; 12] = [
+            "SW", "NW", "E", "M", "B", "LS", "G", "BS", "L", "EH", "CF", "BT",
+        ];
+        const UK_UNIT_LETTERS: &[u8] = b"ABDEFGHJLNPQRSTUWXYZ";
+        match self {
+            PostalFormat::Digits(min, max) => format!("{:05}", rng.random_range(*min..*max)),
+            PostalFormat::Uk => {
+                let mut unit =
+                    || UK_UNIT_LETTERS[rng.random_range(0..UK_UNIT_LETTERS.len())] as char;
+                let (a, b) = (unit(), unit());
+                format!(
+                    "{}{} {}{}{}",
+                    UK_AREAS[rng.random_range(0..UK_AREAS.len())],
+                    rng.random_range(1..10),
+                    rng.random_range(0..10),
+                    a,
+                    b
+                )
+            }
+            PostalFormat::Japan => format!(
+                "{:03}-{:04}",
+                rng.random_range(100..1000),
+                rng.random_range(0..10000)
+            ),
+        }
+    }
+}
+
+/// Location data and currency for a supported superstore locale
+struct SuperstoreLocale {
+    code: &'static str,
+    /// ISO 3166 country code for the `country` column
+    country: &'static str,
+    currency: &'static str,
+    cities: &'static [&'static str],
+    states: &'static [&'static str],
+    postal_format: PostalFormat,
+}
+
+const SUPERSTORE_LOCALES: &[SuperstoreLocale] = &[
+    SuperstoreLocale {
+        code: "en_US",
+        country: "US",
+        currency: "USD",
+        cities: &[
             "Springfield",
             "Franklin",
             "Riverton",
@@ -678,47 +1659,221 @@ impl LocationPool {
             "Ashland",
             "Burlington",
             "Milton",
-        ];
-        const STATES: [&str; 10] = ["CA", "NY", "TX", "WA", "FL", "IL", "GA", "AZ", "CO", "NC"];
+        ],
+        states: &["CA", "NY", "TX", "WA", "FL", "IL", "GA", "AZ", "CO", "NC"],
+        postal_format: PostalFormat::Digits(10000, 100000),
+    },
+    SuperstoreLocale {
+        code: "en_GB",
+        country: "GB",
+        currency: "GBP",
+        cities: &[
+            "London",
+            "Manchester",
+            "Birmingham",
+            "Leeds",
+            "Glasgow",
+            "Bristol",
+            "Liverpool",
+            "Edinburgh",
+            "Cardiff",
+            "Belfast",
+            "Sheffield",
+            "Nottingham",
+        ],
+        states: &["England", "Scotland", "Wales", "Northern Ireland"],
+        postal_format: PostalFormat::Uk,
+    },
+    SuperstoreLocale {
+        code: "de_DE",
+        country: "DE",
+        currency: "EUR",
+        cities: &[
+            "Berlin",
+            "Hamburg",
+            "München",
+            "Köln",
+            "Frankfurt am Main",
+            "Stuttgart",
+            "Düsseldorf",
+            "Leipzig",
+            "Dortmund",
+            "Essen",
+            "Bremen",
+            "Dresden",
+        ],
+        states: &[
+            "BW", "BY", "BE", "BB", "HB", "HH", "HE", "NI", "NW", "RP", "SN", "TH",
+        ],
+        postal_format: PostalFormat::Digits(1001, 99999),
+    },
+    SuperstoreLocale {
+        code: "fr_FR",
+        country: "FR",
+        currency: "EUR",
+        cities: &[
+            "Paris",
+            "Marseille",
+            "Lyon",
+            "Toulouse",
+            "Nice",
+            "Nantes",
+            "Strasbourg",
+            "Montpellier",
+            "Bordeaux",
+            "Lille",
+            "Rennes",
+            "Reims",
+        ],
+        states: &[
+            "Île-de-France",
+            "Provence-Alpes-Côte d'Azur",
+            "Auvergne-Rhône-Alpes",
+            "Occitanie",
+            "Pays de la Loire",
+            "Grand Est",
+            "Nouvelle-Aquitaine",
+            "Hauts-de-France",
+            "Bretagne",
+            "Normandie",
+        ],
+        postal_format: PostalFormat::Digits(1000, 96000),
+    },
+    SuperstoreLocale {
+        code: "ja_JP",
+        country: "JP",
+        currency: "JPY",
+        cities: &[
+            "Tokyo",
+            "Yokohama",
+            "Osaka",
+            "Nagoya",
+            "Sapporo",
+            "Fukuoka",
+            "Kobe",
+            "Kyoto",
+            "Kawasaki",
+            "Saitama",
+            "Hiroshima",
+            "Sendai",
+        ],
+        states: &[
+            "Tokyo",
+            "Kanagawa",
+            "Osaka",
+            "Aichi",
+            "Hokkaido",
+            "Fukuoka",
+            "Hyogo",
+            "Kyoto",
+            "Saitama",
+            "Hiroshima",
+            "Miyagi",
+        ],
+        postal_format: PostalFormat::Japan,
+    },
+];
 
-        let cities: Vec<String> = (0..pool_size)
-            .map(|_| CITIES[rng.random_range(0..CITIES.len())].to_string())
-            .collect();
-        let states: Vec<String> = (0..pool_size)
-            .map(|_| STATES[rng.random_range(0..STATES.len())].to_string())
-            .collect();
-        let zip_codes: Vec<String> = (0..pool_size)
-            .map(|_| format!("{:05}", rng.random_range(10000..100000)))
-            .collect();
-        Self {
-            cities,
-            states,
-            zip_codes,
-        }
-    }
+/// Look up a superstore locale by code ("de_DE" or "de-DE")
+fn superstore_locale(code: &str) -> Option<&'static SuperstoreLocale> {
+    let code = code.replace('-', "_");
+    SUPERSTORE_LOCALES
+        .iter()
+        .find(|l| l.code.eq_ignore_ascii_case(&code))
+}
 
-    fn random_city<R: Rng>(&self, rng: &mut R) -> &str {
-        self.cities.choose(rng).unwrap()
-    }
+/// Supported currencies and their units per USD. Rates are fixed so seeded
+/// output doesn't depend on the market
+const CURRENCY_USD_RATES: [(&str, f64); 4] =
+    [("USD", 1.0), ("EUR", 0.92), ("GBP", 0.79), ("JPY", 150.0)];
 
-    fn random_state<R: Rng>(&self, rng: &mut R) -> &str {
-        self.states.choose(rng).unwrap()
-    }
+fn currency_usd_rate(currency: &str) -> Option<f64> {
+    CURRENCY_USD_RATES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(currency))
+        .map(|(_, rate)| *rate)
+}
 
-    fn random_zip<R: Rng>(&self, rng: &mut R) -> &str {
-        self.zip_codes.choose(rng).unwrap()
+/// Decimal digits of a currency's minor unit (yen have none)
+pub(crate) fn currency_minor_units(currency: &str) -> i32 {
+    if currency.eq_ignore_ascii_case("JPY") {
+        0
+    } else {
+        2
     }
 }
 
-struct NamePool {
-    first_names: Vec<String>,
-    last_names: Vec<String>,
-    emails: Vec<String>,
-    phone_numbers: Vec<String>,
+/// Currency of a supported locale and its units per USD, shared by every
+/// generator that prices in local currency
+pub(crate) fn locale_currency(code: &str) -> Option<(&'static str, f64)> {
+    let currency = superstore_locale(code)?.currency;
+    Some((currency, currency_usd_rate(currency)?))
+}
+
+/// Message for a locale without data of its own, which falls back to en_US
+pub(crate) fn unsupported_locale_warning(code: &str) -> Option<String> {
+    if superstore_locale(code).is_some() {
+        return None;
+    }
+    let supported: Vec<&str> = SUPERSTORE_LOCALES.iter().map(|l| l.code).collect();
+    Some(format!(
+        "unsupported locale '{}'; falling back to en_US (supported: {})",
+        code,
+        supported.join(", ")
+    ))
+}
+
+pub(crate) struct LocationPool {
+    cities: Vec<String>,
+    states: Vec<String>,
+    zip_codes: Vec<String>,
+}
+
+impl LocationPool {
+    /// US locations
+    pub(crate) fn generate<R: Rng>(rng: &mut R, pool_size: usize) -> Self {
+        Self::for_locale(rng, pool_size, &SUPERSTORE_LOCALES[0])
+    }
+
+    fn for_locale<R: Rng>(rng: &mut R, pool_size: usize, locale: &SuperstoreLocale) -> Self {
+        let cities: Vec<String> = (0..pool_size)
+            .map(|_| locale.cities[rng.random_range(0..locale.cities.len())].to_string())
+            .collect();
+        let states: Vec<String> = (0..pool_size)
+            .map(|_| locale.states[rng.random_range(0..locale.states.len())].to_string())
+            .collect();
+        let zip_codes: Vec<String> = (0..pool_size)
+            .map(|_| locale.postal_format.generate(rng))
+            .collect();
+        Self {
+            cities,
+            states,
+            zip_codes,
+        }
+    }
+
+    pub(crate) fn random_city<R: Rng>(&self, rng: &mut R) -> &str {
+        self.cities.choose(rng).unwrap()
+    }
+
+    pub(crate) fn random_state<R: Rng>(&self, rng: &mut R) -> &str {
+        self.states.choose(rng).unwrap()
+    }
+
+    pub(crate) fn random_zip<R: Rng>(&self, rng: &mut R) -> &str {
+        self.zip_codes.choose(rng).unwrap()
+    }
+}
+
+pub(crate) struct NamePool {
+    pub(crate) first_names: Vec<String>,
+    pub(crate) last_names: Vec<String>,
+    pub(crate) emails: Vec<String>,
+    pub(crate) phone_numbers: Vec<String>,
 }
 
 impl NamePool {
-    fn generate<R: Rng>(rng: &mut R, pool_size: usize) -> Self {
+    pub(crate) fn generate<R: Rng>(rng: &mut R, pool_size: usize) -> Self {
         const FIRST_NAMES: [&str; 16] = [
             "Alex", "Jordan", "Taylor", "Casey", "Morgan", "Riley", "Avery", "Parker", "Quinn",
             "Jamie", "Drew", "Reese", "Skyler", "Hayden", "Cameron", "Rowan",
@@ -770,6 +1925,28 @@ fn generate_ein<R: Rng>(rng: &mut R) -> String {
     )
 }
 
+/// Generate an EIN with an IRS-assigned prefix
+fn generate_valid_ein<R: Rng>(rng: &mut R) -> String {
+    format!(
+        "{:02}-{:07}",
+        VALID_EIN_PREFIXES.choose(rng).unwrap(),
+        rng.random_range(1000000..9999999)
+    )
+}
+
+/// Check an EIN (`XX-XXXXXXX`) for a valid format and IRS-assigned prefix
+pub fn is_valid_ein(ein: &str) -> bool {
+    let Some((prefix, serial)) = ein.split_once('-') else {
+        return false;
+    };
+    prefix.len() == 2
+        && serial.len() == 7
+        && serial.chars().all(|c| c.is_ascii_digit())
+        && prefix
+            .parse::<u32>()
+            .is_ok_and(|p| VALID_EIN_PREFIXES.contains(&p))
+}
+
 fn generate_license_plate<R: Rng>(rng: &mut R) -> String {
     let letters: String = (0..3)
         .map(|_| (b'A' + rng.random_range(0..26)) as char)
@@ -793,6 +1970,37 @@ fn generate_ssn<R: Rng>(rng: &mut R) -> String {
     )
 }
 
+/// Generate an SSN that passes structural validation: area not 000, 666
+/// or 900-999, group not 00, serial not 0000
+fn generate_valid_ssn<R: Rng>(rng: &mut R) -> String {
+    let mut area = rng.random_range(1..899);
+    if area >= 666 {
+        area += 1;
+    }
+    format!(
+        "{:03}-{:02}-{:04}",
+        area,
+        rng.random_range(1..100),
+        rng.random_range(1..10000)
+    )
+}
+
+/// Check an SSN (`AAA-GG-SSSS`) against the structural rules: area not 000,
+/// 666 or 900-999, group not 00, serial not 0000
+pub fn is_valid_ssn(ssn: &str) -> bool {
+    let parts: Vec<&str> = ssn.split('-').collect();
+    if parts.len() != 3
+        || [3, 2, 4] != [parts[0].len(), parts[1].len(), parts[2].len()]
+        || !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit()))
+    {
+        return false;
+    }
+    let area: u32 = parts[0].parse().unwrap_or(0);
+    let group: u32 = parts[1].parse().unwrap_or(0);
+    let serial: u32 = parts[2].parse().unwrap_or(0);
+    area != 0 && area != 666 && area < 900 && group != 0 && serial != 0
+}
+
 fn generate_street_address<R: Rng>(rng: &mut R) -> String {
     let number: u32 = rng.random_range(1..9999);
     let street_names = [
@@ -811,18 +2019,29 @@ fn generate_street_address<R: Rng>(rng: &mut R) -> String {
 }
 
 fn random_date_this_year<R: Rng>(rng: &mut R) -> NaiveDate {
-    let year = Utc::now().naive_utc().date().year();
+    random_date_in_year(rng, Utc::now().naive_utc().date().year())
+}
+
+fn random_date_in_year<R: Rng>(rng: &mut R, year: i32) -> NaiveDate {
     let day_of_year = rng.random_range(1..=365);
     NaiveDate::from_yo_opt(year, day_of_year)
         .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, 1, 1).unwrap())
 }
 
+fn random_date_in_range<R: Rng>(rng: &mut R, start: NaiveDate, end: NaiveDate) -> NaiveDate {
+    let days = (end - start).num_days() as u32;
+    start + chrono::Duration::days(rng.random_range(0..=days) as i64)
+}
+
 fn random_date_between<R: Rng>(rng: &mut R, start: NaiveDate) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    if start >= today {
-        return today;
+    random_date_until(rng, start, Utc::now().naive_utc().date())
+}
+
+fn random_date_until<R: Rng>(rng: &mut R, start: NaiveDate, end: NaiveDate) -> NaiveDate {
+    if start >= end {
+        return end;
     }
-    let days_between = (today - start).num_days() as u32;
+    let days_between = (end - start).num_days() as u32;
     if days_between == 0 {
         return start;
     }
@@ -830,30 +2049,124 @@ fn random_date_between<R: Rng>(rng: &mut R, start: NaiveDate) -> NaiveDate {
     start + chrono::Duration::days(random_days as i64)
 }
 
-fn random_date_30_years<R: Rng>(rng: &mut R) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    let thirty_years_ago = today - chrono::Duration::days(30 * 365);
-    let days_range = (today - thirty_years_ago).num_days() as u32;
-    let random_days = rng.random_range(0..=days_range);
-    thirty_years_ago + chrono::Duration::days(random_days as i64)
+/// `date` moved back `years` calendar years (Feb 29 becomes Feb 28)
+fn years_before(date: NaiveDate, years: u32) -> NaiveDate {
+    date.checked_sub_months(Months::new(years * 12)).unwrap()
 }
 
-fn random_date_of_birth<R: Rng>(rng: &mut R) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    let min_age = 18;
-    let max_age = 70;
-    let min_date = today - chrono::Duration::days(max_age * 365);
-    let max_date = today - chrono::Duration::days(min_age * 365);
-    let days_range = (max_date - min_date).num_days() as u32;
-    let random_days = rng.random_range(0..=days_range);
-    min_date + chrono::Duration::days(random_days as i64)
+/// `date` moved forward `years` calendar years (Feb 29 becomes Feb 28)
+fn years_after(date: NaiveDate, years: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(years * 12)).unwrap()
 }
 
 /// Create an RNG from an optional seed
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
+}
+
+/// `derive_seed` index reserved for the location and name pools
+const POOL_SEED_INDEX: u64 = u64::MAX;
+
+/// RNG for the pre-generated location and name pools.
+///
+/// Derived from the seed but separate from the row stream, so changing
+/// `pool_size` changes only the pooled values, not the rest of each row.
+pub(crate) fn pool_rng(seed: Option<u64>) -> SeededRng {
+    create_rng(seed.map(|s| derive_seed(s, POOL_SEED_INDEX)))
+}
+
+/// `derive_seed` index reserved for return rows, so enabling returns leaves
+/// the sale rows unchanged
+const RETURNS_SEED_INDEX: u64 = u64::MAX - 1;
+
+/// `derive_seed` index reserved for chargeback events
+const CHARGEBACK_SEED_INDEX: u64 = u64::MAX - 2;
+
+/// `derive_seed` index reserved for the copula uniforms of sequential rows
+const UNIFORMS_SEED_INDEX: u64 = u64::MAX - 3;
+
+/// Rows per block of copula uniforms in sequential mode. Each block has its
+/// own RNG, so a stream can start at any block without sampling the earlier ones.
+const UNIFORM_BLOCK_ROWS: usize = 4096;
+
+/// Copula that drew the correlated sales, quantity, discount, and profit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CopulaKind {
+    Gaussian,
+    StudentT,
+}
+
+impl CopulaKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopulaKind::Gaussian => "gaussian",
+            CopulaKind::StudentT => "student_t",
+        }
+    }
+}
+
+/// What generation decided for a superstore dataset, for debugging configs
+/// that produce odd data (see [`superstore_with_diagnostics`])
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SuperstoreDiagnostics {
+    /// Copula behind the correlated columns; `None` when the correlations
+    /// were invalid and the columns were drawn independently
+    pub copula: Option<CopulaKind>,
+    /// Frobenius distance from the configured correlations to the nearest
+    /// valid matrix used in their place; `None` when no repair happened
+    pub correlation_repair_distance: Option<f64>,
+    /// Sale rows whose quantity was clamped to `min_quantity..=max_quantity`
+    /// (before the VIP multiplier); `None` for `stable_v1`, which isn't
+    /// instrumented
+    pub clamped_quantities: Option<usize>,
+    /// Sale rows whose profit was clamped to `margin_bounds`
+    pub clamped_margins: usize,
+    /// Sale rows per customer segment, including segments with none
+    pub segment_counts: BTreeMap<String, usize>,
+    /// Segments no sale row landed in
+    pub empty_segments: Vec<String>,
+}
+
+impl SuperstoreDiagnostics {
+    fn new(
+        config: &SuperstoreConfig,
+        rows: &[SuperstoreRow],
+        clamped_quantities: Option<usize>,
+        clamped_margins: usize,
+    ) -> Self {
+        let (copula, correlation_repair_distance) = if config.stable_v1 {
+            let valid = GaussianCopula::new(sales_correlation_matrix(config)).is_ok();
+            (valid.then_some(CopulaKind::Gaussian), None)
+        } else {
+            let copula = superstore_copula(config).map(|copula| match copula {
+                SalesCopula::Gaussian(_) => CopulaKind::Gaussian,
+                SalesCopula::StudentT(_) => CopulaKind::StudentT,
+            });
+            let repair = superstore_correlation_repair(config)
+                .filter(|_| config.repair_correlations && copula.is_some());
+            (copula, repair)
+        };
+
+        let mut segment_counts: BTreeMap<String, usize> =
+            SEGMENTS.iter().map(|s| (s.to_string(), 0)).collect();
+        for row in rows.iter().filter(|r| r.original_order_id.is_none()) {
+            *segment_counts.entry(row.segment.to_string()).or_default() += 1;
+        }
+        let empty_segments = segment_counts
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(segment, _)| segment.clone())
+            .collect();
+
+        Self {
+            copula,
+            correlation_repair_distance,
+            clamped_quantities,
+            clamped_margins,
+            segment_counts,
+            empty_segments,
+        }
     }
 }
 
@@ -863,19 +2176,33 @@ pub struct SuperstoreRow {
     pub order_id: String,
     pub order_date: String,
     pub ship_date: String,
-    pub ship_mode: String,
+    // Low-cardinality values are shared between rows rather than allocated
+    // per row
+    pub ship_mode: Arc<str>,
     pub customer_id: String,
-    pub segment: String,
-    pub country: String,
+    pub segment: Arc<str>,
+    pub country: Arc<str>,
     pub city: String,
     pub state: String,
     pub postal_code: String,
-    pub region: String,
+    pub region: Arc<str>,
     pub product_id: String,
-    pub category: String,
+    pub category: Arc<str>,
     pub sub_category: String,
+    /// Third taxonomy level, set only when `SuperstoreConfig::taxonomy` is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<Arc<str>>,
     pub item_status: String,
+    /// Currency of `item_price`, `sales`, `profit`, and `processing_fee`;
+    /// `None` (USD) for `stable_v1` rows
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Arc<str>>,
     pub item_price: f64,
+    /// List price before `discount`, so `item_price` is
+    /// `msrp * (1 - discount / 100)` to the cent; `None` for `stable_v1` and
+    /// parallel rows
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub msrp: Option<f64>,
     pub sales: i32,
     pub quantity: i32,
     pub discount: f64,
@@ -885,8 +2212,51 @@ pub struct SuperstoreRow {
     pub payment_method: Option<String>,
     pub is_fraud: Option<bool>,
     pub processing_fee: Option<f64>,
+    pub card_number: Option<String>,
+    pub card_network: Option<String>,
+    pub settlement_date: Option<String>,
     pub backorder_days: Option<i32>,
     pub stock_status: Option<String>,
+    // Returns: set only on return rows, and left out of serialized sale rows
+    // so serialized output without returns is unchanged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_order_id: Option<String>,
+}
+
+/// Optional superstore column groups, emitted only when the feature that fills
+/// them is enabled so the default schema stays unchanged
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptionalColumns {
+    pub bundling: bool,
+    pub inventory: bool,
+    pub payment: bool,
+    pub cards: bool,
+    pub returns: bool,
+    pub product_type: bool,
+    pub currency: bool,
+}
+
+impl OptionalColumns {
+    /// Column groups filled by rows generated from `config`
+    pub fn for_config(config: &SuperstoreConfig) -> Self {
+        // The stable_v1 path never fills the optional fields
+        if config.stable_v1 {
+            return Self::default();
+        }
+        Self {
+            bundling: config.bundling.enable,
+            inventory: config.inventory.enable,
+            payment: config.payment.enable,
+            cards: config.payment.enable && config.payment.card_numbers,
+            returns: config.returns.enable,
+            product_type: config.taxonomy.is_some(),
+            currency: config.currency.is_some() || config.locale != "en_US",
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -908,6 +2278,14 @@ pub struct EmployeeRow {
     pub country: String,
     pub start_date: NaiveDate,
     pub date_of_birth: NaiveDate,
+    pub department: String,
+    pub job_title: String,
+    /// Annual salary in USD, rounded to whole dollars
+    pub salary: f64,
+    /// `employee_id` of the employee's manager; `None` for managers
+    pub manager_id: Option<String>,
+    /// Last day of employment; `None` for current employees
+    pub termination_date: Option<NaiveDate>,
 }
 
 pub fn superstore(count: usize, seed: Option<u64>, pool_size: Option<usize>) -> Vec<SuperstoreRow> {
@@ -920,22 +2298,370 @@ pub fn superstore(count: usize, seed: Option<u64>, pool_size: Option<usize>) ->
 }
 
 /// Generate superstore data with full configuration
+///
+/// # Panics
+///
+/// Panics if [`SuperstoreConfig::validate`] fails.
 pub fn superstore_with_config(config: &SuperstoreConfig) -> Vec<SuperstoreRow> {
     let mut rng = create_rng(config.seed);
-    let mut data = Vec::with_capacity(config.count);
-
-    let sectors: Vec<&str> = US_SECTORS.clone();
 
     // Pre-generate location pool for performance
-    let location_pool = LocationPool::generate(&mut rng, config.pool_size);
+    let location_pool = Arc::new(superstore_location_pool(config, &mut rng));
 
-    // Pre-generate customer pool for repeat customer simulation
-    let customer_pool: Vec<String> = if config.customers.enable_cohorts {
-        (0..100).map(|_| generate_license_plate(&mut rng)).collect()
+    superstore_rows(config, &mut rng, &location_pool)
+}
+
+/// Generate superstore data with full configuration, along with
+/// [`SuperstoreDiagnostics`] on what generation decided: whether the copula
+/// fell back to independent sampling, how many values hit a configured bound,
+/// and which segments the rows cover.
+///
+/// The rows are the same as [`superstore_with_config`] returns.
+///
+/// # Panics
+///
+/// Panics if [`SuperstoreConfig::validate`] fails.
+pub fn superstore_with_diagnostics(
+    config: &SuperstoreConfig,
+) -> (Vec<SuperstoreRow>, SuperstoreDiagnostics) {
+    let mut rng = create_rng(config.seed);
+    let location_pool = Arc::new(superstore_location_pool(config, &mut rng));
+    superstore_rows_with_diagnostics(config, &mut rng, &location_pool)
+}
+
+/// Rows of [`superstore_with_config`] with `per_row_seeding: true`, generated
+/// on the current Rayon pool. Each row depends only on its own seed, so the
+/// output doesn't depend on how rows are split across threads. `stable_v1` is
+/// ignored.
+pub(crate) fn superstore_rows_parallel(config: &SuperstoreConfig) -> Vec<SuperstoreRow> {
+    let mut rng = create_rng(config.seed);
+    let context = RowContext::new(config, &mut rng, Arc::new(locale_location_pool(config)));
+    let copula = superstore_copula(config);
+    let base_seed = row_seed_base(config, &mut rng);
+
+    let mut rows = (0..config.count)
+        .into_par_iter()
+        .map(|id| seeded_superstore_row(config, &context, copula.as_ref(), base_seed, id))
+        .collect();
+    append_returns(config, &mut rows);
+    rows
+}
+
+/// Generate the single superstore row at `index` without generating the rows
+/// before it.
+///
+/// Equals row `index` of [`superstore_with_config`] run with
+/// `per_row_seeding: true` and the same config. Only the shared location and
+/// customer pools are rebuilt, so the cost does not depend on `index`.
+/// `stable_v1` is ignored.
+pub fn superstore_row_at(config: &SuperstoreConfig, index: usize) -> SuperstoreRow {
+    let mut rng = create_rng(config.seed);
+    let context = RowContext::new(config, &mut rng, Arc::new(locale_location_pool(config)));
+    let base_seed = row_seed_base(config, &mut rng);
+    seeded_superstore_row(
+        config,
+        &context,
+        superstore_copula(config).as_ref(),
+        base_seed,
+        index,
+    )
+}
+
+/// Generate `n_datasets` independent superstore datasets from one base seed.
+///
+/// Dataset `i` uses `derive_seed(base_seed, i)`, so the datasets differ from
+/// each other but the whole batch is reproducible from `config.seed`. Without
+/// a seed, a random base seed is drawn.
+pub fn superstore_batch(config: &SuperstoreConfig, n_datasets: usize) -> Vec<Vec<SuperstoreRow>> {
+    let base_seed = config.seed.unwrap_or_else(|| rand::rng().random());
+    (0..n_datasets)
+        .map(|i| {
+            superstore_with_config(&SuperstoreConfig {
+                seed: Some(derive_seed(base_seed, i as u64)),
+                ..config.clone()
+            })
+        })
+        .collect()
+}
+
+/// Generate chargeback events for superstore rows with payment data.
+///
+/// Fraudulent orders are charged back at `fraud_chargeback_rate` for the full
+/// amount with a fraud reason code; legitimate orders are disputed at the much
+/// lower `dispute_chargeback_rate`. Return rows are skipped. Returns an empty
+/// list unless `payment.chargebacks` is enabled.
+pub fn generate_chargebacks(
+    rows: &[SuperstoreRow],
+    config: &PaymentConfig,
+    seed: Option<u64>,
+) -> Vec<ChargebackEvent> {
+    if !config.chargebacks {
+        return Vec::new();
+    }
+    let mut rng = create_rng(seed);
+
+    rows.iter()
+        .filter(|row| row.original_order_id.is_none())
+        .filter_map(|row| {
+            let is_fraud = row.is_fraud?;
+            let rate = if is_fraud {
+                config.fraud_chargeback_rate
+            } else {
+                config.dispute_chargeback_rate
+            };
+            if rng.random::<f64>() >= rate {
+                return None;
+            }
+
+            let order_date = NaiveDate::parse_from_str(&row.order_date, "%Y-%m-%d").ok()?;
+            let (reason_code, amount, delay) = if is_fraud {
+                // Cardholders spot fraud on their next statement
+                (
+                    FRAUD_REASON_CODE,
+                    row.sales as f64,
+                    rng.random_range(10..60),
+                )
+            } else {
+                let share = if rng.random::<f64>() < 0.7 {
+                    1.0
+                } else {
+                    rng.random_range(0.2..1.0)
+                };
+                (
+                    *DISPUTE_REASON_CODES.choose(&mut rng).unwrap(),
+                    row.sales as f64 * share,
+                    rng.random_range(15..120),
+                )
+            };
+
+            Some(ChargebackEvent {
+                order_id: row.order_id.clone(),
+                chargeback_date: (order_date + chrono::Duration::days(delay))
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                amount: (amount * 100.0).round() / 100.0,
+                reason_code: reason_code.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Generate superstore data together with its chargeback events.
+///
+/// The chargebacks are drawn from a seed derived from `config.seed`, so the
+/// pair is reproducible. The event list is empty unless `payment.enable` and
+/// `payment.chargebacks` are both set.
+pub fn superstore_with_chargebacks(
+    config: &SuperstoreConfig,
+) -> (Vec<SuperstoreRow>, Vec<ChargebackEvent>) {
+    let rows = superstore_with_config(config);
+    let chargebacks = generate_chargebacks(
+        &rows,
+        &config.payment,
+        config.seed.map(|s| derive_seed(s, CHARGEBACK_SEED_INDEX)),
+    );
+    (rows, chargebacks)
+}
+
+/// Generate superstore data together with an employee table drawn from the
+/// same location pool and region set, so the two can be joined on region.
+/// Cities and states come from the shared pool but are drawn per row, so
+/// only region is guaranteed to match between the tables.
+pub fn superstore_with_employees(
+    config: &SuperstoreConfig,
+    employee_count: usize,
+) -> (Vec<SuperstoreRow>, Vec<EmployeeRow>) {
+    let mut rng = create_rng(config.seed);
+    let location_pool = Arc::new(superstore_location_pool(config, &mut rng));
+
+    let rows = superstore_rows(config, &mut rng, &location_pool);
+
+    let name_pool = if config.stable_v1 {
+        NamePool::generate(&mut rng, config.pool_size)
     } else {
-        Vec::new()
+        NamePool::generate(&mut pool_rng(config.seed), config.pool_size)
+    };
+    let employee_config = EmployeeConfig {
+        count: employee_count,
+        valid_identifiers: config.valid_identifiers,
+        regions: config.regions.clone(),
+        ..Default::default()
     };
+    let employees = employee_rows(&mut rng, &employee_config, &location_pool, &name_pool);
+
+    (rows, employees)
+}
+
+/// Location pool for a superstore dataset. The frozen `stable_v1` path keeps
+/// drawing it from the row RNG; every other mode uses [`pool_rng`].
+fn superstore_location_pool<R: Rng>(config: &SuperstoreConfig, rng: &mut R) -> LocationPool {
+    if config.stable_v1 {
+        LocationPool::generate(rng, config.pool_size)
+    } else {
+        locale_location_pool(config)
+    }
+}
+
+/// Location pool for `config.locale` (US for unsupported locales), drawn
+/// from [`pool_rng`]
+fn locale_location_pool(config: &SuperstoreConfig) -> LocationPool {
+    let locale = superstore_locale(&config.locale).unwrap_or(&SUPERSTORE_LOCALES[0]);
+    LocationPool::for_locale(&mut pool_rng(config.seed), config.pool_size, locale)
+}
+
+/// Per-dataset state shared by every superstore row: the categories, their
+/// sub-categories and product types, repeat-customer pool, location pool, and
+/// value ranges.
+struct RowContext {
+    categories: Vec<Arc<str>>,
+    sub_categories: Vec<Vec<Arc<str>>>,
+    /// Product types per sub-category; empty without a taxonomy
+    product_types: Vec<Vec<Vec<Arc<str>>>>,
+    regions: Vec<Arc<str>>,
+    order_dates: Option<(NaiveDate, NaiveDate)>,
+    country: Arc<str>,
+    currency: Arc<str>,
+    /// Units of `currency` per USD
+    usd_rate: f64,
+    customer_pool: Vec<String>,
+    location_pool: Arc<LocationPool>,
+    sales_range: f64,
+    quantity_range: f64,
+    /// Rows whose quantity or profit hit a configured bound, for
+    /// [`SuperstoreDiagnostics`]
+    clamped_quantities: AtomicUsize,
+    clamped_margins: AtomicUsize,
+}
+
+impl RowContext {
+    fn new<R: Rng>(
+        config: &SuperstoreConfig,
+        rng: &mut R,
+        location_pool: Arc<LocationPool>,
+    ) -> Self {
+        if let Err(err) = config.validate() {
+            panic!("invalid superstore config: {}", err);
+        }
+        // Pre-generate customer pool for repeat customer simulation
+        let customer_pool: Vec<String> = if config.customers.enable_cohorts {
+            (0..100).map(|_| generate_license_plate(rng)).collect()
+        } else {
+            Vec::new()
+        };
+        let mut product_types = Vec::new();
+        let (categories, sub_categories) = match (&config.categories, &config.taxonomy) {
+            (Some(categories), _) => categories
+                .iter()
+                .map(|(category, subs)| (Arc::from(category.as_str()), intern(subs)))
+                .unzip(),
+            (None, Some(taxonomy)) => taxonomy
+                .iter()
+                .map(|(category, subs)| {
+                    let (names, types): (Vec<&String>, Vec<Vec<Arc<str>>>) =
+                        subs.iter().map(|(sub, types)| (sub, intern(types))).unzip();
+                    product_types.push(types);
+                    (Arc::from(category.as_str()), intern(&names))
+                })
+                .unzip(),
+            (None, None) => (
+                SECTOR_VALUES.clone(),
+                US_SECTORS
+                    .iter()
+                    .map(|sector| intern(&US_SECTORS_MAP[sector]))
+                    .collect(),
+            ),
+        };
+        let locale = superstore_locale(&config.locale).unwrap_or(&SUPERSTORE_LOCALES[0]);
+        let currency = config.currency.as_deref().unwrap_or(locale.currency);
+        Self {
+            categories,
+            sub_categories,
+            product_types,
+            regions: intern(&config.regions),
+            order_dates: config.order_date_range().expect("validated above"),
+            country: if locale.country == "US" {
+                COUNTRY_VALUE.clone()
+            } else {
+                Arc::from(locale.country)
+            },
+            currency: Arc::from(currency.to_ascii_uppercase()),
+            usd_rate: currency_usd_rate(currency).expect("validated above"),
+            customer_pool,
+            location_pool,
+            sales_range: (config.max_sales - config.min_sales) as f64,
+            quantity_range: (config.max_quantity - config.min_quantity) as f64,
+            clamped_quantities: AtomicUsize::new(0),
+            clamped_margins: AtomicUsize::new(0),
+        }
+    }
+
+    fn diagnostics(
+        &self,
+        config: &SuperstoreConfig,
+        rows: &[SuperstoreRow],
+    ) -> SuperstoreDiagnostics {
+        SuperstoreDiagnostics::new(
+            config,
+            rows,
+            Some(self.clamped_quantities.load(Ordering::Relaxed)),
+            self.clamped_margins.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// Copula the correlated sales columns are sampled from
+enum SalesCopula {
+    Gaussian(GaussianCopula),
+    StudentT(StudentTCopula),
+}
+
+impl SalesCopula {
+    fn sample_n<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<Vec<f64>> {
+        match self {
+            SalesCopula::Gaussian(copula) => copula.sample_n(rng, n),
+            SalesCopula::StudentT(copula) => copula.sample_n(rng, n),
+        }
+    }
+}
+
+/// Frobenius distance from the configured sales correlations to the nearest
+/// valid correlation matrix, or `None` if they already form one.
+///
+/// Three pairwise correlations are configurable and the rest are derived, so
+/// strong settings (e.g. 0.8 / 0.9 / -0.6) can combine into a matrix no data
+/// could have. With `repair_correlations` such a matrix is replaced by
+/// [`nearest_correlation_matrix`]; callers can use this to warn about it.
+pub fn superstore_correlation_repair(config: &SuperstoreConfig) -> Option<f64> {
+    let matrix = sales_correlation_matrix(config);
+    if GaussianCopula::new(matrix.clone()).is_ok() {
+        return None;
+    }
+    nearest_correlation_matrix(&matrix)
+        .ok()
+        .map(|(_, distance)| distance)
+}
+
+/// Copula over [Sales, Quantity, Discount, Profit], or `None` if the
+/// configured correlations aren't valid
+fn superstore_copula(config: &SuperstoreConfig) -> Option<SalesCopula> {
+    let mut correlation_matrix = sales_correlation_matrix(config);
+    if config.repair_correlations && GaussianCopula::new(correlation_matrix.clone()).is_err() {
+        if let Ok((repaired, _)) = nearest_correlation_matrix(&correlation_matrix) {
+            correlation_matrix = repaired;
+        }
+    }
+    match config.copula_df {
+        Some(df) => StudentTCopula::new(correlation_matrix, df)
+            .ok()
+            .map(SalesCopula::StudentT),
+        None => GaussianCopula::new(correlation_matrix)
+            .ok()
+            .map(SalesCopula::Gaussian),
+    }
+}
 
+/// Correlation matrix over [Sales, Quantity, Discount, Profit]
+fn sales_correlation_matrix(config: &SuperstoreConfig) -> Vec<Vec<f64>> {
     // Build correlation matrix from config
     //   [Sales, Quantity, Discount, Profit]
     // We use configured correlations for key relationships
@@ -947,231 +2673,788 @@ pub fn superstore_with_config(config: &SuperstoreConfig) -> Vec<SuperstoreRow> {
     let ds = (-0.2_f64).clamp(-0.99, 0.99); // Discount-Sales weak negative
     let dq = (0.1_f64).clamp(-0.99, 0.99); // Discount-Quantity weak positive (more discount -> more bought)
 
-    let correlation_matrix = vec![
+    vec![
         vec![1.0, sq, ds, sp], // Sales
         vec![sq, 1.0, dq, qp], // Quantity
         vec![ds, dq, 1.0, dp], // Discount
         vec![sp, qp, dp, 1.0], // Profit
-    ];
-
-    // Pre-generate all correlated values using copula
-    let correlated_values = if let Ok(copula) = GaussianCopula::new(correlation_matrix) {
-        copula.sample_n(&mut rng, config.count)
-    } else {
-        // Fallback to independent uniform values
-        (0..config.count)
-            .map(|_| {
-                vec![
-                    rng.random::<f64>(),
-                    rng.random::<f64>(),
-                    rng.random::<f64>(),
-                    rng.random::<f64>(),
-                ]
-            })
-            .collect()
-    };
+    ]
+}
 
-    let sales_range = (config.max_sales - config.min_sales) as f64;
-    let quantity_range = (config.max_quantity - config.min_quantity) as f64;
+/// Base seed that per-row seeds are derived from
+fn row_seed_base<R: Rng>(config: &SuperstoreConfig, rng: &mut R) -> u64 {
+    config.seed.unwrap_or_else(|| rng.random())
+}
 
-    for (id, uniforms) in correlated_values.into_iter().enumerate() {
-        let order_date = random_date_this_year(&mut rng);
-        let mut ship_date = random_date_between(&mut rng, order_date);
-
-        // Choose region from config first (needed for regional preferences)
-        let region = config
-            .regions
-            .choose(&mut rng)
-            .unwrap_or(&config.regions[0])
-            .clone();
-
-        // Apply regional preference to sector selection
-        let sector_idx = apply_regional_preference(&mut rng, &region, &sectors, &config.regional);
-        let sector = sectors[sector_idx];
-        let industries = US_SECTORS_MAP.get(sector).unwrap();
-        let industry = *industries.choose(&mut rng).unwrap();
+/// One row's correlated uniforms over [Sales, Quantity, Discount, Profit],
+/// or independent ones without a copula
+fn sample_uniforms<R: Rng>(copula: Option<&SalesCopula>, rng: &mut R) -> Vec<f64> {
+    match copula {
+        Some(copula) => copula.sample_n(rng, 1).remove(0),
+        None => (0..4).map(|_| rng.random::<f64>()).collect(),
+    }
+}
 
-        // Calculate seasonality multiplier based on order date
-        let month = order_date.month();
-        let seasonality_mult = get_seasonality_multiplier(month, &config.seasonality);
+/// Seed of the copula-uniform blocks; draws from `rng` only when unseeded
+fn uniform_seed_base<R: Rng>(config: &SuperstoreConfig, rng: &mut R) -> u64 {
+    derive_seed(row_seed_base(config, rng), UNIFORMS_SEED_INDEX)
+}
 
-        // Transform uniform copula values to actual ranges
-        let base_sales = config.min_sales as f64 + uniforms[0] * sales_range;
-        let sales_with_season = base_sales * seasonality_mult;
+/// Copula uniforms for the rows of block `block` that fall below `count`,
+/// from the block's own RNG
+fn uniform_block(
+    copula: Option<&SalesCopula>,
+    base_seed: u64,
+    block: usize,
+    count: usize,
+) -> Vec<Vec<f64>> {
+    let rows = UNIFORM_BLOCK_ROWS.min(count.saturating_sub(block * UNIFORM_BLOCK_ROWS));
+    let mut rng = create_rng(Some(derive_seed(base_seed, block as u64)));
+    match copula {
+        Some(copula) => copula.sample_n(&mut rng, rows),
+        None => (0..rows)
+            .map(|_| (0..4).map(|_| rng.random::<f64>()).collect())
+            .collect(),
+    }
+}
 
-        // Generate item status with discount-biased distribution
-        let discount = (uniforms[2] * config.max_discount_percent * 100.0).round() / 100.0;
-        let discount_factor = discount / config.max_discount_percent;
-        let item_status = generate_item_status(&mut rng, discount_factor);
+/// Generate row `id` from its own RNG seeded with `derive_seed(base_seed, id)`
+fn seeded_superstore_row(
+    config: &SuperstoreConfig,
+    context: &RowContext,
+    copula: Option<&SalesCopula>,
+    base_seed: u64,
+    id: usize,
+) -> SuperstoreRow {
+    let mut rng = create_rng(Some(derive_seed(base_seed, id as u64)));
+    let uniforms = sample_uniforms(copula, &mut rng);
+    let discount = round_cent(uniforms[2] * config.max_discount_percent);
+    superstore_row(config, context, &mut rng, id, &uniforms, discount)
+}
 
-        // Round to Costco-style price point based on item status
-        let mut item_price = round_to_price_point_with_status(sales_with_season, &item_status);
+/// Superstore rows generated one at a time, identical to the rows of
+/// [`superstore_with_config`] with the same config (`stable_v1` is ignored).
+/// Return rows, which follow every sale, are not generated.
+///
+/// Copula uniforms come in blocks of [`UNIFORM_BLOCK_ROWS`] rows, each from
+/// its own seed, so the iterator samples one block at a time and starts in
+/// time and memory independent of `count`.
+pub(crate) struct SuperstoreRowIter {
+    config: SuperstoreConfig,
+    context: RowContext,
+    copula: Option<SalesCopula>,
+    rng: SeededRng,
+    order: RowOrder,
+    next_id: usize,
+}
 
-        // Generate order ID early (needed for bundle ID)
-        let order_id = generate_ein(&mut rng);
+/// Where each streamed row's randomness comes from
+enum RowOrder {
+    /// `per_row_seeding`: every row has its own RNG
+    Seeded { base_seed: u64 },
+    /// Copula uniforms come from per-block RNGs; `uniforms` holds the
+    /// current block
+    Sequential {
+        uniform_base: u64,
+        uniforms: Vec<Vec<f64>>,
+    },
+}
 
-        // Check for product bundle
-        let (bundle_id, bundle_discount) = if config.bundling.enable {
-            if let Some((bid, disc)) = check_bundle(&mut rng, &config.bundling, &order_id) {
-                // Apply bundle discount to price
-                item_price *= disc;
-                (Some(bid), disc)
-            } else {
-                (None, 1.0)
+impl SuperstoreRowIter {
+    pub(crate) fn new(config: &SuperstoreConfig) -> Self {
+        // An unseeded stream picks its seed once, shared by every block
+        let config = &SuperstoreConfig {
+            seed: Some(config.seed.unwrap_or_else(rand::random)),
+            ..config.clone()
+        };
+        let mut rng = create_rng(config.seed);
+        let location_pool = Arc::new(locale_location_pool(config));
+        let context = RowContext::new(config, &mut rng, location_pool);
+        let copula = superstore_copula(config);
+        let order = if config.per_row_seeding {
+            RowOrder::Seeded {
+                base_seed: row_seed_base(config, &mut rng),
             }
         } else {
-            (None, 1.0)
+            RowOrder::Sequential {
+                uniform_base: uniform_seed_base(config, &mut rng),
+                uniforms: Vec::new(),
+            }
         };
+        Self {
+            config: config.clone(),
+            context,
+            copula,
+            rng,
+            order,
+            next_id: 0,
+        }
+    }
 
-        // Check inventory status
-        let (stock_status, backorder_days) = determine_stock_status(&mut rng, &config.inventory);
+    /// Draw locations from a pool of `pool_size` values instead. The pool
+    /// has its own RNG, so no other column changes.
+    pub(crate) fn with_pool_size(mut self, pool_size: usize) -> Self {
+        self.config.pool_size = pool_size;
+        self.context.location_pool = Arc::new(locale_location_pool(&self.config));
+        self
+    }
+}
 
-        // If backordered, adjust ship date
-        if let Some(delay) = backorder_days {
-            ship_date = ship_date + chrono::Duration::days(delay as i64);
-        }
+impl Iterator for SuperstoreRowIter {
+    type Item = SuperstoreRow;
 
-        // Apply inventory low stock premium
-        if config.inventory.enable && stock_status == "Low Stock" {
-            item_price *= config.inventory.low_stock_price_premium;
+    fn next(&mut self) -> Option<SuperstoreRow> {
+        if self.next_id >= self.config.count {
+            return None;
         }
+        let id = self.next_id;
+        self.next_id += 1;
+        Some(match &mut self.order {
+            RowOrder::Seeded { base_seed } => seeded_superstore_row(
+                &self.config,
+                &self.context,
+                self.copula.as_ref(),
+                *base_seed,
+                id,
+            ),
+            RowOrder::Sequential {
+                uniform_base,
+                uniforms,
+            } => {
+                if id.is_multiple_of(UNIFORM_BLOCK_ROWS) {
+                    *uniforms = uniform_block(
+                        self.copula.as_ref(),
+                        *uniform_base,
+                        id / UNIFORM_BLOCK_ROWS,
+                        self.config.count,
+                    );
+                }
+                let uniforms = &uniforms[id % UNIFORM_BLOCK_ROWS];
+                let discount = round_cent(uniforms[2] * self.config.max_discount_percent);
+                superstore_row(
+                    &self.config,
+                    &self.context,
+                    &mut self.rng,
+                    id,
+                    uniforms,
+                    discount,
+                )
+            }
+        })
+    }
+}
 
-        // Quantity with promotional boost and item status effects
-        let base_quantity = config.min_quantity as f64 + uniforms[1] * quantity_range;
-        let quantity_with_promotion = apply_promotional_effects(
-            &mut rng,
-            base_quantity.round() as i32,
-            discount,
-            &config.promotions,
-        );
-        // Apply item status volume effects (bimodal for sale/clearance)
-        let quantity_with_status =
-            apply_item_status_volume_effect(&mut rng, quantity_with_promotion, &item_status);
-        let quantity = quantity_with_status.clamp(config.min_quantity, config.max_quantity);
+fn superstore_rows<R: Rng>(
+    config: &SuperstoreConfig,
+    rng: &mut R,
+    location_pool: &Arc<LocationPool>,
+) -> Vec<SuperstoreRow> {
+    superstore_rows_with_diagnostics(config, rng, location_pool).0
+}
+
+fn superstore_rows_with_diagnostics<R: Rng>(
+    config: &SuperstoreConfig,
+    rng: &mut R,
+    location_pool: &Arc<LocationPool>,
+) -> (Vec<SuperstoreRow>, SuperstoreDiagnostics) {
+    if config.stable_v1 {
+        let rows = superstore_rows_stable_v1(config, rng, location_pool);
+        let diagnostics = SuperstoreDiagnostics::new(config, &rows, None, 0);
+        return (rows, diagnostics);
+    }
 
-        // Customer with cohort behavior
-        let (customer_id, is_vip) =
-            generate_customer_id(&mut rng, &customer_pool, &config.customers);
+    let context = RowContext::new(config, rng, location_pool.clone());
+    let copula = superstore_copula(config);
 
-        // VIP customers get bigger orders
-        let vip_mult = if is_vip && config.customers.enable_cohorts {
-            config.customers.vip_order_multiplier
+    if config.per_row_seeding {
+        let base_seed = row_seed_base(config, rng);
+        let mut rows = (0..config.count)
+            .map(|id| seeded_superstore_row(config, &context, copula.as_ref(), base_seed, id))
+            .collect();
+        append_returns(config, &mut rows);
+        let diagnostics = context.diagnostics(config, &rows);
+        return (rows, diagnostics);
+    }
+
+    // Pre-generate all correlated values using copula (independent uniform
+    // values without one), a block of rows per seed
+    let uniform_base = uniform_seed_base(config, rng);
+    let correlated_values: Vec<Vec<f64>> = (0..config.count.div_ceil(UNIFORM_BLOCK_ROWS))
+        .flat_map(|block| uniform_block(copula.as_ref(), uniform_base, block, config.count))
+        .collect();
+
+    // Round the whole discount column in one vectorized pass. Item price and
+    // profit stay rounded per row: sales and the margin clamp are computed
+    // from the rounded values.
+    let mut discounts: Vec<f64> = correlated_values
+        .iter()
+        .map(|uniforms| uniforms[2] * config.max_discount_percent)
+        .collect();
+    round_cents(&mut discounts);
+
+    let mut rows = correlated_values
+        .into_iter()
+        .zip(discounts)
+        .enumerate()
+        .map(|(id, (uniforms, discount))| {
+            superstore_row(config, &context, rng, id, &uniforms, discount)
+        })
+        .collect();
+    append_returns(config, &mut rows);
+    let diagnostics = context.diagnostics(config, &rows);
+    (rows, diagnostics)
+}
+
+/// Append a return row for a fraction of the sale rows when returns are
+/// enabled.
+///
+/// Each return copies its sale with negated `quantity` and `sales` (less the
+/// restocking fee, which the store keeps as profit), gets its own order ID,
+/// and points back to the sale via `original_order_id`. Returns come after
+/// all sales and are drawn from their own RNG, so the sale rows are the same
+/// with returns on or off.
+fn append_returns(config: &SuperstoreConfig, rows: &mut Vec<SuperstoreRow>) {
+    let returns = &config.returns;
+    if !returns.enable || rows.is_empty() {
+        return;
+    }
+    let mut rng = create_rng(config.seed.map(|s| derive_seed(s, RETURNS_SEED_INDEX)));
+
+    // Scale the per-status weights so the expected return rate is `return_rate`
+    let mean_weight = rows
+        .iter()
+        .map(|r| return_weight(&r.item_status))
+        .sum::<f64>()
+        / rows.len() as f64;
+    let scale = returns.return_rate / mean_weight;
+
+    let sales = rows.len();
+    for i in 0..sales {
+        let sale = &rows[i];
+        let probability = (return_weight(&sale.item_status) * scale).min(1.0);
+        if rng.random::<f64>() >= probability {
+            continue;
+        }
+
+        let weights = if sale.item_status == ItemStatus::Regular.as_str() {
+            &RETURN_REASON_WEIGHTS
         } else {
-            1.0
+            &DISCOUNTED_RETURN_REASON_WEIGHTS
         };
-        let final_sales = (item_price * vip_mult).round() as i32;
-        let final_quantity = ((quantity as f64) * vip_mult.sqrt()).round() as i32;
-
-        // Payment method generation
-        let (payment_method, is_fraud, processing_fee) = if config.payment.enable {
-            let pm = generate_payment_method(&mut rng);
-            let fraud = if config.payment.fraud_simulation {
-                check_fraud(&mut rng, &pm)
-            } else {
-                false
-            };
-            let fee = (final_sales as f64) * pm.processing_fee_rate();
-            (
-                Some(pm.as_str().to_string()),
-                Some(fraud),
-                Some((fee * 100.0).round() / 100.0),
-            )
+        let reason = RETURN_REASONS[sample_categorical(&mut rng, weights)];
+        let restocking_fee = if reason == "No Longer Needed" {
+            (sale.sales as f64 * returns.restocking_fee_rate).round() as i32
+        } else {
+            0
+        };
+
+        // Exponentially distributed delay with the configured mean, at least a day
+        let days = (-(1.0 - rng.random::<f64>()).ln() * returns.avg_days_to_return)
+            .round()
+            .max(1.0) as i64;
+        let ship_date = NaiveDate::parse_from_str(&sale.ship_date, "%Y-%m-%d").unwrap();
+        let order_id = if config.valid_identifiers {
+            generate_valid_ein(&mut rng)
+        } else {
+            generate_ein(&mut rng)
+        };
+
+        let mut row = sale.clone();
+        row.row_id = rows.len() as i32;
+        row.order_id = order_id;
+        row.sales = -(sale.sales - restocking_fee);
+        row.quantity = -sale.quantity;
+        row.profit = round_cent(restocking_fee as f64 - sale.profit);
+        row.return_reason = Some(reason.to_string());
+        row.return_date = Some(
+            (ship_date + chrono::Duration::days(days))
+                .format("%Y-%m-%d")
+                .to_string(),
+        );
+        row.original_order_id = Some(sale.order_id.clone());
+        rows.push(row);
+    }
+}
+
+/// Generate a single superstore row from its correlated uniforms and its
+/// discount (the discount uniform scaled and rounded to cents)
+fn superstore_row<R: Rng>(
+    config: &SuperstoreConfig,
+    context: &RowContext,
+    rng: &mut R,
+    id: usize,
+    uniforms: &[f64],
+    discount: f64,
+) -> SuperstoreRow {
+    let (order_date, mut ship_date) = match context.order_dates {
+        Some((start, end)) => {
+            let order_date = random_date_in_range(rng, start, end);
+            (order_date, random_date_until(rng, order_date, end))
+        }
+        None => {
+            let order_date = random_date_this_year(rng);
+            (order_date, random_date_between(rng, order_date))
+        }
+    };
+
+    // Choose region from config first (needed for regional preferences)
+    let region = context
+        .regions
+        .choose(rng)
+        .unwrap_or(&context.regions[0])
+        .clone();
+
+    // Apply regional preference to category selection
+    let category_idx =
+        apply_regional_preference(rng, &region, &context.categories, &config.regional);
+    let sub_categories = &context.sub_categories[category_idx];
+    let sub_category = sub_categories.choose(rng).unwrap();
+    // The product type is drawn from the chosen sub-category's own types
+    let product_type = context.product_types.get(category_idx).map(|types| {
+        let sub_idx = sub_categories
+            .iter()
+            .position(|s| Arc::ptr_eq(s, sub_category))
+            .unwrap();
+        types[sub_idx].choose(rng).unwrap().clone()
+    });
+    let sub_category = sub_category.to_string();
+
+    // Calculate seasonality multiplier based on order date
+    let month = order_date.month();
+    let seasonality_mult = get_seasonality_multiplier(month, &config.seasonality);
+
+    // Transform uniform copula values to actual ranges
+    let base_sales = config.min_sales as f64 + uniforms[0] * context.sales_range;
+    let sales_with_season = base_sales * seasonality_mult;
+
+    // Generate item status with discount-biased distribution
+    let discount_factor = discount / config.max_discount_percent;
+    let item_status = generate_item_status(rng, discount_factor);
+
+    // Round to Costco-style price point based on item status
+    let mut item_price = round_to_price_point_with_status(sales_with_season, &item_status);
+
+    // Generate order ID early (needed for bundle ID)
+    let order_id = if config.valid_identifiers {
+        generate_valid_ein(rng)
+    } else {
+        generate_ein(rng)
+    };
+
+    // Check for product bundle
+    let (bundle_id, bundle_discount) = if config.bundling.enable {
+        if let Some((bid, disc)) = check_bundle(rng, &config.bundling, &order_id) {
+            // Apply bundle discount to price
+            item_price *= disc;
+            (Some(bid), disc)
         } else {
-            (None, None, None)
+            (None, 1.0)
+        }
+    } else {
+        (None, 1.0)
+    };
+
+    // Check inventory status
+    let (stock_status, backorder_days) = determine_stock_status(rng, &config.inventory);
+
+    // If backordered, adjust ship date
+    if let Some(delay) = backorder_days {
+        ship_date = ship_date + chrono::Duration::days(delay as i64);
+    }
+
+    // Apply inventory low stock premium
+    if config.inventory.enable && stock_status == "Low Stock" {
+        item_price *= config.inventory.low_stock_price_premium;
+    }
+
+    // Quantity with promotional boost and item status effects, priced in
+    // USD so elasticity doesn't depend on the output currency
+    let base_quantity = config.min_quantity as f64 + uniforms[1] * context.quantity_range;
+    let quantity_with_promotion =
+        apply_promotional_effects(base_quantity.round() as i32, discount, item_price, config);
+
+    // Prices are generated in USD; convert to the configured currency
+    item_price *= context.usd_rate;
+
+    // Apply item status volume effects (bimodal for sale/clearance)
+    let quantity_with_status =
+        apply_item_status_volume_effect(rng, quantity_with_promotion, &item_status);
+    let quantity = quantity_with_status.clamp(config.min_quantity, config.max_quantity);
+    if quantity != quantity_with_status {
+        context.clamped_quantities.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // Customer with cohort behavior
+    let (customer_id, is_vip) =
+        generate_customer_id(rng, &context.customer_pool, &config.customers);
+
+    // VIP customers get bigger orders
+    let vip_mult = if is_vip && config.customers.enable_cohorts {
+        config.customers.vip_order_multiplier
+    } else {
+        1.0
+    };
+    let final_quantity = ((quantity as f64) * vip_mult.sqrt()).round() as i32;
+    let final_sales = match config.sales_mode {
+        SalesMode::Synthetic => (item_price * vip_mult).round() as i32,
+        SalesMode::PriceTimesQuantity => {
+            (round_cent(item_price) * final_quantity as f64).round() as i32
+        }
+    };
+
+    // Discounts come off the list price; back it out of the item price,
+    // whose ending encodes the item status
+    let item_price = round_cent(item_price);
+    let msrp = if discount < 100.0 {
+        round_cent(item_price / (1.0 - discount / 100.0))
+    } else {
+        item_price
+    };
+
+    // Payment method generation
+    let (payment_method, is_fraud, processing_fee) = if config.payment.enable {
+        let pm = generate_payment_method(rng);
+        let fraud = if config.payment.fraud_simulation {
+            check_fraud(rng, &pm)
+        } else {
+            false
+        };
+        let fee = processing_fee(final_sales as f64, &pm, &config.payment);
+        (Some(pm), Some(fraud), Some(fee))
+    } else {
+        (None, None, None)
+    };
+
+    let (card_network, card_number) = match &payment_method {
+        Some(pm @ (PaymentMethod::CreditCard | PaymentMethod::DebitCard))
+            if config.payment.card_numbers =>
+        {
+            let (network, pan) = generate_card(rng, pm);
+            (Some(network.as_str().to_string()), Some(pan))
+        }
+        _ => (None, None),
+    };
+    let settlement_date = payment_method.as_ref().map(|pm| {
+        let (min_days, max_days) = pm.settlement_delay_days();
+        let delay = rng.random_range(min_days..=max_days);
+        (order_date + chrono::Duration::days(delay))
+            .format("%Y-%m-%d")
+            .to_string()
+    });
+    let payment_method = payment_method.map(|pm| pm.as_str().to_string());
+
+    // The copula alone ties profit to discount. Discounted items are already
+    // more likely to be on sale or clearance, so they earn less through the
+    // configured correlation; a further discount penalty or item status
+    // multiplier would pull corr(discount, profit) away from its target.
+    let profit = match config.sales_mode {
+        SalesMode::Synthetic => {
+            let base_profit = (-500.0 + uniforms[3] * 3500.0) * context.usd_rate;
+            // Apply bundle discount effect on profit
+            let bundle_adjusted_profit = base_profit * bundle_discount;
+            // Deduct processing fee if applicable
+            let fee_adjusted_profit = bundle_adjusted_profit - processing_fee.unwrap_or(0.0);
+            round_cent(fee_adjusted_profit * seasonality_mult)
+        }
+        SalesMode::PriceTimesQuantity => {
+            // Margin drawn from the copula keeps profit correlated with sales
+            // and discount; seasonality and bundles already moved the price
+            let (low, high) = PRICE_TIMES_QUANTITY_MARGIN_RANGE;
+            let margin = low + uniforms[3] * (high - low);
+            round_cent(final_sales as f64 * margin - processing_fee.unwrap_or(0.0))
+        }
+    };
+    let profit = match config.margin_bounds {
+        Some((low, high)) => {
+            let sales = final_sales as f64;
+            let clamped = profit.clamp(low * sales, high * sales);
+            if clamped != profit {
+                context.clamped_margins.fetch_add(1, Ordering::Relaxed);
+            }
+            round_cent(clamped)
+        }
+        None => profit,
+    };
+
+    SuperstoreRow {
+        row_id: id as i32,
+        order_id,
+        order_date: order_date.format("%Y-%m-%d").to_string(),
+        ship_date: ship_date.format("%Y-%m-%d").to_string(),
+        ship_mode: SHIP_MODE_VALUES.choose(rng).unwrap().clone(),
+        customer_id,
+        segment: SEGMENT_VALUES.choose(rng).unwrap().clone(),
+        country: context.country.clone(),
+        city: context.location_pool.random_city(rng).to_string(),
+        state: context.location_pool.random_state(rng).to_string(),
+        postal_code: context.location_pool.random_zip(rng).to_string(),
+        region,
+        product_id: generate_bban(rng),
+        category: context.categories[category_idx].clone(),
+        sub_category,
+        product_type,
+        item_status: item_status.as_str().to_string(),
+        currency: Some(context.currency.clone()),
+        item_price,
+        msrp: Some(msrp),
+        sales: final_sales,
+        quantity: final_quantity,
+        discount,
+        profit,
+        // Priority 4 fields
+        bundle_id,
+        payment_method,
+        is_fraud,
+        processing_fee,
+        card_number,
+        card_network,
+        settlement_date,
+        backorder_days,
+        stock_status: if config.inventory.enable {
+            Some(stock_status)
+        } else {
+            None
+        },
+        return_reason: None,
+        return_date: None,
+        original_order_id: None,
+    }
+}
+
+/// Frozen `stable_v1` generation path.
+///
+/// The order of RNG draws here is part of the public reproducibility
+/// guarantee: for a given seed this must produce identical rows across
+/// releases (checked by the golden-file tests in `tests/golden.rs`). New
+/// features belong in `superstore_rows`, never here. Optional columns
+/// (bundles, payments, inventory) are always empty and `valid_identifiers`
+/// is ignored; dates are relative to [`STABLE_V1_REFERENCE_DATE`].
+fn superstore_rows_stable_v1<R: Rng>(
+    config: &SuperstoreConfig,
+    rng: &mut R,
+    location_pool: &LocationPool,
+) -> Vec<SuperstoreRow> {
+    let mut data = Vec::with_capacity(config.count);
+    let reference_date = NaiveDate::parse_from_str(STABLE_V1_REFERENCE_DATE, "%Y-%m-%d").unwrap();
+    let sectors: Vec<&str> = US_SECTORS.clone();
+    let regions = intern(&config.regions);
+
+    let customer_pool: Vec<String> = if config.customers.enable_cohorts {
+        (0..100).map(|_| generate_license_plate(rng)).collect()
+    } else {
+        Vec::new()
+    };
+
+    let sq = config.sales_quantity_correlation;
+    let sp = config.sales_profit_correlation;
+    let dp = config.discount_profit_correlation;
+    let qp = (sq * sp).clamp(-0.99, 0.99);
+    let correlation_matrix = vec![
+        vec![1.0, sq, -0.2, sp],
+        vec![sq, 1.0, 0.1, qp],
+        vec![-0.2, 0.1, 1.0, dp],
+        vec![sp, qp, dp, 1.0],
+    ];
+    let correlated_values = if let Ok(copula) = GaussianCopula::new(correlation_matrix) {
+        copula.sample_n(rng, config.count)
+    } else {
+        (0..config.count)
+            .map(|_| (0..4).map(|_| rng.random::<f64>()).collect())
+            .collect()
+    };
+
+    let sales_range = (config.max_sales - config.min_sales) as f64;
+    let quantity_range = (config.max_quantity - config.min_quantity) as f64;
+
+    for (id, uniforms) in correlated_values.into_iter().enumerate() {
+        let order_date = random_date_in_year(rng, reference_date.year());
+        let ship_date = random_date_until(rng, order_date, reference_date);
+
+        let region = regions.choose(rng).unwrap_or(&regions[0]).clone();
+        let sector_idx = apply_regional_preference(rng, &region, &sectors, &config.regional);
+        let sector = sectors[sector_idx];
+        let industry = *US_SECTORS_MAP.get(sector).unwrap().choose(rng).unwrap();
+
+        let seasonality_mult = get_seasonality_multiplier(order_date.month(), &config.seasonality);
+        let base_sales = config.min_sales as f64 + uniforms[0] * sales_range;
+        let discount = (uniforms[2] * config.max_discount_percent * 100.0).round() / 100.0;
+        let item_status = generate_item_status(rng, discount / config.max_discount_percent);
+        let item_price =
+            round_to_price_point_with_status(base_sales * seasonality_mult, &item_status);
+        let order_id = generate_ein(rng);
+
+        let base_quantity = config.min_quantity as f64 + uniforms[1] * quantity_range;
+        let quantity = apply_promotional_effects_v1(
+            rng,
+            base_quantity.round() as i32,
+            discount,
+            &config.promotions,
+        );
+        let quantity = apply_item_status_volume_effect(rng, quantity, &item_status)
+            .clamp(config.min_quantity, config.max_quantity);
+
+        let (customer_id, is_vip) = generate_customer_id(rng, &customer_pool, &config.customers);
+        let vip_mult = if is_vip && config.customers.enable_cohorts {
+            config.customers.vip_order_multiplier
+        } else {
+            1.0
         };
 
-        // Profit calculation with item status correlation
-        // Sale/clearance items have reduced profit margins
         let base_profit = -500.0 + uniforms[3] * 3500.0;
-        // High discounts hurt profit more
         let discount_penalty = (discount / 100.0) * 500.0;
-        // Apply item status profit multiplier (regular=1.0, sale=0.4, clearance=0.1, returned=0.05)
-        let status_adjusted_profit =
-            (base_profit - discount_penalty) * item_status.profit_multiplier();
-        // Apply bundle discount effect on profit
-        let bundle_adjusted_profit = status_adjusted_profit * bundle_discount;
-        // Deduct processing fee if applicable
-        let fee_adjusted_profit = bundle_adjusted_profit - processing_fee.unwrap_or(0.0);
-        let profit = (fee_adjusted_profit * seasonality_mult * 100.0).round() / 100.0;
-
-        let row = SuperstoreRow {
+        let profit = (base_profit - discount_penalty) * item_status.profit_multiplier();
+
+        data.push(SuperstoreRow {
             row_id: id as i32,
             order_id,
             order_date: order_date.format("%Y-%m-%d").to_string(),
             ship_date: ship_date.format("%Y-%m-%d").to_string(),
-            ship_mode: SHIP_MODES.choose(&mut rng).unwrap().to_string(),
+            ship_mode: SHIP_MODE_VALUES.choose(rng).unwrap().clone(),
             customer_id,
-            segment: SEGMENTS.choose(&mut rng).unwrap().to_string(),
-            country: "US".to_string(),
-            city: location_pool.random_city(&mut rng).to_string(),
-            state: location_pool.random_state(&mut rng).to_string(),
-            postal_code: location_pool.random_zip(&mut rng).to_string(),
+            segment: SEGMENT_VALUES.choose(rng).unwrap().clone(),
+            country: COUNTRY_VALUE.clone(),
+            city: location_pool.random_city(rng).to_string(),
+            state: location_pool.random_state(rng).to_string(),
+            postal_code: location_pool.random_zip(rng).to_string(),
             region,
-            product_id: generate_bban(&mut rng),
-            category: sector.to_string(),
+            product_id: generate_bban(rng),
+            category: SECTOR_VALUES[sector_idx].clone(),
             sub_category: industry.to_string(),
+            product_type: None,
             item_status: item_status.as_str().to_string(),
+            currency: None,
             item_price: (item_price * 100.0).round() / 100.0,
-            sales: final_sales,
-            quantity: final_quantity,
+            msrp: None,
+            sales: (item_price * vip_mult).round() as i32,
+            quantity: ((quantity as f64) * vip_mult.sqrt()).round() as i32,
             discount,
-            profit,
-            // Priority 4 fields
-            bundle_id,
-            payment_method,
-            is_fraud,
-            processing_fee,
-            backorder_days,
-            stock_status: if config.inventory.enable {
-                Some(stock_status)
-            } else {
-                None
-            },
-        };
-        data.push(row);
+            profit: (profit * seasonality_mult * 100.0).round() / 100.0,
+            bundle_id: None,
+            payment_method: None,
+            is_fraud: None,
+            processing_fee: None,
+            card_number: None,
+            card_network: None,
+            settlement_date: None,
+            backorder_days: None,
+            stock_status: None,
+            return_reason: None,
+            return_date: None,
+            original_order_id: None,
+        });
     }
 
     data
 }
 
+/// Last `n` characters of `value` that are ASCII digits
+fn last_digits(value: &str, n: usize) -> String {
+    let digits: Vec<char> = value.chars().filter(|c| c.is_ascii_digit()).collect();
+    digits[digits.len().saturating_sub(n)..].iter().collect()
+}
+
+/// Mask an SSN down to its last four digits: `***-**-1234`
+pub fn mask_ssn(ssn: &str) -> String {
+    format!("***-**-{}", last_digits(ssn, 4))
+}
+
+/// Mask a phone number down to its last four digits: `(***) ***-1234`
+pub fn mask_phone(phone: &str) -> String {
+    format!("(***) ***-{}", last_digits(phone, 4))
+}
+
+/// Tokenize an email by replacing the local part with a stable 64-bit
+/// FNV-1a hash, so the same address always maps to the same token.
+pub fn mask_email(email: &str) -> String {
+    let hash = email
+        .to_lowercase()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x100000001b3)
+        });
+    match email.rsplit_once('@') {
+        Some((_, domain)) => format!("{:016x}@{}", hash, domain),
+        None => format!("{:016x}", hash),
+    }
+}
+
+/// Mask PII fields of employee rows in place
+pub fn mask_employee_pii(rows: &mut [EmployeeRow], mask: &PiiMaskConfig) {
+    for row in rows.iter_mut() {
+        if mask.ssn {
+            row.ssn = mask_ssn(&row.ssn);
+        }
+        if mask.email {
+            row.email = mask_email(&row.email);
+        }
+        if mask.phone_number {
+            row.phone_number = mask_phone(&row.phone_number);
+        }
+    }
+}
+
 pub fn employees(count: usize, seed: Option<u64>, pool_size: Option<usize>) -> Vec<EmployeeRow> {
-    let mut rng = create_rng(seed);
-    let mut data = Vec::with_capacity(count);
+    employees_with_config(&EmployeeConfig {
+        count,
+        pool_size: pool_size.unwrap_or(DEFAULT_POOL_SIZE),
+        seed,
+        ..Default::default()
+    })
+}
+
+/// Generate employee data with full configuration
+pub fn employees_with_config(config: &EmployeeConfig) -> Vec<EmployeeRow> {
+    let mut rng = create_rng(config.seed);
 
     // Pre-generate pools for performance
-    let actual_pool_size = pool_size.unwrap_or(DEFAULT_POOL_SIZE);
-    let location_pool = LocationPool::generate(&mut rng, actual_pool_size);
-    let name_pool = NamePool::generate(&mut rng, actual_pool_size);
-
-    // Pre-generate region strings
-    let regions: [String; 5] = [
-        "Region 0".to_string(),
-        "Region 1".to_string(),
-        "Region 2".to_string(),
-        "Region 3".to_string(),
-        "Region 4".to_string(),
-    ];
+    let mut pool_rng = pool_rng(config.seed);
+    let location_pool = LocationPool::generate(&mut pool_rng, config.pool_size);
+    let name_pool = NamePool::generate(&mut pool_rng, config.pool_size);
+
+    employee_rows(&mut rng, config, &location_pool, &name_pool)
+}
+
+fn employee_rows<R: Rng>(
+    rng: &mut R,
+    config: &EmployeeConfig,
+    location_pool: &LocationPool,
+    name_pool: &NamePool,
+) -> Vec<EmployeeRow> {
+    let mut data = Vec::with_capacity(config.count);
+    let mut org = EmployeeOrg::new(config);
+    let regions = &config.regions;
 
-    for id in 0..count {
-        let row = EmployeeRow {
+    for id in 0..config.count {
+        let (date_of_birth, start_date, termination_date) = org.employment_dates(rng);
+        let mut row = EmployeeRow {
             row_id: id as i32,
-            employee_id: generate_license_plate(&mut rng),
-            first_name: name_pool.first_names.choose(&mut rng).unwrap().clone(),
-            surname: name_pool.last_names.choose(&mut rng).unwrap().clone(),
-            prefix: PREFIXES.choose(&mut rng).unwrap().to_string(),
-            suffix: SUFFIXES.choose(&mut rng).unwrap().to_string(),
-            phone_number: name_pool.phone_numbers.choose(&mut rng).unwrap().clone(),
-            email: name_pool.emails.choose(&mut rng).unwrap().clone(),
-            ssn: generate_ssn(&mut rng),
-            street: generate_street_address(&mut rng),
-            city: location_pool.random_city(&mut rng).to_string(),
-            postal_code: location_pool.random_zip(&mut rng).to_string(),
-            region: regions[rng.random_range(0..5)].clone(),
-            state: location_pool.random_state(&mut rng).to_string(),
+            employee_id: generate_license_plate(rng),
+            first_name: name_pool.first_names.choose(rng).unwrap().clone(),
+            surname: name_pool.last_names.choose(rng).unwrap().clone(),
+            prefix: PREFIXES.choose(rng).unwrap().to_string(),
+            suffix: SUFFIXES.choose(rng).unwrap().to_string(),
+            phone_number: name_pool.phone_numbers.choose(rng).unwrap().clone(),
+            email: name_pool.emails.choose(rng).unwrap().clone(),
+            ssn: if config.valid_identifiers {
+                generate_valid_ssn(rng)
+            } else {
+                generate_ssn(rng)
+            },
+            street: generate_street_address(rng),
+            city: location_pool.random_city(rng).to_string(),
+            postal_code: location_pool.random_zip(rng).to_string(),
+            region: regions[rng.random_range(0..regions.len())].clone(),
+            state: location_pool.random_state(rng).to_string(),
             country: "US".to_string(),
-            start_date: random_date_30_years(&mut rng),
-            date_of_birth: random_date_of_birth(&mut rng),
+            start_date,
+            date_of_birth,
+            department: String::new(),
+            job_title: String::new(),
+            salary: 0.0,
+            manager_id: None,
+            termination_date,
         };
+        org.place(rng, &mut row);
         data.push(row);
     }
 
@@ -1188,12 +3471,657 @@ mod tests {
         assert_eq!(data.len(), 100);
         for (i, row) in data.iter().enumerate() {
             assert_eq!(row.row_id, i as i32);
-            assert_eq!(row.country, "US");
-            assert!(SHIP_MODES.contains(&row.ship_mode.as_str()));
-            assert!(SEGMENTS.contains(&row.segment.as_str()));
+            assert_eq!(&*row.country, "US");
+            assert!(SHIP_MODES.contains(&&*row.ship_mode));
+            assert!(SEGMENTS.contains(&&*row.segment));
+        }
+    }
+
+    #[test]
+    fn test_low_cardinality_values_are_shared() {
+        let config = SuperstoreConfig {
+            count: 500,
+            seed: Some(42),
+            regions: vec!["North".to_string(), "South".to_string()],
+            ..Default::default()
+        };
+        for rows in [
+            superstore_with_config(&config),
+            superstore_with_config(&SuperstoreConfig {
+                stable_v1: true,
+                ..config.clone()
+            }),
+        ] {
+            for row in &rows {
+                assert!(SHIP_MODES.contains(&&*row.ship_mode));
+                assert!(SEGMENTS.contains(&&*row.segment));
+                assert!(US_SECTORS.contains(&&*row.category));
+                assert!(config.regions.iter().any(|r| **r == *row.region));
+            }
+
+            // Rows with the same value share one allocation
+            let fields: [fn(&SuperstoreRow) -> &Arc<str>; 5] = [
+                |r| &r.ship_mode,
+                |r| &r.segment,
+                |r| &r.country,
+                |r| &r.region,
+                |r| &r.category,
+            ];
+            for field in fields {
+                let mut first: std::collections::HashMap<&str, &Arc<str>> = Default::default();
+                for row in &rows {
+                    let value = field(row);
+                    assert!(Arc::ptr_eq(first.entry(value).or_insert(value), value));
+                }
+            }
+
+            // Serialized as plain strings
+            let json = serde_json::to_value(&rows[0]).unwrap();
+            assert_eq!(json["country"], "US");
+            assert_eq!(json["region"], *rows[0].region);
         }
     }
 
+    #[test]
+    fn test_custom_categories() {
+        let categories = vec![
+            (
+                "Oncology".to_string(),
+                vec!["Biologics".to_string(), "Small Molecules".to_string()],
+            ),
+            ("Vaccines".to_string(), vec!["Pediatric".to_string()]),
+        ];
+        let config = SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            regions: vec!["North".to_string(), "South".to_string()],
+            categories: Some(categories.clone()),
+            regional: RegionalConfig {
+                enable: true,
+                preferences: vec![RegionalPreference {
+                    region: "North".to_string(),
+                    category_weights: vec![("Vaccines".to_string(), 9.0)],
+                }],
+            },
+            ..Default::default()
+        };
+        let rows = superstore_with_config(&config);
+
+        let mut vaccines = std::collections::HashMap::<&str, usize>::new();
+        for row in &rows {
+            let (_, subs) = categories
+                .iter()
+                .find(|(category, _)| **category == *row.category)
+                .unwrap();
+            assert!(subs.contains(&row.sub_category));
+            if &*row.category == "Vaccines" {
+                *vaccines.entry(&row.region).or_default() += 1;
+            }
+        }
+        // 90% of North rows are vaccines against 50% in the South
+        assert!(vaccines["North"] > vaccines["South"] * 3 / 2);
+    }
+
+    #[test]
+    fn test_taxonomy_paths_are_valid() {
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        // "Accessories" appears under both categories with different types
+        let taxonomy = vec![
+            (
+                "Electronics".to_string(),
+                vec![
+                    ("Audio".to_string(), names(&["Headphones", "Speakers"])),
+                    ("Accessories".to_string(), names(&["Cables", "Chargers"])),
+                ],
+            ),
+            (
+                "Apparel".to_string(),
+                vec![(
+                    "Accessories".to_string(),
+                    names(&["Belts", "Hats", "Scarves"]),
+                )],
+            ),
+        ];
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 3000,
+            seed: Some(42),
+            taxonomy: Some(taxonomy.clone()),
+            ..Default::default()
+        });
+
+        let mut seen = std::collections::HashSet::new();
+        for row in &rows {
+            let product_type = row.product_type.as_deref().unwrap();
+            let valid = taxonomy.iter().any(|(category, subs)| {
+                **category == *row.category
+                    && subs.iter().any(|(sub, types)| {
+                        *sub == row.sub_category && types.iter().any(|t| t == product_type)
+                    })
+            });
+            assert!(
+                valid,
+                "{} / {} / {}",
+                row.category, row.sub_category, product_type
+            );
+            seen.insert((row.category.clone(), product_type.to_string()));
+        }
+        // Every path is reachable
+        assert_eq!(seen.len(), 7);
+
+        // Without a taxonomy there is no third level, and the default rows
+        // don't change
+        let plain = superstore(50, Some(42), None);
+        assert!(plain.iter().all(|r| r.product_type.is_none()));
+    }
+
+    #[test]
+    fn test_locales_and_currency() {
+        let config = SuperstoreConfig {
+            count: 500,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let us = superstore_with_config(&config);
+        let de = superstore_with_config(&SuperstoreConfig {
+            locale: "de_DE".to_string(),
+            ..config.clone()
+        });
+        let de_cities = &superstore_locale("de_DE").unwrap().cities;
+        for (a, b) in us.iter().zip(&de) {
+            assert_eq!(&*a.country, "US");
+            assert_eq!(a.currency.as_deref(), Some("USD"));
+            assert_eq!(&*b.country, "DE");
+            assert_eq!(b.currency.as_deref(), Some("EUR"));
+            assert!(de_cities.contains(&b.city.as_str()));
+            assert!(b.postal_code.len() == 5 && b.postal_code.chars().all(|c| c.is_ascii_digit()));
+            // Same rows, converted at the fixed EUR rate
+            assert_eq!(a.order_id, b.order_id);
+            assert!((a.item_price * 0.92 - b.item_price).abs() < 0.01);
+        }
+
+        // The currency can differ from the locale's
+        let jp = superstore_with_config(&SuperstoreConfig {
+            locale: "ja-JP".to_string(),
+            currency: Some("usd".to_string()),
+            ..config.clone()
+        });
+        for (a, b) in us.iter().zip(&jp) {
+            assert_eq!(&*b.country, "JP");
+            assert_eq!(b.currency.as_deref(), Some("USD"));
+            assert_eq!(a.item_price, b.item_price);
+            let (area, local) = b.postal_code.split_once('-').unwrap();
+            assert_eq!((area.len(), local.len()), (3, 4));
+        }
+        let gb = superstore_with_config(&SuperstoreConfig {
+            locale: "en_GB".to_string(),
+            ..config.clone()
+        });
+        assert!(gb.iter().all(|r| r.postal_code.contains(' ')));
+
+        // Unknown locales fall back to en_US
+        let fallback = SuperstoreConfig {
+            locale: "xx_XX".to_string(),
+            ..config.clone()
+        };
+        assert!(!fallback.locale_supported());
+        let rows = superstore_with_config(&fallback);
+        for (a, b) in us.iter().zip(&rows) {
+            assert_eq!(a.city, b.city);
+            assert_eq!(a.item_price, b.item_price);
+        }
+
+        let unknown = SuperstoreConfig {
+            currency: Some("XYZ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            unknown.validate(),
+            Err(SuperstoreConfigError::UnknownCurrency("XYZ".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate_categories() {
+        let with = |categories| SuperstoreConfig {
+            categories: Some(categories),
+            ..Default::default()
+        };
+        assert_eq!(SuperstoreConfig::default().validate(), Ok(()));
+        assert_eq!(
+            with(vec![]).validate(),
+            Err(SuperstoreConfigError::NoCategories)
+        );
+        assert_eq!(
+            with(vec![
+                ("A".to_string(), vec!["a".to_string()]),
+                ("B".to_string(), vec![]),
+            ])
+            .validate(),
+            Err(SuperstoreConfigError::EmptySubCategories("B".to_string()))
+        );
+
+        let taxonomy = |types: Vec<String>| SuperstoreConfig {
+            taxonomy: Some(vec![("A".to_string(), vec![("a".to_string(), types)])]),
+            ..Default::default()
+        };
+        assert_eq!(taxonomy(vec!["t".to_string()]).validate(), Ok(()));
+        assert_eq!(
+            taxonomy(vec![]).validate(),
+            Err(SuperstoreConfigError::EmptyProductTypes {
+                category: "A".to_string(),
+                sub_category: "a".to_string(),
+            })
+        );
+        assert_eq!(
+            SuperstoreConfig {
+                categories: Some(vec![("A".to_string(), vec!["a".to_string()])]),
+                ..taxonomy(vec!["t".to_string()])
+            }
+            .validate(),
+            Err(SuperstoreConfigError::CategoriesAndTaxonomy)
+        );
+    }
+
+    #[test]
+    fn test_order_date_range() {
+        let config = SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            order_date_start: Some("2019-02-01".to_string()),
+            order_date_end: Some("2019-02-28".to_string()),
+            ..Default::default()
+        };
+        let (start, end) = config.order_date_range().unwrap().unwrap();
+        let rows = superstore_with_config(&config);
+        let mut order_dates = std::collections::HashSet::new();
+        for row in &rows {
+            let order = NaiveDate::parse_from_str(&row.order_date, "%Y-%m-%d").unwrap();
+            let ship = NaiveDate::parse_from_str(&row.ship_date, "%Y-%m-%d").unwrap();
+            assert!(start <= order && order <= end);
+            assert!(order <= ship && ship <= end);
+            order_dates.insert(order);
+        }
+        // Uniform over every day, both bounds included
+        assert_eq!(order_dates.len(), 28);
+
+        // A single bound extends to the rest of its year
+        let open_ended = SuperstoreConfig {
+            order_date_end: None,
+            ..config.clone()
+        };
+        assert_eq!(
+            open_ended.order_date_range(),
+            Ok(Some((
+                start,
+                NaiveDate::from_ymd_opt(2019, 12, 31).unwrap()
+            )))
+        );
+        assert_eq!(SuperstoreConfig::default().order_date_range(), Ok(None));
+    }
+
+    #[test]
+    fn test_invalid_order_date_range() {
+        let with = |start: &str, end: &str| SuperstoreConfig {
+            order_date_start: Some(start.to_string()),
+            order_date_end: Some(end.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            with("2019-02-30", "2019-03-01").validate(),
+            Err(SuperstoreConfigError::InvalidOrderDate(
+                "2019-02-30".to_string()
+            ))
+        );
+        assert!(matches!(
+            with("2019-03-02", "2019-03-01").validate(),
+            Err(SuperstoreConfigError::InvalidOrderDateRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_bounds() {
+        let config = SuperstoreConfig {
+            min_quantity: 10,
+            max_quantity: 5,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SuperstoreConfigError::InvalidBounds {
+                field: "quantity",
+                min: 10,
+                max: 5
+            })
+        );
+        let config = SuperstoreConfig {
+            max_discount_percent: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SuperstoreConfigError::InvalidMaxDiscount(0.0))
+        );
+    }
+
+    #[test]
+    fn test_config_warnings() {
+        assert!(SuperstoreConfig::default().warnings().is_empty());
+        assert!(SuperstoreConfig {
+            count: 1_000_000,
+            ..Default::default()
+        }
+        .warnings()
+        .is_empty());
+
+        let config = SuperstoreConfig {
+            count: 1_000_000,
+            pool_size: 10,
+            customers: CustomerConfig {
+                enable_cohorts: true,
+                vip_order_multiplier: 5.0,
+                ..Default::default()
+            },
+            seasonality: SeasonalityConfig {
+                enable: true,
+                q4_multiplier: 1.0,
+                summer_multiplier: 1.0,
+                back_to_school_multiplier: 1.0,
+            },
+            ..Default::default()
+        };
+        let warnings = config.warnings();
+        assert_eq!(warnings.len(), 3, "{:?}", warnings);
+        assert!(warnings[0].contains("low cardinality"));
+        assert!(warnings[1].contains("vip_order_multiplier"));
+        assert!(warnings[2].contains("seasonality"));
+        assert!(config.validate().is_ok());
+
+        // Copula fallback and locale fallback are reported too
+        let config = SuperstoreConfig {
+            sales_quantity_correlation: 0.8,
+            sales_profit_correlation: 0.9,
+            discount_profit_correlation: -0.6,
+            repair_correlations: false,
+            locale: "xx_XX".into(),
+            ..Default::default()
+        };
+        let warnings = config.warnings();
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+        assert!(warnings[0].contains("sampled independently"));
+        assert!(warnings[1].contains("unsupported locale 'xx_XX'"));
+        assert!(warnings[1].contains("ja_JP"));
+    }
+
+    #[test]
+    fn test_msrp_reconciles_with_discount() {
+        for config in [
+            SuperstoreConfig {
+                count: 2000,
+                seed: Some(42),
+                ..Default::default()
+            },
+            SuperstoreConfig {
+                count: 2000,
+                seed: Some(42),
+                max_discount_percent: 90.0,
+                currency: Some("JPY".to_string()),
+                bundling: BundlingConfig {
+                    enable: true,
+                    ..Default::default()
+                },
+                inventory: InventoryConfig {
+                    enable: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ] {
+            for row in superstore_with_config(&config) {
+                let msrp = row.msrp.unwrap();
+                assert!(row.item_price <= msrp);
+                let expected = msrp * (1.0 - row.discount / 100.0);
+                assert!(
+                    (expected - row.item_price).abs() <= 0.01,
+                    "{} * (1 - {}%) != {}",
+                    msrp,
+                    row.discount,
+                    row.item_price
+                );
+            }
+        }
+
+        let stable = superstore_with_config(&SuperstoreConfig {
+            count: 10,
+            seed: Some(42),
+            stable_v1: true,
+            ..Default::default()
+        });
+        assert!(stable.iter().all(|r| r.msrp.is_none()));
+    }
+
+    #[test]
+    fn test_price_elasticity() {
+        // Log-log slope of quantity on item price over regular-status rows,
+        // with prices at or above the reference so quantities stay in range
+        let slope = |elasticity: f64| {
+            let rows = superstore_with_config(&SuperstoreConfig {
+                count: 20000,
+                seed: Some(42),
+                min_sales: 1000,
+                max_sales: 4000,
+                max_quantity: 10000,
+                sales_quantity_correlation: 0.0,
+                promotions: PromotionalConfig {
+                    enable: true,
+                    discount_quantity_correlation: 0.0,
+                    price_elasticity: elasticity,
+                    reference_price: Some(900.0),
+                },
+                ..Default::default()
+            });
+            let points: Vec<(f64, f64)> = rows
+                .iter()
+                .filter(|r| r.item_status == "Regular")
+                .map(|r| (r.item_price.ln(), (r.quantity as f64).ln()))
+                .collect();
+            let n = points.len() as f64;
+            let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+            let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+            let cov: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+            let var: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+            cov / var
+        };
+        for elasticity in [-0.5, -1.2, -2.0] {
+            let measured = slope(elasticity);
+            assert!(
+                (measured - elasticity).abs() < 0.1,
+                "elasticity {} measured as {}",
+                elasticity,
+                measured
+            );
+        }
+        assert!(slope(0.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_price_times_quantity_sales_mode() {
+        let config = SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            sales_mode: SalesMode::PriceTimesQuantity,
+            ..Default::default()
+        };
+        let rows = superstore_with_config(&config);
+        let (low, high) = PRICE_TIMES_QUANTITY_MARGIN_RANGE;
+        for row in &rows {
+            assert_eq!(
+                row.sales,
+                (row.item_price * row.quantity as f64).round() as i32
+            );
+            let margin = row.profit / row.sales as f64;
+            assert!(margin <= high + 1e-3, "margin {}", margin);
+            assert!(margin >= low - 1e-3, "margin {}", margin);
+        }
+
+        // Synthetic sales ignore quantity; revenue doesn't
+        let synthetic = superstore_with_config(&SuperstoreConfig {
+            sales_mode: SalesMode::Synthetic,
+            ..config.clone()
+        });
+        let corr = |rows: &[SuperstoreRow]| {
+            let x: Vec<f64> = rows.iter().map(|r| r.quantity as f64).collect();
+            let y: Vec<f64> = rows.iter().map(|r| r.sales as f64).collect();
+            crate::correlation::pearson_correlation(&x, &y)
+        };
+        assert!(corr(&rows) > corr(&synthetic) + 0.1);
+        for (a, b) in rows.iter().zip(&synthetic) {
+            assert_eq!(a.item_price, b.item_price);
+            assert_eq!(a.quantity, b.quantity);
+        }
+    }
+
+    #[test]
+    fn test_margin_bounds() {
+        for sales_mode in [SalesMode::Synthetic, SalesMode::PriceTimesQuantity] {
+            let config = SuperstoreConfig {
+                count: 5000,
+                seed: Some(42),
+                sales_mode,
+                payment: PaymentConfig {
+                    enable: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let unbounded = superstore_with_config(&config);
+            let rows = superstore_with_config(&SuperstoreConfig {
+                margin_bounds: Some((-0.2, 0.6)),
+                ..config
+            });
+            for (row, original) in rows.iter().zip(&unbounded) {
+                let sales = row.sales as f64;
+                assert!(row.profit >= -0.2 * sales - 0.005, "{:?}", row);
+                assert!(row.profit <= 0.6 * sales + 0.005, "{:?}", row);
+                // Margins already in the band are left alone
+                if original.profit >= -0.2 * sales && original.profit <= 0.6 * sales {
+                    assert_eq!(row.profit, original.profit);
+                }
+            }
+        }
+
+        let config = SuperstoreConfig {
+            margin_bounds: Some((0.5, 0.1)),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SuperstoreConfigError::InvalidMarginBounds { min: 0.5, max: 0.1 })
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_copula_df() {
+        for df in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let config = SuperstoreConfig {
+                copula_df: Some(df),
+                ..Default::default()
+            };
+            assert!(
+                matches!(config.validate(), Err(SuperstoreConfigError::InvalidCopulaDf(v)) if v.is_nan() == df.is_nan()),
+                "{}",
+                df
+            );
+        }
+        let config = SuperstoreConfig {
+            copula_df: Some(3.0),
+            ..Default::default()
+        };
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_pool() {
+        let config = SuperstoreConfig {
+            pool_size: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.validate(), Err(SuperstoreConfigError::EmptyPool));
+        let config = SuperstoreConfig {
+            pool_size: 1,
+            count: 10,
+            ..Default::default()
+        };
+        assert_eq!(superstore_with_config(&config).len(), 10);
+    }
+
+    #[test]
+    fn test_returns() {
+        let config = SuperstoreConfig {
+            count: 5000,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let sales = superstore_with_config(&config);
+        let rows = superstore_with_config(&SuperstoreConfig {
+            returns: ReturnsConfig {
+                enable: true,
+                return_rate: 0.1,
+                ..Default::default()
+            },
+            ..config.clone()
+        });
+
+        // Sales are unchanged and returns follow them
+        assert!(rows.len() > sales.len());
+        for (a, b) in rows.iter().zip(&sales) {
+            assert_eq!(a.order_id, b.order_id);
+            assert_eq!(a.profit, b.profit);
+            assert!(a.original_order_id.is_none());
+        }
+        let returns = &rows[sales.len()..];
+        let rate = returns.len() as f64 / sales.len() as f64;
+        assert!((rate - 0.1).abs() < 0.02, "return rate {}", rate);
+
+        let by_order: std::collections::HashMap<&str, &SuperstoreRow> =
+            sales.iter().map(|r| (r.order_id.as_str(), r)).collect();
+        for (i, ret) in returns.iter().enumerate() {
+            assert_eq!(ret.row_id as usize, sales.len() + i);
+            let sale = by_order[ret.original_order_id.as_deref().unwrap()];
+            assert_eq!(ret.quantity, -sale.quantity);
+            assert!(ret.sales < 0 && -ret.sales <= sale.sales);
+            let reason = ret.return_reason.as_deref().unwrap();
+            assert!(RETURN_REASONS.contains(&reason));
+            if reason != "No Longer Needed" {
+                assert_eq!(ret.sales, -sale.sales);
+            }
+            assert!(ret.return_date.as_deref().unwrap() > sale.ship_date.as_str());
+            assert_eq!(ret.product_id, sale.product_id);
+        }
+
+        // Clearance items come back more often than regular ones
+        let rate_for = |status: &str| {
+            let sold = sales.iter().filter(|r| r.item_status == status).count();
+            let returned = returns.iter().filter(|r| r.item_status == status).count();
+            returned as f64 / sold as f64
+        };
+        assert!(rate_for("Clearance") > rate_for("Regular") * 1.5);
+
+        let invalid = SuperstoreConfig {
+            returns: ReturnsConfig {
+                return_rate: 1.5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            invalid.validate(),
+            Err(SuperstoreConfigError::InvalidRate {
+                field: "returns.return_rate",
+                value: 1.5
+            })
+        );
+    }
+
     #[test]
     fn test_superstore_seeded() {
         let data1 = superstore(10, Some(12345), None);
@@ -1213,10 +4141,423 @@ mod tests {
         assert_eq!(data.len(), 100);
         for (i, row) in data.iter().enumerate() {
             assert_eq!(row.row_id, i as i32);
-            assert_eq!(row.country, "US");
+            assert_eq!(&*row.country, "US");
         }
     }
 
+    #[test]
+    fn test_employee_departments_and_managers() {
+        let config = EmployeeConfig {
+            count: 5000,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let rows = employees_with_config(&config);
+        let by_id: std::collections::HashMap<&str, &EmployeeRow> =
+            rows.iter().map(|r| (r.employee_id.as_str(), r)).collect();
+
+        let mut managers = 0;
+        for (i, row) in rows.iter().enumerate() {
+            match &row.manager_id {
+                Some(manager_id) => {
+                    let manager = by_id[manager_id.as_str()];
+                    assert_eq!(manager.department, row.department);
+                    assert!(manager.manager_id.is_none());
+                    assert!(manager.row_id < i as i32);
+                }
+                None => {
+                    assert_eq!(row.job_title, format!("{} Manager", row.department));
+                    managers += 1;
+                }
+            }
+            assert!(row.salary > 0.0);
+        }
+        let ratio = managers as f64 / rows.len() as f64;
+        assert!((0.08..0.13).contains(&ratio), "manager ratio {}", ratio);
+
+        let median_salary = |department: &str| {
+            let mut salaries: Vec<f64> = rows
+                .iter()
+                .filter(|r| r.department == department && r.manager_id.is_some())
+                .map(|r| r.salary)
+                .collect();
+            salaries.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            salaries[salaries.len() / 2]
+        };
+        let engineering = median_salary("Engineering");
+        let support = median_salary("Support");
+        assert!((engineering / 127_279.0 - 1.0).abs() < 0.1);
+        assert!(engineering > 2.0 * support);
+
+        let custom = employees_with_config(&EmployeeConfig {
+            count: 200,
+            seed: Some(42),
+            departments: vec![Department {
+                name: "Research".to_string(),
+                job_titles: vec!["Scientist".to_string()],
+                salary_range: (80_000.0, 120_000.0),
+            }],
+            manager_ratio: 0.0,
+            ..Default::default()
+        });
+        assert!(custom.iter().all(|r| r.department == "Research"));
+        assert_eq!(custom.iter().filter(|r| r.manager_id.is_none()).count(), 1);
+    }
+
+    #[test]
+    fn test_employee_dates_and_attrition() {
+        let today = Utc::now().naive_utc().date();
+        let rows = employees_with_config(&EmployeeConfig {
+            count: 5000,
+            seed: Some(42),
+            attrition_rate: 0.2,
+            ..Default::default()
+        });
+
+        let mut terminated = 0;
+        for row in &rows {
+            assert!(row.start_date >= years_after(row.date_of_birth, 18));
+            assert!(row.start_date <= today);
+            if years_after(row.date_of_birth, 22) <= row.start_date {
+                assert!(row.start_date >= years_before(today, 30));
+            }
+            if let Some(termination) = row.termination_date {
+                assert!(termination > row.start_date && termination <= today);
+                terminated += 1;
+            }
+        }
+        let ratio = terminated as f64 / rows.len() as f64;
+        assert!((0.17..0.23).contains(&ratio), "attrition {}", ratio);
+
+        let retained = employees_with_config(&EmployeeConfig {
+            count: 500,
+            seed: Some(42),
+            attrition_rate: 0.0,
+            ..Default::default()
+        });
+        assert!(retained.iter().all(|r| r.termination_date.is_none()));
+    }
+
+    #[test]
+    fn test_superstore_with_employees_join() {
+        let config = SuperstoreConfig {
+            count: 500,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let (rows, staff) = superstore_with_employees(&config, 50);
+        assert_eq!(rows.len(), 500);
+        assert_eq!(staff.len(), 50);
+
+        // Employees use the superstore region set
+        assert!(staff.iter().all(|e| config.regions.contains(&e.region)));
+
+        // Joining on region matches rows
+        let matches = rows
+            .iter()
+            .filter(|r| staff.iter().any(|e| *e.region == *r.region))
+            .count();
+        assert!(matches > 0);
+
+        // Same seed reproduces the superstore table alone
+        let alone = superstore_with_config(&config);
+        assert!(rows
+            .iter()
+            .zip(alone.iter())
+            .all(|(a, b)| a.order_id == b.order_id && a.city == b.city));
+    }
+
+    #[test]
+    fn test_employee_regions_match_superstore() {
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 500,
+            seed: Some(1),
+            ..Default::default()
+        });
+        let staff = employees_with_config(&EmployeeConfig {
+            count: 200,
+            seed: Some(1),
+            ..Default::default()
+        });
+        let store_regions: std::collections::HashSet<&str> =
+            rows.iter().map(|r| &*r.region).collect();
+        assert!(staff
+            .iter()
+            .all(|e| store_regions.contains(e.region.as_str())));
+    }
+
+    #[test]
+    fn test_pool_size_does_not_shift_rows() {
+        let generate = |pool_size| {
+            superstore_with_config(&SuperstoreConfig {
+                count: 300,
+                seed: Some(42),
+                pool_size,
+                ..Default::default()
+            })
+        };
+        let small = generate(10);
+        let large = generate(5000);
+        for (a, b) in small.iter().zip(&large) {
+            assert_eq!(a.order_id, b.order_id);
+            assert_eq!(a.order_date, b.order_date);
+            assert_eq!(a.sales, b.sales);
+            assert_eq!(a.quantity, b.quantity);
+            assert_eq!(a.profit, b.profit);
+        }
+        // Only the pooled location values change
+        let cities = |rows: &[SuperstoreRow]| {
+            rows.iter()
+                .map(|r| r.city.clone())
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        };
+        assert!(cities(&small) <= cities(&large));
+    }
+
+    #[test]
+    fn test_mask_employee_pii() {
+        let raw = employees(200, Some(42), None);
+        let mut masked = raw.clone();
+        mask_employee_pii(&mut masked, &PiiMaskConfig::all());
+
+        for (r, m) in raw.iter().zip(masked.iter()) {
+            assert_eq!(m.ssn, format!("***-**-{}", &r.ssn[7..]));
+            assert!(m.phone_number.starts_with("(***) ***-"));
+            assert!(r.phone_number.ends_with(&m.phone_number[10..]));
+            let (token, domain) = m.email.split_once('@').unwrap();
+            assert_eq!(token.len(), 16);
+            assert!(r.email.ends_with(&format!("@{}", domain)));
+            assert_eq!(m.email, mask_email(&r.email));
+        }
+
+        // No raw values survive
+        for r in &raw {
+            assert!(masked
+                .iter()
+                .all(|m| m.ssn != r.ssn && m.email != r.email && m.phone_number != r.phone_number));
+        }
+
+        // Masking is per field
+        let mut ssn_only = raw.clone();
+        mask_employee_pii(
+            &mut ssn_only,
+            &PiiMaskConfig {
+                ssn: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(ssn_only[0].email, raw[0].email);
+        assert_ne!(ssn_only[0].ssn, raw[0].ssn);
+    }
+
+    #[test]
+    fn test_valid_identifiers() {
+        let data = employees_with_config(&EmployeeConfig {
+            count: 5000,
+            seed: Some(42),
+            valid_identifiers: true,
+            ..Default::default()
+        });
+        for row in &data {
+            assert!(is_valid_ssn(&row.ssn), "invalid SSN {}", row.ssn);
+        }
+
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            valid_identifiers: true,
+            ..Default::default()
+        });
+        assert!(rows.iter().all(|r| is_valid_ein(&r.order_id)));
+
+        assert!(!is_valid_ssn("000-12-3456"));
+        assert!(!is_valid_ssn("666-12-3456"));
+        assert!(!is_valid_ssn("912-12-3456"));
+        assert!(!is_valid_ssn("123-00-3456"));
+        assert!(!is_valid_ssn("123-45-0000"));
+        assert!(is_valid_ssn("123-45-6789"));
+        assert!(!is_valid_ein("07-1234567"));
+        assert!(is_valid_ein("12-3456789"));
+    }
+
+    #[test]
+    fn test_card_numbers_luhn_valid() {
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            payment: PaymentConfig {
+                enable: true,
+                card_numbers: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let mut n_cards = 0;
+        for row in &rows {
+            let method = row.payment_method.as_deref().unwrap();
+            let is_card = method == "credit_card" || method == "debit_card";
+            assert_eq!(row.card_number.is_some(), is_card);
+            let (Some(pan), Some(network)) = (&row.card_number, &row.card_network) else {
+                continue;
+            };
+            n_cards += 1;
+
+            assert!(luhn_valid(pan), "{} fails Luhn", pan);
+            match network.as_str() {
+                "Visa" => assert!(pan.len() == 16 && pan.starts_with('4')),
+                "Mastercard" => {
+                    assert!(pan.len() == 16 && (pan.starts_with('5') || pan.starts_with("22")))
+                }
+                "Amex" => {
+                    assert!(pan.len() == 15 && (pan.starts_with("34") || pan.starts_with("37")));
+                    assert_eq!(method, "credit_card");
+                }
+                other => panic!("unexpected network {}", other),
+            }
+        }
+        assert!(n_cards > 1000);
+
+        assert!(luhn_valid("4111111111111111"));
+        assert!(luhn_valid("378282246310005"));
+        assert!(!luhn_valid("4111111111111112"));
+    }
+
+    #[test]
+    fn test_settlement_delay_by_method() {
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 3000,
+            seed: Some(42),
+            payment: PaymentConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let mut delays: std::collections::HashMap<String, Vec<i64>> =
+            std::collections::HashMap::new();
+        for row in &rows {
+            let order = NaiveDate::parse_from_str(&row.order_date, "%Y-%m-%d").unwrap();
+            let settled =
+                NaiveDate::parse_from_str(row.settlement_date.as_deref().unwrap(), "%Y-%m-%d")
+                    .unwrap();
+            delays
+                .entry(row.payment_method.clone().unwrap())
+                .or_default()
+                .push((settled - order).num_days());
+        }
+        let mean = |method: &str| {
+            let d = &delays[method];
+            d.iter().sum::<i64>() as f64 / d.len() as f64
+        };
+
+        assert!(delays["cash_on_delivery"].iter().all(|&d| d == 0));
+        assert!(delays["gift_card"].iter().all(|&d| d == 0));
+        let bank_min = *delays["bank_transfer"].iter().min().unwrap();
+        for method in [
+            "credit_card",
+            "debit_card",
+            "paypal",
+            "apple_pay",
+            "google_pay",
+            "gift_card",
+            "cash_on_delivery",
+        ] {
+            assert!(delays[method].iter().all(|&d| d <= bank_min));
+            assert!(mean(method) < mean("bank_transfer"));
+        }
+        assert!(mean("debit_card") <= mean("credit_card"));
+    }
+
+    #[test]
+    fn test_chargebacks_concentrated_in_fraud() {
+        let config = SuperstoreConfig {
+            count: 20000,
+            seed: Some(42),
+            payment: PaymentConfig {
+                enable: true,
+                chargebacks: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let rows = superstore_with_config(&config);
+        let chargebacks = generate_chargebacks(&rows, &config.payment, Some(42));
+
+        let charged: std::collections::HashSet<&str> =
+            chargebacks.iter().map(|c| c.order_id.as_str()).collect();
+        let rate = |fraud: bool| {
+            let group: Vec<&SuperstoreRow> =
+                rows.iter().filter(|r| r.is_fraud == Some(fraud)).collect();
+            let hits = group
+                .iter()
+                .filter(|r| charged.contains(r.order_id.as_str()))
+                .count();
+            hits as f64 / group.len() as f64
+        };
+
+        let (fraud_rate, legit_rate) = (rate(true), rate(false));
+        assert!(fraud_rate > 0.5, "fraud chargeback rate {}", fraud_rate);
+        assert!(legit_rate < 0.02, "legit chargeback rate {}", legit_rate);
+        assert!(fraud_rate > 20.0 * legit_rate);
+
+        for cb in &chargebacks {
+            assert!(cb.amount > 0.0);
+            assert!(cb.chargeback_date.len() == 10);
+        }
+
+        // Disabled by default
+        assert!(generate_chargebacks(&rows, &PaymentConfig::default(), Some(42)).is_empty());
+
+        let (paired_rows, paired) = superstore_with_chargebacks(&config);
+        assert_eq!(paired_rows.len(), rows.len());
+        let order_ids: std::collections::HashSet<&str> =
+            paired_rows.iter().map(|r| r.order_id.as_str()).collect();
+        assert!(!paired.is_empty());
+        assert!(paired
+            .iter()
+            .all(|c| order_ids.contains(c.order_id.as_str())));
+        assert_eq!(superstore_with_chargebacks(&config).1.len(), paired.len());
+    }
+
+    #[test]
+    fn test_processing_fee_fixed_and_minimum() {
+        let config = PaymentConfig {
+            enable: true,
+            fixed_fee: 0.30,
+            min_fee: 0.50,
+            ..Default::default()
+        };
+
+        // $1 sale: 2.9% + $0.30 = $0.33, floored at the $0.50 minimum
+        assert_eq!(
+            processing_fee(1.0, &PaymentMethod::CreditCard, &config),
+            0.50
+        );
+        let no_min = PaymentConfig {
+            min_fee: 0.0,
+            ..config.clone()
+        };
+        let fee = processing_fee(1.0, &PaymentMethod::CreditCard, &no_min);
+        assert_eq!(fee, 0.33);
+        assert!(fee >= no_min.fixed_fee);
+
+        // Large sale: percentage dominates
+        assert_eq!(
+            processing_fee(1000.0, &PaymentMethod::CreditCard, &config),
+            29.30
+        );
+        // Fee-free methods stay free
+        assert_eq!(processing_fee(1.0, &PaymentMethod::GiftCard, &config), 0.0);
+        // Defaults keep the pure percentage fee
+        assert_eq!(
+            processing_fee(100.0, &PaymentMethod::CreditCard, &PaymentConfig::default()),
+            2.9
+        );
+    }
+
     #[test]
     fn test_employees_seeded() {
         let data1 = employees(10, Some(54321), None);
@@ -1229,4 +4570,212 @@ mod tests {
             assert_eq!(r1.ssn, r2.ssn);
         }
     }
+
+    #[test]
+    fn test_superstore_batch_distinct_and_reproducible() {
+        let config = SuperstoreConfig {
+            count: 50,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let batch1 = superstore_batch(&config, 3);
+        let batch2 = superstore_batch(&config, 3);
+        assert_eq!(batch1.len(), 3);
+
+        let ids = |rows: &[SuperstoreRow]| -> Vec<String> {
+            rows.iter().map(|r| r.order_id.clone()).collect()
+        };
+        for (a, b) in batch1.iter().zip(batch2.iter()) {
+            assert_eq!(a.len(), 50);
+            assert_eq!(ids(a), ids(b));
+        }
+        assert_ne!(ids(&batch1[0]), ids(&batch1[1]));
+        assert_ne!(ids(&batch1[1]), ids(&batch1[2]));
+    }
+
+    #[test]
+    fn test_superstore_row_at_matches_full_run() {
+        let mut config = SuperstoreConfig {
+            count: 500,
+            seed: Some(42),
+            per_row_seeding: true,
+            ..Default::default()
+        };
+        config.customers.enable_cohorts = true;
+        config.payment.enable = true;
+        let rows = superstore_with_config(&config);
+
+        for index in [0, 1, 137, 256, 499] {
+            let row = superstore_row_at(&config, index);
+            let expected = &rows[index];
+            assert_eq!(row.row_id, expected.row_id);
+            assert_eq!(row.order_id, expected.order_id);
+            assert_eq!(row.order_date, expected.order_date);
+            assert_eq!(row.customer_id, expected.customer_id);
+            assert_eq!(row.city, expected.city);
+            assert_eq!(row.sales, expected.sales);
+            assert_eq!(row.profit, expected.profit);
+            assert_eq!(row.payment_method, expected.payment_method);
+        }
+
+        // Rows don't depend on how many come before or after them
+        let longer = superstore_with_config(&SuperstoreConfig {
+            count: 800,
+            ..config.clone()
+        });
+        assert_eq!(longer[499].order_id, rows[499].order_id);
+    }
+
+    #[test]
+    fn test_student_t_copula_joint_crashes() {
+        // Share of orders in the bottom 2% of both sales and quantity
+        fn joint_lows(rows: &[SuperstoreRow]) -> usize {
+            let cutoff = |mut values: Vec<i32>| {
+                values.sort_unstable();
+                values[values.len() / 50]
+            };
+            let sales = cutoff(rows.iter().map(|r| r.sales).collect());
+            let quantity = cutoff(rows.iter().map(|r| r.quantity).collect());
+            rows.iter()
+                .filter(|r| r.sales < sales && r.quantity < quantity)
+                .count()
+        }
+
+        let config = SuperstoreConfig {
+            count: 20_000,
+            seed: Some(42),
+            ..Default::default()
+        };
+        let gaussian = superstore_with_config(&config);
+        let student = superstore_with_config(&SuperstoreConfig {
+            copula_df: Some(2.0),
+            ..config
+        });
+        let (g, t) = (joint_lows(&gaussian), joint_lows(&student));
+        assert!(t > g + g / 2, "t {} vs gaussian {}", t, g);
+    }
+
+    #[test]
+    fn test_discount_profit_correlation_matches_config() {
+        let corr = |rows: &[SuperstoreRow], y: fn(&SuperstoreRow) -> f64| {
+            let discount: Vec<f64> = rows.iter().map(|r| r.discount).collect();
+            let y: Vec<f64> = rows.iter().map(y).collect();
+            crate::correlation::pearson_correlation(&discount, &y)
+        };
+        for target in [-0.6, -0.4, 0.0, 0.3] {
+            let config = SuperstoreConfig {
+                count: 20_000,
+                seed: Some(42),
+                discount_profit_correlation: target,
+                ..Default::default()
+            };
+            let rows = superstore_with_config(&config);
+            let realized = corr(&rows, |r| r.profit);
+            assert!(
+                (realized - target).abs() < 0.05,
+                "{} vs {}",
+                realized,
+                target
+            );
+
+            // Revenue-based profit scales with sales, so the margin carries it
+            let rows = superstore_with_config(&SuperstoreConfig {
+                sales_mode: SalesMode::PriceTimesQuantity,
+                ..config
+            });
+            let realized = corr(&rows, |r| r.profit / r.sales as f64);
+            assert!(
+                (realized - target).abs() < 0.05,
+                "{} vs {}",
+                realized,
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_correlations_are_repaired() {
+        let sales_profit = |rows: &[SuperstoreRow]| {
+            let sales: Vec<f64> = rows.iter().map(|r| r.sales as f64).collect();
+            let profit: Vec<f64> = rows.iter().map(|r| r.profit).collect();
+            crate::correlation::pearson_correlation(&sales, &profit)
+        };
+
+        assert_eq!(
+            superstore_correlation_repair(&SuperstoreConfig::default()),
+            None
+        );
+
+        // Each correlation is valid on its own but together they aren't PSD
+        let config = SuperstoreConfig {
+            count: 5000,
+            seed: Some(42),
+            sales_quantity_correlation: 0.8,
+            sales_profit_correlation: 0.9,
+            discount_profit_correlation: -0.6,
+            ..Default::default()
+        };
+        let distance = superstore_correlation_repair(&config).unwrap();
+        assert!(distance > 0.0 && distance < 0.2, "{}", distance);
+
+        let repaired = superstore_with_config(&config);
+        let dropped = superstore_with_config(&SuperstoreConfig {
+            repair_correlations: false,
+            ..config
+        });
+        assert!(sales_profit(&repaired) > 0.5, "{}", sales_profit(&repaired));
+        assert!(sales_profit(&dropped) < sales_profit(&repaired) - 0.3);
+    }
+
+    #[test]
+    fn test_diagnostics_report_copula_fallback() {
+        let config = SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            sales_quantity_correlation: 0.8,
+            sales_profit_correlation: 0.9,
+            discount_profit_correlation: -0.6,
+            repair_correlations: false,
+            ..Default::default()
+        };
+        let (rows, diagnostics) = superstore_with_diagnostics(&config);
+        assert_eq!(diagnostics.copula, None);
+        assert_eq!(diagnostics.correlation_repair_distance, None);
+        let expected = superstore_with_config(&config);
+        assert_eq!(
+            serde_json::to_string(&rows).unwrap(),
+            serde_json::to_string(&expected).unwrap()
+        );
+
+        let (_, repaired) = superstore_with_diagnostics(&SuperstoreConfig {
+            repair_correlations: true,
+            ..config.clone()
+        });
+        assert_eq!(repaired.copula, Some(CopulaKind::Gaussian));
+        assert!(repaired.correlation_repair_distance.unwrap() > 0.0);
+
+        let (rows, clamped) = superstore_with_diagnostics(&SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            max_quantity: 2,
+            copula_df: Some(5.0),
+            margin_bounds: Some((0.0, 0.1)),
+            ..Default::default()
+        });
+        assert_eq!(clamped.copula, Some(CopulaKind::StudentT));
+        assert!(clamped.clamped_quantities.unwrap() > 0);
+        assert!(clamped.clamped_margins > 0);
+        assert_eq!(clamped.segment_counts.values().sum::<usize>(), rows.len());
+        assert_eq!(clamped.segment_counts.len(), SEGMENTS.len());
+        assert!(clamped.empty_segments.is_empty());
+
+        let (_, tiny) = superstore_with_diagnostics(&SuperstoreConfig {
+            count: 1,
+            seed: Some(42),
+            stable_v1: true,
+            ..Default::default()
+        });
+        assert_eq!(tiny.clamped_quantities, None);
+        assert_eq!(tiny.empty_segments.len(), SEGMENTS.len() - 1);
+    }
 }
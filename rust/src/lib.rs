@@ -9,7 +9,10 @@ pub mod finance;
 pub mod general;
 pub mod logs;
 pub mod parallel;
+pub mod quantiles;
+pub mod rng;
 pub mod streaming;
+pub mod subscriptions;
 pub mod temporal;
 pub mod timeseries;
 pub mod utils;
@@ -26,7 +29,10 @@ pub use finance::*;
 pub use general::*;
 pub use logs::*;
 pub use parallel::*;
+pub use quantiles::*;
+pub use rng::*;
 pub use streaming::*;
+pub use subscriptions::*;
 pub use temporal::*;
 pub use timeseries::*;
 pub use utils::*;
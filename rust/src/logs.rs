@@ -1,16 +1,21 @@
 //! Web server and application logs generator module.
 //!
 //! Generates realistic log data with:
-//! - Poisson-driven request arrival times
+//! - Poisson-driven request arrival times, optionally modulated by a
+//!   diurnal curve and traffic spikes
 //! - HTTP status code sequences via Markov chains
-//! - Log-normal latency distributions with outliers
+//! - Log-normal latency distributions with outliers, slower for 5xx and
+//!   faster for 304 responses
 //! - Error bursts via clustered Poisson
-//! - Realistic user agents, IPs, and request paths
+//! - Deploy events followed by a decaying spike in 5xx errors
+//! - Distributed traces: nested spans across services sharing a trace ID
+//! - Realistic user agents, IPs, and Zipf-distributed request paths
+//! - Client geo (country/city), IPv4/IPv6 mix, and bot IP ranges
 
-use chrono::{Duration, NaiveDateTime, Utc};
-use rand::rngs::StdRng;
+use crate::rng::{seeded_rng, SeededRng};
+use chrono::{Duration, NaiveDateTime, Timelike, Utc};
 use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
 use rand_distr::{Distribution, Exp, LogNormal};
 use serde::{Deserialize, Serialize};
 
@@ -20,52 +25,121 @@ use crate::temporal::MarkovChain;
 // Constants
 // =============================================================================
 
+// Browsers, mobile, and API clients used by regular (non-bot) traffic
 const USER_AGENTS: &[&str] = &[
     "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 Chrome/120.0.0.0",
     "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 Safari/537.36",
     "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15",
     "Mozilla/5.0 (Linux; Android 14) AppleWebKit/537.36 Chrome/120.0.0.0",
     "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:120.0) Gecko/20100101 Firefox/120.0",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 Chrome/120.0.0.0 Edg/120.0.0.0",
+    "Mozilla/5.0 (iPad; CPU OS 17_0 like Mac OS X) AppleWebKit/605.1.15",
     "curl/8.4.0",
     "python-requests/2.31.0",
     "Go-http-client/2.0",
     "PostmanRuntime/7.35.0",
     "Apache-HttpClient/4.5.14",
 ];
+// Per-mille shares of USER_AGENTS
+const USER_AGENT_WEIGHTS: &[u32] = &[300, 120, 180, 160, 60, 60, 40, 20, 20, 10, 20, 10];
+
+// Crawler user agents, one per entry in BOT_RANGES
+const CRAWLER_USER_AGENTS: &[&str] = &[
+    "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+    "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)",
+    "Mozilla/5.0 (compatible; AhrefsBot/7.0; +http://ahrefs.com/robot/)",
+    "Mozilla/5.0 (compatible; YandexBot/3.0; +http://yandex.com/bots)",
+    "Mozilla/5.0 (compatible; Baiduspider/2.0; +http://www.baidu.com/search/spider.html)",
+];
 
 const HTTP_METHODS: &[&str] = &["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS"];
 const HTTP_METHOD_WEIGHTS: &[f64] = &[0.65, 0.20, 0.05, 0.03, 0.02, 0.03, 0.02];
 
 const LOG_LEVELS: &[&str] = &["DEBUG", "INFO", "WARN", "ERROR", "FATAL"];
 
-// Common request paths by category
+// (country, city, weight) for regular client IPs
+const GEO_LOCATIONS: &[(&str, &str, f64)] = &[
+    ("US", "New York", 0.12),
+    ("US", "San Francisco", 0.08),
+    ("US", "Chicago", 0.06),
+    ("US", "Dallas", 0.05),
+    ("CA", "Toronto", 0.05),
+    ("GB", "London", 0.09),
+    ("DE", "Berlin", 0.06),
+    ("FR", "Paris", 0.05),
+    ("NL", "Amsterdam", 0.03),
+    ("IN", "Bangalore", 0.08),
+    ("JP", "Tokyo", 0.06),
+    ("SG", "Singapore", 0.04),
+    ("AU", "Sydney", 0.04),
+    ("BR", "Sao Paulo", 0.05),
+    ("MX", "Mexico City", 0.03),
+    ("ZA", "Johannesburg", 0.02),
+    ("KR", "Seoul", 0.03),
+    ("ES", "Madrid", 0.03),
+    ("IT", "Milan", 0.03),
+];
+
+// Bot/crawler address ranges (/24 prefix, country, city)
+const BOT_RANGES: &[(&str, &str, &str)] = &[
+    ("66.249.66", "US", "Mountain View"),
+    ("40.77.167", "US", "Redmond"),
+    ("52.70.240", "US", "Ashburn"),
+    ("5.255.253", "RU", "Moscow"),
+    ("220.181.108", "CN", "Beijing"),
+];
+
+// Root spans enter through the gateway; downstream spans use the rest
+const SERVICES: &[&str] = &[
+    "api-gateway",
+    "user-service",
+    "order-service",
+    "inventory-service",
+    "payment-service",
+    "cache",
+    "database",
+];
+
+// Common request paths by category, most popular first
 const API_PATHS: &[&str] = &[
-    "/api/v1/users",
-    "/api/v1/users/{id}",
     "/api/v1/products",
     "/api/v1/products/{id}",
-    "/api/v1/orders",
-    "/api/v1/orders/{id}",
-    "/api/v1/cart",
-    "/api/v1/checkout",
     "/api/v1/search",
-    "/api/v1/auth/login",
-    "/api/v1/auth/logout",
-    "/api/v1/auth/refresh",
-    "/api/v2/graphql",
     "/health",
+    "/api/v1/cart",
+    "/api/v1/users/{id}",
+    "/api/v2/graphql",
+    "/api/v1/auth/refresh",
+    "/api/v1/orders",
+    "/api/v1/auth/login",
     "/metrics",
+    "/api/v1/orders/{id}",
+    "/api/v1/checkout",
+    "/api/v1/users",
     "/ready",
+    "/api/v1/auth/logout",
 ];
 
-const STATIC_PATHS: &[&str] = &[
+// Pages and static assets, most popular first; followed by a generated
+// long tail of LONG_TAIL_PAGES blog posts
+const PAGE_PATHS: &[&str] = &[
+    "/",
+    "/products",
+    "/search",
     "/static/js/main.js",
     "/static/css/styles.css",
-    "/static/images/logo.png",
+    "/cart",
     "/favicon.ico",
+    "/login",
+    "/static/images/logo.png",
+    "/checkout",
+    "/about",
     "/robots.txt",
     "/sitemap.xml",
+    "/contact",
+    "/help",
 ];
+const LONG_TAIL_PAGES: usize = 200;
 
 // =============================================================================
 // Configuration
@@ -111,6 +185,101 @@ impl Default for ErrorBurstConfig {
     }
 }
 
+/// Configuration for time-varying traffic volume
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrafficConfig {
+    /// Modulate the request rate over the day (otherwise it is constant)
+    pub enable: bool,
+    /// Hour of day (0-24) with the most traffic
+    pub peak_hour: f64,
+    /// Ratio of the request rate at the peak hour to the quietest hour
+    pub peak_to_trough: f64,
+    /// Average traffic spikes per day (Poisson arrivals)
+    pub spikes_per_day: f64,
+    pub spike_duration_seconds: u32,
+    /// Rate multiplier while a spike is active
+    pub spike_multiplier: f64,
+}
+
+impl Default for TrafficConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            peak_hour: 14.0,
+            peak_to_trough: 5.0,
+            spikes_per_day: 2.0,
+            spike_duration_seconds: 600,
+            spike_multiplier: 3.0,
+        }
+    }
+}
+
+/// Configuration for client addresses, geo, and bot traffic
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClientConfig {
+    /// Share of regular client IPs that are IPv6
+    pub ipv6_ratio: f64,
+    /// Share of requests from crawlers (bot IP ranges, crawler user agents)
+    pub bot_traffic_share: f64,
+    /// Distinct IPs per bot range (few IPs, so each sends many requests)
+    pub bot_ips_per_range: usize,
+    /// Probability a bot request gets a 4xx response
+    pub bot_client_error_rate: f64,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            ipv6_ratio: 0.1,
+            bot_traffic_share: 0.05,
+            bot_ips_per_range: 2,
+            bot_client_error_rate: 0.3,
+        }
+    }
+}
+
+/// Configuration for deploy events and their post-deploy error spikes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeployConfig {
+    pub enable: bool,
+    /// Average deploys per hour (Poisson arrivals)
+    pub deploys_per_hour: f64,
+    /// How long after a deploy requests count as post-deploy
+    pub impact_duration_seconds: u32,
+    /// Extra 5xx rate right after a deploy, decaying linearly to zero
+    /// over the impact window
+    pub post_deploy_error_rate: f64,
+}
+
+impl Default for DeployConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            deploys_per_hour: 2.0,
+            impact_duration_seconds: 300,
+            post_deploy_error_rate: 0.3,
+        }
+    }
+}
+
+/// Configuration for distributed trace shape in application logs
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TraceConfig {
+    /// Maximum span depth, counting the root span
+    pub max_depth: usize,
+    /// Maximum child spans per span
+    pub max_children: usize,
+}
+
+impl Default for TraceConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            max_children: 3,
+        }
+    }
+}
+
 /// Configuration for latency simulation
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LatencyConfig {
@@ -118,6 +287,10 @@ pub struct LatencyConfig {
     pub latency_stddev: f64,
     pub slow_request_probability: f64,
     pub slow_request_multiplier: f64,
+    /// Latency multiplier for 5xx responses (slow/timeout paths)
+    pub server_error_multiplier: f64,
+    /// Latency multiplier for 304 Not Modified responses
+    pub not_modified_multiplier: f64,
 }
 
 impl Default for LatencyConfig {
@@ -127,6 +300,8 @@ impl Default for LatencyConfig {
             latency_stddev: 0.8,
             slow_request_probability: 0.05,
             slow_request_multiplier: 10.0,
+            server_error_multiplier: 5.0,
+            not_modified_multiplier: 0.2,
         }
     }
 }
@@ -138,15 +313,24 @@ pub struct LogsConfig {
     pub seed: Option<u64>,
     pub start_time: Option<String>,
     pub format: LogFormat,
+    /// Request rate; with `traffic` enabled, the rate at the diurnal peak
     pub requests_per_second: f64,
+    pub traffic: TrafficConfig,
     pub success_rate: f64,
     pub error_burst: ErrorBurstConfig,
+    pub deploys: DeployConfig,
+    pub traces: TraceConfig,
     pub latency: LatencyConfig,
     pub include_user_agent: bool,
     pub include_referer: bool,
     pub unique_ips: usize,
     pub unique_users: usize,
     pub api_path_ratio: f64,
+    /// Zipf exponent of endpoint popularity (0 = uniform)
+    pub endpoint_zipf_exponent: f64,
+    /// Share of successful static-asset GETs answered with 304 Not Modified
+    pub not_modified_rate: f64,
+    pub clients: ClientConfig,
 }
 
 impl Default for LogsConfig {
@@ -157,14 +341,20 @@ impl Default for LogsConfig {
             start_time: None,
             format: LogFormat::Combined,
             requests_per_second: 100.0,
+            traffic: TrafficConfig::default(),
             success_rate: 0.95,
             error_burst: ErrorBurstConfig::default(),
+            deploys: DeployConfig::default(),
+            traces: TraceConfig::default(),
             latency: LatencyConfig::default(),
             include_user_agent: true,
             include_referer: true,
             unique_ips: 1000,
             unique_users: 500,
             api_path_ratio: 0.7,
+            endpoint_zipf_exponent: 1.0,
+            not_modified_rate: 0.3,
+            clients: ClientConfig::default(),
         }
     }
 }
@@ -178,6 +368,8 @@ impl Default for LogsConfig {
 pub struct LogEntry {
     pub timestamp: String,
     pub ip_address: String,
+    pub country: String,
+    pub city: String,
     pub user_id: Option<String>,
     pub method: String,
     pub path: String,
@@ -185,7 +377,21 @@ pub struct LogEntry {
     pub response_bytes: u32,
     pub latency_ms: f64,
     pub user_agent: Option<String>,
+    /// Request came from a crawler (bot IP range and crawler user agent)
+    pub is_bot: bool,
     pub referer: Option<String>,
+    /// Most recent deploy at the time of the request (when deploys are enabled)
+    pub deploy_id: Option<String>,
+    /// Whether the request falls within a deploy's impact window
+    pub post_deploy: bool,
+}
+
+/// A deploy event in the logs' deploy sub-stream
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeployEvent {
+    pub deploy_id: String,
+    pub timestamp: String,
+    pub version: String,
 }
 
 /// Application event log entry
@@ -196,8 +402,14 @@ pub struct AppLogEntry {
     pub logger: String,
     pub message: String,
     pub thread_id: u32,
+    /// Service that emitted the span
+    pub service: String,
     pub trace_id: Option<String>,
     pub span_id: Option<String>,
+    /// Parent span (None for the root span of a trace)
+    pub parent_span_id: Option<String>,
+    /// Span duration; the span starts at `timestamp`
+    pub duration_ms: Option<f64>,
     pub exception: Option<String>,
 }
 
@@ -205,14 +417,11 @@ pub struct AppLogEntry {
 // Helper Functions
 // =============================================================================
 
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    }
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
 }
 
-fn generate_ip_pool(rng: &mut StdRng, count: usize) -> Vec<String> {
+fn generate_ip_pool(rng: &mut SeededRng, count: usize) -> Vec<String> {
     (0..count)
         .map(|_| {
             format!(
@@ -226,7 +435,89 @@ fn generate_ip_pool(rng: &mut StdRng, count: usize) -> Vec<String> {
         .collect()
 }
 
-fn generate_user_pool(rng: &mut StdRng, count: usize) -> Vec<String> {
+/// Pair each regular client IP with a geo location, rewriting a share of
+/// them as IPv6.
+///
+/// Uses its own RNG so the IPv4 pool (and everything drawn after it from
+/// the main RNG) is the same for any client configuration.
+fn assign_client_geo(
+    rng: &mut SeededRng,
+    ip_pool: Vec<String>,
+    ipv6_ratio: f64,
+) -> Vec<(String, &'static str, &'static str)> {
+    let weights: Vec<f64> = GEO_LOCATIONS.iter().map(|g| g.2).collect();
+    ip_pool
+        .into_iter()
+        .map(|mut ip| {
+            if rng.random::<f64>() < ipv6_ratio {
+                ip = format!(
+                    "2a0{}:{:x}:{:x}:{:x}::{:x}",
+                    rng.random_range(0..10),
+                    rng.random::<u16>(),
+                    rng.random::<u16>(),
+                    rng.random::<u16>(),
+                    rng.random::<u16>()
+                );
+            }
+            let (country, city, _) = GEO_LOCATIONS[weighted_choice(rng, &weights)];
+            (ip, country, city)
+        })
+        .collect()
+}
+
+/// A bot client: address in a bot range, that range's geo and crawler
+struct BotClient {
+    ip: String,
+    country: &'static str,
+    city: &'static str,
+    user_agent: &'static str,
+}
+
+/// A few fixed addresses in each bot range
+fn generate_bot_pool(rng: &mut SeededRng, per_range: usize) -> Vec<BotClient> {
+    BOT_RANGES
+        .iter()
+        .zip(CRAWLER_USER_AGENTS)
+        .flat_map(|((prefix, country, city), user_agent)| {
+            (0..per_range.max(1))
+                .map(|_| BotClient {
+                    ip: format!("{}.{}", prefix, rng.random_range(1..255)),
+                    country,
+                    city,
+                    user_agent,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Draw a regular (non-bot) user agent by its per-mille share
+fn pick_user_agent<R: Rng>(rng: &mut R) -> &'static str {
+    let total: u32 = USER_AGENT_WEIGHTS.iter().sum();
+    let mut roll = rng.random_range(0..total);
+    for (ua, &weight) in USER_AGENTS.iter().zip(USER_AGENT_WEIGHTS) {
+        if roll < weight {
+            return ua;
+        }
+        roll -= weight;
+    }
+    USER_AGENTS[0]
+}
+
+/// Whether a user agent string identifies a crawler
+pub fn is_crawler_user_agent(user_agent: &str) -> bool {
+    let ua = user_agent.to_lowercase();
+    ["bot", "spider", "crawler"].iter().any(|t| ua.contains(t))
+}
+
+/// Whether an address falls in one of the known bot ranges
+pub fn is_bot_ip(ip: &str) -> bool {
+    BOT_RANGES
+        .iter()
+        .any(|(prefix, _, _)| ip.strip_prefix(prefix).is_some_and(|r| r.starts_with('.')))
+}
+
+fn generate_user_pool(rng: &mut SeededRng, count: usize) -> Vec<String> {
     (0..count)
         .map(|_| format!("user_{:06}", rng.random_range(100000..999999)))
         .collect()
@@ -306,19 +597,54 @@ fn create_status_code_chain() -> MarkovChain {
     MarkovChain::new(matrix, states).expect("Invalid Markov chain")
 }
 
-fn generate_path<R: Rng>(rng: &mut R, api_ratio: f64) -> String {
-    if rng.random::<f64>() < api_ratio {
-        let path = API_PATHS.choose(rng).unwrap_or(&"/api/v1/users");
-        // Replace {id} placeholders with random IDs
-        path.replace("{id}", &format!("{}", rng.random_range(1..10000)))
-    } else {
-        STATIC_PATHS
-            .choose(rng)
-            .unwrap_or(&"/static/js/main.js")
-            .to_string()
+/// Request path sampler with Zipf endpoint popularity: the endpoint at
+/// rank r (1-based) within API or page paths is drawn with weight 1 / r^s.
+struct EndpointSampler {
+    pages: Vec<String>,
+    page_cumulative: Vec<f64>,
+    api_cumulative: Vec<f64>,
+}
+
+impl EndpointSampler {
+    fn new(exponent: f64) -> Self {
+        let mut pages: Vec<String> = PAGE_PATHS.iter().map(|p| p.to_string()).collect();
+        pages.extend((1..=LONG_TAIL_PAGES).map(|n| format!("/blog/post-{}", n)));
+        Self {
+            page_cumulative: zipf_cumulative(pages.len(), exponent),
+            api_cumulative: zipf_cumulative(API_PATHS.len(), exponent),
+            pages,
+        }
+    }
+
+    fn sample<R: Rng>(&self, rng: &mut R, api_ratio: f64) -> String {
+        if rng.random::<f64>() < api_ratio {
+            let path = API_PATHS[sample_rank(rng, &self.api_cumulative)];
+            // Replace {id} placeholders with random IDs
+            path.replace("{id}", &format!("{}", rng.random_range(1..10000)))
+        } else {
+            self.pages[sample_rank(rng, &self.page_cumulative)].clone()
+        }
     }
 }
 
+fn zipf_cumulative(n: usize, exponent: f64) -> Vec<f64> {
+    let mut total = 0.0;
+    (1..=n)
+        .map(|rank| {
+            total += 1.0 / (rank as f64).powf(exponent);
+            total
+        })
+        .collect()
+}
+
+fn sample_rank<R: Rng>(rng: &mut R, cumulative: &[f64]) -> usize {
+    let total = cumulative.last().copied().unwrap_or(0.0);
+    let u = rng.random::<f64>() * total;
+    cumulative
+        .partition_point(|&c| c < u)
+        .min(cumulative.len().saturating_sub(1))
+}
+
 fn generate_latency<R: Rng>(rng: &mut R, config: &LatencyConfig) -> f64 {
     // Use log-normal for realistic latency distribution
     let mu = config.base_latency_ms.ln();
@@ -334,9 +660,152 @@ fn generate_latency<R: Rng>(rng: &mut R, config: &LatencyConfig) -> f64 {
     latency.max(1.0).min(30000.0) // Clamp between 1ms and 30s
 }
 
+/// Latency scaling for a response status: 5xx come from slow or timed-out
+/// paths, while 304s skip the response body and return quickly.
+fn status_latency_factor(status_code: u16, config: &LatencyConfig) -> f64 {
+    match status_code {
+        304 => config.not_modified_multiplier,
+        500..=599 => config.server_error_multiplier,
+        _ => 1.0,
+    }
+}
+
+/// Request arrival process shared by the log generators.
+///
+/// Without a traffic pattern this is a homogeneous Poisson process drawing
+/// from the generator's RNG. With one, arrivals are thinned from a Poisson
+/// process at the maximum rate using a separate RNG stream, so the accepted
+/// density follows the diurnal curve and spikes.
+struct ArrivalProcess {
+    exp_dist: Exp<f64>,
+    traffic: TrafficConfig,
+    traffic_rng: SeededRng,
+    spike_gap: Option<Exp<f64>>,
+    next_spike: NaiveDateTime,
+    spike_end: NaiveDateTime,
+}
+
+impl ArrivalProcess {
+    fn new(config: &LogsConfig, start: NaiveDateTime) -> Self {
+        let mean_interval_ms = 1000.0 / config.requests_per_second;
+        let exp_dist = Exp::new(1.0 / mean_interval_ms).unwrap_or_else(|_| Exp::new(0.01).unwrap());
+        let traffic = config.traffic.clone();
+        let mut traffic_rng = create_rng(config.seed.map(|s| s.wrapping_add(2)));
+
+        // Spike gaps in seconds
+        let spike_gap = (traffic.enable && traffic.spikes_per_day > 0.0)
+            .then(|| Exp::new(traffic.spikes_per_day / 86400.0).ok())
+            .flatten();
+        let next_spike = match &spike_gap {
+            Some(gap) => start + Duration::seconds(gap.sample(&mut traffic_rng) as i64),
+            None => start,
+        };
+
+        Self {
+            exp_dist,
+            traffic,
+            traffic_rng,
+            spike_gap,
+            next_spike,
+            spike_end: start,
+        }
+    }
+
+    /// Relative rate at `t` from the diurnal curve, 1.0 at the peak hour
+    fn diurnal(&self, t: NaiveDateTime) -> f64 {
+        let trough = 1.0 / self.traffic.peak_to_trough.max(1.0);
+        let hour = t.hour() as f64 + t.minute() as f64 / 60.0 + t.second() as f64 / 3600.0;
+        let phase = 2.0 * std::f64::consts::PI * (hour - self.traffic.peak_hour) / 24.0;
+        trough + (1.0 - trough) * 0.5 * (1.0 + phase.cos())
+    }
+
+    /// Arrival time of the request after `current`
+    fn next(&mut self, rng: &mut SeededRng, current: NaiveDateTime) -> NaiveDateTime {
+        if !self.traffic.enable {
+            let interval_ms: f64 = self.exp_dist.sample(rng);
+            return current + Duration::milliseconds(interval_ms as i64);
+        }
+
+        let max_multiplier = if self.spike_gap.is_some() {
+            self.traffic.spike_multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        let mut t = current;
+        loop {
+            let interval_ms: f64 = self.exp_dist.sample(&mut self.traffic_rng) / max_multiplier;
+            t += Duration::microseconds((interval_ms * 1000.0) as i64);
+
+            if let Some(gap) = &self.spike_gap {
+                while self.next_spike <= t {
+                    self.spike_end = self.next_spike
+                        + Duration::seconds(self.traffic.spike_duration_seconds as i64);
+                    let wait: f64 = gap.sample(&mut self.traffic_rng);
+                    self.next_spike += Duration::seconds(wait.max(1.0) as i64);
+                }
+            }
+            let spike = if t < self.spike_end {
+                max_multiplier
+            } else {
+                1.0
+            };
+
+            if self.traffic_rng.random::<f64>() * max_multiplier < self.diurnal(t) * spike {
+                return t;
+            }
+        }
+    }
+}
+
+/// A span in a trace, timed in milliseconds from the trace start
+struct Span {
+    span_id: String,
+    parent_span_id: Option<String>,
+    service: &'static str,
+    start_ms: f64,
+    duration_ms: f64,
+}
+
+/// Append a span and its descendants to `spans` in pre-order.
+///
+/// Children run sequentially, each inside its own slot of the parent's
+/// interval, so every child starts and ends within its parent.
+fn build_span_tree<R: Rng>(
+    rng: &mut R,
+    config: &TraceConfig,
+    span: Span,
+    depth: usize,
+    spans: &mut Vec<Span>,
+) {
+    let span_id = span.span_id.clone();
+    let (start_ms, duration_ms) = (span.start_ms, span.duration_ms);
+    spans.push(span);
+
+    if depth + 1 >= config.max_depth || config.max_children == 0 {
+        return;
+    }
+    let n_children = rng.random_range(0..=config.max_children);
+    if n_children == 0 {
+        return;
+    }
+
+    let slot = duration_ms / n_children as f64;
+    for i in 0..n_children {
+        let child_duration = slot * rng.random_range(0.3..0.9);
+        let child = Span {
+            span_id: format!("{:016x}", rng.random::<u64>()),
+            parent_span_id: Some(span_id.clone()),
+            service: SERVICES[1..].choose(rng).unwrap_or(&"database"),
+            start_ms: start_ms + i as f64 * slot + rng.random::<f64>() * (slot - child_duration),
+            duration_ms: child_duration,
+        };
+        build_span_tree(rng, config, child, depth + 1, spans);
+    }
+}
+
 fn generate_response_bytes<R: Rng>(rng: &mut R, status: u16, path: &str) -> u32 {
     match status {
-        204 => 0,
+        204 | 304 => 0,
         301 | 302 => rng.random_range(0..200),
         400..=499 => rng.random_range(100..1000),
         500..=599 => rng.random_range(100..500),
@@ -359,9 +828,24 @@ fn generate_response_bytes<R: Rng>(rng: &mut R, status: u16, path: &str) -> u32
 
 /// Generate web server log entries
 pub fn generate_logs(config: &LogsConfig) -> Vec<LogEntry> {
+    generate_logs_with_deploys(config).0
+}
+
+/// Generate web server log entries along with the deploy events that
+/// occurred while they were served.
+///
+/// Deploys draw from their own RNG stream, so enabling them only changes
+/// status codes inside impact windows and leaves every other field as is.
+pub fn generate_logs_with_deploys(config: &LogsConfig) -> (Vec<LogEntry>, Vec<DeployEvent>) {
     let mut rng = create_rng(config.seed);
+    let mut deploy_rng = create_rng(config.seed.map(|s| s.wrapping_add(1)));
+    let mut client_rng = create_rng(config.seed.map(|s| s.wrapping_add(3)));
+    let mut cache_rng = create_rng(config.seed.map(|s| s.wrapping_add(4)));
     let ip_pool = generate_ip_pool(&mut rng, config.unique_ips);
     let user_pool = generate_user_pool(&mut rng, config.unique_users);
+    let client_pool = assign_client_geo(&mut client_rng, ip_pool, config.clients.ipv6_ratio);
+    let bot_pool = generate_bot_pool(&mut client_rng, config.clients.bot_ips_per_range);
+    let endpoints = EndpointSampler::new(config.endpoint_zipf_exponent);
     let mut status_chain = create_status_code_chain();
 
     // Parse start time or use now
@@ -371,9 +855,8 @@ pub fn generate_logs(config: &LogsConfig) -> Vec<LogEntry> {
         .and_then(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok())
         .unwrap_or_else(|| Utc::now().naive_utc() - Duration::hours(1));
 
-    // Inter-arrival time distribution (exponential for Poisson process)
-    let mean_interval_ms = 1000.0 / config.requests_per_second;
-    let exp_dist = Exp::new(1.0 / mean_interval_ms).unwrap_or_else(|_| Exp::new(0.01).unwrap());
+    // Inter-arrival times (exponential for Poisson process)
+    let mut arrivals = ArrivalProcess::new(config, start);
 
     let mut current_time = start;
     let mut entries = Vec::with_capacity(config.count);
@@ -382,10 +865,34 @@ pub fn generate_logs(config: &LogsConfig) -> Vec<LogEntry> {
     let mut in_burst = false;
     let mut burst_end_time = start;
 
+    // Deploy state
+    let deploy_gap = Exp::new(config.deploys.deploys_per_hour.max(1e-9) / 3600.0)
+        .unwrap_or_else(|_| Exp::new(1.0 / 1800.0).unwrap());
+    let impact = Duration::seconds(config.deploys.impact_duration_seconds as i64);
+    let mut deploys: Vec<DeployEvent> = Vec::new();
+    let mut last_deploy: Option<NaiveDateTime> = None;
+    let mut next_deploy = start;
+    if config.deploys.enable {
+        let gap: f64 = deploy_gap.sample(&mut deploy_rng);
+        next_deploy += Duration::milliseconds((gap * 1000.0) as i64);
+    }
+
     for _ in 0..config.count {
-        // Advance time using exponential inter-arrival
-        let interval_ms: f64 = exp_dist.sample(&mut rng);
-        current_time += Duration::milliseconds(interval_ms as i64);
+        // Advance time to the next arrival
+        current_time = arrivals.next(&mut rng, current_time);
+
+        if config.deploys.enable {
+            while next_deploy <= current_time {
+                deploys.push(DeployEvent {
+                    deploy_id: format!("DEP-{:05}", deploys.len() + 1),
+                    timestamp: next_deploy.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+                    version: format!("v1.{}.{}", deploys.len() / 10, deploys.len() % 10),
+                });
+                last_deploy = Some(next_deploy);
+                let gap: f64 = deploy_gap.sample(&mut deploy_rng);
+                next_deploy += Duration::milliseconds((gap * 1000.0).max(1.0) as i64);
+            }
+        }
 
         // Check for error burst
         if config.error_burst.enable {
@@ -409,24 +916,37 @@ pub fn generate_logs(config: &LogsConfig) -> Vec<LogEntry> {
         } else {
             status_chain.next(&mut rng)
         };
-        let status_code: u16 = status_str.parse().unwrap_or(200);
+        let mut status_code: u16 = status_str.parse().unwrap_or(200);
 
         // Generate other fields
         let method_idx = weighted_choice(&mut rng, HTTP_METHOD_WEIGHTS);
         let method = HTTP_METHODS[method_idx].to_string();
-        let path = generate_path(&mut rng, config.api_path_ratio);
+        let path = endpoints.sample(&mut rng, config.api_path_ratio);
         let latency = generate_latency(&mut rng, &config.latency);
-        let response_bytes = generate_response_bytes(&mut rng, status_code, &path);
+        let mut response_bytes = generate_response_bytes(&mut rng, status_code, &path);
+
+        // Conditional GETs for cached static assets
+        if status_code == 200
+            && method == "GET"
+            && (path.starts_with("/static/") || path == "/favicon.ico")
+            && cache_rng.random::<f64>() < config.not_modified_rate
+        {
+            status_code = 304;
+            response_bytes = 0;
+        }
 
-        let ip = ip_pool.choose(&mut rng).cloned().unwrap_or_default();
+        let (mut ip, mut country, mut city) = client_pool
+            .choose(&mut rng)
+            .cloned()
+            .unwrap_or_else(|| (String::new(), "", ""));
         let user_id = if rng.random::<f64>() < 0.6 {
             user_pool.choose(&mut rng).cloned()
         } else {
             None
         };
 
-        let user_agent = if config.include_user_agent {
-            USER_AGENTS.choose(&mut rng).map(|s| s.to_string())
+        let mut user_agent = if config.include_user_agent {
+            Some(pick_user_agent(&mut rng).to_string())
         } else {
             None
         };
@@ -440,9 +960,46 @@ pub fn generate_logs(config: &LogsConfig) -> Vec<LogEntry> {
             None
         };
 
+        // Bot traffic: crawlers on a few IPs in known ranges, hitting more 4xx
+        let is_bot = client_rng.random::<f64>() < config.clients.bot_traffic_share;
+        if is_bot {
+            if let Some(bot) = bot_pool.choose(&mut client_rng) {
+                ip = bot.ip.clone();
+                country = bot.country;
+                city = bot.city;
+                if config.include_user_agent {
+                    user_agent = Some(bot.user_agent.to_string());
+                }
+            }
+            if client_rng.random::<f64>() < config.clients.bot_client_error_rate {
+                status_code = *[404, 404, 403, 429, 400]
+                    .choose(&mut client_rng)
+                    .unwrap_or(&404);
+                response_bytes = generate_response_bytes(&mut client_rng, status_code, &path);
+            }
+        }
+
+        // Post-deploy error spike, recovering linearly over the impact window
+        let since_deploy = last_deploy.map(|t| current_time - t);
+        let post_deploy = since_deploy.is_some_and(|d| d < impact);
+        if post_deploy {
+            let elapsed = since_deploy.unwrap().num_milliseconds() as f64;
+            let remaining = 1.0 - elapsed / impact.num_milliseconds().max(1) as f64;
+            if deploy_rng.random::<f64>() < config.deploys.post_deploy_error_rate * remaining {
+                status_code = *[500, 502, 503].choose(&mut deploy_rng).unwrap_or(&500);
+                response_bytes = generate_response_bytes(&mut deploy_rng, status_code, &path);
+            }
+        }
+
+        // Scale by the final status so overridden errors are slow too
+        let latency =
+            (latency * status_latency_factor(status_code, &config.latency)).clamp(1.0, 30000.0);
+
         entries.push(LogEntry {
             timestamp: current_time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
             ip_address: ip,
+            country: country.to_string(),
+            city: city.to_string(),
             user_id,
             method,
             path,
@@ -450,11 +1007,14 @@ pub fn generate_logs(config: &LogsConfig) -> Vec<LogEntry> {
             response_bytes,
             latency_ms: (latency * 100.0).round() / 100.0,
             user_agent,
+            is_bot,
             referer,
+            deploy_id: last_deploy.map(|_| deploys[deploys.len() - 1].deploy_id.clone()),
+            post_deploy,
         });
     }
 
-    entries
+    (entries, deploys)
 }
 
 /// Generate application event logs
@@ -467,8 +1027,7 @@ pub fn generate_app_logs(config: &LogsConfig) -> Vec<AppLogEntry> {
         .and_then(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok())
         .unwrap_or_else(|| Utc::now().naive_utc() - Duration::hours(1));
 
-    let mean_interval_ms = 1000.0 / config.requests_per_second;
-    let exp_dist = Exp::new(1.0 / mean_interval_ms).unwrap_or_else(|_| Exp::new(0.01).unwrap());
+    let mut arrivals = ArrivalProcess::new(config, start);
 
     let mut current_time = start;
     let mut entries = Vec::with_capacity(config.count);
@@ -512,45 +1071,57 @@ pub fn generate_app_logs(config: &LogsConfig) -> Vec<AppLogEntry> {
     // Level weights: DEBUG, INFO, WARN, ERROR, FATAL
     let level_weights = vec![0.1, 0.7, 0.12, 0.07, 0.01];
 
-    for _ in 0..config.count {
-        let interval_ms: f64 = exp_dist.sample(&mut rng);
-        current_time += Duration::milliseconds(interval_ms as i64);
-
-        let level_idx = weighted_choice(&mut rng, &level_weights);
-        let level = LOG_LEVELS[level_idx].to_string();
-
-        let exception = if level == "ERROR" || level == "FATAL" {
-            if rng.random::<f64>() < 0.7 {
-                exceptions.choose(&mut rng).map(|s| s.to_string())
+    // Each request is a trace: one log line per span, at the span's start
+    let mut spans = Vec::new();
+    while entries.len() < config.count {
+        current_time = arrivals.next(&mut rng, current_time);
+
+        let trace_id = format!("{:032x}", rng.random::<u128>());
+        let root = Span {
+            span_id: format!("{:016x}", rng.random::<u64>()),
+            parent_span_id: None,
+            service: SERVICES[0],
+            start_ms: 0.0,
+            duration_ms: generate_latency(&mut rng, &config.latency),
+        };
+        spans.clear();
+        build_span_tree(&mut rng, &config.traces, root, 0, &mut spans);
+
+        for span in spans.drain(..) {
+            let level_idx = weighted_choice(&mut rng, &level_weights);
+            let level = LOG_LEVELS[level_idx].to_string();
+
+            let exception = if level == "ERROR" || level == "FATAL" {
+                if rng.random::<f64>() < 0.7 {
+                    exceptions.choose(&mut rng).map(|s| s.to_string())
+                } else {
+                    None
+                }
             } else {
                 None
-            }
-        } else {
-            None
-        };
-
-        let trace_id = if rng.random::<f64>() < 0.8 {
-            Some(format!("{:032x}", rng.random::<u128>()))
-        } else {
-            None
-        };
-
-        let span_id = trace_id
-            .as_ref()
-            .map(|_| format!("{:016x}", rng.random::<u64>()));
-
-        entries.push(AppLogEntry {
-            timestamp: current_time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
-            level,
-            logger: loggers.choose(&mut rng).unwrap_or(&"app").to_string(),
-            message: messages.choose(&mut rng).unwrap_or(&"Event").to_string(),
-            thread_id: rng.random_range(1..100),
-            trace_id,
-            span_id,
-            exception,
-        });
+            };
+
+            let timestamp = current_time + Duration::microseconds((span.start_ms * 1000.0) as i64);
+            entries.push(AppLogEntry {
+                timestamp: timestamp.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+                level,
+                logger: loggers.choose(&mut rng).unwrap_or(&"app").to_string(),
+                message: messages.choose(&mut rng).unwrap_or(&"Event").to_string(),
+                thread_id: rng.random_range(1..100),
+                service: span.service.to_string(),
+                trace_id: Some(trace_id.clone()),
+                span_id: Some(span.span_id),
+                parent_span_id: span.parent_span_id,
+                duration_ms: Some((span.duration_ms * 100.0).round() / 100.0),
+                exception,
+            });
+        }
     }
 
+    // Traces overlap in time; a stable sort keeps each parent ahead of its
+    // children, so truncating to `count` never orphans a span
+    entries.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    entries.truncate(config.count);
     entries
 }
 
@@ -629,6 +1200,330 @@ mod tests {
         assert!(avg_latency > 10.0 && avg_latency < 500.0);
     }
 
+    #[test]
+    fn test_latency_by_status_class() {
+        let config = LogsConfig {
+            count: 20000,
+            seed: Some(42),
+            success_rate: 0.8,
+            ..Default::default()
+        };
+        let logs = generate_logs(&config);
+
+        let mean_latency = |pred: &dyn Fn(u16) -> bool| {
+            let values: Vec<f64> = logs
+                .iter()
+                .filter(|l| pred(l.status_code))
+                .map(|l| l.latency_ms)
+                .collect();
+            assert!(!values.is_empty());
+            values.iter().sum::<f64>() / values.len() as f64
+        };
+        let ok = mean_latency(&|s| (200..300).contains(&s));
+        let server_error = mean_latency(&|s| s >= 500);
+        let not_modified = mean_latency(&|s| s == 304);
+        assert!(
+            server_error > 2.0 * ok,
+            "5xx {} vs 2xx {}",
+            server_error,
+            ok
+        );
+        assert!(not_modified < ok, "304 {} vs 2xx {}", not_modified, ok);
+
+        // Multipliers of 1.0 remove the correlation
+        let flat = generate_logs(&LogsConfig {
+            latency: LatencyConfig {
+                server_error_multiplier: 1.0,
+                not_modified_multiplier: 1.0,
+                ..Default::default()
+            },
+            ..config
+        });
+        for (a, b) in logs.iter().zip(&flat) {
+            if (200..300).contains(&a.status_code) {
+                assert_eq!(a.latency_ms, b.latency_ms);
+            }
+        }
+    }
+
+    #[test]
+    fn test_post_deploy_error_spike() {
+        let base = LogsConfig {
+            count: 50000,
+            seed: Some(42),
+            start_time: Some("2024-01-01 00:00:00".to_string()),
+            requests_per_second: 2.0,
+            ..Default::default()
+        };
+        let config = LogsConfig {
+            deploys: DeployConfig {
+                enable: true,
+                deploys_per_hour: 4.0,
+                ..Default::default()
+            },
+            ..base.clone()
+        };
+        let (logs, deploys) = generate_logs_with_deploys(&config);
+        assert!(deploys.len() > 5);
+
+        let error_rate = |rows: &[&LogEntry]| {
+            rows.iter().filter(|l| l.status_code >= 500).count() as f64 / rows.len() as f64
+        };
+        let post: Vec<&LogEntry> = logs.iter().filter(|l| l.post_deploy).collect();
+        let baseline: Vec<&LogEntry> = logs.iter().filter(|l| !l.post_deploy).collect();
+        assert!(!post.is_empty() && !baseline.is_empty());
+        assert!(post.iter().all(|l| l.deploy_id.is_some()));
+        assert!(
+            error_rate(&post) > 2.0 * error_rate(&baseline),
+            "post-deploy {} vs baseline {}",
+            error_rate(&post),
+            error_rate(&baseline)
+        );
+
+        // Outside impact windows, rows match the deploy-free output
+        let plain = generate_logs(&base);
+        for (a, b) in logs.iter().zip(plain.iter()) {
+            assert_eq!(a.timestamp, b.timestamp);
+            if !a.post_deploy {
+                assert_eq!(a.status_code, b.status_code);
+            }
+        }
+        assert!(plain
+            .iter()
+            .all(|l| l.deploy_id.is_none() && !l.post_deploy));
+    }
+
+    #[test]
+    fn test_trace_span_tree() {
+        let config = LogsConfig {
+            count: 2000,
+            seed: Some(42),
+            start_time: Some("2024-01-01 00:00:00".to_string()),
+            ..Default::default()
+        };
+        let logs = generate_app_logs(&config);
+        assert_eq!(logs.len(), 2000);
+
+        let parse = |ts: &str| NaiveDateTime::parse_from_str(ts, "%Y-%m-%dT%H:%M:%S%.3fZ").unwrap();
+        let mut spans: std::collections::HashMap<&str, &AppLogEntry> =
+            std::collections::HashMap::new();
+        let mut roots = 0;
+        let mut multi_service = 0;
+        for entry in &logs {
+            let span_id = entry.span_id.as_deref().unwrap();
+            match entry.parent_span_id.as_deref() {
+                None => {
+                    roots += 1;
+                    assert_eq!(entry.service, "api-gateway");
+                }
+                Some(parent_id) => {
+                    // Parents are logged before their children
+                    let parent = spans[parent_id];
+                    assert_eq!(parent.trace_id, entry.trace_id);
+                    if parent.service != entry.service {
+                        multi_service += 1;
+                    }
+
+                    let start = parse(&entry.timestamp);
+                    let end = start
+                        + Duration::microseconds((entry.duration_ms.unwrap() * 1000.0) as i64);
+                    let parent_start = parse(&parent.timestamp);
+                    let parent_end = parent_start
+                        + Duration::microseconds((parent.duration_ms.unwrap() * 1000.0) as i64);
+                    // Allow for millisecond timestamp truncation
+                    assert!(start >= parent_start);
+                    assert!(end <= parent_end + Duration::milliseconds(1));
+                }
+            }
+            assert!(spans.insert(span_id, entry).is_none());
+        }
+        assert!(roots > 100 && roots < 2000);
+        assert!(multi_service > 0);
+    }
+
+    #[test]
+    fn test_diurnal_traffic_volume() {
+        let config = LogsConfig {
+            count: 80000,
+            seed: Some(42),
+            start_time: Some("2024-01-01 00:00:00".to_string()),
+            requests_per_second: 0.5,
+            traffic: TrafficConfig {
+                enable: true,
+                peak_hour: 14.0,
+                peak_to_trough: 5.0,
+                spikes_per_day: 0.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let logs = generate_logs(&config);
+
+        // Bucket the first three full days by hour of day
+        let start =
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut per_hour = [0usize; 24];
+        for entry in &logs {
+            let t =
+                NaiveDateTime::parse_from_str(&entry.timestamp, "%Y-%m-%dT%H:%M:%S%.3fZ").unwrap();
+            if t < start + Duration::days(3) {
+                per_hour[t.hour() as usize] += 1;
+            }
+        }
+        assert!(logs.last().unwrap().timestamp.as_str() > "2024-01-04");
+
+        let (peak, &peak_count) = per_hour
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, c)| **c)
+            .unwrap();
+        let (trough, &trough_count) = per_hour
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, c)| **c)
+            .unwrap();
+        assert!((12..=16).contains(&peak), "peak hour {}", peak);
+        assert!(trough <= 4 || trough == 23, "trough hour {}", trough);
+        let ratio = peak_count as f64 / trough_count as f64;
+        assert!(ratio > 4.0 && ratio < 6.5, "peak/trough ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_bot_ips_elevated_client_errors() {
+        let config = LogsConfig {
+            count: 20000,
+            seed: Some(42),
+            start_time: Some("2024-01-01 00:00:00".to_string()),
+            ..Default::default()
+        };
+        let logs = generate_logs(&config);
+
+        let client_error_rate = |bots: bool| {
+            let rows: Vec<&LogEntry> = logs
+                .iter()
+                .filter(|l| is_bot_ip(&l.ip_address) == bots)
+                .collect();
+            let errors = rows
+                .iter()
+                .filter(|l| (400..500).contains(&l.status_code))
+                .count();
+            (errors as f64 / rows.len() as f64, rows)
+        };
+        let (bot_rate, bot_rows) = client_error_rate(true);
+        let (human_rate, human_rows) = client_error_rate(false);
+        let bot_share = bot_rows.len() as f64 / logs.len() as f64;
+        assert!((bot_share - 0.05).abs() < 0.01, "bot share {}", bot_share);
+        assert!(
+            bot_rate > human_rate + 0.15,
+            "bot 4xx {} vs non-bot {}",
+            bot_rate,
+            human_rate
+        );
+
+        // Few bot IPs send many requests each
+        let bot_ips: std::collections::HashSet<_> =
+            bot_rows.iter().map(|l| l.ip_address.as_str()).collect();
+        let human_ips: std::collections::HashSet<_> =
+            human_rows.iter().map(|l| l.ip_address.as_str()).collect();
+        assert!(
+            bot_rows.len() / bot_ips.len() > 3 * (human_rows.len() / human_ips.len()),
+            "per-IP request rate"
+        );
+
+        let labeled = logs.iter().filter(|l| l.is_bot).count();
+        assert_eq!(labeled, bot_rows.len());
+
+        let ipv6 = human_rows
+            .iter()
+            .filter(|l| l.ip_address.contains(':'))
+            .count();
+        assert!(ipv6 > 0 && ipv6 < human_rows.len() / 4);
+        assert!(logs
+            .iter()
+            .all(|l| !l.country.is_empty() && !l.city.is_empty()));
+    }
+
+    #[test]
+    fn test_bot_user_agents_labeled() {
+        let config = LogsConfig {
+            count: 20000,
+            seed: Some(7),
+            clients: ClientConfig {
+                bot_traffic_share: 0.2,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let logs = generate_logs(&config);
+
+        for entry in &logs {
+            let ua = entry.user_agent.as_deref().unwrap();
+            assert_eq!(entry.is_bot, is_crawler_user_agent(ua), "{}", ua);
+            assert_eq!(entry.is_bot, CRAWLER_USER_AGENTS.contains(&ua));
+        }
+        let bot_fraction = logs.iter().filter(|l| l.is_bot).count() as f64 / logs.len() as f64;
+        assert!(
+            (bot_fraction - 0.2).abs() < 0.015,
+            "bot fraction {}",
+            bot_fraction
+        );
+
+        // Browsers dominate regular traffic; scripted clients are a small tail
+        let chrome = logs
+            .iter()
+            .filter(|l| l.user_agent.as_deref().unwrap().contains("Chrome"))
+            .count();
+        assert!(chrome > logs.len() / 3);
+    }
+
+    #[test]
+    fn test_endpoint_popularity_power_law() {
+        let config = LogsConfig {
+            count: 50000,
+            seed: Some(42),
+            api_path_ratio: 0.0,
+            endpoint_zipf_exponent: 1.0,
+            ..Default::default()
+        };
+        let logs = generate_logs(&config);
+
+        let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+        for entry in &logs {
+            *counts.entry(entry.path.as_str()).or_insert(0) += 1;
+        }
+        let mut ranked: Vec<(&str, usize)> = counts.into_iter().collect();
+        ranked.sort_by_key(|r| std::cmp::Reverse(r.1));
+        assert_eq!(ranked[0].0, "/");
+
+        // Log-log rank/frequency slope over the head of the distribution
+        let points: Vec<(f64, f64)> = ranked
+            .iter()
+            .take(30)
+            .enumerate()
+            .map(|(i, (_, c))| (((i + 1) as f64).ln(), (*c as f64).ln()))
+            .collect();
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+        let slope = points
+            .iter()
+            .map(|p| (p.0 - mean_x) * (p.1 - mean_y))
+            .sum::<f64>()
+            / points.iter().map(|p| (p.0 - mean_x).powi(2)).sum::<f64>();
+        assert!((slope + 1.0).abs() < 0.15, "slope {}", slope);
+
+        // A few endpoints dominate; individual long-tail posts are rare
+        let top5: usize = ranked.iter().take(5).map(|r| r.1).sum();
+        assert!(top5 as f64 / logs.len() as f64 > 0.3);
+        let post_max = ranked
+            .iter()
+            .filter(|r| r.0.starts_with("/blog/"))
+            .map(|r| r.1)
+            .max()
+            .unwrap();
+        assert!(post_max * 10 < ranked[0].1);
+    }
+
     #[test]
     fn test_ip_pool() {
         let mut rng = create_rng(Some(42));
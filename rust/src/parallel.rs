@@ -5,17 +5,48 @@
 
 use rayon::prelude::*;
 
-use rand::rngs::StdRng;
+use crate::rng::{derive_seed, seeded_rng};
 use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
 
-use crate::general::{EmployeeRow, SuperstoreRow};
-use crate::utils::{US_SECTORS, US_SECTORS_MAP};
+use crate::general::{
+    superstore_rows_parallel, EmployeeConfig, EmployeeOrg, EmployeeRow, SuperstoreConfig,
+    SuperstoreConfigError, SuperstoreRow, COUNTRY_VALUE, DEFAULT_REGIONS, DEFAULT_REGION_VALUES,
+    SECTOR_VALUES, SEGMENT_VALUES, SHIP_MODE_VALUES,
+};
+use crate::utils::US_SECTORS_MAP;
 
 use chrono::{Datelike, NaiveDate, Utc};
 
-const SHIP_MODES: [&str; 3] = ["First Class", "Standard Class", "Second Class"];
-const SEGMENTS: [&str; 4] = ["A", "B", "C", "D"];
+/// Error type for configured parallel generation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParallelError {
+    /// `per_row_seeding` is off, so rows come from one RNG stream that can't
+    /// be split across threads
+    PerRowSeedingRequired,
+    /// `stable_v1` output is frozen to the sequential generator
+    StableV1,
+    /// The config fails [`SuperstoreConfig::validate`]
+    InvalidConfig(SuperstoreConfigError),
+}
+
+impl std::fmt::Display for ParallelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParallelError::PerRowSeedingRequired => write!(
+                f,
+                "Parallel generation requires per_row_seeding: sequential seeding draws every row from one RNG stream"
+            ),
+            ParallelError::StableV1 => {
+                write!(f, "Parallel generation doesn't support stable_v1")
+            }
+            ParallelError::InvalidConfig(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ParallelError {}
+
 const PREFIXES: [&str; 6] = ["Mr.", "Mrs.", "Ms.", "Dr.", "Prof.", "Rev."];
 const SUFFIXES: [&str; 4] = ["Jr.", "Sr.", "III", "IV"];
 
@@ -127,6 +158,15 @@ fn generate_phone<R: Rng>(rng: &mut R) -> String {
     )
 }
 
+/// Rows per block of [`employees_parallel`]; managers only manage employees
+/// in their own block
+pub const EMPLOYEE_BLOCK_SIZE: usize = 1024;
+
+/// Base seed for the per-row seeds, drawn at random when `seed` is `None`
+fn base_seed(seed: Option<u64>) -> u64 {
+    seed.unwrap_or_else(|| rand::rng().random())
+}
+
 fn random_date_this_year<R: Rng>(rng: &mut R) -> NaiveDate {
     let year = Utc::now().naive_utc().date().year();
     let day_of_year = rng.random_range(1..=365);
@@ -147,34 +187,17 @@ fn random_date_between<R: Rng>(rng: &mut R, start: NaiveDate) -> NaiveDate {
     start + chrono::Duration::days(random_days as i64)
 }
 
-fn random_date_30_years<R: Rng>(rng: &mut R) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    let thirty_years_ago = today - chrono::Duration::days(30 * 365);
-    let days_range = (today - thirty_years_ago).num_days() as u32;
-    let random_days = rng.random_range(0..=days_range);
-    thirty_years_ago + chrono::Duration::days(random_days as i64)
-}
-
-fn random_date_of_birth<R: Rng>(rng: &mut R) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    let min_age = 18;
-    let max_age = 70;
-    let min_date = today - chrono::Duration::days(max_age * 365);
-    let max_date = today - chrono::Duration::days(min_age * 365);
-    let days_range = (max_date - min_date).num_days() as u32;
-    let random_days = rng.random_range(0..=days_range);
-    min_date + chrono::Duration::days(random_days as i64)
-}
-
 /// Generate superstore data in parallel using multiple threads.
 ///
 /// This function divides the work across available CPU cores for faster
-/// generation of large datasets. With a seed, results are reproducible
-/// but row order may differ from the sequential version.
+/// generation of large datasets. Rows are returned in `row_id` order, and
+/// each row draws from its own RNG seeded with `derive_seed(seed, row_id)`,
+/// so a seed gives the same rows whatever the thread count. The rows differ
+/// from the sequential version's.
 ///
 /// # Arguments
 /// * `count` - Total number of rows to generate
-/// * `seed` - Optional seed for reproducibility (per-thread seeds derived from this)
+/// * `seed` - Optional seed for reproducibility (per-row seeds derived from this)
 ///
 /// # Example
 /// ```
@@ -184,79 +207,118 @@ fn random_date_of_birth<R: Rng>(rng: &mut R) -> NaiveDate {
 /// let data = superstore_parallel(1_000_000, Some(42));
 /// ```
 pub fn superstore_parallel(count: usize, seed: Option<u64>) -> Vec<SuperstoreRow> {
-    let num_threads = rayon::current_num_threads();
-    let chunk_size = (count + num_threads - 1) / num_threads;
+    let base_seed = base_seed(seed);
 
-    let chunks: Vec<Vec<SuperstoreRow>> = (0..num_threads)
+    (0..count)
         .into_par_iter()
-        .map(|thread_idx| {
-            let start_idx = thread_idx * chunk_size;
-            let end_idx = (start_idx + chunk_size).min(count);
-            if start_idx >= count {
-                return Vec::new();
+        .map(|row_id| {
+            let mut rng = seeded_rng(Some(derive_seed(base_seed, row_id as u64)));
+
+            let order_date = random_date_this_year(&mut rng);
+            let ship_date = random_date_between(&mut rng, order_date);
+
+            let sector = SECTOR_VALUES.choose(&mut rng).unwrap();
+            let industries = US_SECTORS_MAP.get(&**sector).unwrap();
+            let industry = *industries.choose(&mut rng).unwrap();
+
+            SuperstoreRow {
+                row_id: row_id as i32,
+                order_id: generate_ein(&mut rng),
+                order_date: order_date.format("%Y-%m-%d").to_string(),
+                ship_date: ship_date.format("%Y-%m-%d").to_string(),
+                ship_mode: SHIP_MODE_VALUES.choose(&mut rng).unwrap().clone(),
+                customer_id: generate_license_plate(&mut rng),
+                segment: SEGMENT_VALUES.choose(&mut rng).unwrap().clone(),
+                country: COUNTRY_VALUE.clone(),
+                city: generate_city(&mut rng),
+                state: generate_state(&mut rng),
+                postal_code: generate_zip(&mut rng),
+                region: DEFAULT_REGION_VALUES.choose(&mut rng).unwrap().clone(),
+                product_id: generate_bban(&mut rng),
+                category: sector.clone(),
+                sub_category: industry.to_string(),
+                product_type: None,
+                item_status: "Regular".to_string(),
+                currency: None,
+                item_price: (rng.random_range(1..=100) as f64) * 10.0 + 0.99,
+                msrp: None,
+                sales: rng.random_range(1..=100) * 100,
+                quantity: rng.random_range(1..=100) * 10,
+                discount: (rng.random::<f64>() * 100.0 * 100.0).round() / 100.0,
+                profit: (rng.random::<f64>() * 1000.0 * 100.0).round() / 100.0,
+                // Priority 4 fields (not enabled in parallel simple mode)
+                bundle_id: None,
+                payment_method: None,
+                is_fraud: None,
+                processing_fee: None,
+                card_number: None,
+                card_network: None,
+                settlement_date: None,
+                backorder_days: None,
+                stock_status: None,
+                return_reason: None,
+                return_date: None,
+                original_order_id: None,
             }
-
-            // Create per-thread RNG with deterministic seed based on thread index
-            let mut rng = match seed {
-                Some(s) => StdRng::seed_from_u64(s.wrapping_add(thread_idx as u64)),
-                None => StdRng::from_rng(&mut rand::rng()),
-            };
-
-            let sectors: Vec<&str> = US_SECTORS.clone();
-            let mut chunk = Vec::with_capacity(end_idx - start_idx);
-
-            for row_id in start_idx..end_idx {
-                let order_date = random_date_this_year(&mut rng);
-                let ship_date = random_date_between(&mut rng, order_date);
-
-                let sector = *sectors.choose(&mut rng).unwrap();
-                let industries = US_SECTORS_MAP.get(sector).unwrap();
-                let industry = *industries.choose(&mut rng).unwrap();
-
-                let row = SuperstoreRow {
-                    row_id: row_id as i32,
-                    order_id: generate_ein(&mut rng),
-                    order_date: order_date.format("%Y-%m-%d").to_string(),
-                    ship_date: ship_date.format("%Y-%m-%d").to_string(),
-                    ship_mode: SHIP_MODES.choose(&mut rng).unwrap().to_string(),
-                    customer_id: generate_license_plate(&mut rng),
-                    segment: SEGMENTS.choose(&mut rng).unwrap().to_string(),
-                    country: "US".to_string(),
-                    city: generate_city(&mut rng),
-                    state: generate_state(&mut rng),
-                    postal_code: generate_zip(&mut rng),
-                    region: format!("Region {}", rng.random_range(0..5)),
-                    product_id: generate_bban(&mut rng),
-                    category: sector.to_string(),
-                    sub_category: industry.to_string(),
-                    item_status: "Regular".to_string(),
-                    item_price: (rng.random_range(1..=100) as f64) * 10.0 + 0.99,
-                    sales: rng.random_range(1..=100) * 100,
-                    quantity: rng.random_range(1..=100) * 10,
-                    discount: (rng.random::<f64>() * 100.0 * 100.0).round() / 100.0,
-                    profit: (rng.random::<f64>() * 1000.0 * 100.0).round() / 100.0,
-                    // Priority 4 fields (not enabled in parallel simple mode)
-                    bundle_id: None,
-                    payment_method: None,
-                    is_fraud: None,
-                    processing_fee: None,
-                    backorder_days: None,
-                    stock_status: None,
-                };
-                chunk.push(row);
-            }
-            chunk
         })
-        .collect();
+        .collect()
+}
 
-    // Flatten chunks into single vector
-    chunks.into_iter().flatten().collect()
+/// Generate superstore data from a full [`SuperstoreConfig`] in parallel.
+///
+/// Unlike [`superstore_parallel`], rows get every configured feature:
+/// seasonality, copula correlations, promotions, payments, returns, and so
+/// on. Each row, copula sample included, draws from its own RNG seeded with
+/// `derive_seed(seed, row_id)`, so no row depends on thread boundaries. The
+/// output equals [`superstore_with_config`](crate::superstore_with_config)
+/// with the same config for any thread count.
+///
+/// Rows share no state while they are generated, so the work divides evenly
+/// across cores. `cargo run --release --example parallel -p superstore`
+/// times 1M rows against the single-threaded generator.
+///
+/// # Errors
+///
+/// Sequential seeding draws every row from one RNG stream, which can't be
+/// split, so this returns [`ParallelError::PerRowSeedingRequired`] unless
+/// `config.per_row_seeding` is set, and [`ParallelError::StableV1`] if
+/// `config.stable_v1` is. A config that fails [`SuperstoreConfig::validate`]
+/// returns [`ParallelError::InvalidConfig`].
+///
+/// # Example
+/// ```
+/// use superstore::parallel::superstore_parallel_with_config;
+/// use superstore::{superstore_with_config, SuperstoreConfig};
+///
+/// let config = SuperstoreConfig {
+///     count: 1000,
+///     seed: Some(42),
+///     per_row_seeding: true,
+///     ..Default::default()
+/// };
+/// let rows = superstore_parallel_with_config(&config).unwrap();
+/// assert_eq!(rows.len(), 1000);
+/// assert_eq!(rows[999].order_id, superstore_with_config(&config)[999].order_id);
+/// ```
+pub fn superstore_parallel_with_config(
+    config: &SuperstoreConfig,
+) -> Result<Vec<SuperstoreRow>, ParallelError> {
+    if config.stable_v1 {
+        return Err(ParallelError::StableV1);
+    }
+    if !config.per_row_seeding {
+        return Err(ParallelError::PerRowSeedingRequired);
+    }
+    config.validate().map_err(ParallelError::InvalidConfig)?;
+    Ok(superstore_rows_parallel(config))
 }
 
 /// Generate employee data in parallel using multiple threads.
 ///
 /// This function divides the work across available CPU cores for faster
-/// generation of large datasets.
+/// generation of large datasets. As in [`superstore_parallel`], each row has
+/// its own seeded RNG, and managers are assigned within fixed blocks of
+/// [`EMPLOYEE_BLOCK_SIZE`] rows, so output doesn't depend on the thread count.
 ///
 /// # Arguments
 /// * `count` - Total number of employees to generate
@@ -270,28 +332,22 @@ pub fn superstore_parallel(count: usize, seed: Option<u64>) -> Vec<SuperstoreRow
 /// let data = employees_parallel(1_000_000, Some(42));
 /// ```
 pub fn employees_parallel(count: usize, seed: Option<u64>) -> Vec<EmployeeRow> {
-    let num_threads = rayon::current_num_threads();
-    let chunk_size = (count + num_threads - 1) / num_threads;
+    let base_seed = base_seed(seed);
 
-    let chunks: Vec<Vec<EmployeeRow>> = (0..num_threads)
+    let blocks: Vec<Vec<EmployeeRow>> = (0..count.div_ceil(EMPLOYEE_BLOCK_SIZE))
         .into_par_iter()
-        .map(|thread_idx| {
-            let start_idx = thread_idx * chunk_size;
-            let end_idx = (start_idx + chunk_size).min(count);
-            if start_idx >= count {
-                return Vec::new();
-            }
-
-            // Create per-thread RNG with deterministic seed based on thread index
-            let mut rng = match seed {
-                Some(s) => StdRng::seed_from_u64(s.wrapping_add(thread_idx as u64)),
-                None => StdRng::from_rng(&mut rand::rng()),
-            };
+        .map(|block| {
+            let start_idx = block * EMPLOYEE_BLOCK_SIZE;
+            let end_idx = (start_idx + EMPLOYEE_BLOCK_SIZE).min(count);
 
             let mut chunk = Vec::with_capacity(end_idx - start_idx);
+            // Managers are drawn per block, so reports never cross blocks
+            let mut org = EmployeeOrg::new(&EmployeeConfig::default());
 
             for row_id in start_idx..end_idx {
-                let row = EmployeeRow {
+                let mut rng = seeded_rng(Some(derive_seed(base_seed, row_id as u64)));
+                let (date_of_birth, start_date, termination_date) = org.employment_dates(&mut rng);
+                let mut row = EmployeeRow {
                     row_id: row_id as i32,
                     employee_id: generate_ein(&mut rng),
                     first_name: generate_first_name(&mut rng),
@@ -304,19 +360,73 @@ pub fn employees_parallel(count: usize, seed: Option<u64>) -> Vec<EmployeeRow> {
                     street: generate_street_address(&mut rng),
                     city: generate_city(&mut rng),
                     postal_code: generate_zip(&mut rng),
-                    region: format!("Region {}", rng.random_range(0..5)),
+                    region: DEFAULT_REGIONS.choose(&mut rng).unwrap().to_string(),
                     state: generate_state(&mut rng),
                     country: "US".to_string(),
-                    start_date: random_date_30_years(&mut rng),
-                    date_of_birth: random_date_of_birth(&mut rng),
+                    start_date,
+                    date_of_birth,
+                    department: String::new(),
+                    job_title: String::new(),
+                    salary: 0.0,
+                    manager_id: None,
+                    termination_date,
                 };
+                org.place(&mut rng, &mut row);
                 chunk.push(row);
             }
             chunk
         })
         .collect();
 
-    chunks.into_iter().flatten().collect()
+    blocks.into_iter().flatten().collect()
+}
+
+/// Generate superstore data in parallel on a dedicated pool of `num_threads`
+/// threads instead of the global Rayon pool.
+///
+/// Each call builds its own scoped pool, so different calls in one process
+/// can use different thread counts. Output doesn't depend on `num_threads`.
+///
+/// # Example
+/// ```
+/// use superstore::parallel::superstore_parallel_with_threads;
+///
+/// let data = superstore_parallel_with_threads(10_000, Some(42), 2).unwrap();
+/// assert_eq!(data.len(), 10_000);
+/// ```
+pub fn superstore_parallel_with_threads(
+    count: usize,
+    seed: Option<u64>,
+    num_threads: usize,
+) -> Result<Vec<SuperstoreRow>, rayon::ThreadPoolBuildError> {
+    with_num_threads(num_threads, || superstore_parallel(count, seed))
+}
+
+/// Generate employee data in parallel on a dedicated pool of `num_threads`
+/// threads instead of the global Rayon pool.
+///
+/// See [`superstore_parallel_with_threads`].
+pub fn employees_parallel_with_threads(
+    count: usize,
+    seed: Option<u64>,
+    num_threads: usize,
+) -> Result<Vec<EmployeeRow>, rayon::ThreadPoolBuildError> {
+    with_num_threads(num_threads, || employees_parallel(count, seed))
+}
+
+/// Run `f` inside a scoped Rayon pool of `num_threads` threads.
+///
+/// Parallel generators called from `f` split their work across this pool
+/// rather than the global one, which is left untouched.
+pub fn with_num_threads<R, F>(num_threads: usize, f: F) -> Result<R, rayon::ThreadPoolBuildError>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()?;
+    Ok(pool.install(f))
 }
 
 /// Get the number of threads Rayon will use for parallel operations.
@@ -389,6 +499,98 @@ mod tests {
         assert!(same_count < 50); // Very unlikely to be more than half the same
     }
 
+    #[test]
+    fn test_parallel_with_different_thread_counts() {
+        // Scoped pools stand in for set_num_threads, which can only run once
+        // per process
+        let superstore = |threads| {
+            let rows = superstore_parallel_with_threads(3000, Some(42), threads).unwrap();
+            serde_json::to_string(&rows).unwrap()
+        };
+        let employees = |threads| {
+            let rows = employees_parallel_with_threads(3000, Some(42), threads).unwrap();
+            serde_json::to_string(&rows).unwrap()
+        };
+        assert_eq!(superstore(2), superstore(4));
+        assert_eq!(employees(2), employees(4));
+        assert_eq!(employees(1), employees(3));
+
+        let rows = superstore_parallel_with_threads(1000, Some(42), 3).unwrap();
+        assert!(rows.iter().enumerate().all(|(i, r)| r.row_id == i as i32));
+        let employees = employees_parallel_with_threads(3000, Some(42), 3).unwrap();
+        assert!(employees
+            .iter()
+            .enumerate()
+            .all(|(i, r)| r.row_id == i as i32));
+        assert_eq!(with_num_threads(3, num_threads).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_superstore_parallel_with_config_matches_sequential() {
+        use crate::general::{
+            superstore_with_config, BundlingConfig, PaymentConfig, ReturnsConfig, SeasonalityConfig,
+        };
+
+        let config = SuperstoreConfig {
+            count: 2000,
+            seed: Some(42),
+            per_row_seeding: true,
+            copula_df: Some(4.0),
+            seasonality: SeasonalityConfig {
+                enable: true,
+                ..Default::default()
+            },
+            bundling: BundlingConfig {
+                enable: true,
+                ..Default::default()
+            },
+            payment: PaymentConfig {
+                enable: true,
+                ..Default::default()
+            },
+            returns: ReturnsConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = serde_json::to_string(&superstore_with_config(&config)).unwrap();
+        for threads in [1, 3] {
+            let rows = with_num_threads(threads, || superstore_parallel_with_config(&config))
+                .unwrap()
+                .unwrap();
+            assert!(rows.len() > 2000);
+            assert_eq!(serde_json::to_string(&rows).unwrap(), expected);
+        }
+
+        assert_eq!(
+            superstore_parallel_with_config(&SuperstoreConfig {
+                per_row_seeding: false,
+                ..config.clone()
+            })
+            .unwrap_err(),
+            ParallelError::PerRowSeedingRequired
+        );
+        assert_eq!(
+            superstore_parallel_with_config(&SuperstoreConfig {
+                stable_v1: true,
+                ..config.clone()
+            })
+            .unwrap_err(),
+            ParallelError::StableV1
+        );
+        assert_eq!(
+            superstore_parallel_with_config(&SuperstoreConfig {
+                order_date_start: Some("2024-13-01".to_string()),
+                ..config
+            })
+            .unwrap_err(),
+            ParallelError::InvalidConfig(SuperstoreConfigError::InvalidOrderDate(
+                "2024-13-01".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn test_num_threads() {
         let threads = num_threads();
@@ -0,0 +1,344 @@
+//! Streaming quantile estimation.
+//!
+//! [`P2Quantile`] tracks one quantile of a stream in constant memory with the
+//! P² algorithm (Jain & Chlamtac, 1985): five markers whose heights are
+//! adjusted with a piecewise-parabolic fit as observations arrive.
+//! [`stream_quantiles`] runs one estimator per quantile over a superstore
+//! stream, so percentiles of arbitrarily large datasets never need the rows
+//! in memory.
+
+use crate::general::SuperstoreRow;
+use crate::streaming::{superstore_stream, StreamingError};
+
+/// Error type for streaming quantiles
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantileError {
+    /// Quantiles must lie in [0, 1]
+    InvalidQuantile(f64),
+    /// Column isn't a numeric superstore column
+    UnknownColumn(String),
+    /// The underlying stream couldn't be created
+    Streaming(StreamingError),
+}
+
+impl std::fmt::Display for QuantileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuantileError::InvalidQuantile(q) => {
+                write!(f, "Invalid quantile: {} (must be between 0 and 1)", q)
+            }
+            QuantileError::UnknownColumn(column) => write!(
+                f,
+                "Unknown column '{}'. Must be one of: {}",
+                column,
+                QUANTILE_COLUMNS.join(", ")
+            ),
+            QuantileError::Streaming(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for QuantileError {}
+
+impl From<StreamingError> for QuantileError {
+    fn from(err: StreamingError) -> Self {
+        QuantileError::Streaming(err)
+    }
+}
+
+/// Numeric superstore columns [`stream_quantiles`] accepts
+pub const QUANTILE_COLUMNS: [&str; 5] = ["Sales", "Quantity", "Discount", "Profit", "Item Price"];
+
+/// Value of a numeric column, matched case-insensitively with spaces and
+/// underscores interchangeable (`"Item Price"` or `"item_price"`)
+fn column_value(column: &str) -> Option<fn(&SuperstoreRow) -> f64> {
+    let column = column.to_lowercase().replace(' ', "_");
+    Some(match column.as_str() {
+        "sales" => |r| r.sales as f64,
+        "quantity" => |r| r.quantity as f64,
+        "discount" => |r| r.discount,
+        "profit" => |r| r.profit,
+        "item_price" => |r| r.item_price,
+        _ => return None,
+    })
+}
+
+/// Constant-memory estimate of quantile `p` of a stream (P² algorithm).
+///
+/// The first five observations are kept exactly; after that five markers
+/// track the minimum, `p/2`, `p`, `(1+p)/2` quantiles, and the maximum.
+///
+/// # Example
+/// ```
+/// use superstore::quantiles::P2Quantile;
+///
+/// let mut median = P2Quantile::new(0.5).unwrap();
+/// for x in 1..=1001 {
+///     median.observe(x as f64);
+/// }
+/// assert!((median.estimate().unwrap() - 501.0).abs() < 1.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct P2Quantile {
+    p: f64,
+    count: usize,
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Quantile {
+    /// Estimator for quantile `p`, which must lie in [0, 1]
+    pub fn new(p: f64) -> Result<Self, QuantileError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(QuantileError::InvalidQuantile(p));
+        }
+        Ok(Self {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        })
+    }
+
+    /// Number of values observed so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Add one value to the stream; NaN values are ignored and not counted
+    pub fn observe(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        if self.count < 5 {
+            self.heights[self.count] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        // Cell the value falls in, stretching the extremes if needed
+        let q = &mut self.heights;
+        let k = if x < q[0] {
+            q[0] = x;
+            0
+        } else if x >= q[4] {
+            q[4] = x;
+            3
+        } else {
+            (1..5).find(|&i| x < q[i]).unwrap() - 1
+        };
+        for position in &mut self.positions[k + 1..] {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        // Move the middle markers toward their desired positions
+        for i in 1..4 {
+            let n = &self.positions;
+            let offset = self.desired[i] - n[i];
+            if (offset >= 1.0 && n[i + 1] - n[i] > 1.0)
+                || (offset <= -1.0 && n[i - 1] - n[i] < -1.0)
+            {
+                let d = offset.signum();
+                let parabolic = self.parabolic(i, d);
+                self.heights[i] =
+                    if self.heights[i - 1] < parabolic && parabolic < self.heights[i + 1] {
+                        parabolic
+                    } else {
+                        self.linear(i, d)
+                    };
+                self.positions[i] += d;
+            }
+        }
+    }
+
+    fn parabolic(&self, i: usize, d: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + d / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    fn linear(&self, i: usize, d: f64) -> f64 {
+        let j = if d > 0.0 { i + 1 } else { i - 1 };
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + d * (q[j] - q[i]) / (n[j] - n[i])
+    }
+
+    /// Current estimate, or `None` before any value is observed. Up to five
+    /// values, and for `p` of 0 or 1 (the extreme markers), the exact
+    /// quantile (linearly interpolated) is returned.
+    pub fn estimate(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            1..=4 => {
+                let mut seen = self.heights[..self.count].to_vec();
+                seen.sort_by(f64::total_cmp);
+                let rank = self.p * (seen.len() - 1) as f64;
+                let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+                Some(seen[low] + (rank - low as f64) * (seen[high] - seen[low]))
+            }
+            _ if self.p == 0.0 => Some(self.heights[0]),
+            _ if self.p == 1.0 => Some(self.heights[4]),
+            _ => Some(self.heights[2]),
+        }
+    }
+}
+
+/// Estimate `quantiles` of a numeric superstore `column` over a stream of
+/// `count` rows, generated `chunk_size` rows at a time.
+///
+/// Only one chunk is held in memory, and each quantile costs a constant
+/// amount of memory (see [`P2Quantile`]), so this works for datasets far
+/// larger than memory. The rows are those of
+/// [`superstore_stream`] with the same seed. Estimates are `NaN` when
+/// `count` is zero.
+///
+/// `column` is one of [`QUANTILE_COLUMNS`], matched case-insensitively with
+/// spaces and underscores interchangeable.
+///
+/// # Example
+/// ```
+/// use superstore::quantiles::stream_quantiles;
+///
+/// // p50, p95, and p99 of sales without holding the rows
+/// let q = stream_quantiles(20_000, 5_000, "sales", &[0.5, 0.95, 0.99], Some(42)).unwrap();
+/// assert!(q[0] <= q[1] && q[1] <= q[2]);
+/// ```
+pub fn stream_quantiles(
+    count: usize,
+    chunk_size: usize,
+    column: &str,
+    quantiles: &[f64],
+    seed: Option<u64>,
+) -> Result<Vec<f64>, QuantileError> {
+    let value = column_value(column).ok_or_else(|| QuantileError::UnknownColumn(column.into()))?;
+    let mut estimators = quantiles
+        .iter()
+        .map(|&q| P2Quantile::new(q))
+        .collect::<Result<Vec<_>, _>>()?;
+    for chunk in superstore_stream(count, chunk_size, seed)? {
+        for row in &chunk {
+            let x = value(row);
+            for estimator in &mut estimators {
+                estimator.observe(x);
+            }
+        }
+    }
+    Ok(estimators
+        .iter()
+        .map(|e| e.estimate().unwrap_or(f64::NAN))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::seeded_rng;
+    use rand::RngExt;
+
+    fn exact_quantile(values: &[f64], p: f64) -> f64 {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let rank = p * (sorted.len() - 1) as f64;
+        let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+        sorted[low] + (rank - low as f64) * (sorted[high] - sorted[low])
+    }
+
+    #[test]
+    fn test_p2_matches_exact_quantiles() {
+        let mut rng = seeded_rng(Some(42));
+        let values: Vec<f64> = (0..20_000)
+            .map(|_| -rng.random::<f64>().ln() * 10.0)
+            .collect();
+        for p in [0.0, 0.05, 0.5, 0.95, 0.99, 1.0] {
+            let mut estimator = P2Quantile::new(p).unwrap();
+            values.iter().for_each(|&x| estimator.observe(x));
+            let exact = exact_quantile(&values, p);
+            let estimate = estimator.estimate().unwrap();
+            assert!(
+                (estimate - exact).abs() <= 0.02 * exact.max(1.0),
+                "p{}: {} vs {}",
+                p,
+                estimate,
+                exact
+            );
+        }
+
+        let mut few = P2Quantile::new(0.5).unwrap();
+        assert_eq!(few.estimate(), None);
+        for x in [3.0, 1.0, 2.0, 10.0] {
+            few.observe(x);
+        }
+        assert_eq!(few.estimate(), Some(2.5));
+        assert_eq!(few.count(), 4);
+    }
+
+    #[test]
+    fn test_p2_ignores_nan() {
+        let mut estimator = P2Quantile::new(0.5).unwrap();
+        estimator.observe(f64::NAN);
+        assert_eq!(estimator.estimate(), None);
+        for x in 1..=101 {
+            estimator.observe(x as f64);
+            estimator.observe(f64::NAN);
+        }
+        assert_eq!(estimator.count(), 101);
+        assert!((estimator.estimate().unwrap() - 51.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_stream_quantiles_match_exact() {
+        use crate::general::{superstore_with_config, SuperstoreConfig};
+
+        let quantiles = [0.5, 0.95, 0.99];
+        let rows = superstore_with_config(&SuperstoreConfig {
+            count: 20_000,
+            seed: Some(7),
+            ..Default::default()
+        });
+        for column in ["sales", "Profit", "item_price"] {
+            let value = column_value(column).unwrap();
+            let values: Vec<f64> = rows.iter().map(value).collect();
+            let estimates = stream_quantiles(20_000, 3_000, column, &quantiles, Some(7)).unwrap();
+            let spread = exact_quantile(&values, 0.99) - exact_quantile(&values, 0.01);
+            for (&p, estimate) in quantiles.iter().zip(estimates) {
+                let exact = exact_quantile(&values, p);
+                assert!(
+                    (estimate - exact).abs() <= 0.02 * spread,
+                    "{} p{}: {} vs {}",
+                    column,
+                    p,
+                    estimate,
+                    exact
+                );
+            }
+        }
+
+        assert!(stream_quantiles(0, 10, "sales", &[0.5], None).unwrap()[0].is_nan());
+        assert_eq!(
+            stream_quantiles(10, 10, "latency", &[0.5], None),
+            Err(QuantileError::UnknownColumn("latency".into()))
+        );
+        assert_eq!(
+            stream_quantiles(10, 10, "sales", &[1.5], None),
+            Err(QuantileError::InvalidQuantile(1.5))
+        );
+        assert_eq!(
+            stream_quantiles(10, 0, "sales", &[0.5], None),
+            Err(QuantileError::Streaming(StreamingError::InvalidChunkSize(
+                0
+            )))
+        );
+    }
+}
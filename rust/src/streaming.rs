@@ -2,19 +2,48 @@
 //!
 //! This module provides iterator-based generators that yield data in chunks,
 //! allowing processing of arbitrarily large datasets without loading everything
-//! into memory at once.
+//! into memory at once. Chunks can be split across partitions by a row key, as
+//! a Kafka producer would, via [`Partitioned`].
 
-use rand::rngs::StdRng;
+use crate::rng::{seeded_rng, SeededRng};
 use rand::seq::IndexedRandom;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
 
-use crate::general::{EmployeeRow, SuperstoreRow};
-use crate::utils::{US_SECTORS, US_SECTORS_MAP};
+use crate::general::{
+    pool_rng, EmployeeConfig, EmployeeOrg, EmployeeRow, LocationPool, NamePool, SuperstoreConfig,
+    SuperstoreConfigError, SuperstoreRow, SuperstoreRowIter, DEFAULT_POOL_SIZE, DEFAULT_REGIONS,
+};
 
-use chrono::{Datelike, NaiveDate, Utc};
+/// Error type for streaming generators
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamingError {
+    /// Chunks must hold at least one row
+    InvalidChunkSize(usize),
+    /// `stable_v1` output is frozen to the eager generator
+    StableV1,
+    /// The config fails [`SuperstoreConfig::validate`]
+    InvalidConfig(SuperstoreConfigError),
+    /// Rows must be split across at least one partition
+    InvalidPartitions(usize),
+}
+
+impl std::fmt::Display for StreamingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamingError::InvalidChunkSize(size) => {
+                write!(f, "Invalid chunk_size: {} (must be at least 1)", size)
+            }
+            StreamingError::StableV1 => write!(f, "Streaming doesn't support stable_v1"),
+            StreamingError::InvalidConfig(e) => write!(f, "{}", e),
+            StreamingError::InvalidPartitions(partitions) => {
+                write!(f, "Invalid partitions: {} (must be at least 1)", partitions)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StreamingError {}
 
-const SHIP_MODES: [&str; 3] = ["First Class", "Standard Class", "Second Class"];
-const SEGMENTS: [&str; 4] = ["A", "B", "C", "D"];
 const PREFIXES: [&str; 6] = ["Mr.", "Mrs.", "Ms.", "Dr.", "Prof.", "Rev."];
 const SUFFIXES: [&str; 4] = ["Jr.", "Sr.", "III", "IV"];
 
@@ -26,20 +55,6 @@ fn generate_ein<R: Rng>(rng: &mut R) -> String {
     )
 }
 
-fn generate_license_plate<R: Rng>(rng: &mut R) -> String {
-    let letters: String = (0..3)
-        .map(|_| (b'A' + rng.random_range(0..26)) as char)
-        .collect();
-    let numbers: u16 = rng.random_range(100..1000);
-    format!("{}{}", letters, numbers)
-}
-
-fn generate_bban<R: Rng>(rng: &mut R) -> String {
-    (0..18)
-        .map(|_| (b'0' + rng.random_range(0..10)) as char)
-        .collect()
-}
-
 fn generate_ssn<R: Rng>(rng: &mut R) -> String {
     format!(
         "{:03}-{:02}-{:04}",
@@ -66,115 +81,21 @@ fn generate_street_address<R: Rng>(rng: &mut R) -> String {
     format!("{} {}", number, street_names.choose(rng).unwrap())
 }
 
-fn generate_city<R: Rng>(rng: &mut R) -> String {
-    const CITIES: [&str; 12] = [
-        "Springfield",
-        "Franklin",
-        "Riverton",
-        "Madison",
-        "Clinton",
-        "Georgetown",
-        "Salem",
-        "Arlington",
-        "Fairview",
-        "Ashland",
-        "Burlington",
-        "Milton",
-    ];
-    CITIES[rng.random_range(0..CITIES.len())].to_string()
-}
-
-fn generate_state<R: Rng>(rng: &mut R) -> String {
-    const STATES: [&str; 10] = ["CA", "NY", "TX", "WA", "FL", "IL", "GA", "AZ", "CO", "NC"];
-    STATES[rng.random_range(0..STATES.len())].to_string()
-}
-
-fn generate_zip<R: Rng>(rng: &mut R) -> String {
-    format!("{:05}", rng.random_range(10000..100000))
-}
-
-fn generate_first_name<R: Rng>(rng: &mut R) -> String {
-    const FIRST_NAMES: [&str; 16] = [
-        "Alex", "Jordan", "Taylor", "Casey", "Morgan", "Riley", "Avery", "Parker", "Quinn",
-        "Jamie", "Drew", "Reese", "Skyler", "Hayden", "Cameron", "Rowan",
-    ];
-    FIRST_NAMES[rng.random_range(0..FIRST_NAMES.len())].to_string()
-}
-
-fn generate_last_name<R: Rng>(rng: &mut R) -> String {
-    const LAST_NAMES: [&str; 16] = [
-        "Smith", "Johnson", "Davis", "Miller", "Wilson", "Moore", "Taylor", "Anderson", "Thomas",
-        "Jackson", "White", "Harris", "Martin", "Thompson", "Garcia", "Clark",
-    ];
-    LAST_NAMES[rng.random_range(0..LAST_NAMES.len())].to_string()
-}
-
-fn generate_email<R: Rng>(rng: &mut R) -> String {
-    const DOMAINS: [&str; 4] = ["example.com", "corp.test", "mail.test", "demo.local"];
-    let first = generate_first_name(rng).to_lowercase();
-    let last = generate_last_name(rng).to_lowercase();
-    let domain = DOMAINS[rng.random_range(0..DOMAINS.len())];
-    format!("{}.{}@{}", first, last, domain)
-}
-
-fn generate_phone<R: Rng>(rng: &mut R) -> String {
-    format!(
-        "({:03}) {:03}-{:04}",
-        rng.random_range(200..1000),
-        rng.random_range(100..1000),
-        rng.random_range(1000..10000)
-    )
-}
-
-fn random_date_this_year<R: Rng>(rng: &mut R) -> NaiveDate {
-    let year = Utc::now().naive_utc().date().year();
-    let day_of_year = rng.random_range(1..=365);
-    NaiveDate::from_yo_opt(year, day_of_year)
-        .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, 1, 1).unwrap())
-}
-
-fn random_date_between<R: Rng>(rng: &mut R, start: NaiveDate) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    if start >= today {
-        return today;
-    }
-    let days_between = (today - start).num_days() as u32;
-    if days_between == 0 {
-        return start;
-    }
-    let random_days = rng.random_range(0..=days_between);
-    start + chrono::Duration::days(random_days as i64)
-}
-
-fn random_date_30_years<R: Rng>(rng: &mut R) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    let thirty_years_ago = today - chrono::Duration::days(30 * 365);
-    let days_range = (today - thirty_years_ago).num_days() as u32;
-    let random_days = rng.random_range(0..=days_range);
-    thirty_years_ago + chrono::Duration::days(random_days as i64)
-}
-
-fn random_date_of_birth<R: Rng>(rng: &mut R) -> NaiveDate {
-    let today = Utc::now().naive_utc().date();
-    let min_age = 18;
-    let max_age = 70;
-    let min_date = today - chrono::Duration::days(max_age * 365);
-    let max_date = today - chrono::Duration::days(min_age * 365);
-    let days_range = (max_date - min_date).num_days() as u32;
-    let random_days = rng.random_range(0..=days_range);
-    min_date + chrono::Duration::days(random_days as i64)
-}
-
 /// Iterator that generates superstore rows in chunks.
 ///
 /// This is memory-efficient for large datasets as it only holds one chunk
-/// in memory at a time.
+/// in memory at a time. Every chunk except the last holds exactly
+/// `chunk_size` rows, and the chunk lengths sum to `total_count`.
+///
+/// With a seed, the chunks flattened are exactly the rows of
+/// [`superstore`](crate::superstore) with the same count and seed,
+/// whatever the chunk size. Locations are drawn from the same pool of
+/// `pool_size` pre-generated values (see [`SuperstoreIterator::with_pool_size`]).
 pub struct SuperstoreIterator {
-    rng: StdRng,
+    rows: SuperstoreRowIter,
     total_count: usize,
     generated: usize,
     chunk_size: usize,
-    sectors: Vec<&'static str>,
 }
 
 impl SuperstoreIterator {
@@ -184,18 +105,51 @@ impl SuperstoreIterator {
     /// * `total_count` - Total number of rows to generate
     /// * `chunk_size` - Number of rows per chunk (default: 1000)
     /// * `seed` - Optional seed for reproducibility
-    pub fn new(total_count: usize, chunk_size: usize, seed: Option<u64>) -> Self {
-        let rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
+    ///
+    /// Returns an error if `chunk_size` is zero.
+    pub fn new(
+        total_count: usize,
+        chunk_size: usize,
+        seed: Option<u64>,
+    ) -> Result<Self, StreamingError> {
+        if chunk_size == 0 {
+            return Err(StreamingError::InvalidChunkSize(chunk_size));
+        }
+        let config = SuperstoreConfig {
+            count: total_count,
+            seed,
+            ..Default::default()
         };
-        Self {
-            rng,
+        Ok(Self {
+            rows: SuperstoreRowIter::new(&config),
             total_count,
             generated: 0,
             chunk_size,
-            sectors: US_SECTORS.clone(),
+        })
+    }
+
+    /// Draw locations from a pool of `pool_size` values (default 1000)
+    /// instead, matching the eager generator with the same `pool_size`.
+    ///
+    /// The pool has its own RNG, so this changes only the pooled values and
+    /// can be called at any point in the stream.
+    pub fn with_pool_size(mut self, pool_size: usize) -> Self {
+        self.rows = self.rows.with_pool_size(pool_size);
+        self
+    }
+
+    /// Advance to row `n` (0-based), so the next chunk starts with the row a
+    /// fresh stream with the same seed would yield at position `n`.
+    ///
+    /// Rows consume a variable number of random draws, so this generates and
+    /// discards the skipped rows; it is O(n) but allocates only one row at a
+    /// time. Positions at or before the current one are a no-op.
+    pub fn skip_to(mut self, n: usize) -> Self {
+        while self.generated < n.min(self.total_count) {
+            self.rows.next();
+            self.generated += 1;
         }
+        self
     }
 }
 
@@ -211,56 +165,90 @@ impl Iterator for SuperstoreIterator {
         let chunk_len = remaining.min(self.chunk_size);
         let mut chunk = Vec::with_capacity(chunk_len);
 
-        for i in 0..chunk_len {
-            let row_id = (self.generated + i) as i32;
-            let order_date = random_date_this_year(&mut self.rng);
-            let ship_date = random_date_between(&mut self.rng, order_date);
-
-            let sector = *self.sectors.choose(&mut self.rng).unwrap();
-            let industries = US_SECTORS_MAP.get(sector).unwrap();
-            let industry = *industries.choose(&mut self.rng).unwrap();
-
-            let row = SuperstoreRow {
-                row_id,
-                order_id: generate_ein(&mut self.rng),
-                order_date: order_date.format("%Y-%m-%d").to_string(),
-                ship_date: ship_date.format("%Y-%m-%d").to_string(),
-                ship_mode: SHIP_MODES.choose(&mut self.rng).unwrap().to_string(),
-                customer_id: generate_license_plate(&mut self.rng),
-                segment: SEGMENTS.choose(&mut self.rng).unwrap().to_string(),
-                country: "US".to_string(),
-                city: generate_city(&mut self.rng),
-                state: generate_state(&mut self.rng),
-                postal_code: generate_zip(&mut self.rng),
-                region: format!("Region {}", self.rng.random_range(0..5)),
-                product_id: generate_bban(&mut self.rng),
-                category: sector.to_string(),
-                sub_category: industry.to_string(),
-                item_status: "Regular".to_string(),
-                item_price: (self.rng.random_range(1..=100) as f64) * 10.0 + 0.99,
-                sales: self.rng.random_range(1..=100) * 100,
-                quantity: self.rng.random_range(1..=100) * 10,
-                discount: (self.rng.random::<f64>() * 100.0 * 100.0).round() / 100.0,
-                profit: (self.rng.random::<f64>() * 1000.0 * 100.0).round() / 100.0,
-                // Priority 4 fields (not enabled in streaming simple mode)
-                bundle_id: None,
-                payment_method: None,
-                is_fraud: None,
-                processing_fee: None,
-                backorder_days: None,
-                stock_status: None,
-            };
-            chunk.push(row);
+        chunk.extend(self.rows.by_ref().take(chunk_len));
+
+        self.generated += chunk_len;
+        Some(chunk)
+    }
+}
+
+/// Iterator that generates superstore rows in chunks from a full
+/// [`SuperstoreConfig`].
+///
+/// Every configured feature (seasonality, promotions, cohorts, bundling,
+/// inventory, payment, locale, copula) applies: the chunks flattened are
+/// exactly the sale rows of
+/// [`superstore_with_config`](crate::superstore_with_config) with the same
+/// config, whatever the chunk size. Location and customer pools are generated
+/// once when the iterator is created and shared by every chunk.
+///
+/// `config.count` is the total number of rows. Return rows, which the eager
+/// generator appends after every sale, are not streamed, and `stable_v1`
+/// configs are rejected.
+pub struct SuperstoreConfigIterator {
+    rows: SuperstoreRowIter,
+    total_count: usize,
+    generated: usize,
+    chunk_size: usize,
+}
+
+impl SuperstoreConfigIterator {
+    /// Create a new streaming superstore generator for `config`.
+    ///
+    /// Returns an error if `chunk_size` is zero, `config.stable_v1` is set,
+    /// or [`SuperstoreConfig::validate`] fails.
+    pub fn new(config: &SuperstoreConfig, chunk_size: usize) -> Result<Self, StreamingError> {
+        if chunk_size == 0 {
+            return Err(StreamingError::InvalidChunkSize(chunk_size));
+        }
+        if config.stable_v1 {
+            return Err(StreamingError::StableV1);
+        }
+        config.validate().map_err(StreamingError::InvalidConfig)?;
+        Ok(Self {
+            rows: SuperstoreRowIter::new(config),
+            total_count: config.count,
+            generated: 0,
+            chunk_size,
+        })
+    }
+
+    /// Advance to row `n` (0-based), as [`SuperstoreIterator::skip_to`].
+    pub fn skip_to(mut self, n: usize) -> Self {
+        while self.generated < n.min(self.total_count) {
+            self.rows.next();
+            self.generated += 1;
+        }
+        self
+    }
+}
+
+impl Iterator for SuperstoreConfigIterator {
+    type Item = Vec<SuperstoreRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.generated >= self.total_count {
+            return None;
         }
 
+        let chunk_len = (self.total_count - self.generated).min(self.chunk_size);
+        let chunk: Vec<SuperstoreRow> = self.rows.by_ref().take(chunk_len).collect();
+
         self.generated += chunk_len;
         Some(chunk)
     }
 }
 
 /// Iterator that generates employee rows in chunks.
+///
+/// Like the eager generator, locations, names, emails, and phone numbers are
+/// drawn from pools of `pool_size` pre-generated values.
 pub struct EmployeeIterator {
-    rng: StdRng,
+    rng: SeededRng,
+    seed: Option<u64>,
+    location_pool: LocationPool,
+    name_pool: NamePool,
+    org: EmployeeOrg,
     total_count: usize,
     generated: usize,
     chunk_size: usize,
@@ -273,17 +261,98 @@ impl EmployeeIterator {
     /// * `total_count` - Total number of rows to generate
     /// * `chunk_size` - Number of rows per chunk (default: 1000)
     /// * `seed` - Optional seed for reproducibility
-    pub fn new(total_count: usize, chunk_size: usize, seed: Option<u64>) -> Self {
-        let rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_rng(&mut rand::rng()),
-        };
-        Self {
+    ///
+    /// Returns an error if `chunk_size` is zero.
+    pub fn new(
+        total_count: usize,
+        chunk_size: usize,
+        seed: Option<u64>,
+    ) -> Result<Self, StreamingError> {
+        if chunk_size == 0 {
+            return Err(StreamingError::InvalidChunkSize(chunk_size));
+        }
+        let rng = seeded_rng(seed);
+        let (location_pool, name_pool) = Self::pools(seed, DEFAULT_POOL_SIZE);
+        Ok(Self {
             rng,
+            seed,
+            location_pool,
+            name_pool,
+            org: EmployeeOrg::new(&EmployeeConfig::default()),
             total_count,
             generated: 0,
             chunk_size,
+        })
+    }
+
+    /// Draw from pools of `pool_size` values (default 1000) instead; see
+    /// [`SuperstoreIterator::with_pool_size`].
+    pub fn with_pool_size(mut self, pool_size: usize) -> Self {
+        (self.location_pool, self.name_pool) = Self::pools(self.seed, pool_size);
+        self
+    }
+
+    /// Location and name pools, generated as `employees_with_config` does
+    fn pools(seed: Option<u64>, pool_size: usize) -> (LocationPool, NamePool) {
+        let mut rng = pool_rng(seed);
+        let location_pool = LocationPool::generate(&mut rng, pool_size);
+        let name_pool = NamePool::generate(&mut rng, pool_size);
+        (location_pool, name_pool)
+    }
+
+    /// Advance to row `n` (0-based); see [`SuperstoreIterator::skip_to`].
+    pub fn skip_to(mut self, n: usize) -> Self {
+        while self.generated < n.min(self.total_count) {
+            self.generate_row(self.generated as i32);
+            self.generated += 1;
         }
+        self
+    }
+
+    fn generate_row(&mut self, row_id: i32) -> EmployeeRow {
+        let (date_of_birth, start_date, termination_date) =
+            self.org.employment_dates(&mut self.rng);
+        let mut row = EmployeeRow {
+            row_id,
+            employee_id: generate_ein(&mut self.rng),
+            first_name: self
+                .name_pool
+                .first_names
+                .choose(&mut self.rng)
+                .unwrap()
+                .clone(),
+            surname: self
+                .name_pool
+                .last_names
+                .choose(&mut self.rng)
+                .unwrap()
+                .clone(),
+            prefix: PREFIXES.choose(&mut self.rng).unwrap().to_string(),
+            suffix: SUFFIXES.choose(&mut self.rng).unwrap().to_string(),
+            phone_number: self
+                .name_pool
+                .phone_numbers
+                .choose(&mut self.rng)
+                .unwrap()
+                .clone(),
+            email: self.name_pool.emails.choose(&mut self.rng).unwrap().clone(),
+            ssn: generate_ssn(&mut self.rng),
+            street: generate_street_address(&mut self.rng),
+            city: self.location_pool.random_city(&mut self.rng).to_string(),
+            postal_code: self.location_pool.random_zip(&mut self.rng).to_string(),
+            region: DEFAULT_REGIONS.choose(&mut self.rng).unwrap().to_string(),
+            state: self.location_pool.random_state(&mut self.rng).to_string(),
+            country: "US".to_string(),
+            start_date,
+            date_of_birth,
+            department: String::new(),
+            job_title: String::new(),
+            salary: 0.0,
+            manager_id: None,
+            termination_date,
+        };
+        self.org.place(&mut self.rng, &mut row);
+        row
     }
 }
 
@@ -300,27 +369,7 @@ impl Iterator for EmployeeIterator {
         let mut chunk = Vec::with_capacity(chunk_len);
 
         for i in 0..chunk_len {
-            let row_id = (self.generated + i) as i32;
-            let row = EmployeeRow {
-                row_id,
-                employee_id: generate_ein(&mut self.rng),
-                first_name: generate_first_name(&mut self.rng),
-                surname: generate_last_name(&mut self.rng),
-                prefix: PREFIXES.choose(&mut self.rng).unwrap().to_string(),
-                suffix: SUFFIXES.choose(&mut self.rng).unwrap().to_string(),
-                phone_number: generate_phone(&mut self.rng),
-                email: generate_email(&mut self.rng),
-                ssn: generate_ssn(&mut self.rng),
-                street: generate_street_address(&mut self.rng),
-                city: generate_city(&mut self.rng),
-                postal_code: generate_zip(&mut self.rng),
-                region: format!("Region {}", self.rng.random_range(0..5)),
-                state: generate_state(&mut self.rng),
-                country: "US".to_string(),
-                start_date: random_date_30_years(&mut self.rng),
-                date_of_birth: random_date_of_birth(&mut self.rng),
-            };
-            chunk.push(row);
+            chunk.push(self.generate_row((self.generated + i) as i32));
         }
 
         self.generated += chunk_len;
@@ -328,6 +377,142 @@ impl Iterator for EmployeeIterator {
     }
 }
 
+/// Kafka's murmur2 hash (the variant used by its default partitioner)
+fn murmur2(data: &[u8]) -> u32 {
+    const SEED: u32 = 0x9747_b28c;
+    const M: u32 = 0x5bd1_e995;
+    const R: u32 = 24;
+
+    let mut h = SEED ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        for (i, byte) in tail.iter().enumerate() {
+            h ^= (*byte as u32) << (8 * i);
+        }
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+    h
+}
+
+/// Partition for a key out of `partitions`, matching Kafka's default
+/// partitioner (`toPositive(murmur2(key)) % partitions`). Errors if
+/// `partitions` is zero.
+pub fn kafka_partition(key: &[u8], partitions: usize) -> Result<usize, StreamingError> {
+    if partitions == 0 {
+        return Err(StreamingError::InvalidPartitions(partitions));
+    }
+    Ok(partition_of(key, partitions))
+}
+
+/// [`kafka_partition`] for a `partitions` already checked to be non-zero
+fn partition_of(key: &[u8], partitions: usize) -> usize {
+    (murmur2(key) & 0x7fff_ffff) as usize % partitions
+}
+
+/// Iterator adapter that tags every row of each chunk with its partition.
+///
+/// Rows are assigned with [`kafka_partition`] on the key returned by the
+/// key function, so the same key always lands on the same partition.
+pub struct Partitioned<I, F> {
+    iter: I,
+    partitions: usize,
+    key: F,
+}
+
+impl<I, F> Partitioned<I, F> {
+    /// Wrap a chunk iterator, assigning rows to `partitions` by `key`.
+    /// Errors if `partitions` is zero.
+    pub fn new(iter: I, partitions: usize, key: F) -> Result<Self, StreamingError> {
+        if partitions == 0 {
+            return Err(StreamingError::InvalidPartitions(partitions));
+        }
+        Ok(Self {
+            iter,
+            partitions,
+            key,
+        })
+    }
+}
+
+impl<I, T, F> Iterator for Partitioned<I, F>
+where
+    I: Iterator<Item = Vec<T>>,
+    F: Fn(&T) -> &str,
+{
+    type Item = Vec<(usize, T)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.next()?;
+        Some(
+            chunk
+                .into_iter()
+                .map(|row| {
+                    let partition = partition_of((self.key)(&row).as_bytes(), self.partitions);
+                    (partition, row)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl SuperstoreIterator {
+    /// Tag each row with one of `partitions` partitions by `key`
+    /// (e.g. `|r| r.customer_id.as_str()`).
+    pub fn partitioned<F>(
+        self,
+        partitions: usize,
+        key: F,
+    ) -> Result<Partitioned<Self, F>, StreamingError>
+    where
+        F: Fn(&SuperstoreRow) -> &str,
+    {
+        Partitioned::new(self, partitions, key)
+    }
+}
+
+impl SuperstoreConfigIterator {
+    /// Tag each row with one of `partitions` partitions by `key`
+    /// (e.g. `|r| r.customer_id.as_str()`).
+    pub fn partitioned<F>(
+        self,
+        partitions: usize,
+        key: F,
+    ) -> Result<Partitioned<Self, F>, StreamingError>
+    where
+        F: Fn(&SuperstoreRow) -> &str,
+    {
+        Partitioned::new(self, partitions, key)
+    }
+}
+
+impl EmployeeIterator {
+    /// Tag each row with one of `partitions` partitions by `key`
+    /// (e.g. `|r| r.employee_id.as_str()`).
+    pub fn partitioned<F>(
+        self,
+        partitions: usize,
+        key: F,
+    ) -> Result<Partitioned<Self, F>, StreamingError>
+    where
+        F: Fn(&EmployeeRow) -> &str,
+    {
+        Partitioned::new(self, partitions, key)
+    }
+}
+
 /// Create a streaming superstore generator.
 ///
 /// # Example
@@ -335,7 +520,7 @@ impl Iterator for EmployeeIterator {
 /// use superstore::streaming::superstore_stream;
 ///
 /// // Generate 1 million rows in chunks of 10,000
-/// for chunk in superstore_stream(1_000_000, 10_000, Some(42)) {
+/// for chunk in superstore_stream(1_000_000, 10_000, Some(42)).unwrap() {
 ///     // Process each chunk
 ///     println!("Processing {} rows", chunk.len());
 /// }
@@ -344,10 +529,39 @@ pub fn superstore_stream(
     total_count: usize,
     chunk_size: usize,
     seed: Option<u64>,
-) -> SuperstoreIterator {
+) -> Result<SuperstoreIterator, StreamingError> {
     SuperstoreIterator::new(total_count, chunk_size, seed)
 }
 
+/// Create a streaming superstore generator for a full configuration, whose
+/// chunks flattened are the sale rows of
+/// [`superstore_with_config`](crate::superstore_with_config).
+///
+/// # Example
+/// ```
+/// use superstore::streaming::superstore_stream_with_config;
+/// use superstore::{SeasonalityConfig, SuperstoreConfig};
+///
+/// let config = SuperstoreConfig {
+///     count: 100_000,
+///     seed: Some(42),
+///     seasonality: SeasonalityConfig {
+///         enable: true,
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// for chunk in superstore_stream_with_config(&config, 10_000).unwrap() {
+///     println!("Processing {} rows", chunk.len());
+/// }
+/// ```
+pub fn superstore_stream_with_config(
+    config: &SuperstoreConfig,
+    chunk_size: usize,
+) -> Result<SuperstoreConfigIterator, StreamingError> {
+    SuperstoreConfigIterator::new(config, chunk_size)
+}
+
 /// Create a streaming employee generator.
 ///
 /// # Example
@@ -355,7 +569,7 @@ pub fn superstore_stream(
 /// use superstore::streaming::employees_stream;
 ///
 /// // Generate 1 million employees in chunks of 10,000
-/// for chunk in employees_stream(1_000_000, 10_000, Some(42)) {
+/// for chunk in employees_stream(1_000_000, 10_000, Some(42)).unwrap() {
 ///     // Process each chunk
 ///     println!("Processing {} employees", chunk.len());
 /// }
@@ -364,7 +578,7 @@ pub fn employees_stream(
     total_count: usize,
     chunk_size: usize,
     seed: Option<u64>,
-) -> EmployeeIterator {
+) -> Result<EmployeeIterator, StreamingError> {
     EmployeeIterator::new(total_count, chunk_size, seed)
 }
 
@@ -374,7 +588,7 @@ mod tests {
 
     #[test]
     fn test_superstore_stream_basic() {
-        let chunks: Vec<_> = superstore_stream(100, 30, Some(42)).collect();
+        let chunks: Vec<_> = superstore_stream(100, 30, Some(42)).unwrap().collect();
         assert_eq!(chunks.len(), 4); // 30 + 30 + 30 + 10
         assert_eq!(chunks[0].len(), 30);
         assert_eq!(chunks[1].len(), 30);
@@ -384,8 +598,8 @@ mod tests {
 
     #[test]
     fn test_superstore_stream_reproducible() {
-        let chunks1: Vec<_> = superstore_stream(100, 50, Some(42)).collect();
-        let chunks2: Vec<_> = superstore_stream(100, 50, Some(42)).collect();
+        let chunks1: Vec<_> = superstore_stream(100, 50, Some(42)).unwrap().collect();
+        let chunks2: Vec<_> = superstore_stream(100, 50, Some(42)).unwrap().collect();
 
         assert_eq!(chunks1.len(), chunks2.len());
         for (c1, c2) in chunks1.iter().zip(chunks2.iter()) {
@@ -398,7 +612,7 @@ mod tests {
 
     #[test]
     fn test_employees_stream_basic() {
-        let chunks: Vec<_> = employees_stream(100, 30, Some(42)).collect();
+        let chunks: Vec<_> = employees_stream(100, 30, Some(42)).unwrap().collect();
         assert_eq!(chunks.len(), 4);
         let total: usize = chunks.iter().map(|c| c.len()).sum();
         assert_eq!(total, 100);
@@ -406,8 +620,8 @@ mod tests {
 
     #[test]
     fn test_employees_stream_reproducible() {
-        let chunks1: Vec<_> = employees_stream(50, 20, Some(123)).collect();
-        let chunks2: Vec<_> = employees_stream(50, 20, Some(123)).collect();
+        let chunks1: Vec<_> = employees_stream(50, 20, Some(123)).unwrap().collect();
+        let chunks2: Vec<_> = employees_stream(50, 20, Some(123)).unwrap().collect();
 
         for (c1, c2) in chunks1.iter().zip(chunks2.iter()) {
             for (r1, r2) in c1.iter().zip(c2.iter()) {
@@ -417,10 +631,411 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_kafka_partition_matches_murmur2() {
+        // Reference values from Kafka's own murmur2 tests (as signed i32)
+        let cases: [(&[u8], i32); 6] = [
+            (b"21", -973932308),
+            (b"foobar", -790332482),
+            (b"a-little-bit-long-string", -985981536),
+            (b"a-little-bit-longer-string", -1486304829),
+            (
+                b"lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8",
+                -58897971,
+            ),
+            (b"abc", 479470107),
+        ];
+        for (key, expected) in cases {
+            assert_eq!(murmur2(key) as i32, expected);
+        }
+    }
+
+    #[test]
+    fn test_partitioned_stream_stable_and_balanced() {
+        let partitions = 8;
+        let chunks: Vec<_> = superstore_stream(8000, 500, Some(42))
+            .unwrap()
+            .partitioned(partitions, |r| r.customer_id.as_str())
+            .unwrap()
+            .collect();
+
+        let mut counts = vec![0usize; partitions];
+        let mut assigned = std::collections::HashMap::new();
+        for (partition, row) in chunks.iter().flatten() {
+            assert!(*partition < partitions);
+            counts[*partition] += 1;
+            // Same key, same partition
+            let prev = assigned.insert(row.customer_id.clone(), *partition);
+            assert!(prev.is_none_or(|p| p == *partition));
+            assert_eq!(
+                Ok(*partition),
+                kafka_partition(row.customer_id.as_bytes(), partitions)
+            );
+        }
+
+        // Same keys map to the same partitions on a second run
+        let rerun: Vec<_> = superstore_stream(8000, 700, Some(42))
+            .unwrap()
+            .partitioned(partitions, |r| r.customer_id.as_str())
+            .unwrap()
+            .flatten()
+            .collect();
+        for (partition, row) in &rerun {
+            assert_eq!(assigned[&row.customer_id], *partition);
+        }
+
+        let expected = 8000.0 / partitions as f64;
+        for count in counts {
+            assert!((count as f64 - expected).abs() < expected * 0.15);
+        }
+    }
+
+    #[test]
+    fn test_zero_partitions_rejected() {
+        assert_eq!(
+            kafka_partition(b"key", 0),
+            Err(StreamingError::InvalidPartitions(0))
+        );
+        assert!(matches!(
+            superstore_stream(10, 5, Some(1))
+                .unwrap()
+                .partitioned(0, |r| r.customer_id.as_str()),
+            Err(StreamingError::InvalidPartitions(0))
+        ));
+    }
+
     #[test]
     fn test_stream_total_count() {
         // Verify total row count matches expected
-        let total: usize = superstore_stream(1000, 100, None).map(|c| c.len()).sum();
+        let total: usize = superstore_stream(1000, 100, None)
+            .unwrap()
+            .map(|c| c.len())
+            .sum();
         assert_eq!(total, 1000);
     }
+
+    #[test]
+    fn test_stream_chunk_lengths_sum_to_total() {
+        for (total, chunk_size) in [
+            (0, 1),
+            (0, 100),
+            (1, 1),
+            (7, 1),
+            (10, 3),
+            (5, 1000),
+            (64, 64),
+        ] {
+            let lens: Vec<usize> = superstore_stream(total, chunk_size, Some(1))
+                .unwrap()
+                .map(|c| c.len())
+                .collect();
+            assert_eq!(lens.iter().sum::<usize>(), total);
+            assert_eq!(lens.len(), total.div_ceil(chunk_size));
+            assert!(lens.iter().all(|&l| l >= 1 && l <= chunk_size));
+
+            let lens: Vec<usize> = employees_stream(total, chunk_size, Some(1))
+                .unwrap()
+                .map(|c| c.len())
+                .collect();
+            assert_eq!(lens.iter().sum::<usize>(), total);
+        }
+    }
+
+    #[test]
+    fn test_stream_rejects_zero_chunk_size() {
+        assert_eq!(
+            superstore_stream(10, 0, None).err(),
+            Some(StreamingError::InvalidChunkSize(0))
+        );
+        assert!(employees_stream(10, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_streams_draw_from_eager_pools() {
+        use crate::general::{
+            employees_with_config, superstore_with_config, EmployeeConfig, SuperstoreConfig,
+        };
+        use std::collections::HashSet;
+
+        let pool_size = 5;
+        let eager = employees_with_config(&EmployeeConfig {
+            count: 2000,
+            pool_size,
+            seed: Some(42),
+            ..Default::default()
+        });
+        let streamed: Vec<EmployeeRow> = employees_stream(2000, 300, Some(42))
+            .unwrap()
+            .with_pool_size(pool_size)
+            .flatten()
+            .collect();
+        let distinct = |rows: &[EmployeeRow], field: fn(&EmployeeRow) -> &str| {
+            rows.iter()
+                .map(|r| field(r).to_string())
+                .collect::<HashSet<_>>()
+        };
+        let fields: [fn(&EmployeeRow) -> &str; 5] = [
+            |r| r.city.as_str(),
+            |r| r.postal_code.as_str(),
+            |r| r.first_name.as_str(),
+            |r| r.email.as_str(),
+            |r| r.phone_number.as_str(),
+        ];
+        for field in fields {
+            let values = distinct(&streamed, field);
+            assert!(values.len() <= pool_size);
+            assert_eq!(values, distinct(&eager, field));
+        }
+
+        let eager = superstore_with_config(&SuperstoreConfig {
+            count: 2000,
+            pool_size,
+            seed: Some(42),
+            ..Default::default()
+        });
+        let streamed: Vec<SuperstoreRow> = superstore_stream(2000, 300, Some(42))
+            .unwrap()
+            .with_pool_size(pool_size)
+            .flatten()
+            .collect();
+        let zips = |rows: &[SuperstoreRow]| {
+            rows.iter()
+                .map(|r| r.postal_code.clone())
+                .collect::<HashSet<_>>()
+        };
+        assert!(zips(&streamed).len() <= pool_size);
+        assert_eq!(zips(&streamed), zips(&eager));
+    }
+
+    #[test]
+    fn test_skip_to_matches_full_stream() {
+        let full: Vec<SuperstoreRow> = superstore_stream(1000, 64, Some(7))
+            .unwrap()
+            .flatten()
+            .collect();
+        let resumed: Vec<SuperstoreRow> = superstore_stream(1000, 100, Some(7))
+            .unwrap()
+            .skip_to(500)
+            .flatten()
+            .collect();
+        assert_eq!(resumed.len(), 500);
+        for (a, b) in full[500..].iter().zip(&resumed) {
+            assert_eq!(a.row_id, b.row_id);
+            assert_eq!(a.order_id, b.order_id);
+            assert_eq!(a.customer_id, b.customer_id);
+            assert_eq!(a.sales, b.sales);
+            assert_eq!(a.profit, b.profit);
+        }
+
+        let full: Vec<EmployeeRow> = employees_stream(300, 50, Some(7))
+            .unwrap()
+            .flatten()
+            .collect();
+        let resumed: Vec<EmployeeRow> = employees_stream(300, 50, Some(7))
+            .unwrap()
+            .skip_to(120)
+            .flatten()
+            .collect();
+        assert_eq!(resumed.len(), 180);
+        for (a, b) in full[120..].iter().zip(&resumed) {
+            assert_eq!(a.employee_id, b.employee_id);
+            assert_eq!(a.email, b.email);
+        }
+
+        assert_eq!(
+            superstore_stream(10, 4, Some(1))
+                .unwrap()
+                .skip_to(50)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_flattened_stream_matches_eager() {
+        let eager = crate::superstore(500, Some(42), None);
+        for chunk_size in [1, 7, 64, 500, 1000] {
+            let streamed: Vec<SuperstoreRow> = superstore_stream(500, chunk_size, Some(42))
+                .unwrap()
+                .flatten()
+                .collect();
+            assert_eq!(streamed.len(), eager.len());
+            for (a, b) in streamed.iter().zip(&eager) {
+                let a = serde_json::to_value(a).unwrap();
+                let b = serde_json::to_value(b).unwrap();
+                for (field, value) in a.as_object().unwrap() {
+                    assert_eq!(
+                        value, &b[field],
+                        "{} differs at chunk_size {}",
+                        field, chunk_size
+                    );
+                }
+            }
+        }
+
+        // Also holds with a non-default pool size
+        let eager = crate::superstore_with_config(&SuperstoreConfig {
+            count: 200,
+            seed: Some(3),
+            pool_size: 25,
+            ..Default::default()
+        });
+        let streamed: Vec<SuperstoreRow> = superstore_stream(200, 30, Some(3))
+            .unwrap()
+            .with_pool_size(25)
+            .flatten()
+            .collect();
+        for (a, b) in streamed.iter().zip(&eager) {
+            assert_eq!(a.city, b.city);
+            assert_eq!(a.customer_id, b.customer_id);
+            assert_eq!(a.profit, b.profit);
+        }
+    }
+
+    #[test]
+    fn test_config_stream_matches_eager() {
+        use crate::general::{
+            superstore_with_config, BundlingConfig, CustomerConfig, InventoryConfig, PaymentConfig,
+            PromotionalConfig, ReturnsConfig, SeasonalityConfig,
+        };
+
+        let config = SuperstoreConfig {
+            count: 600,
+            seed: Some(11),
+            pool_size: 40,
+            copula_df: Some(4.0),
+            locale: "de_DE".to_string(),
+            seasonality: SeasonalityConfig {
+                enable: true,
+                ..Default::default()
+            },
+            promotions: PromotionalConfig {
+                enable: true,
+                ..Default::default()
+            },
+            customers: CustomerConfig {
+                enable_cohorts: true,
+                ..Default::default()
+            },
+            bundling: BundlingConfig {
+                enable: true,
+                ..Default::default()
+            },
+            inventory: InventoryConfig {
+                enable: true,
+                ..Default::default()
+            },
+            payment: PaymentConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for per_row_seeding in [false, true] {
+            let config = SuperstoreConfig {
+                per_row_seeding,
+                ..config.clone()
+            };
+            let eager = serde_json::to_value(superstore_with_config(&config)).unwrap();
+            for chunk_size in [1, 64, 600, 1000] {
+                let chunks: Vec<_> = superstore_stream_with_config(&config, chunk_size)
+                    .unwrap()
+                    .collect();
+                assert_eq!(chunks.len(), 600usize.div_ceil(chunk_size));
+                let streamed: Vec<SuperstoreRow> = chunks.into_iter().flatten().collect();
+                assert_eq!(serde_json::to_value(&streamed).unwrap(), eager);
+            }
+
+            let resumed: Vec<SuperstoreRow> = superstore_stream_with_config(&config, 100)
+                .unwrap()
+                .skip_to(450)
+                .flatten()
+                .collect();
+            assert_eq!(serde_json::to_value(&resumed).unwrap()[0], eager[450]);
+            assert_eq!(resumed.len(), 150);
+        }
+
+        // Returns are appended after the sales and aren't streamed
+        let config = SuperstoreConfig {
+            returns: ReturnsConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..config
+        };
+        let eager = superstore_with_config(&config);
+        let streamed: Vec<SuperstoreRow> = superstore_stream_with_config(&config, 64)
+            .unwrap()
+            .flatten()
+            .collect();
+        assert!(eager.len() > streamed.len());
+        assert_eq!(
+            serde_json::to_value(&streamed).unwrap(),
+            serde_json::to_value(&eager[..600]).unwrap()
+        );
+        assert!(superstore_stream_with_config(&config, 0).is_err());
+        assert_eq!(
+            superstore_stream_with_config(
+                &SuperstoreConfig {
+                    stable_v1: true,
+                    ..config.clone()
+                },
+                64
+            )
+            .err(),
+            Some(StreamingError::StableV1)
+        );
+        assert_eq!(
+            superstore_stream_with_config(
+                &SuperstoreConfig {
+                    order_date_start: Some("2024-13-01".to_string()),
+                    ..config.clone()
+                },
+                64
+            )
+            .err(),
+            Some(StreamingError::InvalidConfig(
+                SuperstoreConfigError::InvalidOrderDate("2024-13-01".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_superstore_stream_with_config_spans_uniform_blocks() {
+        use crate::general::superstore_with_config;
+
+        // Copula uniforms come in blocks of 4096 rows; chunks straddling a
+        // block boundary still match the eager rows
+        let config = SuperstoreConfig {
+            count: 9_000,
+            seed: Some(11),
+            ..Default::default()
+        };
+        let eager = serde_json::to_value(superstore_with_config(&config)).unwrap();
+        let streamed: Vec<SuperstoreRow> = superstore_stream_with_config(&config, 1_000)
+            .unwrap()
+            .flatten()
+            .collect();
+        assert_eq!(serde_json::to_value(&streamed).unwrap(), eager);
+
+        // Starting a stream samples one block, not `count` rows of uniforms
+        let head: Vec<SuperstoreRow> = superstore_stream_with_config(
+            &SuperstoreConfig {
+                count: 1_000_000_000_000,
+                ..config.clone()
+            },
+            10,
+        )
+        .unwrap()
+        .next()
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&head).unwrap(),
+            serde_json::to_value(superstore_with_config(&SuperstoreConfig {
+                count: 10,
+                ..config
+            }))
+            .unwrap()
+        );
+    }
 }
@@ -0,0 +1,326 @@
+//! Subscription / recurring-billing data generator module.
+//!
+//! Generates SaaS-style subscription data including:
+//! - Subscriptions with plan tier, MRR, and start/cancel dates
+//! - Monthly billing events (new, renewal, upgrade, downgrade)
+//! - Churn and expansion via per-month cancel/upgrade/downgrade rates
+
+use crate::rng::{seeded_rng, SeededRng};
+use chrono::{Months, NaiveDate};
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::distributions::sample_categorical;
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// Subscription plan tier
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlanTier {
+    /// Plan name
+    pub name: String,
+    /// Monthly price
+    pub monthly_price: f64,
+    /// Share of new subscriptions starting on this plan
+    pub weight: f64,
+}
+
+/// Configuration for subscription generation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubscriptionConfig {
+    /// Number of subscriptions
+    pub count: usize,
+    /// Random seed
+    pub seed: Option<u64>,
+    /// First possible subscription start date (YYYY-MM-DD)
+    pub start_date: Option<String>,
+    /// Length of the simulated period in months
+    pub months: u32,
+    /// Plan tiers, cheapest first (upgrades move up the list)
+    pub plans: Vec<PlanTier>,
+    /// Probability of cancelling at each monthly renewal
+    pub monthly_churn_rate: f64,
+    /// Probability of upgrading at each monthly renewal
+    pub upgrade_rate: f64,
+    /// Probability of downgrading at each monthly renewal
+    pub downgrade_rate: f64,
+}
+
+impl Default for SubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            count: 1000,
+            seed: None,
+            start_date: None,
+            months: 24,
+            plans: vec![
+                PlanTier {
+                    name: "Basic".to_string(),
+                    monthly_price: 29.0,
+                    weight: 0.6,
+                },
+                PlanTier {
+                    name: "Pro".to_string(),
+                    monthly_price: 99.0,
+                    weight: 0.3,
+                },
+                PlanTier {
+                    name: "Enterprise".to_string(),
+                    monthly_price: 499.0,
+                    weight: 0.1,
+                },
+            ],
+            monthly_churn_rate: 0.03,
+            upgrade_rate: 0.02,
+            downgrade_rate: 0.01,
+        }
+    }
+}
+
+// =============================================================================
+// Data Structures
+// =============================================================================
+
+/// A subscription with its lifecycle summary
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub customer_id: String,
+    pub initial_plan: String,
+    pub current_plan: String,
+    pub start_date: String,
+    pub cancel_date: Option<String>,
+    pub status: String,
+    pub initial_mrr: f64,
+    /// MRR at the end of the period (0 once cancelled)
+    pub current_mrr: f64,
+}
+
+/// A monthly invoice for a subscription
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BillingEvent {
+    pub event_id: String,
+    pub subscription_id: String,
+    pub billing_date: String,
+    /// "new", "renewal", "upgrade", or "downgrade"
+    pub event_type: String,
+    pub plan: String,
+    pub mrr: f64,
+    /// Change in MRR vs. the previous invoice
+    pub mrr_change: f64,
+}
+
+/// Subscriptions and their billing events
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubscriptionData {
+    pub subscriptions: Vec<Subscription>,
+    pub billing_events: Vec<BillingEvent>,
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
+}
+
+fn parse_start_date(date_str: &Option<String>) -> NaiveDate {
+    date_str
+        .as_ref()
+        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+}
+
+// =============================================================================
+// Generation Functions
+// =============================================================================
+
+/// Generate subscriptions and monthly billing events.
+///
+/// Each subscription starts on a random day within the period and is billed
+/// monthly on that day. At every renewal it may cancel (no further invoices),
+/// upgrade or downgrade one tier (MRR changes on that invoice), or renew.
+pub fn generate_subscriptions(config: &SubscriptionConfig) -> SubscriptionData {
+    let mut rng = create_rng(config.seed);
+    let period_start = parse_start_date(&config.start_date);
+    let period_end = period_start + Months::new(config.months);
+    let period_days = (period_end - period_start).num_days().max(1);
+
+    let weights: Vec<f64> = config.plans.iter().map(|p| p.weight).collect();
+    let mut subscriptions = Vec::with_capacity(config.count);
+    let mut billing_events = Vec::new();
+
+    if config.plans.is_empty() {
+        return SubscriptionData {
+            subscriptions,
+            billing_events,
+        };
+    }
+
+    for i in 0..config.count {
+        let subscription_id = format!("SUB-{:06}", i + 1);
+        let customer_id = format!("CUST-{:08x}", rng.random::<u32>());
+        let start = period_start + chrono::Duration::days(rng.random_range(0..period_days));
+
+        let initial_tier = sample_categorical(&mut rng, &weights).min(config.plans.len() - 1);
+        let mut tier = initial_tier;
+        let mut mrr = config.plans[tier].monthly_price;
+        let mut cancel_date = None;
+
+        billing_events.push(BillingEvent {
+            event_id: format!("INV-{:08}", billing_events.len() + 1),
+            subscription_id: subscription_id.clone(),
+            billing_date: start.format("%Y-%m-%d").to_string(),
+            event_type: "new".to_string(),
+            plan: config.plans[tier].name.clone(),
+            mrr,
+            mrr_change: mrr,
+        });
+
+        let mut month = 1;
+        loop {
+            let billing_date = start + Months::new(month);
+            if billing_date >= period_end {
+                break;
+            }
+
+            let roll = rng.random::<f64>();
+            if roll < config.monthly_churn_rate {
+                cancel_date = Some(billing_date);
+                break;
+            }
+
+            let roll = roll - config.monthly_churn_rate;
+            let event_type = if roll < config.upgrade_rate && tier + 1 < config.plans.len() {
+                tier += 1;
+                "upgrade"
+            } else if roll >= config.upgrade_rate
+                && roll < config.upgrade_rate + config.downgrade_rate
+                && tier > 0
+            {
+                tier -= 1;
+                "downgrade"
+            } else {
+                "renewal"
+            };
+
+            let new_mrr = config.plans[tier].monthly_price;
+            billing_events.push(BillingEvent {
+                event_id: format!("INV-{:08}", billing_events.len() + 1),
+                subscription_id: subscription_id.clone(),
+                billing_date: billing_date.format("%Y-%m-%d").to_string(),
+                event_type: event_type.to_string(),
+                plan: config.plans[tier].name.clone(),
+                mrr: new_mrr,
+                mrr_change: new_mrr - mrr,
+            });
+            mrr = new_mrr;
+            month += 1;
+        }
+
+        subscriptions.push(Subscription {
+            subscription_id,
+            customer_id,
+            initial_plan: config.plans[initial_tier].name.clone(),
+            current_plan: config.plans[tier].name.clone(),
+            start_date: start.format("%Y-%m-%d").to_string(),
+            cancel_date: cancel_date.map(|d| d.format("%Y-%m-%d").to_string()),
+            status: if cancel_date.is_some() {
+                "cancelled".to_string()
+            } else {
+                "active".to_string()
+            },
+            initial_mrr: config.plans[initial_tier].monthly_price,
+            current_mrr: if cancel_date.is_some() { 0.0 } else { mrr },
+        });
+    }
+
+    SubscriptionData {
+        subscriptions,
+        billing_events,
+    }
+}
+
+/// Generate subscription data with default settings
+pub fn subscriptions(count: usize, seed: Option<u64>) -> SubscriptionData {
+    generate_subscriptions(&SubscriptionConfig {
+        count,
+        seed,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_billing_stops_after_cancel() {
+        let data = generate_subscriptions(&SubscriptionConfig {
+            count: 500,
+            seed: Some(42),
+            monthly_churn_rate: 0.08,
+            ..Default::default()
+        });
+        assert_eq!(data.subscriptions.len(), 500);
+
+        let cancels: HashMap<&str, &str> = data
+            .subscriptions
+            .iter()
+            .filter_map(|s| Some((s.subscription_id.as_str(), s.cancel_date.as_deref()?)))
+            .collect();
+        assert!(!cancels.is_empty());
+
+        for event in &data.billing_events {
+            if let Some(cancel) = cancels.get(event.subscription_id.as_str()) {
+                assert!(event.billing_date.as_str() < *cancel);
+            }
+        }
+        for sub in data
+            .subscriptions
+            .iter()
+            .filter(|s| s.cancel_date.is_some())
+        {
+            assert_eq!(sub.status, "cancelled");
+            assert_eq!(sub.current_mrr, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_mrr_changes_on_plan_change() {
+        let data = generate_subscriptions(&SubscriptionConfig {
+            count: 300,
+            seed: Some(7),
+            upgrade_rate: 0.1,
+            downgrade_rate: 0.05,
+            ..Default::default()
+        });
+
+        let mut last_mrr: HashMap<&str, f64> = HashMap::new();
+        let (mut upgrades, mut downgrades) = (0, 0);
+        for event in &data.billing_events {
+            let prev = last_mrr.insert(event.subscription_id.as_str(), event.mrr);
+            match event.event_type.as_str() {
+                "new" => assert!(prev.is_none()),
+                "renewal" => assert_eq!(prev, Some(event.mrr)),
+                "upgrade" => {
+                    upgrades += 1;
+                    assert!(event.mrr > prev.unwrap());
+                }
+                "downgrade" => {
+                    downgrades += 1;
+                    assert!(event.mrr < prev.unwrap());
+                }
+                other => panic!("unexpected event type {}", other),
+            }
+            if let Some(prev) = prev {
+                assert!((event.mrr_change - (event.mrr - prev)).abs() < 1e-9);
+            }
+        }
+        assert!(upgrades > 0 && downgrades > 0);
+    }
+}
@@ -3,11 +3,14 @@
 //! This module provides models for generating data with temporal dependencies,
 //! including autoregressive (AR) processes and Markov chains.
 
+use std::collections::VecDeque;
+
+use nalgebra::DMatrix;
 use rand::{Rng, RngExt};
 use rand_distr::{Distribution, Normal};
 
 /// Error type for temporal model operations.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TemporalError {
     InvalidCoefficients(String),
     InvalidProbabilities(String),
@@ -123,13 +126,34 @@ pub struct ARp {
     pub sigma: f64,
     /// Mean of the process
     pub mean: f64,
-    /// History buffer (most recent first)
-    history: Vec<f64>,
+    /// Ring buffer of the last p values (most recent first)
+    history: VecDeque<f64>,
+}
+
+/// Largest modulus among the roots of the AR(p) companion matrix.
+///
+/// The process is stationary when this is below 1; for AR(1) it is `|phi|`.
+fn companion_spectral_radius(coefficients: &[f64]) -> f64 {
+    let p = coefficients.len();
+    // First row holds the coefficients, the subdiagonal shifts the lags
+    let companion = DMatrix::from_fn(p, p, |i, j| match i {
+        0 => coefficients[j],
+        _ if j + 1 == i => 1.0,
+        _ => 0.0,
+    });
+    companion
+        .complex_eigenvalues()
+        .iter()
+        .map(|root| root.norm())
+        .fold(0.0, f64::max)
 }
 
 impl ARp {
     /// Create a new AR(p) model.
     ///
+    /// The coefficients must be stationary: every root of the companion
+    /// matrix has to lie strictly inside the unit circle.
+    ///
     /// # Arguments
     /// * `coefficients` - Vector of AR coefficients [phi_1, phi_2, ..., phi_p]
     /// * `sigma` - Standard deviation of innovations
@@ -146,9 +170,17 @@ impl ARp {
                 sigma
             )));
         }
+        // Unit roots come back from the eigensolver a hair below 1
+        let radius = companion_spectral_radius(&coefficients);
+        if radius.is_nan() || radius >= 1.0 - 1e-10 {
+            return Err(TemporalError::InvalidCoefficients(format!(
+                "coefficients {:?} are not stationary (companion root modulus {:.4}, must be below 1)",
+                coefficients, radius
+            )));
+        }
 
         let p = coefficients.len();
-        let history = vec![mean; p];
+        let history = VecDeque::from(vec![mean; p]);
 
         Ok(Self {
             coefficients,
@@ -173,9 +205,17 @@ impl ARp {
         self.coefficients.len()
     }
 
+    /// Largest modulus among the companion-matrix roots, in `[0, 1)`.
+    ///
+    /// Shocks decay roughly by this factor per step, so it plays the role
+    /// `|phi|` does for AR(1).
+    pub fn spectral_radius(&self) -> f64 {
+        companion_spectral_radius(&self.coefficients)
+    }
+
     /// Reset the state to the mean.
     pub fn reset(&mut self) {
-        self.history = vec![self.mean; self.coefficients.len()];
+        self.history = VecDeque::from(vec![self.mean; self.coefficients.len()]);
     }
 
     /// Set the history (most recent first).
@@ -187,7 +227,7 @@ impl ARp {
                 self.coefficients.len()
             )));
         }
-        self.history = history;
+        self.history = VecDeque::from(history);
         Ok(())
     }
 
@@ -207,8 +247,8 @@ impl ARp {
         let value = self.mean + ar_component + innovation;
 
         // Update history (shift in the new value)
-        self.history.pop();
-        self.history.insert(0, value);
+        self.history.pop_back();
+        self.history.push_front(value);
 
         value
     }
@@ -377,24 +417,59 @@ impl MarkovChain {
         (0..n).map(|_| self.next_index(rng)).collect()
     }
 
-    /// Get stationary distribution (long-run probabilities).
+    /// Get stationary distribution (long-run probabilities), aligned with
+    /// [`MarkovChain::states`].
+    ///
+    /// Power iteration from the uniform distribution on the lazy chain
+    /// `(P + I) / 2`, which has the same stationary distribution as `P` but
+    /// cannot oscillate on periodic chains. With absorbing states, all of the
+    /// mass ends up in them.
     pub fn stationary_distribution(&self) -> Vec<f64> {
-        // Use power iteration to find stationary distribution
         let n = self.states.len();
+        if n == 0 {
+            return Vec::new();
+        }
         let mut pi = vec![1.0 / n as f64; n];
 
-        for _ in 0..1000 {
-            let mut new_pi = vec![0.0; n];
-            for j in 0..n {
-                for i in 0..n {
-                    new_pi[j] += pi[i] * self.transition_matrix[i][j];
+        for _ in 0..100_000 {
+            let mut new_pi: Vec<f64> = pi.iter().map(|p| 0.5 * p).collect();
+            for (row, &p) in self.transition_matrix.iter().zip(&pi) {
+                for (next, &prob) in new_pi.iter_mut().zip(row) {
+                    *next += 0.5 * p * prob;
                 }
             }
+            let total: f64 = new_pi.iter().sum();
+            new_pi.iter_mut().for_each(|p| *p /= total);
+
+            let change = pi
+                .iter()
+                .zip(&new_pi)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f64::max);
             pi = new_pi;
+            if change < 1e-15 {
+                break;
+            }
         }
 
         pi
     }
+
+    /// Expected number of consecutive steps spent in `state` once entered,
+    /// `1 / (1 - p_ii)` (the mean of a geometric distribution). Absorbing
+    /// states (`p_ii = 1`) are never left, so their dwell time is
+    /// `f64::INFINITY`.
+    pub fn expected_dwell_time(&self, state: &str) -> Result<f64, TemporalError> {
+        let i = self.states.iter().position(|s| s == state).ok_or_else(|| {
+            TemporalError::InvalidProbabilities(format!("Unknown state: {}", state))
+        })?;
+        let stay = self.transition_matrix[i][i];
+        Ok(if stay >= 1.0 {
+            f64::INFINITY
+        } else {
+            1.0 / (1.0 - stay)
+        })
+    }
 }
 
 /// Random walk model.
@@ -564,6 +639,31 @@ mod tests {
         assert_eq!(samples.len(), 100);
     }
 
+    #[test]
+    fn test_arp_stationarity() {
+        // Complex roots of modulus sqrt(0.75): stationary but quasi-cyclical
+        let ar = ARp::ar2(1.5, -0.75, 1.0, 0.0).unwrap();
+        assert!((ar.spectral_radius() - 0.75_f64.sqrt()).abs() < 1e-9);
+        assert!((ARp::ar1(-0.6, 1.0, 0.0).unwrap().spectral_radius() - 0.6).abs() < 1e-12);
+
+        // Explosive, unit root, and a phi_2 outside the triangle
+        assert!(ARp::ar2(0.5, 0.6, 1.0, 0.0).is_err());
+        assert!(ARp::ar2(0.5, 0.5, 1.0, 0.0).is_err());
+        assert!(ARp::ar2(0.2, -1.1, 1.0, 0.0).is_err());
+        assert!(ARp::new(vec![1.2, -0.4, 0.1], 1.0, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_arp_matches_ar1() {
+        let mut ar1 = AR1::new(0.7, 1.0, 2.0).unwrap();
+        let mut arp = ARp::ar1(0.7, 1.0, 2.0).unwrap();
+        let a = ar1.sample_n(&mut StdRng::seed_from_u64(42), 50);
+        let b = arp.sample_n(&mut StdRng::seed_from_u64(42), 50);
+        for (x, y) in a.iter().zip(&b) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_markov_chain() {
         let mut rng = StdRng::seed_from_u64(42);
@@ -588,6 +688,44 @@ mod tests {
         assert!((stationary[1] - 0.375).abs() < 0.05);
     }
 
+    #[test]
+    fn test_markov_stationary_periodic() {
+        // Period 2: plain power iteration from uniform oscillates forever
+        let mc = MarkovChain::new(
+            vec![
+                vec![0.0, 1.0, 0.0],
+                vec![0.5, 0.0, 0.5],
+                vec![0.0, 1.0, 0.0],
+            ],
+            vec!["A".to_string(), "B".to_string(), "C".to_string()],
+        )
+        .unwrap();
+        let stationary = mc.stationary_distribution();
+        for (p, expected) in stationary.iter().zip([0.25, 0.5, 0.25]) {
+            assert!((p - expected).abs() < 1e-9);
+        }
+
+        let mc = MarkovChain::two_state("A", "B", 0.3, 0.5).unwrap();
+        let stationary = mc.stationary_distribution();
+        assert!((stationary[0] - 0.625).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_markov_dwell_time() {
+        let mc = MarkovChain::new(
+            vec![vec![0.75, 0.25], vec![0.0, 1.0]],
+            vec!["browse".to_string(), "exit".to_string()],
+        )
+        .unwrap();
+        assert_eq!(mc.expected_dwell_time("browse").unwrap(), 4.0);
+        assert_eq!(mc.expected_dwell_time("exit").unwrap(), f64::INFINITY);
+        assert!(mc.expected_dwell_time("checkout").is_err());
+
+        // All long-run mass sits in the absorbing state
+        let stationary = mc.stationary_distribution();
+        assert!(stationary[1] > 1.0 - 1e-9);
+    }
+
     #[test]
     fn test_random_walk() {
         let mut rng = StdRng::seed_from_u64(42);
@@ -1,14 +1,85 @@
-use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, Weekday};
-use rand::rngs::StdRng;
-use rand::{Rng, RngExt, SeedableRng};
-use rand_distr::{Distribution, Normal};
+use crate::rng::{seeded_rng, SeededRng};
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use rand::{Rng, RngExt};
+use rand_distr::{ChiSquared, Distribution, Normal, StandardNormal};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::temporal::{MarkovChain, AR1};
+use crate::correlation::{CorrelationError, CorrelationMatrix};
+use crate::temporal::{ARp, MarkovChain, TemporalError, AR1};
 
 const ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
+/// Error type for timeseries configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeseriesError {
+    /// More custom column names than `ncol`
+    ColumnNamesMismatch { expected: usize, got: usize },
+    /// Two columns would share a name, including a custom name that matches
+    /// a default label kept by a later column
+    DuplicateColumnName(String),
+    /// `start_date` isn't of the form "YYYY-MM-DD" or "YYYY-MM-DD HH:MM:SS"
+    InvalidStartDate(String),
+    /// Intraday market open/close aren't "HH:MM" times with open before close
+    InvalidTradingWindow { open: String, close: String },
+    /// Holiday isn't an ISO date ("YYYY-MM-DD")
+    InvalidHoliday(String),
+    /// `exchange` has no built-in holiday calendar
+    UnknownExchange(String),
+    /// Indicator spec isn't of the form "sma:20", "ema:12", or "rsi:14"
+    InvalidIndicator(String),
+    /// `correlation_matrix` isn't a valid `ncol` x `ncol` correlation matrix
+    InvalidCorrelationMatrix(CorrelationError),
+    /// `ar_coefficients` is empty or not stationary
+    InvalidArCoefficients(TemporalError),
+}
+
+impl std::fmt::Display for TimeseriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeseriesError::ColumnNamesMismatch { expected, got } => write!(
+                f,
+                "Expected at most {} column names (one per column), got {}",
+                expected, got
+            ),
+            TimeseriesError::DuplicateColumnName(name) => {
+                write!(f, "Duplicate column name '{}'", name)
+            }
+            TimeseriesError::InvalidStartDate(s) => write!(
+                f,
+                "Invalid start_date '{}'. Expected 'YYYY-MM-DD' or 'YYYY-MM-DD HH:MM:SS'",
+                s
+            ),
+            TimeseriesError::InvalidTradingWindow { open, close } => write!(
+                f,
+                "Invalid trading window {}-{}. Expected 'HH:MM' times with market_open before market_close",
+                open, close
+            ),
+            TimeseriesError::InvalidHoliday(s) => {
+                write!(f, "Invalid holiday '{}'. Expected 'YYYY-MM-DD'", s)
+            }
+            TimeseriesError::UnknownExchange(s) => write!(
+                f,
+                "Unknown exchange '{}'. Expected 'NYSE' or 'LSE'",
+                s
+            ),
+            TimeseriesError::InvalidIndicator(spec) => write!(
+                f,
+                "Invalid indicator '{}'. Expected 'sma:N', 'ema:N', or 'rsi:N' with N >= 1",
+                spec
+            ),
+            TimeseriesError::InvalidCorrelationMatrix(e) => {
+                write!(f, "Invalid correlation_matrix: {}", e)
+            }
+            TimeseriesError::InvalidArCoefficients(e) => {
+                write!(f, "Invalid ar_coefficients: {}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeseriesError {}
+
 // =============================================================================
 // Timeseries Configuration Structs
 // =============================================================================
@@ -20,6 +91,10 @@ pub struct RegimeConfig {
     pub n_regimes: usize,
     pub regime_persistence: f64,
     pub volatility_multipliers: Vec<f64>,
+    /// Per-regime multipliers applied to `cross_correlation`. When non-empty,
+    /// all columns share a single regime path and the blend with the common
+    /// factor follows the regime (e.g. correlations spike in stress regimes).
+    pub correlation_multipliers: Vec<f64>,
 }
 
 impl Default for RegimeConfig {
@@ -29,6 +104,7 @@ impl Default for RegimeConfig {
             n_regimes: 2,
             regime_persistence: 0.95,
             volatility_multipliers: vec![1.0, 2.5],
+            correlation_multipliers: vec![],
         }
     }
 }
@@ -104,6 +180,10 @@ pub struct IntradayConfig {
     pub opening_volatility_mult: f64, // Multiplier at market open
     pub midday_volatility_mult: f64,  // Multiplier at midday (lowest)
     pub closing_volatility_mult: f64, // Multiplier at market close
+    /// Start of the trading window ("HH:MM") for intraday frequencies
+    pub market_open: String,
+    /// End of the trading window ("HH:MM", exclusive)
+    pub market_close: String,
 }
 
 impl Default for IntradayConfig {
@@ -113,6 +193,22 @@ impl Default for IntradayConfig {
             opening_volatility_mult: 1.5,
             midday_volatility_mult: 0.7,
             closing_volatility_mult: 1.3,
+            market_open: "09:30".to_string(),
+            market_close: "16:00".to_string(),
+        }
+    }
+}
+
+impl IntradayConfig {
+    /// Parsed `market_open` and `market_close`
+    pub fn trading_window(&self) -> Result<(NaiveTime, NaiveTime), TimeseriesError> {
+        let parse = |s: &str| NaiveTime::parse_from_str(s, "%H:%M").ok();
+        match (parse(&self.market_open), parse(&self.market_close)) {
+            (Some(open), Some(close)) if open < close => Ok((open, close)),
+            _ => Err(TimeseriesError::InvalidTradingWindow {
+                open: self.market_open.clone(),
+                close: self.market_close.clone(),
+            }),
         }
     }
 }
@@ -171,12 +267,17 @@ pub struct TimeseriesConfig {
     pub freq: String,
     pub seed: Option<u64>,
     pub ar_phi: f64,
+    /// AR(p) coefficients [phi_1, ..., phi_p]; overrides `ar_phi` when set
+    pub ar_coefficients: Option<Vec<f64>>,
     pub sigma: f64,
     pub drift: f64,
     pub cumulative: bool,
     pub use_fat_tails: bool,
     pub degrees_freedom: f64,
     pub cross_correlation: f64,
+    /// Pairwise correlations between columns (`ncol` x `ncol`, symmetric,
+    /// positive semi-definite); replaces `cross_correlation` when set
+    pub correlation_matrix: Option<Vec<Vec<f64>>>,
     pub regimes: RegimeConfig,
     pub jumps: JumpConfig,
     // Priority 5 enhancements
@@ -185,6 +286,15 @@ pub struct TimeseriesConfig {
     pub intraday: IntradayConfig,
     pub event_windows: EventWindowConfig,
     pub compute_metrics: bool,
+    /// Custom column names (e.g. tickers) instead of A, B, C...; at most
+    /// `ncol` entries, with the remaining columns keeping their default label
+    pub column_names: Option<Vec<String>>,
+    /// First date of the index ("YYYY-MM-DD"); defaults to 2000-01-01
+    pub start_date: Option<String>,
+    /// Dates ("YYYY-MM-DD") skipped by business-day and intraday indices
+    pub holidays: Vec<String>,
+    /// Exchange whose built-in holiday calendar is also skipped ("NYSE", "LSE")
+    pub exchange: Option<String>,
 }
 
 impl Default for TimeseriesConfig {
@@ -195,12 +305,14 @@ impl Default for TimeseriesConfig {
             freq: "B".to_string(),
             seed: None,
             ar_phi: 0.95,
+            ar_coefficients: None,
             sigma: 1.0,
             drift: 0.0,
             cumulative: true,
             use_fat_tails: false,
             degrees_freedom: 5.0,
             cross_correlation: 0.0,
+            correlation_matrix: None,
             regimes: RegimeConfig::default(),
             jumps: JumpConfig::default(),
             garch: GarchConfig::default(),
@@ -208,10 +320,111 @@ impl Default for TimeseriesConfig {
             intraday: IntradayConfig::default(),
             event_windows: EventWindowConfig::default(),
             compute_metrics: false,
+            column_names: None,
+            start_date: None,
+            holidays: Vec::new(),
+            exchange: None,
         }
     }
 }
 
+impl TimeseriesConfig {
+    /// Column names: `column_names` where given, otherwise A, B, C...
+    ///
+    /// Errors if there are more names than columns or if two columns end up
+    /// with the same name (e.g. `["B"]` with `ncol: 2`).
+    pub fn column_labels(&self) -> Result<Vec<String>, TimeseriesError> {
+        let names = self.column_names.as_deref().unwrap_or_default();
+        if names.len() > self.ncol {
+            return Err(TimeseriesError::ColumnNamesMismatch {
+                expected: self.ncol,
+                got: names.len(),
+            });
+        }
+        let labels: Vec<String> = get_cols(self.ncol)
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| names.get(i).cloned().unwrap_or_else(|| c.to_string()))
+            .collect();
+        let mut seen = std::collections::HashSet::new();
+        if let Some(duplicate) = labels.iter().find(|label| !seen.insert(label.as_str())) {
+            return Err(TimeseriesError::DuplicateColumnName(duplicate.clone()));
+        }
+        Ok(labels)
+    }
+
+    /// Timestamps for `nper` periods at `freq` from [`TimeseriesConfig::start`].
+    /// Business-day and intraday frequencies skip weekends, `holidays` and
+    /// the `exchange` calendar; intraday frequencies ("H", "T"/"min") only
+    /// fall inside the `intraday` trading window.
+    pub fn date_index(&self) -> Result<Vec<NaiveDateTime>, TimeseriesError> {
+        Ok(make_date_index(
+            self.nper,
+            &self.freq,
+            self.start()?,
+            self.intraday.trading_window()?,
+            &mut self.holiday_calendar()?,
+        ))
+    }
+
+    /// `holidays` and the `exchange` calendar, parsed
+    fn holiday_calendar(&self) -> Result<HolidayCalendar, TimeseriesError> {
+        let dates = self
+            .holidays
+            .iter()
+            .map(|s| {
+                NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                    .map_err(|_| TimeseriesError::InvalidHoliday(s.clone()))
+            })
+            .collect::<Result<_, _>>()?;
+        let exchange = self.exchange.as_deref().map(Exchange::parse).transpose()?;
+        Ok(HolidayCalendar {
+            dates,
+            exchange,
+            ..Default::default()
+        })
+    }
+
+    /// First timestamp of the index: `start_date` if set, otherwise 2000-01-01
+    pub fn start(&self) -> Result<NaiveDateTime, TimeseriesError> {
+        let Some(s) = &self.start_date else {
+            return Ok(default_start());
+        };
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .or_else(|_| {
+                NaiveDate::parse_from_str(s, "%Y-%m-%d").map(|d| d.and_hms_opt(0, 0, 0).unwrap())
+            })
+            .map_err(|_| TimeseriesError::InvalidStartDate(s.clone()))
+    }
+
+    /// Factorized `correlation_matrix`, checked against `ncol`
+    pub fn correlation(&self) -> Result<Option<CorrelationMatrix>, TimeseriesError> {
+        let Some(rows) = &self.correlation_matrix else {
+            return Ok(None);
+        };
+        let corr = CorrelationMatrix::from_rows(rows, None)
+            .map_err(TimeseriesError::InvalidCorrelationMatrix)?;
+        if corr.dim() != self.ncol {
+            return Err(TimeseriesError::InvalidCorrelationMatrix(
+                CorrelationError::DimensionMismatch {
+                    expected: self.ncol,
+                    got: corr.dim(),
+                },
+            ));
+        }
+        Ok(Some(corr))
+    }
+
+    /// AR(p) process for `ar_coefficients`, checked for stationarity
+    pub fn autoregression(&self) -> Result<Option<ARp>, TimeseriesError> {
+        self.ar_coefficients
+            .as_ref()
+            .map(|coefficients| ARp::new(coefficients.clone(), 1.0, 0.0))
+            .transpose()
+            .map_err(TimeseriesError::InvalidArCoefficients)
+    }
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
@@ -221,32 +434,18 @@ fn get_cols(k: usize) -> Vec<char> {
 }
 
 /// Create an RNG from an optional seed
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    }
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
 }
 
-/// Generate a Student-t random variate using the ratio of normals method
-/// This is more efficient than the inverse CDF method for most df values
+/// Generate a Student-t random variate as T = Z / sqrt(V / df), where
+/// Z ~ N(0, 1) and V ~ Chi^2(df). The chi-square is a Gamma(df / 2, 2) draw,
+/// so non-integer df is exact.
 fn sample_student_t<R: Rng>(rng: &mut R, df: f64) -> f64 {
-    // Use the fact that T = Z / sqrt(V/df) where Z ~ N(0,1) and V ~ Chi^2(df)
-    // Chi^2(df) = sum of df squared standard normals
-    let normal = Normal::new(0.0, 1.0).expect("Invalid normal params");
-    let z: f64 = normal.sample(rng);
-
-    // For Chi^2, we use df/2 independent pairs
-    let mut chi_sq = 0.0;
-    let n_samples = (df / 2.0).ceil() as usize;
-    for _ in 0..n_samples {
-        let x: f64 = normal.sample(rng);
-        let y: f64 = normal.sample(rng);
-        chi_sq += x * x + y * y;
-    }
-    // Adjust for actual df
-    chi_sq = chi_sq * (df / (2.0 * n_samples as f64));
-
+    let z: f64 = rng.sample(StandardNormal);
+    let chi_sq = ChiSquared::new(df)
+        .expect("Invalid degrees of freedom")
+        .sample(rng);
     z / (chi_sq / df).sqrt()
 }
 
@@ -285,6 +484,25 @@ fn create_regime_chain(config: &RegimeConfig) -> Option<MarkovChain> {
     MarkovChain::new(matrix, states).ok()
 }
 
+/// Sample a single regime path of length `n` shared across all columns
+fn sample_regime_path<R: Rng>(rng: &mut R, config: &RegimeConfig, n: usize) -> Vec<usize> {
+    match create_regime_chain(config) {
+        Some(mut chain) => chain.sample_n_indices(rng, n),
+        None => vec![0; n],
+    }
+}
+
+/// Effective cross-correlation for a given regime, clamped to [0, 1]
+fn regime_cross_correlation(config: &TimeseriesConfig, regime: usize) -> f64 {
+    let mult = config
+        .regimes
+        .correlation_multipliers
+        .get(regime)
+        .copied()
+        .unwrap_or(1.0);
+    (config.cross_correlation * mult).clamp(0.0, 1.0)
+}
+
 // =============================================================================
 // Priority 5: Helper Functions
 // =============================================================================
@@ -315,10 +533,12 @@ fn apply_garch_volatility<R: Rng>(_rng: &mut R, innovations: &mut [f64], config:
 
 /// Ornstein-Uhlenbeck (mean-reverting) process
 /// dX_t = theta * (mu - X_t) * dt + sigma * dW_t
+/// With `shocks`, those standard normal draws drive the process instead of `rng`.
 fn generate_ornstein_uhlenbeck<R: Rng>(
     rng: &mut R,
     n: usize,
     config: &MeanReversionConfig,
+    shocks: Option<&[f64]>,
 ) -> Vec<f64> {
     let normal = Normal::new(0.0, 1.0).expect("Invalid normal params");
     let mut values = Vec::with_capacity(n);
@@ -326,8 +546,11 @@ fn generate_ornstein_uhlenbeck<R: Rng>(
 
     let dt = 1.0; // Daily timestep
 
-    for _ in 0..n {
-        let dw: f64 = normal.sample(rng);
+    for i in 0..n {
+        let dw: f64 = match shocks {
+            Some(shocks) => shocks[i],
+            None => normal.sample(rng),
+        };
         // Euler-Maruyama discretization
         x += config.theta * (config.mu - x) * dt + config.sigma * dt.sqrt() * dw;
         values.push(x);
@@ -338,7 +561,11 @@ fn generate_ornstein_uhlenbeck<R: Rng>(
 
 /// Get intraday volatility multiplier based on position in trading day
 /// Creates a U-shaped pattern: high at open, low at midday, high at close
-fn get_intraday_volatility_mult(index: usize, total: usize, config: &IntradayConfig) -> f64 {
+pub(crate) fn get_intraday_volatility_mult(
+    index: usize,
+    total: usize,
+    config: &IntradayConfig,
+) -> f64 {
     if !config.enable || total == 0 {
         return 1.0;
     }
@@ -373,7 +600,11 @@ fn get_intraday_volatility_mult(index: usize, total: usize, config: &IntradayCon
 }
 
 /// Apply abnormal returns around event dates
-fn apply_event_windows<R: Rng>(rng: &mut R, values: &mut [f64], config: &EventWindowConfig) {
+pub(crate) fn apply_event_windows<R: Rng>(
+    rng: &mut R,
+    values: &mut [f64],
+    config: &EventWindowConfig,
+) {
     if !config.enable || config.event_indices.is_empty() {
         return;
     }
@@ -497,20 +728,56 @@ fn calculate_financial_metrics(
     }
 }
 
-fn make_date_index(k: usize, freq: &str) -> Vec<NaiveDateTime> {
-    let start = NaiveDate::from_ymd_opt(2000, 1, 1)
+fn default_start() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
         .unwrap()
         .and_hms_opt(0, 0, 0)
-        .unwrap();
+        .unwrap()
+}
+
+fn default_trading_window() -> (NaiveTime, NaiveTime) {
+    IntradayConfig::default().trading_window().unwrap()
+}
+
+/// Bar length for intraday frequencies
+fn intraday_step(freq: &str) -> Option<ChronoDuration> {
+    match freq {
+        "H" => Some(ChronoDuration::hours(1)),
+        "T" | "min" => Some(ChronoDuration::minutes(1)),
+        _ => None,
+    }
+}
+
+fn make_date_index(
+    k: usize,
+    freq: &str,
+    start: NaiveDateTime,
+    (open, close): (NaiveTime, NaiveTime),
+    holidays: &mut HolidayCalendar,
+) -> Vec<NaiveDateTime> {
     let mut dates = Vec::with_capacity(k);
     let mut current = start;
 
+    if let Some(step) = intraday_step(freq) {
+        // Bars inside the trading window, rolling to the next business day
+        // once the window is exhausted
+        current = current.max(current.date().and_time(open));
+        while dates.len() < k {
+            if current.time() >= close || !holidays.is_business_day(current.date()) {
+                current = (current.date() + ChronoDuration::days(1)).and_time(open);
+                continue;
+            }
+            dates.push(current);
+            current += step;
+        }
+        return dates;
+    }
+
     match freq {
         "B" => {
             // Business day frequency
             while dates.len() < k {
-                let weekday = current.weekday();
-                if weekday != Weekday::Sat && weekday != Weekday::Sun {
+                if holidays.is_business_day(current.date()) {
                     dates.push(current);
                 }
                 current += ChronoDuration::days(1);
@@ -537,8 +804,7 @@ fn make_date_index(k: usize, freq: &str) -> Vec<NaiveDateTime> {
         _ => {
             // Default to business day
             while dates.len() < k {
-                let weekday = current.weekday();
-                if weekday != Weekday::Sat && weekday != Weekday::Sun {
+                if holidays.is_business_day(current.date()) {
                     dates.push(current);
                 }
                 current += ChronoDuration::days(1);
@@ -568,24 +834,113 @@ fn make_time_series_with_config_inner<R: Rng>(
     rng: &mut R,
     config: &TimeseriesConfig,
 ) -> (Vec<NaiveDateTime>, Vec<f64>) {
-    let dates = make_date_index(config.nper, &config.freq);
+    make_time_series_with_regime_path(rng, config, None, None)
+}
+
+/// Zero-mean random inputs of one column, drawn up front so a correlation
+/// matrix can mix them across columns before they drive the series
+struct ColumnShocks {
+    /// Unit-variance innovations (Brownian increments for mean reversion)
+    innovations: Vec<f64>,
+    /// AR path blended with the innovations
+    ar_weights: Vec<f64>,
+}
+
+/// AR path for `config` and its persistence: AR(p) when coefficients are
+/// given, otherwise AR(1)
+fn sample_ar_weights<R: Rng>(rng: &mut R, config: &TimeseriesConfig) -> (Vec<f64>, f64) {
+    match config.autoregression().expect("invalid timeseries config") {
+        Some(mut arp) => (arp.sample_n(rng, config.nper), arp.spectral_radius()),
+        None => {
+            let mut ar1 = AR1::new(config.ar_phi, 1.0, 0.0).expect("Invalid AR1 parameters");
+            (ar1.sample_n(rng, config.nper), config.ar_phi.abs())
+        }
+    }
+}
+
+/// Independent shocks for each column, mixed by `correlation`. AR paths are
+/// linear in their noise, so mixing them equals mixing the AR innovations.
+fn correlated_shocks<R: Rng>(
+    rng: &mut R,
+    config: &TimeseriesConfig,
+    correlation: &CorrelationMatrix,
+) -> Result<Vec<ColumnShocks>, TimeseriesError> {
+    let mut innovations = Vec::with_capacity(config.ncol);
+    let mut ar_weights = Vec::with_capacity(config.ncol);
+    for _ in 0..config.ncol {
+        innovations.push(
+            (0..config.nper)
+                .map(|_| {
+                    if config.mean_reversion.enable {
+                        rng.sample(StandardNormal)
+                    } else {
+                        sample_innovation(rng, 1.0, config.use_fat_tails, config.degrees_freedom)
+                    }
+                })
+                .collect::<Vec<f64>>(),
+        );
+        ar_weights.push(sample_ar_weights(rng, config).0);
+    }
+    let innovations = correlation
+        .correlate_columns(&innovations)
+        .map_err(TimeseriesError::InvalidCorrelationMatrix)?;
+    let ar_weights = correlation
+        .correlate_columns(&ar_weights)
+        .map_err(TimeseriesError::InvalidCorrelationMatrix)?;
+    Ok(innovations
+        .into_iter()
+        .zip(ar_weights)
+        .map(|(innovations, ar_weights)| ColumnShocks {
+            innovations,
+            ar_weights,
+        })
+        .collect())
+}
+
+/// Time series generation driven by an optional pre-sampled regime path.
+/// Without a path, each call runs its own regime chain. With `shocks`, the
+/// innovations and AR path come from them instead of fresh draws.
+fn make_time_series_with_regime_path<R: Rng>(
+    rng: &mut R,
+    config: &TimeseriesConfig,
+    regime_path: Option<&[usize]>,
+    shocks: Option<&ColumnShocks>,
+) -> (Vec<NaiveDateTime>, Vec<f64>) {
+    // get_time_series_with_config checks start_date and the trading window up front
+    let dates = config.date_index().expect("invalid timeseries config");
+    let window = config
+        .intraday
+        .trading_window()
+        .unwrap_or_else(|_| default_trading_window());
+    let window_seconds = (window.1 - window.0).num_seconds() as usize;
+    let intraday_freq = intraday_step(&config.freq).is_some();
 
     // If mean reversion is enabled, use Ornstein-Uhlenbeck process instead
     if config.mean_reversion.enable {
-        let values = generate_ornstein_uhlenbeck(rng, config.nper, &config.mean_reversion);
+        let values = generate_ornstein_uhlenbeck(
+            rng,
+            config.nper,
+            &config.mean_reversion,
+            shocks.map(|s| s.innovations.as_slice()),
+        );
         return (dates, values);
     }
 
-    // Set up regime chain if enabled
-    let mut regime_chain = create_regime_chain(&config.regimes);
+    // Set up regime chain if enabled (unless a shared path was supplied)
+    let mut regime_chain = match regime_path {
+        Some(_) => None,
+        None => create_regime_chain(&config.regimes),
+    };
     let mut current_regime = 0usize;
 
     // Generate innovations
     let mut innovations = Vec::with_capacity(config.nper);
 
-    for i in 0..config.nper {
+    for (i, date) in dates.iter().enumerate() {
         // Update regime if we have regime switching
-        if let Some(ref mut chain) = regime_chain {
+        if let Some(path) = regime_path {
+            current_regime = path.get(i).copied().unwrap_or(0);
+        } else if let Some(ref mut chain) = regime_chain {
             let state = chain.next(rng);
             // Parse regime number from state name
             current_regime = state
@@ -605,17 +960,26 @@ fn make_time_series_with_config_inner<R: Rng>(
             1.0
         };
 
-        // Apply intraday volatility pattern
-        let intraday_mult = get_intraday_volatility_mult(i, config.nper, &config.intraday);
+        // Apply intraday volatility pattern: by time of day for intraday
+        // frequencies, otherwise across the whole series
+        let intraday_mult = if intraday_freq {
+            let elapsed = (date.time() - window.0).num_seconds() as usize;
+            get_intraday_volatility_mult(elapsed, window_seconds, &config.intraday)
+        } else {
+            get_intraday_volatility_mult(i, config.nper, &config.intraday)
+        };
 
         // Sample innovation (normal or Student-t for fat tails)
         let effective_sigma = config.sigma * vol_mult * intraday_mult;
-        let mut innovation = sample_innovation(
-            rng,
-            effective_sigma,
-            config.use_fat_tails,
-            config.degrees_freedom,
-        );
+        let mut innovation = match shocks {
+            Some(shocks) => shocks.innovations[i] * effective_sigma,
+            None => sample_innovation(
+                rng,
+                effective_sigma,
+                config.use_fat_tails,
+                config.degrees_freedom,
+            ),
+        };
 
         // Add jump component if enabled
         if config.jumps.enable && rng.random::<f64>() < config.jumps.jump_probability {
@@ -633,15 +997,23 @@ fn make_time_series_with_config_inner<R: Rng>(
     // Apply GARCH volatility clustering
     apply_garch_volatility(rng, &mut innovations, &config.garch);
 
-    // Apply AR(1) dynamics
-    let mut ar1 = AR1::new(config.ar_phi, 1.0, 0.0).expect("Invalid AR1 parameters");
-    let ar_weights = ar1.sample_n(rng, config.nper);
+    // Apply AR dynamics: AR(p) when coefficients are given, otherwise AR(1)
+    let (ar_weights, persistence) = match shocks {
+        Some(shocks) => {
+            let persistence = match config.autoregression().expect("invalid timeseries config") {
+                Some(arp) => arp.spectral_radius(),
+                None => config.ar_phi.abs(),
+            };
+            (shocks.ar_weights.clone(), persistence)
+        }
+        None => sample_ar_weights(rng, config),
+    };
 
     // Blend AR weights with innovations
     let mut values: Vec<f64> = innovations
         .iter()
         .zip(ar_weights.iter())
-        .map(|(&inn, &ar)| inn * (1.0 - config.ar_phi.abs()) + ar * config.ar_phi.abs())
+        .map(|(&inn, &ar)| inn * (1.0 - persistence) + ar * persistence)
         .collect();
 
     // Apply event window effects
@@ -663,16 +1035,53 @@ fn make_time_series_with_config_inner<R: Rng>(
 }
 
 /// Generate time series data with full configuration support
-pub fn get_time_series_with_config(config: &TimeseriesConfig) -> TimeSeriesDataWithMetrics {
+pub fn get_time_series_with_config(
+    config: &TimeseriesConfig,
+) -> Result<TimeSeriesDataWithMetrics, TimeseriesError> {
+    let cols = config.column_labels()?;
+    let correlation = config.correlation()?;
+    config.autoregression()?;
+    let index = config.date_index()?;
     let mut rng = create_rng(config.seed);
-    let cols = get_cols(config.ncol);
-    let index = make_date_index(config.nper, &config.freq);
     let mut columns = Vec::with_capacity(config.ncol);
     let mut metrics_map = HashMap::new();
 
+    // Time-varying correlation: all columns share one regime path and are
+    // blended with the common factor on increments, with a per-regime weight
+    let dynamic_correlation = correlation.is_none()
+        && config.regimes.enable
+        && !config.regimes.correlation_multipliers.is_empty()
+        && config.cross_correlation > 0.0;
+    let regime_path: Option<Vec<usize>> = if dynamic_correlation {
+        Some(sample_regime_path(&mut rng, &config.regimes, config.nper))
+    } else {
+        None
+    };
+    let increments_config = TimeseriesConfig {
+        cumulative: false,
+        ..config.clone()
+    };
+    let series_config = if dynamic_correlation {
+        &increments_config
+    } else {
+        config
+    };
+
+    // With a full matrix, mix each column's zero-mean shocks jointly; drift,
+    // jumps, mean levels and AR dynamics are then applied per column as usual
+    let shocks: Option<Vec<ColumnShocks>> = correlation
+        .as_ref()
+        .map(|corr| correlated_shocks(&mut rng, config, corr))
+        .transpose()?;
+
     // For cross-correlated series, generate a common factor
-    let common_factor: Vec<f64> = if config.cross_correlation > 0.0 {
-        let (_, factor) = make_time_series_with_config_inner(&mut rng, config);
+    let common_factor: Vec<f64> = if correlation.is_none() && config.cross_correlation > 0.0 {
+        let (_, factor) = make_time_series_with_regime_path(
+            &mut rng,
+            series_config,
+            regime_path.as_deref(),
+            None,
+        );
         factor
     } else {
         vec![]
@@ -681,16 +1090,43 @@ pub fn get_time_series_with_config(config: &TimeseriesConfig) -> TimeSeriesDataW
     // Generate market returns for beta calculation (first column acts as market)
     let mut market_returns: Option<Vec<f64>> = None;
 
-    for (col_idx, c) in cols.iter().enumerate() {
-        let (_, mut values) = make_time_series_with_config_inner(&mut rng, config);
+    for (col_idx, c) in cols.into_iter().enumerate() {
+        let column_shocks = shocks.as_ref().map(|s| &s[col_idx]);
+        let mut values = make_time_series_with_regime_path(
+            &mut rng,
+            series_config,
+            regime_path.as_deref(),
+            column_shocks,
+        )
+        .1;
 
         // Blend with common factor for cross-correlation
-        if config.cross_correlation > 0.0 && !common_factor.is_empty() {
+        if let Some(path) = regime_path.as_deref() {
+            values = values
+                .iter()
+                .zip(common_factor.iter())
+                .zip(path.iter())
+                .map(|((&v, &f), &regime)| {
+                    let rho = regime_cross_correlation(config, regime);
+                    (1.0 - rho).sqrt() * v + rho.sqrt() * f
+                })
+                .collect();
+            if config.cumulative {
+                values = values
+                    .iter()
+                    .scan(0.0, |acc, &x| {
+                        *acc += x;
+                        Some(*acc)
+                    })
+                    .collect();
+            }
+        } else if config.cross_correlation > 0.0 && !common_factor.is_empty() {
+            // Weights keep the variance, so columns correlate at exactly rho
             let rho = config.cross_correlation;
             values = values
                 .iter()
                 .zip(common_factor.iter())
-                .map(|(&v, &f)| (1.0 - rho.sqrt()) * v + rho.sqrt() * f)
+                .map(|(&v, &f)| (1.0 - rho).sqrt() * v + rho.sqrt() * f)
                 .collect();
         }
 
@@ -717,13 +1153,13 @@ pub fn get_time_series_with_config(config: &TimeseriesConfig) -> TimeSeriesDataW
                 },
                 0.02, // 2% annual risk-free rate
             );
-            metrics_map.insert(*c, metrics);
+            metrics_map.insert(c.clone(), metrics);
         }
 
-        columns.push(TimeSeriesColumn { name: *c, values });
+        columns.push(TimeSeriesColumn { name: c, values });
     }
 
-    TimeSeriesDataWithMetrics {
+    Ok(TimeSeriesDataWithMetrics {
         index,
         columns,
         metrics: if config.compute_metrics {
@@ -731,12 +1167,311 @@ pub fn get_time_series_with_config(config: &TimeseriesConfig) -> TimeSeriesDataW
         } else {
             None
         },
+    })
+}
+
+/// Empirical correlation matrix between columns.
+///
+/// Cumulative (price-like) series are differenced first, since levels of
+/// random walks are spuriously correlated; pass `cumulative = false` to
+/// correlate the values directly. Rows and columns follow `columns` order.
+pub fn realized_correlation(columns: &[TimeSeriesColumn], cumulative: bool) -> Vec<Vec<f64>> {
+    let series: Vec<Vec<f64>> = columns
+        .iter()
+        .map(|c| {
+            if cumulative {
+                c.values.windows(2).map(|w| w[1] - w[0]).collect()
+            } else {
+                c.values.clone()
+            }
+        })
+        .collect();
+
+    let n = series.len();
+    let mut matrix = vec![vec![1.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let corr = crate::correlation::pearson_correlation(&series[i], &series[j]);
+            matrix[i][j] = corr;
+            matrix[j][i] = corr;
+        }
     }
+    matrix
+}
+
+// =============================================================================
+// Holiday Calendars
+// =============================================================================
+
+/// Exchange with a built-in, rule-based holiday calendar. Only recurring
+/// holidays are covered, not one-off closures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exchange {
+    /// New York Stock Exchange
+    Nyse,
+    /// London Stock Exchange (England and Wales bank holidays)
+    Lse,
+}
+
+impl Exchange {
+    /// Parse an exchange name such as "NYSE" or "lse"
+    pub fn parse(name: &str) -> Result<Self, TimeseriesError> {
+        match name.trim().to_uppercase().as_str() {
+            "NYSE" => Ok(Exchange::Nyse),
+            "LSE" => Ok(Exchange::Lse),
+            _ => Err(TimeseriesError::UnknownExchange(name.to_string())),
+        }
+    }
+
+    /// Weekday holidays in `year`, after moving weekend dates to the
+    /// observed day
+    pub fn holidays(&self, year: i32) -> Vec<NaiveDate> {
+        let date = |month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let nth = |month, weekday, n| {
+            NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+        };
+        let last = |month, weekday| {
+            NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+                .unwrap_or_else(|| nth(month, weekday, 4))
+        };
+        let easter = easter_sunday(year);
+
+        match self {
+            Exchange::Nyse => {
+                // Saturday holidays close the Friday before, Sunday ones the
+                // Monday after (except New Year's Day on a Saturday)
+                let observed = |d: NaiveDate| match d.weekday() {
+                    Weekday::Sat => d - ChronoDuration::days(1),
+                    Weekday::Sun => d + ChronoDuration::days(1),
+                    _ => d,
+                };
+                let mut days = vec![
+                    observed(date(1, 1)),
+                    nth(1, Weekday::Mon, 3),
+                    nth(2, Weekday::Mon, 3),
+                    easter - ChronoDuration::days(2),
+                    last(5, Weekday::Mon),
+                    observed(date(7, 4)),
+                    nth(9, Weekday::Mon, 1),
+                    nth(11, Weekday::Thu, 4),
+                    observed(date(12, 25)),
+                ];
+                if year >= 2022 {
+                    days.push(observed(date(6, 19)));
+                }
+                days.retain(|d| d.year() == year);
+                days.sort();
+                days
+            }
+            Exchange::Lse => {
+                let mut days = vec![
+                    easter - ChronoDuration::days(2),
+                    easter + ChronoDuration::days(1),
+                    nth(5, Weekday::Mon, 1),
+                    last(5, Weekday::Mon),
+                    last(8, Weekday::Mon),
+                ];
+                // Weekend dates roll forward to the next free weekday
+                for day in [date(1, 1), date(12, 25), date(12, 26)] {
+                    let mut d = day;
+                    while is_weekend(d) || days.contains(&d) {
+                        d += ChronoDuration::days(1);
+                    }
+                    days.push(d);
+                }
+                days.sort();
+                days
+            }
+        }
+    }
+
+    /// Whether the exchange is closed for a holiday on `date`
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays(date.year()).contains(&date)
+    }
+}
+
+/// Easter Sunday in the Gregorian calendar (anonymous Gregorian algorithm)
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Days skipped by business-day and intraday indices besides weekends
+#[derive(Clone, Debug, Default)]
+struct HolidayCalendar {
+    dates: HashSet<NaiveDate>,
+    exchange: Option<Exchange>,
+    /// Exchange holidays by year, computed on first use
+    exchange_years: HashMap<i32, HashSet<NaiveDate>>,
+}
+
+impl HolidayCalendar {
+    fn is_business_day(&mut self, date: NaiveDate) -> bool {
+        if is_weekend(date) || self.dates.contains(&date) {
+            return false;
+        }
+        let Some(exchange) = self.exchange else {
+            return true;
+        };
+        !self
+            .exchange_years
+            .entry(date.year())
+            .or_insert_with(|| exchange.holidays(date.year()).into_iter().collect())
+            .contains(&date)
+    }
+}
+
+// =============================================================================
+// Technical Indicators
+// =============================================================================
+
+/// Rolling technical indicator with its window length
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Indicator {
+    /// Simple moving average
+    Sma(usize),
+    /// Exponential moving average, smoothing 2 / (N + 1)
+    Ema(usize),
+    /// Wilder's relative strength index (0-100)
+    Rsi(usize),
+}
+
+impl Indicator {
+    /// Parse a spec such as "sma:20", "ema:12", or "rsi:14"
+    pub fn parse(spec: &str) -> Result<Self, TimeseriesError> {
+        let invalid = || TimeseriesError::InvalidIndicator(spec.to_string());
+        let (kind, window) = spec.split_once(':').ok_or_else(invalid)?;
+        let window: usize = window.trim().parse().map_err(|_| invalid())?;
+        if window == 0 {
+            return Err(invalid());
+        }
+        match kind.trim().to_lowercase().as_str() {
+            "sma" => Ok(Indicator::Sma(window)),
+            "ema" => Ok(Indicator::Ema(window)),
+            "rsi" => Ok(Indicator::Rsi(window)),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Suffix used for the output column, e.g. "sma_20"
+    pub fn label(&self) -> String {
+        match self {
+            Indicator::Sma(n) => format!("sma_{}", n),
+            Indicator::Ema(n) => format!("ema_{}", n),
+            Indicator::Rsi(n) => format!("rsi_{}", n),
+        }
+    }
+
+    /// Compute the indicator over `values`.
+    ///
+    /// The output has the same length as the input, with NaN during warm-up:
+    /// SMA(N) at index i is the mean of values[i+1-N..=i], EMA(N) is seeded
+    /// with that SMA, and RSI(N) needs N price changes so starts at index N.
+    pub fn apply(&self, values: &[f64]) -> Vec<f64> {
+        match *self {
+            Indicator::Sma(n) => rolling_mean(values, n),
+            Indicator::Ema(n) => {
+                let mut out = vec![f64::NAN; values.len()];
+                if values.len() < n {
+                    return out;
+                }
+                let alpha = 2.0 / (n as f64 + 1.0);
+                let mut ema = values[..n].iter().sum::<f64>() / n as f64;
+                out[n - 1] = ema;
+                for i in n..values.len() {
+                    ema += alpha * (values[i] - ema);
+                    out[i] = ema;
+                }
+                out
+            }
+            Indicator::Rsi(n) => {
+                let mut out = vec![f64::NAN; values.len()];
+                if values.len() <= n {
+                    return out;
+                }
+                let changes: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+                let mut avg_gain = changes[..n].iter().map(|c| c.max(0.0)).sum::<f64>() / n as f64;
+                let mut avg_loss =
+                    changes[..n].iter().map(|c| (-c).max(0.0)).sum::<f64>() / n as f64;
+                out[n] = rsi_value(avg_gain, avg_loss);
+                for i in n..changes.len() {
+                    // Wilder's smoothing
+                    avg_gain = (avg_gain * (n - 1) as f64 + changes[i].max(0.0)) / n as f64;
+                    avg_loss = (avg_loss * (n - 1) as f64 + (-changes[i]).max(0.0)) / n as f64;
+                    out[i + 1] = rsi_value(avg_gain, avg_loss);
+                }
+                out
+            }
+        }
+    }
+}
+
+fn rolling_mean(values: &[f64], n: usize) -> Vec<f64> {
+    let mut out = vec![f64::NAN; values.len()];
+    let mut sum = 0.0;
+    for (i, &v) in values.iter().enumerate() {
+        sum += v;
+        if i >= n {
+            sum -= values[i - n];
+        }
+        if i + 1 >= n {
+            out[i] = sum / n as f64;
+        }
+    }
+    out
+}
+
+fn rsi_value(avg_gain: f64, avg_loss: f64) -> f64 {
+    if avg_loss == 0.0 {
+        100.0
+    } else {
+        100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+    }
+}
+
+/// Append indicator columns named "{column}_{indicator}" (e.g. "A_sma_20").
+///
+/// Columns are appended per source column, in `specs` order.
+pub fn add_indicators(data: &mut TimeSeriesData, specs: &[&str]) -> Result<(), TimeseriesError> {
+    let indicators = specs
+        .iter()
+        .map(|s| Indicator::parse(s))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut added = Vec::with_capacity(data.columns.len() * indicators.len());
+    for col in &data.columns {
+        for indicator in &indicators {
+            added.push(TimeSeriesColumn {
+                name: format!("{}_{}", col.name, indicator.label()),
+                values: indicator.apply(&col.values),
+            });
+        }
+    }
+    data.columns.extend(added);
+    Ok(())
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TimeSeriesColumn {
-    pub name: char,
+    pub name: String,
     pub values: Vec<f64>,
 }
 
@@ -751,7 +1486,7 @@ pub struct TimeSeriesData {
 pub struct TimeSeriesDataWithMetrics {
     pub index: Vec<NaiveDateTime>,
     pub columns: Vec<TimeSeriesColumn>,
-    pub metrics: Option<HashMap<char, FinancialMetrics>>,
+    pub metrics: Option<HashMap<String, FinancialMetrics>>,
 }
 
 impl From<TimeSeriesDataWithMetrics> for TimeSeriesData {
@@ -783,12 +1518,21 @@ pub fn get_time_series_data(
 pub fn get_time_series(nper: usize, freq: &str, ncol: usize, seed: Option<u64>) -> TimeSeriesData {
     let mut rng = create_rng(seed);
     let cols = get_cols(ncol);
-    let index = make_date_index(nper, freq);
+    let index = make_date_index(
+        nper,
+        freq,
+        default_start(),
+        default_trading_window(),
+        &mut HolidayCalendar::default(),
+    );
     let mut columns = Vec::with_capacity(ncol);
 
     for c in cols {
         let (_, values) = make_time_series_with_rng(&mut rng, nper, freq);
-        columns.push(TimeSeriesColumn { name: c, values });
+        columns.push(TimeSeriesColumn {
+            name: c.to_string(),
+            values,
+        });
     }
 
     TimeSeriesData { index, columns }
@@ -809,7 +1553,13 @@ mod tests {
 
     #[test]
     fn test_make_date_index() {
-        let dates = make_date_index(10, "B");
+        let dates = make_date_index(
+            10,
+            "B",
+            default_start(),
+            default_trading_window(),
+            &mut HolidayCalendar::default(),
+        );
         assert_eq!(dates.len(), 10);
         // First business day from Jan 1, 2000 (Saturday) should be Jan 3, 2000 (Monday)
         assert_eq!(
@@ -818,15 +1568,446 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_intraday_date_index() {
+        let config = TimeseriesConfig {
+            nper: 391,
+            freq: "T".to_string(),
+            ..Default::default()
+        };
+        let dates = config.date_index().unwrap();
+        // 2000-01-01 is a Saturday: 390 one-minute bars fill Monday's session
+        let monday = NaiveDate::from_ymd_opt(2000, 1, 3).unwrap();
+        assert!(dates[..390].iter().all(|d| d.date() == monday));
+        assert_eq!(dates[0], monday.and_hms_opt(9, 30, 0).unwrap());
+        assert_eq!(dates[389], monday.and_hms_opt(15, 59, 0).unwrap());
+        assert_eq!(
+            dates[390],
+            NaiveDate::from_ymd_opt(2000, 1, 4)
+                .unwrap()
+                .and_hms_opt(9, 30, 0)
+                .unwrap()
+        );
+
+        // Hourly bars: 09:30 through 15:30, then the next business day
+        let dates = TimeseriesConfig {
+            nper: 8,
+            freq: "H".to_string(),
+            start_date: Some("2024-01-05".to_string()),
+            ..Default::default()
+        }
+        .date_index()
+        .unwrap();
+        assert_eq!(dates[6].time(), NaiveTime::from_hms_opt(15, 30, 0).unwrap());
+        assert_eq!(
+            dates[7],
+            NaiveDate::from_ymd_opt(2024, 1, 8)
+                .unwrap()
+                .and_hms_opt(9, 30, 0)
+                .unwrap()
+        );
+
+        let err = TimeseriesConfig {
+            freq: "min".to_string(),
+            intraday: IntradayConfig {
+                market_open: "16:00".to_string(),
+                market_close: "09:30".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .date_index()
+        .unwrap_err();
+        assert!(matches!(err, TimeseriesError::InvalidTradingWindow { .. }));
+    }
+
+    #[test]
+    fn test_intraday_volatility_follows_time_of_day() {
+        let config = TimeseriesConfig {
+            nper: 390 * 20,
+            ncol: 1,
+            freq: "T".to_string(),
+            seed: Some(42),
+            ar_phi: 0.0,
+            cumulative: false,
+            intraday: IntradayConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+        let values = &data.columns[0].values;
+        // Mean absolute move in a slice of every day's session
+        let mean_abs = |minutes: std::ops::Range<usize>| {
+            let moves: Vec<f64> = (0..20)
+                .flat_map(|day| minutes.clone().map(move |m| values[day * 390 + m].abs()))
+                .collect();
+            moves.iter().sum::<f64>() / moves.len() as f64
+        };
+        let open = mean_abs(0..30);
+        let midday = mean_abs(180..210);
+        assert!(open > 1.5 * midday, "open {} midday {}", open, midday);
+    }
+
+    #[test]
+    fn test_exchange_holidays() {
+        let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+        let nyse_2021 = [
+            d(2021, 1, 1),
+            d(2021, 1, 18),
+            d(2021, 2, 15),
+            d(2021, 4, 2),
+            d(2021, 5, 31),
+            d(2021, 7, 5),
+            d(2021, 9, 6),
+            d(2021, 11, 25),
+            d(2021, 12, 24),
+        ];
+        assert_eq!(Exchange::Nyse.holidays(2021), nyse_2021);
+        // Juneteenth from 2022; New Year's Day 2022 fell on a Saturday and
+        // was not observed
+        assert!(Exchange::Nyse.is_holiday(d(2022, 6, 20)));
+        assert!(!Exchange::Nyse.is_holiday(d(2021, 12, 31)));
+        assert_eq!(
+            Exchange::Lse.holidays(2021),
+            [
+                d(2021, 1, 1),
+                d(2021, 4, 2),
+                d(2021, 4, 5),
+                d(2021, 5, 3),
+                d(2021, 5, 31),
+                d(2021, 8, 30),
+                d(2021, 12, 27),
+                d(2021, 12, 28),
+            ]
+        );
+
+        // 2021 had 252 NYSE trading days
+        let config = TimeseriesConfig {
+            nper: 252,
+            start_date: Some("2021-01-01".to_string()),
+            exchange: Some("NYSE".to_string()),
+            holidays: vec!["2021-03-01".to_string()],
+            ..Default::default()
+        };
+        let dates = config.date_index().unwrap();
+        assert_eq!(dates.len(), 252);
+        assert_eq!(dates[0].date(), d(2021, 1, 4));
+        assert_eq!(dates[251].date(), d(2022, 1, 3));
+        for date in &dates {
+            assert!(!nyse_2021.contains(&date.date()));
+            assert_ne!(date.date(), d(2021, 3, 1));
+        }
+
+        // Each year's exchange holidays are computed once per index
+        let mut calendar = config.holiday_calendar().unwrap();
+        let dates = make_date_index(700, "B", dates[0], default_trading_window(), &mut calendar);
+        assert!(dates
+            .iter()
+            .all(|date| !Exchange::Nyse.is_holiday(date.date())));
+        let mut years: Vec<i32> = calendar.exchange_years.keys().copied().collect();
+        years.sort();
+        assert_eq!(years, [2021, 2022, 2023]);
+        assert_eq!(calendar.exchange_years[&2021].len(), nyse_2021.len());
+
+        let err = TimeseriesConfig {
+            exchange: Some("TSE".to_string()),
+            ..Default::default()
+        }
+        .date_index()
+        .unwrap_err();
+        assert_eq!(err, TimeseriesError::UnknownExchange("TSE".to_string()));
+        let err = TimeseriesConfig {
+            holidays: vec!["July 4".to_string()],
+            ..Default::default()
+        }
+        .date_index()
+        .unwrap_err();
+        assert_eq!(err, TimeseriesError::InvalidHoliday("July 4".to_string()));
+    }
+
     #[test]
     fn test_get_time_series() {
         let data = get_time_series(30, "B", 4, None);
         assert_eq!(data.index.len(), 30);
         assert_eq!(data.columns.len(), 4);
-        assert_eq!(data.columns[0].name, 'A');
-        assert_eq!(data.columns[1].name, 'B');
-        assert_eq!(data.columns[2].name, 'C');
-        assert_eq!(data.columns[3].name, 'D');
+        assert_eq!(data.columns[0].name, "A");
+        assert_eq!(data.columns[1].name, "B");
+        assert_eq!(data.columns[2].name, "C");
+        assert_eq!(data.columns[3].name, "D");
+    }
+
+    #[test]
+    fn test_custom_column_names() {
+        let tickers = vec!["AAPL".to_string(), "MSFT".to_string(), "GOOG".to_string()];
+        let config = TimeseriesConfig {
+            ncol: 3,
+            seed: Some(7),
+            column_names: Some(tickers.clone()),
+            compute_metrics: true,
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+        let names: Vec<String> = data.columns.iter().map(|c| c.name.clone()).collect();
+        assert_eq!(names, tickers);
+        assert!(data.metrics.unwrap().contains_key("MSFT"));
+
+        // Names only relabel: values match the default A/B/C output
+        let default = get_time_series_with_config(&TimeseriesConfig {
+            column_names: None,
+            ..config.clone()
+        })
+        .unwrap();
+        assert_eq!(data.columns[1].values, default.columns[1].values);
+
+        // Fewer names than columns: the rest keep their default label
+        let data = get_time_series_with_config(&TimeseriesConfig {
+            ncol: 5,
+            ..config.clone()
+        })
+        .unwrap();
+        let names: Vec<String> = data.columns.iter().map(|c| c.name.clone()).collect();
+        assert_eq!(names, ["AAPL", "MSFT", "GOOG", "D", "E"]);
+
+        let err = get_time_series_with_config(&TimeseriesConfig {
+            ncol: 2,
+            ..config.clone()
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            TimeseriesError::ColumnNamesMismatch {
+                expected: 2,
+                got: 3
+            }
+        );
+
+        // Names must be unique, including against the default labels kept
+        for (names, duplicate) in [(vec!["AAPL", "AAPL"], "AAPL"), (vec!["B"], "B")] {
+            let err = get_time_series_with_config(&TimeseriesConfig {
+                ncol: 2,
+                column_names: Some(names.iter().map(|n| n.to_string()).collect()),
+                ..config.clone()
+            })
+            .unwrap_err();
+            assert_eq!(err, TimeseriesError::DuplicateColumnName(duplicate.into()));
+        }
+    }
+
+    #[test]
+    fn test_start_date() {
+        let config = TimeseriesConfig {
+            nper: 5,
+            seed: Some(7),
+            start_date: Some("2020-01-01".to_string()),
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+        // 2020-01-01 is a Wednesday
+        assert_eq!(
+            data.index[0].date(),
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()
+        );
+        assert_eq!(
+            data.index[4].date(),
+            NaiveDate::from_ymd_opt(2020, 1, 7).unwrap()
+        );
+
+        // Only the index moves: values match the default start
+        let default = get_time_series_with_config(&TimeseriesConfig {
+            start_date: None,
+            ..config.clone()
+        })
+        .unwrap();
+        assert_eq!(data.columns[0].values, default.columns[0].values);
+
+        let err = get_time_series_with_config(&TimeseriesConfig {
+            start_date: Some("01/01/2020".to_string()),
+            ..config
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            TimeseriesError::InvalidStartDate("01/01/2020".to_string())
+        );
+    }
+
+    #[test]
+    fn test_student_t_kurtosis() {
+        // Kurtosis of Student-t is 6 / (df - 4) + 3 for df > 4. The sample
+        // estimate runs low at df = 5, but stays clear of the 6 a sampler with
+        // the wrong df (6 here) would give
+        let df = 5.0;
+        let mut rng = create_rng(Some(42));
+        let samples: Vec<f64> = (0..100_000)
+            .map(|_| sample_student_t(&mut rng, df))
+            .collect();
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let m2 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let m4 = samples.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / n;
+        let kurtosis = m4 / (m2 * m2);
+        let expected = 6.0 / (df - 4.0) + 3.0;
+        assert!(
+            (kurtosis - expected).abs() < 2.0,
+            "kurtosis {} vs {}",
+            kurtosis,
+            expected
+        );
+        // Variance is df / (df - 2)
+        assert!((m2 - df / (df - 2.0)).abs() < 0.08, "variance {}", m2);
+
+        // Fractional df samples without panicking and stays finite
+        assert!((0..1000).all(|_| sample_student_t(&mut rng, 4.5).is_finite()));
+    }
+
+    #[test]
+    fn test_correlation_matrix() {
+        let matrix = vec![
+            vec![1.0, 0.8, -0.4],
+            vec![0.8, 1.0, 0.0],
+            vec![-0.4, 0.0, 1.0],
+        ];
+        let config = TimeseriesConfig {
+            nper: 5000,
+            ncol: 3,
+            seed: Some(42),
+            // Independent increments, so the sample correlation is tight
+            ar_phi: 0.0,
+            correlation_matrix: Some(matrix.clone()),
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+        let corr = realized_correlation(&data.columns, config.cumulative);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (corr[i][j] - matrix[i][j]).abs() < 0.05,
+                    "[{}][{}] = {}",
+                    i,
+                    j,
+                    corr[i][j]
+                );
+            }
+        }
+
+        // The matrix must be ncol x ncol and positive semi-definite
+        let err = get_time_series_with_config(&TimeseriesConfig {
+            ncol: 2,
+            ..config.clone()
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            TimeseriesError::InvalidCorrelationMatrix(CorrelationError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        );
+        let err = get_time_series_with_config(&TimeseriesConfig {
+            correlation_matrix: Some(vec![
+                vec![1.0, 0.9, 0.9],
+                vec![0.9, 1.0, -0.9],
+                vec![0.9, -0.9, 1.0],
+            ]),
+            ..config
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            TimeseriesError::InvalidCorrelationMatrix(CorrelationError::NotPositiveSemiDefinite)
+        );
+    }
+
+    #[test]
+    fn test_correlation_matrix_keeps_drift_and_mean() {
+        let matrix = vec![
+            vec![1.0, 0.8, -0.4],
+            vec![0.8, 1.0, 0.0],
+            vec![-0.4, 0.0, 1.0],
+        ];
+        let config = TimeseriesConfig {
+            nper: 5000,
+            ncol: 3,
+            seed: Some(7),
+            ar_phi: 0.0,
+            drift: 0.5,
+            correlation_matrix: Some(matrix.clone()),
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+        for column in &data.columns {
+            let slope = column.values.last().unwrap() / config.nper as f64;
+            assert!(
+                (slope - 0.5).abs() < 0.05,
+                "{} slope = {}",
+                column.name,
+                slope
+            );
+        }
+        let corr = realized_correlation(&data.columns, config.cumulative);
+        assert!((corr[0][1] - 0.8).abs() < 0.05, "corr = {}", corr[0][1]);
+
+        let reverting = get_time_series_with_config(&TimeseriesConfig {
+            mean_reversion: MeanReversionConfig {
+                enable: true,
+                mu: 100.0,
+                ..Default::default()
+            },
+            ..config
+        })
+        .unwrap();
+        for column in &reverting.columns {
+            let mean = column.values.iter().sum::<f64>() / column.values.len() as f64;
+            assert!(
+                (mean - 100.0).abs() < 0.5,
+                "{} mean = {}",
+                column.name,
+                mean
+            );
+        }
+    }
+
+    #[test]
+    fn test_ar_coefficients() {
+        let config = TimeseriesConfig {
+            nper: 200,
+            ncol: 2,
+            seed: Some(42),
+            ar_phi: 0.6,
+            ..Default::default()
+        };
+        // A single coefficient reproduces the scalar ar_phi
+        let ar1 = get_time_series_with_config(&config).unwrap();
+        let arp = get_time_series_with_config(&TimeseriesConfig {
+            ar_phi: 0.0,
+            ar_coefficients: Some(vec![0.6]),
+            ..config.clone()
+        })
+        .unwrap();
+        for (a, b) in ar1.columns.iter().zip(&arp.columns) {
+            for (x, y) in a.values.iter().zip(&b.values) {
+                assert!((x - y).abs() < 1e-9);
+            }
+        }
+
+        let ar2 = get_time_series_with_config(&TimeseriesConfig {
+            ar_coefficients: Some(vec![1.5, -0.75]),
+            ..config.clone()
+        })
+        .unwrap();
+        assert_eq!(ar2.columns[0].values.len(), 200);
+
+        let err = get_time_series_with_config(&TimeseriesConfig {
+            ar_coefficients: Some(vec![0.5, 0.6]),
+            ..config
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            TimeseriesError::InvalidArCoefficients(TemporalError::InvalidCoefficients(_))
+        ));
     }
 
     #[test]
@@ -846,4 +2027,158 @@ mod tests {
         assert_eq!(data1.get(&'A').unwrap().1, data2.get(&'A').unwrap().1);
         assert_eq!(data1.get(&'B').unwrap().1, data2.get(&'B').unwrap().1);
     }
+
+    #[test]
+    fn test_cross_correlation_blend_keeps_volatility() {
+        let config = TimeseriesConfig {
+            nper: 5000,
+            ncol: 3,
+            seed: Some(11),
+            ..Default::default()
+        };
+        let increment_std = |cross_correlation: f64| -> Vec<f64> {
+            let data = get_time_series_with_config(&TimeseriesConfig {
+                cross_correlation,
+                ..config.clone()
+            })
+            .unwrap();
+            data.columns
+                .iter()
+                .map(|c| {
+                    let diffs: Vec<f64> = c.values.windows(2).map(|w| w[1] - w[0]).collect();
+                    let mean = diffs.iter().sum::<f64>() / diffs.len() as f64;
+                    let var =
+                        diffs.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / diffs.len() as f64;
+                    var.sqrt()
+                })
+                .collect()
+        };
+
+        // sqrt(1 - rho) and sqrt(rho) weights add variances to 1, so blending
+        // in the common factor leaves each column's volatility unchanged
+        for (independent, blended) in increment_std(0.0).iter().zip(increment_std(0.8)) {
+            assert!(
+                (blended / independent - 1.0).abs() < 0.05,
+                "std {} vs {}",
+                blended,
+                independent
+            );
+        }
+    }
+
+    #[test]
+    fn test_realized_correlation_matches_target() {
+        let config = TimeseriesConfig {
+            nper: 5000,
+            ncol: 4,
+            seed: Some(11),
+            cross_correlation: 0.8,
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+        let corr = realized_correlation(&data.columns, config.cumulative);
+
+        assert_eq!(corr.len(), 4);
+        for (i, row) in corr.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                assert_eq!(value, corr[j][i]);
+                if i == j {
+                    assert_eq!(value, 1.0);
+                } else {
+                    assert!((value - 0.8).abs() < 0.1, "corr[{}][{}] = {}", i, j, value);
+                }
+            }
+        }
+
+        let independent = get_time_series_with_config(&TimeseriesConfig {
+            cross_correlation: 0.0,
+            ..config
+        })
+        .unwrap();
+        let corr = realized_correlation(&independent.columns, true);
+        assert!(corr[0][1].abs() < 0.2);
+    }
+
+    #[test]
+    fn test_sma_matches_manual_mean() {
+        let mut data = get_time_series(100, "B", 2, Some(3));
+        add_indicators(&mut data, &["sma:20", "ema:12", "rsi:14"]).unwrap();
+
+        let names: Vec<&str> = data.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["A", "B", "A_sma_20", "A_ema_12", "A_rsi_14", "B_sma_20", "B_ema_12", "B_rsi_14"]
+        );
+
+        let values = &data.columns[0].values;
+        let sma = &data.columns[2].values;
+        assert_eq!(sma.len(), values.len());
+        for (i, &s) in sma.iter().enumerate() {
+            if i < 19 {
+                assert!(s.is_nan());
+            } else {
+                let manual = values[i - 19..=i].iter().sum::<f64>() / 20.0;
+                assert!((s - manual).abs() < 1e-9, "sma[{}] {} vs {}", i, s, manual);
+            }
+        }
+
+        let ema = &data.columns[3].values;
+        assert!(ema[10].is_nan() && !ema[11].is_nan());
+        let rsi = &data.columns[4].values;
+        assert!(rsi[13].is_nan());
+        assert!(rsi[14..].iter().all(|r| (0.0..=100.0).contains(r)));
+
+        assert!(add_indicators(&mut data, &["macd:9"]).is_err());
+        assert!(Indicator::parse("sma:0").is_err());
+    }
+
+    #[test]
+    fn test_regime_dependent_correlation() {
+        let config = TimeseriesConfig {
+            nper: 4000,
+            ncol: 2,
+            seed: Some(2024),
+            cumulative: false,
+            cross_correlation: 0.3,
+            regimes: RegimeConfig {
+                enable: true,
+                regime_persistence: 0.98,
+                correlation_multipliers: vec![1.0, 3.0],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let data = get_time_series_with_config(&config).unwrap();
+
+        // The shared regime path is the first thing drawn from the seeded RNG
+        let mut rng = create_rng(config.seed);
+        let path = sample_regime_path(&mut rng, &config.regimes, config.nper);
+
+        let a = &data.columns[0].values;
+        let b = &data.columns[1].values;
+        let window = 20;
+        let mut calm = Vec::new();
+        let mut stress = Vec::new();
+        for start in 0..(config.nper - window) {
+            let regimes = &path[start..start + window];
+            let corr = crate::correlation::pearson_correlation(
+                &a[start..start + window],
+                &b[start..start + window],
+            );
+            if regimes.iter().all(|&r| r == 0) {
+                calm.push(corr);
+            } else if regimes.iter().all(|&r| r == 1) {
+                stress.push(corr);
+            }
+        }
+        assert!(!calm.is_empty() && !stress.is_empty());
+
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        assert!(
+            mean(&stress) > mean(&calm) + 0.2,
+            "stress corr {} should exceed calm corr {}",
+            mean(&stress),
+            mean(&calm)
+        );
+    }
 }
@@ -96,5 +96,83 @@ lazy_static::lazy_static! {
         m
     };
 
-    pub static ref US_SECTORS: Vec<&'static str> = US_SECTORS_MAP.keys().copied().collect();
+    // Sorted so seeded sector draws don't depend on HashMap iteration order
+    pub static ref US_SECTORS: Vec<&'static str> = {
+        let mut sectors: Vec<&'static str> = US_SECTORS_MAP.keys().copied().collect();
+        sectors.sort_unstable();
+        sectors
+    };
+}
+
+// Adding then subtracting 2^52 rounds any smaller non-negative f64 to an
+// integer (ties to even)
+const TWO_POW_52: f64 = 4_503_599_627_370_496.0;
+
+/// Round `x` to cents, bit-for-bit the same as `(x * 100.0).round() / 100.0`.
+///
+/// Built from adds, compares, and selects rather than a call to `round`, so
+/// loops over it compile to SIMD; see [`round_cents`].
+#[inline(always)]
+pub fn round_cent(x: f64) -> f64 {
+    let scaled = x * 100.0;
+    let magnitude = scaled.abs();
+    let mut rounded = (magnitude + TWO_POW_52) - TWO_POW_52;
+    // Ties went to even; `round` takes them away from zero
+    if magnitude - rounded == 0.5 {
+        rounded += 1.0;
+    }
+    // Values this large (and infinities) are already integers
+    if magnitude >= TWO_POW_52 {
+        rounded = magnitude;
+    }
+    rounded.copysign(scaled) / 100.0
+}
+
+/// Round a whole column to cents in place.
+///
+/// Gives the same values as rounding each one with
+/// `(x * 100.0).round() / 100.0`. The loop vectorizes; on 10M values it runs
+/// in about 60% of the scalar time (see `examples/round_cents.rs`).
+pub fn round_cents(values: &mut [f64]) {
+    for value in values.iter_mut() {
+        *value = round_cent(*value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::seeded_rng;
+    use rand::RngExt;
+
+    #[test]
+    fn test_round_cents_matches_scalar() {
+        let mut rng = seeded_rng(Some(42));
+        let mut values: Vec<f64> = (0..100_000)
+            .map(|_| rng.random_range(-10_000.0..10_000.0))
+            .collect();
+        // Exact ties at every magnitude, plus edge cases
+        values.extend((-2000..2000).map(|i| i as f64 * 0.005));
+        values.extend([
+            0.0,
+            -0.0,
+            0.004999999999999999,
+            1e15,
+            -1e300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ]);
+
+        let expected: Vec<f64> = values.iter().map(|x| (x * 100.0).round() / 100.0).collect();
+        round_cents(&mut values);
+        for (got, want) in values.iter().zip(&expected) {
+            assert_eq!(got.to_bits(), want.to_bits(), "{} vs {}", got, want);
+        }
+
+        let mut nan = [f64::NAN];
+        round_cents(&mut nan);
+        assert!(nan[0].is_nan());
+    }
 }
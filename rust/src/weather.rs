@@ -6,9 +6,9 @@
 //! - Weather events (rain, snow, clouds)
 //! - Occasional outlier events (sensor errors, extreme weather)
 
+use crate::rng::{seeded_rng, SeededRng};
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike, Utc};
-use rand::rngs::StdRng;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -131,11 +131,8 @@ impl Default for WeatherState {
 }
 
 /// Create an RNG from an optional seed
-fn create_rng(seed: Option<u64>) -> StdRng {
-    match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_rng(&mut rand::rng()),
-    }
+fn create_rng(seed: Option<u64>) -> SeededRng {
+    seeded_rng(seed)
 }
 
 /// Parse a date string or use default
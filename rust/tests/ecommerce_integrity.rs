@@ -0,0 +1,324 @@
+//! Referential integrity of the full `ecommerce()` pipeline.
+//!
+//! Each table is generated by its own function from the tables before it;
+//! these tests check that the joins a user would run (orders to sessions,
+//! sessions to customers, events to products) hold across the whole dataset.
+
+use std::collections::{HashMap, HashSet};
+
+use superstore::{
+    ecommerce, generate_cart_events, generate_catalog, generate_interactions, generate_sessions,
+    CatalogConfig, EcommerceConfig, EcommerceData, FunnelConfig, MinOrderPolicy, Product, Session,
+};
+
+fn config(seed: u64) -> EcommerceConfig {
+    EcommerceConfig {
+        sessions: 3000,
+        customers: 400,
+        seed: Some(seed),
+        funnel: FunnelConfig {
+            enable: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn dataset(seed: u64) -> EcommerceData {
+    ecommerce(&config(seed))
+}
+
+/// The dataset without a minimum order value and with one under each policy
+fn datasets(seed: u64) -> Vec<EcommerceData> {
+    let mut datasets = vec![dataset(seed)];
+    for min_order_policy in [MinOrderPolicy::Bump, MinOrderPolicy::Drop] {
+        datasets.push(ecommerce(&EcommerceConfig {
+            min_order_value: Some(100.0),
+            min_order_policy,
+            ..config(seed)
+        }));
+    }
+    datasets
+}
+
+fn sessions_by_id(data: &EcommerceData) -> HashMap<&str, &Session> {
+    data.sessions
+        .iter()
+        .map(|s| (s.session_id.as_str(), s))
+        .collect()
+}
+
+#[test]
+fn test_orders_reference_converted_sessions() {
+    for data in [1, 42].into_iter().flat_map(datasets) {
+        let sessions = sessions_by_id(&data);
+        assert_eq!(sessions.len(), data.sessions.len(), "duplicate session ids");
+
+        let mut ordered = HashSet::new();
+        for order in &data.orders {
+            let session = sessions[order.session_id.as_str()];
+            assert!(session.converted);
+            assert_eq!(order.user_id, session.user_id);
+            assert_eq!(order.currency, session.currency);
+            assert_eq!(order.subtotal, session.total_value);
+            assert!(ordered.insert(order.session_id.as_str()));
+
+            let total = order.subtotal - order.discount + order.tax + order.shipping;
+            assert!((order.total - total).abs() < 0.02);
+        }
+
+        let converted = data.sessions.iter().filter(|s| s.converted).count();
+        assert!(converted > 0);
+        assert_eq!(data.orders.len(), converted);
+    }
+}
+
+#[test]
+fn test_customers_match_order_users() {
+    let data = dataset(42);
+
+    let mut orders_by_user: HashMap<&str, (u32, f64)> = HashMap::new();
+    for order in &data.orders {
+        let entry = orders_by_user.entry(order.user_id.as_str()).or_default();
+        entry.0 += 1;
+        entry.1 += order.total;
+    }
+
+    let session_users: HashSet<&str> = data.sessions.iter().map(|s| s.user_id.as_str()).collect();
+    let customer_ids: HashSet<&str> = data
+        .customers
+        .iter()
+        .map(|c| c.customer_id.as_str())
+        .collect();
+    assert_eq!(customer_ids.len(), data.customers.len());
+    assert_eq!(
+        customer_ids,
+        orders_by_user.keys().copied().collect::<HashSet<_>>()
+    );
+
+    for customer in &data.customers {
+        assert!(session_users.contains(customer.customer_id.as_str()));
+        let (count, spent) = orders_by_user[customer.customer_id.as_str()];
+        assert_eq!(customer.total_orders, count);
+        assert!((customer.total_spent - spent).abs() < 0.01);
+        assert!(customer.first_order_date <= customer.last_order_date);
+    }
+}
+
+#[test]
+fn test_cart_event_prices_are_consistent() {
+    for seed in [1, 42] {
+        let data = dataset(seed);
+        let prices: HashMap<&str, f64> = data
+            .products
+            .iter()
+            .map(|p| (p.product_id.as_str(), p.price))
+            .collect();
+
+        // Session-level events carry the running cart total. Updates and
+        // removes apply to the line added last in the session.
+        let mut carts: HashMap<&str, f64> = HashMap::new();
+        let mut last_line: HashMap<&str, (&str, f64)> = HashMap::new();
+        let mut updates = 0;
+        let mut abandoned = 0;
+        for event in &data.cart_events {
+            let session = event.session_id.as_str();
+            let cart = carts.entry(session).or_default();
+            match (event.event_type.as_str(), event.product_id.as_deref()) {
+                (
+                    kind @ ("add_to_cart" | "update_quantity" | "remove_from_cart"),
+                    Some(product_id),
+                ) => {
+                    assert!(event.quantity >= 1);
+                    assert_eq!(event.unit_price, prices[product_id]);
+                    assert_eq!(event.total_price, event.unit_price * event.quantity as f64);
+                    if kind == "add_to_cart" {
+                        *cart += event.total_price;
+                        last_line.insert(session, (product_id, event.total_price));
+                        continue;
+                    }
+                    let (added, line_total) = last_line[session];
+                    assert_eq!(added, product_id);
+                    if kind == "update_quantity" {
+                        assert_ne!(event.total_price, line_total);
+                        *cart += event.total_price - line_total;
+                        last_line.insert(session, (product_id, event.total_price));
+                        updates += 1;
+                    } else {
+                        assert_eq!(event.total_price, line_total);
+                        *cart -= event.total_price;
+                    }
+                }
+                (_, None) => {
+                    assert_eq!(event.quantity, 0);
+                    assert_eq!(event.unit_price, 0.0);
+                    assert!((event.total_price - *cart).abs() < 1e-9);
+                    // Zero only when every added line was removed again
+                    assert!(event.total_price > -1e-9);
+                    if event.event_type == "cart_abandoned" && event.total_price > 0.0 {
+                        abandoned += 1;
+                    }
+                }
+                _ => panic!("unexpected event {:?}", event),
+            }
+        }
+        assert!(updates > 0);
+        assert!(abandoned > 0);
+    }
+}
+
+#[test]
+fn test_cart_event_products_exist_in_catalog() {
+    let config = EcommerceConfig {
+        sessions: 2000,
+        seed: Some(7),
+        ..Default::default()
+    };
+    let data = ecommerce(&config);
+
+    // Sub-generators called on their own rebuild the same catalog and events
+    let catalog = generate_catalog(&config);
+    let sessions = generate_sessions(&config);
+    let events = generate_cart_events(&sessions, &catalog, &config);
+    let ids = |products: &[Product]| -> Vec<String> {
+        products.iter().map(|p| p.product_id.clone()).collect()
+    };
+    assert_eq!(ids(&catalog), ids(&data.products));
+    assert_eq!(events.len(), data.cart_events.len());
+    for (a, b) in events.iter().zip(&data.cart_events) {
+        assert_eq!(a.product_id, b.product_id);
+    }
+
+    // Events always reference whichever catalog instance was passed in
+    for catalog in [
+        data.products.clone(),
+        generate_catalog(&EcommerceConfig {
+            catalog: CatalogConfig {
+                num_products: 40,
+                variants_per_product: 3,
+                ..Default::default()
+            },
+            ..config.clone()
+        }),
+    ] {
+        let known: HashSet<String> = ids(&catalog).into_iter().collect();
+        let events = generate_cart_events(&sessions, &catalog, &config);
+        assert!(events.iter().any(|e| e.product_id.is_some()));
+        for product_id in events.iter().filter_map(|e| e.product_id.as_ref()) {
+            assert!(known.contains(product_id), "unknown product {}", product_id);
+        }
+    }
+
+    assert!(generate_cart_events(&sessions, &[], &config).is_empty());
+}
+
+#[test]
+fn test_events_reference_sessions_and_products() {
+    for data in datasets(42) {
+        check_events_reference_sessions_and_products(&data);
+    }
+}
+
+fn check_events_reference_sessions_and_products(data: &EcommerceData) {
+    let sessions = sessions_by_id(data);
+    let products: HashSet<&str> = data
+        .products
+        .iter()
+        .map(|p| p.product_id.as_str())
+        .collect();
+
+    assert!(!data.cart_events.is_empty());
+    for event in &data.cart_events {
+        let session = sessions[event.session_id.as_str()];
+        assert_eq!(event.user_id, session.user_id);
+        assert!(!session.bounced);
+        match event.product_id.as_deref() {
+            Some(product_id) => assert!(products.contains(product_id)),
+            None => assert!(!matches!(
+                event.event_type.as_str(),
+                "add_to_cart" | "remove_from_cart"
+            )),
+        }
+        if event.event_type == "checkout_complete" {
+            assert!(session.converted);
+        }
+    }
+
+    let last_stage = data
+        .funnel_events
+        .iter()
+        .map(|e| e.stage_number)
+        .max()
+        .unwrap();
+    let mut deepest: HashMap<&str, u32> = HashMap::new();
+    for event in &data.funnel_events {
+        let session = sessions[event.session_id.as_str()];
+        assert_eq!(event.user_id, session.user_id);
+        let stage = deepest.entry(event.session_id.as_str()).or_default();
+        *stage = (*stage).max(event.stage_number);
+    }
+    assert_eq!(deepest.len(), data.sessions.len());
+    for session in &data.sessions {
+        let stage = deepest[session.session_id.as_str()];
+        if session.bounced {
+            assert_eq!(stage, 0);
+        }
+        assert_eq!(session.converted, stage == last_stage);
+    }
+}
+
+#[test]
+fn test_interactions_are_backed_by_events() {
+    for data in datasets(42) {
+        check_interactions_are_backed_by_events(&data);
+    }
+}
+
+fn check_interactions_are_backed_by_events(data: &EcommerceData) {
+    let edges = generate_interactions(&data.cart_events, &data.orders);
+    let sessions = sessions_by_id(data);
+    let ordered: HashSet<&str> = data.orders.iter().map(|o| o.session_id.as_str()).collect();
+
+    // Count the events behind each (user, product, type) edge: item events
+    // are views, adds are add_to_cart, and lines left at checkout of an
+    // ordered session are purchases
+    let mut backing: HashMap<(&str, &str, &str), u32> = HashMap::new();
+    let mut carts: HashMap<&str, Vec<&str>> = HashMap::new();
+    for event in &data.cart_events {
+        let user = event.user_id.as_str();
+        let session = event.session_id.as_str();
+        if let Some(product) = event.product_id.as_deref() {
+            *backing.entry((user, product, "view")).or_default() += 1;
+            let cart = carts.entry(session).or_default();
+            match event.event_type.as_str() {
+                "add_to_cart" => {
+                    *backing.entry((user, product, "add_to_cart")).or_default() += 1;
+                    cart.push(product);
+                }
+                "remove_from_cart" => {
+                    let line = cart.iter().rposition(|&p| p == product).unwrap();
+                    cart.remove(line);
+                }
+                _ => {}
+            }
+        } else if event.event_type == "checkout_complete" && ordered.contains(session) {
+            assert!(sessions[session].converted);
+            for product in carts.remove(session).unwrap_or_default() {
+                *backing.entry((user, product, "purchase")).or_default() += 1;
+            }
+        }
+    }
+
+    assert!(edges.iter().any(|e| e.interaction_type == "purchase"));
+    let mut seen = HashSet::new();
+    for edge in &edges {
+        let key = (
+            edge.user_id.as_str(),
+            edge.product_id.as_str(),
+            edge.interaction_type.as_str(),
+        );
+        assert!(seen.insert(key), "duplicate edge {:?}", key);
+        assert_eq!(backing.get(&key), Some(&edge.weight), "edge {:?}", key);
+    }
+    assert_eq!(seen.len(), backing.len());
+}
@@ -0,0 +1,97 @@
+//! Golden-file tests for the version-stable `stable_v1` generation mode.
+//!
+//! These pin the exact seeded output of `stable_v1`. If one fails, the frozen
+//! generation path changed: that is a breaking change for users relying on
+//! reproducible fixtures, and must not be "fixed" by regenerating the files.
+
+use superstore::{superstore_with_config, PaymentConfig, SuperstoreConfig, SuperstoreRow};
+
+const GOLDEN_CSV: &str = include_str!("golden/superstore_stable_v1_seed42.csv");
+
+/// FNV-1a checksum of 1000 `stable_v1` rows with seed 42
+const GOLDEN_CHECKSUM: u64 = 11191593311069940357;
+
+fn stable_config(count: usize) -> SuperstoreConfig {
+    SuperstoreConfig {
+        count,
+        seed: Some(42),
+        stable_v1: true,
+        ..Default::default()
+    }
+}
+
+fn quote(field: &str) -> String {
+    if field.contains(',') {
+        format!("\"{}\"", field)
+    } else {
+        field.to_string()
+    }
+}
+
+fn row_line(row: &SuperstoreRow) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        row.row_id,
+        row.order_id,
+        row.order_date,
+        row.ship_date,
+        row.ship_mode,
+        row.customer_id,
+        row.segment,
+        row.country,
+        row.city,
+        row.state,
+        row.postal_code,
+        row.region,
+        row.product_id,
+        quote(&row.category),
+        quote(&row.sub_category),
+        row.item_status,
+        row.item_price,
+        row.sales,
+        row.quantity,
+        row.discount,
+        row.profit
+    )
+}
+
+fn checksum(rows: &[SuperstoreRow]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for row in rows {
+        for byte in row_line(row).bytes().chain(std::iter::once(b'\n')) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+#[test]
+fn test_stable_v1_matches_golden_rows() {
+    let rows = superstore_with_config(&stable_config(20));
+    let expected: Vec<&str> = GOLDEN_CSV.lines().skip(1).collect();
+    let actual: Vec<String> = rows.iter().map(row_line).collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_stable_v1_checksum() {
+    let rows = superstore_with_config(&stable_config(1000));
+    assert_eq!(checksum(&rows), GOLDEN_CHECKSUM);
+}
+
+#[test]
+fn test_stable_v1_ignores_optional_features() {
+    let baseline = superstore_with_config(&stable_config(200));
+    let with_features = superstore_with_config(&SuperstoreConfig {
+        valid_identifiers: true,
+        payment: PaymentConfig {
+            enable: true,
+            card_numbers: true,
+            ..Default::default()
+        },
+        ..stable_config(200)
+    });
+    assert_eq!(checksum(&baseline), checksum(&with_features));
+    assert!(with_features.iter().all(|r| r.payment_method.is_none()));
+}
@@ -0,0 +1,38 @@
+//! Checksums of seeded output under the pinned PCG64 generator.
+//!
+//! Selecting the PRNG is process-wide, so these live in their own test binary
+//! rather than alongside tests that expect the default generator. A failure
+//! here means seeded output under `RngAlgorithm::Pcg64` changed.
+
+use superstore::{
+    generate_subscriptions, set_rng_algorithm, superstore_with_config, RngAlgorithm,
+    SubscriptionConfig, SuperstoreConfig,
+};
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[test]
+fn test_pcg64_output_checksums() {
+    set_rng_algorithm(RngAlgorithm::Pcg64);
+
+    let rows = superstore_with_config(&SuperstoreConfig {
+        count: 500,
+        seed: Some(42),
+        stable_v1: true,
+        ..Default::default()
+    });
+    let subscriptions = generate_subscriptions(&SubscriptionConfig {
+        count: 200,
+        seed: Some(42),
+        ..Default::default()
+    });
+
+    let rows_json = serde_json::to_string(&rows).unwrap();
+    let subscriptions_json = serde_json::to_string(&subscriptions).unwrap();
+    assert_eq!(fnv1a(rows_json.as_bytes()), 13887362586159331634);
+    assert_eq!(fnv1a(subscriptions_json.as_bytes()), 5844030648507162130);
+}
@@ -10,16 +10,22 @@ from .config import (
     ClimateZone,
     CrossfilterConfig,
     EcommerceConfig,
+    EmployeeConfig,
+    EventWindowConfig,
     FinanceConfig,
     FunnelConfig,
+    InteractionConfig,
+    IntradayBarConfig,
     LogFormat,
     LogLevel,
     LogsConfig,
     MachineType,
+    MinOrderPolicy,
     OhlcvConfig,
     OptionsConfig,
     OutputFormat,
     RfmConfig,
+    SalesMode,
     Season,
     SessionConfig,
     StockConfig,
@@ -36,6 +42,8 @@ from .config import (
     timeseries_config,
     weather_config,
 )
+from .bench import bench
+from .pacing import replay
 from .superstore import (
     # Temporal dependency models
     AR1,
@@ -57,16 +65,23 @@ from .superstore import (
     GumbelCopula,
     MarkovChain,
     RandomWalk,
+    StudentTCopula,
     SuperstoreStream,
     addGaussianNoise,
     app_logs,
     applyMissing,
+    batch,
+    cohort_retention,
+    credit_defaults,
     # E-commerce generators
     ecommerce_data,
+    ecommerce_interactions,
     ecommerce_products,
     ecommerce_sessions,
+    effective_config,
     # Core generators
     employees,
+    employees_stream,
     # Arrow IPC export
     employeesArrowIpc,
     employeesParallel,
@@ -75,13 +90,20 @@ from .superstore import (
     employeesToCsv,
     employeesToParquet,
     finance,
+    fix_messages,
+    indicators,
     jobs,
     # Logs generators
     logs,
+    logs_with_deploys,
     machines,
+    nearestCorrelationMatrix,
     numThreads,
     options_chain,
     pearsonCorrelation,
+    portfolio,
+    rngAlgorithm,
+    row_at,
     sampleBeta,
     sampleBivariate,
     sampleCategorical,
@@ -98,10 +120,15 @@ from .superstore import (
     # Deterministic mode
     setDeterministicMode,
     setNumThreads,
+    setRngAlgorithm,
     status,
     # Finance generators
     stock_prices,
+    stream_quantiles,
+    # Subscription generators
+    subscriptions,
     superstore,
+    superstore_stream,
     superstoreArrowIpc,
     # Parallel generators
     superstoreParallel,
@@ -113,8 +140,13 @@ from .superstore import (
     timeseries,
     timeseriesData,
     usage,
+    validate_config,
+    var_cvar,
     # Weather generator
     weather,
+    with_chargebacks,
+    with_employees,
+    yield_curve,
 )
 
 __all__ = (
@@ -129,26 +161,41 @@ __all__ = (
     "employees",
     "timeseries",
     "timeseriesData",
+    "indicators",
     "jobs",
     "machines",
     "status",
     "superstore",
+    "with_employees",
+    "with_chargebacks",
+    "batch",
+    "row_at",
+    "effective_config",
+    "validate_config",
     "telemetry",
     "usage",
     "weather",
     "logs",
+    "logs_with_deploys",
     "app_logs",
     # Streaming generators
     "superstoreStream",
     "employeesStream",
+    "superstore_stream",
+    "employees_stream",
+    "stream_quantiles",
     "SuperstoreStream",
     "EmployeeStream",
+    "replay",
+    "bench",
     # Parallel generators
     "superstoreParallel",
     "employeesParallel",
     "numThreads",
     "setNumThreads",
     "setDeterministicMode",
+    "setRngAlgorithm",
+    "rngAlgorithm",
     # Statistical distributions
     "sampleUniform",
     "sampleNormal",
@@ -175,6 +222,7 @@ __all__ = (
     "CorrelationMatrix",
     "sampleBivariate",
     "pearsonCorrelation",
+    "nearestCorrelationMatrix",
     # Temporal dependency models
     "AR1",
     "ARp",
@@ -183,19 +231,23 @@ __all__ = (
     "ExponentialSmoothing",
     # Copula models
     "GaussianCopula",
+    "StudentTCopula",
     "ClaytonCopula",
     "FrankCopula",
     "GumbelCopula",
     # Configuration classes
     "WeatherConfig",
     "SuperstoreConfig",
+    "EmployeeConfig",
     "TimeseriesConfig",
     "CrossfilterConfig",
     # Config enums
     "ClimateZone",
+    "SalesMode",
     "Season",
     "WeatherEvent",
     "MachineType",
+    "MinOrderPolicy",
     "OutputFormat",
     # Config factory functions
     "weather_config",
@@ -211,16 +263,25 @@ __all__ = (
     "stock_prices",
     "options_chain",
     "finance",
+    "fix_messages",
+    "portfolio",
+    "var_cvar",
+    "yield_curve",
+    "credit_defaults",
     # Finance config
     "FinanceConfig",
     "StockConfig",
     "OhlcvConfig",
     "OptionsConfig",
+    "IntradayBarConfig",
+    "EventWindowConfig",
     "finance_config",
     # E-commerce generators
     "ecommerce_data",
     "ecommerce_sessions",
     "ecommerce_products",
+    "cohort_retention",
+    "ecommerce_interactions",
     # E-commerce config
     "EcommerceConfig",
     "SessionConfig",
@@ -228,5 +289,8 @@ __all__ = (
     "CatalogConfig",
     "RfmConfig",
     "FunnelConfig",
+    "InteractionConfig",
     "ecommerce_config",
+    # Subscription generators
+    "subscriptions",
 )
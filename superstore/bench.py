@@ -0,0 +1,86 @@
+"""Time generators across output formats for reproducible perf comparisons."""
+
+import time
+import tracemalloc
+from typing import Any, Callable, Sequence
+
+from .superstore import (
+    ecommerce_sessions as _ecommerce_sessions,
+    employees as _employees,
+    superstore as _superstore,
+    timeseries as _timeseries,
+    weather as _weather,
+)
+
+__all__ = ("bench",)
+
+
+# Dataset name -> generator called as (count, output, seed)
+_DATASETS: dict[str, Callable[[int, str, int | None], Any]] = {
+    "superstore": lambda count, output, seed: _superstore(count=count, output=output, seed=seed),
+    "employees": lambda count, output, seed: _employees(count=count, output=output, seed=seed),
+    "weather": lambda count, output, seed: _weather(count=count, output=output, seed=seed),
+    "ecommerce_sessions": lambda count, output, seed: _ecommerce_sessions(count, seed=seed, output=output),
+    "timeseries": lambda count, output, seed: _timeseries(nper=count, output=output, seed=seed),
+}
+
+
+def bench(
+    dataset: str,
+    count: int,
+    outputs: Sequence[str] = ("pandas", "polars", "arrow"),
+    seed: int | None = 42,
+    repeat: int = 3,
+) -> dict[str, dict[str, float]]:
+    """Time a generator for each output format.
+
+    Every output is generated from the same seed, so runs compare the same
+    rows. ``elapsed_s`` is the fastest of ``repeat`` untraced runs, which
+    keeps one-off costs such as importing the output library out of the
+    number. ``peak_memory_bytes`` comes from one extra run under
+    ``tracemalloc``: it counts memory allocated through Python's allocators
+    (including numpy-backed pandas columns), not the Rust rows or arrow
+    buffers, so it mostly reflects the cost of building the output.
+
+    Args:
+        dataset: Generator to run: "superstore", "employees", "weather",
+            "ecommerce_sessions", or "timeseries" (``count`` is ``nper``)
+        count: Number of rows to generate
+        outputs: Output formats to compare
+        seed: Random seed shared by every run
+        repeat: Timed runs per output (must be positive)
+
+    Returns:
+        A dict mapping each output to ``{"elapsed_s", "peak_memory_bytes"}``
+
+    Example:
+        >>> results = bench("superstore", 100_000, outputs=["pandas", "arrow"])
+        >>> results["arrow"]["elapsed_s"] < results["pandas"]["elapsed_s"]
+    """
+    if dataset not in _DATASETS:
+        raise ValueError(f"Unknown dataset '{dataset}'. Expected one of: {', '.join(_DATASETS)}")
+    if repeat < 1:
+        raise ValueError("repeat must be positive")
+    generate = _DATASETS[dataset]
+
+    results: dict[str, dict[str, float]] = {}
+    for output in outputs:
+        elapsed = []
+        for _ in range(repeat):
+            start = time.perf_counter()
+            generate(count, output, seed)
+            elapsed.append(time.perf_counter() - start)
+
+        tracing = tracemalloc.is_tracing()
+        if not tracing:
+            tracemalloc.start()
+        tracemalloc.reset_peak()
+        baseline = tracemalloc.get_traced_memory()[0]
+        data = generate(count, output, seed)
+        peak = tracemalloc.get_traced_memory()[1]
+        del data
+        if not tracing:
+            tracemalloc.stop()
+
+        results[output] = {"elapsed_s": min(elapsed), "peak_memory_bytes": peak - baseline}
+    return results
@@ -9,7 +9,7 @@ from __future__ import annotations
 from enum import Enum
 from typing import Literal
 
-from pydantic import BaseModel, Field
+from pydantic import BaseModel, Field, model_validator
 
 # =============================================================================
 # Enums for configuration options
@@ -65,6 +65,21 @@ class OutputFormat(str, Enum):
     PANDAS = "pandas"
     POLARS = "polars"
     DICT = "dict"
+    ARROW = "arrow"
+
+
+class SalesMode(str, Enum):
+    """How the superstore Sales and Profit columns relate to price and quantity."""
+
+    SYNTHETIC = "synthetic"  # Sales is the item price; profit is drawn independently
+    PRICE_TIMES_QUANTITY = "price_times_quantity"  # Sales is revenue; profit is a margin of it
+
+
+class MinOrderPolicy(str, Enum):
+    """How e-commerce baskets below the minimum order value are handled."""
+
+    BUMP = "bump"  # Top the basket up to the minimum; the session converts
+    DROP = "drop"  # Abandon at checkout; the session does not convert
 
 
 class LogFormat(str, Enum):
@@ -205,7 +220,17 @@ class PromotionalConfig(BaseModel):
         le=1.0,
         description="How much discounts increase quantity (correlation factor)",
     )
-    price_elasticity: float = Field(default=-0.8, ge=-2.0, le=0.0, description="Price elasticity of demand")
+    price_elasticity: float = Field(
+        default=-0.8,
+        ge=-2.0,
+        le=0.0,
+        description="Constant price elasticity of demand: quantity scales with (price / reference_price) ** price_elasticity",
+    )
+    reference_price: float | None = Field(
+        default=None,
+        gt=0.0,
+        description="Price (USD) at which demand is unscaled (None uses the midpoint of min_sales and max_sales)",
+    )
 
 
 class CustomerConfig(BaseModel):
@@ -227,6 +252,70 @@ class CustomerConfig(BaseModel):
     vip_order_multiplier: float = Field(default=2.0, ge=1.0, le=5.0, description="VIP customer order value multiplier")
 
 
+class ProductBundle(BaseModel):
+    """A named product bundle sold at a discount."""
+
+    name: str = Field(description="Bundle name (its first three letters appear in the Bundle ID)")
+    categories: list[str] = Field(default_factory=list, description="Categories the bundle combines")
+    discount_multiplier: float = Field(default=0.9, gt=0.0, le=1.0, description="Price multiplier for bundled items")
+
+
+class BundlingConfig(BaseModel):
+    """Configuration for product bundling."""
+
+    enable: bool = Field(default=False, description="Enable product bundles")
+    bundle_probability: float = Field(default=0.15, ge=0.0, le=1.0, description="Probability an order is part of a bundle")
+    bundles: list[ProductBundle] | None = Field(default=None, description="Bundle definitions (None uses the built-in bundles)")
+
+
+class RegionalConfig(BaseModel):
+    """Configuration for regional category preferences."""
+
+    enable: bool = Field(default=False, description="Enable regional category preferences")
+    preferences: dict[str, dict[str, float]] | None = Field(
+        default=None,
+        description="Category weights per region, e.g. {'West': {'Health Care': 2.0}} (None uses the built-in preferences)",
+    )
+
+
+class InventoryConfig(BaseModel):
+    """Configuration for inventory effects."""
+
+    enable: bool = Field(default=False, description="Enable stock status and backorders")
+    stock_out_probability: float = Field(default=0.05, ge=0.0, le=1.0, description="Probability an item is backordered")
+    backorder_delay_days: int = Field(default=7, ge=1, description="Maximum extra shipping delay for backordered items")
+    low_stock_threshold: float = Field(default=0.2, ge=0.0, le=1.0, description="Probability an item is low on stock")
+    low_stock_price_premium: float = Field(default=1.05, ge=1.0, le=2.0, description="Price multiplier for low-stock items")
+
+
+class PaymentConfig(BaseModel):
+    """Configuration for payment methods, fees, and fraud."""
+
+    enable: bool = Field(default=False, description="Enable payment method, processing fee, and settlement columns")
+    fraud_simulation: bool = Field(default=True, description="Flag fraudulent orders using per-method fraud rates")
+    card_numbers: bool = Field(default=False, description="Emit Luhn-valid test-range card numbers for card payments")
+    chargebacks: bool = Field(default=False, description="Generate chargeback events for fraudulent and disputed orders")
+    fraud_chargeback_rate: float = Field(
+        default=0.75, ge=0.0, le=1.0, description="Probability that a fraudulent order is charged back"
+    )
+    dispute_chargeback_rate: float = Field(
+        default=0.005, ge=0.0, le=1.0, description="Probability that a legitimate order is disputed and charged back"
+    )
+    fixed_fee: float = Field(default=0.0, ge=0.0, description="Fixed per-transaction fee added to the percentage fee")
+    min_fee: float = Field(default=0.0, ge=0.0, description="Minimum fee charged per transaction")
+
+
+class ReturnsConfig(BaseModel):
+    """Configuration for return and refund rows."""
+
+    enable: bool = Field(default=False, description="Append return rows referencing the original orders")
+    return_rate: float = Field(default=0.08, ge=0.0, le=1.0, description="Expected fraction of orders returned")
+    restocking_fee_rate: float = Field(
+        default=0.15, ge=0.0, le=1.0, description="Share of the sale kept on 'No Longer Needed' returns"
+    )
+    avg_days_to_return: float = Field(default=14.0, gt=0.0, description="Mean days between ship date and return date")
+
+
 class SuperstoreConfig(BaseModel):
     """Configuration for the superstore data generator.
 
@@ -239,11 +328,66 @@ class SuperstoreConfig(BaseModel):
     output: OutputFormat = Field(default=OutputFormat.DICT, description="Output format")
     seed: int | None = Field(default=None, description="Random seed for reproducibility")
     pool_size: int = Field(default=1000, ge=1, le=100000, description="Size of pre-generated data pools for performance")
+    regions: list[str] | None = Field(
+        default=None,
+        min_length=1,
+        description="Region labels for the Region column (None uses West, East, Central, South)",
+    )
+    categories: dict[str, list[str]] | None = Field(
+        default=None,
+        min_length=1,
+        description="Categories mapped to their sub-categories (None uses the US sectors and industries)",
+    )
+    taxonomy: dict[str, dict[str, list[str]]] | None = Field(
+        default=None,
+        min_length=1,
+        description="Categories mapped to sub-categories mapped to product types; adds a Product Type column (replaces categories)",
+    )
+    order_date_start: str | None = Field(
+        default=None,
+        description="First order date (YYYY-MM-DD). With neither bound set, orders fall in the current year",
+    )
+    order_date_end: str | None = Field(
+        default=None,
+        description="Last order date (YYYY-MM-DD); orders ship by this date",
+    )
+    locale: str = Field(
+        default="en_US",
+        description="Locale for Country, City, State and Postal Code (en_US, en_GB, de_DE, fr_FR, ja_JP; others fall back to en_US)",
+    )
+    currency: str | None = Field(
+        default=None,
+        description="Currency of the money columns (USD, EUR, GBP, JPY), converted from USD at fixed rates (None uses the locale's)",
+    )
+
+    # Value ranges
+    min_sales: int = Field(default=100, ge=0, description="Lower bound of the base sales draw (USD)")
+    max_sales: int = Field(default=10000, ge=0, description="Upper bound of the base sales draw (USD)")
+    min_quantity: int = Field(default=1, ge=1, description="Minimum quantity per row")
+    max_quantity: int = Field(default=100, ge=1, description="Maximum quantity per row")
+    max_discount_percent: float = Field(default=50.0, gt=0.0, le=100.0, description="Largest discount, in percent")
 
     # Correlation settings
-    sales_quantity_correlation: float = Field(default=0.8, ge=-1.0, le=1.0, description="Sales-quantity correlation")
-    sales_profit_correlation: float = Field(default=0.9, ge=-1.0, le=1.0, description="Sales-profit correlation")
-    discount_profit_correlation: float = Field(default=-0.6, ge=-1.0, le=1.0, description="Discount-profit correlation")
+    sales_quantity_correlation: float = Field(default=0.7, ge=-1.0, le=1.0, description="Sales-quantity correlation")
+    sales_profit_correlation: float = Field(default=0.6, ge=-1.0, le=1.0, description="Sales-profit correlation")
+    discount_profit_correlation: float = Field(default=-0.4, ge=-1.0, le=1.0, description="Discount-profit correlation")
+    sales_mode: SalesMode = Field(
+        default=SalesMode.SYNTHETIC,
+        description="'synthetic' (Sales ignores quantity) or 'price_times_quantity' (Sales = Item Price * Quantity)",
+    )
+    margin_bounds: tuple[float, float] | None = Field(
+        default=None,
+        description="Clamp each row's Profit / Sales to (min, max), e.g. (-0.2, 0.6) (None = unbounded)",
+    )
+    repair_correlations: bool = Field(
+        default=True,
+        description="Use the nearest valid correlation matrix when the correlations don't form one (False = sample independently)",
+    )
+    copula_df: float | None = Field(
+        default=None,
+        gt=0.0,
+        description="Degrees of freedom of a Student-t copula so sales, quantity, discount and profit extremes occur together (None = Gaussian)",
+    )
 
     # Pricing
     enable_price_points: bool = Field(default=True, description="Round prices to realistic $X.99 values")
@@ -252,6 +396,98 @@ class SuperstoreConfig(BaseModel):
     seasonality: SeasonalityConfig = Field(default_factory=SeasonalityConfig, description="Seasonal patterns")
     promotions: PromotionalConfig = Field(default_factory=PromotionalConfig, description="Promotional effects")
     customers: CustomerConfig = Field(default_factory=CustomerConfig, description="Customer behavior")
+    bundling: BundlingConfig = Field(default_factory=BundlingConfig, description="Product bundles")
+    regional: RegionalConfig = Field(default_factory=RegionalConfig, description="Regional category preferences")
+    inventory: InventoryConfig = Field(default_factory=InventoryConfig, description="Inventory effects")
+    payment: PaymentConfig = Field(default_factory=PaymentConfig, description="Payment methods and fraud")
+    returns: ReturnsConfig = Field(default_factory=ReturnsConfig, description="Return and refund rows")
+
+    # Identifiers
+    valid_identifiers: bool = Field(default=False, description="Only emit structurally valid SSNs and EINs (order IDs)")
+
+    # Reproducibility
+    stable_v1: bool = Field(default=False, description="Use the version-stable generation mode (seeded output frozen across releases)")
+    per_row_seeding: bool = Field(default=False, description="Seed each row independently so single rows can be generated with row_at()")
+
+    model_config = {"use_enum_values": True}
+
+    @model_validator(mode="after")
+    def _check_ranges(self) -> SuperstoreConfig:
+        if self.min_sales > self.max_sales:
+            raise ValueError("min_sales must not exceed max_sales")
+        if self.min_quantity > self.max_quantity:
+            raise ValueError("min_quantity must not exceed max_quantity")
+        if self.margin_bounds is not None and self.margin_bounds[0] > self.margin_bounds[1]:
+            raise ValueError("margin_bounds must have min <= max")
+        return self
+
+    @model_validator(mode="after")
+    def _check_sub_categories(self) -> SuperstoreConfig:
+        for category, sub_categories in (self.categories or {}).items():
+            if not sub_categories:
+                raise ValueError(f"category '{category}' has no sub-categories")
+        if self.taxonomy is not None:
+            if self.categories is not None:
+                raise ValueError("categories and taxonomy can't both be set")
+            for category, sub_categories in self.taxonomy.items():
+                if not sub_categories:
+                    raise ValueError(f"category '{category}' has no sub-categories")
+                for sub_category, product_types in sub_categories.items():
+                    if not product_types:
+                        raise ValueError(f"sub-category '{sub_category}' of '{category}' has no product types")
+        return self
+
+
+class Department(BaseModel):
+    """A department employees can belong to."""
+
+    name: str = Field(description="Department name")
+    job_titles: list[str] = Field(
+        default_factory=list,
+        description="Individual contributor titles; managers are titled '<name> Manager'",
+    )
+    salary_range: tuple[float, float] = Field(
+        description="Salary range (USD) holding about 90% of the department's log-normal salaries",
+    )
+
+    @model_validator(mode="after")
+    def _check_salary_range(self) -> Department:
+        low, high = self.salary_range
+        if not 0 < low <= high:
+            raise ValueError(f"department '{self.name}' needs a salary_range with 0 < low <= high")
+        return self
+
+
+class EmployeeConfig(BaseModel):
+    """Configuration for the employee data generator."""
+
+    count: int = Field(default=1000, ge=1, description="Number of employees to generate")
+    output: OutputFormat = Field(default=OutputFormat.DICT, description="Output format")
+    seed: int | None = Field(default=None, description="Random seed for reproducibility")
+    pool_size: int = Field(default=1000, ge=1, le=100000, description="Size of pre-generated data pools for performance")
+    valid_identifiers: bool = Field(default=False, description="Only emit structurally valid SSNs")
+    regions: list[str] | None = Field(
+        default=None,
+        min_length=1,
+        description="Region labels employees are assigned to (None uses the superstore regions)",
+    )
+    departments: list[Department] | None = Field(
+        default=None,
+        min_length=1,
+        description="Departments employees are spread across (None uses Engineering, Sales, Marketing, Finance, Human Resources, Operations and Support)",
+    )
+    manager_ratio: float = Field(
+        default=0.1,
+        ge=0.0,
+        le=1.0,
+        description="Fraction of employees who are managers; the rest report to a manager in their department",
+    )
+    attrition_rate: float = Field(
+        default=0.1,
+        ge=0.0,
+        le=1.0,
+        description="Fraction of employees who have left and have a termination date",
+    )
 
     model_config = {"use_enum_values": True}
 
@@ -276,6 +512,10 @@ class RegimeConfig(BaseModel):
         default_factory=lambda: [1.0, 2.5],
         description="Volatility multiplier for each regime",
     )
+    correlation_multipliers: list[float] = Field(
+        default_factory=list,
+        description="Cross-correlation multiplier for each regime (empty = constant correlation)",
+    )
 
 
 class JumpConfig(BaseModel):
@@ -287,6 +527,52 @@ class JumpConfig(BaseModel):
     jump_stddev: float = Field(default=0.05, ge=0.0, description="Standard deviation of jump size")
 
 
+class GarchConfig(BaseModel):
+    """Configuration for GARCH(1,1) volatility clustering."""
+
+    enable: bool = Field(default=False, description="Enable GARCH volatility clustering")
+    alpha: float = Field(default=0.1, ge=0.0, description="Weight on the previous squared return")
+    beta: float = Field(default=0.85, ge=0.0, description="Weight on the previous variance (persistence)")
+    omega: float = Field(default=0.05, gt=0.0, description="Long-run variance constant")
+
+    @model_validator(mode="after")
+    def _check_stationary(self) -> GarchConfig:
+        if self.enable and self.alpha + self.beta >= 1.0:
+            raise ValueError("garch alpha + beta must be less than 1")
+        return self
+
+
+class MeanReversionConfig(BaseModel):
+    """Configuration for an Ornstein-Uhlenbeck (mean-reverting) process."""
+
+    enable: bool = Field(default=False, description="Generate a mean-reverting series instead of the AR(1) process")
+    theta: float = Field(default=0.15, ge=0.0, description="Speed of mean reversion")
+    mu: float = Field(default=0.0, description="Long-run mean")
+    sigma: float = Field(default=0.2, ge=0.0, description="Volatility")
+
+
+class IntradayConfig(BaseModel):
+    """Configuration for a U-shaped intraday volatility pattern."""
+
+    enable: bool = Field(default=False, description="Enable the intraday volatility pattern")
+    opening_volatility_mult: float = Field(default=1.5, ge=0.0, description="Volatility multiplier at the open")
+    midday_volatility_mult: float = Field(default=0.7, ge=0.0, description="Volatility multiplier at midday")
+    closing_volatility_mult: float = Field(default=1.3, ge=0.0, description="Volatility multiplier at the close")
+    market_open: str = Field(default="09:30", pattern=r"^\d{2}:\d{2}$", description="Start of the trading window for intraday frequencies (HH:MM)")
+    market_close: str = Field(default="16:00", pattern=r"^\d{2}:\d{2}$", description="End of the trading window for intraday frequencies (HH:MM)")
+
+
+class EventWindowConfig(BaseModel):
+    """Configuration for event windows (abnormal returns around event dates)."""
+
+    enable: bool = Field(default=False, description="Enable event windows")
+    event_indices: list[int] = Field(default_factory=list, description="Period indices where events occur")
+    pre_event_window: int = Field(default=5, ge=0, description="Periods before each event")
+    post_event_window: int = Field(default=5, ge=0, description="Periods after each event")
+    abnormal_return_mean: float = Field(default=0.02, description="Mean of abnormal return at the event")
+    abnormal_return_stddev: float = Field(default=0.03, ge=0.0, description="Standard deviation of abnormal return")
+
+
 class TimeseriesConfig(BaseModel):
     """Configuration for the time series generator.
 
@@ -297,9 +583,19 @@ class TimeseriesConfig(BaseModel):
     # Basic parameters
     nper: int = Field(default=30, ge=1, description="Number of periods")
     ncol: int = Field(default=4, ge=1, le=26, description="Number of columns (max 26)")
-    freq: Literal["B", "D", "W", "M"] = Field(default="B", description="Frequency: B=business, D=daily, W=weekly, M=monthly")
+    freq: Literal["B", "D", "W", "M", "H", "T", "min"] = Field(
+        default="B",
+        description="Frequency: B=business, D=daily, W=weekly, M=monthly, H=hourly, T/min=minute (intraday bars fall inside the trading window)",
+    )
     output: OutputFormat = Field(default=OutputFormat.DICT, description="Output format")
     seed: int | None = Field(default=None, description="Random seed for reproducibility")
+    column_names: list[str] | None = Field(
+        default=None,
+        description="Custom column names (e.g. tickers) instead of A, B, C...; at most ncol entries, the rest keep their default label",
+    )
+    start_date: str | None = Field(default=None, description="First date of the index (YYYY-MM-DD), defaults to 2000-01-01")
+    holidays: list[str] = Field(default_factory=list, description="Dates (YYYY-MM-DD) skipped by business-day and intraday frequencies")
+    exchange: Literal["NYSE", "LSE"] | None = Field(default=None, description="Also skip this exchange's built-in holiday calendar")
 
     # Process parameters
     ar_phi: float = Field(
@@ -308,6 +604,11 @@ class TimeseriesConfig(BaseModel):
         le=1.0,
         description="AR(1) persistence parameter",
     )
+    ar_coefficients: list[float] | None = Field(
+        default=None,
+        min_length=1,
+        description="AR(p) coefficients [phi_1, ..., phi_p], must be stationary; overrides ar_phi when set",
+    )
     sigma: float = Field(default=1.0, ge=0.0, description="Innovation standard deviation")
     drift: float = Field(default=0.0, description="Drift/trend per period")
     cumulative: bool = Field(default=True, description="Apply cumulative sum (price-like behavior)")
@@ -323,10 +624,18 @@ class TimeseriesConfig(BaseModel):
         le=1.0,
         description="Correlation between columns (0 = independent)",
     )
+    correlation_matrix: list[list[float]] | None = Field(
+        default=None,
+        description="Pairwise correlations between columns (ncol x ncol); replaces cross_correlation when set",
+    )
 
     # Advanced features
     regimes: RegimeConfig = Field(default_factory=RegimeConfig, description="Regime switching configuration")
     jumps: JumpConfig = Field(default_factory=JumpConfig, description="Jump diffusion configuration")
+    garch: GarchConfig = Field(default_factory=GarchConfig, description="GARCH volatility clustering configuration")
+    mean_reversion: MeanReversionConfig = Field(default_factory=MeanReversionConfig, description="Mean reversion configuration")
+    intraday: IntradayConfig = Field(default_factory=IntradayConfig, description="Intraday volatility pattern configuration")
+    event_windows: EventWindowConfig = Field(default_factory=EventWindowConfig, description="Event window configuration")
 
     model_config = {"use_enum_values": True}
 
@@ -433,6 +742,103 @@ class ErrorBurstConfig(BaseModel):
     )
 
 
+class ClientConfig(BaseModel):
+    """Configuration for client IPs, geo, and bot traffic in access logs."""
+
+    ipv6_ratio: float = Field(
+        default=0.1,
+        ge=0.0,
+        le=1.0,
+        description="Share of regular client IPs that are IPv6",
+    )
+    bot_traffic_share: float = Field(
+        default=0.05,
+        ge=0.0,
+        le=1.0,
+        description="Share of requests from crawlers (bot IP ranges with crawler user agents)",
+    )
+    bot_ips_per_range: int = Field(
+        default=2,
+        ge=1,
+        description="Distinct IPs per bot range",
+    )
+    bot_client_error_rate: float = Field(
+        default=0.3,
+        ge=0.0,
+        le=1.0,
+        description="Probability a bot request gets a 4xx response",
+    )
+
+
+class TrafficConfig(BaseModel):
+    """Configuration for time-varying log volume (diurnal curve and spikes)."""
+
+    enable: bool = Field(default=False, description="Modulate the request rate over the day")
+    peak_hour: float = Field(
+        default=14.0,
+        ge=0.0,
+        le=24.0,
+        description="Hour of day with the most traffic",
+    )
+    peak_to_trough: float = Field(
+        default=5.0,
+        ge=1.0,
+        description="Ratio of the request rate at the peak hour to the quietest hour",
+    )
+    spikes_per_day: float = Field(
+        default=2.0,
+        ge=0.0,
+        description="Average number of traffic spikes per day",
+    )
+    spike_duration_seconds: int = Field(
+        default=600,
+        ge=1,
+        description="Duration of each traffic spike in seconds",
+    )
+    spike_multiplier: float = Field(
+        default=3.0,
+        ge=1.0,
+        description="Request rate multiplier during a spike",
+    )
+
+
+class DeployConfig(BaseModel):
+    """Configuration for deploy events and post-deploy error spikes in logs."""
+
+    enable: bool = Field(default=False, description="Enable the deploy sub-stream")
+    deploys_per_hour: float = Field(
+        default=2.0,
+        gt=0.0,
+        description="Average number of deploys per hour (Poisson arrivals)",
+    )
+    impact_duration_seconds: int = Field(
+        default=300,
+        ge=1,
+        description="Seconds after a deploy during which requests are marked post_deploy",
+    )
+    post_deploy_error_rate: float = Field(
+        default=0.3,
+        ge=0.0,
+        le=1.0,
+        description="Extra 5xx rate right after a deploy, decaying to zero over the impact window",
+    )
+
+
+class TraceConfig(BaseModel):
+    """Configuration for distributed trace shape in application logs."""
+
+    max_depth: int = Field(
+        default=3,
+        ge=1,
+        description="Maximum span depth, counting the root span",
+    )
+    max_children: int = Field(
+        default=3,
+        ge=0,
+        description="Maximum child spans per span",
+    )
+
+
 class LatencyConfig(BaseModel):
     """Configuration for request latency distribution."""
 
@@ -457,6 +863,16 @@ class LatencyConfig(BaseModel):
         ge=1.0,
         description="Multiplier for slow request latency",
     )
+    server_error_multiplier: float = Field(
+        default=5.0,
+        gt=0.0,
+        description="Latency multiplier for 5xx responses",
+    )
+    not_modified_multiplier: float = Field(
+        default=0.2,
+        gt=0.0,
+        description="Latency multiplier for 304 Not Modified responses",
+    )
 
 
 class LogsConfig(BaseModel):
@@ -470,7 +886,7 @@ class LogsConfig(BaseModel):
     count: int = Field(default=1000, ge=1, description="Number of log entries to generate")
     output: OutputFormat = Field(
         default=OutputFormat.DICT,
-        description="Output format (pandas, polars, or dict)",
+        description="Output format (pandas, polars, dict, or arrow)",
     )
     seed: int | None = Field(
         default=None,
@@ -489,7 +905,11 @@ class LogsConfig(BaseModel):
     requests_per_second: float = Field(
         default=100.0,
         ge=0.1,
-        description="Average requests per second (Poisson rate)",
+        description="Average requests per second (Poisson rate); the peak-hour rate when traffic is enabled",
+    )
+    traffic: TrafficConfig = Field(
+        default_factory=TrafficConfig,
+        description="Diurnal volume curve and traffic spikes",
     )
 
     # Status code distribution
@@ -506,6 +926,18 @@ class LogsConfig(BaseModel):
         description="Error burst configuration",
     )
 
+    # Deploys
+    deploys: DeployConfig = Field(
+        default_factory=DeployConfig,
+        description="Deploy events and post-deploy error spikes",
+    )
+
+    # Traces (application logs)
+    traces: TraceConfig = Field(
+        default_factory=TraceConfig,
+        description="Span tree shape for application log traces",
+    )
+
     # Latency
     latency: LatencyConfig = Field(
         default_factory=LatencyConfig,
@@ -533,6 +965,21 @@ class LogsConfig(BaseModel):
         le=1.0,
         description="Ratio of API paths vs static paths",
     )
+    endpoint_zipf_exponent: float = Field(
+        default=1.0,
+        ge=0.0,
+        description="Zipf exponent of endpoint popularity (0 = uniform)",
+    )
+    not_modified_rate: float = Field(
+        default=0.3,
+        ge=0.0,
+        le=1.0,
+        description="Share of successful static-asset GETs answered with 304 Not Modified",
+    )
+    clients: ClientConfig = Field(
+        default_factory=ClientConfig,
+        description="Client IPv4/IPv6 mix, geo, and bot traffic",
+    )
 
     model_config = {"use_enum_values": True}
 
@@ -628,6 +1075,36 @@ class OptionsConfig(BaseModel):
     )
 
 
+class IntradayBarConfig(BaseModel):
+    """Configuration for intraday (minute) bar generation."""
+
+    enable: bool = Field(
+        default=False,
+        description="Emit intraday bars over a 09:30-16:00 session instead of daily bars",
+    )
+    bar_minutes: int = Field(
+        default=1,
+        ge=1,
+        le=390,
+        description="Bar length in minutes",
+    )
+    opening_volatility_mult: float = Field(
+        default=1.5,
+        ge=0.0,
+        description="Volatility/volume multiplier at the open",
+    )
+    midday_volatility_mult: float = Field(
+        default=0.7,
+        ge=0.0,
+        description="Volatility/volume multiplier at midday (lowest)",
+    )
+    closing_volatility_mult: float = Field(
+        default=1.3,
+        ge=0.0,
+        description="Volatility/volume multiplier at the close",
+    )
+
+
 class FinanceConfig(BaseModel):
     """Configuration for the finance data generator.
 
@@ -648,7 +1125,7 @@ class FinanceConfig(BaseModel):
     )
     output: OutputFormat = Field(
         default=OutputFormat.DICT,
-        description="Output format (pandas, polars, or dict)",
+        description="Output format (pandas, polars, dict, or arrow)",
     )
     seed: int | None = Field(
         default=None,
@@ -682,6 +1159,18 @@ class FinanceConfig(BaseModel):
         default_factory=OptionsConfig,
         description="Options chain configuration",
     )
+    intraday: IntradayBarConfig = Field(
+        default_factory=IntradayBarConfig,
+        description="Intraday bar configuration",
+    )
+    events: EventWindowConfig = Field(
+        default_factory=EventWindowConfig,
+        description="Event windows with abnormal returns and elevated volatility",
+    )
+    earnings_dates: list[str] = Field(
+        default_factory=list,
+        description="Earnings dates (YYYY-MM-DD); each adds an event window and enables events",
+    )
 
     model_config = {"use_enum_values": True}
 
@@ -732,6 +1221,30 @@ class SessionConfig(BaseModel):
         le=1.0,
         description="Bounce rate (probability of immediate exit)",
     )
+    custom_states: list[str] | None = Field(
+        default=None,
+        description="Custom navigation states, entry state first (replaces the built-in retail states)",
+    )
+    custom_transition_matrix: list[list[float]] | None = Field(
+        default=None,
+        description="Row-stochastic transition matrix over custom_states",
+    )
+    purchase_state: str = Field(default="purchase", description="State that ends a session as converted")
+    exit_state: str = Field(default="exit", description="State that ends a session without converting")
+    target_conversion_rate: float | None = Field(
+        default=None,
+        gt=0.0,
+        lt=1.0,
+        description="Overall share of sessions that convert; rescales exits so the compounded funnel hits this rate",
+    )
+    landing_page_weights: dict[str, float] = Field(
+        default_factory=dict,
+        description="Landing page selection weights, overriding the defaults for the pages listed",
+    )
+    landing_page_conversion: dict[str, float] = Field(
+        default_factory=dict,
+        description="Relative conversion multipliers per landing page, overriding the defaults for the pages listed",
+    )
 
 
 class CartConfig(BaseModel):
@@ -806,6 +1319,20 @@ class CatalogConfig(BaseModel):
         ],
         description="Product categories",
     )
+    brands: dict[str, list[str]] | None = Field(
+        default=None,
+        description="Brand pool per category, most popular first (overrides the built-in pools)",
+    )
+    brand_zipf_exponent: float = Field(
+        default=1.1,
+        ge=0.0,
+        description="Zipf exponent for brand popularity within a category (0 = uniform)",
+    )
+    variants_per_product: int = Field(
+        default=1,
+        ge=1,
+        description="SKUs per base product, differing by size/color (1 = no variants)",
+    )
 
 
 class RfmConfig(BaseModel):
@@ -860,6 +1387,16 @@ class FunnelConfig(BaseModel):
     )
 
 
+class InteractionConfig(BaseModel):
+    """Configuration for the user-product interaction graph."""
+
+    popularity_exponent: float = Field(
+        default=1.0,
+        ge=0.0,
+        description="Zipf exponent of product popularity (0 = uniform)",
+    )
+
+
 class EcommerceConfig(BaseModel):
     """Configuration for e-commerce data generation.
 
@@ -914,6 +1451,23 @@ class EcommerceConfig(BaseModel):
         default_factory=FunnelConfig,
         description="Conversion funnel configuration",
     )
+    interactions: InteractionConfig = Field(
+        default_factory=InteractionConfig,
+        description="Interaction graph configuration",
+    )
+    locale: str = Field(
+        default="en_US",
+        description="Market locale (en_US, en_GB, de_DE, fr_FR, ja_JP); sets currency, tax rate, email domains and product naming",
+    )
+    min_order_value: float | None = Field(
+        default=None,
+        ge=0.0,
+        description="Minimum order subtotal in the market currency",
+    )
+    min_order_policy: MinOrderPolicy = Field(
+        default=MinOrderPolicy.BUMP,
+        description="Whether baskets below min_order_value are topped up to it (bump) or abandoned at checkout (drop)",
+    )
 
     model_config = {"use_enum_values": True}
 
@@ -0,0 +1,101 @@
+"""Replay generated datasets as a time-paced event stream."""
+
+import math
+import time
+from datetime import datetime
+from typing import Any, Callable, Iterator
+
+from .superstore import app_logs as _app_logs, logs as _logs, weather as _weather
+
+__all__ = ("replay",)
+
+
+def _config_dict(config: Any) -> dict[str, Any]:
+    if config is None:
+        return {}
+    if hasattr(config, "model_dump"):
+        return config.model_dump(mode="json")
+    if isinstance(config, dict):
+        return dict(config)
+    raise TypeError("config must be a pydantic config, dict, or None")
+
+
+def _generate_logs(config: Any) -> Any:
+    return _logs({**_config_dict(config), "output": "dict"})
+
+
+def _generate_app_logs(config: Any) -> Any:
+    return _app_logs({**_config_dict(config), "output": "dict"})
+
+
+def _generate_weather(config: Any) -> Any:
+    return _weather(config, output="dict")
+
+
+# Dataset name -> (generator, timestamp column)
+_DATASETS: dict[str, tuple[Callable[[Any], Any], str]] = {
+    "logs": (_generate_logs, "timestamp"),
+    "app_logs": (_generate_app_logs, "timestamp"),
+    "weather": (_generate_weather, "timestamp"),
+}
+
+
+def _to_rows(data: dict[str, list[Any]] | list[dict[str, Any]]) -> list[dict[str, Any]]:
+    if isinstance(data, dict):
+        columns = list(data)
+        return [dict(zip(columns, values)) for values in zip(*(data[c] for c in columns))]
+    return list(data)
+
+
+def _parse_timestamp(value: Any) -> datetime:
+    if isinstance(value, datetime):
+        return value
+    text = str(value)
+    if text.endswith("Z"):
+        text = text[:-1]
+    return datetime.fromisoformat(text)
+
+
+def replay(dataset: str, speed: float = 1.0, config: Any | None = None) -> Iterator[dict[str, Any]]:
+    """Yield the rows of a generated dataset paced to their timestamps.
+
+    Rows are emitted in timestamp order. Each row is released once
+    ``(row_time - first_row_time) / speed`` seconds of wall-clock time have
+    passed since the first row, so ``speed=1`` replays in real time,
+    ``speed=60`` plays a minute per second, and ``speed=math.inf`` yields
+    everything immediately.
+
+    Args:
+        dataset: Generator to replay: "logs", "app_logs", or "weather"
+        speed: Playback rate relative to real time (must be positive)
+        config: Generator config (pydantic model or dict); output is forced to dicts
+
+    Returns:
+        An iterator of row dicts
+
+    Example:
+        >>> for row in replay("logs", speed=100, config={"count": 1000}):
+        ...     dashboard.push(row)
+    """
+    if dataset not in _DATASETS:
+        raise ValueError(f"Unknown dataset '{dataset}'. Must be one of: {', '.join(_DATASETS)}")
+    if not speed > 0:
+        raise ValueError("speed must be positive")
+
+    generate, timestamp_column = _DATASETS[dataset]
+    rows = [(_parse_timestamp(row[timestamp_column]), row) for row in _to_rows(generate(config))]
+    rows.sort(key=lambda item: item[0])
+    return _paced(rows, speed)
+
+
+def _paced(rows: list[tuple[datetime, dict[str, Any]]], speed: float) -> Iterator[dict[str, Any]]:
+    if not rows:
+        return
+    first = rows[0][0]
+    start = time.monotonic()
+    for timestamp, row in rows:
+        if not math.isinf(speed):
+            delay = (timestamp - first).total_seconds() / speed - (time.monotonic() - start)
+            if delay > 0:
+                time.sleep(delay)
+        yield row
@@ -1,11 +1,21 @@
 """Type stubs for superstore - a library for realistic data generation."""
 
+from collections.abc import Callable, Sequence
 from typing import Any, Literal, final, overload
 
 import pandas as pd
 import polars as pl
-
-from .config import CrossfilterConfig, SuperstoreConfig, TimeseriesConfig
+import pyarrow as pa
+
+from .config import (
+    CrossfilterConfig,
+    EcommerceConfig,
+    EmployeeConfig,
+    FinanceConfig,
+    LogsConfig,
+    SuperstoreConfig,
+    TimeseriesConfig,
+)
 
 # =============================================================================
 # Schema constants
@@ -29,6 +39,8 @@ def superstore(
     count: int | None = ...,
     output: Literal["pandas"] | None = ...,
     seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def superstore(
@@ -37,6 +49,8 @@ def superstore(
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def superstore(
@@ -45,7 +59,19 @@ def superstore(
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
 ) -> list[dict[str, Any]]: ...
+@overload
+def superstore(
+    config: SuperstoreConfig,
+    count: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
+) -> pa.Table: ...
 
 # superstore() without config (backward compatible)
 @overload
@@ -54,6 +80,8 @@ def superstore(
     count: int | None = ...,
     output: Literal["pandas"] | None = ...,
     seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def superstore(
@@ -62,6 +90,8 @@ def superstore(
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def superstore(
@@ -70,27 +100,186 @@ def superstore(
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
 ) -> list[dict[str, Any]]: ...
 @overload
+def superstore(
+    config: None = ...,
+    count: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    verbose: Literal[False] | None = ...,
+) -> pa.Table: ...
+
+# superstore(verbose=True) also returns generation diagnostics
+@overload
+def superstore(
+    config: SuperstoreConfig | None = ...,
+    count: int | None = ...,
+    output: Literal["pandas", "polars", "dict", "arrow"] | None = ...,
+    seed: int | None = ...,
+    stable_v1: bool | None = ...,
+    *,
+    verbose: Literal[True],
+) -> tuple[pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table, dict[str, Any]]: ...
+@overload
 def employees(
-    count: int = ...,
+    count: int | None = ...,
     output: Literal["pandas"] = ...,
     seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+    valid_identifiers: bool | None = ...,
+    config: EmployeeConfig | dict[str, Any] | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def employees(
-    count: int = ...,
+    count: int | None = ...,
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+    valid_identifiers: bool | None = ...,
+    config: EmployeeConfig | dict[str, Any] | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def employees(
-    count: int = ...,
+    count: int | None = ...,
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+    valid_identifiers: bool | None = ...,
+    config: EmployeeConfig | dict[str, Any] | None = ...,
 ) -> list[dict[str, Any]]: ...
+@overload
+def employees(
+    count: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+    valid_identifiers: bool | None = ...,
+    config: EmployeeConfig | dict[str, Any] | None = ...,
+) -> pa.Table: ...
+@overload
+def with_employees(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    employee_count: int | None = ...,
+    output: Literal["pandas"] | None = ...,
+    seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+) -> tuple[pd.DataFrame, pd.DataFrame]: ...
+@overload
+def with_employees(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    employee_count: int | None = ...,
+    *,
+    output: Literal["polars"],
+    seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+) -> tuple[pl.DataFrame, pl.DataFrame]: ...
+@overload
+def with_employees(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    employee_count: int | None = ...,
+    *,
+    output: Literal["dict"],
+    seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+) -> tuple[list[dict[str, Any]], list[dict[str, Any]]]: ...
+@overload
+def with_employees(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    employee_count: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    mask_pii: bool | list[Literal["ssn", "email", "phone_number"]] | None = ...,
+) -> tuple[pa.Table, pa.Table]: ...
+@overload
+def with_chargebacks(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    output: Literal["pandas"] | None = ...,
+    seed: int | None = ...,
+) -> tuple[pd.DataFrame, pd.DataFrame]: ...
+@overload
+def with_chargebacks(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    *,
+    output: Literal["polars"],
+    seed: int | None = ...,
+) -> tuple[pl.DataFrame, pl.DataFrame]: ...
+@overload
+def with_chargebacks(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    *,
+    output: Literal["dict"],
+    seed: int | None = ...,
+) -> tuple[list[dict[str, Any]], list[dict[str, Any]]]: ...
+@overload
+def with_chargebacks(
+    count: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+) -> tuple[pa.Table, pa.Table]: ...
+@overload
+def batch(
+    count: int | None = ...,
+    n_datasets: int = ...,
+    base_seed: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    output: Literal["pandas"] | None = ...,
+) -> list[pd.DataFrame]: ...
+@overload
+def batch(
+    count: int | None = ...,
+    n_datasets: int = ...,
+    base_seed: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    *,
+    output: Literal["polars"],
+) -> list[pl.DataFrame]: ...
+@overload
+def batch(
+    count: int | None = ...,
+    n_datasets: int = ...,
+    base_seed: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    *,
+    output: Literal["dict"],
+) -> list[list[dict[str, Any]]]: ...
+@overload
+def batch(
+    count: int | None = ...,
+    n_datasets: int = ...,
+    base_seed: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> list[pa.Table]: ...
+def row_at(
+    index: int,
+    seed: int | None = ...,
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+) -> dict[str, Any]: ...
+def effective_config(
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+) -> dict[str, Any]: ...
+def validate_config(
+    config: SuperstoreConfig | dict[str, Any] | int | None = ...,
+) -> list[str]: ...
 
 # =============================================================================
 # Time series generators
@@ -105,6 +294,7 @@ def timeseries(
     ncol: int | None = ...,
     output: Literal["pandas"] | None = ...,
     seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def timeseries(
@@ -115,6 +305,7 @@ def timeseries(
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def timeseries(
@@ -125,7 +316,30 @@ def timeseries(
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
 ) -> dict[str, Any]: ...
+@overload
+def timeseries(
+    config: TimeseriesConfig,
+    nper: int | None = ...,
+    freq: str | None = ...,
+    ncol: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
+) -> pa.Table: ...
+@overload
+def timeseries(
+    config: TimeseriesConfig,
+    nper: int | None = ...,
+    freq: str | None = ...,
+    ncol: int | None = ...,
+    *,
+    output: Literal["with_corr"],
+    seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
+) -> tuple[Any, Any]: ...
 
 # timeseries() with int (backward compatible) or None
 @overload
@@ -136,6 +350,7 @@ def timeseries(
     ncol: int | None = ...,
     output: Literal["pandas"] | None = ...,
     seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def timeseries(
@@ -146,6 +361,7 @@ def timeseries(
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def timeseries(
@@ -156,8 +372,42 @@ def timeseries(
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
 ) -> dict[str, Any]: ...
 @overload
+def timeseries(
+    config: int | None = ...,
+    nper: int | None = ...,
+    freq: str | None = ...,
+    ncol: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
+) -> pa.Table: ...
+@overload
+def timeseries(
+    config: int | None = ...,
+    nper: int | None = ...,
+    freq: str | None = ...,
+    ncol: int | None = ...,
+    *,
+    output: Literal["with_corr"],
+    seed: int | None = ...,
+    include_metrics: Literal[False] | None = ...,
+) -> tuple[Any, Any]: ...
+@overload
+def timeseries(
+    config: TimeseriesConfig | int | None = ...,
+    nper: int | None = ...,
+    freq: str | None = ...,
+    ncol: int | None = ...,
+    output: str | None = ...,
+    seed: int | None = ...,
+    *,
+    include_metrics: Literal[True],
+) -> tuple[Any, ...]: ...
+@overload
 def timeseriesData(
     nper: int = ...,
     freq: str = ...,
@@ -183,6 +433,21 @@ def timeseriesData(
     output: Literal["dict"],
     seed: int | None = ...,
 ) -> dict[str, dict[str, Any]]: ...
+@overload
+def timeseriesData(
+    nper: int = ...,
+    freq: str = ...,
+    ncol: int = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+) -> dict[str, pa.Table]: ...
+@overload
+def indicators(data: pd.DataFrame, indicators: list[str] | None = ...) -> pd.DataFrame: ...
+@overload
+def indicators(data: pl.DataFrame, indicators: list[str] | None = ...) -> pl.DataFrame: ...
+@overload
+def indicators(data: dict[str, Any], indicators: list[str] | None = ...) -> dict[str, Any]: ...
 
 # =============================================================================
 # Crossfilter generators
@@ -236,17 +501,28 @@ def weather(
     output: Literal["dict"],
     seed: int | None = ...,
 ) -> list[dict[str, Any]]: ...
+@overload
+def weather(
+    config: Any | None = ...,
+    count: int | None = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+) -> pa.Table: ...
 
 # =============================================================================
 # Logs generators
 # =============================================================================
 
 def logs(
-    config: dict[str, Any] | None = ...,
-) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]]: ...
+    config: LogsConfig | dict[str, Any] | None = ...,
+) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
+def logs_with_deploys(
+    config: LogsConfig | dict[str, Any] | None = ...,
+) -> dict[str, Any]: ...
 def app_logs(
-    config: dict[str, Any] | None = ...,
-) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]]: ...
+    config: LogsConfig | dict[str, Any] | None = ...,
+) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
 
 # =============================================================================
 # E-commerce generators
@@ -273,6 +549,13 @@ def ecommerce_sessions(
     output: Literal["dict"],
 ) -> dict[str, Any]: ...
 @overload
+def ecommerce_sessions(
+    count: int,
+    seed: int | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+@overload
 def ecommerce_products(
     count: int,
     seed: int | None = ...,
@@ -293,47 +576,324 @@ def ecommerce_products(
     output: Literal["dict"],
 ) -> dict[str, Any]: ...
 @overload
+def ecommerce_products(
+    count: int,
+    seed: int | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+@overload
 def ecommerce_data(
-    config: dict[str, Any] | None = ...,
+    config: EcommerceConfig | dict[str, Any] | None = ...,
     output: Literal["pandas"] = ...,
 ) -> dict[str, pd.DataFrame]: ...
 @overload
 def ecommerce_data(
-    config: dict[str, Any] | None = ...,
+    config: EcommerceConfig | dict[str, Any] | None = ...,
     *,
     output: Literal["polars"],
 ) -> dict[str, pl.DataFrame]: ...
 @overload
 def ecommerce_data(
-    config: dict[str, Any] | None = ...,
+    config: EcommerceConfig | dict[str, Any] | None = ...,
     *,
     output: Literal["dict"],
 ) -> dict[str, Any]: ...
+@overload
+def ecommerce_data(
+    config: EcommerceConfig | dict[str, Any] | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> dict[str, pa.Table]: ...
+
+@overload
+def cohort_retention(
+    data: pd.DataFrame | pl.DataFrame | dict[str, Any],
+    period: Literal["day", "week", "month"] = ...,
+    output: Literal["pandas"] = ...,
+) -> pd.DataFrame: ...
+@overload
+def cohort_retention(
+    data: pd.DataFrame | pl.DataFrame | dict[str, Any],
+    period: Literal["day", "week", "month"] = ...,
+    *,
+    output: Literal["polars"],
+) -> pl.DataFrame: ...
+@overload
+def cohort_retention(
+    data: pd.DataFrame | pl.DataFrame | dict[str, Any],
+    period: Literal["day", "week", "month"] = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, Any]: ...
+@overload
+def cohort_retention(
+    data: pd.DataFrame | pl.DataFrame | dict[str, Any],
+    period: Literal["day", "week", "month"] = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+@overload
+def ecommerce_interactions(
+    config: EcommerceConfig | dict[str, Any] | None = ...,
+    output: Literal["pandas"] = ...,
+) -> pd.DataFrame: ...
+@overload
+def ecommerce_interactions(
+    config: EcommerceConfig | dict[str, Any] | None = ...,
+    *,
+    output: Literal["polars"],
+) -> pl.DataFrame: ...
+@overload
+def ecommerce_interactions(
+    config: EcommerceConfig | dict[str, Any] | None = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, Any]: ...
+@overload
+def ecommerce_interactions(
+    config: EcommerceConfig | dict[str, Any] | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+@overload
+def ecommerce_interactions(
+    config: EcommerceConfig | dict[str, Any] | None = ...,
+    *,
+    output: Literal["edgelist"],
+) -> list[tuple[str, str, int]]: ...
+
+# =============================================================================
+# Subscription generators
+# =============================================================================
+
+@overload
+def subscriptions(
+    count: int | None = ...,
+    seed: int | None = ...,
+    config: dict[str, Any] | None = ...,
+    output: Literal["pandas"] = ...,
+) -> dict[str, pd.DataFrame]: ...
+@overload
+def subscriptions(
+    count: int | None = ...,
+    seed: int | None = ...,
+    config: dict[str, Any] | None = ...,
+    *,
+    output: Literal["polars"],
+) -> dict[str, pl.DataFrame]: ...
+@overload
+def subscriptions(
+    count: int | None = ...,
+    seed: int | None = ...,
+    config: dict[str, Any] | None = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, dict[str, Any]]: ...
+@overload
+def subscriptions(
+    count: int | None = ...,
+    seed: int | None = ...,
+    config: dict[str, Any] | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> dict[str, pa.Table]: ...
 
 # =============================================================================
 # Finance generators
 # =============================================================================
 
 def stock_prices(
-    config: dict[str, Any] | None = ...,
-) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]]: ...
+    config: FinanceConfig | dict[str, Any] | None = ...,
+) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
 def options_chain(
-    config: dict[str, Any] | None = ...,
+    config: FinanceConfig | dict[str, Any] | None = ...,
     spot_price: float | None = ...,
     date: str | None = ...,
-) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]]: ...
+) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
 def finance(
-    config: dict[str, Any] | None = ...,
-) -> tuple[pd.DataFrame, pd.DataFrame]: ...
+    config: FinanceConfig | dict[str, Any] | None = ...,
+) -> (
+    tuple[pd.DataFrame, pd.DataFrame]
+    | tuple[pl.DataFrame, pl.DataFrame]
+    | tuple[dict[str, list[Any]], dict[str, list[Any]]]
+    | tuple[pa.Table, pa.Table]
+): ...
+@overload
+def fix_messages(
+    n: int = ...,
+    seed: int | None = ...,
+    output: Literal["pandas"] = ...,
+) -> pd.DataFrame: ...
+@overload
+def fix_messages(
+    n: int = ...,
+    seed: int | None = ...,
+    *,
+    output: Literal["polars"],
+) -> pl.DataFrame: ...
+@overload
+def fix_messages(
+    n: int = ...,
+    seed: int | None = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, list[Any]]: ...
+@overload
+def fix_messages(
+    n: int = ...,
+    seed: int | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+@overload
+def portfolio(
+    prices: pd.DataFrame | pl.DataFrame | dict[str, list[Any]],
+    weights: list[float],
+    rebalance: Literal["none", "daily"] = ...,
+    initial_value: float | None = ...,
+    output: Literal["pandas"] = ...,
+) -> pd.DataFrame: ...
+@overload
+def portfolio(
+    prices: pd.DataFrame | pl.DataFrame | dict[str, list[Any]],
+    weights: list[float],
+    rebalance: Literal["none", "daily"] = ...,
+    initial_value: float | None = ...,
+    *,
+    output: Literal["polars"],
+) -> pl.DataFrame: ...
+@overload
+def portfolio(
+    prices: pd.DataFrame | pl.DataFrame | dict[str, list[Any]],
+    weights: list[float],
+    rebalance: Literal["none", "daily"] = ...,
+    initial_value: float | None = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, list[Any]]: ...
+@overload
+def portfolio(
+    prices: pd.DataFrame | pl.DataFrame | dict[str, list[Any]],
+    weights: list[float],
+    rebalance: Literal["none", "daily"] = ...,
+    initial_value: float | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+def var_cvar(
+    returns: Sequence[float],
+    alpha: float = ...,
+    method: Literal["historical", "gaussian"] = ...,
+) -> dict[str, float]: ...
+@overload
+def yield_curve(
+    tenors: Sequence[float] | None = ...,
+    seed: int | None = ...,
+    level: float = ...,
+    slope: float = ...,
+    curvature: float = ...,
+    tau: float = ...,
+    ndays: int = ...,
+    start_date: str | None = ...,
+    factor_volatility: float = ...,
+    mean_reversion: float = ...,
+    output: Literal["pandas"] = ...,
+) -> pd.DataFrame: ...
+@overload
+def yield_curve(
+    tenors: Sequence[float] | None = ...,
+    seed: int | None = ...,
+    level: float = ...,
+    slope: float = ...,
+    curvature: float = ...,
+    tau: float = ...,
+    ndays: int = ...,
+    start_date: str | None = ...,
+    factor_volatility: float = ...,
+    mean_reversion: float = ...,
+    *,
+    output: Literal["polars"],
+) -> pl.DataFrame: ...
+@overload
+def yield_curve(
+    tenors: Sequence[float] | None = ...,
+    seed: int | None = ...,
+    level: float = ...,
+    slope: float = ...,
+    curvature: float = ...,
+    tau: float = ...,
+    ndays: int = ...,
+    start_date: str | None = ...,
+    factor_volatility: float = ...,
+    mean_reversion: float = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, list[Any]]: ...
+@overload
+def yield_curve(
+    tenors: Sequence[float] | None = ...,
+    seed: int | None = ...,
+    level: float = ...,
+    slope: float = ...,
+    curvature: float = ...,
+    tau: float = ...,
+    ndays: int = ...,
+    start_date: str | None = ...,
+    factor_volatility: float = ...,
+    mean_reversion: float = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
+
+@overload
+def credit_defaults(
+    n_obligors: int = ...,
+    horizon_years: int = ...,
+    asset_correlation: float = ...,
+    default_probabilities: dict[str, float] | None = ...,
+    seed: int | None = ...,
+    output: Literal["pandas"] = ...,
+) -> pd.DataFrame: ...
+@overload
+def credit_defaults(
+    n_obligors: int = ...,
+    horizon_years: int = ...,
+    asset_correlation: float = ...,
+    default_probabilities: dict[str, float] | None = ...,
+    seed: int | None = ...,
+    *,
+    output: Literal["polars"],
+) -> pl.DataFrame: ...
+@overload
+def credit_defaults(
+    n_obligors: int = ...,
+    horizon_years: int = ...,
+    asset_correlation: float = ...,
+    default_probabilities: dict[str, float] | None = ...,
+    seed: int | None = ...,
+    *,
+    output: Literal["dict"],
+) -> dict[str, list[Any]]: ...
+@overload
+def credit_defaults(
+    n_obligors: int = ...,
+    horizon_years: int = ...,
+    asset_correlation: float = ...,
+    default_probabilities: dict[str, float] | None = ...,
+    seed: int | None = ...,
+    *,
+    output: Literal["arrow"],
+) -> pa.Table: ...
 
 # =============================================================================
 # Telemetry generators
 # =============================================================================
 
 def telemetry(
-    config: dict[str, Any] | None = ...,
+    config: CrossfilterConfig | dict[str, Any] | None = ...,
     scenario: str | None = ...,
-) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]]: ...
+) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
 
 # =============================================================================
 # Streaming generators
@@ -357,13 +917,56 @@ def superstoreStream(
     total_count: int,
     chunk_size: int = ...,
     seed: int | None = ...,
+    partitions: int | None = ...,
+    partition_key: str | Callable[[dict[str, Any]], Any] | None = ...,
+    start_offset: int = ...,
+    pool_size: int = ...,
 ) -> SuperstoreStream: ...
 def employeesStream(
     total_count: int,
     chunk_size: int = ...,
     seed: int | None = ...,
+    partitions: int | None = ...,
+    partition_key: str | Callable[[dict[str, Any]], Any] | None = ...,
+    start_offset: int = ...,
+    pool_size: int = ...,
 ) -> EmployeeStream: ...
 
+@final
+class SuperstoreChunks:
+    """Iterator yielding one superstore table per chunk."""
+
+    def __iter__(self) -> SuperstoreChunks: ...
+    def __next__(self) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
+
+@final
+class EmployeeChunks:
+    """Iterator yielding one employee table per chunk."""
+
+    def __iter__(self) -> EmployeeChunks: ...
+    def __next__(self) -> pd.DataFrame | pl.DataFrame | list[dict[str, Any]] | pa.Table: ...
+
+def superstore_stream(
+    total_count: int,
+    chunk_size: int = ...,
+    seed: int | None = ...,
+    output: Literal["pandas", "polars", "dict", "arrow"] = ...,
+    config: SuperstoreConfig | dict[str, Any] | None = ...,
+) -> SuperstoreChunks: ...
+def employees_stream(
+    total_count: int,
+    chunk_size: int = ...,
+    seed: int | None = ...,
+    output: Literal["pandas", "polars", "dict", "arrow"] = ...,
+) -> EmployeeChunks: ...
+def stream_quantiles(
+    count: int,
+    chunk_size: int = ...,
+    column: str = ...,
+    quantiles: Sequence[float] = ...,
+    seed: int | None = ...,
+) -> list[float]: ...
+
 # =============================================================================
 # Parallel generators
 # =============================================================================
@@ -373,6 +976,7 @@ def superstoreParallel(
     count: int = ...,
     output: Literal["pandas"] = ...,
     seed: int | None = ...,
+    num_threads: int | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def superstoreParallel(
@@ -380,6 +984,7 @@ def superstoreParallel(
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    num_threads: int | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def superstoreParallel(
@@ -387,12 +992,22 @@ def superstoreParallel(
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    num_threads: int | None = ...,
 ) -> list[dict[str, Any]]: ...
 @overload
+def superstoreParallel(
+    count: int = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    num_threads: int | None = ...,
+) -> pa.Table: ...
+@overload
 def employeesParallel(
     count: int = ...,
     output: Literal["pandas"] = ...,
     seed: int | None = ...,
+    num_threads: int | None = ...,
 ) -> pd.DataFrame: ...
 @overload
 def employeesParallel(
@@ -400,6 +1015,7 @@ def employeesParallel(
     *,
     output: Literal["polars"],
     seed: int | None = ...,
+    num_threads: int | None = ...,
 ) -> pl.DataFrame: ...
 @overload
 def employeesParallel(
@@ -407,10 +1023,21 @@ def employeesParallel(
     *,
     output: Literal["dict"],
     seed: int | None = ...,
+    num_threads: int | None = ...,
 ) -> list[dict[str, Any]]: ...
+@overload
+def employeesParallel(
+    count: int = ...,
+    *,
+    output: Literal["arrow"],
+    seed: int | None = ...,
+    num_threads: int | None = ...,
+) -> pa.Table: ...
 def numThreads() -> int: ...
 def setNumThreads(num_threads: int) -> None: ...
 def setDeterministicMode(num_threads: int = ...) -> None: ...
+def setRngAlgorithm(algorithm: Literal["std", "pcg64"]) -> None: ...
+def rngAlgorithm() -> Literal["std", "pcg64"]: ...
 
 # =============================================================================
 # Statistical distributions
@@ -662,6 +1289,9 @@ def pearsonCorrelation(
     x: list[float],
     y: list[float],
 ) -> float: ...
+def nearestCorrelationMatrix(
+    matrix: list[list[float]],
+) -> tuple[list[list[float]], float]: ...
 
 # =============================================================================
 # Temporal dependency models
@@ -752,6 +1382,7 @@ class MarkovChain:
     def current_state(self) -> str: ...
     def set_state(self, state: str) -> None: ...
     def stationary_distribution(self) -> list[float]: ...
+    def expected_dwell_time(self, state: str) -> float: ...
     def sample(
         self,
         n: int,
@@ -830,6 +1461,28 @@ class GaussianCopula:
         seed: int | None = ...,
     ) -> list[list[float]]: ...
 
+@final
+class StudentTCopula:
+    """Student-t copula with symmetric tail dependence.
+
+    Extreme values tend to occur together; lower df means stronger tail dependence.
+    """
+
+    def __init__(
+        self,
+        correlation_matrix: list[list[float]],
+        df: float,
+    ) -> None: ...
+    @property
+    def df(self) -> float: ...
+    @property
+    def dim(self) -> int: ...
+    def sample(
+        self,
+        n: int,
+        seed: int | None = ...,
+    ) -> list[list[float]]: ...
+
 @final
 class ClaytonCopula:
     """Clayton copula with lower tail dependence.
@@ -847,6 +1500,7 @@ class ClaytonCopula:
     @property
     def dim(self) -> int: ...
     def kendalls_tau(self) -> float: ...
+    def lower_tail_dependence(self) -> float: ...
     def sample(
         self,
         n: int,
@@ -883,9 +1537,12 @@ class GumbelCopula:
     def __init__(
         self,
         theta: float,
+        dim: int = ...,
     ) -> None: ...
     @property
     def theta(self) -> float: ...
+    @property
+    def dim(self) -> int: ...
     def kendalls_tau(self) -> float: ...
     def upper_tail_dependence(self) -> float: ...
     def sample(
@@ -44,6 +44,166 @@ class TestArrowIpc:
         assert "row_id" in table.column_names
 
 
+class TestArrowOutput:
+    """Test output="arrow" against the pandas output of each generator."""
+
+    @staticmethod
+    def assert_matches_pandas(table, df):
+        pa = pytest.importorskip("pyarrow")
+        import pandas as pd
+
+        assert isinstance(table, pa.Table)
+        assert table.column_names == list(df.columns)
+        pd.testing.assert_frame_equal(table.to_pandas(), df, check_dtype=False)
+
+    @pytest.fixture(autouse=True)
+    def _require_pyarrow(self):
+        pytest.importorskip("pyarrow")
+
+    def test_superstore(self):
+        from superstore import superstore
+
+        self.assert_matches_pandas(superstore(200, output="arrow", seed=42), superstore(200, seed=42))
+
+    def test_employees(self):
+        from superstore import employees
+
+        self.assert_matches_pandas(employees(100, output="arrow", seed=42), employees(100, seed=42))
+
+    def test_timeseries(self):
+        from superstore import timeseries
+
+        table = timeseries(nper=50, output="arrow", seed=42)
+        self.assert_matches_pandas(table, timeseries(nper=50, seed=42).reset_index())
+
+    def test_timeseries_data(self):
+        from superstore import timeseriesData
+
+        tables = timeseriesData(nper=30, output="arrow", seed=42)
+        series = timeseriesData(nper=30, seed=42)
+        assert tables.keys() == series.keys()
+        for name, table in tables.items():
+            assert table.column_names == ["index", "value"]
+            assert table.column("value").to_pylist() == series[name].tolist()
+            assert list(table.column("index").to_pandas()) == list(series[name].index)
+
+    def test_weather(self):
+        from superstore import weather
+
+        table = weather(count=200, output="arrow", seed=42)
+        self.assert_matches_pandas(table, weather(count=200, seed=42))
+
+    def test_logs(self):
+        from superstore import app_logs, logs
+
+        config = {"count": 300, "seed": 42}
+        self.assert_matches_pandas(logs({**config, "output": "arrow"}), logs(config))
+        self.assert_matches_pandas(app_logs({**config, "output": "arrow"}), app_logs(config))
+
+    def test_ecommerce(self):
+        from superstore import cohort_retention, ecommerce_data, ecommerce_interactions, ecommerce_products, ecommerce_sessions
+
+        self.assert_matches_pandas(ecommerce_sessions(200, seed=42, output="arrow"), ecommerce_sessions(200, seed=42))
+        self.assert_matches_pandas(ecommerce_products(50, seed=42, output="arrow"), ecommerce_products(50, seed=42))
+
+        config = {"sessions": 200, "customers": 50, "seed": 42}
+        tables = ecommerce_data(config, output="arrow")
+        frames = ecommerce_data(config)
+        assert tables.keys() == frames.keys()
+        for name in frames:
+            self.assert_matches_pandas(tables[name], frames[name])
+
+        self.assert_matches_pandas(cohort_retention(frames["orders"], output="arrow"), cohort_retention(frames["orders"]))
+        self.assert_matches_pandas(ecommerce_interactions(config, output="arrow"), ecommerce_interactions(config))
+
+    def test_subscriptions(self):
+        from superstore import subscriptions
+
+        tables = subscriptions(count=100, seed=42, output="arrow")
+        frames = subscriptions(count=100, seed=42)
+        assert tables.keys() == frames.keys()
+        for name in frames:
+            self.assert_matches_pandas(tables[name], frames[name])
+
+    def test_finance(self):
+        from superstore import credit_defaults, finance, fix_messages, options_chain, portfolio, stock_prices, yield_curve
+
+        config = {"ndays": 30, "seed": 42}
+        self.assert_matches_pandas(stock_prices({**config, "output": "arrow"}), stock_prices(config))
+        self.assert_matches_pandas(options_chain({**config, "output": "arrow"}), options_chain(config))
+
+        prices, options = finance({**config, "output": "arrow"})
+        expected_prices, expected_options = finance(config)
+        self.assert_matches_pandas(prices, expected_prices)
+        self.assert_matches_pandas(options, expected_options)
+
+        self.assert_matches_pandas(fix_messages(20, seed=42, output="arrow"), fix_messages(20, seed=42))
+        self.assert_matches_pandas(yield_curve(seed=42, ndays=5, output="arrow"), yield_curve(seed=42, ndays=5))
+        self.assert_matches_pandas(credit_defaults(200, horizon_years=3, seed=42, output="arrow"), credit_defaults(200, horizon_years=3, seed=42))
+        self.assert_matches_pandas(portfolio(expected_prices, [1.0], output="arrow"), portfolio(expected_prices, [1.0]))
+
+    def test_parallel(self):
+        from superstore import employeesParallel, superstoreParallel
+
+        self.assert_matches_pandas(superstoreParallel(200, output="arrow", seed=42), superstoreParallel(200, seed=42))
+        self.assert_matches_pandas(employeesParallel(100, output="arrow", seed=42), employeesParallel(100, seed=42))
+
+    def test_telemetry(self):
+        from superstore import telemetry
+
+        config = {"machine_count": 5, "readings_per_machine": 20, "seed": 42}
+        self.assert_matches_pandas(telemetry({**config, "output": "arrow"}), telemetry(config))
+
+    def test_column_types(self):
+        import pyarrow as pa
+
+        from superstore import logs, superstore, timeseries, weather
+
+        sales = superstore(50, output="arrow", seed=42).schema
+        assert sales.field("Row ID").type == pa.int64()
+        assert sales.field("Profit").type == pa.float64()
+        assert sales.field("Order ID").type == pa.string()
+
+        assert weather(count=10, output="arrow", seed=42).schema.field("timestamp").type == pa.timestamp("ns")
+        assert weather(count=10, output="arrow", seed=42).schema.field("is_outlier").type == pa.bool_()
+        assert timeseries(nper=10, output="arrow", seed=42).schema.field("index").type == pa.timestamp("ns")
+
+        # Optional fields are nullable strings, even when unset in every row
+        schema = logs({"count": 200, "seed": 42, "output": "arrow"}).schema
+        assert schema.field("user_id").type == pa.string()
+        assert schema.field("user_id").nullable
+        assert schema.field("deploy_id").type == pa.string()
+
+    def test_empty_schema(self):
+        import pyarrow as pa
+
+        from superstore import employees, logs, superstore, weather
+
+        # The schema is fixed by the generator, not inferred from the values
+        sales = superstore(0, output="arrow", seed=42).schema
+        assert sales.field("Row ID").type == pa.int64()
+        assert sales.field("Sales").type == pa.int64()
+        assert sales.field("Discount").type == pa.float64()
+        assert sales.field("MSRP").type == pa.float64()
+        assert sales.field("Order ID").type == pa.string()
+        assert sales == superstore(50, output="arrow", seed=42).schema
+
+        staff = employees(0, output="arrow", seed=42).schema
+        assert staff.field("Salary").type == pa.float64()
+        assert staff == employees(20, output="arrow", seed=42).schema
+
+        readings = weather(count=0, output="arrow", seed=42).schema
+        assert readings.field("timestamp").type == pa.timestamp("ns")
+        assert readings.field("temperature_celsius").type == pa.float64()
+        assert readings.field("is_outlier").type == pa.bool_()
+
+        requests = logs({"count": 0, "seed": 42, "output": "arrow"}).schema
+        assert requests.field("status_code").type == pa.int64()
+        assert requests.field("latency_ms").type == pa.float64()
+        assert requests.field("user_id").type == pa.string()
+        assert requests == logs({"count": 200, "seed": 42, "output": "arrow"}).schema
+
+
 class TestFileExport:
     """Test Parquet and CSV export functions."""
 
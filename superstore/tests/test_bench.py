@@ -0,0 +1,31 @@
+"""Tests for the output-format benchmark helper."""
+
+import pytest
+
+
+class TestBench:
+    def test_bench_reports_each_output(self):
+        from superstore import bench
+
+        outputs = ["pandas", "polars", "arrow", "dict"]
+        results = bench("superstore", 500, outputs=outputs, seed=42, repeat=2)
+        assert list(results) == outputs
+        for result in results.values():
+            assert result["elapsed_s"] > 0
+            assert result["peak_memory_bytes"] >= 0
+        assert results["dict"]["peak_memory_bytes"] > 0
+
+    @pytest.mark.parametrize("dataset", ["employees", "weather", "ecommerce_sessions", "timeseries"])
+    def test_bench_datasets(self, dataset):
+        from superstore import bench
+
+        results = bench(dataset, 100, outputs=["pandas"], repeat=1)
+        assert results["pandas"]["elapsed_s"] > 0
+
+    def test_bench_invalid_arguments(self):
+        from superstore import bench
+
+        with pytest.raises(ValueError, match="Unknown dataset"):
+            bench("nope", 10)
+        with pytest.raises(ValueError, match="repeat"):
+            bench("superstore", 10, repeat=0)
@@ -16,14 +16,21 @@ SESSIONS_COLUMNS = [
     "bounced",
     "converted",
     "total_value",
+    "currency",
 ]
 
 PRODUCTS_COLUMNS = [
     "product_id",
+    "parent_product_id",
+    "sku",
     "name",
     "category",
     "subcategory",
+    "brand",
+    "size",
+    "color",
     "price",
+    "currency",
     "rating",
     "review_count",
     "in_stock",
@@ -52,6 +59,7 @@ ORDERS_COLUMNS = [
     "tax",
     "shipping",
     "total",
+    "currency",
     "payment_method",
     "status",
 ]
@@ -241,6 +249,102 @@ class TestEcommerceData:
         assert data1["sessions"]["session_id"].tolist() == data2["sessions"]["session_id"].tolist()
         assert data1["products"]["product_id"].tolist() == data2["products"]["product_id"].tolist()
 
+    def test_ecommerce_data_locale(self):
+        from superstore import ecommerce_data
+
+        data = ecommerce_data(config={"sessions": 5000, "seed": 42, "locale": "de_DE"}, output="dict")
+
+        assert data["orders"]["currency"]
+        assert set(data["orders"]["currency"]) == {"EUR"}
+        assert set(data["sessions"]["currency"]) == {"EUR"}
+        assert set(data["products"]["currency"]) == {"EUR"}
+        assert all("Artikel" in name for name in data["products"]["name"])
+        domains = {email.split("@")[1] for email in data["customers"]["email"]}
+        assert domains <= {"web.de", "gmx.de", "t-online.de", "gmail.com"}
+
+        us = ecommerce_data(config={"sessions": 5000, "seed": 42}, output="dict")
+        assert set(us["orders"]["currency"]) == {"USD"}
+
+        jp = ecommerce_data(config={"sessions": 5000, "seed": 42, "locale": "ja_JP"}, output="dict")
+        assert set(jp["orders"]["currency"]) == {"JPY"}
+
+    def test_ecommerce_data_unknown_locale(self):
+        import pytest
+
+        from superstore import ecommerce_data
+
+        with pytest.warns(UserWarning, match="unsupported locale 'xx_XX'"):
+            data = ecommerce_data(config={"sessions": 50, "seed": 42, "locale": "xx_XX"}, output="dict")
+        assert set(data["sessions"]["currency"]) == {"USD"}
+        with pytest.raises(TypeError):
+            ecommerce_data(config={"sessions": 50, "locale": 42}, output="dict")
+
+    def test_ecommerce_data_brands(self):
+        from collections import Counter
+
+        from superstore import ecommerce_data
+
+        pools = {"Electronics": ["Voltix", "Nexa", "Pixelon"], "Toys": ["Brickle", "Kiddo"]}
+        config = {"seed": 7, "catalog": {"num_products": 2000, "categories": ["Electronics", "Toys"], "brands": pools}}
+        products = ecommerce_data(config={**config, "sessions": 10}, output="dict")["products"]
+
+        for category, pool in pools.items():
+            brands = Counter(b for b, c in zip(products["brand"], products["category"]) if c == category)
+            assert set(brands) <= set(pool)
+            assert brands.most_common(1)[0][0] == pool[0]
+
+    def test_ecommerce_data_variants(self):
+        from collections import defaultdict
+
+        from superstore import ecommerce_data
+
+        config = {"seed": 42, "sessions": 10, "catalog": {"num_products": 50, "variants_per_product": 3}}
+        products = ecommerce_data(config=config, output="dict")["products"]
+        assert len(products["product_id"]) == 150
+        assert len(set(products["sku"])) == 150
+
+        families = defaultdict(list)
+        for parent, category, size, color in zip(products["parent_product_id"], products["category"], products["size"], products["color"]):
+            families[parent].append((category, size, color))
+        assert len(families) == 50
+        for variants in families.values():
+            assert len(variants) == 3
+            assert len({category for category, _, _ in variants}) == 1
+            assert len({(size, color) for _, size, color in variants}) == 3
+
+    def test_cart_event_products_exist_in_catalog(self):
+        from superstore import ecommerce_data
+
+        config = {"seed": 7, "sessions": 2000, "catalog": {"num_products": 40, "variants_per_product": 3}}
+        data = ecommerce_data(config=config, output="dict")
+        catalog = set(data["products"]["product_id"])
+        referenced = {product for product in data["cart_events"]["product_id"] if product is not None}
+        assert referenced and referenced <= catalog
+        assert ecommerce_data(config=config, output="dict")["products"]["product_id"] == data["products"]["product_id"]
+
+    def test_cart_event_running_total(self):
+        from superstore import ecommerce_data
+
+        config = {"seed": 42, "sessions": 2000, "cart": {"quantity_update_probability": 0.3}}
+        events = ecommerce_data(config=config, output="dict")["cart_events"]
+        carts, last_line = {}, {}
+        updates = abandoned = 0
+        for session, kind, product, total in zip(events["session_id"], events["event_type"], events["product_id"], events["total_price"]):
+            if kind == "add_to_cart":
+                carts[session] = carts.get(session, 0.0) + total
+                last_line[session] = (product, total)
+            elif kind in ("update_quantity", "remove_from_cart"):
+                added, line_total = last_line[session]
+                assert product == added
+                carts[session] += total - line_total if kind == "update_quantity" else -total
+                last_line[session] = (product, total)
+                updates += kind == "update_quantity"
+            else:
+                assert product is None
+                assert abs(total - carts[session]) < 1e-9
+                abandoned += kind == "cart_abandoned" and total > 0
+        assert updates > 0 and abandoned > 0
+
 
 class TestEcommerceConfig:
     def test_config_defaults(self):
@@ -279,9 +383,170 @@ class TestEcommerceConfig:
         with pytest.raises(ValidationError):
             SessionConfig(cart_add_probability=-0.1)  # Must be >= 0.0
 
+    def test_custom_session_states(self):
+        from superstore import ecommerce_data
+
+        def sessions(**session):
+            config = {"sessions": 500, "seed": 42, "session": {"enable_bounces": False, **session}}
+            return ecommerce_data(config=config, output="dict")["sessions"]
+
+        chain = {
+            "custom_states": ["home", "article", "leave"],
+            "custom_transition_matrix": [[0.0, 0.7, 0.3], [0.0, 0.6, 0.4], [0.0, 0.0, 1.0]],
+            "exit_state": "leave",
+        }
+        data = sessions(**chain, purchase_state="article")
+        assert 0.6 < sum(data["converted"]) / 500 < 0.8
+        assert min(data["pages_viewed"]) >= 2
+
+        # Terminal states must be among the custom states
+        with pytest.raises(ValueError, match="purchase_state 'purchase'"):
+            sessions(**chain)
+        with pytest.raises(ValueError, match="exit_state 'exit'"):
+            sessions(**{**chain, "exit_state": "exit"}, purchase_state="article")
+
+        with pytest.raises(ValueError):
+            sessions(**{**chain, "custom_transition_matrix": [[0.5, 0.5], [0.5, 0.5]]})
+        with pytest.raises(ValueError):
+            sessions(**{**chain, "custom_transition_matrix": [[0.0, 0.7, 0.2], [0.0, 0.6, 0.4], [0.0, 0.0, 1.0]]})
+        with pytest.raises(ValueError):
+            sessions(custom_states=["home", "leave"])
+
+    def test_target_conversion_rate(self):
+        from superstore import EcommerceConfig, ecommerce_data
+
+        for target in (0.03, 0.1):
+            config = EcommerceConfig(sessions=20000, seed=42, session={"target_conversion_rate": target})
+            sessions = ecommerce_data(config=config, output="dict")["sessions"]
+            assert abs(sum(sessions["converted"]) / 20000 - target) < 0.01
+
+        with pytest.raises(ValueError):
+            ecommerce_data(config={"sessions": 10, "session": {"target_conversion_rate": 0.9}})
+
+    def test_landing_page_conversion(self):
+        from superstore import ecommerce_data
+
+        def rates(**session):
+            data = ecommerce_data(config={"sessions": 20000, "seed": 42, "session": session}, output="dict")["sessions"]
+            pages = {}
+            for page, converted in zip(data["landing_page"], data["converted"]):
+                pages.setdefault(page, []).append(converted)
+            return {page: sum(c) / len(c) for page, c in pages.items()}, {page: len(c) for page, c in pages.items()}
+
+        rate, count = rates()
+        assert rate["/sale"] > 1.4 * rate["/"]
+        assert count["/"] > count["/sale"]
+
+        rate, count = rates(landing_page_weights={"/": 0.0}, landing_page_conversion={"/products": 3.0})
+        assert "/" not in count
+        assert rate["/products"] > rate["/sale"]
+
+        with pytest.raises(ValueError):
+            rates(landing_page_weights={"/blog": 1.0})
+
+    def test_min_order_value(self):
+        from superstore import EcommerceConfig, MinOrderPolicy, ecommerce_data
+
+        def orders(**config):
+            data = ecommerce_data(config={"sessions": 3000, "seed": 42, **config}, output="dict")
+            # The floor applies to sessions, so converted sessions and orders agree
+            assert sum(data["sessions"]["converted"]) == len(data["orders"]["order_id"])
+            return data["orders"]
+
+        baseline = orders()
+        assert min(baseline["subtotal"]) < 100.0
+
+        bumped = orders(min_order_value=100.0)
+        assert len(bumped["order_id"]) == len(baseline["order_id"])
+        assert all(subtotal >= 100.0 for subtotal in bumped["subtotal"])
+
+        dropped = orders(min_order_value=100.0, min_order_policy="drop")
+        assert len(dropped["order_id"]) == sum(subtotal >= 100.0 for subtotal in baseline["subtotal"])
+        assert all(subtotal >= 100.0 for subtotal in dropped["subtotal"])
+
+        config = EcommerceConfig(sessions=3000, seed=42, min_order_value=100.0, min_order_policy=MinOrderPolicy.DROP)
+        assert all(subtotal >= 100.0 for subtotal in ecommerce_data(config=config, output="dict")["orders"]["subtotal"])
+
+        with pytest.raises(ValueError):
+            orders(min_order_value=100.0, min_order_policy="skip")
+
     def test_config_factory(self):
         from superstore import ecommerce_config
 
         config = ecommerce_config(sessions=1000, seed=42)
         assert config.sessions == 1000
         assert config.seed == 42
+
+
+class TestEcommerceInteractions:
+    def test_interaction_columns(self):
+        from superstore import ecommerce_interactions
+
+        edges = ecommerce_interactions(config={"sessions": 500, "seed": 42}, output="dict")
+        assert list(edges) == ["user_id", "product_id", "interaction_type", "weight"]
+        assert set(edges["interaction_type"]) <= {"view", "add_to_cart", "purchase"}
+        assert all(w >= 1 for w in edges["weight"])
+
+    def test_product_degree_heavy_tail(self):
+        from collections import defaultdict
+
+        from superstore import ecommerce_interactions
+
+        edges = ecommerce_interactions(config={"sessions": 10000, "seed": 42}, output="dict")
+        users = defaultdict(set)
+        for user, product in zip(edges["user_id"], edges["product_id"]):
+            users[product].add(user)
+        degrees = sorted((len(u) for u in users.values()), reverse=True)
+
+        top = degrees[: len(degrees) // 20]
+        assert sum(top) / sum(degrees) > 0.25
+        assert degrees[0] > 10 * degrees[len(degrees) // 2]
+
+    def test_edgelist_round_trip(self):
+        nx = pytest.importorskip("networkx")
+
+        from superstore import ecommerce_interactions
+
+        config = {"sessions": 1000, "seed": 42}
+        edges = ecommerce_interactions(config=config, output="edgelist")
+        interactions = ecommerce_interactions(config=config, output="dict")
+
+        pairs = set(zip(interactions["user_id"], interactions["product_id"]))
+        assert all(isinstance(edge, tuple) and len(edge) == 3 for edge in edges)
+        assert sum(w for _, _, w in edges) == sum(interactions["weight"])
+
+        graph = nx.Graph()
+        graph.add_weighted_edges_from(edges)
+        assert graph.number_of_edges() == len(pairs)
+        assert graph.number_of_nodes() == len({u for u, _ in pairs}) + len({p for _, p in pairs})
+
+
+class TestCohortRetention:
+    def test_period_zero_is_one(self):
+        from superstore import cohort_retention, ecommerce_data
+
+        data = ecommerce_data(config={"sessions": 5000, "customers": 500, "days": 120, "seed": 42}, output="dict")
+        matrix = cohort_retention(data["orders"], period="month", output="dict")
+        assert matrix["cohort"]
+        assert all(v == 1.0 for v in matrix["period_0"])
+
+        periods = [k for k in matrix if k.startswith("period_")]
+        for k in periods[1:]:
+            assert all(v is None or 0.0 <= v <= 1.0 for v in matrix[k])
+
+    def test_subscriptions_input(self):
+        from superstore import cohort_retention, subscriptions
+
+        data = subscriptions(count=500, seed=1, config={"months": 6}, output="dict")
+        matrix = cohort_retention(data["subscriptions"], period="month", output="dict")
+        assert all(v == 1.0 for v in matrix["period_0"])
+        assert sum(matrix["cohort_size"]) == 500
+        # Later cohorts are observed for fewer periods
+        assert matrix["period_1"][-1] is None
+
+    def test_invalid_period(self):
+        from superstore import cohort_retention, subscriptions
+
+        data = subscriptions(count=10, seed=1, output="dict")
+        with pytest.raises(ValueError):
+            cohort_retention(data["subscriptions"], period="year", output="dict")
@@ -0,0 +1,169 @@
+"""Tests for finance data generators."""
+
+
+class TestFixMessages:
+    def test_fix_messages_dict(self):
+        from superstore import fix_messages
+
+        result = fix_messages(n=20, seed=42, output="dict")
+        assert isinstance(result, dict)
+        assert result["msg_type"][0] == "D"
+        assert all(raw.startswith("8=FIX.4.4|") for raw in result["raw"])
+
+    def test_output_is_case_sensitive(self):
+        import pytest
+
+        from superstore import credit_defaults, fix_messages, yield_curve
+
+        for generate in (fix_messages, yield_curve, credit_defaults):
+            with pytest.raises(ValueError, match="Invalid output format 'Pandas'"):
+                generate(output="Pandas")
+
+    def test_fix_messages_seed_reproducibility(self):
+        from superstore import fix_messages
+
+        a = fix_messages(n=10, seed=7, output="dict")
+        b = fix_messages(n=10, seed=7, output="dict")
+        assert a["raw"] == b["raw"]
+
+    def test_fix_messages_order_linkage(self):
+        from superstore import fix_messages
+
+        result = fix_messages(n=50, seed=1, output="dict")
+        seen = set()
+        for msg_type, cl_ord_id, orig in zip(result["msg_type"], result["cl_ord_id"], result["orig_cl_ord_id"]):
+            if msg_type == "D":
+                seen.add(cl_ord_id)
+            else:
+                assert (orig or cl_ord_id) in seen
+
+
+class TestIntradayBars:
+    def test_intraday_bar_count(self):
+        from superstore import stock_prices
+
+        result = stock_prices({"ndays": 2, "seed": 42, "output": "dict", "intraday": {"enable": True, "bar_minutes": 5}})
+        assert len(result["date"]) == 2 * 78
+        assert result["date"][0] == "2024-01-02 09:30:00"
+
+    def test_intraday_volume_u_shape(self):
+        from superstore import stock_prices
+
+        result = stock_prices({"ndays": 20, "seed": 42, "output": "dict", "intraday": {"enable": True}})
+        volumes = result["volume"]
+        days = [volumes[i : i + 390] for i in range(0, len(volumes), 390)]
+        open_vol = sum(sum(day[:30]) for day in days)
+        mid_vol = sum(sum(day[180:210]) for day in days)
+        close_vol = sum(sum(day[360:]) for day in days)
+        assert open_vol > mid_vol
+        assert close_vol > mid_vol
+
+
+class TestEventWindows:
+    def test_earnings_dates_elevate_returns(self):
+        from superstore import stock_prices
+
+        earnings = ["2024-02-01", "2024-05-01", "2024-08-01", "2024-11-01"]
+        result = stock_prices({"ndays": 252, "seed": 42, "output": "dict", "earnings_dates": earnings})
+        dates, returns = result["date"], result["returns"]
+        events = [next(i for i, d in enumerate(dates) if d >= e) for e in earnings]
+        in_window = [any(e - 5 <= i <= e + 5 for e in events) for i in range(len(dates))]
+        event_abs = [abs(r) for r, w in zip(returns, in_window) if w]
+        base_abs = [abs(r) for r, w in zip(returns, in_window) if not w]
+        assert sum(event_abs) / len(event_abs) > sum(base_abs) / len(base_abs)
+
+
+class TestPortfolio:
+    def test_identical_assets_reproduce_path(self):
+        from superstore import portfolio, stock_prices
+
+        single = stock_prices({"ndays": 50, "seed": 42, "output": "dict"})
+        n = len(single["date"])
+        prices = {
+            "date": single["date"] * 2,
+            "ticker": ["A"] * n + ["B"] * n,
+            "close": single["close"] * 2,
+        }
+        for rebalance in ("none", "daily"):
+            result = portfolio(prices, [0.5, 0.5], rebalance=rebalance, output="dict")
+            assert result["date"] == single["date"]
+            for value, close in zip(result["value"], single["close"]):
+                assert abs(value - close) < 1e-9
+
+    def test_weight_count_mismatch(self):
+        import pytest
+
+        from superstore import portfolio, stock_prices
+
+        prices = stock_prices({"ndays": 10, "n_assets": 3, "seed": 42, "output": "dict"})
+        with pytest.raises(ValueError):
+            portfolio(prices, [1.0, 1.0], output="dict")
+
+
+class TestVarCvar:
+    def test_cvar_at_least_var(self):
+        from superstore import stock_prices, var_cvar
+
+        returns = stock_prices({"ndays": 500, "seed": 42, "output": "dict"})["returns"]
+        for method in ("historical", "gaussian"):
+            risk = var_cvar(returns, alpha=0.95, method=method)
+            assert risk["var"] > 0
+            assert risk["cvar"] >= risk["var"]
+
+    def test_gaussian_var_closed_form(self):
+        import statistics
+
+        from superstore import var_cvar
+
+        returns = [0.01, -0.02, 0.015, -0.005, 0.0, 0.03, -0.025, 0.005]
+        risk = var_cvar(returns, alpha=0.95, method="gaussian")
+        expected = -statistics.mean(returns) + statistics.stdev(returns) * statistics.NormalDist().inv_cdf(0.95)
+        assert abs(risk["var"] - expected) < 1e-8
+
+
+class TestYieldCurve:
+    def test_matches_nelson_siegel(self):
+        import math
+
+        from superstore import yield_curve
+
+        result = yield_curve(level=0.05, slope=-0.03, curvature=0.02, tau=1.5, output="dict")
+        for tenor, value in zip(result["tenor"], result["yield"]):
+            x = tenor / 1.5
+            loading = (1 - math.exp(-x)) / x
+            assert abs(value - (0.05 - 0.03 * loading + 0.02 * (loading - math.exp(-x)))) < 1e-12
+        assert result["yield"][-1] > result["yield"][0]
+
+    def test_daily_evolution(self):
+        from superstore import yield_curve
+
+        result = yield_curve(tenors=[1, 5, 10], ndays=10, seed=42, output="dict")
+        assert len(result["date"]) == 30
+        assert len(set(result["date"])) == 10
+
+
+class TestCreditDefaults:
+    def test_default_rate_by_rating(self):
+        from superstore import credit_defaults
+
+        pds = {"AAA": 0.0001, "AA": 0.0002, "A": 0.0006, "BBB": 0.0018, "BB": 0.007, "B": 0.035, "CCC": 0.25}
+        result = credit_defaults(n_obligors=100_000, asset_correlation=0.0, seed=42, output="dict")
+        for rating, pd in pds.items():
+            flags = [d for r, d in zip(result["rating"], result["defaulted"]) if r == rating]
+            rate = sum(flags) / len(flags)
+            assert abs(rate - pd) < 4 * (pd * (1 - pd) / len(flags)) ** 0.5 + 1e-4
+
+    def test_defaulted_obligors_rated_d(self):
+        from superstore import credit_defaults
+
+        result = credit_defaults(n_obligors=1000, horizon_years=5, seed=42, output="dict")
+        for defaulted, final in zip(result["defaulted"], result["final_rating"]):
+            assert defaulted == (final == "D")
+
+    def test_invalid_rating(self):
+        import pytest
+
+        from superstore import credit_defaults
+
+        with pytest.raises(ValueError):
+            credit_defaults(default_probabilities={"ZZZ": 0.1}, output="dict")
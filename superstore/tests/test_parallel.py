@@ -67,6 +67,19 @@ class TestParallel:
         threads = numThreads()
         assert threads >= 1
 
+    def test_parallel_per_call_num_threads(self):
+        from superstore import employeesParallel, superstoreParallel
+
+        # Each call builds its own pool, so differing counts never clash
+        two = superstoreParallel(1000, output="dict", seed=42, num_threads=2)
+        three = superstoreParallel(1000, output="dict", seed=42, num_threads=3)
+        assert len(two) == 1000
+        # Rows are seeded individually, so the thread count doesn't matter
+        assert three == two
+        assert [r["Row ID"] for r in two] == list(range(1000))
+        employees = employeesParallel(3000, output="dict", seed=42, num_threads=3)
+        assert employeesParallel(3000, output="dict", seed=42, num_threads=2) == employees
+
     def test_parallel_large_count(self):
         from superstore import superstoreParallel
 
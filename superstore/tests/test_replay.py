@@ -0,0 +1,49 @@
+"""Tests for time-paced replay of generated datasets."""
+
+import math
+
+import pytest
+
+
+class TestReplay:
+    def test_replay_ordered_by_timestamp(self):
+        from superstore import replay
+
+        rows = list(replay("app_logs", speed=math.inf, config={"count": 200, "seed": 42}))
+        assert len(rows) == 200
+        timestamps = [row["timestamp"] for row in rows]
+        assert timestamps == sorted(timestamps)
+
+    def test_replay_high_speed_completes_quickly(self):
+        import time
+
+        from superstore import replay
+
+        # Default logs span minutes of log time; 1e6x replays them in well under a second
+        start = time.monotonic()
+        rows = list(replay("logs", speed=1e6, config={"count": 500, "seed": 42}))
+        assert len(rows) == 500
+        assert time.monotonic() - start < 5.0
+        assert [r["timestamp"] for r in rows] == sorted(r["timestamp"] for r in rows)
+
+    def test_replay_paces_to_speed(self):
+        import time
+        from datetime import datetime
+
+        from superstore import replay
+
+        rows = list(replay("logs", speed=math.inf, config={"count": 20, "seed": 1}))
+        first, last = (datetime.fromisoformat(r["timestamp"].rstrip("Z")) for r in (rows[0], rows[-1]))
+        span = (last - first).total_seconds()
+        speed = span / 0.3
+        start = time.monotonic()
+        assert len(list(replay("logs", speed=speed, config={"count": 20, "seed": 1}))) == 20
+        assert time.monotonic() - start >= 0.25
+
+    def test_replay_invalid_arguments(self):
+        from superstore import replay
+
+        with pytest.raises(ValueError):
+            replay("nope")
+        with pytest.raises(ValueError):
+            replay("logs", speed=0)
@@ -30,6 +30,23 @@ class TestStreaming:
                 assert r1["Order ID"] == r2["Order ID"]
                 assert r1["City"] == r2["City"]
 
+    def test_stream_pool_size_matches_eager(self):
+        from superstore import employees, employeesStream
+
+        streamed = [row for chunk in employeesStream(500, chunk_size=100, seed=42, pool_size=5) for row in chunk]
+        eager = employees(config={"count": 500, "seed": 42, "pool_size": 5, "output": "dict"})
+        for column in ("City", "Postal Code", "Email"):
+            assert {r[column] for r in streamed} == {r[column] for r in eager}
+            assert len({r[column] for r in streamed}) <= 5
+
+    def test_superstore_stream_matches_eager(self):
+        from superstore import superstore, superstoreStream
+
+        eager = superstore(200, output="dict", seed=42)
+        for chunk_size in (1, 33, 200):
+            streamed = [row for chunk in superstoreStream(200, chunk_size=chunk_size, seed=42) for row in chunk]
+            assert streamed == eager
+
     def test_superstore_stream_dict_format(self):
         from superstore import superstoreStream
 
@@ -77,3 +94,150 @@ class TestStreaming:
             assert False, "Should have raised StopIteration"
         except StopIteration:
             pass
+
+    def test_superstore_stream_partitions(self):
+        from collections import Counter
+
+        from superstore import superstoreStream
+
+        rows = [row for chunk in superstoreStream(4000, chunk_size=300, seed=42, partitions=4) for row in chunk]
+        assignment = {}
+        for row in rows:
+            assert 0 <= row["Partition"] < 4
+            assert assignment.setdefault(row["Customer ID"], row["Partition"]) == row["Partition"]
+
+        counts = Counter(row["Partition"] for row in rows)
+        assert all(abs(counts[p] - 1000) < 150 for p in range(4))
+
+        # Callable keys: rows with the same key share a partition
+        rows = [row for chunk in superstoreStream(500, seed=1, partitions=3, partition_key=lambda r: r["State"]) for row in chunk]
+        by_state = {}
+        for row in rows:
+            assert by_state.setdefault(row["State"], row["Partition"]) == row["Partition"]
+
+    def test_stream_chunk_size_edge_cases(self):
+        import pytest
+
+        from superstore import employeesStream, superstoreStream
+
+        for total, chunk_size in [(0, 1), (1, 1), (10, 3), (5, 1000)]:
+            assert sum(len(c) for c in superstoreStream(total, chunk_size=chunk_size, seed=1)) == total
+            assert sum(len(c) for c in employeesStream(total, chunk_size=chunk_size, seed=1)) == total
+
+        with pytest.raises(ValueError):
+            superstoreStream(10, chunk_size=0)
+        with pytest.raises(ValueError):
+            employeesStream(10, chunk_size=0)
+
+    def test_stream_rejects_empty_pool(self):
+        import pytest
+
+        from superstore import employeesStream, superstoreStream
+
+        with pytest.raises(ValueError, match="pool_size must be at least 1"):
+            superstoreStream(10, pool_size=0)
+        with pytest.raises(ValueError, match="pool_size must be at least 1"):
+            employeesStream(10, pool_size=0)
+
+    def test_superstore_stream_start_offset(self):
+        from superstore import superstoreStream
+
+        full = [row for chunk in superstoreStream(1000, chunk_size=64, seed=7) for row in chunk]
+        resumed = [row for chunk in superstoreStream(1000, chunk_size=100, seed=7, start_offset=500) for row in chunk]
+        assert resumed == full[500:]
+
+
+class TestTableStreams:
+    def test_superstore_stream_matches_eager(self):
+        from superstore import superstore, superstore_stream
+
+        config = {"seasonality": {"enable": True}, "promotions": {"enable": True}, "payment": {"enable": True}}
+        eager = superstore(config, count=300, seed=42, output="dict")
+        for chunk_size in (1, 64, 300):
+            chunks = list(superstore_stream(300, chunk_size=chunk_size, seed=42, output="dict", config=config))
+            assert len(chunks) == -(-300 // chunk_size)
+            assert [row for chunk in chunks for row in chunk] == eager
+
+    def test_superstore_stream_dataframes(self):
+        import pandas as pd
+
+        from superstore import superstore, superstore_stream
+
+        chunks = list(superstore_stream(250, chunk_size=100, seed=7))
+        assert [len(df) for df in chunks] == [100, 100, 50]
+        assert all(isinstance(df, pd.DataFrame) for df in chunks)
+        eager = superstore(count=250, seed=7)
+        pd.testing.assert_frame_equal(pd.concat(chunks, ignore_index=True), eager)
+
+    def test_employees_stream_matches_dict_stream(self):
+        from superstore import employees_stream, employeesStream
+
+        chunks = list(employees_stream(250, chunk_size=100, seed=7, output="dict"))
+        assert [len(chunk) for chunk in chunks] == [100, 100, 50]
+        expected = [row["Employee ID"] for chunk in employeesStream(250, chunk_size=100, seed=7) for row in chunk]
+        assert [row["Employee ID"] for chunk in chunks for row in chunk] == expected
+
+    def test_invalid_arguments(self):
+        import pytest
+
+        from superstore import employees_stream, superstore_stream
+
+        with pytest.raises(ValueError, match="Invalid output format"):
+            superstore_stream(10, output="csv")
+        with pytest.raises(ValueError, match="Invalid output format"):
+            employees_stream(10, output="csv")
+        with pytest.raises(ValueError, match="chunk_size"):
+            superstore_stream(10, chunk_size=0)
+
+    def test_chunks_generated_without_gil(self):
+        import threading
+        import time
+
+        from superstore import superstore_stream
+
+        ticks = []
+        done = threading.Event()
+
+        def ticker():
+            while not done.is_set():
+                ticks.append(time.perf_counter())
+                time.sleep(0.001)
+
+        stream = superstore_stream(100000, chunk_size=50000, seed=42, output="dict", config={"per_row_seeding": True})
+        thread = threading.Thread(target=ticker)
+        thread.start()
+        try:
+            start = time.perf_counter()
+            next(stream)
+            end = time.perf_counter()
+            assert sum(start < t < end for t in ticks) > 10
+        finally:
+            done.set()
+            thread.join()
+
+
+class TestStreamQuantiles:
+    def test_estimates_match_exact_quantiles(self):
+        from superstore import stream_quantiles, superstore
+
+        rows = superstore(count=20000, seed=7, output="dict")
+        for column in ("Sales", "Profit"):
+            values = sorted(float(r[column]) for r in rows)
+            spread = values[int(0.99 * len(values))] - values[int(0.01 * len(values))]
+            estimates = stream_quantiles(20000, chunk_size=3000, column=column, quantiles=[0.5, 0.95, 0.99], seed=7)
+            for q, estimate in zip((0.5, 0.95, 0.99), estimates):
+                exact = values[round(q * (len(values) - 1))]
+                assert abs(estimate - exact) <= 0.02 * spread
+
+    def test_invalid_arguments(self):
+        import math
+
+        import pytest
+
+        from superstore import stream_quantiles
+
+        with pytest.raises(ValueError, match="Unknown column"):
+            stream_quantiles(10, column="latency")
+        with pytest.raises(ValueError, match="Invalid quantile"):
+            stream_quantiles(10, quantiles=[1.5])
+        assert math.isnan(stream_quantiles(0, quantiles=[0.5])[0])
@@ -0,0 +1,68 @@
+"""Tests for subscription data generators."""
+
+SUBSCRIPTIONS_COLUMNS = [
+    "subscription_id",
+    "customer_id",
+    "initial_plan",
+    "current_plan",
+    "start_date",
+    "cancel_date",
+    "status",
+    "initial_mrr",
+    "current_mrr",
+]
+
+BILLING_EVENTS_COLUMNS = [
+    "event_id",
+    "subscription_id",
+    "billing_date",
+    "event_type",
+    "plan",
+    "mrr",
+    "mrr_change",
+]
+
+
+class TestSubscriptions:
+    def test_tables_and_columns(self):
+        from superstore import subscriptions
+
+        data = subscriptions(count=50, seed=42, output="dict")
+        assert set(data.keys()) == {"subscriptions", "billing_events"}
+        assert list(data["subscriptions"].keys()) == SUBSCRIPTIONS_COLUMNS
+        assert list(data["billing_events"].keys()) == BILLING_EVENTS_COLUMNS
+        assert len(data["subscriptions"]["subscription_id"]) == 50
+
+    def test_reproducible(self):
+        from superstore import subscriptions
+
+        a = subscriptions(count=100, seed=7, output="dict")
+        b = subscriptions(count=100, seed=7, output="dict")
+        assert a == b
+
+    def test_billing_stops_after_cancel(self):
+        from superstore import subscriptions
+
+        data = subscriptions(count=300, seed=1, config={"monthly_churn_rate": 0.1}, output="dict")
+        subs = data["subscriptions"]
+        cancels = {sid: cd for sid, cd in zip(subs["subscription_id"], subs["cancel_date"]) if cd is not None}
+        assert cancels
+
+        events = data["billing_events"]
+        for sid, date in zip(events["subscription_id"], events["billing_date"]):
+            if sid in cancels:
+                assert date < cancels[sid]
+
+    def test_custom_plans(self):
+        from superstore import subscriptions
+
+        config = {
+            "plans": [
+                {"name": "Starter", "monthly_price": 10.0},
+                {"name": "Team", "monthly_price": 50.0},
+            ],
+            "upgrade_rate": 0.2,
+        }
+        data = subscriptions(count=100, seed=3, config=config, output="dict")
+        assert set(data["billing_events"]["plan"]) <= {"Starter", "Team"}
+        assert set(data["billing_events"]["mrr"]) <= {10.0, 50.0}
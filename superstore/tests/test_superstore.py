@@ -16,6 +16,7 @@ SUPERSTORE_COLUMNS = [
     "Sub-Category",
     "Item Status",
     "Item Price",
+    "MSRP",
     "Sales",
     "Quantity",
     "Discount",
@@ -40,6 +41,11 @@ EMPLOYEES_COLUMNS = [
     "Country",
     "Start Date",
     "Date of Birth",
+    "Department",
+    "Job Title",
+    "Salary",
+    "Manager ID",
+    "Termination Date",
 ]
 
 
@@ -245,6 +251,85 @@ class TestSuperstoreConfig:
         assert len(data) == 25
         assert all(isinstance(row, dict) for row in data)
 
+    def test_superstore_student_t_copula(self):
+        """copula_df switches to a Student-t copula with joint extremes."""
+        from superstore import GaussianCopula, StudentTCopula, superstore
+        from superstore.config import SuperstoreConfig
+
+        correlations = {"sales_quantity_correlation": 0.7, "sales_profit_correlation": 0.6, "discount_profit_correlation": -0.4}
+        config = SuperstoreConfig(count=200, seed=42, copula_df=3.0, output="dict", **correlations)
+        rows = superstore(config)
+        assert rows == superstore(config)
+        assert rows != superstore(config.model_copy(update={"copula_df": None}))
+
+        def joint_upper_tail(samples):
+            return sum(1 for a, b in samples if a > 0.99 and b > 0.99)
+
+        matrix = [[1.0, 0.5], [0.5, 1.0]]
+        samples = StudentTCopula(matrix, df=3.0).sample(20000, seed=42)
+        assert all(0.0 <= u <= 1.0 for sample in samples for u in sample)
+        assert joint_upper_tail(samples) > 1.5 * joint_upper_tail(GaussianCopula(matrix).sample(20000, seed=42))
+
+    def test_discount_profit_correlation(self):
+        from superstore import pearsonCorrelation, superstore
+
+        for target in (-0.4, 0.0):
+            rows = superstore({"count": 10000, "seed": 42, "discount_profit_correlation": target}, output="dict")
+            realized = pearsonCorrelation([r["Discount"] for r in rows], [r["Profit"] for r in rows])
+            assert abs(realized - target) < 0.05
+
+    def test_superstore_repairs_invalid_correlations(self):
+        """Correlations that can't hold together are repaired with a warning."""
+        import warnings
+
+        import pytest
+
+        from superstore import nearestCorrelationMatrix, pearsonCorrelation, superstore
+
+        def sales_profit(rows):
+            return pearsonCorrelation([float(r["Sales"]) for r in rows], [r["Profit"] for r in rows])
+
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            superstore({"count": 10, "seed": 1}, output="dict")
+
+        config = {"count": 2000, "seed": 42, "sales_quantity_correlation": 0.8, "sales_profit_correlation": 0.9, "discount_profit_correlation": -0.6}
+        with pytest.warns(UserWarning, match="nearest valid one"):
+            repaired = superstore(config, output="dict")
+        with pytest.warns(UserWarning, match="sampled independently"):
+            dropped = superstore({**config, "repair_correlations": False}, output="dict")
+        assert sales_profit(repaired) > 0.5
+        assert sales_profit(dropped) < sales_profit(repaired) - 0.3
+
+        matrix, distance = nearestCorrelationMatrix([[1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]])
+        assert abs(matrix[0][1] - 0.7607) < 1e-3
+        assert abs(matrix[0][2] - 0.1573) < 1e-3
+        assert abs(distance - 0.5278) < 1e-3
+        with pytest.raises(ValueError, match="empty"):
+            nearestCorrelationMatrix([])
+
+    def test_verbose_reports_copula_fallback(self):
+        import pytest
+
+        from superstore import superstore
+
+        config = {"count": 2000, "seed": 42, "sales_quantity_correlation": 0.8, "sales_profit_correlation": 0.9, "discount_profit_correlation": -0.6}
+        with pytest.warns(UserWarning, match="sampled independently"):
+            rows, diagnostics = superstore({**config, "repair_correlations": False}, output="dict", verbose=True)
+            assert rows == superstore({**config, "repair_correlations": False}, output="dict")
+        assert diagnostics["copula"] is None
+        assert "copula_fallback" not in diagnostics
+        assert sum(diagnostics["segment_counts"].values()) == len(rows)
+
+        with pytest.warns(UserWarning, match="nearest valid one"):
+            _, diagnostics = superstore(config, output="dict", verbose=True)
+        assert diagnostics["copula"] == "gaussian"
+        assert diagnostics["correlation_repair_distance"] > 0
+
+        _, diagnostics = superstore({"count": 2000, "seed": 42, "max_quantity": 2}, output="dict", verbose=True)
+        assert diagnostics["clamped_quantities"] > 0
+        assert diagnostics["empty_segments"] == []
+
     def test_superstore_config_pool_size(self):
         """Test SuperstoreConfig with custom pool_size."""
         from superstore import superstore
@@ -278,3 +363,905 @@ class TestSuperstoreConfig:
         # (limited by pool size of 10)
         assert unique_cities_tiny <= 10
         assert unique_cities_big > unique_cities_tiny
+
+    def test_superstore_dict_config(self):
+        """A plain dict is accepted wherever a SuperstoreConfig is."""
+        import pandas as pd
+
+        from superstore import superstore
+        from superstore.config import SuperstoreConfig
+
+        df = superstore(config={"count": 10, "seed": 42, "seasonality": {"enable": True}})
+        assert isinstance(df, pd.DataFrame)
+        assert len(df) == 10
+
+        model = SuperstoreConfig(count=10, seed=42, seasonality={"enable": True}, output="pandas")
+        assert superstore(config=model).equals(superstore(config=model.model_dump()))
+
+    def test_superstore_dict_config_override(self):
+        """Explicit keyword arguments override dict config values."""
+        from superstore import superstore
+
+        rows = superstore(config={"count": 10, "seed": 1, "output": "pandas"}, count=5, seed=42, output="dict")
+        assert rows == superstore(config={"count": 5, "seed": 42}, output="dict")
+
+    def test_invalid_config_type(self):
+        import pytest
+
+        from superstore import employees, logs, superstore
+
+        with pytest.raises(TypeError, match="config must be a SuperstoreConfig, dict, int, or None"):
+            superstore(config="count=10")
+        with pytest.raises(TypeError, match="config must be an EmployeeConfig, dict, or None"):
+            employees(config=10)
+        with pytest.raises(TypeError, match="config must be a LogsConfig, dict, or None"):
+            logs([("count", 10)])
+
+    def test_pydantic_config_for_dict_generators(self):
+        """Generators that historically took dicts also accept their pydantic model."""
+        from superstore import logs
+        from superstore.config import LogsConfig
+
+        config = LogsConfig(count=20, seed=7)
+        data = logs(config)
+        assert isinstance(data, dict)
+        assert len(data["timestamp"]) == 20
+        assert data["ip_address"] == logs(config.model_dump())["ip_address"]
+
+
+class TestSuperstoreSubConfigs:
+    """Each SuperstoreConfig sub-config, toggled through a config dict, changes the output."""
+
+    @staticmethod
+    def _rows(**sub_configs):
+        from superstore import superstore
+
+        return superstore(config={"count": 5000, "seed": 7, "output": "dict", **sub_configs})
+
+    def test_seasonality(self):
+        from statistics import mean
+
+        def q4_to_summer(rows):
+            q4 = [r["Sales"] for r in rows if int(r["Order Date"][5:7]) in (10, 11, 12)]
+            summer = [r["Sales"] for r in rows if int(r["Order Date"][5:7]) in (6, 7)]
+            return mean(q4) / mean(summer)
+
+        assert 0.9 < q4_to_summer(self._rows()) < 1.1
+        assert q4_to_summer(self._rows(seasonality={"enable": True})) > 1.4
+
+    def test_promotions(self):
+        from statistics import mean
+
+        baseline = mean(r["Quantity"] for r in self._rows())
+        promoted = mean(r["Quantity"] for r in self._rows(promotions={"enable": True, "discount_quantity_correlation": 1.0}))
+        assert promoted > baseline * 1.05
+
+    def test_msrp(self):
+        for row in self._rows(returns={"enable": True}):
+            assert row["Item Price"] <= row["MSRP"]
+            assert abs(row["MSRP"] * (1 - row["Discount"] / 100) - row["Item Price"]) <= 0.01
+
+    def test_price_elasticity(self):
+        from statistics import mean
+
+        def cheap_to_dear(**promotions):
+            promotions = {"enable": True, "discount_quantity_correlation": 0.0, **promotions}
+            rows = self._rows(sales_quantity_correlation=0.0, max_quantity=10000, promotions=promotions)
+            cheap = [r["Quantity"] for r in rows if r["Item Price"] < 2000]
+            dear = [r["Quantity"] for r in rows if r["Item Price"] > 8000]
+            return mean(cheap) / mean(dear)
+
+        assert cheap_to_dear(price_elasticity=-1.5, reference_price=100.0) > 10.0
+        assert 0.8 < cheap_to_dear(price_elasticity=0.0) < 1.25
+
+    def test_margin_bounds(self):
+        import pytest
+
+        from superstore import SuperstoreConfig, superstore
+
+        assert any(r["Profit"] < -0.2 * r["Sales"] for r in self._rows())
+        for row in self._rows(margin_bounds=[-0.2, 0.6]):
+            assert -0.2 * row["Sales"] - 0.005 <= row["Profit"] <= 0.6 * row["Sales"] + 0.005
+
+        with pytest.raises(ValueError, match="margin_bounds"):
+            superstore(config={"count": 5, "margin_bounds": [0.6, -0.2]})
+        with pytest.raises(ValueError):
+            SuperstoreConfig(margin_bounds=(0.6, -0.2))
+
+    def test_empty_pool_rejected(self):
+        import pytest
+
+        from superstore import employees, superstore
+
+        with pytest.raises(ValueError, match="pool_size must be at least 1"):
+            superstore(config={"count": 5, "pool_size": 0})
+        with pytest.raises(ValueError, match="pool_size must be at least 1"):
+            employees(config={"count": 5, "pool_size": 0})
+
+    def test_invalid_copula_df_rejected(self):
+        import pytest
+
+        from superstore import superstore
+
+        for df in (0.0, -2.0, float("nan")):
+            with pytest.raises(ValueError, match="copula_df must be finite and positive"):
+                superstore(config={"count": 5, "copula_df": df})
+
+    def test_customers(self):
+        baseline = {r["Customer ID"] for r in self._rows()}
+        cohorts = {r["Customer ID"] for r in self._rows(customers={"enable_cohorts": True, "repeat_customer_rate": 0.9})}
+        assert len(cohorts) < len(baseline) / 2
+
+    def test_bundling(self):
+        assert "Bundle ID" not in self._rows()[0]
+
+        rows = self._rows(bundling={"enable": True, "bundle_probability": 0.3})
+        bundled = [r for r in rows if r["Bundle ID"] is not None]
+        assert 0.25 < len(bundled) / len(rows) < 0.35
+        assert all(r["Bundle ID"].startswith("BDL-") for r in bundled)
+
+    def test_regional(self):
+        def health_care_share_in_west(rows):
+            west = [r for r in rows if r["Region"] == "West"]
+            return sum(r["Category"] == "Health Care" for r in west) / len(west)
+
+        assert health_care_share_in_west(self._rows()) < 0.15
+        rows = self._rows(regional={"enable": True, "preferences": {"West": {"Health Care": 20.0}}})
+        assert health_care_share_in_west(rows) > 0.5
+
+    def test_inventory(self):
+        assert "Stock Status" not in self._rows()[0]
+
+        rows = self._rows(inventory={"enable": True, "stock_out_probability": 0.2})
+        assert {r["Stock Status"] for r in rows} == {"In Stock", "Low Stock", "Backorder"}
+        for row in rows:
+            assert (row["Backorder Days"] is not None) == (row["Stock Status"] == "Backorder")
+
+    def test_payment(self):
+        assert "Payment Method" not in self._rows()[0]
+
+        rows = self._rows(payment={"enable": True, "card_numbers": True, "min_fee": 1.0})
+        assert len({r["Payment Method"] for r in rows}) > 3
+        for row in rows:
+            assert row["Processing Fee"] == 0.0 or row["Processing Fee"] >= 1.0
+            assert (row["Card Number"] is not None) == (row["Payment Method"] in ("credit_card", "debit_card"))
+
+    def test_payment_chargeback_settings(self):
+        import pytest
+
+        from superstore import effective_config
+        from superstore.config import SuperstoreConfig
+
+        payment = {"enable": True, "chargebacks": True, "fraud_chargeback_rate": 0.5}
+        resolved = effective_config({"payment": payment})["payment"]
+        assert resolved["chargebacks"] is True
+        assert resolved["fraud_chargeback_rate"] == 0.5
+        assert resolved["dispute_chargeback_rate"] == 0.005
+        assert effective_config(SuperstoreConfig(payment=payment))["payment"] == resolved
+
+        with pytest.raises(ValueError, match="payment.dispute_chargeback_rate"):
+            self._rows(payment={"enable": True, "dispute_chargeback_rate": 2.0})
+
+    def test_with_chargebacks(self):
+        from superstore import with_chargebacks
+
+        config = {"count": 5000, "seed": 7, "output": "dict", "payment": {"enable": True, "chargebacks": True}}
+        sales, chargebacks = with_chargebacks(config=config)
+        assert sales == self._rows(payment=config["payment"])
+        assert chargebacks
+        assert with_chargebacks(config=config) == (sales, chargebacks)
+
+        by_id = {r["Order ID"]: r for r in sales}
+        for event in chargebacks:
+            sale = by_id[event["Order ID"]]
+            assert 0 < event["Amount"] <= sale["Sales"]
+            assert event["Chargeback Date"] > sale["Order Date"]
+            assert (event["Reason Code"] == "10.4") == sale["Is Fraud"]
+        fraud = sum(r["Is Fraud"] for r in sales)
+        fraud_charged = sum(e["Reason Code"] == "10.4" for e in chargebacks)
+        assert fraud_charged > 0.5 * fraud
+
+        _, none = with_chargebacks(config={**config, "payment": {"enable": True}})
+        assert none == []
+
+    def test_returns(self):
+        assert "Return Reason" not in self._rows()[0]
+
+        rows = self._rows(returns={"enable": True, "return_rate": 0.2})
+        sales = {r["Order ID"]: r for r in rows if r["Original Order ID"] is None}
+        returns = [r for r in rows if r["Original Order ID"] is not None]
+        assert returns
+        for row in returns:
+            sale = sales[row["Original Order ID"]]
+            assert row["Quantity"] == -sale["Quantity"]
+            assert row["Sales"] < 0
+            assert row["Return Reason"] in ("Defective", "Wrong Item", "No Longer Needed")
+            assert row["Return Date"] > sale["Ship Date"]
+
+    def test_payment_vocabulary_matches_orders(self):
+        """Superstore rows and e-commerce orders share one canonical payment vocabulary."""
+        from superstore import ecommerce_data
+
+        orders = ecommerce_data({"sessions": 30000, "customers": 2000, "seed": 42}, output="dict")["orders"]
+        rows = self._rows(payment={"enable": True})
+        assert {r["Payment Method"] for r in rows} == set(orders["payment_method"])
+        assert set(orders["payment_method"]) == {
+            "credit_card",
+            "debit_card",
+            "paypal",
+            "apple_pay",
+            "google_pay",
+            "gift_card",
+            "bank_transfer",
+            "cash_on_delivery",
+        }
+
+    def test_pydantic_sub_configs(self):
+        from superstore import superstore
+        from superstore.config import SuperstoreConfig
+
+        config = SuperstoreConfig(count=10, seed=1, inventory={"enable": True}, payment={"enable": True})
+        row = superstore(config=config)[0]
+        assert {"Stock Status", "Payment Method", "Processing Fee"} <= row.keys()
+        assert "Bundle ID" not in row
+
+    def test_stable_v1_omits_optional_columns(self):
+        rows = self._rows(stable_v1=True, payment={"enable": True}, inventory={"enable": True})
+        assert "Payment Method" not in rows[0]
+        assert "Stock Status" not in rows[0]
+
+    def test_order_date_range(self):
+        import pytest
+
+        from superstore import superstore
+
+        config = {"count": 500, "seed": 1, "order_date_start": "2019-02-01", "order_date_end": "2019-02-28", "output": "dict"}
+        rows = superstore(config=config)
+        for row in rows:
+            assert "2019-02-01" <= row["Order Date"] <= row["Ship Date"] <= "2019-02-28"
+        assert superstore(config=config) == rows
+
+        with pytest.raises(ValueError, match="after order_date_end"):
+            superstore(config={"count": 5, "order_date_start": "2019-03-01", "order_date_end": "2019-02-01"})
+
+    def test_price_times_quantity_sales_mode(self):
+        import pytest
+
+        from superstore import SalesMode, SuperstoreConfig, effective_config, superstore
+
+        config = SuperstoreConfig(count=200, seed=1, sales_mode=SalesMode.PRICE_TIMES_QUANTITY)
+        for row in superstore(config=config):
+            assert abs(row["Sales"] - row["Item Price"] * row["Quantity"]) <= 0.5
+        assert effective_config(config)["sales_mode"] == "price_times_quantity"
+        assert effective_config({})["sales_mode"] == "synthetic"
+
+        with pytest.raises(ValueError, match="Invalid sales_mode"):
+            superstore(config={"count": 5, "sales_mode": "revenue"})
+
+    def test_custom_categories(self):
+        import pytest
+
+        from superstore import SuperstoreConfig, effective_config, superstore
+
+        categories = {"Oncology": ["Biologics", "Small Molecules"], "Vaccines": ["Pediatric"]}
+        config = {"count": 500, "seed": 1, "categories": categories, "output": "dict"}
+        rows = superstore(config=config)
+        assert {r["Category"] for r in rows} == set(categories)
+        for row in rows:
+            assert row["Sub-Category"] in categories[row["Category"]]
+        assert effective_config(config)["categories"] == categories
+        assert {r["Category"] for r in superstore(config=SuperstoreConfig(**config))} == set(categories)
+
+        with pytest.raises(ValueError, match="category 'Vaccines' has no sub-categories"):
+            superstore(config={"count": 5, "categories": {"Oncology": ["Biologics"], "Vaccines": []}})
+        with pytest.raises(ValueError, match="no sub-categories"):
+            SuperstoreConfig(categories={"Vaccines": []})
+
+    def test_locale_and_currency(self):
+        import pytest
+
+        from superstore import superstore
+
+        base = {"count": 200, "seed": 3, "output": "dict"}
+        us = superstore(config=base)
+        assert "Currency" not in us[0]
+        rows = superstore(config={**base, "locale": "ja_JP"})
+        for a, b in zip(us, rows):
+            assert (b["Country"], b["Currency"]) == ("JP", "JPY")
+            assert b["Postal Code"][3] == "-"
+            assert a["Order ID"] == b["Order ID"]
+            assert abs(a["Item Price"] * 150 - b["Item Price"]) < 1
+
+        assert {r["Currency"] for r in superstore(config={**base, "locale": "de_DE", "currency": "GBP"})} == {"GBP"}
+        with pytest.warns(UserWarning, match="unsupported locale 'xx_XX'"):
+            rows = superstore(config={**base, "locale": "xx_XX"})
+        assert [r["City"] for r in rows] == [r["City"] for r in us]
+        with pytest.raises(ValueError, match="Unknown currency 'XYZ'"):
+            superstore(config={**base, "currency": "XYZ"})
+
+    def test_taxonomy(self):
+        import pytest
+
+        from superstore import SuperstoreConfig, effective_config, superstore
+
+        taxonomy = {
+            "Electronics": {"Audio": ["Headphones", "Speakers"], "Accessories": ["Cables"]},
+            "Apparel": {"Accessories": ["Belts", "Hats"]},
+        }
+        config = {"count": 500, "seed": 1, "taxonomy": taxonomy, "output": "dict"}
+        rows = superstore(config=config)
+        for row in rows:
+            assert row["Product Type"] in taxonomy[row["Category"]][row["Sub-Category"]]
+        assert {r["Product Type"] for r in rows} == {"Headphones", "Speakers", "Cables", "Belts", "Hats"}
+        assert effective_config(config)["taxonomy"] == taxonomy
+        for row in superstore(config=SuperstoreConfig(**config)):
+            assert row["Product Type"] in taxonomy[row["Category"]][row["Sub-Category"]]
+        assert "Product Type" not in superstore(config={"count": 5, "output": "dict"})[0]
+
+        with pytest.raises(ValueError, match="sub-category 'Audio' of 'Electronics' has no product types"):
+            superstore(config={"count": 5, "taxonomy": {"Electronics": {"Audio": []}}})
+        with pytest.raises(ValueError, match="can't both be set"):
+            SuperstoreConfig(categories={"A": ["a"]}, taxonomy={"A": {"a": ["t"]}})
+
+
+class TestEmployeeConfig:
+    """Tests for EmployeeConfig-based API."""
+
+    def test_employees_with_config(self):
+        from superstore import EmployeeConfig, employees
+
+        data = employees(config=EmployeeConfig(count=20, seed=42))
+        assert isinstance(data, list)
+        assert len(data) == 20
+        assert data == employees(count=20, seed=42, output="dict")
+
+    def test_employees_dict_config(self):
+        import pandas as pd
+
+        from superstore import employees
+
+        df = employees(config={"count": 15, "seed": 3, "valid_identifiers": True})
+        assert isinstance(df, pd.DataFrame)
+        assert len(df) == 15
+        assert df.equals(employees(count=15, seed=3, valid_identifiers=True))
+
+    def test_employee_regions_match_superstore(self):
+        from superstore import employees, superstore
+
+        store_regions = {r["Region"] for r in superstore(count=500, seed=1, output="dict")}
+        staff_regions = {e["Region"] for e in employees(count=200, seed=1, output="dict")}
+        assert staff_regions <= store_regions
+
+        regions = ["North America", "EMEA", "APAC"]
+        store_regions = {r["Region"] for r in superstore(config={"count": 500, "seed": 1, "regions": regions, "output": "dict"})}
+        staff_regions = {e["Region"] for e in employees(config={"count": 200, "seed": 1, "regions": regions, "output": "dict"})}
+        assert store_regions == staff_regions == set(regions)
+
+    def test_empty_regions_rejected(self):
+        import pytest
+
+        from superstore import employees
+
+        with pytest.raises(ValueError, match="regions must not be empty"):
+            employees(config={"count": 5, "regions": []})
+
+    def test_departments_and_managers(self):
+        import pytest
+
+        from superstore import employees
+        from superstore.config import Department, EmployeeConfig
+
+        data = employees(config={"count": 2000, "seed": 5, "output": "dict"})
+        by_id = {e["Employee ID"]: e for e in data}
+        for e in data:
+            if e["Manager ID"] is None:
+                assert e["Job Title"] == f"{e['Department']} Manager"
+            else:
+                assert by_id[e["Manager ID"]]["Department"] == e["Department"]
+
+        def median(department):
+            salaries = sorted(e["Salary"] for e in data if e["Department"] == department)
+            return salaries[len(salaries) // 2]
+
+        assert median("Engineering") > median("Support")
+
+        config = EmployeeConfig(
+            count=100,
+            seed=5,
+            departments=[Department(name="Research", job_titles=["Scientist"], salary_range=(80000, 120000))],
+            manager_ratio=0.2,
+        )
+        data = employees(config=config)
+        assert {e["Department"] for e in data} == {"Research"}
+        assert {e["Job Title"] for e in data} == {"Scientist", "Research Manager"}
+        assert data == employees(config=config.model_dump())
+
+        with pytest.raises(ValueError):
+            employees(config={"count": 5, "departments": [{"name": "Research", "salary_range": [2, 1]}]})
+
+    def test_tenure_and_attrition(self):
+        import datetime
+
+        import pytest
+
+        from superstore import employees
+
+        data = employees(config={"count": 2000, "seed": 5, "attrition_rate": 0.3, "output": "dict"})
+        terminated = 0
+        for e in data:
+            born = datetime.date.fromisoformat(e["Date of Birth"])
+            started = datetime.date.fromisoformat(e["Start Date"])
+            assert started.year - born.year - ((started.month, started.day) < (born.month, born.day)) >= 18
+            if e["Termination Date"] is not None:
+                assert datetime.date.fromisoformat(e["Termination Date"]) > started
+                terminated += 1
+        assert 0.25 < terminated / len(data) < 0.35
+
+        data = employees(config={"count": 200, "seed": 5, "attrition_rate": 0, "output": "dict"})
+        assert all(e["Termination Date"] is None for e in data)
+
+        with pytest.raises(ValueError, match="attrition_rate"):
+            employees(config={"count": 5, "attrition_rate": 1.5})
+
+    def test_employees_config_override(self):
+        from superstore import EmployeeConfig, employees
+
+        config = EmployeeConfig(count=50, seed=1)
+        data = employees(config=config, count=5, seed=42)
+        assert data == employees(count=5, seed=42, output="dict")
+
+
+class TestMaskPii:
+    def test_masked_formats(self):
+        import re
+
+        from superstore import employees
+
+        raw = employees(count=200, seed=42, output="dict")
+        masked = employees(count=200, seed=42, output="dict", mask_pii=True)
+
+        raw_values = {r[k] for r in raw for k in ("SSN", "Email", "Phone Number")}
+        for r, m in zip(raw, masked):
+            assert re.fullmatch(r"\*\*\*-\*\*-\d{4}", m["SSN"])
+            assert m["SSN"][-4:] == r["SSN"][-4:]
+            assert re.fullmatch(r"\(\*\*\*\) \*\*\*-\d{4}", m["Phone Number"])
+            assert re.fullmatch(r"[0-9a-f]{16}@.+", m["Email"])
+            assert m["Email"].split("@")[1] == r["Email"].split("@")[1]
+            assert not {m["SSN"], m["Email"], m["Phone Number"]} & raw_values
+
+    def test_mask_selected_fields(self):
+        from superstore import employees
+
+        raw = employees(count=10, seed=42, output="dict")
+        masked = employees(count=10, seed=42, output="dict", mask_pii=["ssn"])
+        assert [r["Email"] for r in raw] == [m["Email"] for m in masked]
+        assert all(m["SSN"].startswith("***-**-") for m in masked)
+
+
+class TestValidIdentifiers:
+    def test_ssn_structural_rules(self):
+        from superstore import employees
+
+        data = employees(count=2000, seed=42, output="dict", valid_identifiers=True)
+        for row in data:
+            area, group, serial = row["SSN"].split("-")
+            assert len(area) == 3 and len(group) == 2 and len(serial) == 4
+            assert area != "000" and area != "666" and int(area) < 900
+            assert group != "00"
+            assert serial != "0000"
+
+    def test_ein_prefixes(self):
+        from superstore import SuperstoreConfig, superstore
+
+        invalid = {0, 7, 8, 9, 17, 18, 19, 28, 29, 49, 69, 70, 78, 79, 89, 96, 97}
+        data = superstore(SuperstoreConfig(count=1000, seed=42, valid_identifiers=True, output="dict"))
+        assert all(int(row["Order ID"][:2]) not in invalid for row in data)
+
+
+class TestStableV1:
+    def test_golden_rows(self):
+        from superstore import superstore
+
+        # Pinned to rust/tests/golden/superstore_stable_v1_seed42.csv
+        rows = superstore(count=20, seed=42, output="dict", stable_v1=True)[:3]
+        assert [r["Order ID"] for r in rows] == ["43-6113407", "22-3055354", "37-2456009"]
+        assert [r["Order Date"] for r in rows] == ["2024-08-18", "2024-06-10", "2024-09-15"]
+        assert [r["Sales"] for r in rows] == [1882, 8130, 6695]
+
+    def test_repeatable(self):
+        from superstore import superstore
+
+        a = superstore(count=100, seed=7, output="dict", stable_v1=True)
+        b = superstore(count=100, seed=7, output="dict", stable_v1=True)
+        assert a == b
+
+    def test_unaffected_by_optional_features(self):
+        from superstore import superstore
+
+        base = {"count": 200, "seed": 42, "stable_v1": True}
+        a = superstore(base, output="dict")
+        b = superstore({**base, "valid_identifiers": True}, output="dict")
+        assert a == b
+
+
+class TestRngAlgorithm:
+    def test_pcg64_reproducible(self):
+        from superstore import rngAlgorithm, setRngAlgorithm, superstore
+
+        assert rngAlgorithm() == "std"
+        std_rows = superstore(count=50, seed=42, output="dict", stable_v1=True)
+        try:
+            setRngAlgorithm("pcg64")
+            assert rngAlgorithm() == "pcg64"
+            a = superstore(count=50, seed=42, output="dict", stable_v1=True)
+            b = superstore(count=50, seed=42, output="dict", stable_v1=True)
+        finally:
+            setRngAlgorithm("std")
+        assert a == b
+        assert a != std_rows
+
+    def test_unknown_algorithm(self):
+        import pytest
+
+        from superstore import setRngAlgorithm
+
+        with pytest.raises(ValueError):
+            setRngAlgorithm("mt19937")
+
+
+class TestWithEmployees:
+    def test_join_on_region(self):
+        from superstore import with_employees
+
+        sales, staff = with_employees(count=500, employee_count=40, seed=42)
+        assert list(sales.columns) == SUPERSTORE_COLUMNS
+        assert list(staff.columns) == EMPLOYEES_COLUMNS
+        assert len(staff) == 40
+
+        assert set(staff["Region"]) <= set(sales["Region"])
+        joined = sales.merge(staff, on="Region", suffixes=("", " (Employee)"))
+        assert len(joined) > 0
+
+    def test_matches_superstore(self):
+        from superstore import superstore, with_employees
+
+        sales, _ = with_employees(count=100, seed=7, output="dict")
+        assert sales == superstore(count=100, seed=7, output="dict")
+
+
+class TestBatch:
+    def test_distinct_and_reproducible(self):
+        from superstore import batch
+
+        first = batch(count=50, n_datasets=3, base_seed=42, output="dict")
+        second = batch(count=50, n_datasets=3, base_seed=42, output="dict")
+        assert len(first) == 3
+        assert all(len(rows) == 50 for rows in first)
+        assert first == second
+
+        order_ids = [[row["Order ID"] for row in rows] for rows in first]
+        assert order_ids[0] != order_ids[1]
+        assert order_ids[1] != order_ids[2]
+
+    def test_base_seed_changes_batch(self):
+        from superstore import batch
+
+        a = batch(count=20, n_datasets=2, base_seed=1, output="dict")
+        b = batch(count=20, n_datasets=2, base_seed=2, output="dict")
+        assert a != b
+
+
+class TestRowAt:
+    def test_row_at_matches_full_run(self):
+        from superstore import row_at, superstore
+
+        config = {"per_row_seeding": True, "count": 300}
+        rows = superstore(config=config, seed=42, output="dict")
+        for index in (0, 17, 150, 299):
+            assert row_at(index, seed=42, config=config) == rows[index]
+
+
+class TestEffectiveConfig:
+    def test_empty_config_gives_defaults(self):
+        from superstore import effective_config
+
+        defaults = effective_config({})
+        assert defaults["count"] == 1000
+        assert defaults["output"] == "pandas"
+        assert defaults["seed"] is None
+        assert defaults["seasonality"]["q4_multiplier"] == 1.5
+        assert defaults["payment"]["fraud_simulation"] is True
+        assert len(defaults["bundling"]["bundles"]) == 3
+
+    def test_partial_config_includes_all_default_fields(self):
+        from superstore import effective_config
+
+        defaults = effective_config({})
+        resolved = effective_config({"count": 10, "seasonality": {"enable": True}})
+        assert resolved.keys() == defaults.keys()
+        assert resolved["seasonality"].keys() == defaults["seasonality"].keys()
+        assert resolved["count"] == 10
+        assert resolved["seasonality"]["enable"] is True
+        assert resolved["seasonality"]["q4_multiplier"] == defaults["seasonality"]["q4_multiplier"]
+        assert {k: v for k, v in resolved.items() if k not in ("count", "seasonality")} == {
+            k: v for k, v in defaults.items() if k not in ("count", "seasonality")
+        }
+
+    def test_nested_config_dict_round_trip(self):
+        import pytest
+
+        from superstore import effective_config, superstore
+        from superstore.config import SuperstoreConfig
+
+        config = {
+            "count": 300,
+            "seed": 11,
+            "min_sales": 50,
+            "max_sales": 500,
+            "min_quantity": 2,
+            "max_quantity": 20,
+            "max_discount_percent": 30.0,
+            "seasonality": {"enable": True, "q4_multiplier": 2.0},
+            "promotions": {"enable": True},
+            "customers": {"enable_cohorts": True, "vip_rate": 0.2},
+            "bundling": {"enable": True},
+            "regional": {"enable": True},
+            "inventory": {"enable": True},
+            "payment": {"enable": True, "fraud_simulation": True},
+            "returns": {"enable": True},
+        }
+        rows = superstore(config, output="dict")
+        assert superstore(config, output="dict") == rows
+        assert superstore(effective_config(config), output="dict") == rows
+        # VIP orders may exceed max_quantity; returns carry negative quantities
+        assert min(r["Quantity"] for r in rows if r["Quantity"] > 0) >= 2
+        assert all(0 <= r["Discount"] <= 30.0 for r in rows)
+
+        model = SuperstoreConfig(**config)
+        assert superstore(model.model_dump(), output="dict") == superstore(model, output="dict")
+
+        with pytest.raises(ValueError):
+            superstore({**config, "min_quantity": 30}, output="dict")
+
+    def test_resolved_config_reproduces_data(self):
+        from superstore import effective_config, superstore
+
+        config = {"count": 50, "seed": 3, "promotions": {"enable": True}, "regional": {"enable": True}}
+        resolved = effective_config(config)
+        assert superstore(resolved, output="dict") == superstore(config, output="dict")
+
+
+class TestValidateConfig:
+    def test_defaults_have_no_warnings(self):
+        from superstore import validate_config
+        from superstore.config import SuperstoreConfig
+
+        assert validate_config() == []
+        assert validate_config({}) == []
+        assert validate_config(SuperstoreConfig(count=1_000_000)) == []
+
+    def test_small_pool_warns_about_location_cardinality(self):
+        from superstore import validate_config
+        from superstore.config import SuperstoreConfig
+
+        for config in ({"pool_size": 10, "count": 1_000_000}, SuperstoreConfig(pool_size=10, count=1_000_000)):
+            warnings = validate_config(config)
+            assert len(warnings) == 1
+            assert "pool_size 10" in warnings[0]
+            assert "low cardinality" in warnings[0]
+
+    def test_ineffective_settings_warn(self):
+        from superstore import validate_config
+
+        warnings = validate_config(
+            {
+                "customers": {"enable_cohorts": True, "vip_order_multiplier": 5.0},
+                "seasonality": {"enable": True, "q4_multiplier": 1.0, "summer_multiplier": 1.0, "back_to_school_multiplier": 1.0},
+            }
+        )
+        assert len(warnings) == 2
+        assert "vip_order_multiplier" in warnings[0]
+        assert "seasonality" in warnings[1]
+
+    def test_fallbacks_are_returned_not_warned(self):
+        import warnings as pywarnings
+
+        from superstore import validate_config
+
+        config = {
+            "sales_quantity_correlation": 0.8,
+            "sales_profit_correlation": 0.9,
+            "discount_profit_correlation": -0.6,
+            "repair_correlations": False,
+            "locale": "xx_XX",
+        }
+        with pywarnings.catch_warnings():
+            pywarnings.simplefilter("error")
+            warnings = validate_config(config)
+        assert len(warnings) == 2
+        assert "sampled independently" in warnings[0]
+        assert "unsupported locale 'xx_XX'" in warnings[1]
+
+
+class TestMissingOutputLibrary:
+    def test_missing_polars_gives_install_hint(self, monkeypatch):
+        import sys
+
+        import pytest
+
+        from superstore import logs, superstore
+
+        # A None entry in sys.modules makes `import polars` raise ImportError
+        monkeypatch.setitem(sys.modules, "polars", None)
+        with pytest.raises(ImportError, match="pip install polars"):
+            superstore(count=5, output="polars")
+        with pytest.raises(ImportError, match='output="polars"'):
+            logs({"count": 5, "output": "polars"})
+
+    def test_missing_pandas_gives_install_hint(self, monkeypatch):
+        import sys
+
+        import pytest
+
+        from superstore import weather
+
+        monkeypatch.setitem(sys.modules, "pandas", None)
+        with pytest.raises(ImportError, match="pip install pandas"):
+            weather(count=5)
+
+    def test_missing_library_in_helper_outputs(self, monkeypatch):
+        import sys
+
+        import pytest
+
+        from superstore import ecommerce_interactions, fix_messages, subscriptions
+
+        monkeypatch.setitem(sys.modules, "pandas", None)
+        monkeypatch.setitem(sys.modules, "polars", None)
+        with pytest.raises(ImportError, match="pip install pandas"):
+            fix_messages(n=5, seed=1)
+        with pytest.raises(ImportError, match="pip install polars"):
+            subscriptions(count=5, seed=1, output="polars")
+        with pytest.raises(ImportError, match="pip install polars"):
+            ecommerce_interactions({"sessions": 20, "seed": 1}, output="polars")
+
+
+class TestInvalidOutput:
+    """A misspelled output format raises instead of silently returning pandas."""
+
+    def test_core_generators(self):
+        import pytest
+
+        from superstore import batch, employees, superstore, superstoreParallel, timeseries, timeseriesData, weather, with_employees
+
+        with pytest.raises(ValueError, match="Invalid output format 'pands'. Must be 'pandas', 'polars', 'dict', or 'arrow'"):
+            superstore(count=5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            superstore(config={"count": 5, "output": "pands"})
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            employees(count=5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            with_employees(count=5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            batch(count=5, n_datasets=2, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            superstoreParallel(count=5, output="pands")
+        with pytest.raises(ValueError, match="'with_corr'"):
+            timeseries(nper=5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            timeseriesData(nper=5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            weather(count=5, output="pands")
+
+    def test_ecommerce_generators(self):
+        import pytest
+
+        from superstore import cohort_retention, ecommerce_data, ecommerce_interactions, ecommerce_products, ecommerce_sessions, subscriptions
+
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            ecommerce_sessions(5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            ecommerce_products(5, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            ecommerce_data({"sessions": 5, "customers": 5}, output="pands")
+        with pytest.raises(ValueError, match="'edgelist'"):
+            ecommerce_interactions({"sessions": 5, "customers": 5}, output="pands")
+        customers = ecommerce_data({"sessions": 20, "customers": 5, "seed": 1}, output="dict")["customers"]
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            cohort_retention(customers, output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            subscriptions(count=5, output="pands")
+
+    def test_log_and_telemetry_generators(self):
+        import pytest
+
+        from superstore import app_logs, logs, logs_with_deploys, telemetry
+
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            logs({"count": 5, "output": "pands"})
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            logs_with_deploys({"count": 5, "output": "pands"})
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            app_logs({"count": 5, "output": "pands"})
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            telemetry({"machine_count": 1, "readings_per_machine": 2, "output": "pands"})
+
+    def test_finance_generators(self):
+        import pytest
+
+        from superstore import credit_defaults, finance, fix_messages, options_chain, portfolio, stock_prices, yield_curve
+
+        config = {"ndays": 5, "output": "pands"}
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            stock_prices(config)
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            options_chain(config)
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            finance(config)
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            fix_messages(5, output="pands")
+        prices = stock_prices({"ndays": 5, "seed": 1, "output": "dict"})
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            portfolio(prices, [1.0], output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            yield_curve(output="pands")
+        with pytest.raises(ValueError, match="Invalid output format 'pands'"):
+            credit_defaults(n_obligors=5, output="pands")
+
+    def test_dict_output_for_options(self):
+        from superstore import finance, options_chain
+
+        chain = options_chain({"ndays": 5, "seed": 1, "output": "dict"})
+        assert set(chain) >= {"strike", "option_type", "implied_vol"}
+        prices, options = finance({"ndays": 5, "seed": 1, "output": "dict"})
+        assert len(prices["close"]) == 5
+        assert options.keys() == chain.keys()
+
+
+class TestReleasesGil:
+    def test_other_threads_progress_during_generation(self):
+        import threading
+        import time
+
+        from superstore import (
+            batch,
+            ecommerce_data,
+            employeesParallel,
+            fix_messages,
+            stock_prices,
+            superstore,
+            superstoreParallel,
+            timeseries,
+            with_employees,
+        )
+
+        ticks = []
+        done = threading.Event()
+
+        def ticker():
+            while not done.is_set():
+                ticks.append(time.perf_counter())
+                time.sleep(0.001)
+
+        thread = threading.Thread(target=ticker)
+        thread.start()
+        try:
+            for generate in (
+                lambda: superstore(count=50000, seed=42, output="dict"),
+                lambda: ecommerce_data(config={"sessions": 50000, "seed": 42}, output="dict"),
+                lambda: timeseries(config={"nper": 200000, "ncol": 8, "seed": 42, "output": "dict"}),
+                lambda: superstoreParallel(50000, output="dict", seed=42),
+                lambda: employeesParallel(50000, output="dict", seed=42),
+                lambda: with_employees(count=50000, employee_count=5000, seed=42, output="dict"),
+                lambda: batch(count=10000, n_datasets=5, base_seed=42, output="dict"),
+                lambda: stock_prices({"ndays": 2000, "n_assets": 50, "seed": 42, "output": "dict"}),
+                lambda: fix_messages(n=50000, seed=42, output="dict"),
+            ):
+                start = time.perf_counter()
+                generate()
+                end = time.perf_counter()
+                # Holding the GIL for the whole call would allow a tick or two at the edges at most
+                assert sum(start < t < end for t in ticks) > 10
+        finally:
+            done.set()
+            thread.join()
@@ -1,3 +1,6 @@
+import pytest
+
+
 class TestVendored:
     def test_timeseries(self):
         from superstore import timeseries
@@ -137,3 +140,205 @@ class TestVendored:
         data1 = timeseries(nper=50, ncol=3, output="dict", seed=42)
         data2 = timeseries(nper=50, ncol=3, output="dict", seed=42)
         assert data1 == data2
+
+    def test_timeseries_column_names(self):
+        from superstore import timeseries
+
+        tickers = ["AAPL", "MSFT", "GOOG"]
+        data = timeseries(config={"ncol": 3, "column_names": tickers, "seed": 42}, output="dict")
+        assert list(data) == ["index", *tickers]
+
+        default = timeseries(config={"ncol": 3, "seed": 42}, output="dict")
+        assert data["MSFT"] == default["B"]
+
+    def test_timeseries_column_names_mismatch(self):
+        from superstore import timeseries
+
+        data = timeseries(config={"ncol": 4, "column_names": ["AAPL", "MSFT"]}, output="dict")
+        assert list(data) == ["index", "AAPL", "MSFT", "C", "D"]
+        with pytest.raises(ValueError):
+            timeseries(config={"ncol": 2, "column_names": ["AAPL", "MSFT", "GOOG"]})
+        with pytest.raises(ValueError, match="Duplicate column name 'B'"):
+            timeseries(config={"ncol": 2, "column_names": ["B"]})
+        with pytest.raises(ValueError, match="Duplicate column name 'AAPL'"):
+            timeseries(config={"ncol": 2, "column_names": ["AAPL", "AAPL"]})
+
+    def test_timeseries_start_date(self):
+        from superstore import timeseries
+
+        config = {"nper": 5, "ncol": 2, "seed": 42, "start_date": "2020-01-01", "output": "dict"}
+        data = timeseries(config=config)
+        assert str(data["index"][0]).startswith("2020-01-01")
+        assert data["A"] == timeseries(config={**config, "start_date": None})["A"]
+        with pytest.raises(ValueError):
+            timeseries(config={**config, "start_date": "01/01/2020"})
+
+    def test_timeseries_intraday_frequency(self):
+        from superstore import timeseries
+
+        data = timeseries(config={"nper": 391, "ncol": 1, "freq": "T", "start_date": "2024-01-05", "output": "dict"})
+        assert str(data["index"][0]) == "2024-01-05 09:30:00"
+        assert str(data["index"][389]) == "2024-01-05 15:59:00"
+        assert str(data["index"][390]) == "2024-01-08 09:30:00"
+
+        data = timeseries(config={"nper": 3, "ncol": 1, "freq": "H", "intraday": {"market_open": "10:00", "market_close": "12:00"}, "output": "dict"})
+        assert [str(d)[11:16] for d in data["index"]] == ["10:00", "11:00", "10:00"]
+        with pytest.raises(ValueError):
+            timeseries(config={"freq": "min", "intraday": {"market_open": "16:00", "market_close": "09:30"}})
+
+    def test_timeseries_exchange_holidays(self):
+        from superstore import timeseries
+
+        config = {"nper": 252, "ncol": 1, "start_date": "2021-01-01", "exchange": "NYSE", "output": "dict"}
+        dates = {str(d)[:10] for d in timeseries(config=config)["index"]}
+        assert len(dates) == 252
+        assert max(dates) == "2021-12-31"
+        assert not dates & {"2021-01-01", "2021-04-02", "2021-07-05", "2021-11-25", "2021-12-24"}
+
+        dates = {str(d)[:10] for d in timeseries(config={**config, "exchange": None, "holidays": ["2021-01-04"]})["index"]}
+        assert "2021-01-04" not in dates and "2021-01-01" in dates
+        with pytest.raises(ValueError):
+            timeseries(config={**config, "exchange": "TSE"})
+
+    def test_timeseries_with_corr(self):
+        from superstore import timeseries
+
+        config = {"nper": 2000, "ncol": 3, "cross_correlation": 0.8, "seed": 42, "output": "dict"}
+        data, corr = timeseries(config=config, output="with_corr")
+        assert list(data) == ["index", "A", "B", "C"]
+        assert list(corr) == ["A", "B", "C"]
+        for i, a in enumerate(corr):
+            assert corr[a][i] == 1.0
+            for j, b in enumerate(corr):
+                assert corr[a][j] == corr[b][i]
+                if i != j:
+                    assert abs(corr[a][j] - 0.8) < 0.1
+
+        for output in ("arrow", "pands"):
+            with pytest.raises(ValueError, match=f"Invalid output format '{output}'"):
+                timeseries(config={**config, "output": output}, output="with_corr")
+
+    def test_timeseries_correlation_matrix(self):
+        from superstore import timeseries
+
+        matrix = [[1.0, 0.8, -0.4], [0.8, 1.0, 0.0], [-0.4, 0.0, 1.0]]
+        config = {"nper": 3000, "ncol": 3, "ar_phi": 0.0, "correlation_matrix": matrix, "seed": 42, "output": "dict"}
+        _, corr = timeseries(config=config, output="with_corr")
+        for i, a in enumerate(corr):
+            for j in range(3):
+                assert abs(corr[a][j] - matrix[i][j]) < 0.05
+
+        with pytest.raises(ValueError):
+            timeseries(config={**config, "ncol": 2})
+        with pytest.raises(ValueError):
+            timeseries(config={**config, "correlation_matrix": [[1.0, 0.9, 0.9], [0.9, 1.0, -0.9], [0.9, -0.9, 1.0]]})
+
+    def test_timeseries_ar_coefficients(self):
+        from superstore import timeseries
+
+        config = {"nper": 200, "ncol": 2, "ar_phi": 0.6, "seed": 42, "output": "dict"}
+        ar1 = timeseries(config=config)
+        arp = timeseries(config={**config, "ar_phi": 0.0, "ar_coefficients": [0.6]})
+        for col in ar1:
+            if col != "index":
+                assert all(abs(a - b) < 1e-9 for a, b in zip(ar1[col], arp[col]))
+
+        assert len(timeseries(config={**config, "ar_coefficients": [1.5, -0.75]})["A"]) == 200
+        with pytest.raises(ValueError):
+            timeseries(config={**config, "ar_coefficients": [0.5, 0.6]})
+
+    def test_timeseries_include_metrics(self):
+        from superstore import timeseries
+
+        config = {"nper": 500, "ncol": 3, "cross_correlation": 0.7, "seed": 42, "output": "dict"}
+        data, metrics = timeseries(config=config, include_metrics=True)
+        assert data == timeseries(config=config)
+        assert list(metrics) == ["A", "B", "C"]
+        for values in metrics.values():
+            assert set(values) == {"alpha", "beta", "sharpe_ratio", "volatility", "max_drawdown"}
+            assert values["volatility"] > 0 and values["max_drawdown"] >= 0
+
+        # The first column is the market proxy
+        assert metrics["A"]["alpha"] == 0.0 and metrics["A"]["beta"] == 1.0
+        assert 0.4 < metrics["B"]["beta"] < 1.0
+
+        _, corr, metrics = timeseries(config=config, output="with_corr", include_metrics=True)
+        assert list(corr) == list(metrics) == ["A", "B", "C"]
+
+    def test_timeseries_include_metrics_pandas(self):
+        import pandas as pd
+
+        from superstore import timeseries
+
+        config = {"nper": 500, "ncol": 3, "seed": 42, "output": "pandas"}
+        data, metrics = timeseries(config=config, include_metrics=True)
+        assert isinstance(metrics, pd.DataFrame)
+        # One row per data column, indexed by column name
+        assert list(metrics.index) == list(data.columns) == ["A", "B", "C"]
+        assert list(metrics.columns) == ["alpha", "beta", "sharpe_ratio", "volatility", "max_drawdown"]
+        assert metrics.loc["A", "alpha"] == 0.0 and metrics.loc["A", "beta"] == 1.0
+
+    def test_timeseries_include_metrics_empty_series(self):
+        from superstore import timeseries
+
+        _, metrics = timeseries(config={"nper": 0, "ncol": 2, "output": "dict"}, include_metrics=True)
+        for values in metrics.values():
+            assert values == {"alpha": 0.0, "beta": 1.0, "sharpe_ratio": 0.0, "volatility": 0.0, "max_drawdown": 0.0}
+
+    def test_timeseries_garch_clusters_volatility(self):
+        from superstore import timeseries
+
+        def squared_return_autocorr(garch):
+            config = {"nper": 5000, "ncol": 1, "ar_phi": 0.0, "cumulative": False, "seed": 42, "output": "dict", "garch": garch}
+            sq = [v * v for v in timeseries(config=config)["A"]]
+            mean = sum(sq) / len(sq)
+            dev = [v - mean for v in sq]
+            return sum(a * b for a, b in zip(dev, dev[1:])) / sum(d * d for d in dev)
+
+        assert abs(squared_return_autocorr({})) < 0.05
+        assert squared_return_autocorr({"enable": True, "alpha": 0.1, "beta": 0.85, "omega": 0.05}) > 0.1
+
+    def test_timeseries_garch_rejects_nonstationary(self):
+        from superstore import TimeseriesConfig, timeseries
+
+        with pytest.raises(ValueError):
+            timeseries(config={"garch": {"enable": True, "alpha": 0.2, "beta": 0.8}})
+        with pytest.raises(ValueError):
+            TimeseriesConfig(garch={"enable": True, "alpha": 0.2, "beta": 0.8})
+
+    def test_timeseries_advanced_sub_configs(self):
+        from superstore import TimeseriesConfig, timeseries
+
+        base = {"nper": 200, "ncol": 1, "seed": 42, "output": "dict"}
+        plain = timeseries(config=base)["A"]
+
+        reverting = timeseries(config={**base, "mean_reversion": {"enable": True, "mu": 100.0, "theta": 0.5}})["A"]
+        assert abs(sum(reverting[50:]) / len(reverting[50:]) - 100.0) < 1.0
+
+        for key, sub in [
+            ("intraday", {"enable": True, "opening_volatility_mult": 3.0}),
+            ("event_windows", {"enable": True, "event_indices": [100], "abnormal_return_mean": 1.0}),
+        ]:
+            assert timeseries(config={**base, key: sub})["A"] != plain
+            assert timeseries(config={**base, key: {**sub, "enable": False}})["A"] == plain
+
+        config = TimeseriesConfig(**base, event_windows={"enable": True, "event_indices": [100]})
+        assert timeseries(config=config)["A"] == timeseries(config=config.model_dump())["A"]
+
+    def test_indicators_sma(self):
+        import math
+
+        from superstore import indicators, timeseries
+
+        data = timeseries(config={"nper": 60, "ncol": 2, "seed": 42}, output="dict")
+        result = indicators(data, ["sma:20", "ema:12", "rsi:14"])
+        assert list(result) == ["index", "A", "B", "A_sma_20", "A_ema_12", "A_rsi_14", "B_sma_20", "B_ema_12", "B_rsi_14"]
+        assert "A_sma_20" not in data
+
+        values, sma = data["A"], result["A_sma_20"]
+        assert all(math.isnan(v) for v in sma[:19])
+        for i in range(19, len(values)):
+            assert abs(sma[i] - sum(values[i - 19 : i + 1]) / 20) < 1e-9
+
+        with pytest.raises(ValueError):
+            indicators(data, ["macd:9"])